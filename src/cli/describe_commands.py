@@ -0,0 +1,91 @@
+"""Machine-readable description of the CLI's subcommands.
+
+Studio runs ``forge --describe-commands`` to build its pipeline palette from
+the parameters the installed version accepts, so the JSON shape here is
+part of that contract.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+from typing import Any, Sequence
+
+PATH_PARAMETER_SUFFIXES = ("_path", "_dir", "_file")
+PATH_PARAMETER_NAMES = ("source",)
+
+
+class DescribeCommandsAction(argparse.Action):
+    """Print every subcommand as JSON and exit, like ``--version``."""
+
+    def __init__(self, option_strings: Sequence[str], dest: str, **kwargs: Any) -> None:
+        super().__init__(option_strings, dest, nargs=0, default=argparse.SUPPRESS, **kwargs)
+
+    def __call__(
+        self,
+        parser: argparse.ArgumentParser,
+        namespace: argparse.Namespace,
+        values: Any,
+        option_string: str | None = None,
+    ) -> None:
+        print(json.dumps(describe_commands(parser), sort_keys=True))
+        parser.exit()
+
+
+def describe_commands(parser: argparse.ArgumentParser) -> dict[str, Any]:
+    """Describe each subcommand of ``parser`` and its parameters.
+
+    Args:
+        parser: Top-level CLI parser.
+
+    Returns:
+        ``{"commands": [...]}`` with a name, description, and parameter list
+        per subcommand.
+    """
+    commands = []
+    for action in parser._actions:
+        if not isinstance(action, argparse._SubParsersAction):
+            continue
+        for choice in action._get_subactions():
+            subparser = action.choices[choice.dest]
+            commands.append(
+                {
+                    "name": choice.dest,
+                    "description": choice.help or "",
+                    "parameters": [
+                        _describe_parameter(argument)
+                        for argument in subparser._actions
+                        if argument.help != argparse.SUPPRESS
+                        and not isinstance(argument, argparse._HelpAction)
+                    ],
+                }
+            )
+    return {"commands": commands}
+
+
+def _describe_parameter(argument: argparse.Action) -> dict[str, Any]:
+    """Describe one argument in the shape of Studio's node parameters."""
+    long_flags = [flag for flag in argument.option_strings if flag.startswith("--")]
+    default = argument.default
+    return {
+        "name": argument.dest,
+        "flag": long_flags[0] if long_flags else None,
+        "value_type": _value_type(argument),
+        "required": bool(argument.required),
+        "default_value": None if default in (None, False) else str(default),
+        "range": None,
+        "allowed_values": [str(choice) for choice in argument.choices or ()],
+    }
+
+
+def _value_type(argument: argparse.Action) -> str:
+    """Map an argument's parser type onto Studio's parameter value types."""
+    if isinstance(argument, (argparse._StoreTrueAction, argparse.BooleanOptionalAction)):
+        return "boolean"
+    if argument.type is int:
+        return "integer"
+    if argument.type is float:
+        return "float"
+    if argument.dest in PATH_PARAMETER_NAMES or argument.dest.endswith(PATH_PARAMETER_SUFFIXES):
+        return "path"
+    return "string"
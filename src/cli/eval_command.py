@@ -0,0 +1,35 @@
+"""CLI command for scoring a trained model on a dataset version."""
+
+from __future__ import annotations
+
+import argparse
+from typing import Any
+
+from serve.model_evaluation import SUPPORTED_EVAL_METRICS, EvaluationOptions, evaluate_model
+from store.dataset_sdk import ForgeClient
+
+
+def add_eval_command(subparsers: Any) -> None:
+    """Register eval subcommand."""
+    parser = subparsers.add_parser(
+        "eval",
+        help="Score trained weights on a dataset version",
+    )
+    parser.add_argument("--model-path", required=True, help="Path to trained .pt weights")
+    parser.add_argument("--dataset", required=True, help="Dataset name to score on")
+    parser.add_argument("--version-id", help="Optional specific version id")
+    parser.add_argument(
+        "--metrics",
+        default=",".join(SUPPORTED_EVAL_METRICS),
+        help=f"Comma-separated metrics from: {', '.join(SUPPORTED_EVAL_METRICS)}",
+    )
+
+
+def run_eval_command(client: ForgeClient, args: argparse.Namespace) -> int:
+    """Score the model and print one metric=value row per metric."""
+    metrics = tuple(metric.strip() for metric in args.metrics.split(",") if metric.strip())
+    _, records = client.dataset(args.dataset).load_records(args.version_id)
+    scores = evaluate_model(records, EvaluationOptions(model_path=args.model_path, metrics=metrics))
+    for metric in metrics:
+        print(f"{metric}={scores[metric]}")
+    return 0
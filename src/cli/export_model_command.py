@@ -0,0 +1,47 @@
+"""CLI command for exporting trained weights to ONNX."""
+
+from __future__ import annotations
+
+import argparse
+from typing import Any
+
+from serve.model_export import (
+    SUPPORTED_EXPORT_FORMATS,
+    SUPPORTED_EXPORT_QUANTIZATIONS,
+    ModelExportOptions,
+    export_model,
+)
+
+
+def add_export_model_command(subparsers: Any) -> None:
+    """Register export-model subcommand."""
+    parser = subparsers.add_parser(
+        "export-model",
+        help="Export trained model weights to ONNX for deployment",
+    )
+    parser.add_argument("--model-path", required=True, help="Path to trained .pt weights")
+    parser.add_argument("--output-path", required=True, help="Path of the exported file")
+    parser.add_argument(
+        "--format",
+        default="onnx",
+        choices=SUPPORTED_EXPORT_FORMATS,
+        help="Export format",
+    )
+    parser.add_argument(
+        "--quantization",
+        default=None,
+        choices=SUPPORTED_EXPORT_QUANTIZATIONS,
+        help="Optional weight quantization",
+    )
+
+
+def run_export_model_command(args: argparse.Namespace) -> int:
+    """Export the model and print the exported file path."""
+    options = ModelExportOptions(
+        model_path=args.model_path,
+        output_path=args.output_path,
+        export_format=args.format,
+        quantization=args.quantization,
+    )
+    print(export_model(options))
+    return 0
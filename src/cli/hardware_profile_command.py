@@ -2,6 +2,8 @@
 
 from __future__ import annotations
 
+import argparse
+import json
 from typing import Any
 
 from serve.hardware_profile import detect_hardware_profile
@@ -9,15 +11,23 @@ from serve.hardware_profile import detect_hardware_profile
 
 def add_hardware_profile_command(subparsers: Any) -> None:
     """Register hardware-profile subcommand."""
-    subparsers.add_parser(
+    parser = subparsers.add_parser(
         "hardware-profile",
         help="Detect accelerator hardware and suggested training defaults",
     )
+    parser.add_argument(
+        "--json",
+        action="store_true",
+        help="Print the profile as one JSON object instead of key=value rows",
+    )
 
 
-def run_hardware_profile_command() -> int:
-    """Print detected hardware profile as key=value rows."""
+def run_hardware_profile_command(args: argparse.Namespace) -> int:
+    """Print detected hardware profile as key=value rows or JSON."""
     profile = detect_hardware_profile().to_dict()
+    if args.json:
+        print(json.dumps(profile, sort_keys=True))
+        return 0
     for key in sorted(profile.keys()):
         print(f"{key}={profile[key]}")
     return 0
@@ -7,10 +7,14 @@ from __future__ import annotations
 
 import argparse
 from dataclasses import replace
+from importlib import metadata
 from pathlib import Path
 from typing import Any, Sequence
 
 from cli.chat_command import add_chat_command, run_chat_command
+from cli.describe_commands import DescribeCommandsAction
+from cli.eval_command import add_eval_command, run_eval_command
+from cli.export_model_command import add_export_model_command, run_export_model_command
 from cli.hardware_profile_command import (
     add_hardware_profile_command,
     run_hardware_profile_command,
@@ -21,6 +25,8 @@ from cli.verify_command import add_verify_command, run_verify_command
 from core.config import ForgeConfig
 from core.constants import (
     DEFAULT_QUALITY_MODEL,
+    FORGE_DISTRIBUTION_NAME,
+    UNKNOWN_FORGE_VERSION,
 )
 from core.types import IngestOptions, MetadataFilter
 from store.dataset_sdk import ForgeClient
@@ -35,6 +41,12 @@ def build_parser() -> argparse.ArgumentParser:
     """
     parser = argparse.ArgumentParser(prog="forge", description="Forge phase-one CLI")
     parser.add_argument("--data-root", help="Override FORGE_DATA_ROOT for this command")
+    parser.add_argument("--version", action="version", version=f"forge {forge_version()}")
+    parser.add_argument(
+        "--describe-commands",
+        action=DescribeCommandsAction,
+        help="Print every subcommand and its parameters as JSON",
+    )
     subparsers = parser.add_subparsers(dest="command", required=True)
     _add_ingest_command(subparsers)
     _add_versions_command(subparsers)
@@ -45,9 +57,23 @@ def build_parser() -> argparse.ArgumentParser:
     add_hardware_profile_command(subparsers)
     add_train_command(subparsers)
     add_chat_command(subparsers)
+    add_export_model_command(subparsers)
+    add_eval_command(subparsers)
     return parser
 
 
+def forge_version() -> str:
+    """Return the installed forge package version.
+
+    Returns:
+        Version string, or ``unknown`` when forge runs from a source tree.
+    """
+    try:
+        return metadata.version(FORGE_DISTRIBUTION_NAME)
+    except metadata.PackageNotFoundError:
+        return UNKNOWN_FORGE_VERSION
+
+
 def main(argv: Sequence[str] | None = None) -> int:
     """Run the Forge CLI.
 
@@ -77,7 +103,11 @@ def main(argv: Sequence[str] | None = None) -> int:
     if args.command == "verify":
         return run_verify_command(client, args)
     if args.command == "hardware-profile":
-        return run_hardware_profile_command()
+        return run_hardware_profile_command(args)
+    if args.command == "export-model":
+        return run_export_model_command(args)
+    if args.command == "eval":
+        return run_eval_command(client, args)
     parser.error(f"Unsupported command: {args.command}")
     return 2
 
@@ -247,12 +247,11 @@ def add_train_command(subparsers: Any) -> None:
         help="Keep at most N epoch checkpoint files",
     )
     parser.add_argument(
-        "--no-save-best-checkpoint",
-        action="store_false",
-        dest="save_best_checkpoint",
-        help="Disable writing best.pt checkpoint",
+        "--save-best-checkpoint",
+        action=argparse.BooleanOptionalAction,
+        default=True,
+        help="Write best.pt checkpoint",
     )
-    parser.set_defaults(save_best_checkpoint=True)
     parser.add_argument(
         "--resume-checkpoint-path",
         help="Resume training state from a previously saved checkpoint file",
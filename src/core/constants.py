@@ -79,3 +79,8 @@ DEFAULT_TOKENIZER_VOCAB_FILE_NAME = "tokenizer_vocab.json"
 DEFAULT_CHAT_MAX_NEW_TOKENS = 80
 DEFAULT_CHAT_TEMPERATURE = 0.8
 DEFAULT_CHAT_TOP_K = 40
+FILE_LOCK_SUFFIX = ".lock"
+FILE_LOCK_WAIT_SECONDS = 30.0
+FILE_LOCK_RETRY_INTERVAL_SECONDS = 0.05
+FORGE_DISTRIBUTION_NAME = "forge"
+UNKNOWN_FORGE_VERSION = "unknown"
@@ -41,3 +41,7 @@ class ForgeRunSpecError(ForgeError):
 
 class ForgeVerificationError(ForgeError):
     """Raised when automated verification checks fail."""
+
+
+class ForgeLockError(ForgeError):
+    """Raised when another process holds a shared data-root file lock too long."""
@@ -0,0 +1,83 @@
+"""Advisory cross-process locks for shared data-root files.
+
+Catalogs, the run index, and the lineage graph are rewritten in full on
+every append, so Forge Studio and a concurrent forge command could each
+drop the other's entry. Writers hold an exclusive lock on a sidecar
+``<file>.lock`` from read to write; Studio locks the same sidecar.
+"""
+
+from __future__ import annotations
+
+import os
+import time
+from collections.abc import Iterator
+from contextlib import contextmanager
+from pathlib import Path
+from typing import IO
+
+from core.constants import (
+    FILE_LOCK_RETRY_INTERVAL_SECONDS,
+    FILE_LOCK_SUFFIX,
+    FILE_LOCK_WAIT_SECONDS,
+)
+from core.errors import ForgeLockError
+
+if os.name == "nt":
+    import msvcrt
+else:
+    import fcntl
+
+
+@contextmanager
+def locked_file(
+    path: Path,
+    wait_seconds: float = FILE_LOCK_WAIT_SECONDS,
+) -> Iterator[None]:
+    """Hold the exclusive lock for ``path`` while the block runs.
+
+    Args:
+        path: Data file being read and rewritten.
+        wait_seconds: How long to retry while another process holds it.
+
+    Raises:
+        ForgeLockError: If the lock is still held after ``wait_seconds``.
+    """
+    lock_path = path.with_name(path.name + FILE_LOCK_SUFFIX)
+    lock_path.parent.mkdir(parents=True, exist_ok=True)
+    with lock_path.open("a+b") as lock_handle:
+        _acquire(lock_handle, path, wait_seconds)
+        try:
+            yield
+        finally:
+            _release(lock_handle)
+
+
+def _acquire(lock_handle: IO[bytes], path: Path, wait_seconds: float) -> None:
+    deadline = time.monotonic() + wait_seconds
+    while not _try_lock(lock_handle):
+        if time.monotonic() >= deadline:
+            raise ForgeLockError(
+                f"{path} is in use by another process, likely Forge Studio or another "
+                "forge command. Retry once it finishes."
+            )
+        time.sleep(FILE_LOCK_RETRY_INTERVAL_SECONDS)
+
+
+def _try_lock(lock_handle: IO[bytes]) -> bool:
+    try:
+        if os.name == "nt":
+            lock_handle.seek(0)
+            msvcrt.locking(lock_handle.fileno(), msvcrt.LK_NBLCK, 1)
+        else:
+            fcntl.flock(lock_handle.fileno(), fcntl.LOCK_EX | fcntl.LOCK_NB)
+    except (BlockingIOError, PermissionError):
+        return False
+    return True
+
+
+def _release(lock_handle: IO[bytes]) -> None:
+    if os.name == "nt":
+        lock_handle.seek(0)
+        msvcrt.locking(lock_handle.fileno(), msvcrt.LK_UNLCK, 1)
+    else:
+        fcntl.flock(lock_handle.fileno(), fcntl.LOCK_UN)
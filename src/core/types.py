@@ -89,6 +89,7 @@ class SnapshotManifest:
         parent_version: Previous version id when derived.
         recipe_steps: Ordered transforms used to create snapshot.
         record_count: Number of records in snapshot.
+        content_checksum: SHA-256 of the records file, for integrity checks.
     """
 
     dataset_name: str
@@ -97,6 +98,7 @@ class SnapshotManifest:
     parent_version: str | None
     recipe_steps: tuple[str, ...]
     record_count: int
+    content_checksum: str | None = None
 
 
 @dataclass(frozen=True)
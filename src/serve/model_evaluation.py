@@ -0,0 +1,125 @@
+"""Held-out scoring of trained models on a dataset version.
+
+This module runs a trained model over every record of a dataset version
+with next-token targets, the same way training scores its validation
+split, and reports loss, perplexity, and next-token accuracy.
+"""
+
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass
+from typing import Any
+
+from core.errors import ForgeDependencyError, ForgeServeError
+from core.types import DataRecord
+from serve.device_selection import resolve_execution_device
+from serve.tokenization import build_sequence_batches, build_training_sequences
+from serve.trained_model_loader import load_trained_model
+
+SUPPORTED_EVAL_METRICS = ("perplexity", "loss", "accuracy")
+EVAL_BATCH_SIZE = 16
+PAD_TOKEN_ID = 0
+
+
+@dataclass(frozen=True)
+class EvaluationOptions:
+    """Model evaluation command options."""
+
+    model_path: str
+    metrics: tuple[str, ...] = SUPPORTED_EVAL_METRICS
+
+
+def evaluate_model(records: list[DataRecord], options: EvaluationOptions) -> dict[str, float]:
+    """Score a trained model on dataset records.
+
+    Args:
+        records: Records of the dataset version to score on.
+        options: Evaluation options.
+
+    Returns:
+        Requested metric values keyed by metric name.
+
+    Raises:
+        ForgeServeError: If metrics are unknown or no record is scorable.
+        ForgeDependencyError: If torch is not installed.
+    """
+    unknown = [metric for metric in options.metrics if metric not in SUPPORTED_EVAL_METRICS]
+    if unknown or not options.metrics:
+        raise ForgeServeError(
+            f"Unsupported eval metrics {unknown or '[]'}. "
+            f"Use one or more of: {', '.join(SUPPORTED_EVAL_METRICS)}."
+        )
+    torch_module = _import_torch()
+    device = resolve_execution_device(torch_module)
+    trained = load_trained_model(torch_module, options.model_path, device, records)
+    sequences = build_training_sequences(
+        records,
+        trained.tokenizer,  # type: ignore[arg-type]
+        trained.training_options.max_token_length,
+    )
+    if not sequences:
+        raise ForgeServeError(
+            "Dataset has no record with at least two tokens to score. "
+            "Evaluate on a dataset version with text records."
+        )
+    total_loss, total_correct, total_tokens = _score_sequences(
+        torch_module, trained.model, sequences, device
+    )
+    loss = total_loss / total_tokens
+    scores = {
+        "loss": loss,
+        "perplexity": math.exp(min(loss, 50.0)),
+        "accuracy": total_correct / total_tokens,
+    }
+    return {metric: round(scores[metric], 6) for metric in options.metrics}
+
+
+def _score_sequences(
+    torch_module: Any,
+    model: Any,
+    sequences: list[list[int]],
+    device: Any,
+) -> tuple[float, int, int]:
+    """Return summed token loss, correct predictions, and scored token count."""
+    loss_function = torch_module.nn.CrossEntropyLoss(ignore_index=PAD_TOKEN_ID, reduction="sum")
+    total_loss, total_correct, total_tokens = 0.0, 0, 0
+    with torch_module.no_grad():
+        for batch in build_sequence_batches(sequences, EVAL_BATCH_SIZE):
+            max_length = max(len(sequence) for sequence in batch.inputs)
+            inputs = torch_module.tensor(
+                [_pad(sequence, max_length) for sequence in batch.inputs], dtype=torch_module.long
+            ).to(device)
+            targets = torch_module.tensor(
+                [_pad(sequence, max_length) for sequence in batch.targets], dtype=torch_module.long
+            ).to(device)
+            logits = model(inputs)
+            flat_logits = logits.reshape(-1, logits.shape[-1])
+            flat_targets = targets.reshape(-1)
+            mask = flat_targets != PAD_TOKEN_ID
+            total_loss += float(loss_function(flat_logits, flat_targets).item())
+            predictions = flat_logits.argmax(dim=-1)
+            total_correct += int(((predictions == flat_targets) & mask).sum().item())
+            total_tokens += int(mask.sum().item())
+    if total_tokens == 0:
+        raise ForgeServeError(
+            "Dataset records only contain padding tokens. Evaluate on a dataset with text."
+        )
+    return total_loss, total_correct, total_tokens
+
+
+def _pad(sequence: list[int], max_length: int) -> list[int]:
+    """Pad sequence with the pad id up to max length."""
+    return sequence + [PAD_TOKEN_ID] * (max_length - len(sequence))
+
+
+def _import_torch() -> Any:
+    """Import torch dependency."""
+    try:
+        import torch
+    except ImportError as error:
+        raise ForgeDependencyError(
+            "Model evaluation requires torch, but it is not installed. "
+            "Install torch to run forge eval."
+        ) from error
+    return torch
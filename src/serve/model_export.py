@@ -0,0 +1,159 @@
+"""ONNX export for Forge-trained models.
+
+This module rebuilds a trained model from its weights and persisted
+training config, traces it to an ONNX graph, and optionally quantizes the
+result. The tokenizer and training config are copied beside the exported
+file so `forge chat` can load it the same way it loads the `.pt` weights.
+"""
+
+from __future__ import annotations
+
+import shutil
+import tempfile
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from core.constants import (
+    DEFAULT_TOKENIZER_VOCAB_FILE_NAME,
+    DEFAULT_TRAINING_CONFIG_FILE_NAME,
+)
+from core.errors import ForgeDependencyError, ForgeServeError
+from serve.model_format import detect_model_format
+from serve.trained_model_loader import load_trained_model
+
+SUPPORTED_EXPORT_FORMATS = ("onnx",)
+SUPPORTED_EXPORT_QUANTIZATIONS = ("fp16", "int8")
+ONNX_OPSET_VERSION = 17
+TRACE_SEQUENCE_LENGTH = 8
+
+
+@dataclass(frozen=True)
+class ModelExportOptions:
+    """Model export command options."""
+
+    model_path: str
+    output_path: str
+    export_format: str = "onnx"
+    quantization: str | None = None
+
+
+def export_model(options: ModelExportOptions) -> Path:
+    """Export trained weights to an ONNX file.
+
+    Args:
+        options: Export options.
+
+    Returns:
+        Path of the exported model file.
+
+    Raises:
+        ForgeServeError: If the options or weights are invalid.
+        ForgeDependencyError: If torch, onnx, or onnxruntime is missing.
+    """
+    _validate_export_options(options)
+    torch_module = _import_torch()
+    model = load_trained_model(torch_module, options.model_path, torch_module.device("cpu")).model
+    output_path = Path(options.output_path).expanduser().resolve()
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    if options.quantization == "fp16":
+        model = model.half()
+    if options.quantization == "int8":
+        with tempfile.TemporaryDirectory() as temp_dir:
+            float_path = Path(temp_dir) / "model-fp32.onnx"
+            _trace_to_onnx(torch_module, model, float_path)
+            _quantize_int8(float_path, output_path)
+    else:
+        _trace_to_onnx(torch_module, model, output_path)
+    _copy_model_metadata(Path(options.model_path), output_path.parent)
+    return output_path
+
+
+def _validate_export_options(options: ModelExportOptions) -> None:
+    """Validate export format, quantization, and source weights."""
+    if options.export_format not in SUPPORTED_EXPORT_FORMATS:
+        raise ForgeServeError(
+            f"Unsupported export format '{options.export_format}'. "
+            f"Use one of: {', '.join(SUPPORTED_EXPORT_FORMATS)}."
+        )
+    if options.quantization not in (None, *SUPPORTED_EXPORT_QUANTIZATIONS):
+        raise ForgeServeError(
+            f"Unsupported quantization '{options.quantization}'. "
+            f"Use one of: {', '.join(SUPPORTED_EXPORT_QUANTIZATIONS)}."
+        )
+    if detect_model_format(options.model_path) != "pytorch":
+        raise ForgeServeError(
+            f"Model path {options.model_path} is not a .pt weights file. "
+            "Export from the weights a training run produced."
+        )
+
+
+def _trace_to_onnx(torch_module: Any, model: Any, output_path: Path) -> None:
+    """Trace the model with dynamic batch and sequence axes."""
+    _import_onnx()
+    sample_input = torch_module.zeros((1, TRACE_SEQUENCE_LENGTH), dtype=torch_module.long)
+    dynamic_axes = {
+        "input_ids": {0: "batch", 1: "sequence"},
+        "logits": {0: "batch", 1: "sequence"},
+    }
+    try:
+        torch_module.onnx.export(
+            model,
+            (sample_input,),
+            str(output_path),
+            input_names=["input_ids"],
+            output_names=["logits"],
+            dynamic_axes=dynamic_axes,
+            opset_version=ONNX_OPSET_VERSION,
+        )
+    except Exception as error:
+        raise ForgeServeError(
+            f"Failed to export model to {output_path}: {error}. "
+            "Check that the architecture only uses ONNX-exportable operations."
+        ) from error
+
+
+def _quantize_int8(float_path: Path, output_path: Path) -> None:
+    """Quantize linear-layer weights of an ONNX graph to int8."""
+    try:
+        from onnxruntime.quantization import QuantType, quantize_dynamic
+    except ImportError as error:
+        raise ForgeDependencyError(
+            "int8 export requires onnxruntime, but it is not installed. "
+            "Install forge with the onnx extra."
+        ) from error
+    quantize_dynamic(str(float_path), str(output_path), weight_type=QuantType.QInt8)
+
+
+def _copy_model_metadata(model_path: Path, export_dir: Path) -> None:
+    """Copy tokenizer and training config beside the exported model."""
+    source_dir = model_path.expanduser().resolve().parent
+    if source_dir == export_dir:
+        return
+    for file_name in (DEFAULT_TRAINING_CONFIG_FILE_NAME, DEFAULT_TOKENIZER_VOCAB_FILE_NAME):
+        source = source_dir / file_name
+        if source.is_file():
+            shutil.copy2(source, export_dir / file_name)
+
+
+def _import_torch() -> Any:
+    """Import torch dependency."""
+    try:
+        import torch
+    except ImportError as error:
+        raise ForgeDependencyError(
+            "Model export requires torch, but it is not installed. "
+            "Install torch to run forge export-model."
+        ) from error
+    return torch
+
+
+def _import_onnx() -> None:
+    """Check that the onnx package torch's exporter needs is installed."""
+    try:
+        import onnx  # noqa: F401
+    except ImportError as error:
+        raise ForgeDependencyError(
+            "ONNX export requires onnx, but it is not installed. "
+            "Install forge with the onnx extra."
+        ) from error
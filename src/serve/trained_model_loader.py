@@ -0,0 +1,70 @@
+"""Rebuild a trained model outside of chat for export and evaluation.
+
+This module reuses chat's option resolution so a model loads with the
+same architecture, tokenizer, and vocabulary size it was trained with.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any
+
+from core.chat_types import ChatOptions, ChatTokenizer
+from core.types import DataRecord, TrainingOptions
+from serve.architecture_loader import load_training_model
+from serve.chat_option_resolver import (
+    resolve_chat_model_vocab_size,
+    resolve_chat_tokenizer,
+    resolve_chat_training_options,
+)
+from serve.model_weights import load_initial_weights, read_model_state_dict
+
+
+@dataclass
+class TrainedModel:
+    """A trained model in eval mode with the settings it was built from."""
+
+    model: Any
+    tokenizer: ChatTokenizer
+    training_options: TrainingOptions
+
+
+def load_trained_model(
+    torch_module: Any,
+    model_path: str,
+    device: Any,
+    records: list[DataRecord] | None = None,
+) -> TrainedModel:
+    """Load trained weights into their architecture in eval mode.
+
+    Args:
+        torch_module: Imported torch module.
+        model_path: Path to trained .pt weights.
+        device: Device to load the model on.
+        records: Dataset records for tokenizer fallback, or None when a
+            persisted tokenizer sits beside the weights.
+
+    Returns:
+        The rebuilt model with its tokenizer and training options.
+
+    Raises:
+        ForgeServeError: If the weights or tokenizer cannot be resolved.
+    """
+    model_state = read_model_state_dict(torch_module, model_path, device)
+    options = ChatOptions(model_path=model_path, prompt="")
+    training_options = resolve_chat_training_options(options, model_state)
+    tokenizer = resolve_chat_tokenizer(records, options, training_options)
+    model = load_training_model(
+        torch_module,
+        training_options,
+        resolve_chat_model_vocab_size(tokenizer.vocabulary, model_state, training_options),
+    )
+    model = model.to(device)
+    load_initial_weights(
+        torch_module=torch_module,
+        model=model,
+        initial_weights_path=model_path,
+        device=device,
+    )
+    model.eval()
+    return TrainedModel(model=model, tokenizer=tokenizer, training_options=training_options)
@@ -6,19 +6,30 @@ systems can consume artifacts without guessing file names or paths.
 
 from __future__ import annotations
 
+import hashlib
 import json
-from dataclasses import asdict, dataclass
+from dataclasses import asdict, dataclass, replace
 from datetime import datetime, timezone
 from pathlib import Path
 
 from core.constants import (
     DEFAULT_TOKENIZER_VOCAB_FILE_NAME,
+    HASH_ALGORITHM,
     DEFAULT_TRAINING_CONFIG_FILE_NAME,
     TRAINING_ARTIFACT_CONTRACT_FILE_NAME,
 )
 from core.errors import ForgeServeError
 from core.types import TrainingRunResult
 
+HASHED_ARTIFACT_FIELDS = (
+    "model_path",
+    "training_config_path",
+    "tokenizer_path",
+    "best_checkpoint_path",
+    "reproducibility_bundle_path",
+)
+HASH_CHUNK_BYTES = 1024 * 1024
+
 
 @dataclass(frozen=True)
 class TrainingArtifactContract:
@@ -40,6 +51,7 @@ class TrainingArtifactContract:
     reproducibility_bundle_path: str | None
     logs_path: str | None
     benchmark_results_path: str | None
+    artifact_hashes: dict[str, str]
 
 
 def save_training_artifact_contract(
@@ -75,7 +87,9 @@ def save_training_artifact_contract(
         reproducibility_bundle_path=reproducibility_bundle_path,
         logs_path=None,
         benchmark_results_path=None,
+        artifact_hashes={},
     )
+    payload = replace(payload, artifact_hashes=_hash_artifacts(payload))
     _write_payload(manifest_path, asdict(payload))
     return manifest_path
 
@@ -94,6 +108,30 @@ def load_training_artifact_contract(model_path: str) -> dict[str, object] | None
     return payload
 
 
+def _hash_artifacts(contract: TrainingArtifactContract) -> dict[str, str]:
+    """Hash each existing artifact file so consumers can detect later edits."""
+    hashes: dict[str, str] = {}
+    for field_name in HASHED_ARTIFACT_FIELDS:
+        artifact_path = getattr(contract, field_name)
+        if isinstance(artifact_path, str) and Path(artifact_path).is_file():
+            hashes[field_name] = _hash_file(Path(artifact_path))
+    return hashes
+
+
+def _hash_file(file_path: Path) -> str:
+    hash_builder = hashlib.new(HASH_ALGORITHM)
+    try:
+        with file_path.open("rb") as handle:
+            for chunk in iter(lambda: handle.read(HASH_CHUNK_BYTES), b""):
+                hash_builder.update(chunk)
+    except OSError as error:
+        raise ForgeServeError(
+            f"Failed to hash training artifact {file_path}: {error}. "
+            "Check file permissions and retry."
+        ) from error
+    return hash_builder.hexdigest()
+
+
 def _write_payload(payload_path: Path, payload: dict[str, object]) -> None:
     try:
         payload_path.write_text(json.dumps(payload, indent=2) + "\n", encoding="utf-8")
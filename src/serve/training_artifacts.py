@@ -41,6 +41,10 @@ def save_training_history(
 ) -> Path:
     """Persist training history JSON file.
 
+    Called after every epoch as well as at the end of training, so Studio can
+    tail the file while a run is in progress. The file is replaced atomically
+    so a reader never sees a partial write.
+
     Args:
         output_dir: Training output directory.
         metrics: Epoch metrics.
@@ -54,7 +58,9 @@ def save_training_history(
         "epochs": [asdict(metric) for metric in metrics],
         "batch_losses": [asdict(metric) for metric in batch_losses],
     }
-    history_path.write_text(json.dumps(payload, indent=2) + "\n", encoding="utf-8")
+    temp_path = history_path.with_name(f".{history_path.name}.tmp")
+    temp_path.write_text(json.dumps(payload, indent=2) + "\n", encoding="utf-8")
+    temp_path.replace(history_path)
     return history_path
 
 
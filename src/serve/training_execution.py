@@ -20,6 +20,7 @@ from serve.training_checkpoint import (
     save_best_checkpoint,
     save_epoch_checkpoint,
 )
+from serve.training_artifacts import save_training_history
 from serve.training_context import TrainingRuntimeContext
 from serve.training_epoch_pass import run_epoch_pass
 from serve.training_hooks import invoke_hook
@@ -148,6 +149,7 @@ def _run_default_training_loop(
             validation_loss=validation_loss,
             learning_rate=read_optimizer_learning_rate(context.optimizer),
         )
+        save_training_history(context.output_dir, epoch_rows, batch_rows)
         invoke_hook(
             "on_epoch_end",
             context.hooks.on_epoch_end,
@@ -6,7 +6,7 @@ configured data-root so training runs remain inspectable and reproducible.
 
 from __future__ import annotations
 
-from dataclasses import asdict
+from dataclasses import asdict, replace
 from datetime import datetime, timezone
 from pathlib import Path
 from uuid import uuid4
@@ -19,6 +19,7 @@ from core.constants import (
     RUNS_DIR_NAME,
 )
 from core.errors import ForgeServeError
+from core.file_lock import locked_file
 from serve.training_run_io import read_json_file, write_json_file
 from serve.training_run_types import (
     TrainingRunEvent,
@@ -46,8 +47,13 @@ class TrainingRunRegistry:
         output_dir: str,
         parent_model_path: str | None,
         config_hash: str,
+        resumed_from_checkpoint: str | None = None,
     ) -> TrainingRunRecord:
-        """Create a new queued run record and register lineage inputs."""
+        """Create a new queued run record and register lineage inputs.
+
+        A resumed run records its checkpoint and the run whose output dir
+        holds that checkpoint, when one is registered.
+        """
         run_id = _build_run_id()
         timestamp = _utc_now_iso()
         record = TrainingRunRecord(
@@ -61,6 +67,8 @@ class TrainingRunRegistry:
             created_at=timestamp,
             updated_at=timestamp,
             events=(TrainingRunEvent(state="queued", timestamp=timestamp, message=None),),
+            resumed_from_checkpoint=resumed_from_checkpoint,
+            resumed_from_run_id=self._find_checkpoint_owner(resumed_from_checkpoint),
         )
         self._write_run_record(record)
         self._append_index_row(run_id)
@@ -82,15 +90,9 @@ class TrainingRunRegistry:
         next_events = record.events + (
             TrainingRunEvent(state=next_state, timestamp=timestamp, message=message),
         )
-        next_record = TrainingRunRecord(
-            run_id=record.run_id,
-            dataset_name=record.dataset_name,
-            dataset_version_id=record.dataset_version_id,
-            output_dir=record.output_dir,
-            parent_model_path=record.parent_model_path,
-            config_hash=record.config_hash,
+        next_record = replace(
+            record,
             state=next_state,
-            created_at=record.created_at,
             updated_at=timestamp,
             events=next_events,
             artifact_contract_path=artifact_contract_path or record.artifact_contract_path,
@@ -124,6 +126,16 @@ class TrainingRunRegistry:
         runs_payload, edges_payload = self._read_lineage_graph()
         return {"runs": runs_payload, "edges": edges_payload}
 
+    def _find_checkpoint_owner(self, checkpoint_path: str | None) -> str | None:
+        """Return the newest run whose output dir contains the checkpoint."""
+        if checkpoint_path is None:
+            return None
+        resolved_checkpoint = Path(checkpoint_path).expanduser().resolve()
+        for run_id in reversed(self.list_runs()):
+            if resolved_checkpoint.is_relative_to(Path(self._load_run_record(run_id).output_dir)):
+                return run_id
+        return None
+
     def _write_run_record(self, record: TrainingRunRecord) -> None:
         run_dir = self._runs_root / record.run_id
         run_dir.mkdir(parents=True, exist_ok=True)
@@ -140,39 +152,43 @@ class TrainingRunRegistry:
 
     def _append_index_row(self, run_id: str) -> None:
         index_path = self._runs_root / RUN_INDEX_FILE_NAME
-        payload = read_json_file(index_path, default_value={"runs": []})
-        if not isinstance(payload, dict) or not isinstance(payload.get("runs"), list):
-            raise ForgeServeError(f"Invalid run index format at {index_path}: expected runs list.")
-        run_ids = payload["runs"]
-        if run_id not in run_ids:
-            run_ids.append(run_id)
-            write_json_file(index_path, payload)
+        with locked_file(index_path):
+            payload = read_json_file(index_path, default_value={"runs": []})
+            if not isinstance(payload, dict) or not isinstance(payload.get("runs"), list):
+                raise ForgeServeError(
+                    f"Invalid run index format at {index_path}: expected runs list."
+                )
+            run_ids = payload["runs"]
+            if run_id not in run_ids:
+                run_ids.append(run_id)
+                write_json_file(index_path, payload)
 
     def _append_lineage_inputs(self, record: TrainingRunRecord) -> None:
-        runs_payload, edges_payload = self._read_lineage_graph()
-        runs_payload[record.run_id] = {
-            "dataset_name": record.dataset_name,
-            "dataset_version_id": record.dataset_version_id,
-            "output_dir": record.output_dir,
-            "parent_model_path": record.parent_model_path,
-            "config_hash": record.config_hash,
-            "created_at": record.created_at,
-            "artifact_contract_path": record.artifact_contract_path,
-        }
-        _append_unique_edge(
-            edges_payload,
-            from_node=f"dataset:{record.dataset_name}:{record.dataset_version_id}",
-            to_node=f"run:{record.run_id}",
-            edge_type="trained_on",
-        )
-        if record.parent_model_path:
+        with locked_file(self._lineage_root / LINEAGE_GRAPH_FILE_NAME):
+            runs_payload, edges_payload = self._read_lineage_graph()
+            runs_payload[record.run_id] = {
+                "dataset_name": record.dataset_name,
+                "dataset_version_id": record.dataset_version_id,
+                "output_dir": record.output_dir,
+                "parent_model_path": record.parent_model_path,
+                "config_hash": record.config_hash,
+                "created_at": record.created_at,
+                "artifact_contract_path": record.artifact_contract_path,
+            }
             _append_unique_edge(
                 edges_payload,
-                from_node=f"model:{record.parent_model_path}",
+                from_node=f"dataset:{record.dataset_name}:{record.dataset_version_id}",
                 to_node=f"run:{record.run_id}",
-                edge_type="initialized_from",
+                edge_type="trained_on",
             )
-        self._write_lineage_graph(runs_payload, edges_payload)
+            if record.parent_model_path:
+                _append_unique_edge(
+                    edges_payload,
+                    from_node=f"model:{record.parent_model_path}",
+                    to_node=f"run:{record.run_id}",
+                    edge_type="initialized_from",
+                )
+            self._write_lineage_graph(runs_payload, edges_payload)
 
     def _update_lineage_outputs(
         self,
@@ -180,21 +196,22 @@ class TrainingRunRegistry:
         artifact_contract_path: str | None,
         model_path: str | None,
     ) -> None:
-        runs_payload, edges_payload = self._read_lineage_graph()
-        run_payload = runs_payload.get(run_id)
-        if isinstance(run_payload, dict):
-            if artifact_contract_path:
-                run_payload["artifact_contract_path"] = artifact_contract_path
+        with locked_file(self._lineage_root / LINEAGE_GRAPH_FILE_NAME):
+            runs_payload, edges_payload = self._read_lineage_graph()
+            run_payload = runs_payload.get(run_id)
+            if isinstance(run_payload, dict):
+                if artifact_contract_path:
+                    run_payload["artifact_contract_path"] = artifact_contract_path
+                if model_path:
+                    run_payload["model_path"] = model_path
             if model_path:
-                run_payload["model_path"] = model_path
-        if model_path:
-            _append_unique_edge(
-                edges_payload,
-                from_node=f"run:{run_id}",
-                to_node=f"model:{model_path}",
-                edge_type="produced",
-            )
-        self._write_lineage_graph(runs_payload, edges_payload)
+                _append_unique_edge(
+                    edges_payload,
+                    from_node=f"run:{run_id}",
+                    to_node=f"model:{model_path}",
+                    edge_type="produced",
+                )
+            self._write_lineage_graph(runs_payload, edges_payload)
 
     def _read_lineage_graph(self) -> tuple[dict[str, dict[str, object]], list[object]]:
         graph_path = self._lineage_root / LINEAGE_GRAPH_FILE_NAME
@@ -55,6 +55,8 @@ class TrainingRunRecord:
     events: tuple[TrainingRunEvent, ...]
     artifact_contract_path: str | None = None
     error_message: str | None = None
+    resumed_from_checkpoint: str | None = None
+    resumed_from_run_id: str | None = None
 
 
 def validate_transition(current: TrainingRunState, next_state: TrainingRunState) -> None:
@@ -88,6 +90,8 @@ def run_record_from_payload(payload: dict[str, object], payload_path: Path) -> T
             events=events,
             artifact_contract_path=optional_string(payload.get("artifact_contract_path")),
             error_message=optional_string(payload.get("error_message")),
+            resumed_from_checkpoint=optional_string(payload.get("resumed_from_checkpoint")),
+            resumed_from_run_id=optional_string(payload.get("resumed_from_run_id")),
         )
     except KeyError as error:
         raise ForgeServeError(
@@ -61,6 +61,7 @@ def run_training(
         output_dir=str(Path(options.output_dir).expanduser().resolve()),
         parent_model_path=options.initial_weights_path,
         config_hash=config_hash,
+        resumed_from_checkpoint=options.resume_checkpoint_path,
     )
     context: TrainingRuntimeContext | None = None
     try:
@@ -15,6 +15,7 @@ from typing import Any, cast
 
 from core.constants import MANIFEST_FILE_NAME
 from core.errors import ForgeStoreError
+from core.file_lock import locked_file
 from core.types import DataRecord, SnapshotManifest
 
 
@@ -60,16 +61,17 @@ def update_catalog(catalog_path: Path, manifest: SnapshotManifest) -> None:
         catalog_path: Catalog JSON path.
         manifest: Manifest to append.
     """
-    if catalog_path.exists():
-        catalog = read_catalog_file(catalog_path)
-    else:
-        catalog = {"latest_version": None, "versions": []}
     manifest_dict = asdict(manifest)
     manifest_dict["created_at"] = manifest.created_at.isoformat()
-    versions = cast(list[dict[str, Any]], catalog["versions"])
-    versions.append(manifest_dict)
-    catalog["latest_version"] = manifest.version_id
-    catalog_path.write_text(json.dumps(catalog, indent=2) + "\n", encoding="utf-8")
+    with locked_file(catalog_path):
+        if catalog_path.exists():
+            catalog = read_catalog_file(catalog_path)
+        else:
+            catalog = {"latest_version": None, "versions": []}
+        versions = cast(list[dict[str, Any]], catalog["versions"])
+        versions.append(manifest_dict)
+        catalog["latest_version"] = manifest.version_id
+        catalog_path.write_text(json.dumps(catalog, indent=2) + "\n", encoding="utf-8")
 
 
 def read_catalog_file(catalog_path: Path) -> dict[str, Any]:
@@ -120,4 +122,5 @@ def manifest_from_dict(payload: dict[str, Any]) -> SnapshotManifest:
         parent_version=str(payload["parent_version"]) if payload["parent_version"] else None,
         recipe_steps=tuple(str(step) for step in payload["recipe_steps"]),
         record_count=int(payload["record_count"]),
+        content_checksum=payload.get("content_checksum"),
     )
@@ -6,6 +6,7 @@ It also maintains JSONL mirror files for lightweight compatibility.
 
 from __future__ import annotations
 
+import hashlib
 import json
 from pathlib import Path
 
@@ -32,6 +33,18 @@ def write_version_payload(version_dir: Path, records: list[DataRecord]) -> bool:
     return _try_write_lance_dataset(version_dir, records)
 
 
+def records_checksum(version_dir: Path) -> str:
+    """Return the SHA-256 hex digest of a snapshot's JSONL records file.
+
+    Args:
+        version_dir: Snapshot version directory.
+
+    Returns:
+        Digest that ``verify_version_integrity`` compares against.
+    """
+    return hashlib.sha256((version_dir / RECORDS_FILE_NAME).read_bytes()).hexdigest()
+
+
 def read_version_payload(version_dir: Path) -> list[DataRecord]:
     """Load snapshot records from JSONL mirror file.
 
@@ -34,7 +34,7 @@ from store.catalog_io import (
     update_catalog,
     write_manifest_file,
 )
-from store.lance_dataset import read_version_payload, write_version_payload
+from store.lance_dataset import read_version_payload, records_checksum, write_version_payload
 from store.metadata_filtering import filter_records
 from store.s3_export import create_s3_client, upload_directory
 from store.training_export import export_training_shards
@@ -93,6 +93,7 @@ class SnapshotStore:
             parent_version=request.parent_version,
             recipe_steps=request.recipe_steps,
             record_count=len(request.records),
+            content_checksum=records_checksum(version_dir),
         )
         write_manifest_file(version_dir, manifest, lance_written)
         update_catalog(dataset_root / CATALOG_FILE_NAME, manifest)
@@ -0,0 +1,187 @@
+//! Vendor tool probes that read one accelerator telemetry sample.
+//!
+//! Each probe shells out to the tool the vendor ships with its driver
+//! (`nvidia-smi`, `rocm-smi`, `ioreg` on macOS) and returns `None` when the
+//! tool is missing or fails, so the first backend that answers wins.
+
+use crate::models::AcceleratorSample;
+use serde_json::Value;
+use std::process::Command;
+
+const NVIDIA_QUERY_FIELDS: &str =
+    "index,name,utilization.gpu,memory.used,memory.total,temperature.gpu,power.draw";
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+/// Samples the first available backend; an empty list means no readable accelerator.
+pub(crate) fn sample_accelerators() -> Vec<AcceleratorSample> {
+    probe_nvidia()
+        .or_else(probe_rocm)
+        .or_else(probe_metal)
+        .unwrap_or_default()
+}
+
+fn run_tool(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn probe_nvidia() -> Option<Vec<AcceleratorSample>> {
+    let query = format!("--query-gpu={NVIDIA_QUERY_FIELDS}");
+    let stdout = run_tool("nvidia-smi", &[&query, "--format=csv,noheader,nounits"])?;
+    Some(parse_nvidia_csv(&stdout))
+}
+
+/// Parses `nvidia-smi` CSV rows; `[N/A]` cells become `None`.
+fn parse_nvidia_csv(stdout: &str) -> Vec<AcceleratorSample> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split(',').map(str::trim).collect();
+            if cells.len() < 7 {
+                return None;
+            }
+            let number = |index: usize| cells[index].parse::<f64>().ok();
+            Some(AcceleratorSample {
+                device_index: cells[0].parse().ok()?,
+                name: cells[1].to_string(),
+                backend: "cuda".to_string(),
+                utilization_percent: number(2),
+                memory_used_mb: number(3),
+                memory_total_mb: number(4),
+                temperature_celsius: number(5),
+                power_watts: number(6),
+            })
+        })
+        .collect()
+}
+
+fn probe_rocm() -> Option<Vec<AcceleratorSample>> {
+    let args = [
+        "--showuse",
+        "--showmeminfo",
+        "vram",
+        "--showtemp",
+        "--showpower",
+        "--json",
+    ];
+    let stdout = run_tool("rocm-smi", &args)?;
+    parse_rocm_json(&stdout)
+}
+
+/// `rocm-smi --json` keys vary by version, so fields are matched by stable prefixes.
+fn parse_rocm_json(stdout: &str) -> Option<Vec<AcceleratorSample>> {
+    let payload: Value = serde_json::from_str(stdout).ok()?;
+    let cards = payload.as_object()?;
+    let mut samples = Vec::new();
+    for (card_name, fields) in cards {
+        let Some(device_index) = card_name
+            .strip_prefix("card")
+            .and_then(|id| id.parse().ok())
+        else {
+            continue;
+        };
+        let Some(fields) = fields.as_object() else {
+            continue;
+        };
+        let number = |matches: &dyn Fn(&str) -> bool| {
+            fields
+                .iter()
+                .find(|(key, _)| matches(key))
+                .and_then(|(_, value)| value.as_str()?.trim().parse::<f64>().ok())
+        };
+        samples.push(AcceleratorSample {
+            device_index,
+            name: card_name.clone(),
+            backend: "rocm".to_string(),
+            utilization_percent: number(&|key| key.starts_with("GPU use")),
+            memory_used_mb: number(&|key| key.starts_with("VRAM Total Used Memory"))
+                .map(|bytes| bytes / BYTES_PER_MB),
+            memory_total_mb: number(&|key| key.starts_with("VRAM Total Memory"))
+                .map(|bytes| bytes / BYTES_PER_MB),
+            temperature_celsius: number(&|key| key.starts_with("Temperature")),
+            power_watts: number(&|key| key.contains("Power (W)")),
+        });
+    }
+    Some(samples)
+}
+
+fn probe_metal() -> Option<Vec<AcceleratorSample>> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+    let stdout = run_tool(
+        "ioreg",
+        &["-r", "-d", "1", "-w", "0", "-c", "IOAccelerator"],
+    )?;
+    Some(parse_ioreg_accelerators(&stdout))
+}
+
+/// Reads `PerformanceStatistics`; macOS exposes no temperature or power without root.
+fn parse_ioreg_accelerators(stdout: &str) -> Vec<AcceleratorSample> {
+    stdout
+        .lines()
+        .filter(|line| line.contains("\"PerformanceStatistics\""))
+        .enumerate()
+        .map(|(index, line)| AcceleratorSample {
+            device_index: index as u32,
+            name: "Apple GPU".to_string(),
+            backend: "metal".to_string(),
+            utilization_percent: ioreg_number(line, "Device Utilization %"),
+            memory_used_mb: ioreg_number(line, "In use system memory")
+                .map(|bytes| bytes / BYTES_PER_MB),
+            memory_total_mb: ioreg_number(line, "Alloc system memory")
+                .map(|bytes| bytes / BYTES_PER_MB),
+            temperature_celsius: None,
+            power_watts: None,
+        })
+        .collect()
+}
+
+fn ioreg_number(line: &str, key: &str) -> Option<f64> {
+    let marker = format!("\"{key}\"=");
+    let start = line.find(&marker)? + marker.len();
+    let digits: String = line[start..]
+        .chars()
+        .take_while(|character| character.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_ioreg_accelerators, parse_nvidia_csv, parse_rocm_json};
+
+    #[test]
+    fn parse_nvidia_csv_reads_rows_and_tolerates_missing_power() {
+        let stdout = "0, NVIDIA A100, 87, 30500, 40960, 61, [N/A]\n";
+        let samples = parse_nvidia_csv(stdout);
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].utilization_percent, Some(87.0));
+        assert_eq!(samples[0].power_watts, None);
+    }
+
+    #[test]
+    fn parse_rocm_json_converts_vram_bytes_to_mb() {
+        let stdout = r#"{"card0": {"GPU use (%)": "45", "VRAM Total Memory (B)": "2097152",
+            "VRAM Total Used Memory (B)": "1048576", "Temperature (Sensor edge) (C)": "52.0",
+            "Average Graphics Package Power (W)": "110.0"}, "system": {}}"#;
+        let samples = parse_rocm_json(stdout).expect("json should parse");
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].memory_used_mb, Some(1.0));
+        assert_eq!(samples[0].power_watts, Some(110.0));
+    }
+
+    #[test]
+    fn parse_ioreg_accelerators_reads_utilization() {
+        let stdout = concat!(
+            r#"  | "PerformanceStatistics" = "#,
+            r#"{"In use system memory"=2097152,"Device Utilization %"=37}"#
+        );
+        let samples = parse_ioreg_accelerators(stdout);
+        assert_eq!(samples[0].utilization_percent, Some(37.0));
+        assert_eq!(samples[0].memory_used_mb, Some(2.0));
+    }
+}
@@ -0,0 +1,247 @@
+//! Studio's own diagnostics log, written through `tracing`.
+//!
+//! Events go to stderr and to a JSON-lines file in the app log directory that
+//! rolls over daily, keeping a week of files. Levels come from the
+//! `log_filter` setting, an `EnvFilter` directive such as
+//! `info,studio_app_lib::commands::pipeline_run_scheduler=debug`, so a single
+//! module can be made more verbose; `FORGE_STUDIO_LOG` overrides it for one
+//! launch. `get_recent_app_logs` reads the files back so users can copy the
+//! events around a failure into a bug report.
+
+use crate::models::{AppLogEntry, ForgeStudioError};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tauri::{AppHandle, Manager};
+use tracing::{Level, Subscriber};
+use tracing_appender::rolling::{InitError, RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
+
+const LOG_FILE_PREFIX: &str = "studio";
+const LOG_FILE_SUFFIX: &str = "log";
+const MAX_LOG_FILES: usize = 7;
+const LOG_FILTER_ENV: &str = "FORGE_STUDIO_LOG";
+const FALLBACK_LOG_FILTER: &str = "info";
+const DEFAULT_LOG_LIMIT: usize = 200;
+
+/// Installs the global subscriber. When `log_dir` is unusable Studio still
+/// logs to stderr, and says so there.
+pub fn init_logging(log_dir: &Path, log_filter: &str) {
+    let filter = std::env::var(LOG_FILTER_ENV)
+        .ok()
+        .and_then(|directives| parse_log_filter(&directives).ok())
+        .or_else(|| parse_log_filter(log_filter).ok())
+        .unwrap_or_else(|| EnvFilter::new(FALLBACK_LOG_FILTER));
+    let (file_appender, file_error) = match rolling_file_appender(log_dir) {
+        Ok(file_appender) => (Some(file_appender), None),
+        Err(error) => (None, Some(error)),
+    };
+    if let Err(error) = log_subscriber(filter, file_appender).try_init() {
+        eprintln!("Failed to install the Studio logger: {error}");
+        return;
+    }
+    if let Some(error) = file_error {
+        tracing::warn!(
+            "Failed to open log directory {}: {error}. Logging to stderr only.",
+            log_dir.display()
+        );
+    }
+}
+
+/// Parses `log_filter` directives, rejecting ones `EnvFilter` cannot read.
+pub(crate) fn parse_log_filter(log_filter: &str) -> Result<EnvFilter, ForgeStudioError> {
+    if log_filter.trim().is_empty() {
+        return Err(
+            ForgeStudioError::invalid_input(
+                "log_filter is empty. Use a level such as `info`, optionally followed by `module=level` pairs.",
+            ),
+        );
+    }
+    EnvFilter::try_new(log_filter).map_err(|error| {
+        ForgeStudioError::invalid_input(format!(
+            "Invalid log_filter '{log_filter}': {error}. Use directives like `info,studio_app_lib::commands::forge_task_store=debug`."
+        ))
+    })
+}
+
+/// Newest logged events first, keeping those at `level` or more severe.
+#[tauri::command]
+pub fn get_recent_app_logs(
+    level: Option<String>,
+    limit: Option<usize>,
+    app: AppHandle,
+) -> Result<Vec<AppLogEntry>, ForgeStudioError> {
+    let min_level = match level.as_deref() {
+        Some(level) => Level::from_str(level).map_err(|_| {
+            ForgeStudioError::invalid_input(format!(
+                "Unknown log level '{level}'. Use error, warn, info, debug, or trace."
+            ))
+        })?,
+        None => Level::TRACE,
+    };
+    let log_dir = app.path().app_log_dir().map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to locate the Studio log directory: {error}"
+        ))
+    })?;
+    let limit = limit.unwrap_or(DEFAULT_LOG_LIMIT);
+    read_recent_logs(&log_dir, min_level, limit)
+}
+
+/// Daily files named `studio.<date>.log`, so name order is age order.
+fn rolling_file_appender(log_dir: &Path) -> Result<RollingFileAppender, InitError> {
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix(LOG_FILE_SUFFIX)
+        .max_log_files(MAX_LOG_FILES)
+        .build(log_dir)
+}
+
+fn log_subscriber(
+    filter: EnvFilter,
+    file_appender: Option<RollingFileAppender>,
+) -> impl Subscriber + Send + Sync {
+    let file_layer = file_appender.map(|file_appender| {
+        fmt::layer()
+            .json()
+            .with_current_span(false)
+            .with_span_list(false)
+            .with_writer(file_appender)
+    });
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer().with_writer(std::io::stderr))
+        .with(file_layer)
+}
+
+fn read_recent_logs(
+    log_dir: &Path,
+    min_level: Level,
+    limit: usize,
+) -> Result<Vec<AppLogEntry>, ForgeStudioError> {
+    let mut entries = Vec::new();
+    for log_path in log_file_paths(log_dir)?.iter().rev() {
+        let text = fs::read_to_string(log_path).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to read {}: {error}", log_path.display()))
+        })?;
+        // Lines that are not events, e.g. cut off by a crash, are skipped.
+        let events = text.lines().rev().filter_map(parse_log_line);
+        for (level, entry) in events {
+            if entries.len() == limit {
+                return Ok(entries);
+            }
+            // More verbose levels compare greater.
+            if level <= min_level {
+                entries.push(entry);
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// The last `limit` raw lines across the log files, oldest first.
+pub(crate) fn tail_log_lines(log_dir: &Path, limit: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for log_path in log_file_paths(log_dir).unwrap_or_default().iter().rev() {
+        let Ok(text) = fs::read_to_string(log_path) else {
+            continue;
+        };
+        let remaining = limit - lines.len();
+        lines.extend(text.lines().rev().take(remaining).map(str::to_string));
+        if lines.len() == limit {
+            break;
+        }
+    }
+    lines.reverse();
+    lines
+}
+
+/// Log files in `log_dir`, oldest first.
+fn log_file_paths(log_dir: &Path) -> Result<Vec<PathBuf>, ForgeStudioError> {
+    if !log_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let read_dir = fs::read_dir(log_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", log_dir.display()))
+    })?;
+    let mut log_paths: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_SUFFIX)
+                })
+        })
+        .collect();
+    log_paths.sort();
+    Ok(log_paths)
+}
+
+fn parse_log_line(line: &str) -> Option<(Level, AppLogEntry)> {
+    let event: Value = serde_json::from_str(line).ok()?;
+    let level = Level::from_str(event.get("level")?.as_str()?).ok()?;
+    let text = |key: &str| event.get(key).and_then(Value::as_str).unwrap_or_default();
+    let mut fields = event.get("fields")?.as_object()?.clone();
+    let message = match fields.remove("message") {
+        Some(Value::String(message)) => message,
+        Some(other) => other.to_string(),
+        None => String::new(),
+    };
+    let entry = AppLogEntry {
+        timestamp: text("timestamp").to_string(),
+        level: level.as_str().to_lowercase(),
+        target: text("target").to_string(),
+        message,
+        fields,
+    };
+    Some((level, entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        log_subscriber, parse_log_filter, read_recent_logs, rolling_file_appender, LOG_FILE_PREFIX,
+    };
+    use std::fs;
+    use tracing::Level;
+
+    #[test]
+    fn logged_events_read_back_newest_first_by_level() {
+        let log_dir = std::env::temp_dir().join(format!("forge-app-logs-{}", std::process::id()));
+        fs::create_dir_all(&log_dir).expect("log dir should be created");
+        fs::write(
+            log_dir.join(format!("{LOG_FILE_PREFIX}.2000-01-01.log")),
+            "{\"timestamp\":\"2000-01-01T00:00:00Z\",\"level\":\"ERROR\",\
+             \"fields\":{\"message\":\"old failure\"},\"target\":\"studio\"}\nnot json\n",
+        )
+        .expect("old log should be written");
+        let file_appender = rolling_file_appender(&log_dir).expect("appender should open");
+        let filter = parse_log_filter("debug").expect("filter should parse");
+        tracing::subscriber::with_default(log_subscriber(filter, Some(file_appender)), || {
+            tracing::info!(task_id = "task-1", "Started forge task");
+            tracing::warn!(exit_code = 2, "Forge task failed");
+            tracing::trace!("filtered out");
+        });
+
+        let all = read_recent_logs(&log_dir, Level::TRACE, 10).expect("logs should read");
+        let messages: Vec<&str> = all.iter().map(|entry| entry.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            ["Forge task failed", "Started forge task", "old failure"]
+        );
+        assert_eq!(
+            (all[0].level.as_str(), &all[0].fields["exit_code"]),
+            ("warn", &2.into())
+        );
+        let warnings = read_recent_logs(&log_dir, Level::WARN, 1).expect("logs should read");
+        assert_eq!(warnings[0].message, "Forge task failed");
+        assert_eq!(warnings.len(), 1);
+        assert!(parse_log_filter("info,studio_app_lib=nonsense").is_err());
+        fs::remove_dir_all(&log_dir).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,220 @@
+//! Persistent Studio settings, kept as JSON in the app config directory.
+//!
+//! Settings load once at startup. A missing file means defaults, and a file
+//! that cannot be read is reported and left alone until the next update
+//! replaces it, so a bad edit never blocks startup. Updates are partial: the
+//! frontend sends only the fields it changed, merged over the current
+//! settings, with `null` resetting a field to its default. Unknown fields
+//! are rejected so a misspelt name does not silently do nothing.
+
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::run_store_io::{read_json_file, write_json_file};
+use crate::commands::settings_validation::validate_settings;
+use crate::models::{AppSettings, ForgeStudioError};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::State;
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// Current settings and the file they persist to, shared by every command.
+#[derive(Clone, Default)]
+pub struct SettingsStore {
+    inner: Arc<Mutex<SettingsState>>,
+}
+
+#[derive(Default)]
+struct SettingsState {
+    /// Unset until `load` runs during app setup.
+    path: Option<PathBuf>,
+    settings: AppSettings,
+}
+
+impl SettingsStore {
+    /// Reads the settings file in `config_dir`, falling back to defaults.
+    /// The error describes an unreadable file; defaults are loaded anyway.
+    pub fn load(&self, config_dir: &Path) -> Result<(), ForgeStudioError> {
+        let path = config_dir.join(SETTINGS_FILE_NAME);
+        let (settings, outcome) = if path.is_file() {
+            match read_json_file(&path).and_then(|payload| {
+                serde_json::from_value(payload)
+                    .map_err(|error| ForgeStudioError::parse(error.to_string()))
+            }) {
+                Ok(settings) => (settings, Ok(())),
+                Err(error) => (
+                    AppSettings::default(),
+                    Err(ForgeStudioError::parse(format!(
+                        "Ignoring unreadable settings file {}: {error}. Saving settings will replace it.",
+                        path.display()
+                    ))),
+                ),
+            }
+        } else {
+            (AppSettings::default(), Ok(()))
+        };
+        if let Ok(mut state) = self.inner.lock() {
+            *state = SettingsState {
+                path: Some(path),
+                settings,
+            };
+        }
+        outcome
+    }
+
+    pub fn current(&self) -> AppSettings {
+        self.inner
+            .lock()
+            .map(|state| state.settings.clone())
+            .unwrap_or_default()
+    }
+
+    /// Folders user-supplied paths may lie in, from `workspace_roots` and
+    /// the federated `data_roots`.
+    pub(crate) fn workspace_roots(&self) -> WorkspaceRoots {
+        let settings = self.current();
+        WorkspaceRoots::new(&[settings.workspace_roots, settings.data_roots].concat())
+    }
+
+    /// Directory the settings file lives in, once `load` has run.
+    pub(crate) fn config_dir(&self) -> Option<PathBuf> {
+        let state = self.inner.lock().ok()?;
+        state.path.as_deref()?.parent().map(Path::to_path_buf)
+    }
+
+    /// The configured identity as `Name <email>`, or whichever half is set;
+    /// `None` when neither is, so changes stay unattributed.
+    pub(crate) fn author(&self) -> Option<String> {
+        let identity = self.current().identity;
+        match (identity.name, identity.email) {
+            (Some(name), Some(email)) => Some(format!("{name} <{email}>")),
+            (name, email) => name.or(email),
+        }
+    }
+
+    pub(crate) fn update(&self, changes: Value) -> Result<AppSettings, ForgeStudioError> {
+        let mut state = self.inner.lock().map_err(|_| {
+            ForgeStudioError::internal("Settings store lock poisoned. Restart Studio.")
+        })?;
+        let mut merged = serde_json::to_value(&state.settings).map_err(|error| {
+            ForgeStudioError::internal(format!("Failed to serialize settings: {error}"))
+        })?;
+        merge_changes(&mut merged, changes, "")?;
+        let settings: AppSettings = serde_json::from_value(merged).map_err(|error| {
+            ForgeStudioError::invalid_input(format!(
+                "Settings were not saved: {error}. Check the value types."
+            ))
+        })?;
+        validate_settings(&settings)?;
+        let Some(path) = state.path.clone() else {
+            return Err(ForgeStudioError::internal(
+                "Settings were not saved: the settings file is not loaded yet. Restart Studio.",
+            ));
+        };
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir).map_err(|error| {
+                ForgeStudioError::io(format!(
+                    "Failed to create {}: {error}",
+                    parent_dir.display()
+                ))
+            })?;
+        }
+        let payload = serde_json::to_value(&settings).map_err(|error| {
+            ForgeStudioError::internal(format!("Failed to serialize settings: {error}"))
+        })?;
+        write_json_file(&path, &payload)?;
+        state.settings = settings.clone();
+        Ok(settings)
+    }
+}
+
+#[tauri::command]
+pub fn get_settings(store: State<'_, SettingsStore>) -> AppSettings {
+    store.current()
+}
+
+/// Applies the changed fields and returns the saved settings.
+#[tauri::command]
+pub fn update_settings(
+    changes: Value,
+    store: State<'_, SettingsStore>,
+) -> Result<AppSettings, ForgeStudioError> {
+    store.update(changes)
+}
+
+/// JSON merge patch of `changes` onto `target`, refusing fields `target` lacks.
+fn merge_changes(target: &mut Value, changes: Value, prefix: &str) -> Result<(), ForgeStudioError> {
+    let Value::Object(changes) = changes else {
+        *target = changes;
+        return Ok(());
+    };
+    let Value::Object(target) = target else {
+        *target = Value::Object(changes);
+        return Ok(());
+    };
+    for (key, change) in changes {
+        let field = format!("{prefix}{key}");
+        let Some(current) = target.get_mut(&key) else {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Unknown setting '{field}'. Check the spelling against get_settings."
+            )));
+        };
+        if change.is_null() {
+            // Removed fields take their serde default on deserialization.
+            target.remove(&key);
+        } else {
+            merge_changes(current, change, &format!("{field}."))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SettingsStore;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn updates_merge_validate_and_persist() {
+        let config_dir =
+            std::env::temp_dir().join(format!("forge-app-settings-{}", std::process::id()));
+        let store = SettingsStore::default();
+        store
+            .load(&config_dir)
+            .expect("missing file should load defaults");
+        assert_eq!(store.current().forge_binary, "forge");
+
+        let updated = store
+            .update(json!({"max_concurrent_tasks": 2, "notifications": {"task_completed": true}}))
+            .expect("update should apply");
+        assert_eq!(updated.max_concurrent_tasks, 2);
+        assert!(updated.notifications.task_completed && updated.notifications.task_failed);
+        assert!(store.update(json!({"max_concurent_tasks": 3})).is_err());
+        assert!(store.update(json!({"max_concurrent_tasks": 0})).is_err());
+        assert!(store
+            .update(json!({"log_filter": "info,studio=loud"}))
+            .is_err());
+        assert!(store
+            .update(json!({"workspace_roots": ["/no/such/dir"]}))
+            .is_err());
+        assert!(store
+            .update(json!({"telemetry": {"upload_url": "http://telemetry.invalid"}}))
+            .is_err());
+        assert!(store.update(json!({"identity": {"email": "ada"}})).is_err());
+        store
+            .update(json!({"identity": {"name": "Ada", "email": "ada@example.com"}}))
+            .expect("identity should apply");
+        assert_eq!(store.author().as_deref(), Some("Ada <ada@example.com>"));
+        let reset = store
+            .update(json!({"max_concurrent_tasks": null}))
+            .expect("null should reset");
+        assert_eq!(reset.max_concurrent_tasks, 4);
+
+        let reloaded = SettingsStore::default();
+        reloaded.load(&config_dir).expect("saved file should load");
+        assert_eq!(reloaded.current(), reset);
+        fs::remove_dir_all(&config_dir).expect("temp dir should be removed");
+    }
+}
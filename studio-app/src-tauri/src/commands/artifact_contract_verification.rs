@@ -0,0 +1,190 @@
+//! Rehashes a run's artifacts against the hashes in its artifact contract.
+//!
+//! The CLI records a SHA-256 of the model weights, training config,
+//! tokenizer, best checkpoint, and reproducibility bundle when it writes the
+//! contract. A match for every recorded artifact, plus agreeing config hashes
+//! in the lifecycle and contract, means `model_path` still holds the weights
+//! trained under the recorded `config_hash`.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::content_hash::sha256_file;
+use crate::commands::run_details::ARTIFACT_CONTRACT_FILE_NAME;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, optional_string, read_json_file, resolve_data_root_path,
+    resolve_workspace_path,
+};
+use crate::models::{ArtifactContractVerification, ArtifactHashCheck, ForgeStudioError};
+use serde_json::{Map, Value};
+use tauri::State;
+
+/// Mirrors `HASHED_ARTIFACT_FIELDS` in `serve/training_artifact_contract.py`.
+const HASHED_ARTIFACT_FIELDS: [&str; 5] = [
+    "model_path",
+    "training_config_path",
+    "tokenizer_path",
+    "best_checkpoint_path",
+    "reproducibility_bundle_path",
+];
+const ARTIFACT_HASHES_FIELD: &str = "artifact_hashes";
+const MATCH_STATUS: &str = "match";
+const MISMATCH_STATUS: &str = "mismatch";
+const MISSING_STATUS: &str = "missing";
+const UNRECORDED_STATUS: &str = "unrecorded";
+
+#[tauri::command]
+pub fn verify_artifact_contract(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<ArtifactContractVerification, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let contract_path = lifecycle
+        .artifact_contract_path
+        .as_deref()
+        .map(resolve_workspace_path)
+        .unwrap_or_else(|| {
+            resolve_workspace_path(&lifecycle.output_dir).join(ARTIFACT_CONTRACT_FILE_NAME)
+        });
+    if !contract_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Run '{run_id}' has no artifact contract at {}. Contracts are written when \
+             training completes; verify the run after it finishes.",
+            contract_path.display()
+        )));
+    }
+    let payload = read_json_file(&contract_path)?;
+    let contract = payload.as_object().ok_or_else(|| {
+        ForgeStudioError::parse(format!(
+            "Artifact contract {} must be a JSON object",
+            contract_path.display()
+        ))
+    })?;
+    let checks = check_artifacts(contract)?;
+    let contract_config_hash = optional_string(contract, "config_hash");
+    let mut issues = collect_issues(&checks);
+    if contract_config_hash.as_deref() != Some(lifecycle.config_hash.as_str()) {
+        issues.push(
+            "The contract's config_hash differs from the run lifecycle; the contract was \
+             overwritten by another run in the same output dir."
+                .to_string(),
+        );
+    }
+    Ok(ArtifactContractVerification {
+        run_id,
+        contract_path: contract_path.display().to_string(),
+        is_valid: issues.is_empty(),
+        lifecycle_config_hash: lifecycle.config_hash,
+        contract_config_hash,
+        checks,
+        issues,
+    })
+}
+
+/// One check per hashed field that names a path or has a recorded hash.
+fn check_artifacts(
+    contract: &Map<String, Value>,
+) -> Result<Vec<ArtifactHashCheck>, ForgeStudioError> {
+    let recorded_hashes = contract
+        .get(ARTIFACT_HASHES_FIELD)
+        .and_then(Value::as_object);
+    let mut checks = Vec::new();
+    for artifact in HASHED_ARTIFACT_FIELDS {
+        let recorded_hash = recorded_hashes
+            .and_then(|hashes| hashes.get(artifact))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let path = optional_string(contract, artifact).map(|value| resolve_workspace_path(&value));
+        if path.is_none() && recorded_hash.is_none() {
+            continue;
+        }
+        let computed_hash = match &path {
+            Some(file_path) if file_path.is_file() => Some(sha256_file(file_path)?),
+            _ => None,
+        };
+        let status = match (&recorded_hash, &computed_hash) {
+            (_, None) => MISSING_STATUS,
+            (None, Some(_)) => UNRECORDED_STATUS,
+            (Some(recorded), Some(computed)) if recorded == computed => MATCH_STATUS,
+            (Some(_), Some(_)) => MISMATCH_STATUS,
+        };
+        checks.push(ArtifactHashCheck {
+            artifact: artifact.to_string(),
+            path: path.map(|value| value.display().to_string()),
+            recorded_hash,
+            computed_hash,
+            status: status.to_string(),
+        });
+    }
+    Ok(checks)
+}
+
+fn collect_issues(checks: &[ArtifactHashCheck]) -> Vec<String> {
+    let mut issues: Vec<String> = checks
+        .iter()
+        .filter_map(|check| match check.status.as_str() {
+            MISMATCH_STATUS => Some(format!(
+                "{} no longer matches the hash recorded at training time; the file was \
+                 modified or replaced.",
+                check.artifact
+            )),
+            MISSING_STATUS => Some(format!(
+                "{} is missing from disk; restore it from a backup or retrain.",
+                check.artifact
+            )),
+            _ => None,
+        })
+        .collect();
+    if checks.iter().any(|check| check.status == UNRECORDED_STATUS) {
+        issues.push(
+            "The contract predates artifact hashing, so some files cannot be verified. \
+             Retrain with the current CLI to record hashes."
+                .to_string(),
+        );
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_artifacts;
+    use crate::commands::content_hash::sha256_hex;
+    use std::fs;
+
+    #[test]
+    fn check_artifacts_reports_match_mismatch_and_missing() {
+        let output_dir =
+            std::env::temp_dir().join(format!("forge-contract-verify-{}", std::process::id()));
+        fs::create_dir_all(&output_dir).expect("output dir should be created");
+        let model_path = output_dir.join("model.pt");
+        let config_path = output_dir.join("training_config.json");
+        fs::write(&model_path, b"weights").expect("model write");
+        fs::write(&config_path, b"{\"epochs\": 3}").expect("config write");
+        let contract = serde_json::json!({
+            "model_path": model_path.display().to_string(),
+            "training_config_path": config_path.display().to_string(),
+            "tokenizer_path": output_dir.join("vocab.json").display().to_string(),
+            "best_checkpoint_path": null,
+            "artifact_hashes": {
+                "model_path": sha256_hex(b"weights"),
+                "training_config_path": sha256_hex(b"{\"epochs\": 2}"),
+                "tokenizer_path": sha256_hex(b"vocab"),
+            },
+        });
+        let checks = check_artifacts(contract.as_object().expect("contract object"));
+        fs::remove_dir_all(&output_dir).expect("cleanup should succeed");
+        let statuses: Vec<(String, String)> = checks
+            .expect("checks should succeed")
+            .into_iter()
+            .map(|check| (check.artifact, check.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("model_path".to_string(), "match".to_string()),
+                ("training_config_path".to_string(), "mismatch".to_string()),
+                ("tokenizer_path".to_string(), "missing".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Crash-safe file replacement for metadata Studio writes.
+//!
+//! Contents go to a temp file beside the target, are flushed to disk, and the
+//! temp file is renamed over the target. A crash mid-write therefore leaves
+//! either the previous file or the new one, never half-written JSON that the
+//! CLI and Studio would both refuse to parse.
+
+use crate::models::ForgeStudioError;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Distinguishes temp files of concurrent writes from the same process.
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Replaces `path` with `contents` atomically; on failure `path` is untouched.
+pub(crate) fn write_file_atomic(path: &Path, contents: &[u8]) -> Result<(), ForgeStudioError> {
+    let temp_path = temp_path_for(path);
+    let written = write_synced(&temp_path, contents)
+        .map_err(|error| {
+            ForgeStudioError::io(format!("Failed to write {}: {error}", path.display()))
+        })
+        .and_then(|()| {
+            fs::rename(&temp_path, path).map_err(|error| {
+                ForgeStudioError::io(format!(
+                    "Failed to rename {} to {}: {error}",
+                    temp_path.display(),
+                    path.display()
+                ))
+            })
+        });
+    if written.is_err() {
+        let _ = fs::remove_file(&temp_path);
+        return written;
+    }
+    sync_parent_dir(path);
+    Ok(())
+}
+
+fn temp_path_for(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let sequence = TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(
+        ".{file_name}.tmp-{}-{sequence}",
+        std::process::id()
+    ))
+}
+
+fn write_synced(temp_path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = File::create(temp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+/// Persists the rename itself. Best effort: Windows and some filesystems
+/// cannot open or sync a directory, and the data is already on disk.
+fn sync_parent_dir(path: &Path) {
+    if let Some(parent_dir) = path.parent() {
+        let _ = File::open(parent_dir).and_then(|dir| dir.sync_all());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_file_atomic;
+    use std::fs;
+
+    #[test]
+    fn replaces_contents_and_leaves_no_temp_files() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("forge-atomic-write-{}", std::process::id()));
+        fs::create_dir_all(&temp_dir).expect("temp dir should be created");
+        let target = temp_dir.join("catalog.json");
+        write_file_atomic(&target, b"{\"v\": 1}").expect("first write should succeed");
+        write_file_atomic(&target, b"{\"v\": 2}").expect("overwrite should succeed");
+        assert_eq!(
+            fs::read_to_string(&target).expect("target should be readable"),
+            "{\"v\": 2}"
+        );
+        let missing_dir = temp_dir.join("missing").join("catalog.json");
+        let error = write_file_atomic(&missing_dir, b"{}").expect_err("missing dir should fail");
+        assert_eq!(error.code(), "io");
+        assert!(error.to_string().starts_with("Failed to write"));
+        let entries = fs::read_dir(&temp_dir)
+            .expect("temp dir should list")
+            .count();
+        assert_eq!(entries, 1);
+        fs::remove_dir_all(&temp_dir).expect("temp dir should be removed");
+    }
+}
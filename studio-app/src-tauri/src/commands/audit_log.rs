@@ -0,0 +1,118 @@
+//! Append-only log of who changed what in a data root.
+//!
+//! Commands that annotate runs or change the model registry append one JSON
+//! line to `<data_root>/audit_log.jsonl`, stamped with the identity from
+//! settings, so teams sharing a data root can attribute changes. Appends
+//! hold the file's advisory lock because several Studios may share the data
+//! root. Logging is best effort: a change that succeeded is not reported as
+//! failed because its audit line could not be written.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{AuditLogEntry, ForgeStudioError};
+use chrono::{SecondsFormat, Utc};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use tauri::State;
+
+const AUDIT_LOG_FILE_NAME: &str = "audit_log.jsonl";
+
+/// Audit log entries for the data root, newest first, optionally only the
+/// newest `limit`.
+#[tauri::command]
+pub fn get_audit_log(
+    data_root: String,
+    limit: Option<usize>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<AuditLogEntry>, ForgeStudioError> {
+    let audit_log_path =
+        resolve_data_root_path(&data_root, &settings.workspace_roots())?.join(AUDIT_LOG_FILE_NAME);
+    Ok(read_audit_log(&audit_log_path)?
+        .into_iter()
+        .rev()
+        .take(limit.unwrap_or(usize::MAX))
+        .collect())
+}
+
+/// Appends an entry for `action` on `target`; failures are logged only.
+pub(crate) fn record_audit_entry(
+    data_root: &Path,
+    author: Option<String>,
+    action: &str,
+    target: &str,
+) {
+    let entry = AuditLogEntry {
+        timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        author,
+        action: action.to_string(),
+        target: target.to_string(),
+    };
+    if let Err(error) = append_entry(&data_root.join(AUDIT_LOG_FILE_NAME), &entry) {
+        tracing::warn!("Audit log entry for {action} on {target} was not written: {error}");
+    }
+}
+
+fn append_entry(audit_log_path: &Path, entry: &AuditLogEntry) -> Result<(), ForgeStudioError> {
+    let line = serde_json::to_string(entry).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize audit log entry: {error}"))
+    })?;
+    with_file_lock(audit_log_path, || {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(audit_log_path)
+            .and_then(|mut file| writeln!(file, "{line}"))
+            .map_err(|error| {
+                ForgeStudioError::io(format!(
+                    "Failed to write {}: {error}",
+                    audit_log_path.display()
+                ))
+            })
+    })
+}
+
+/// Entries oldest first; malformed lines (e.g. a write cut short) are skipped.
+fn read_audit_log(audit_log_path: &Path) -> Result<Vec<AuditLogEntry>, ForgeStudioError> {
+    if !audit_log_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let payload = fs::read_to_string(audit_log_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read {}: {error}",
+            audit_log_path.display()
+        ))
+    })?;
+    Ok(payload
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_audit_log, record_audit_entry, AUDIT_LOG_FILE_NAME};
+    use std::fs;
+
+    #[test]
+    fn entries_are_appended_with_their_author() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-audit-log-{}", std::process::id()));
+        fs::create_dir_all(&data_root).expect("data root should be created");
+        let author = Some("Ada <ada@example.com>".to_string());
+        record_audit_entry(&data_root, author.clone(), "set_run_notes", "run-1");
+        record_audit_entry(&data_root, None, "set_model_stage", "classifier@2");
+
+        let entries =
+            read_audit_log(&data_root.join(AUDIT_LOG_FILE_NAME)).expect("audit log should read");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            (entries[0].author.clone(), entries[0].target.as_str()),
+            (author, "run-1")
+        );
+        assert_eq!(entries[1].action, "set_model_stage");
+        assert!(entries[1].author.is_none());
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,285 @@
+//! Batch inference over a dataset sample for side-by-side checkpoint review.
+//!
+//! The sample is evenly strided over the version's records rather than
+//! random, so two batches over the same dataset version and sample size
+//! prompt exactly the same records and can be compared row by row. Sampling
+//! defaults to greedy decoding for the same reason. Results are rewritten
+//! after every record so the review table fills in while the batch runs.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::batch_inference_store::{
+    list_batch_ids, load_batch, next_batch_id, register_running_batch, release_running_batch,
+    save_batch,
+};
+use crate::commands::chat_process::{build_chat_args, run_chat_process};
+use crate::commands::dataset_store_io::{read_records, resolve_version, string_field};
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::run_details::resolve_run_artifacts;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, resolve_data_root_path, resolve_workspace_path,
+};
+use crate::models::{
+    BatchInferenceComparison, BatchInferenceComparisonRow, BatchInferencePair, BatchInferenceRef,
+    BatchInferenceRequest, BatchInferenceRun, BatchInferenceStart, ChatSessionOptions,
+    ForgeStudioError, RunLifecycleRecord,
+};
+use chrono::{SecondsFormat, Utc};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const MAX_SAMPLE_SIZE: usize = 200;
+const PROMPT_PREFIX_CHARS: usize = 256;
+
+struct BatchWorker {
+    forge_binary: String,
+    data_root: String,
+    resolved_data_root: PathBuf,
+    options: ChatSessionOptions,
+    prompts: Vec<(String, String)>,
+    batch: BatchInferenceRun,
+}
+
+#[tauri::command]
+pub fn run_batch_inference(
+    data_root: String,
+    request: BatchInferenceRequest,
+    settings: State<'_, SettingsStore>,
+) -> Result<BatchInferenceStart, ForgeStudioError> {
+    if request.sample_size == 0 || request.sample_size > MAX_SAMPLE_SIZE {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Sample size {} is out of range. Use 1 to {MAX_SAMPLE_SIZE} records.",
+            request.sample_size
+        )));
+    }
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &request.run_id)?;
+    let model_path = resolve_batch_model_path(&lifecycle, request.model_path.as_deref())?;
+    let version_id = resolve_version(
+        &data_root,
+        &request.dataset_name,
+        request.version_id.clone(),
+    )?;
+    let records = read_records(&data_root, &request.dataset_name, &version_id)?;
+    let prompts = stride_sample(&records, request.sample_size)?;
+    let batch = BatchInferenceRun {
+        batch_id: next_batch_id(&resolved_data_root, &request.run_id)?,
+        run_id: request.run_id.clone(),
+        model_path,
+        dataset_name: request.dataset_name.clone(),
+        version_id,
+        sample_size: prompts.len(),
+        status: task_status_name(TaskLifecycleStatus::Running).to_string(),
+        created_at: timestamp_now(),
+        finished_at: None,
+        pairs: vec![],
+    };
+    save_batch(&resolved_data_root, &batch)?;
+    register_running_batch(&resolved_data_root, &batch);
+    let start = BatchInferenceStart {
+        batch_id: batch.batch_id.clone(),
+        run_id: batch.run_id.clone(),
+        record_count: prompts.len(),
+    };
+    let mut options = request.options.unwrap_or_default();
+    options.dataset_name.get_or_insert(request.dataset_name);
+    options.temperature.get_or_insert(0.0);
+    let forge_binary = settings.current().forge_binary;
+    std::thread::spawn(move || {
+        run_batch(BatchWorker {
+            forge_binary,
+            data_root,
+            resolved_data_root,
+            options,
+            prompts,
+            batch,
+        })
+    });
+    Ok(start)
+}
+
+#[tauri::command]
+pub fn list_batch_inferences(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<BatchInferenceRun>, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let read_only = settings.current().read_only;
+    let mut batches = Vec::new();
+    for batch_id in list_batch_ids(&resolved_data_root, &run_id)? {
+        batches.push(load_batch(
+            &resolved_data_root,
+            &run_id,
+            &batch_id,
+            read_only,
+        )?);
+    }
+    Ok(batches)
+}
+
+#[tauri::command]
+pub fn get_batch_inference(
+    data_root: String,
+    run_id: String,
+    batch_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<BatchInferenceRun, ForgeStudioError> {
+    load_batch(
+        &resolve_data_root_path(&data_root, &settings.workspace_roots())?,
+        &run_id,
+        &batch_id,
+        settings.current().read_only,
+    )
+}
+
+/// Aligns two batches by record id, keeping the left batch's order.
+#[tauri::command]
+pub fn compare_batch_inferences(
+    data_root: String,
+    left: BatchInferenceRef,
+    right: BatchInferenceRef,
+    settings: State<'_, SettingsStore>,
+) -> Result<BatchInferenceComparison, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let read_only = settings.current().read_only;
+    let left_batch = load_batch(&resolved_data_root, &left.run_id, &left.batch_id, read_only)?;
+    let right_batch = load_batch(
+        &resolved_data_root,
+        &right.run_id,
+        &right.batch_id,
+        read_only,
+    )?;
+    Ok(align_batches(&left_batch, &right_batch))
+}
+
+fn resolve_batch_model_path(
+    lifecycle: &RunLifecycleRecord,
+    requested: Option<&str>,
+) -> Result<String, ForgeStudioError> {
+    let model_path = match requested {
+        Some(path) => Some(resolve_workspace_path(path).display().to_string()),
+        None => resolve_run_artifacts(lifecycle)?.model_path,
+    };
+    model_path
+        .filter(|path| Path::new(path).is_file())
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{}' has no model file for batch inference. Pass a checkpoint path.",
+                lifecycle.run_id
+            ))
+        })
+}
+
+fn run_batch(worker: BatchWorker) {
+    let mut batch = worker.batch;
+    for (record_id, prompt) in worker.prompts {
+        let args = build_chat_args(&batch.model_path, &worker.options, &prompt);
+        let (response, error) =
+            run_chat_process(&worker.forge_binary, &worker.data_root, args, &mut |_| {});
+        batch.pairs.push(BatchInferencePair {
+            record_id,
+            prompt,
+            response,
+            error,
+        });
+        save_batch_or_warn(&worker.resolved_data_root, &batch);
+    }
+    batch.status = task_status_name(TaskLifecycleStatus::Completed).to_string();
+    batch.finished_at = Some(timestamp_now());
+    save_batch_or_warn(&worker.resolved_data_root, &batch);
+    release_running_batch(&worker.resolved_data_root, &batch);
+}
+
+/// Logs a failed rewrite; the next record's rewrite retries it.
+fn save_batch_or_warn(data_root: &Path, batch: &BatchInferenceRun) {
+    if let Err(error) = save_batch(data_root, batch) {
+        tracing::warn!("{error}");
+    }
+}
+
+fn align_batches(left: &BatchInferenceRun, right: &BatchInferenceRun) -> BatchInferenceComparison {
+    let right_pairs: HashMap<&str, &BatchInferencePair> = right
+        .pairs
+        .iter()
+        .map(|pair| (pair.record_id.as_str(), pair))
+        .collect();
+    let mut rows: Vec<BatchInferenceComparisonRow> = left
+        .pairs
+        .iter()
+        .map(|pair| BatchInferenceComparisonRow {
+            record_id: pair.record_id.clone(),
+            prompt: pair.prompt.clone(),
+            left_response: Some(pair.response.clone()),
+            right_response: right_pairs
+                .get(pair.record_id.as_str())
+                .map(|matched| matched.response.clone()),
+        })
+        .collect();
+    let left_ids: HashSet<&str> = left
+        .pairs
+        .iter()
+        .map(|pair| pair.record_id.as_str())
+        .collect();
+    for pair in right
+        .pairs
+        .iter()
+        .filter(|pair| !left_ids.contains(pair.record_id.as_str()))
+    {
+        rows.push(BatchInferenceComparisonRow {
+            record_id: pair.record_id.clone(),
+            prompt: pair.prompt.clone(),
+            left_response: None,
+            right_response: Some(pair.response.clone()),
+        });
+    }
+    BatchInferenceComparison {
+        left_model_path: left.model_path.clone(),
+        right_model_path: right.model_path.clone(),
+        rows,
+    }
+}
+
+/// Picks `sample_size` evenly spaced records and truncates their text to a prompt prefix.
+fn stride_sample(
+    records: &[Value],
+    sample_size: usize,
+) -> Result<Vec<(String, String)>, ForgeStudioError> {
+    let count = sample_size.min(records.len());
+    let mut prompts = Vec::with_capacity(count);
+    for index in 0..count {
+        let record = &records[index * records.len() / count];
+        let fields = record
+            .as_object()
+            .ok_or_else(|| ForgeStudioError::parse("Record entry is not an object"))?;
+        let text = string_field(fields, "text")?;
+        prompts.push((
+            string_field(fields, "record_id")?,
+            text.chars().take(PROMPT_PREFIX_CHARS).collect(),
+        ));
+    }
+    Ok(prompts)
+}
+
+fn timestamp_now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stride_sample;
+    use serde_json::json;
+
+    #[test]
+    fn stride_sample_spreads_over_records_and_truncates_text() {
+        let records: Vec<_> = (0..10)
+            .map(|index| json!({"record_id": format!("r{index}"), "text": "x".repeat(300)}))
+            .collect();
+        let prompts = stride_sample(&records, 3).expect("records should sample");
+        let ids: Vec<&str> = prompts.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["r0", "r3", "r6"]);
+        assert_eq!(prompts[0].1.len(), 256);
+    }
+}
@@ -0,0 +1,171 @@
+//! Batch inference files under `runs/<run_id>/inference/`.
+//!
+//! A batch is written as "running" before its worker starts and rewritten
+//! after every record. Workers are threads of this process, so a batch left
+//! "running" by a closed or crashed Studio never finishes; loading such a
+//! batch marks it "interrupted" instead of reporting it as still in progress.
+
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::run_store_io::{read_json_file, run_dir, write_json_file};
+use crate::models::{BatchInferenceRun, ForgeStudioError};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const INTERRUPTED_STATUS: &str = "interrupted";
+const INFERENCE_DIR_NAME: &str = "inference";
+
+/// Payload paths of batches whose worker is running in this process.
+static RUNNING_BATCHES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Records that a worker owns `batch` until `release_running_batch` is called.
+pub(crate) fn register_running_batch(data_root: &Path, batch: &BatchInferenceRun) {
+    let payload_path = batch_path(data_root, &batch.run_id, &batch.batch_id);
+    running_batches().push(payload_path);
+}
+
+pub(crate) fn release_running_batch(data_root: &Path, batch: &BatchInferenceRun) {
+    let payload_path = batch_path(data_root, &batch.run_id, &batch.batch_id);
+    running_batches().retain(|path| *path != payload_path);
+}
+
+pub(crate) fn list_batch_ids(
+    data_root: &Path,
+    run_id: &str,
+) -> Result<Vec<String>, ForgeStudioError> {
+    let inference_dir = run_dir(data_root, run_id).join(INFERENCE_DIR_NAME);
+    if !inference_dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let entries = fs::read_dir(&inference_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read {}: {error}",
+            inference_dir.display()
+        ))
+    })?;
+    let mut batch_ids: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_suffix(".json")
+                .map(str::to_string)
+        })
+        .collect();
+    batch_ids.sort();
+    Ok(batch_ids)
+}
+
+pub(crate) fn next_batch_id(data_root: &Path, run_id: &str) -> Result<String, ForgeStudioError> {
+    Ok(format!(
+        "batch-{:04}",
+        list_batch_ids(data_root, run_id)?.len() + 1
+    ))
+}
+
+/// Loads a batch, marking it "interrupted" if it is "running" with no worker.
+pub(crate) fn load_batch(
+    data_root: &Path,
+    run_id: &str,
+    batch_id: &str,
+    read_only: bool,
+) -> Result<BatchInferenceRun, ForgeStudioError> {
+    let payload_path = batch_path(data_root, run_id, batch_id);
+    if !payload_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Batch inference '{batch_id}' does not exist for run '{run_id}'. \
+             List the run's batches to pick one."
+        )));
+    }
+    let mut batch: BatchInferenceRun = serde_json::from_value(read_json_file(&payload_path)?)
+        .map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Failed to parse batch inference {}: {error}",
+                payload_path.display()
+            ))
+        })?;
+    if batch.status == task_status_name(TaskLifecycleStatus::Running)
+        && !running_batches().contains(&payload_path)
+    {
+        batch.status = INTERRUPTED_STATUS.to_string();
+        // Read-only mode reports the batch as interrupted without rewriting it.
+        if !read_only {
+            if let Err(error) = save_batch(data_root, &batch) {
+                tracing::warn!("{error}");
+            }
+        }
+    }
+    Ok(batch)
+}
+
+pub(crate) fn save_batch(
+    data_root: &Path,
+    batch: &BatchInferenceRun,
+) -> Result<(), ForgeStudioError> {
+    let payload_path = batch_path(data_root, &batch.run_id, &batch.batch_id);
+    if let Some(parent) = payload_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(batch).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize batch '{}': {error}",
+            batch.batch_id
+        ))
+    })?;
+    write_json_file(&payload_path, &payload)
+}
+
+fn batch_path(data_root: &Path, run_id: &str, batch_id: &str) -> PathBuf {
+    run_dir(data_root, run_id)
+        .join(INFERENCE_DIR_NAME)
+        .join(format!("{batch_id}.json"))
+}
+
+fn running_batches() -> std::sync::MutexGuard<'static, Vec<PathBuf>> {
+    RUNNING_BATCHES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_batch, register_running_batch, release_running_batch, save_batch};
+    use crate::models::BatchInferenceRun;
+    use std::fs;
+
+    fn running_batch() -> BatchInferenceRun {
+        BatchInferenceRun {
+            batch_id: "batch-0001".to_string(),
+            run_id: "run-1".to_string(),
+            model_path: "model.pt".to_string(),
+            dataset_name: "demo".to_string(),
+            version_id: "v1".to_string(),
+            sample_size: 1,
+            status: "running".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            finished_at: None,
+            pairs: vec![],
+        }
+    }
+
+    #[test]
+    fn running_batch_without_a_worker_loads_as_interrupted() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-batch-store-{}", std::process::id()));
+        let batch = running_batch();
+        save_batch(&data_root, &batch).expect("batch should save");
+        register_running_batch(&data_root, &batch);
+        let loaded =
+            load_batch(&data_root, "run-1", "batch-0001", false).expect("batch should load");
+        assert_eq!(loaded.status, "running");
+
+        release_running_batch(&data_root, &batch);
+        let loaded =
+            load_batch(&data_root, "run-1", "batch-0001", false).expect("batch should load");
+        assert_eq!(loaded.status, "interrupted");
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,39 @@
+//! Runs heavy command bodies on the async runtime's blocking pool.
+//!
+//! Synchronous commands run on the IPC thread, so one that reads a
+//! multi-gigabyte version or waits on a forge subprocess stalls every other
+//! call until it returns. Commands doing that are `async` and hand their work
+//! to `run_blocking`, which keeps the IPC thread free while it runs.
+
+use crate::models::ForgeStudioError;
+use tauri::async_runtime;
+
+/// Runs `task` on a blocking worker thread and returns its result.
+pub(crate) async fn run_blocking<T, F>(task: F) -> Result<T, ForgeStudioError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, ForgeStudioError> + Send + 'static,
+{
+    async_runtime::spawn_blocking(task).await.map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Background worker panicked: {error}. Retry; restart Studio if it keeps failing."
+        ))
+    })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_blocking;
+    use tauri::async_runtime;
+
+    #[test]
+    fn results_and_panics_come_back_as_command_results() {
+        let sum = async_runtime::block_on(run_blocking(|| Ok((1..=4).sum::<u32>())));
+        assert_eq!(sum, Ok(10));
+        let panicked = async_runtime::block_on(run_blocking(|| -> Result<(), _> {
+            panic!("worker exploded")
+        }));
+        let error = panicked.expect_err("panic should become an error");
+        assert_eq!(error.code(), "internal");
+    }
+}
@@ -0,0 +1,174 @@
+//! Canvas bundles for sharing a pipeline with another machine.
+//!
+//! A bundle is one JSON file holding a canvas export, the files its nodes
+//! point at through code and architecture path fields, and the forge version
+//! of the exporting workspace. File contents are base64 encoded inline so the
+//! bundle stays a single file without pulling in an archive crate. Node
+//! configs inside the bundle point at `files/<node id>/<field>/<name>`, which
+//! `import_canvas_bundle` rewrites to wherever it extracts them. Datasets,
+//! weights, and output directories are left out; the destination supplies
+//! its own.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::canvas_export::{build_canvas_payload, validate_canvas_payload};
+use crate::commands::canvas_export_files::{
+    create_parent_dir, resolve_output_path, write_export_file,
+};
+use crate::commands::canvas_variables::has_placeholder;
+use crate::commands::content_hash::sha256_hex;
+use crate::commands::forge_updates::read_forge_version;
+use crate::commands::run_store_io::{resolve_data_root_text, resolve_workspace_path};
+use crate::models::{
+    ForgeStudioError, PipelineBundleFile, PipelineCanvasBundleExport, PipelineCanvasState,
+};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use serde_json::{json, Map, Value};
+use std::fs;
+use tauri::State;
+
+pub(crate) const CANVAS_BUNDLE_FORMAT_VERSION: u32 = 1;
+const CANVAS_BUNDLE_EXTENSION: &str = "forgebundle";
+pub(crate) const BUNDLE_FILES_DIR: &str = "files";
+/// Path fields naming small config or code files that only exist locally.
+const BUNDLED_PATH_FIELDS: [&str; 2] = ["architecture_file", "custom_loop_file"];
+const MAX_BUNDLED_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+#[tauri::command]
+pub async fn export_canvas_bundle(
+    data_root: String,
+    canvas: PipelineCanvasState,
+    output_path: Option<String>,
+    overwrite: Option<bool>,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasBundleExport, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let forge_binary = settings.current().forge_binary;
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        let output_path =
+            resolve_output_path(&data_root, output_path, CANVAS_BUNDLE_EXTENSION, &roots)?;
+        let (serialized, mut export) = build_bundle(&data_root, canvas, &forge_binary)?;
+        create_parent_dir(&output_path)?;
+        write_export_file(&output_path, &serialized, overwrite.unwrap_or(false))?;
+        export.output_path = output_path.display().to_string();
+        Ok(export)
+    })
+    .await
+}
+
+/// The serialized bundle for `canvas` and its summary, whose `output_path`
+/// the caller fills in once written; `data_root` must be resolved.
+pub(crate) fn build_bundle(
+    data_root: &str,
+    mut canvas: PipelineCanvasState,
+    forge_binary: &str,
+) -> Result<(String, PipelineCanvasBundleExport), ForgeStudioError> {
+    validate_canvas_payload(&canvas)?;
+    let mut files = Vec::new();
+    let mut contents = Map::new();
+    let mut warnings = Vec::new();
+    for node in &mut canvas.nodes {
+        for field in BUNDLED_PATH_FIELDS {
+            let Some(raw_path) = node.config.get(field).map(|value| value.trim().to_string())
+            else {
+                continue;
+            };
+            if raw_path.is_empty() {
+                continue;
+            }
+            match read_bundled_file(&node.id, field, &raw_path) {
+                Ok((file, encoded)) => {
+                    node.config
+                        .insert(field.to_string(), file.bundle_path.clone());
+                    contents.insert(file.bundle_path.clone(), Value::String(encoded));
+                    files.push(file);
+                }
+                Err(reason) => warnings.push(reason),
+            }
+        }
+    }
+    let forge_version = read_forge_version(forge_binary);
+    let bundle = json!({
+        "bundle_format_version": CANVAS_BUNDLE_FORMAT_VERSION,
+        "forge_version": forge_version,
+        "files": files,
+        "canvas": build_canvas_payload(data_root, canvas, forge_binary)?,
+        "contents": contents,
+    });
+    let serialized = serde_json::to_string_pretty(&bundle).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Canvas bundle export failed: could not serialize bundle: {error}"
+        ))
+    })?;
+    let export = PipelineCanvasBundleExport {
+        output_path: String::new(),
+        forge_version,
+        files,
+        warnings,
+    };
+    Ok((serialized, export))
+}
+
+/// The file's manifest entry and base64 contents, or why it was left out.
+fn read_bundled_file(
+    node_id: &str,
+    field: &str,
+    raw_path: &str,
+) -> Result<(PipelineBundleFile, String), String> {
+    let skipped =
+        |reason: String| format!("Node '{node_id}' {field} {raw_path} was not bundled: {reason}");
+    if has_placeholder(raw_path) {
+        return Err(skipped(
+            "it uses a variable. Make sure the destination's value points at a real file.".into(),
+        ));
+    }
+    // Runs start in the workspace root, so relative paths resolve from there.
+    let source_path = resolve_workspace_path(raw_path);
+    let metadata = fs::metadata(&source_path).map_err(|error| {
+        skipped(format!(
+            "{error}. Copy the file to the destination by hand."
+        ))
+    })?;
+    if !metadata.is_file() {
+        return Err(skipped(
+            "it is not a file. Point the field at a file or copy it by hand.".into(),
+        ));
+    }
+    if metadata.len() > MAX_BUNDLED_FILE_BYTES {
+        return Err(skipped(format!(
+            "only files up to {MAX_BUNDLED_FILE_BYTES} bytes are bundled. Copy it to the destination by hand."
+        )));
+    }
+    let bytes = fs::read(&source_path)
+        .map_err(|error| skipped(format!("{error}. Check the file's permissions.")))?;
+    let file_name = source_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file = PipelineBundleFile {
+        node_id: node_id.to_string(),
+        field: field.to_string(),
+        bundle_path: format!(
+            "{BUNDLE_FILES_DIR}/{}/{field}/{file_name}",
+            path_segment(node_id)
+        ),
+        original_path: source_path.display().to_string(),
+        sha256: sha256_hex(&bytes),
+        size_bytes: metadata.len(),
+    };
+    Ok((file, BASE64_STANDARD.encode(bytes)))
+}
+
+/// Node ids are free text, so anything but ASCII letters, digits, `-`, and `_`
+/// becomes `_` before the id names a directory.
+fn path_segment(node_id: &str) -> String {
+    node_id
+        .chars()
+        .map(|character| match character {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => character,
+            _ => '_',
+        })
+        .collect()
+}
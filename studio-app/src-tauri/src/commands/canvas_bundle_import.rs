@@ -0,0 +1,243 @@
+//! Unpacks canvas bundles written by `export_canvas_bundle`.
+//!
+//! Bundled files are extracted under the data root's canvas directory, one
+//! directory per bundle, and each node field that pointed into the bundle is
+//! rewritten to the extracted file so the canvas runs on this machine. Bundle
+//! paths and checksums are checked before anything is written, since a bundle
+//! may come from anywhere. A forge version other than the installed one is a
+//! warning rather than an error: most canvases run fine across versions.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::canvas_bundle::{BUNDLE_FILES_DIR, CANVAS_BUNDLE_FORMAT_VERSION};
+use crate::commands::canvas_export::CANVAS_EXPORT_DIR;
+use crate::commands::canvas_import::{read_canvas_payload, resolve_canvas_path};
+use crate::commands::content_hash::sha256_hex;
+use crate::commands::forge_updates::read_forge_version;
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{ForgeStudioError, PipelineBundleFile, PipelineCanvasBundleImport};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::State;
+
+/// Extracted bundles live here, under the data root's canvas export directory.
+const BUNDLE_EXTRACT_DIR: &str = "bundles";
+
+#[derive(Deserialize)]
+struct CanvasBundle {
+    bundle_format_version: u32,
+    forge_version: Option<String>,
+    files: Vec<PipelineBundleFile>,
+    canvas: Value,
+    /// Base64 file contents keyed by bundle path.
+    contents: BTreeMap<String, String>,
+}
+
+#[tauri::command]
+pub async fn import_canvas_bundle(
+    data_root: String,
+    path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasBundleImport, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let forge_binary = settings.current().forge_binary;
+    run_blocking(move || import_bundle(&data_root, &path, &roots, &forge_binary)).await
+}
+
+fn import_bundle(
+    data_root: &str,
+    path: &str,
+    roots: &WorkspaceRoots,
+    forge_binary: &str,
+) -> Result<PipelineCanvasBundleImport, ForgeStudioError> {
+    let data_root = resolve_data_root_text(data_root, roots)?;
+    let source_path = resolve_canvas_path(&data_root, path, roots)?;
+    let serialized = fs::read_to_string(&source_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Canvas bundle import failed: could not read {}: {error}",
+            source_path.display()
+        ))
+    })?;
+    let bundle: CanvasBundle = serde_json::from_str(&serialized).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Canvas bundle import failed: {} is not a canvas bundle: {error}. Pick a file saved by bundle export.",
+            source_path.display()
+        ))
+    })?;
+    if bundle.bundle_format_version > CANVAS_BUNDLE_FORMAT_VERSION {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Canvas bundle import failed: bundle format {} is newer than supported format {CANVAS_BUNDLE_FORMAT_VERSION}. Update Forge Studio to open it.",
+            bundle.bundle_format_version
+        )));
+    }
+    let (_, mut canvas) = read_canvas_payload(bundle.canvas)?;
+    let bundle_name = source_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let files_dir = Path::new(&data_root)
+        .join(CANVAS_EXPORT_DIR)
+        .join(BUNDLE_EXTRACT_DIR)
+        .join(bundle_name);
+    for file in &bundle.files {
+        let extracted_path = extract_file(&files_dir, file, &bundle.contents)?;
+        let node = canvas.nodes.iter_mut().find(|node| node.id == file.node_id);
+        if let Some(value) = node.and_then(|node| node.config.get_mut(&file.field)) {
+            if *value == file.bundle_path {
+                *value = extracted_path.display().to_string();
+            }
+        }
+    }
+    let installed_forge_version = read_forge_version(forge_binary);
+    let warnings = version_warnings(
+        bundle.forge_version.as_deref(),
+        installed_forge_version.as_deref(),
+    );
+    Ok(PipelineCanvasBundleImport {
+        source_path: source_path.display().to_string(),
+        files_dir: files_dir.display().to_string(),
+        bundle_forge_version: bundle.forge_version,
+        installed_forge_version,
+        start_node_id: canvas.start_node_id,
+        nodes: canvas.nodes,
+        edges: canvas.edges,
+        groups: canvas.groups,
+        warnings,
+    })
+}
+
+/// Writes one bundled file after checking its path and checksum.
+fn extract_file(
+    files_dir: &Path,
+    file: &PipelineBundleFile,
+    contents: &BTreeMap<String, String>,
+) -> Result<PathBuf, ForgeStudioError> {
+    let failed = |reason: &str| {
+        format!(
+            "Canvas bundle import failed: bundled file '{}' {reason}",
+            file.bundle_path
+        )
+    };
+    let relative_path = Path::new(&file.bundle_path);
+    let mut components = relative_path.components();
+    let inside_files_dir = components.next() == Some(Component::Normal(BUNDLE_FILES_DIR.as_ref()))
+        && components.all(|component| matches!(component, Component::Normal(_)));
+    if !inside_files_dir {
+        return Err(ForgeStudioError::parse(failed(
+            "points outside the bundle's files directory. Re-export the bundle.",
+        )));
+    }
+    let bytes = contents
+        .get(&file.bundle_path)
+        .and_then(|encoded| BASE64_STANDARD.decode(encoded).ok())
+        .ok_or_else(|| {
+            ForgeStudioError::parse(failed("is missing or not base64. Re-export the bundle."))
+        })?;
+    if sha256_hex(&bytes) != file.sha256 {
+        return Err(ForgeStudioError::parse(failed(
+            "does not match its checksum. The bundle is corrupt; re-export it.",
+        )));
+    }
+    let target_path = files_dir.join(relative_path);
+    if let Some(parent_dir) = target_path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            ForgeStudioError::io(failed(&format!("could not be extracted: {error}")))
+        })?;
+    }
+    fs::write(&target_path, bytes).map_err(|error| {
+        ForgeStudioError::io(failed(&format!("could not be extracted: {error}")))
+    })?;
+    Ok(target_path)
+}
+
+fn version_warnings(bundle_version: Option<&str>, installed_version: Option<&str>) -> Vec<String> {
+    match (bundle_version, installed_version) {
+        (Some(bundle_version), Some(installed_version)) if bundle_version != installed_version => {
+            vec![format!(
+                "Bundle was exported with forge {bundle_version}, but forge {installed_version} is installed. Check node options before running."
+            )]
+        }
+        (Some(bundle_version), None) => vec![format!(
+            "Bundle needs forge {bundle_version}, but the installed forge version could not be read. Check pyproject.toml in the workspace."
+        )],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::import_bundle;
+    use crate::commands::canvas_bundle::build_bundle;
+    use crate::commands::path_safety::WorkspaceRoots;
+    use crate::models::{AppSettings, PipelineCanvasState, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+    use std::fs;
+
+    #[test]
+    fn bundle_round_trip_extracts_referenced_files() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-bundle-{}", std::process::id()));
+        fs::create_dir_all(&data_root).expect("temp dir should be created");
+        let architecture_path = data_root.join("arch.json");
+        fs::write(&architecture_path, "{\"layers\": 2}").expect("architecture should be written");
+        let config = BTreeMap::from([
+            ("dataset".to_string(), "demo".to_string()),
+            ("output_dir".to_string(), "out/run".to_string()),
+            (
+                "architecture_file".to_string(),
+                architecture_path.display().to_string(),
+            ),
+            (
+                "custom_loop_file".to_string(),
+                "missing/loop.py".to_string(),
+            ),
+        ]);
+        let canvas = PipelineCanvasState {
+            start_node_id: Some("train 1".to_string()),
+            nodes: vec![PipelineNodeSnapshot {
+                id: "train 1".to_string(),
+                node_type: "train".to_string(),
+                title: "Train".to_string(),
+                canvas_x: 0.0,
+                canvas_y: 0.0,
+                config,
+            }],
+            edges: vec![],
+            groups: vec![],
+        };
+        let data_root_text = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&data_root_text));
+        let forge_binary = AppSettings::default().forge_binary;
+        let (serialized, exported) =
+            build_bundle(&data_root_text, canvas, &forge_binary).expect("bundle should build");
+        fs::write(data_root.join("share.forgebundle"), serialized)
+            .expect("bundle should be written");
+        assert_eq!(
+            exported.files[0].bundle_path,
+            "files/train_1/architecture_file/arch.json"
+        );
+        assert_eq!(exported.warnings.len(), 1);
+
+        fs::remove_file(&architecture_path).expect("original should be removed");
+        let imported = import_bundle(&data_root_text, "share.forgebundle", &roots, &forge_binary)
+            .expect("bundle should import");
+        let extracted = &imported.nodes[0].config["architecture_file"];
+        assert_ne!(*extracted, architecture_path.display().to_string());
+        assert_eq!(
+            fs::read_to_string(extracted).expect("extracted file should exist"),
+            "{\"layers\": 2}"
+        );
+        assert_eq!(
+            imported.nodes[0].config["custom_loop_file"],
+            "missing/loop.py"
+        );
+        assert!(imported.warnings.is_empty());
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
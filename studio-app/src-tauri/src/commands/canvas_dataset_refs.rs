@@ -0,0 +1,147 @@
+//! Checks canvas nodes' dataset and version references against the catalog.
+//!
+//! A pipeline that names a dataset or version that no longer exists would
+//! only fail once its node starts, possibly after a long training step, so
+//! export and run report stale references up front. They are warnings, not
+//! errors, because the data may legitimately appear before the node runs.
+//! Datasets an ingest node in the same canvas writes count as existing,
+//! and ingest nodes themselves are skipped since they create datasets.
+//! Names still holding a `${variable}` are unknown until a run substitutes them.
+
+use crate::commands::canvas_validation::issue;
+use crate::commands::canvas_variables::has_placeholder;
+use crate::commands::dataset_store_io::{dataset_root, read_catalog};
+use crate::models::{PipelineCanvasIssue, PipelineNodeSnapshot};
+use serde_json::Value;
+use std::collections::HashSet;
+
+pub(crate) const STALE_REFERENCE_KIND: &str = "stale_reference";
+
+pub(crate) fn check_dataset_references(
+    data_root: &str,
+    nodes: &[PipelineNodeSnapshot],
+) -> Vec<PipelineCanvasIssue> {
+    let ingested: HashSet<&str> = nodes
+        .iter()
+        .filter(|node| node.node_type == "ingest")
+        .map(|node| config_value(node, "dataset"))
+        .collect();
+    let mut issues = Vec::new();
+    for node in nodes.iter().filter(|node| node.node_type != "ingest") {
+        let dataset_name = config_value(node, "dataset");
+        if dataset_name.is_empty()
+            || ingested.contains(dataset_name)
+            || has_placeholder(dataset_name)
+        {
+            continue;
+        }
+        let Ok(catalog) = read_catalog(&dataset_root(data_root, dataset_name)) else {
+            let message = format!(
+                "Node '{}' field 'dataset' references dataset '{dataset_name}', which does not exist in {data_root}. Ingest it first or fix the name.",
+                node.id
+            );
+            issues.push(stale_reference(node, "dataset", message));
+            continue;
+        };
+        let version_id = config_value(node, "version_id");
+        if version_id.is_empty()
+            || has_placeholder(version_id)
+            || catalog_has_version(&catalog, version_id)
+        {
+            continue;
+        }
+        let latest = catalog
+            .get("latest_version")
+            .and_then(Value::as_str)
+            .unwrap_or("none");
+        let message = format!(
+            "Node '{}' field 'version_id' references version '{version_id}', which dataset '{dataset_name}' does not have. Use an existing version such as the latest, '{latest}'.",
+            node.id
+        );
+        issues.push(stale_reference(node, "version_id", message));
+    }
+    issues
+}
+
+fn catalog_has_version(catalog: &Value, version_id: &str) -> bool {
+    catalog
+        .get("versions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .any(|version| version.get("version_id").and_then(Value::as_str) == Some(version_id))
+}
+
+fn stale_reference(
+    node: &PipelineNodeSnapshot,
+    field: &str,
+    message: String,
+) -> PipelineCanvasIssue {
+    PipelineCanvasIssue {
+        field: Some(field.to_string()),
+        ..issue(STALE_REFERENCE_KIND, vec![node.id.as_str()], message)
+    }
+}
+
+fn config_value<'a>(node: &'a PipelineNodeSnapshot, key: &str) -> &'a str {
+    node.config.get(key).map_or("", |value| value.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_dataset_references;
+    use crate::models::PipelineNodeSnapshot;
+    use serde_json::json;
+    use std::fs;
+
+    fn node(id: &str, node_type: &str, config: &[(&str, &str)]) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: config
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn check_dataset_references_flags_missing_datasets_and_versions() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-dataset-refs-{}", std::process::id()));
+        let news_root = data_root.join("datasets").join("news");
+        fs::create_dir_all(&news_root).expect("dataset dir should be created");
+        let catalog = json!({"latest_version": "v2", "versions": [{"version_id": "v2"}]});
+        fs::write(news_root.join("catalog.json"), catalog.to_string()).expect("catalog written");
+        let nodes = vec![
+            node("ingest", "ingest", &[("dataset", "fresh")]),
+            node("filter", "filter", &[("dataset", "fresh")]),
+            node(
+                "train",
+                "train",
+                &[("dataset", "news"), ("version_id", "v2")],
+            ),
+            node(
+                "chat",
+                "chat",
+                &[("dataset", "news"), ("version_id", "v12")],
+            ),
+            node("export", "export", &[("dataset", "gone")]),
+        ];
+        let data_root_text = data_root.display().to_string();
+        let issues = check_dataset_references(&data_root_text, &nodes);
+        let flagged: Vec<(&str, Option<&str>)> = issues
+            .iter()
+            .map(|issue| (issue.node_ids[0].as_str(), issue.field.as_deref()))
+            .collect();
+        assert_eq!(
+            flagged,
+            [("chat", Some("version_id")), ("export", Some("dataset"))]
+        );
+        assert!(issues.iter().all(|issue| issue.severity == "warning"));
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
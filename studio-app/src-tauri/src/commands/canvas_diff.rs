@@ -0,0 +1,206 @@
+//! Canvas diff for reviewing what changed between two saved pipelines.
+//!
+//! Both files are loaded through canvas import, so canvases saved in older
+//! formats compare in the current shape. Nodes and edges are matched by id.
+//! Position-only changes are listed apart from modifications because
+//! rearranging the layout does not change what the pipeline runs.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_import::read_canvas_file;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{
+    ConfigKeyChange, ForgeStudioError, PipelineCanvasDiff, PipelineCanvasImport, PipelineEdgeDiff,
+    PipelineEdgeSnapshot, PipelineNodeDiff, PipelineNodeSnapshot,
+};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use tauri::State;
+
+#[tauri::command]
+pub fn diff_canvases(
+    data_root: String,
+    path_a: String,
+    path_b: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasDiff, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let canvas_a = read_canvas_file(&data_root, &path_a, &roots)?;
+    let canvas_b = read_canvas_file(&data_root, &path_b, &roots)?;
+    Ok(diff_canvas_imports(canvas_a, canvas_b))
+}
+
+fn diff_canvas_imports(
+    canvas_a: PipelineCanvasImport,
+    canvas_b: PipelineCanvasImport,
+) -> PipelineCanvasDiff {
+    let start_node_change = (canvas_a.start_node_id != canvas_b.start_node_id).then(|| {
+        key_change(
+            "start_node_id",
+            canvas_a.start_node_id.as_deref(),
+            canvas_b.start_node_id.as_deref(),
+        )
+    });
+    let mut diff = PipelineCanvasDiff {
+        path_a: canvas_a.source_path,
+        path_b: canvas_b.source_path,
+        start_node_change,
+        added_nodes: Vec::new(),
+        removed_nodes: Vec::new(),
+        modified_nodes: Vec::new(),
+        moved_node_ids: Vec::new(),
+        added_edges: Vec::new(),
+        removed_edges: Vec::new(),
+        modified_edges: Vec::new(),
+    };
+    diff_nodes(&mut diff, &canvas_a.nodes, &canvas_b.nodes);
+    diff_edges(&mut diff, &canvas_a.edges, &canvas_b.edges);
+    diff
+}
+
+fn diff_nodes(
+    diff: &mut PipelineCanvasDiff,
+    nodes_a: &[PipelineNodeSnapshot],
+    nodes_b: &[PipelineNodeSnapshot],
+) {
+    let by_id_a: HashMap<&str, &PipelineNodeSnapshot> = nodes_a
+        .iter()
+        .map(|node| (node.id.as_str(), node))
+        .collect();
+    let by_id_b: HashMap<&str, &PipelineNodeSnapshot> = nodes_b
+        .iter()
+        .map(|node| (node.id.as_str(), node))
+        .collect();
+    diff.removed_nodes = nodes_a
+        .iter()
+        .filter(|node| !by_id_b.contains_key(node.id.as_str()))
+        .cloned()
+        .collect();
+    for after in nodes_b {
+        let Some(before) = by_id_a.get(after.id.as_str()) else {
+            diff.added_nodes.push(after.clone());
+            continue;
+        };
+        let attribute_changes: Vec<ConfigKeyChange> = [
+            ("type", &before.node_type, &after.node_type),
+            ("title", &before.title, &after.title),
+        ]
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(key, old, new)| key_change(key, Some(old), Some(new)))
+        .collect();
+        let config_changes = config_changes(before, after);
+        if !attribute_changes.is_empty() || !config_changes.is_empty() {
+            diff.modified_nodes.push(PipelineNodeDiff {
+                node_id: after.id.clone(),
+                node_type: after.node_type.clone(),
+                title: after.title.clone(),
+                attribute_changes,
+                config_changes,
+            });
+        } else if (before.canvas_x, before.canvas_y) != (after.canvas_x, after.canvas_y) {
+            diff.moved_node_ids.push(after.id.clone());
+        }
+    }
+}
+
+/// Config keys whose value was added, removed, or changed, in key order.
+fn config_changes(
+    before: &PipelineNodeSnapshot,
+    after: &PipelineNodeSnapshot,
+) -> Vec<ConfigKeyChange> {
+    let keys: BTreeSet<&String> = before.config.keys().chain(after.config.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let old = before.config.get(key);
+            let new = after.config.get(key);
+            (old != new).then(|| key_change(key, old.map(String::as_str), new.map(String::as_str)))
+        })
+        .collect()
+}
+
+fn diff_edges(
+    diff: &mut PipelineCanvasDiff,
+    edges_a: &[PipelineEdgeSnapshot],
+    edges_b: &[PipelineEdgeSnapshot],
+) {
+    let by_id_a: HashMap<&str, &PipelineEdgeSnapshot> = edges_a
+        .iter()
+        .map(|edge| (edge.id.as_str(), edge))
+        .collect();
+    let by_id_b: HashMap<&str, &PipelineEdgeSnapshot> = edges_b
+        .iter()
+        .map(|edge| (edge.id.as_str(), edge))
+        .collect();
+    diff.removed_edges = edges_a
+        .iter()
+        .filter(|edge| !by_id_b.contains_key(edge.id.as_str()))
+        .cloned()
+        .collect();
+    for after in edges_b {
+        match by_id_a.get(after.id.as_str()) {
+            None => diff.added_edges.push(after.clone()),
+            Some(before) if *before != after => diff.modified_edges.push(PipelineEdgeDiff {
+                edge_id: after.id.clone(),
+                before: (*before).clone(),
+                after: after.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+}
+
+fn key_change(key: &str, before: Option<&str>, after: Option<&str>) -> ConfigKeyChange {
+    ConfigKeyChange {
+        key: key.to_string(),
+        before: before.map(|value| Value::String(value.to_string())),
+        after: after.map(|value| Value::String(value.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_canvas_imports;
+    use crate::commands::canvas_import::read_canvas_file;
+    use crate::commands::path_safety::WorkspaceRoots;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn diff_canvases_reports_node_edge_and_config_changes() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-diff-{}", std::process::id()));
+        fs::create_dir_all(&data_root).expect("temp data root should be created");
+        let node = |id: &str, x: f64, epochs: &str| {
+            json!({"id": id, "type": "train", "title": id, "canvas_x": x, "canvas_y": 0.0,
+                   "config": {"epochs": epochs}})
+        };
+        let canvas_a = json!({
+            "format_version": 1, "start_node_id": "a",
+            "nodes": [node("a", 0.0, "2"), node("b", 0.0, "2"), node("gone", 0.0, "2")],
+            "edges": [{"id": "e1", "source_node_id": "a", "target_node_id": "b"}]
+        });
+        let canvas_b = json!({
+            "format_version": 1, "start_node_id": "a",
+            "nodes": [node("a", 0.0, "4"), node("b", 90.0, "2"), node("new", 0.0, "2")],
+            "edges": [{"id": "e1", "source_node_id": "a", "target_node_id": "new"}]
+        });
+        fs::write(data_root.join("a.json"), canvas_a.to_string()).expect("canvas a written");
+        fs::write(data_root.join("b.json"), canvas_b.to_string()).expect("canvas b written");
+        let root = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&root));
+        let read = |path: &str| read_canvas_file(&root, path, &roots).expect("canvas should load");
+        let diff = diff_canvas_imports(read("a.json"), read("b.json"));
+        assert!(diff.start_node_change.is_none());
+        assert_eq!(diff.added_nodes[0].id, "new");
+        assert_eq!(diff.removed_nodes[0].id, "gone");
+        assert_eq!(diff.modified_nodes[0].node_id, "a");
+        assert_eq!(
+            diff.modified_nodes[0].config_changes[0].after,
+            Some(json!("4"))
+        );
+        assert_eq!(diff.moved_node_ids, ["b"]);
+        assert_eq!(diff.modified_edges[0].after.target_node_id, "new");
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
@@ -0,0 +1,288 @@
+//! Undo and redo journal for canvas edits, kept on disk per canvas file.
+//!
+//! The editor pushes each edit with the canvas before and after it, so undo
+//! history survives a reload and the journal can be replayed to audit how a
+//! canvas reached its current shape. Journals live under
+//! `outputs/canvas/.journal/`, named by a hash of the canvas path so any
+//! path maps to a safe file name. Pushing after an undo drops the undone
+//! edits, as editors do, and only the newest `canvas_edits` edits are kept.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_export::CANVAS_EXPORT_DIR;
+use crate::commands::canvas_import::resolve_canvas_path;
+use crate::commands::content_hash::sha256_hex;
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::run_store_io::{read_json_file, resolve_data_root_text, write_json_file};
+use crate::models::{
+    ForgeStudioError, PipelineCanvasEdit, PipelineCanvasEditCursor, PipelineCanvasEditJournal,
+    PipelineCanvasEditStep, PipelineCanvasState,
+};
+use chrono::{SecondsFormat, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const JOURNAL_DIR_NAME: &str = ".journal";
+/// Hex digits of the path hash used as the journal file name.
+const JOURNAL_KEY_LENGTH: usize = 16;
+
+#[tauri::command]
+pub fn push_canvas_edit(
+    data_root: String,
+    canvas_path: String,
+    label: String,
+    before: PipelineCanvasState,
+    after: PipelineCanvasState,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasEditCursor, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let mut journal = load_journal(&data_root, &canvas_path, &roots)?;
+    if push_edit(&mut journal, label, before, after) {
+        keep_newest_edits(&mut journal, settings.current().cache_sizes.canvas_edits);
+        save_journal(&data_root, &journal)?;
+    }
+    Ok(cursor(&journal))
+}
+
+/// Steps back one edit and returns the canvas as it was before it.
+#[tauri::command]
+pub fn undo_canvas_edit(
+    data_root: String,
+    canvas_path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasEditStep, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let mut journal = load_journal(&data_root, &canvas_path, &roots)?;
+    let canvas = step_back(&mut journal)?;
+    save_journal(&data_root, &journal)?;
+    Ok(PipelineCanvasEditStep {
+        canvas,
+        cursor: cursor(&journal),
+    })
+}
+
+/// Reapplies the most recently undone edit and returns the canvas after it.
+#[tauri::command]
+pub fn redo_canvas_edit(
+    data_root: String,
+    canvas_path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasEditStep, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let mut journal = load_journal(&data_root, &canvas_path, &roots)?;
+    let canvas = step_forward(&mut journal)?;
+    save_journal(&data_root, &journal)?;
+    Ok(PipelineCanvasEditStep {
+        canvas,
+        cursor: cursor(&journal),
+    })
+}
+
+/// The full journal, oldest edit first, for auditing or replay.
+#[tauri::command]
+pub fn get_canvas_edit_journal(
+    data_root: String,
+    canvas_path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasEditJournal, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    load_journal(&data_root, &canvas_path, &roots)
+}
+
+/// Appends an edit after the cursor, dropping undone ones; false when nothing changed.
+fn push_edit(
+    journal: &mut PipelineCanvasEditJournal,
+    label: String,
+    before: PipelineCanvasState,
+    after: PipelineCanvasState,
+) -> bool {
+    let unchanged = serde_json::to_value(&before).ok() == serde_json::to_value(&after).ok();
+    if unchanged {
+        return false;
+    }
+    journal.edits.truncate(journal.position);
+    let edit_id = journal.edits.last().map_or(1, |edit| edit.edit_id + 1);
+    journal.edits.push(PipelineCanvasEdit {
+        edit_id,
+        label: label.trim().to_string(),
+        applied_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        before,
+        after,
+    });
+    journal.position = journal.edits.len();
+    true
+}
+
+/// Drops the oldest edits past `max_edits`; call it right after `push_edit`.
+fn keep_newest_edits(journal: &mut PipelineCanvasEditJournal, max_edits: usize) {
+    let overflow = journal.edits.len().saturating_sub(max_edits);
+    journal.edits.drain(..overflow);
+    journal.position = journal.edits.len();
+}
+
+fn step_back(
+    journal: &mut PipelineCanvasEditJournal,
+) -> Result<PipelineCanvasState, ForgeStudioError> {
+    if journal.position == 0 {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Nothing to undo for {}. Make an edit first.",
+            journal.canvas_path
+        )));
+    }
+    journal.position -= 1;
+    Ok(journal.edits[journal.position].before.clone())
+}
+
+fn step_forward(
+    journal: &mut PipelineCanvasEditJournal,
+) -> Result<PipelineCanvasState, ForgeStudioError> {
+    let Some(edit) = journal.edits.get(journal.position) else {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Nothing to redo for {}. Undo an edit first.",
+            journal.canvas_path
+        )));
+    };
+    let canvas = edit.after.clone();
+    journal.position += 1;
+    Ok(canvas)
+}
+
+/// A canvas without a journal yet has an empty one.
+fn load_journal(
+    data_root: &str,
+    canvas_path: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PipelineCanvasEditJournal, ForgeStudioError> {
+    let canvas_path = resolve_canvas_path(data_root, canvas_path, roots)?
+        .display()
+        .to_string();
+    let path = journal_path(data_root, &canvas_path);
+    if !path.is_file() {
+        return Ok(PipelineCanvasEditJournal {
+            canvas_path,
+            position: 0,
+            edits: vec![],
+        });
+    }
+    let mut journal: PipelineCanvasEditJournal = serde_json::from_value(read_json_file(&path)?)
+        .map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Canvas edit journal {} is corrupt: {error}. Delete it to start a new history.",
+                path.display()
+            ))
+        })?;
+    journal.position = journal.position.min(journal.edits.len());
+    Ok(journal)
+}
+
+fn save_journal(
+    data_root: &str,
+    journal: &PipelineCanvasEditJournal,
+) -> Result<(), ForgeStudioError> {
+    let path = journal_path(data_root, &journal.canvas_path);
+    if let Some(parent_dir) = path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to create {}: {error}",
+                parent_dir.display()
+            ))
+        })?;
+    }
+    let payload = serde_json::to_value(journal).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize canvas edit journal: {error}"))
+    })?;
+    write_json_file(&path, &payload)
+}
+
+fn journal_path(data_root: &str, canvas_path: &str) -> PathBuf {
+    let key = &sha256_hex(canvas_path.as_bytes())[..JOURNAL_KEY_LENGTH];
+    Path::new(data_root)
+        .join(CANVAS_EXPORT_DIR)
+        .join(JOURNAL_DIR_NAME)
+        .join(format!("{key}.json"))
+}
+
+fn cursor(journal: &PipelineCanvasEditJournal) -> PipelineCanvasEditCursor {
+    let label = |index: usize| journal.edits.get(index).map(|edit| edit.label.clone());
+    PipelineCanvasEditCursor {
+        canvas_path: journal.canvas_path.clone(),
+        position: journal.position,
+        edit_count: journal.edits.len(),
+        undo_label: journal.position.checked_sub(1).and_then(label),
+        redo_label: label(journal.position),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        keep_newest_edits, load_journal, push_edit, save_journal, step_back, step_forward,
+    };
+    use crate::commands::path_safety::WorkspaceRoots;
+    use crate::models::PipelineCanvasState;
+
+    fn canvas(start_node_id: &str) -> PipelineCanvasState {
+        PipelineCanvasState {
+            start_node_id: Some(start_node_id.to_string()),
+            nodes: vec![],
+            edges: vec![],
+            groups: vec![],
+        }
+    }
+
+    #[test]
+    fn journal_undoes_and_redoes_across_calls() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-journal-{}", std::process::id()));
+        let root = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&root));
+        let mut journal = load_journal(&root, "a.json", &roots).expect("journal should load");
+        assert!(push_edit(
+            &mut journal,
+            "First".into(),
+            canvas("a"),
+            canvas("b")
+        ));
+        assert!(push_edit(
+            &mut journal,
+            "Second".into(),
+            canvas("b"),
+            canvas("c")
+        ));
+        assert!(!push_edit(
+            &mut journal,
+            "Noop".into(),
+            canvas("c"),
+            canvas("c")
+        ));
+        let undone = step_back(&mut journal).expect("undo should work");
+        assert_eq!(undone.start_node_id.as_deref(), Some("b"));
+        save_journal(&root, &journal).expect("journal should save");
+
+        let mut journal = load_journal(&root, "a.json", &roots).expect("journal should reload");
+        assert_eq!(journal.position, 1);
+        let redone = step_forward(&mut journal).expect("redo should work");
+        assert_eq!(redone.start_node_id.as_deref(), Some("c"));
+        step_back(&mut journal).expect("undo should work");
+        assert!(push_edit(
+            &mut journal,
+            "Branch".into(),
+            canvas("b"),
+            canvas("d")
+        ));
+        assert_eq!((journal.position, journal.edits.len()), (2, 2));
+        keep_newest_edits(&mut journal, 1);
+        assert_eq!(
+            (journal.position, journal.edits[0].label.as_str()),
+            (1, "Branch")
+        );
+        assert!(step_forward(&mut journal).is_err());
+        let mut other = load_journal(&root, "b.json", &roots).expect("journal should load");
+        assert!(step_back(&mut other).is_err());
+        std::fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -1,124 +1,254 @@
 //! Canvas export command for persisting pipeline layout from Studio.
+//!
+//! The default `json` format is the canvas file Studio re-imports. The
+//! `yaml-pipeline` format writes a run spec for `forge run-spec` instead,
+//! so a pipeline can be committed to git and run headlessly. JSON exports
+//! carry a manifest of the `${variable}` placeholders a run must fill in,
+//! and a provenance block with the writing versions and a graph checksum.
+//! Files are written to a hidden temp file and renamed into place, and an
+//! existing export is only replaced when the caller asks to overwrite it.
 
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::canvas_dataset_refs::check_dataset_references;
+use crate::commands::canvas_export_files::{
+    create_parent_dir, resolve_output_path, write_export_file,
+};
+use crate::commands::canvas_groups::{check_node_groups, INVALID_GROUP_KIND};
+use crate::commands::canvas_provenance::export_provenance;
+use crate::commands::canvas_run_spec::build_run_spec_yaml;
+use crate::commands::canvas_validation::{validate_canvas, STRUCTURAL_ISSUE_KINDS};
+use crate::commands::canvas_variables::canvas_variables;
+use crate::commands::node_config_validation::check_node_configs;
+use crate::commands::run_store_io::resolve_data_root_text;
 use crate::models::{
-    PipelineCanvasExportResult, PipelineEdgeSnapshot, PipelineNodeSnapshot,
+    ForgeStudioError, PipelineCanvasExportEntry, PipelineCanvasExportResult, PipelineCanvasState,
 };
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde_json::{json, Value};
 use std::fs;
-use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::path::Path;
+use tauri::State;
 
-const CANVAS_EXPORT_DIR: &str = "outputs/canvas";
-const CANVAS_EXPORT_FORMAT_VERSION: u32 = 1;
+pub(crate) const CANVAS_EXPORT_DIR: &str = "outputs/canvas";
+/// Version 2 added optional edge conditions and version 3 node groups.
+pub(crate) const CANVAS_EXPORT_FORMAT_VERSION: u32 = 3;
+const JSON_EXPORT_FORMAT: &str = "json";
+const YAML_PIPELINE_EXPORT_FORMAT: &str = "yaml-pipeline";
 
 #[tauri::command]
-pub fn export_pipeline_canvas(
+pub async fn export_pipeline_canvas(
     data_root: String,
-    nodes: Vec<PipelineNodeSnapshot>,
-    edges: Vec<PipelineEdgeSnapshot>,
-    start_node_id: Option<String>,
+    canvas: PipelineCanvasState,
     output_path: Option<String>,
-) -> Result<PipelineCanvasExportResult, String> {
-    validate_canvas_payload(&nodes, &edges)?;
-    let output_path = resolve_output_path(&data_root, output_path)?;
-    create_parent_dir(&output_path)?;
-    let payload = build_canvas_payload(nodes, edges, start_node_id)?;
-    write_export_file(&output_path, &payload)?;
-    Ok(PipelineCanvasExportResult {
-        output_path: output_path.display().to_string(),
+    format: Option<String>,
+    overwrite: Option<bool>,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasExportResult, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let forge_binary = settings.current().forge_binary;
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        let warnings = check_dataset_references(&data_root, &canvas.nodes);
+        let (serialized, extension) =
+            serialize_canvas(&data_root, canvas, format.as_deref(), &forge_binary)?;
+        let output_path = resolve_output_path(&data_root, output_path, extension, &roots)?;
+        create_parent_dir(&output_path)?;
+        write_export_file(&output_path, &serialized, overwrite.unwrap_or(false))?;
+        Ok(PipelineCanvasExportResult {
+            output_path: output_path.display().to_string(),
+            warnings,
+        })
     })
+    .await
 }
 
-fn validate_canvas_payload(
-    nodes: &[PipelineNodeSnapshot],
-    edges: &[PipelineEdgeSnapshot],
-) -> Result<(), String> {
-    for node in nodes {
-        if node.id.trim().is_empty() {
-            return Err("Canvas export failed: node id cannot be empty.".to_string());
+/// The canvas in the requested export format, with that format's file extension.
+fn serialize_canvas(
+    data_root: &str,
+    canvas: PipelineCanvasState,
+    format: Option<&str>,
+    forge_binary: &str,
+) -> Result<(String, &'static str), ForgeStudioError> {
+    match format.map(str::trim).unwrap_or_default() {
+        "" | JSON_EXPORT_FORMAT => {
+            validate_canvas_payload(&canvas)?;
+            let payload = build_canvas_payload(data_root, canvas, forge_binary)?;
+            let serialized = serde_json::to_string_pretty(&payload).map_err(|error| {
+                ForgeStudioError::internal(format!(
+                    "Canvas export failed: could not serialize canvas payload: {error}"
+                ))
+            })?;
+            Ok((serialized, "json"))
         }
-    }
-    for edge in edges {
-        if edge.source_node_id.trim().is_empty() || edge.target_node_id.trim().is_empty() {
-            return Err("Canvas export failed: edge source/target ids cannot be empty.".to_string());
+        YAML_PIPELINE_EXPORT_FORMAT => {
+            let yaml =
+                build_run_spec_yaml(&canvas.nodes, &canvas.edges, canvas.start_node_id.as_deref())?;
+            Ok((yaml, "yaml"))
         }
+        other => Err(ForgeStudioError::invalid_input(format!(
+            "Canvas export failed: unknown format '{other}'. Use '{JSON_EXPORT_FORMAT}' or '{YAML_PIPELINE_EXPORT_FORMAT}'."
+        ))),
     }
-    Ok(())
-}
-
-fn create_parent_dir(output_path: &Path) -> Result<(), String> {
-    let Some(parent_dir) = output_path.parent() else {
-        return Err(format!(
-            "Canvas export failed: output path {} is invalid.",
-            output_path.display()
-        ));
-    };
-    fs::create_dir_all(parent_dir).map_err(|error| {
-        format!(
-            "Canvas export failed: could not create export directory {}: {error}",
-            parent_dir.display()
-        )
-    })
-}
-
-fn build_default_output_path(export_dir: &Path) -> Result<PathBuf, String> {
-    let epoch_seconds = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|error| format!("Canvas export failed: system clock is invalid: {error}"))?
-        .as_secs();
-    Ok(export_dir.join(format!("forge-canvas-{epoch_seconds}.json")))
 }
 
-fn resolve_output_path(data_root: &str, output_path: Option<String>) -> Result<PathBuf, String> {
-    if let Some(path_value) = output_path {
-        let trimmed_path = path_value.trim();
-        if !trimmed_path.is_empty() {
-            let requested_path = PathBuf::from(trimmed_path);
-            let normalized_path = if requested_path.is_absolute() {
-                requested_path
-            } else {
-                Path::new(data_root).join(requested_path)
-            };
-            return Ok(append_json_extension_if_missing(normalized_path));
-        }
-    }
-    let export_dir = Path::new(data_root).join(CANVAS_EXPORT_DIR);
-    build_default_output_path(&export_dir)
-}
-
-fn append_json_extension_if_missing(mut output_path: PathBuf) -> PathBuf {
-    if output_path.extension().is_none() {
-        output_path.set_extension("json");
+/// Rejects issues that would make the file unloadable or its nodes unrunnable,
+/// such as edges or a start node naming nodes that do not exist, listing every
+/// one; cycles and groups with a path back into them may still be mid-edit.
+pub(crate) fn validate_canvas_payload(
+    canvas: &PipelineCanvasState,
+) -> Result<(), ForgeStudioError> {
+    let (nodes, edges) = (&canvas.nodes, &canvas.edges);
+    let start_node_id = canvas
+        .start_node_id
+        .as_deref()
+        .filter(|start_node_id| !start_node_id.trim().is_empty());
+    let mut rejected: Vec<String> = validate_canvas(nodes, edges, start_node_id)
+        .into_iter()
+        .chain(check_node_groups(nodes, edges, &canvas.groups))
+        .filter(|issue| {
+            STRUCTURAL_ISSUE_KINDS.contains(&issue.kind.as_str())
+                || issue.kind == INVALID_GROUP_KIND
+        })
+        .map(|issue| issue.message)
+        .collect();
+    rejected.extend(
+        check_node_configs(nodes)
+            .into_iter()
+            .map(|issue| issue.message),
+    );
+    if rejected.is_empty() {
+        return Ok(());
     }
-    output_path
+    Err(ForgeStudioError::invalid_input(format!(
+        "Canvas export failed: {}",
+        rejected.join(" ")
+    )))
 }
 
-fn build_canvas_payload(
-    nodes: Vec<PipelineNodeSnapshot>,
-    edges: Vec<PipelineEdgeSnapshot>,
-    start_node_id: Option<String>,
-) -> Result<Value, String> {
-    let exported_unix_seconds = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|error| format!("Canvas export failed: system clock is invalid: {error}"))?
-        .as_secs();
+pub(crate) fn build_canvas_payload(
+    data_root: &str,
+    canvas: PipelineCanvasState,
+    forge_binary: &str,
+) -> Result<Value, ForgeStudioError> {
+    let exported_unix_seconds = Utc::now().timestamp();
     Ok(json!({
         "format_version": CANVAS_EXPORT_FORMAT_VERSION,
         "exported_unix_seconds": exported_unix_seconds,
-        "start_node_id": start_node_id,
-        "nodes": nodes,
-        "edges": edges
+        "provenance": export_provenance(data_root, &canvas.nodes, &canvas.edges, forge_binary),
+        "start_node_id": canvas.start_node_id,
+        "variables": canvas_variables(&canvas.nodes),
+        "nodes": canvas.nodes,
+        "edges": canvas.edges,
+        "groups": canvas.groups
     }))
 }
 
-fn write_export_file(output_path: &Path, payload: &Value) -> Result<(), String> {
-    let serialized = serde_json::to_string_pretty(payload).map_err(|error| {
-        format!("Canvas export failed: could not serialize canvas payload: {error}")
+/// Exports under the data root's canvas directory, newest first; autosave
+/// history and in-progress temp files are hidden and skipped.
+#[tauri::command]
+pub fn list_canvas_exports(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<PipelineCanvasExportEntry>, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    canvas_exports(Path::new(&data_root))
+}
+
+/// Exported canvases under an already resolved `data_root`.
+fn canvas_exports(data_root: &Path) -> Result<Vec<PipelineCanvasExportEntry>, ForgeStudioError> {
+    let export_dir = data_root.join(CANVAS_EXPORT_DIR);
+    if !export_dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let entries = fs::read_dir(&export_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", export_dir.display()))
     })?;
-    fs::write(output_path, serialized).map_err(|error| {
-        format!(
-            "Canvas export failed: could not write export file {}: {error}",
-            output_path.display()
-        )
-    })
+    let mut exports = Vec::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let format = match Path::new(&file_name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            Some("json") => JSON_EXPORT_FORMAT,
+            Some("yaml" | "yml") => YAML_PIPELINE_EXPORT_FORMAT,
+            _ => continue,
+        };
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if file_name.starts_with('.') || !metadata.is_file() {
+            continue;
+        }
+        exports.push(PipelineCanvasExportEntry {
+            path: entry.path().display().to_string(),
+            file_name,
+            format: format.to_string(),
+            size_bytes: metadata.len(),
+            modified_at: metadata.modified().ok().map(|modified| {
+                DateTime::<Utc>::from(modified).to_rfc3339_opts(SecondsFormat::Secs, true)
+            }),
+        });
+    }
+    exports.sort_by(|left, right| {
+        (&right.modified_at, &right.file_name).cmp(&(&left.modified_at, &left.file_name))
+    });
+    Ok(exports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canvas_exports, validate_canvas_payload, CANVAS_EXPORT_DIR};
+    use crate::commands::canvas_export_files::write_export_file;
+    use crate::models::PipelineCanvasState;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn payload_validation_lists_every_broken_reference() {
+        let canvas: PipelineCanvasState = serde_json::from_value(json!({
+            "start_node_id": "ghost",
+            "nodes": [{
+                "id": "ingest", "type": "ingest", "title": "Ingest",
+                "canvas_x": 0.0, "canvas_y": 0.0,
+                "config": {"dataset": "demo", "source_path": "data.jsonl"},
+            }],
+            "edges": [
+                {"id": "e1", "source_node_id": "ingest", "target_node_id": "filter"},
+                {"id": "e2", "source_node_id": "train", "target_node_id": "ingest"},
+            ],
+        }))
+        .expect("canvas should parse");
+        let error = validate_canvas_payload(&canvas).expect_err("canvas should be rejected");
+        for expected in ["Edge 'e1'", "Edge 'e2'", "Start node 'ghost'"] {
+            assert!(
+                error.to_string().contains(expected),
+                "{expected} missing from: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn export_refuses_to_overwrite_unless_asked() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-export-{}", std::process::id()));
+        let export_dir = data_root.join(CANVAS_EXPORT_DIR);
+        fs::create_dir_all(&export_dir).expect("export dir should be created");
+        let output_path = export_dir.join("nightly.json");
+        write_export_file(&output_path, "{}", false).expect("first export should be written");
+        assert!(write_export_file(&output_path, "{\"v\": 2}", false).is_err());
+        write_export_file(&output_path, "{\"v\": 2}", true).expect("overwrite should replace");
+        assert_eq!(
+            fs::read_to_string(&output_path).expect("export should be readable"),
+            "{\"v\": 2}"
+        );
+        let exports = canvas_exports(&data_root).expect("exports should be listed");
+        let names: Vec<&str> = exports
+            .iter()
+            .map(|entry| entry.file_name.as_str())
+            .collect();
+        assert_eq!(names, ["nightly.json"]);
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
 }
@@ -0,0 +1,80 @@
+//! Output files for canvas exports and bundles.
+//!
+//! Exports without an explicit path go to the data root's canvas export dir
+//! under a timestamped name. Relative paths resolve against the data root,
+//! and every path must stay inside a registered workspace.
+
+use crate::commands::atomic_write::write_file_atomic;
+use crate::commands::canvas_export::CANVAS_EXPORT_DIR;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::models::ForgeStudioError;
+use chrono::Utc;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) fn create_parent_dir(output_path: &Path) -> Result<(), ForgeStudioError> {
+    let Some(parent_dir) = output_path.parent() else {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Canvas export failed: output path {} is invalid.",
+            output_path.display()
+        )));
+    };
+    fs::create_dir_all(parent_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Canvas export failed: could not create export directory {}: {error}",
+            parent_dir.display()
+        ))
+    })
+}
+
+fn build_default_output_path(export_dir: &Path, extension: &str) -> PathBuf {
+    let epoch_seconds = Utc::now().timestamp();
+    export_dir.join(format!("forge-canvas-{epoch_seconds}.{extension}"))
+}
+
+pub(crate) fn resolve_output_path(
+    data_root: &str,
+    output_path: Option<String>,
+    extension: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PathBuf, ForgeStudioError> {
+    if let Some(path_value) = output_path {
+        let trimmed_path = path_value.trim();
+        if !trimmed_path.is_empty() {
+            let requested_path = PathBuf::from(trimmed_path);
+            let normalized_path = if requested_path.is_absolute() {
+                requested_path
+            } else {
+                Path::new(data_root).join(requested_path)
+            };
+            let output_path = append_extension_if_missing(normalized_path, extension);
+            return confine_path(&output_path, "Output path", roots);
+        }
+    }
+    let export_dir = Path::new(data_root).join(CANVAS_EXPORT_DIR);
+    Ok(build_default_output_path(&export_dir, extension))
+}
+
+fn append_extension_if_missing(mut output_path: PathBuf, extension: &str) -> PathBuf {
+    if output_path.extension().is_none() {
+        output_path.set_extension(extension);
+    }
+    output_path
+}
+
+/// Replaces the target atomically, so a reader never sees a half-written
+/// file and a failed write leaves an earlier export intact.
+pub(crate) fn write_export_file(
+    output_path: &Path,
+    serialized: &str,
+    overwrite: bool,
+) -> Result<(), ForgeStudioError> {
+    if output_path.exists() && !overwrite {
+        return Err(ForgeStudioError::conflict(format!(
+            "Canvas export failed: {} already exists. Pass overwrite: true to replace it or choose another path.",
+            output_path.display()
+        )));
+    }
+    write_file_atomic(output_path, serialized.as_bytes())
+        .map_err(|error| ForgeStudioError::io(format!("Canvas export failed: {error}")))
+}
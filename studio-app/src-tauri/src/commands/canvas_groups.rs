@@ -0,0 +1,198 @@
+//! Node groups, added in canvas format v3.
+//!
+//! A group is a collapsible container listing member nodes by id, which a
+//! run treats as one composite step: when a member fails, the rest of the
+//! group is skipped with it. Each node belongs to at most one group, and a
+//! group must be closed under its boundary: a path that leaves the group and
+//! comes back in would become a cycle once the group is collapsed, so it is
+//! a `group_reentry` error. Membership problems are `invalid_group` errors.
+
+use crate::commands::canvas_validation::issue;
+use crate::models::{
+    PipelineCanvasIssue, PipelineEdgeSnapshot, PipelineNodeGroup, PipelineNodeSnapshot,
+    PipelineRunNode,
+};
+use std::collections::{HashMap, HashSet};
+
+pub(crate) const INVALID_GROUP_KIND: &str = "invalid_group";
+const GROUP_REENTRY_KIND: &str = "group_reentry";
+
+/// Membership and boundary problems across all groups.
+pub(crate) fn check_node_groups(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+    groups: &[PipelineNodeGroup],
+) -> Vec<PipelineCanvasIssue> {
+    let node_ids: HashSet<&str> = nodes.iter().map(|node| node.id.as_str()).collect();
+    let mut issues = Vec::new();
+    let mut group_ids = HashSet::new();
+    let mut owners: HashMap<&str, &str> = HashMap::new();
+    for group in groups {
+        let members: Vec<&str> = group
+            .node_ids
+            .iter()
+            .map(String::as_str)
+            .filter(|member| node_ids.contains(member))
+            .collect();
+        if let Some(message) = group_problem(group, &node_ids, &mut group_ids) {
+            issues.push(issue(INVALID_GROUP_KIND, members.clone(), message));
+        }
+        for member in &group.node_ids {
+            let message = if !node_ids.contains(member.as_str()) {
+                format!(
+                    "Group '{}' lists node '{member}', which is not on the canvas. Remove it from the group.",
+                    group.id
+                )
+            } else if let Some(other) = owners.insert(member, &group.id) {
+                format!(
+                    "Node '{member}' is in both group '{other}' and group '{}'. Keep it in one group.",
+                    group.id
+                )
+            } else {
+                continue;
+            };
+            issues.push(issue(INVALID_GROUP_KIND, vec![member.as_str()], message));
+        }
+    }
+    issues.extend(
+        groups
+            .iter()
+            .filter_map(|group| reentry_issue(edges, group)),
+    );
+    issues
+}
+
+/// What is wrong with the group itself, apart from its members.
+fn group_problem<'a>(
+    group: &'a PipelineNodeGroup,
+    node_ids: &HashSet<&str>,
+    group_ids: &mut HashSet<&'a str>,
+) -> Option<String> {
+    if group.id.trim().is_empty() {
+        return Some("A group has an empty id. Give every group an id.".to_string());
+    }
+    if node_ids.contains(group.id.as_str()) || !group_ids.insert(group.id.as_str()) {
+        return Some(format!(
+            "Group id '{}' is already used by another group or node. Rename the group.",
+            group.id
+        ));
+    }
+    if group.node_ids.is_empty() {
+        return Some(format!(
+            "Group '{}' has no member nodes. Add nodes to it or delete it.",
+            group.id
+        ));
+    }
+    None
+}
+
+/// A path out of `group` that leads back into it, found by walking forward
+/// from every edge that leaves the group without passing through a member.
+fn reentry_issue(
+    edges: &[PipelineEdgeSnapshot],
+    group: &PipelineNodeGroup,
+) -> Option<PipelineCanvasIssue> {
+    let members: HashSet<&str> = group.node_ids.iter().map(String::as_str).collect();
+    let mut frontier: Vec<&str> = edges
+        .iter()
+        .filter(|edge| members.contains(edge.source_node_id.as_str()))
+        .map(|edge| edge.target_node_id.as_str())
+        .filter(|target| !members.contains(target))
+        .collect();
+    let mut visited: HashSet<&str> = frontier.iter().copied().collect();
+    while let Some(outside) = frontier.pop() {
+        for edge in edges.iter().filter(|edge| edge.source_node_id == outside) {
+            let target = edge.target_node_id.as_str();
+            if members.contains(target) {
+                let message = format!(
+                    "Group '{}' has a path that leaves it through node '{outside}' and comes back in at node '{target}', so it cannot run as one step. Move the nodes on that path into or out of the group.",
+                    group.id
+                );
+                return Some(issue(GROUP_REENTRY_KIND, vec![outside, target], message));
+            }
+            if visited.insert(target) {
+                frontier.push(target);
+            }
+        }
+    }
+    None
+}
+
+/// Selected ids with every group id replaced by the group's members.
+pub(crate) fn expand_group_ids(ids: &[String], groups: &[PipelineNodeGroup]) -> Vec<String> {
+    let mut expanded = Vec::with_capacity(ids.len());
+    for id in ids {
+        match groups.iter().find(|group| &group.id == id) {
+            Some(group) => expanded.extend(group.node_ids.iter().cloned()),
+            None => expanded.push(id.clone()),
+        }
+    }
+    expanded
+}
+
+/// Tags each planned node with the group it belongs to.
+pub(crate) fn assign_groups(planned: &mut [PipelineRunNode], groups: &[PipelineNodeGroup]) {
+    for node in planned {
+        node.group_id = groups
+            .iter()
+            .find(|group| group.node_ids.contains(&node.node_id))
+            .map(|group| group.id.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_node_groups;
+    use crate::models::{PipelineEdgeSnapshot, PipelineNodeGroup, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+
+    fn node(id: &str) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: "custom".to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> PipelineEdgeSnapshot {
+        PipelineEdgeSnapshot {
+            id: format!("{source}->{target}"),
+            source_node_id: source.to_string(),
+            target_node_id: target.to_string(),
+            condition: None,
+        }
+    }
+
+    fn group(id: &str, node_ids: &[&str]) -> PipelineNodeGroup {
+        PipelineNodeGroup {
+            id: id.to_string(),
+            title: id.to_string(),
+            node_ids: node_ids.iter().map(|node_id| node_id.to_string()).collect(),
+            collapsed: false,
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+        }
+    }
+
+    #[test]
+    fn groups_must_be_closed_and_disjoint() {
+        let nodes = vec![node("a"), node("b"), node("c"), node("d")];
+        let edges = vec![edge("a", "b"), edge("b", "c"), edge("c", "d")];
+        let closed = check_node_groups(&nodes, &edges, &[group("prep", &["a", "b"])]);
+        assert!(closed.is_empty());
+
+        let leaky = check_node_groups(&nodes, &edges, &[group("prep", &["a", "c"])]);
+        assert_eq!(leaky.len(), 1);
+        assert_eq!(leaky[0].kind, "group_reentry");
+
+        let groups = [group("prep", &["a", "ghost"]), group("train", &["a"])];
+        let kinds: Vec<String> = check_node_groups(&nodes, &edges, &groups)
+            .into_iter()
+            .map(|issue| issue.kind)
+            .collect();
+        assert_eq!(kinds, ["invalid_group", "invalid_group"]);
+    }
+}
@@ -0,0 +1,225 @@
+//! Canvas import command for loading layouts saved by `export_pipeline_canvas`.
+//!
+//! Each file records the `format_version` it was written with. Older files
+//! are migrated forward one version at a time in memory, so each step only
+//! knows its neighbour and the file on disk is never rewritten. Files saved
+//! before versioning existed have no `format_version` and count as version 0.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_export::CANVAS_EXPORT_FORMAT_VERSION;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{
+    ForgeStudioError, PipelineCanvasImport, PipelineEdgeSnapshot, PipelineNodeGroup,
+    PipelineNodeSnapshot,
+};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+type CanvasMigration = fn(&mut Map<String, Value>);
+
+/// Upgrade steps keyed by the version they upgrade from.
+const CANVAS_MIGRATIONS: [(u32, CanvasMigration); 3] = [
+    (0, migrate_v0_to_v1),
+    (1, migrate_v1_to_v2),
+    (2, migrate_v2_to_v3),
+];
+
+/// Current-format fields the import returns; export metadata is not needed.
+#[derive(Deserialize)]
+pub(crate) struct CanvasFile {
+    pub(crate) start_node_id: Option<String>,
+    pub(crate) nodes: Vec<PipelineNodeSnapshot>,
+    pub(crate) edges: Vec<PipelineEdgeSnapshot>,
+    #[serde(default)]
+    pub(crate) groups: Vec<PipelineNodeGroup>,
+}
+
+#[tauri::command]
+pub fn import_pipeline_canvas(
+    data_root: String,
+    path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasImport, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    read_canvas_file(&data_root, &path, &roots)
+}
+
+/// Reads and migrates the canvas file at `path`; `data_root` must be resolved.
+pub(crate) fn read_canvas_file(
+    data_root: &str,
+    path: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PipelineCanvasImport, ForgeStudioError> {
+    let source_path = resolve_canvas_path(data_root, path, roots)?;
+    let serialized = fs::read_to_string(&source_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Canvas import failed: could not read {}: {error}",
+            source_path.display()
+        ))
+    })?;
+    let payload: Value = serde_json::from_str(&serialized).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Canvas import failed: {} is not valid JSON: {error}",
+            source_path.display()
+        ))
+    })?;
+    let (source_format_version, canvas) = read_canvas_payload(payload)?;
+    Ok(PipelineCanvasImport {
+        source_path: source_path.display().to_string(),
+        source_format_version,
+        format_version: CANVAS_EXPORT_FORMAT_VERSION,
+        start_node_id: canvas.start_node_id,
+        nodes: canvas.nodes,
+        edges: canvas.edges,
+        groups: canvas.groups,
+    })
+}
+
+/// Migrates a parsed canvas file to the current format and checks its start node.
+pub(crate) fn read_canvas_payload(
+    mut payload: Value,
+) -> Result<(u32, CanvasFile), ForgeStudioError> {
+    let object = payload.as_object_mut().ok_or_else(|| {
+        ForgeStudioError::parse(
+            "Canvas import failed: file must be a JSON object. Pick a file saved by canvas export.",
+        )
+    })?;
+    let source_format_version = read_format_version(object)?;
+    migrate_canvas(object, source_format_version);
+    let canvas: CanvasFile = serde_json::from_value(payload).map_err(|error| {
+        let version = CANVAS_EXPORT_FORMAT_VERSION;
+        ForgeStudioError::parse(format!(
+            "Canvas import failed: canvas does not match format version {version}: {error}"
+        ))
+    })?;
+    if let Some(start_node_id) = canvas.start_node_id.as_deref() {
+        if !canvas.nodes.iter().any(|node| node.id == start_node_id) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Canvas import failed: start node '{start_node_id}' is not in the canvas. Fix or clear start_node_id."
+            )));
+        }
+    }
+    Ok((source_format_version, canvas))
+}
+
+/// Resolves relative paths against the data root, matching where exports are written.
+pub(crate) fn resolve_canvas_path(
+    data_root: &str,
+    path: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PathBuf, ForgeStudioError> {
+    let requested_path = PathBuf::from(path.trim());
+    if requested_path.is_absolute() {
+        confine_path(&requested_path, "Import file", roots)
+    } else {
+        confine_path(
+            &Path::new(data_root).join(requested_path),
+            "Import file",
+            roots,
+        )
+    }
+}
+
+fn read_format_version(object: &Map<String, Value>) -> Result<u32, ForgeStudioError> {
+    let Some(raw_version) = object.get("format_version") else {
+        return Ok(0);
+    };
+    let version = raw_version
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| {
+            ForgeStudioError::parse(format!(
+                "Canvas import failed: format_version {raw_version} is not a version number."
+            ))
+        })?;
+    if version > CANVAS_EXPORT_FORMAT_VERSION {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Canvas import failed: file uses format version {version}, newer than supported version {CANVAS_EXPORT_FORMAT_VERSION}. Update Forge Studio to open it."
+        )));
+    }
+    Ok(version)
+}
+
+fn migrate_canvas(object: &mut Map<String, Value>, from_version: u32) {
+    for (version, migrate) in CANVAS_MIGRATIONS {
+        if version >= from_version {
+            migrate(object);
+        }
+    }
+    object.insert(
+        "format_version".to_string(),
+        json!(CANVAS_EXPORT_FORMAT_VERSION),
+    );
+}
+
+/// Unversioned canvases omitted titles, positions, configs, and edge ids, and
+/// could hold non-string config values that v1 stores as strings.
+fn migrate_v0_to_v1(object: &mut Map<String, Value>) {
+    object.entry("start_node_id").or_insert(Value::Null);
+    let nodes = object.get_mut("nodes").and_then(Value::as_array_mut);
+    for node in nodes.into_iter().flatten().filter_map(Value::as_object_mut) {
+        let fallback_title = node.get("type").cloned().unwrap_or_else(|| json!(""));
+        node.entry("title").or_insert(fallback_title);
+        node.entry("canvas_x").or_insert(json!(0.0));
+        node.entry("canvas_y").or_insert(json!(0.0));
+        let config = node.entry("config").or_insert_with(|| json!({}));
+        for value in config
+            .as_object_mut()
+            .into_iter()
+            .flat_map(|map| map.values_mut())
+        {
+            if !value.is_string() {
+                *value = json!(value.to_string());
+            }
+        }
+    }
+    let edges = object.get_mut("edges").and_then(Value::as_array_mut);
+    for (index, edge) in edges.into_iter().flatten().enumerate() {
+        if let Some(edge) = edge.as_object_mut() {
+            edge.entry("id")
+                .or_insert_with(|| json!(format!("edge-{}", index + 1)));
+        }
+    }
+}
+
+/// v2 edges may carry a `condition`; v1 edges have none and stay unconditional.
+fn migrate_v1_to_v2(_object: &mut Map<String, Value>) {}
+
+/// v3 canvases may list node `groups`; v2 canvases have none.
+fn migrate_v2_to_v3(_object: &mut Map<String, Value>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::{migrate_canvas, read_format_version, CanvasFile};
+    use serde_json::json;
+
+    #[test]
+    fn unversioned_canvas_migrates_to_current_format() {
+        let mut payload = json!({
+            "nodes": [{"id": "n1", "type": "train", "config": {"epochs": 3, "name": "demo"}}],
+            "edges": [{"source_node_id": "n1", "target_node_id": "n1"}]
+        });
+        let object = payload
+            .as_object_mut()
+            .expect("payload should be an object");
+        let version = read_format_version(object).expect("missing version should be accepted");
+        assert_eq!(version, 0);
+        migrate_canvas(object, version);
+        let canvas: CanvasFile = serde_json::from_value(payload).expect("migrated canvas parses");
+        assert_eq!(canvas.nodes[0].title, "train");
+        assert_eq!(canvas.nodes[0].config["epochs"], "3");
+        assert_eq!(canvas.edges[0].id, "edge-1");
+        assert!(canvas.start_node_id.is_none());
+    }
+
+    #[test]
+    fn read_format_version_rejects_newer_files() {
+        let newer = json!({"format_version": 99});
+        assert!(read_format_version(newer.as_object().expect("object")).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Advisory lint for pipeline canvases.
+//!
+//! Validation blocks runs that cannot work; lint flags graphs that will run
+//! but probably not as intended, such as a filter that keeps every record or
+//! a train node that never sees freshly ingested data. Every finding is a
+//! warning, including the graph problems validation would treat as errors,
+//! so lint can run on half-built canvases without alarming anyone.
+
+use crate::commands::canvas_validation::{issue, validate_canvas, WARNING_SEVERITY};
+use crate::models::{PipelineCanvasIssue, PipelineEdgeSnapshot, PipelineNodeSnapshot};
+use std::collections::{HashMap, HashSet};
+
+/// Validation findings lint repeats; the rest are left to validation.
+const LINTED_GRAPH_KINDS: [&str; 2] = ["missing_start_node", "unreachable_node"];
+/// Node types that write a dataset version a downstream node can read.
+const DATASET_NODE_TYPES: [&str; 2] = ["ingest", "filter"];
+
+#[tauri::command]
+pub fn lint_pipeline_canvas(
+    nodes: Vec<PipelineNodeSnapshot>,
+    edges: Vec<PipelineEdgeSnapshot>,
+    start_node_id: Option<String>,
+) -> Vec<PipelineCanvasIssue> {
+    let mut warnings = match start_node_id.as_deref() {
+        Some(start_node_id) => validate_canvas(&nodes, &edges, Some(start_node_id))
+            .into_iter()
+            .filter(|issue| LINTED_GRAPH_KINDS.contains(&issue.kind.as_str()))
+            .collect(),
+        None => vec![issue(
+            "missing_start_node",
+            vec![],
+            "No start node is set, so the canvas cannot run. Pick the node the pipeline starts from."
+                .to_string(),
+        )],
+    };
+    warnings.extend(unfiltered_quality(&nodes));
+    warnings.extend(unfed_train_nodes(&nodes, &edges));
+    for warning in &mut warnings {
+        warning.severity = WARNING_SEVERITY.to_string();
+    }
+    warnings
+}
+
+fn unfiltered_quality(nodes: &[PipelineNodeSnapshot]) -> Vec<PipelineCanvasIssue> {
+    nodes
+        .iter()
+        .filter(|node| node.node_type == "filter")
+        .filter(|node| {
+            node.config
+                .get("min_quality")
+                .is_none_or(|value| value.trim().is_empty())
+        })
+        .map(|node| {
+            let message = format!(
+                "Filter node '{}' has no min_quality, so it keeps records of any quality. Set a threshold such as 0.5.",
+                node.id
+            );
+            PipelineCanvasIssue {
+                field: Some("min_quality".to_string()),
+                ..issue("missing_quality_threshold", vec![node.id.as_str()], message)
+            }
+        })
+        .collect()
+}
+
+/// Train nodes with no ingest or filter node anywhere upstream.
+fn unfed_train_nodes(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+) -> Vec<PipelineCanvasIssue> {
+    let node_types: HashMap<&str, &str> = nodes
+        .iter()
+        .map(|node| (node.id.as_str(), node.node_type.as_str()))
+        .collect();
+    let mut incoming: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges {
+        incoming
+            .entry(edge.target_node_id.as_str())
+            .or_default()
+            .push(edge.source_node_id.as_str());
+    }
+    let mut warnings = Vec::new();
+    for node in nodes.iter().filter(|node| node.node_type == "train") {
+        let mut seen = HashSet::from([node.id.as_str()]);
+        let mut stack = vec![node.id.as_str()];
+        let mut fed = false;
+        while let Some(current) = stack.pop() {
+            for upstream in incoming.get(current).into_iter().flatten() {
+                fed |= node_types
+                    .get(upstream)
+                    .is_some_and(|node_type| DATASET_NODE_TYPES.contains(node_type));
+                if seen.insert(upstream) {
+                    stack.push(upstream);
+                }
+            }
+        }
+        if !fed {
+            let message = format!(
+                "Train node '{}' is not fed by an ingest or filter node, so it trains on whatever dataset version already exists. Connect one upstream to train on fresh data.",
+                node.id
+            );
+            warnings.push(issue("unfed_train_node", vec![node.id.as_str()], message));
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint_pipeline_canvas;
+    use crate::models::{PipelineEdgeSnapshot, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+
+    fn node(id: &str, node_type: &str) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> PipelineEdgeSnapshot {
+        PipelineEdgeSnapshot {
+            id: format!("{source}->{target}"),
+            source_node_id: source.to_string(),
+            target_node_id: target.to_string(),
+            condition: None,
+        }
+    }
+
+    #[test]
+    fn lint_flags_soft_problems_as_warnings() {
+        let nodes = vec![
+            node("ingest", "ingest"),
+            node("filter", "filter"),
+            node("train", "train"),
+            node("chat", "chat"),
+            node("retrain", "train"),
+        ];
+        let edges = vec![
+            edge("ingest", "filter"),
+            edge("filter", "train"),
+            edge("train", "chat"),
+            edge("chat", "retrain"),
+        ];
+        let summary = |start_node_id: Option<&str>| -> Vec<(String, Vec<String>)> {
+            lint_pipeline_canvas(
+                nodes.clone(),
+                edges.clone(),
+                start_node_id.map(str::to_string),
+            )
+            .into_iter()
+            .inspect(|warning| assert_eq!(warning.severity, "warning"))
+            .map(|warning| (warning.kind, warning.node_ids))
+            .collect()
+        };
+        let kinds = |summary: Vec<(String, Vec<String>)>| -> Vec<String> {
+            summary.into_iter().map(|(kind, _)| kind).collect()
+        };
+        assert_eq!(
+            kinds(summary(Some("ingest"))),
+            ["missing_quality_threshold"]
+        );
+        let from_train = summary(Some("train"));
+        assert_eq!(from_train[0].0, "unreachable_node");
+        assert_eq!(from_train[0].1, ["ingest", "filter"]);
+        assert_eq!(
+            kinds(summary(None)),
+            ["missing_start_node", "missing_quality_threshold"]
+        );
+        let unfed = lint_pipeline_canvas(
+            vec![node("train", "train")],
+            vec![],
+            Some("train".to_string()),
+        );
+        assert_eq!(unfed[0].kind, "unfed_train_node");
+    }
+}
@@ -0,0 +1,233 @@
+//! Three-way canvas merge for reconciling two edits of one pipeline file.
+//!
+//! `base` is the version both sides started from; all three load through
+//! canvas import, so older formats merge in the current shape. Nodes and
+//! edges match by id, and nodes merge field by field, config keys included,
+//! so two people changing different options of one node both keep their
+//! change. A field both sides changed differently is a conflict: the merged
+//! canvas keeps ours and the conflict lists all three values, so the editor
+//! can offer theirs instead. So is a node or edge one side changed and the
+//! other deleted, which is kept. Nodes or edges both sides added under one
+//! id are both kept, theirs renamed and its edges pointed at the new id.
+//! Layout moves never conflict, ours wins, and groups merge as one list.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_import::read_canvas_file;
+use crate::commands::canvas_merge_items::{edit_delete_conflict, merge_value, MergeItem};
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{
+    ForgeStudioError, PipelineCanvasImport, PipelineCanvasMerge, PipelineEdgeSnapshot,
+    PipelineMergeConflict, PipelineMergeRename,
+};
+use std::collections::{HashMap, HashSet};
+use tauri::State;
+
+const RENAMED_ID_SUFFIX: &str = "_theirs";
+
+#[tauri::command]
+pub fn merge_canvases(
+    data_root: String,
+    base: String,
+    theirs: String,
+    ours: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasMerge, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let base = read_canvas_file(&data_root, &base, &roots)?;
+    let theirs = read_canvas_file(&data_root, &theirs, &roots)?;
+    let ours = read_canvas_file(&data_root, &ours, &roots)?;
+    Ok(merge_canvas_imports(base, theirs, ours))
+}
+
+fn merge_canvas_imports(
+    base: PipelineCanvasImport,
+    theirs: PipelineCanvasImport,
+    ours: PipelineCanvasImport,
+) -> PipelineCanvasMerge {
+    let mut conflicts = Vec::new();
+    let start_node_ids = [&base, &theirs, &ours].map(|canvas| canvas.start_node_id.as_ref());
+    let start_node_id = merge_value(&mut conflicts, "start_node", "", None, start_node_ids);
+    let (nodes, renamed_node_ids) =
+        merge_items([&base.nodes, &theirs.nodes, &ours.nodes], &mut conflicts);
+    let their_edges = reconnect_edges(&theirs.edges, &renamed_node_ids);
+    let (edges, renamed_edge_ids) =
+        merge_items([&base.edges, &their_edges, &ours.edges], &mut conflicts);
+    let node_ids: HashSet<&str> = nodes.iter().map(|node| node.id.as_str()).collect();
+    let (edges, dropped_edges): (Vec<_>, Vec<_>) = edges.into_iter().partition(|edge| {
+        node_ids.contains(edge.source_node_id.as_str())
+            && node_ids.contains(edge.target_node_id.as_str())
+    });
+    let groups = [&base.groups, &theirs.groups, &ours.groups];
+    let groups = if groups[2] == groups[0] {
+        groups[1]
+    } else {
+        groups[2]
+    };
+    PipelineCanvasMerge {
+        start_node_id,
+        groups: groups.clone(),
+        nodes,
+        edges,
+        conflicts,
+        renamed_node_ids,
+        renamed_edge_ids,
+        dropped_edge_ids: dropped_edges.into_iter().map(|edge| edge.id).collect(),
+    }
+}
+
+/// `edges` pointed at the new ids of renamed nodes.
+fn reconnect_edges(
+    edges: &[PipelineEdgeSnapshot],
+    renames: &[PipelineMergeRename],
+) -> Vec<PipelineEdgeSnapshot> {
+    let renamed = |node_id: &String| {
+        renames
+            .iter()
+            .find(|rename| &rename.from_id == node_id)
+            .map_or_else(|| node_id.clone(), |rename| rename.to_id.clone())
+    };
+    edges
+        .iter()
+        .map(|edge| PipelineEdgeSnapshot {
+            source_node_id: renamed(&edge.source_node_id),
+            target_node_id: renamed(&edge.target_node_id),
+            ..edge.clone()
+        })
+        .collect()
+}
+
+/// Merged items in ours order, then those only theirs has, renamed where
+/// both sides added different items under one id.
+fn merge_items<T: MergeItem>(
+    [base, theirs, ours]: [&[T]; 3],
+    conflicts: &mut Vec<PipelineMergeConflict>,
+) -> (Vec<T>, Vec<PipelineMergeRename>) {
+    let (base_by_id, theirs_by_id, ours_by_id) = (by_id(base), by_id(theirs), by_id(ours));
+    let mut taken: HashSet<String> = base
+        .iter()
+        .chain(theirs)
+        .chain(ours)
+        .map(|item| item.item_id().to_string())
+        .collect();
+    let mut merged = Vec::with_capacity(ours.len());
+    let mut renames = Vec::new();
+    for item in ours {
+        let id = item.item_id();
+        match (base_by_id.get(id), theirs_by_id.get(id)) {
+            (Some(base_item), Some(their_item)) => {
+                merged.push(T::merge_three(base_item, their_item, item, conflicts));
+            }
+            (Some(base_item), None) if base_item.same_content(item) => {}
+            (Some(base_item), None) => {
+                conflicts.push(edit_delete_conflict(*base_item, None, Some(item)));
+                merged.push(item.clone());
+            }
+            (None, _) => merged.push(item.clone()),
+        }
+    }
+    for item in theirs {
+        let id = item.item_id();
+        match (base_by_id.get(id), ours_by_id.get(id)) {
+            (None, None) => merged.push(item.clone()),
+            (None, Some(our_item)) if !our_item.same_content(item) => {
+                let to_id = unused_id(id, &mut taken);
+                merged.push(item.with_id(to_id.clone()));
+                renames.push(PipelineMergeRename {
+                    from_id: id.to_string(),
+                    to_id,
+                });
+            }
+            (Some(base_item), None) if !base_item.same_content(item) => {
+                conflicts.push(edit_delete_conflict(*base_item, Some(item), None));
+                merged.push(item.clone());
+            }
+            _ => {}
+        }
+    }
+    (merged, renames)
+}
+
+fn by_id<T: MergeItem>(items: &[T]) -> HashMap<&str, &T> {
+    items.iter().map(|item| (item.item_id(), item)).collect()
+}
+
+/// `<id>_theirs`, numbered when that is taken too.
+fn unused_id(id: &str, taken: &mut HashSet<String>) -> String {
+    let mut candidate = format!("{id}{RENAMED_ID_SUFFIX}");
+    let mut number = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{id}{RENAMED_ID_SUFFIX}_{number}");
+        number += 1;
+    }
+    taken.insert(candidate.clone());
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_canvas_imports;
+    use crate::commands::canvas_import::read_canvas_file;
+    use crate::commands::path_safety::WorkspaceRoots;
+    use serde_json::{json, Value};
+    use std::fs;
+
+    #[test]
+    fn merge_combines_both_sides_and_reports_conflicts() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-merge-{}", std::process::id()));
+        fs::create_dir_all(&data_root).expect("temp data root should be created");
+        let node = |id: &str, config: Value| {
+            json!({"id": id, "type": "train", "title": id, "canvas_x": 0.0, "canvas_y": 0.0,
+                   "config": config})
+        };
+        let edge = |id: &str, source: &str, target: &str| json!({"id": id, "source_node_id": source, "target_node_id": target});
+        let canvases = [
+            (
+                "base",
+                vec![
+                    node("a", json!({"epochs": "2", "lr": "0.1"})),
+                    node("b", json!({})),
+                ],
+                vec![edge("e1", "a", "b")],
+            ),
+            (
+                "theirs",
+                vec![
+                    node("a", json!({"epochs": "4", "lr": "0.2"})),
+                    node("new", json!({"x": "1"})),
+                ],
+                vec![edge("e2", "a", "new")],
+            ),
+            (
+                "ours",
+                vec![
+                    node("a", json!({"epochs": "3", "lr": "0.1"})),
+                    node("b", json!({})),
+                    node("new", json!({"x": "2"})),
+                ],
+                vec![edge("e1", "a", "b"), edge("e3", "b", "new")],
+            ),
+        ];
+        for (name, nodes, edges) in canvases {
+            let canvas =
+                json!({"format_version": 3, "start_node_id": "a", "nodes": nodes, "edges": edges});
+            fs::write(data_root.join(format!("{name}.json")), canvas.to_string())
+                .expect("canvas should be written");
+        }
+        let root = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&root));
+        let read = |path: &str| read_canvas_file(&root, path, &roots).expect("canvas should load");
+        let merge = merge_canvas_imports(read("base.json"), read("theirs.json"), read("ours.json"));
+
+        let node_ids: Vec<&str> = merge.nodes.iter().map(|node| node.id.as_str()).collect();
+        assert_eq!(node_ids, ["a", "new", "new_theirs"]);
+        assert_eq!(merge.nodes[0].config["lr"], "0.2");
+        assert_eq!(merge.nodes[0].config["epochs"], "3");
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].theirs, Some(json!("4")));
+        assert_eq!(merge.edges[0].target_node_id, "new_theirs");
+        assert_eq!(merge.dropped_edge_ids, ["e3"]);
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
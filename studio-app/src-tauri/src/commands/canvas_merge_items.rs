@@ -0,0 +1,144 @@
+//! Three-way merge of single canvas values, nodes, and edges.
+//!
+//! A value both sides changed differently keeps ours and records a conflict
+//! holding all three versions. Nodes merge field by field so independent
+//! config changes to one node combine; edges merge whole.
+
+use crate::models::{PipelineEdgeSnapshot, PipelineMergeConflict, PipelineNodeSnapshot};
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// What the merge needs to know about nodes and edges alike.
+pub(crate) trait MergeItem: Clone + Serialize {
+    fn item_id(&self) -> &str;
+    /// Equal apart from layout.
+    fn same_content(&self, other: &Self) -> bool;
+    /// Merges an item present in all three canvases.
+    fn merge_three(
+        base: &Self,
+        theirs: &Self,
+        ours: &Self,
+        conflicts: &mut Vec<PipelineMergeConflict>,
+    ) -> Self;
+    fn with_id(&self, id: String) -> Self;
+}
+
+impl MergeItem for PipelineNodeSnapshot {
+    fn item_id(&self) -> &str {
+        &self.id
+    }
+
+    fn same_content(&self, other: &Self) -> bool {
+        (&self.node_type, &self.title, &self.config)
+            == (&other.node_type, &other.title, &other.config)
+    }
+
+    fn merge_three(
+        base: &Self,
+        theirs: &Self,
+        ours: &Self,
+        conflicts: &mut Vec<PipelineMergeConflict>,
+    ) -> Self {
+        let mut merged = ours.clone();
+        let id = ours.id.as_str();
+        let types = [base, theirs, ours].map(|node| Some(&node.node_type));
+        let titles = [base, theirs, ours].map(|node| Some(&node.title));
+        let node_type = merge_value(conflicts, "attribute", id, Some("type"), types);
+        let title = merge_value(conflicts, "attribute", id, Some("title"), titles);
+        merged.node_type = node_type.unwrap_or_default();
+        merged.title = title.unwrap_or_default();
+        if (ours.canvas_x, ours.canvas_y) == (base.canvas_x, base.canvas_y) {
+            (merged.canvas_x, merged.canvas_y) = (theirs.canvas_x, theirs.canvas_y);
+        }
+        let keys: BTreeSet<&String> = [base, theirs, ours]
+            .iter()
+            .flat_map(|node| node.config.keys())
+            .collect();
+        for key in keys {
+            let values = [base, theirs, ours].map(|node| node.config.get(key));
+            match merge_value(conflicts, "config", id, Some(key), values) {
+                Some(value) => merged.config.insert(key.clone(), value),
+                None => merged.config.remove(key),
+            };
+        }
+        merged
+    }
+
+    fn with_id(&self, id: String) -> Self {
+        Self { id, ..self.clone() }
+    }
+}
+
+impl MergeItem for PipelineEdgeSnapshot {
+    fn item_id(&self) -> &str {
+        &self.id
+    }
+
+    fn same_content(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn merge_three(
+        base: &Self,
+        theirs: &Self,
+        ours: &Self,
+        conflicts: &mut Vec<PipelineMergeConflict>,
+    ) -> Self {
+        let edges = [Some(base), Some(theirs), Some(ours)];
+        merge_value(conflicts, "edge", &ours.id, None, edges).unwrap_or_else(|| ours.clone())
+    }
+
+    fn with_id(&self, id: String) -> Self {
+        Self { id, ..self.clone() }
+    }
+}
+
+/// The three-way result for one value; `None` means it was deleted. When
+/// both sides changed it differently, ours is kept and a conflict recorded.
+pub(crate) fn merge_value<T: Clone + PartialEq + Serialize>(
+    conflicts: &mut Vec<PipelineMergeConflict>,
+    kind: &str,
+    item_id: &str,
+    key: Option<&str>,
+    [base, theirs, ours]: [Option<&T>; 3],
+) -> Option<T> {
+    if ours == theirs || theirs == base {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+    conflicts.push(conflict(kind, item_id, key, [base, theirs, ours]));
+    ours.cloned()
+}
+
+fn conflict<T: Serialize>(
+    kind: &str,
+    item_id: &str,
+    key: Option<&str>,
+    values: [Option<&T>; 3],
+) -> PipelineMergeConflict {
+    let [base, theirs, ours] =
+        values.map(|value| value.and_then(|value| serde_json::to_value(value).ok()));
+    PipelineMergeConflict {
+        kind: kind.to_string(),
+        item_id: item_id.to_string(),
+        key: key.map(str::to_string),
+        base,
+        theirs,
+        ours,
+    }
+}
+
+pub(crate) fn edit_delete_conflict<T: MergeItem>(
+    base: &T,
+    theirs: Option<&T>,
+    ours: Option<&T>,
+) -> PipelineMergeConflict {
+    conflict(
+        "edit_delete",
+        base.item_id(),
+        None,
+        [Some(base), theirs, ours],
+    )
+}
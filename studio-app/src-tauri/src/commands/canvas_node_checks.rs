@@ -0,0 +1,89 @@
+//! Per-node checks for pipeline canvas graphs: ids must be set and unique,
+//! and each node type must be built in or registered by a plugin.
+
+use crate::commands::canvas_validation::{issue, PIPELINE_NODE_TYPES};
+use crate::commands::message_catalog::message;
+use crate::commands::plugin_registry::{plugin_node_type, plugin_node_types};
+use crate::models::{PipelineCanvasIssue, PipelineNodeSnapshot};
+use std::collections::HashSet;
+
+pub(crate) fn check_nodes(nodes: &[PipelineNodeSnapshot]) -> Vec<PipelineCanvasIssue> {
+    let mut issues = Vec::new();
+    let mut seen = HashSet::new();
+    for node in nodes {
+        let node_ids = vec![node.id.as_str()];
+        if node.id.trim().is_empty() {
+            let message = message(
+                "validation.empty_node_id",
+                &[("node_type", &node.node_type)],
+            );
+            issues.push(issue("empty_id", node_ids, message));
+            continue;
+        }
+        if !seen.insert(node.id.as_str()) {
+            let message = message("validation.duplicate_node_id", &[("node_id", &node.id)]);
+            issues.push(issue("duplicate_node_id", node_ids, message));
+            continue;
+        }
+        if !PIPELINE_NODE_TYPES.contains(&node.node_type.as_str())
+            && plugin_node_type(&node.node_type).is_none()
+        {
+            let node_types = PIPELINE_NODE_TYPES
+                .iter()
+                .map(|node_type| node_type.to_string())
+                .chain(plugin_node_types().into_iter().map(|entry| entry.node_type))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let message = message(
+                "validation.unknown_node_type",
+                &[
+                    ("node_id", &node.id),
+                    ("node_type", &node.node_type),
+                    ("node_types", &node_types),
+                ],
+            );
+            issues.push(issue("unknown_node_type", node_ids, message));
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_nodes;
+    use crate::models::PipelineNodeSnapshot;
+    use std::collections::BTreeMap;
+
+    fn node(id: &str, node_type: &str) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn check_nodes_reports_one_issue_per_bad_node() {
+        let nodes = vec![
+            node("a", "ingest"),
+            node(" ", "train"),
+            node("a", "deploy"),
+            node("b", "deploy"),
+        ];
+        let kinds: Vec<(String, Vec<String>)> = check_nodes(&nodes)
+            .into_iter()
+            .map(|issue| (issue.kind, issue.node_ids))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("empty_id".to_string(), vec![" ".to_string()]),
+                ("duplicate_node_id".to_string(), vec!["a".to_string()]),
+                ("unknown_node_type".to_string(), vec!["b".to_string()]),
+            ]
+        );
+    }
+}
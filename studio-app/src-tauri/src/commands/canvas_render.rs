@@ -0,0 +1,242 @@
+//! Renders a pipeline canvas as Mermaid text or SVG for documentation.
+//!
+//! SVG output places nodes at their canvas coordinates with the same node
+//! size, edge anchors, and colors as the Studio canvas, shifted so the
+//! drawing starts at a small margin. Mermaid lays graphs out itself, so the
+//! canvas only decides the flow direction and the order nodes are declared.
+//! Edges pointing at missing nodes are left out of both formats.
+
+use crate::models::{ForgeStudioError, PipelineEdgeSnapshot, PipelineNodeSnapshot};
+use std::collections::HashMap;
+
+const MERMAID_FORMAT: &str = "mermaid";
+const SVG_FORMAT: &str = "svg";
+/// Match `PIPELINE_NODE_WIDTH` and `PIPELINE_NODE_HEIGHT` in the frontend.
+const NODE_WIDTH: f64 = 190.0;
+const NODE_HEIGHT: f64 = 88.0;
+const SVG_MARGIN: f64 = 24.0;
+const MAX_SVG_TITLE_CHARS: usize = 24;
+const SVG_STYLE: &str = "<style>\
+.edge{stroke:#63718a;stroke-width:1.5;stroke-linecap:round}\
+.node{fill:#2e3645;stroke:#465061}\
+.title{fill:#d4d9e2;font:600 14px sans-serif}\
+.type{fill:#8f97a6;font:12px sans-serif}\
+</style>";
+/// Arrowhead used by the canvas edges.
+const SVG_DEFS: &str = "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"9\" refY=\"5\" \
+markerUnits=\"strokeWidth\" orient=\"auto\">\
+<path d=\"M0,0 L10,5 L0,10 Z\" fill=\"#7a8aa6\"/></marker></defs>";
+
+#[tauri::command]
+pub fn render_pipeline_canvas(
+    nodes: Vec<PipelineNodeSnapshot>,
+    edges: Vec<PipelineEdgeSnapshot>,
+    format: String,
+) -> Result<String, ForgeStudioError> {
+    match format.trim() {
+        MERMAID_FORMAT => Ok(render_mermaid(&nodes, &edges)),
+        SVG_FORMAT => Ok(render_svg(&nodes, &edges)),
+        other => Err(ForgeStudioError::invalid_input(format!(
+            "Canvas render failed: unknown format '{other}'. Use '{MERMAID_FORMAT}' or '{SVG_FORMAT}'."
+        ))),
+    }
+}
+
+fn render_mermaid(nodes: &[PipelineNodeSnapshot], edges: &[PipelineEdgeSnapshot]) -> String {
+    let (width, height) = extent(nodes);
+    let direction = if width >= height { "LR" } else { "TB" };
+    let mut ordered: Vec<&PipelineNodeSnapshot> = nodes.iter().collect();
+    ordered.sort_by(|left, right| {
+        (left.canvas_x, left.canvas_y)
+            .partial_cmp(&(right.canvas_x, right.canvas_y))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    // Canvas ids may hold characters Mermaid rejects, so nodes get positional aliases.
+    let aliases: HashMap<&str, String> = ordered
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.id.as_str(), format!("n{}", index + 1)))
+        .collect();
+    let mut lines = vec![format!("flowchart {direction}")];
+    for node in &ordered {
+        lines.push(format!(
+            "    {}[\"{}<br/><small>{}</small>\"]",
+            aliases[node.id.as_str()],
+            mermaid_text(&node.title),
+            mermaid_text(&node.node_type)
+        ));
+    }
+    for edge in edges {
+        let source = aliases.get(edge.source_node_id.as_str());
+        let target = aliases.get(edge.target_node_id.as_str());
+        if let (Some(source), Some(target)) = (source, target) {
+            lines.push(format!("    {source} --> {target}"));
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+fn render_svg(nodes: &[PipelineNodeSnapshot], edges: &[PipelineEdgeSnapshot]) -> String {
+    let offset = svg_offset(nodes);
+    let (width, height) = extent(nodes);
+    let (width, height) = (width + SVG_MARGIN * 2.0, height + SVG_MARGIN * 2.0);
+    let mut parts = vec![
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">"
+        ),
+        SVG_STYLE.to_string(),
+        SVG_DEFS.to_string(),
+    ];
+    let by_id: HashMap<&str, &PipelineNodeSnapshot> =
+        nodes.iter().map(|node| (node.id.as_str(), node)).collect();
+    for edge in edges {
+        let source = by_id.get(edge.source_node_id.as_str());
+        let target = by_id.get(edge.target_node_id.as_str());
+        if let (Some(source), Some(target)) = (source, target) {
+            parts.push(svg_edge(source, target, offset));
+        }
+    }
+    parts.extend(nodes.iter().map(|node| svg_node(node, offset)));
+    parts.push("</svg>".to_string());
+    parts.join("\n") + "\n"
+}
+
+/// Shift that moves the top-left node to the drawing margin.
+fn svg_offset(nodes: &[PipelineNodeSnapshot]) -> (f64, f64) {
+    let min_x = nodes
+        .iter()
+        .map(|node| node.canvas_x)
+        .fold(f64::INFINITY, f64::min);
+    let min_y = nodes
+        .iter()
+        .map(|node| node.canvas_y)
+        .fold(f64::INFINITY, f64::min);
+    if !min_x.is_finite() || !min_y.is_finite() {
+        return (0.0, 0.0);
+    }
+    (SVG_MARGIN - min_x, SVG_MARGIN - min_y)
+}
+
+/// Right-middle of the source to left-middle of the target, as the canvas draws edges.
+fn svg_edge(
+    source: &PipelineNodeSnapshot,
+    target: &PipelineNodeSnapshot,
+    (offset_x, offset_y): (f64, f64),
+) -> String {
+    format!(
+        "<line class=\"edge\" x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" \
+         marker-end=\"url(#arrow)\"/>",
+        source.canvas_x + offset_x + NODE_WIDTH,
+        source.canvas_y + offset_y + NODE_HEIGHT / 2.0,
+        target.canvas_x + offset_x,
+        target.canvas_y + offset_y + NODE_HEIGHT / 2.0
+    )
+}
+
+fn svg_node(node: &PipelineNodeSnapshot, (offset_x, offset_y): (f64, f64)) -> String {
+    let (x, y) = (node.canvas_x + offset_x, node.canvas_y + offset_y);
+    format!(
+        "<g><rect class=\"node\" x=\"{x}\" y=\"{y}\" width=\"{NODE_WIDTH}\" \
+         height=\"{NODE_HEIGHT}\" rx=\"4\"/><text class=\"title\" x=\"{}\" y=\"{}\">{}</text>\
+         <text class=\"type\" x=\"{}\" y=\"{}\">{}</text></g>",
+        x + 10.0,
+        y + 38.0,
+        xml_text(&truncated(&node.title)),
+        x + 10.0,
+        y + 58.0,
+        xml_text(&node.node_type)
+    )
+}
+
+/// Width and height spanned by the nodes, including the last node's box.
+fn extent(nodes: &[PipelineNodeSnapshot]) -> (f64, f64) {
+    if nodes.is_empty() {
+        return (0.0, 0.0);
+    }
+    let span = |value: fn(&PipelineNodeSnapshot) -> f64| {
+        let min = nodes.iter().map(value).fold(f64::INFINITY, f64::min);
+        let max = nodes.iter().map(value).fold(f64::NEG_INFINITY, f64::max);
+        max - min
+    };
+    (
+        span(|node| node.canvas_x) + NODE_WIDTH,
+        span(|node| node.canvas_y) + NODE_HEIGHT,
+    )
+}
+
+fn truncated(title: &str) -> String {
+    if title.chars().count() <= MAX_SVG_TITLE_CHARS {
+        return title.to_string();
+    }
+    let kept: String = title.chars().take(MAX_SVG_TITLE_CHARS - 1).collect();
+    format!("{kept}…")
+}
+
+fn xml_text(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Mermaid labels are quoted; quotes and angle brackets become entity codes.
+fn mermaid_text(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_mermaid, render_svg};
+    use crate::models::{PipelineEdgeSnapshot, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+
+    fn node(id: &str, title: &str, canvas_x: f64) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: "train".to_string(),
+            title: title.to_string(),
+            canvas_x,
+            canvas_y: 40.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn renders_follow_canvas_positions() {
+        let nodes = vec![node("b", "Train \"v2\"", 300.0), node("a", "A & B", 100.0)];
+        let edges = vec![
+            PipelineEdgeSnapshot {
+                id: "e1".to_string(),
+                source_node_id: "a".to_string(),
+                target_node_id: "b".to_string(),
+                condition: None,
+            },
+            PipelineEdgeSnapshot {
+                id: "e2".to_string(),
+                source_node_id: "a".to_string(),
+                target_node_id: "ghost".to_string(),
+                condition: None,
+            },
+        ];
+        let mermaid = render_mermaid(&nodes, &edges);
+        assert_eq!(
+            mermaid.lines().collect::<Vec<_>>(),
+            [
+                "flowchart LR",
+                "    n1[\"A & B<br/><small>train</small>\"]",
+                "    n2[\"Train #quot;v2#quot;<br/><small>train</small>\"]",
+                "    n1 --> n2",
+            ]
+        );
+        let svg = render_svg(&nodes, &edges);
+        assert!(svg.contains("width=\"438\" height=\"136\""));
+        assert!(svg.contains("<rect class=\"node\" x=\"24\" y=\"24\""));
+        assert!(svg.contains("A &amp; B"));
+        assert_eq!(svg.matches("<line ").count(), 1);
+    }
+}
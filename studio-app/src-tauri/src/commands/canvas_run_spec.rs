@@ -0,0 +1,266 @@
+//! Converts a pipeline canvas into a YAML run spec for `forge run-spec`.
+//!
+//! Steps follow the plan order a Studio run uses, so the file replays the
+//! canvas headlessly; run specs execute sequentially, which is how Studio
+//! runs pipelines anyway. Values are typed from the node parameter schema
+//! because run-spec fields reject numbers written as strings. The YAML is
+//! written by hand: strings are emitted as JSON-quoted scalars, which YAML
+//! reads verbatim, and floats always carry a decimal point so PyYAML does
+//! not load exponent forms like `1e-3` as strings. Run specs have no
+//! variables or conditional steps, so canvases with `${variable}`
+//! placeholders or edge conditions cannot be exported.
+
+use crate::commands::canvas_validation::blocking_issue_messages;
+use crate::commands::canvas_variables::canvas_variables;
+use crate::commands::pipeline_node_schema::{bundled_parameters, parameter_value_type};
+use crate::commands::pipeline_plan::plan_pipeline;
+use crate::models::{ForgeStudioError, PipelineEdgeSnapshot, PipelineNodeSnapshot};
+use std::collections::HashMap;
+
+const RUN_SPEC_VERSION: u32 = 1;
+/// Commands `forge run-spec` can execute as steps.
+pub(crate) const RUN_SPEC_COMMANDS: [&str; 7] = [
+    "ingest",
+    "filter",
+    "train",
+    "export-training",
+    "chat",
+    "versions",
+    "hardware-profile",
+];
+const RUN_SPEC_HEADER: &str =
+    "# Generated by Forge Studio from a pipeline canvas. Run with: forge run-spec <file>";
+
+type StepField = (String, String);
+
+pub(crate) fn build_run_spec_yaml(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+    start_node_id: Option<&str>,
+) -> Result<String, ForgeStudioError> {
+    let start_node_id = start_node_id.ok_or_else(|| {
+        ForgeStudioError::invalid_input(
+            "Canvas export failed: a YAML pipeline needs a start node. Set one and export again.",
+        )
+    })?;
+    let errors = blocking_issue_messages(nodes, edges, &[], start_node_id);
+    if !errors.is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Canvas export failed: {}",
+            errors.join(" ")
+        )));
+    }
+    reject_variables(nodes)?;
+    reject_conditions(edges)?;
+    let by_id: HashMap<&str, &PipelineNodeSnapshot> =
+        nodes.iter().map(|node| (node.id.as_str(), node)).collect();
+    let mut lines = vec![
+        RUN_SPEC_HEADER.to_string(),
+        format!("version: {RUN_SPEC_VERSION}"),
+        "steps:".to_string(),
+    ];
+    for planned in plan_pipeline(nodes, edges, start_node_id) {
+        let Some(node) = by_id.get(planned.node_id.as_str()) else {
+            continue;
+        };
+        let command = planned.args.first().cloned().unwrap_or_default();
+        let fields = if node.node_type == "custom" {
+            custom_step_fields(node, &command, &planned.args[1..])?
+        } else {
+            typed_step_fields(node)
+        };
+        let title = node.title.split_whitespace().collect::<Vec<_>>().join(" ");
+        lines.push(format!("  # {title} ({})", node.id));
+        lines.push(format!("  - command: {command}"));
+        lines.extend(
+            fields
+                .iter()
+                .map(|(key, value)| format!("    {key}: {value}")),
+        );
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn reject_variables(nodes: &[PipelineNodeSnapshot]) -> Result<(), ForgeStudioError> {
+    let names: Vec<String> = canvas_variables(nodes)
+        .into_iter()
+        .map(|variable| format!("${{{}}}", variable.name))
+        .collect();
+    if names.is_empty() {
+        return Ok(());
+    }
+    Err(ForgeStudioError::invalid_input(format!(
+        "Canvas export failed: YAML pipelines cannot hold variables, but nodes use {}. Replace them with values or export as JSON.",
+        names.join(", ")
+    )))
+}
+
+fn reject_conditions(edges: &[PipelineEdgeSnapshot]) -> Result<(), ForgeStudioError> {
+    let conditional: Vec<&str> = edges
+        .iter()
+        .filter(|edge| edge.condition.is_some())
+        .map(|edge| edge.id.as_str())
+        .collect();
+    if conditional.is_empty() {
+        return Ok(());
+    }
+    Err(ForgeStudioError::invalid_input(format!(
+        "Canvas export failed: YAML pipelines run every step, but edges {} have conditions. Remove the conditions or export as JSON.",
+        conditional.join(", ")
+    )))
+}
+
+/// Non-blank config values in schema order; blanks are left to run-spec defaults.
+fn typed_step_fields(node: &PipelineNodeSnapshot) -> Vec<StepField> {
+    let mut fields: Vec<StepField> = bundled_parameters(&node.node_type)
+        .into_iter()
+        .filter_map(|parameter| {
+            let value = node.config.get(&parameter.name)?.trim();
+            if value.is_empty() {
+                return None;
+            }
+            let yaml = yaml_value(&parameter.value_type, value);
+            Some((parameter.name, yaml))
+        })
+        .collect();
+    if node.node_type == "export" {
+        // Canvas export nodes always pass --include-metadata.
+        fields.push(("include_metadata".to_string(), "true".to_string()));
+    }
+    fields
+}
+
+/// Turns `--some-flag value` pairs into `some_flag: value`; a flag with no value is `true`.
+fn custom_step_fields(
+    node: &PipelineNodeSnapshot,
+    command: &str,
+    tokens: &[String],
+) -> Result<Vec<StepField>, ForgeStudioError> {
+    if !RUN_SPEC_COMMANDS.contains(&command) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Canvas export failed: custom node '{}' runs '{command}', which run specs do not support. Use one of: {}.",
+            node.id,
+            RUN_SPEC_COMMANDS.join(", ")
+        )));
+    }
+    let mut fields: Vec<StepField> = Vec::new();
+    let mut tokens = tokens.iter().peekable();
+    while let Some(token) = tokens.next() {
+        let Some(flag) = token.strip_prefix("--") else {
+            if command == "ingest" && !fields.iter().any(|(key, _)| key == "source") {
+                fields.push(("source".to_string(), yaml_value("path", token)));
+                continue;
+            }
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Canvas export failed: custom node '{}' has positional argument '{token}', which a run spec cannot express. Pass it as a --flag.",
+                node.id
+            )));
+        };
+        let key = flag.replace('-', "_");
+        let value = match tokens.next_if(|next| !next.starts_with("--")) {
+            Some(value) => yaml_value(parameter_value_type(&key), value),
+            None => "true".to_string(),
+        };
+        fields.push((key, value));
+    }
+    Ok(fields)
+}
+
+/// A YAML scalar of the parameter's type; values that do not parse stay quoted strings.
+fn yaml_value(value_type: &str, raw_value: &str) -> String {
+    match value_type {
+        "integer" if raw_value.parse::<i64>().is_ok() => raw_value.to_string(),
+        "float" => match raw_value.parse::<f64>() {
+            Ok(number) if number.is_finite() => yaml_float(number),
+            _ => quoted(raw_value),
+        },
+        "boolean" if ["true", "false"].contains(&raw_value.to_ascii_lowercase().as_str()) => {
+            raw_value.to_ascii_lowercase()
+        }
+        _ => quoted(raw_value),
+    }
+}
+
+/// Rust's shortest float form, adjusted to YAML 1.1: a decimal point and a signed exponent.
+fn yaml_float(number: f64) -> String {
+    let formatted = format!("{number:?}");
+    let Some((mantissa, exponent)) = formatted.split_once('e') else {
+        return formatted;
+    };
+    let mantissa = if mantissa.contains('.') {
+        mantissa.to_string()
+    } else {
+        format!("{mantissa}.0")
+    };
+    let exponent = if exponent.starts_with('-') {
+        exponent.to_string()
+    } else {
+        format!("+{exponent}")
+    };
+    format!("{mantissa}e{exponent}")
+}
+
+fn quoted(raw_value: &str) -> String {
+    serde_json::to_string(raw_value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_run_spec_yaml, yaml_float};
+    use crate::models::{PipelineEdgeSnapshot, PipelineNodeSnapshot};
+
+    fn node(id: &str, node_type: &str, config: &[(&str, &str)]) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: config
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn build_run_spec_yaml_types_values_in_plan_order() {
+        let nodes = vec![
+            node(
+                "train",
+                "train",
+                &[
+                    ("dataset", "demo"),
+                    ("output_dir", "out"),
+                    ("epochs", "2"),
+                    ("learning_rate", "1e-4"),
+                    ("dropout", ""),
+                ],
+            ),
+            node("list", "custom", &[("args", "versions --dataset demo")]),
+        ];
+        let edges = vec![PipelineEdgeSnapshot {
+            id: "e1".to_string(),
+            source_node_id: "list".to_string(),
+            target_node_id: "train".to_string(),
+            condition: None,
+        }];
+        let yaml = build_run_spec_yaml(&nodes, &edges, Some("list")).expect("spec should build");
+        let expected = [
+            "version: 1",
+            "steps:",
+            "  # list (list)",
+            "  - command: versions",
+            "    dataset: \"demo\"",
+            "  # train (train)",
+            "  - command: train",
+            "    dataset: \"demo\"",
+            "    output_dir: \"out\"",
+            "    epochs: 2",
+            "    learning_rate: 0.0001",
+        ];
+        assert_eq!(yaml.lines().skip(1).collect::<Vec<_>>(), expected);
+        assert_eq!(yaml_float(2e20), "2.0e+20");
+        assert_eq!(yaml_float(0.1), "0.1");
+    }
+}
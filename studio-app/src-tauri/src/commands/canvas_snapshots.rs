@@ -0,0 +1,243 @@
+//! Canvas autosave snapshots so a crashed editor session can be recovered.
+//!
+//! Snapshots use the canvas export file format under `outputs/canvas/.history/`,
+//! so restoring goes through the same import and migration path as a saved
+//! canvas. Autosave fires often, so a save identical to the newest snapshot is
+//! skipped and only the newest `canvas_snapshots` are kept. Config validation is
+//! deliberately skipped: autosave captures half-edited canvases too.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_export::{CANVAS_EXPORT_DIR, CANVAS_EXPORT_FORMAT_VERSION};
+use crate::commands::canvas_import::read_canvas_file;
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::run_store_io::{read_json_file, resolve_data_root_text, write_json_file};
+use crate::models::{
+    ForgeStudioError, PipelineCanvasImport, PipelineCanvasSnapshot, PipelineCanvasState,
+};
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const SNAPSHOT_DIR_NAME: &str = ".history";
+/// Payload fields that make up the canvas itself, compared to skip duplicate saves.
+const CANVAS_CONTENT_KEYS: [&str; 4] = ["start_node_id", "nodes", "edges", "groups"];
+
+#[tauri::command]
+pub fn save_canvas_snapshot(
+    data_root: String,
+    canvas: PipelineCanvasState,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasSnapshot, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    write_snapshot(
+        &data_root,
+        canvas,
+        settings.current().cache_sizes.canvas_snapshots,
+    )
+}
+
+/// Snapshots newest first; unreadable files, such as one cut short by a crash, are left out.
+#[tauri::command]
+pub fn list_canvas_snapshots(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<PipelineCanvasSnapshot>, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    read_snapshots(&data_root)
+}
+
+#[tauri::command]
+pub fn restore_canvas_snapshot(
+    data_root: String,
+    snapshot_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineCanvasImport, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    read_snapshot(&data_root, &snapshot_id, &roots)
+}
+
+/// `data_root` is already resolved, as in the helpers below.
+fn write_snapshot(
+    data_root: &str,
+    canvas: PipelineCanvasState,
+    max_snapshots: usize,
+) -> Result<PipelineCanvasSnapshot, ForgeStudioError> {
+    let history_dir = snapshot_dir(data_root);
+    let now = Utc::now();
+    let payload = json!({
+        "format_version": CANVAS_EXPORT_FORMAT_VERSION,
+        "saved_at": now.to_rfc3339_opts(SecondsFormat::Millis, true),
+        "start_node_id": canvas.start_node_id,
+        "nodes": canvas.nodes,
+        "edges": canvas.edges,
+        "groups": canvas.groups,
+    });
+    let existing = snapshot_paths(&history_dir)?;
+    if let Some(newest_path) = existing.first() {
+        let newest = read_json_file(newest_path)?;
+        if same_canvas(&newest, &payload) {
+            return Ok(snapshot_entry(newest_path, &newest));
+        }
+    }
+    fs::create_dir_all(&history_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Canvas snapshot failed: could not create {}: {error}",
+            history_dir.display()
+        ))
+    })?;
+    let snapshot_id = now.format("%Y%m%dT%H%M%S%3fZ").to_string();
+    let snapshot_path = history_dir.join(format!("{snapshot_id}.json"));
+    write_json_file(&snapshot_path, &payload)?;
+    for stale_path in existing.iter().skip(max_snapshots.saturating_sub(1)) {
+        if let Err(error) = fs::remove_file(stale_path) {
+            tracing::warn!(
+                "Failed to prune canvas snapshot {}: {error}",
+                stale_path.display()
+            );
+        }
+    }
+    Ok(snapshot_entry(&snapshot_path, &payload))
+}
+
+fn read_snapshots(data_root: &str) -> Result<Vec<PipelineCanvasSnapshot>, ForgeStudioError> {
+    let snapshots = snapshot_paths(&snapshot_dir(data_root))?
+        .iter()
+        .filter_map(|path| {
+            let payload = read_json_file(path).ok()?;
+            Some(snapshot_entry(path, &payload))
+        })
+        .collect();
+    Ok(snapshots)
+}
+
+fn read_snapshot(
+    data_root: &str,
+    snapshot_id: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PipelineCanvasImport, ForgeStudioError> {
+    let snapshot_id = snapshot_id.trim();
+    let is_plain_id = !snapshot_id.is_empty()
+        && snapshot_id
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric());
+    let snapshot_path = snapshot_dir(data_root).join(format!("{snapshot_id}.json"));
+    if !is_plain_id || !snapshot_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Canvas snapshot '{snapshot_id}' does not exist. Pick one from list_canvas_snapshots."
+        )));
+    }
+    read_canvas_file(data_root, &snapshot_path.display().to_string(), roots)
+}
+
+/// Matches where canvas exports are written.
+fn snapshot_dir(data_root: &str) -> PathBuf {
+    Path::new(data_root)
+        .join(CANVAS_EXPORT_DIR)
+        .join(SNAPSHOT_DIR_NAME)
+}
+
+/// Snapshot files newest first; ids are UTC timestamps, so names sort by age.
+fn snapshot_paths(history_dir: &Path) -> Result<Vec<PathBuf>, ForgeStudioError> {
+    if !history_dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let entries = fs::read_dir(history_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", history_dir.display()))
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect();
+    paths.sort_by(|left, right| right.cmp(left));
+    Ok(paths)
+}
+
+fn same_canvas(left: &Value, right: &Value) -> bool {
+    CANVAS_CONTENT_KEYS
+        .iter()
+        .all(|key| left.get(key) == right.get(key))
+}
+
+fn snapshot_entry(path: &Path, payload: &Value) -> PipelineCanvasSnapshot {
+    let count = |key: &str| {
+        payload
+            .get(key)
+            .and_then(Value::as_array)
+            .map_or(0, Vec::len)
+    };
+    PipelineCanvasSnapshot {
+        snapshot_id: path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        saved_at: payload
+            .get("saved_at")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        path: path.display().to_string(),
+        node_count: count("nodes"),
+        edge_count: count("edges"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_snapshot, read_snapshots, write_snapshot};
+    use crate::commands::path_safety::WorkspaceRoots;
+    use crate::models::{PipelineCanvasState, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+
+    fn canvas(title: &str) -> PipelineCanvasState {
+        PipelineCanvasState {
+            start_node_id: Some("n1".to_string()),
+            nodes: vec![PipelineNodeSnapshot {
+                id: "n1".to_string(),
+                node_type: "train".to_string(),
+                title: title.to_string(),
+                canvas_x: 0.0,
+                canvas_y: 0.0,
+                config: BTreeMap::new(),
+            }],
+            edges: vec![],
+            groups: vec![],
+        }
+    }
+
+    #[test]
+    fn snapshots_skip_duplicates_and_restore_newest_state() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-canvas-snapshots-{}", std::process::id()));
+        let data_root = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&data_root));
+        let first =
+            write_snapshot(&data_root, canvas("Draft"), 10).expect("first snapshot should save");
+        let repeat =
+            write_snapshot(&data_root, canvas("Draft"), 10).expect("unchanged canvas should save");
+        assert_eq!(repeat.snapshot_id, first.snapshot_id);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let second =
+            write_snapshot(&data_root, canvas("Final"), 10).expect("edited canvas should save");
+        let listed = read_snapshots(&data_root).expect("snapshots should list");
+        let ids: Vec<&str> = listed
+            .iter()
+            .map(|entry| entry.snapshot_id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            [second.snapshot_id.as_str(), first.snapshot_id.as_str()]
+        );
+        let restored =
+            read_snapshot(&data_root, &first.snapshot_id, &roots).expect("snapshot should restore");
+        assert_eq!(restored.nodes[0].title, "Draft");
+        assert!(read_snapshot(&data_root, "../escape", &roots).is_err());
+        std::fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
@@ -0,0 +1,266 @@
+//! Structural validation of pipeline canvas graphs.
+//!
+//! Every problem is collected rather than failing on the first one, so the
+//! canvas can highlight all offending nodes at once. Cycles, dangling edges,
+//! unknown node types, bad ids, and edge conditions that can never be
+//! evaluated are errors because a run cannot proceed;
+//! nodes unreachable from the start node are warnings since a run simply
+//! skips them. Self-loops count as cycles. Issue messages come from the
+//! message catalog, so they follow the user's locale.
+
+use crate::commands::canvas_dataset_refs::STALE_REFERENCE_KIND;
+use crate::commands::canvas_groups::check_node_groups;
+use crate::commands::canvas_node_checks::check_nodes;
+use crate::commands::edge_conditions::check_edge_conditions;
+use crate::commands::message_catalog::message;
+use crate::commands::node_config_validation::check_node_configs;
+use crate::models::{
+    PipelineCanvasIssue, PipelineCanvasValidation, PipelineEdgeSnapshot, PipelineNodeGroup,
+    PipelineNodeSnapshot,
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Node types built into the Studio palette; plugins may add more.
+pub(crate) const PIPELINE_NODE_TYPES: [&str; 6] =
+    ["ingest", "filter", "train", "export", "chat", "custom"];
+const ERROR_SEVERITY: &str = "error";
+pub(crate) const WARNING_SEVERITY: &str = "warning";
+/// Issue kinds that do not stop a run; every other kind is an error.
+const WARNING_ISSUE_KINDS: [&str; 2] = ["unreachable_node", STALE_REFERENCE_KIND];
+/// Issues that make a canvas file unloadable, as opposed to merely unrunnable:
+/// broken ids and references to nodes that do not exist.
+pub(crate) const STRUCTURAL_ISSUE_KINDS: [&str; 4] = [
+    "empty_id",
+    "duplicate_node_id",
+    "dangling_edge",
+    "missing_start_node",
+];
+
+#[tauri::command]
+pub fn validate_pipeline_canvas(
+    nodes: Vec<PipelineNodeSnapshot>,
+    edges: Vec<PipelineEdgeSnapshot>,
+    start_node_id: Option<String>,
+    groups: Option<Vec<PipelineNodeGroup>>,
+) -> PipelineCanvasValidation {
+    let mut issues = validate_canvas(&nodes, &edges, start_node_id.as_deref());
+    issues.extend(check_node_configs(&nodes));
+    issues.extend(check_node_groups(
+        &nodes,
+        &edges,
+        &groups.unwrap_or_default(),
+    ));
+    PipelineCanvasValidation {
+        valid: !issues.iter().any(|issue| issue.severity == ERROR_SEVERITY),
+        issues,
+    }
+}
+
+/// Messages for every issue that stops a run from `start_node_id`, config
+/// and group problems included.
+pub(crate) fn blocking_issue_messages(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+    groups: &[PipelineNodeGroup],
+    start_node_id: &str,
+) -> Vec<String> {
+    let mut issues = validate_canvas(nodes, edges, Some(start_node_id));
+    issues.extend(check_node_configs(nodes));
+    issues.extend(check_node_groups(nodes, edges, groups));
+    issues
+        .into_iter()
+        .filter(|issue| issue.severity == ERROR_SEVERITY)
+        .map(|issue| issue.message)
+        .collect()
+}
+
+/// All issues in the graph; reachability is only checked when a start node is given.
+pub(crate) fn validate_canvas(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+    start_node_id: Option<&str>,
+) -> Vec<PipelineCanvasIssue> {
+    let mut issues = check_nodes(nodes);
+    let node_ids: HashSet<&str> = nodes.iter().map(|node| node.id.as_str()).collect();
+    let mut linked_edges = Vec::with_capacity(edges.len());
+    for edge in edges {
+        let missing: Vec<&str> = [edge.source_node_id.as_str(), edge.target_node_id.as_str()]
+            .into_iter()
+            .filter(|node_id| !node_ids.contains(node_id))
+            .collect();
+        if missing.is_empty() {
+            linked_edges.push((edge.source_node_id.as_str(), edge.target_node_id.as_str()));
+            continue;
+        }
+        let kind = if edge.id.trim().is_empty() || missing.iter().any(|id| id.trim().is_empty()) {
+            "empty_id"
+        } else {
+            "dangling_edge"
+        };
+        let message = message(
+            "validation.missing_edge_nodes",
+            &[("edge_id", &edge.id), ("node_ids", &quoted(&missing))],
+        );
+        issues.push(PipelineCanvasIssue {
+            edge_id: Some(edge.id.clone()),
+            ..issue(kind, missing, message)
+        });
+    }
+    let cyclic = cycle_node_ids(&node_ids, &linked_edges);
+    if !cyclic.is_empty() {
+        let message = message("validation.cycle", &[("node_ids", &quoted(&cyclic))]);
+        issues.push(issue("cycle", cyclic, message));
+    }
+    issues.extend(check_edge_conditions(nodes, edges));
+    if let Some(start_node_id) = start_node_id {
+        issues.extend(check_reachability(nodes, &linked_edges, start_node_id));
+    }
+    issues
+}
+
+/// Nodes on a cycle or between cycles, found by peeling sources and sinks away.
+fn cycle_node_ids<'a>(node_ids: &HashSet<&'a str>, edges: &[(&'a str, &'a str)]) -> Vec<&'a str> {
+    let mut remaining: HashSet<&str> = node_ids.clone();
+    loop {
+        let live_edges: Vec<&(&str, &str)> = edges
+            .iter()
+            .filter(|(source, target)| remaining.contains(source) && remaining.contains(target))
+            .collect();
+        let has_incoming: HashSet<&str> = live_edges.iter().map(|(_, target)| *target).collect();
+        let has_outgoing: HashSet<&str> = live_edges.iter().map(|(source, _)| *source).collect();
+        let before = remaining.len();
+        remaining
+            .retain(|node_id| has_incoming.contains(node_id) && has_outgoing.contains(node_id));
+        if remaining.len() == before {
+            break;
+        }
+    }
+    let sorted: BTreeSet<&str> = remaining.into_iter().collect();
+    sorted.into_iter().collect()
+}
+
+fn check_reachability(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[(&str, &str)],
+    start_node_id: &str,
+) -> Vec<PipelineCanvasIssue> {
+    if !nodes.iter().any(|node| node.id == start_node_id) {
+        let message = message(
+            "validation.missing_start_node",
+            &[("node_id", start_node_id)],
+        );
+        let node_ids = vec![start_node_id];
+        return vec![issue("missing_start_node", node_ids, message)];
+    }
+    let mut outgoing: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (source, target) in edges {
+        outgoing.entry(source).or_default().push(target);
+    }
+    let mut reached = HashSet::from([start_node_id]);
+    let mut stack = vec![start_node_id];
+    while let Some(current) = stack.pop() {
+        for next in outgoing.get(current).into_iter().flatten() {
+            if reached.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    let unreachable: Vec<&str> = nodes
+        .iter()
+        .map(|node| node.id.as_str())
+        .filter(|node_id| !node_id.trim().is_empty() && !reached.contains(node_id))
+        .collect();
+    if unreachable.is_empty() {
+        return vec![];
+    }
+    let message = message(
+        "validation.unreachable_node",
+        &[
+            ("node_ids", &quoted(&unreachable)),
+            ("node_id", start_node_id),
+        ],
+    );
+    vec![issue("unreachable_node", unreachable, message)]
+}
+
+pub(crate) fn issue(kind: &str, node_ids: Vec<&str>, message: String) -> PipelineCanvasIssue {
+    let severity = if WARNING_ISSUE_KINDS.contains(&kind) {
+        WARNING_SEVERITY
+    } else {
+        ERROR_SEVERITY
+    };
+    PipelineCanvasIssue {
+        kind: kind.to_string(),
+        severity: severity.to_string(),
+        node_ids: node_ids.into_iter().map(str::to_string).collect(),
+        edge_id: None,
+        field: None,
+        message,
+    }
+}
+
+fn quoted(node_ids: &[&str]) -> String {
+    let quoted: Vec<String> = node_ids
+        .iter()
+        .map(|node_id| format!("'{node_id}'"))
+        .collect();
+    quoted.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_canvas;
+    use crate::models::{PipelineEdgeSnapshot, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+
+    fn node(id: &str, node_type: &str) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> PipelineEdgeSnapshot {
+        PipelineEdgeSnapshot {
+            id: format!("{source}->{target}"),
+            source_node_id: source.to_string(),
+            target_node_id: target.to_string(),
+            condition: None,
+        }
+    }
+
+    #[test]
+    fn validate_canvas_reports_each_issue_kind_with_node_ids() {
+        let nodes = vec![
+            node("a", "ingest"),
+            node("b", "train"),
+            node("c", "train"),
+            node("d", "deploy"),
+        ];
+        let edges = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "b"),
+            edge("a", "ghost"),
+        ];
+        let issues = validate_canvas(&nodes, &edges, Some("a"));
+        let summary: Vec<(&str, Vec<String>)> = issues
+            .iter()
+            .map(|issue| (issue.kind.as_str(), issue.node_ids.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("unknown_node_type", vec!["d".to_string()]),
+                ("dangling_edge", vec!["ghost".to_string()]),
+                ("cycle", vec!["b".to_string(), "c".to_string()]),
+                ("unreachable_node", vec!["d".to_string()]),
+            ]
+        );
+        assert_eq!(issues[3].severity, "warning");
+    }
+}
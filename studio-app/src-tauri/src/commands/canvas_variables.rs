@@ -0,0 +1,182 @@
+//! `${variable}` placeholders in pipeline node configs.
+//!
+//! Naming the parts that vary, e.g. `dataset = ${dataset}`, lets one canvas
+//! serve many datasets. Export lists the placeholders as a variables
+//! manifest, and a run substitutes a value for each before validating and
+//! planning, so the run checks real values. Names are letters, digits, and
+//! underscores and do not start with a digit; any other `${...}` is plain
+//! text. Substituted values are not expanded again.
+
+use crate::models::{
+    ForgeStudioError, PipelineCanvasVariable, PipelineNodeSnapshot, PipelineVariableUsage,
+};
+use std::collections::BTreeMap;
+
+const PLACEHOLDER_OPEN: &str = "${";
+const PLACEHOLDER_CLOSE: char = '}';
+
+/// Every variable the node configs reference, by name, with where each is used.
+pub(crate) fn canvas_variables(nodes: &[PipelineNodeSnapshot]) -> Vec<PipelineCanvasVariable> {
+    let mut usages: BTreeMap<&str, Vec<PipelineVariableUsage>> = BTreeMap::new();
+    for node in nodes {
+        for (field, value) in &node.config {
+            for (_, _, name) in placeholders(value) {
+                usages.entry(name).or_default().push(PipelineVariableUsage {
+                    node_id: node.id.clone(),
+                    field: field.clone(),
+                });
+            }
+        }
+    }
+    usages
+        .into_iter()
+        .map(|(name, usages)| PipelineCanvasVariable {
+            name: name.to_string(),
+            usages,
+        })
+        .collect()
+}
+
+/// True when `value` still holds a placeholder, so its final form is unknown.
+pub(crate) fn has_placeholder(value: &str) -> bool {
+    !placeholders(value).is_empty()
+}
+
+/// Nodes with every placeholder replaced; every variable needs a value and
+/// every value a variable, so a misspelt name fails instead of running as-is.
+pub(crate) fn substitute_variables(
+    nodes: Vec<PipelineNodeSnapshot>,
+    values: &BTreeMap<String, String>,
+) -> Result<Vec<PipelineNodeSnapshot>, ForgeStudioError> {
+    let names: Vec<String> = canvas_variables(&nodes)
+        .into_iter()
+        .map(|variable| variable.name)
+        .collect();
+    let missing: Vec<&str> = names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !values.contains_key(*name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Pipeline cannot run: no value for variable(s) {}. Pass each in the run's variables.",
+            quoted(&missing)
+        )));
+    }
+    let unused: Vec<&str> = values
+        .keys()
+        .map(String::as_str)
+        .filter(|name| !names.iter().any(|used| used == name))
+        .collect();
+    if !unused.is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Pipeline cannot run: variable(s) {} are not used by any node. Check the spelling or remove them.",
+            quoted(&unused)
+        )));
+    }
+    Ok(nodes
+        .into_iter()
+        .map(|mut node| {
+            for value in node.config.values_mut() {
+                *value = substituted(value, values);
+            }
+            node
+        })
+        .collect())
+}
+
+/// Placeholders in `value` as (start, end, name) byte spans, in order.
+fn placeholders(value: &str) -> Vec<(usize, usize, &str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while let Some(open) = value[offset..].find(PLACEHOLDER_OPEN) {
+        let start = offset + open;
+        let name_start = start + PLACEHOLDER_OPEN.len();
+        let Some(close) = value[name_start..].find(PLACEHOLDER_CLOSE) else {
+            break;
+        };
+        let name = &value[name_start..name_start + close];
+        if is_variable_name(name) {
+            let end = name_start + close + 1;
+            found.push((start, end, name));
+            offset = end;
+        } else {
+            offset = name_start;
+        }
+    }
+    found
+}
+
+fn substituted(value: &str, values: &BTreeMap<String, String>) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut copied_to = 0;
+    for (start, end, name) in placeholders(value) {
+        result.push_str(&value[copied_to..start]);
+        result.push_str(values.get(name).map_or(&value[start..end], String::as_str));
+        copied_to = end;
+    }
+    result.push_str(&value[copied_to..]);
+    result
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|rest| rest.is_ascii_alphanumeric() || rest == '_')
+}
+
+fn quoted(names: &[&str]) -> String {
+    let quoted: Vec<String> = names.iter().map(|name| format!("'{name}'")).collect();
+    quoted.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canvas_variables, substitute_variables};
+    use crate::models::PipelineNodeSnapshot;
+    use std::collections::BTreeMap;
+
+    fn node(id: &str, config: &[(&str, &str)]) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: "train".to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: config
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn variables_are_listed_and_substituted() {
+        let nodes = vec![
+            node(
+                "train",
+                &[
+                    ("dataset", "${dataset}"),
+                    ("output_dir", "out/${dataset}-${run}"),
+                ],
+            ),
+            node("chat", &[("prompt", "cost is ${1x} or ${ unset")]),
+        ];
+        let variables = canvas_variables(&nodes);
+        let names: Vec<&str> = variables
+            .iter()
+            .map(|variable| variable.name.as_str())
+            .collect();
+        assert_eq!(names, ["dataset", "run"]);
+        assert_eq!(variables[0].usages.len(), 2);
+
+        let mut values = BTreeMap::from([("dataset".to_string(), "demo".to_string())]);
+        assert!(substitute_variables(nodes.clone(), &values).is_err());
+        values.insert("run".to_string(), "${dataset}".to_string());
+        let substituted = substitute_variables(nodes, &values).expect("all variables have values");
+        assert_eq!(substituted[0].config["output_dir"], "out/demo-${dataset}");
+        assert_eq!(substituted[1].config["prompt"], "cost is ${1x} or ${ unset");
+    }
+}
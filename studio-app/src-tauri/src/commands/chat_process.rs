@@ -0,0 +1,144 @@
+//! One `forge chat` process per playground prompt.
+//!
+//! Stdout is read in small chunks so tokens reach the UI as they are sampled;
+//! a chunk boundary can split a multi-byte character, so undecodable tail
+//! bytes are held until the next read instead of being emitted as `\u{FFFD}`.
+//! Stderr is drained on its own thread meanwhile.
+
+use crate::commands::forge_process::{forge_command, join_pipe_reader, read_pipe_in_background};
+use crate::models::ChatSessionOptions;
+use std::io::Read;
+use std::process::{Child, Stdio};
+
+const STDOUT_CHUNK_BYTES: usize = 64;
+
+pub(crate) fn build_chat_args(
+    model_path: &str,
+    options: &ChatSessionOptions,
+    prompt: &str,
+) -> Vec<String> {
+    let mut args = vec![
+        "chat".to_string(),
+        "--model-path".to_string(),
+        model_path.to_string(),
+        "--prompt".to_string(),
+        prompt.to_string(),
+    ];
+    let optional_flags = [
+        ("--dataset", options.dataset_name.clone()),
+        ("--tokenizer-path", options.tokenizer_path.clone()),
+        ("--architecture-file", options.architecture_path.clone()),
+        (
+            "--max-new-tokens",
+            options.max_new_tokens.map(|value| value.to_string()),
+        ),
+        (
+            "--temperature",
+            options.temperature.map(|value| value.to_string()),
+        ),
+        ("--top-k", options.top_k.map(|value| value.to_string())),
+    ];
+    for (flag, value) in optional_flags {
+        if let Some(value) = value {
+            args.push(flag.to_string());
+            args.push(value);
+        }
+    }
+    args
+}
+
+/// Runs `forge chat`, passing decoded text to `on_text`; returns the reply and any failure.
+pub(crate) fn run_chat_process(
+    forge_binary: &str,
+    data_root: &str,
+    args: Vec<String>,
+    on_text: &mut dyn FnMut(String),
+) -> (String, Option<String>) {
+    let spawn_result = forge_command(forge_binary)
+        .arg("--data-root")
+        .arg(data_root)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawn_result {
+        Ok(child) => child,
+        Err(error) => {
+            return (
+                String::new(),
+                Some(format!("Failed to run forge chat: {error}")),
+            )
+        }
+    };
+    let stderr_reader = read_pipe_in_background(child.stderr.take());
+    let response = stream_stdout(&mut child, on_text);
+    let stderr_text = join_pipe_reader(stderr_reader, "forge chat stderr");
+    let succeeded = child.wait().map(|status| status.success()).unwrap_or(false);
+    let error = (!succeeded).then(|| format!("forge chat failed: {}", stderr_text.trim()));
+    (response.trim().to_string(), error)
+}
+
+fn stream_stdout(child: &mut Child, on_text: &mut dyn FnMut(String)) -> String {
+    let Some(mut stdout) = child.stdout.take() else {
+        return String::new();
+    };
+    let mut response = String::new();
+    let mut pending: Vec<u8> = Vec::new();
+    let mut buf = [0_u8; STDOUT_CHUNK_BYTES];
+    loop {
+        let read_count = match stdout.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read_count) => read_count,
+            Err(error) => {
+                tracing::warn!("Could not read forge chat stdout: {error}");
+                break;
+            }
+        };
+        pending.extend_from_slice(&buf[..read_count]);
+        let text = take_decoded_prefix(&mut pending);
+        if !text.is_empty() {
+            response.push_str(&text);
+            on_text(text);
+        }
+    }
+    response.push_str(&String::from_utf8_lossy(&pending));
+    response
+}
+
+/// Decodes the complete UTF-8 prefix, keeping a character split across reads for later.
+fn take_decoded_prefix(pending: &mut Vec<u8>) -> String {
+    let decodable = match std::str::from_utf8(pending) {
+        Ok(_) => pending.len(),
+        Err(error) if error.error_len().is_none() => error.valid_up_to(),
+        Err(_) => pending.len(),
+    };
+    let text = String::from_utf8_lossy(&pending[..decodable]).to_string();
+    pending.drain(..decodable);
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_chat_args, take_decoded_prefix};
+    use crate::models::ChatSessionOptions;
+
+    #[test]
+    fn take_decoded_prefix_holds_back_split_characters() {
+        let mut pending = "hé".as_bytes()[..2].to_vec();
+        assert_eq!(take_decoded_prefix(&mut pending), "h");
+        pending.extend_from_slice(&"é".as_bytes()[1..]);
+        assert_eq!(take_decoded_prefix(&mut pending), "é");
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn build_chat_args_passes_only_set_options() {
+        let options = ChatSessionOptions {
+            temperature: Some(0.0),
+            ..ChatSessionOptions::default()
+        };
+        let args = build_chat_args("out/model.pt", &options, "hello");
+        assert_eq!(args[..3], ["chat", "--model-path", "out/model.pt"]);
+        assert_eq!(args[args.len() - 2..], ["--temperature", "0"]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Streaming inference playground sessions.
+//!
+//! Each prompt runs one `forge chat` process, which already streams tokens
+//! to stdout as it samples them. Stdout is forwarded as `chat-token` events
+//! while the process runs and a single `chat-complete` event closes the
+//! message. A session remembers its model, sampling options, and transcript
+//! and accepts one prompt at a time so replies never interleave.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::chat_process::{build_chat_args, run_chat_process};
+use crate::commands::path_safety::confine_path;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{
+    ChatCompleteEvent, ChatPromptStart, ChatSessionOptions, ChatSessionStart, ChatTokenEvent,
+    ChatTurn, ForgeStudioError,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, State};
+
+pub const CHAT_TOKEN_EVENT: &str = "chat-token";
+pub const CHAT_COMPLETE_EVENT: &str = "chat-complete";
+const SUPPORTED_MODEL_EXTENSIONS: [&str; 2] = ["pt", "onnx"];
+
+#[derive(Clone, Default)]
+pub struct ChatSessionStore {
+    inner: Arc<ChatSessionStoreInner>,
+}
+
+#[derive(Default)]
+struct ChatSessionStoreInner {
+    sessions: Mutex<HashMap<String, ChatSession>>,
+    next_session_id: AtomicU64,
+    next_message_id: AtomicU64,
+}
+
+struct ChatSession {
+    data_root: String,
+    model_path: String,
+    options: ChatSessionOptions,
+    turns: Vec<ChatTurn>,
+    busy: bool,
+}
+
+struct PromptJob {
+    app: AppHandle,
+    store: ChatSessionStore,
+    session_id: String,
+    message_id: String,
+    prompt: String,
+}
+
+#[tauri::command]
+pub fn start_chat_session(
+    data_root: String,
+    model_path: String,
+    options: Option<ChatSessionOptions>,
+    chat_store: State<'_, ChatSessionStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<ChatSessionStart, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let resolved_model_path = confine_path(
+        Path::new(&model_path),
+        "Model path",
+        &settings.workspace_roots(),
+    )?;
+    let extension = resolved_model_path
+        .extension()
+        .and_then(|value| value.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    if !resolved_model_path.is_file() || !SUPPORTED_MODEL_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Model {} is not a .pt or .onnx file. Pick a trained model from a run's output dir.",
+            resolved_model_path.display()
+        )));
+    }
+    let session_id = format!(
+        "chat-session-{}",
+        chat_store
+            .inner
+            .next_session_id
+            .fetch_add(1, Ordering::Relaxed)
+            + 1
+    );
+    let session = ChatSession {
+        data_root,
+        model_path: resolved_model_path.display().to_string(),
+        options: options.unwrap_or_default(),
+        turns: vec![],
+        busy: false,
+    };
+    let model_path = session.model_path.clone();
+    chat_store
+        .inner
+        .sessions
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("Chat session store lock poisoned"))?
+        .insert(session_id.clone(), session);
+    Ok(ChatSessionStart {
+        session_id,
+        model_path,
+    })
+}
+
+#[tauri::command]
+pub fn send_chat_prompt(
+    app: AppHandle,
+    session_id: String,
+    prompt: String,
+    chat_store: State<'_, ChatSessionStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<ChatPromptStart, ForgeStudioError> {
+    if prompt.trim().is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Chat prompt is empty. Type a message before sending.",
+        ));
+    }
+    let (data_root, args) = {
+        let mut sessions = chat_store
+            .inner
+            .sessions
+            .lock()
+            .map_err(|_| ForgeStudioError::internal("Chat session store lock poisoned"))?;
+        let session = sessions.get_mut(&session_id).ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Unknown chat session '{session_id}'. Start a new session."
+            ))
+        })?;
+        if session.busy {
+            return Err(ForgeStudioError::conflict(format!(
+                "Chat session '{session_id}' is still generating. Wait for chat-complete."
+            )));
+        }
+        session.busy = true;
+        let args = build_chat_args(&session.model_path, &session.options, &prompt);
+        (session.data_root.clone(), args)
+    };
+    let message_id = format!(
+        "chat-message-{}",
+        chat_store
+            .inner
+            .next_message_id
+            .fetch_add(1, Ordering::Relaxed)
+            + 1
+    );
+    let job = PromptJob {
+        app,
+        store: chat_store.inner().clone(),
+        session_id: session_id.clone(),
+        message_id: message_id.clone(),
+        prompt,
+    };
+    let forge_binary = settings.current().forge_binary;
+    std::thread::spawn(move || {
+        let (response, error) = run_chat_process(&forge_binary, &data_root, args, &mut |text| {
+            let _ = job.app.emit(
+                CHAT_TOKEN_EVENT,
+                ChatTokenEvent {
+                    session_id: job.session_id.clone(),
+                    message_id: job.message_id.clone(),
+                    text,
+                },
+            );
+        });
+        finish_prompt(job, response, error);
+    });
+    Ok(ChatPromptStart {
+        session_id,
+        message_id,
+    })
+}
+
+/// Returns the finished turns of a session in sending order.
+#[tauri::command]
+pub fn get_chat_transcript(
+    session_id: String,
+    chat_store: State<'_, ChatSessionStore>,
+) -> Result<Vec<ChatTurn>, ForgeStudioError> {
+    let sessions = chat_store
+        .inner
+        .sessions
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("Chat session store lock poisoned"))?;
+    sessions
+        .get(&session_id)
+        .map(|session| session.turns.clone())
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Unknown chat session '{session_id}'. Start a new session."
+            ))
+        })
+}
+
+#[tauri::command]
+pub fn close_chat_session(
+    session_id: String,
+    chat_store: State<'_, ChatSessionStore>,
+) -> Result<(), ForgeStudioError> {
+    chat_store
+        .inner
+        .sessions
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("Chat session store lock poisoned"))?
+        .remove(&session_id)
+        .map(|_| ())
+        .ok_or_else(|| ForgeStudioError::not_found(format!("Unknown chat session '{session_id}'")))
+}
+
+fn finish_prompt(job: PromptJob, response: String, error: Option<String>) {
+    if let Ok(mut sessions) = job.store.inner.sessions.lock() {
+        if let Some(session) = sessions.get_mut(&job.session_id) {
+            session.busy = false;
+            if error.is_none() {
+                session.turns.push(ChatTurn {
+                    message_id: job.message_id.clone(),
+                    prompt: job.prompt.clone(),
+                    response: response.clone(),
+                });
+            }
+        }
+    }
+    let _ = job.app.emit(
+        CHAT_COMPLETE_EVENT,
+        ChatCompleteEvent {
+            session_id: job.session_id,
+            message_id: job.message_id,
+            response,
+            error,
+        },
+    );
+}
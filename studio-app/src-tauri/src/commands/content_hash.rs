@@ -0,0 +1,51 @@
+//! Content hashing that matches the forge CLI's record and version digests.
+
+use crate::models::ForgeStudioError;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Hex-encoded SHA-256 digest of `payload`.
+pub(crate) fn sha256_hex(payload: &[u8]) -> String {
+    to_hex(&Sha256::digest(payload))
+}
+
+/// Hex-encoded SHA-256 of a file, streamed so model weights are not loaded whole.
+pub(crate) fn sha256_file(file_path: &Path) -> Result<String, ForgeStudioError> {
+    let mut hasher = Sha256::new();
+    File::open(file_path)
+        .and_then(|mut file| io::copy(&mut file, &mut hasher))
+        .map_err(|error| {
+            ForgeStudioError::io(format!("Failed to hash {}: {error}", file_path.display()))
+        })?;
+    Ok(to_hex(&hasher.finalize()))
+}
+
+fn to_hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Mirrors `exact_deduplication.build_record_id`: hash of lowercased,
+/// whitespace-normalized text.
+pub(crate) fn build_record_id(text: &str) -> String {
+    let normalized_text = text
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    sha256_hex(normalized_text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_record_id;
+
+    #[test]
+    fn build_record_id_ignores_case_and_whitespace() {
+        assert_eq!(
+            build_record_id("Hello   World\n"),
+            build_record_id("hello world")
+        );
+    }
+}
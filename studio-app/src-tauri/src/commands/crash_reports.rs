@@ -0,0 +1,299 @@
+//! Crash reports for Studio panics and crashed forge tasks.
+//!
+//! Once logging is up, a panic hook writes each panic's message, location,
+//! and backtrace, together with Studio's last log lines, to a JSON file in
+//! the app data directory before handing over to the default hook. The task
+//! store records forge tasks the same way when they crashed rather than
+//! failed: killed by a signal, or ended by a Python traceback instead of an
+//! error message. Only the newest reports are kept. `get_crash_reports` reads
+//! them back so users can attach them to bug reports.
+
+use crate::commands::app_logging::tail_log_lines;
+use crate::commands::run_store_io::{ensure_dir, read_json_file, write_json_file};
+use crate::models::{CommandTaskStatus, CrashReport, ForgeStudioError};
+use chrono::{SecondsFormat, Utc};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::State;
+
+const CRASH_DIR_NAME: &str = "crash_reports";
+const REPORT_FILE_PREFIX: &str = "crash-";
+const MAX_CRASH_REPORTS: usize = 50;
+const RECENT_LOG_LINES: usize = 100;
+const STDERR_TAIL_LINES: usize = 40;
+const PYTHON_TRACEBACK_MARKER: &str = "Traceback (most recent call last)";
+
+static REPORT_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Where crash reports go; shared by the panic hook and the task store.
+#[derive(Clone, Default)]
+pub struct CrashReporter {
+    inner: Arc<Mutex<CrashReporterState>>,
+}
+
+#[derive(Default)]
+struct CrashReporterState {
+    /// Unset until `install` runs during app setup; nothing is recorded before.
+    crash_dir: Option<PathBuf>,
+    log_dir: Option<PathBuf>,
+}
+
+impl CrashReporter {
+    /// Stores reports under `data_dir`, quoting logs from `log_dir`, and
+    /// installs the panic hook.
+    pub fn install(&self, data_dir: &Path, log_dir: &Path) {
+        self.attach(data_dir, log_dir);
+        let reporter = self.clone();
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            reporter.record_panic(info);
+            default_hook(info);
+        }));
+    }
+
+    fn attach(&self, data_dir: &Path, log_dir: &Path) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.crash_dir = Some(data_dir.join(CRASH_DIR_NAME));
+            state.log_dir = Some(log_dir.to_path_buf());
+        }
+    }
+
+    fn crash_dir(&self) -> Option<PathBuf> {
+        self.inner.lock().ok()?.crash_dir.clone()
+    }
+
+    fn record_panic(&self, info: &PanicHookInfo<'_>) {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic without a message".to_string());
+        let thread = std::thread::current();
+        let thread_name = thread.name().unwrap_or("unnamed");
+        let mut report = self.new_report(
+            "panic",
+            format!("Thread '{thread_name}' panicked: {message}"),
+        );
+        report.location = info.location().map(ToString::to_string);
+        report.backtrace = Some(Backtrace::force_capture().to_string());
+        self.save(&report);
+    }
+
+    /// Records `task` if it crashed; ordinary failures and successes are not
+    /// crashes. `killed` is true when a signal ended the process.
+    pub(crate) fn record_task_crash(&self, task: &CommandTaskStatus, killed: bool) {
+        let traceback = task.stderr.contains(PYTHON_TRACEBACK_MARKER);
+        if task.status != "failed" || !(killed || traceback) {
+            return;
+        }
+        let ending = if killed {
+            "was killed by a signal".to_string()
+        } else {
+            let exit_code = task.exit_code.unwrap_or(-1);
+            format!("exited with code {exit_code} after a Python traceback")
+        };
+        let message = format!(
+            "Forge task {} (`forge {}`) {ending}",
+            task.task_id,
+            task.args.join(" ")
+        );
+        let mut report = self.new_report("task_failure", message);
+        let stderr_lines: Vec<&str> = task.stderr.lines().collect();
+        let tail_start = stderr_lines.len().saturating_sub(STDERR_TAIL_LINES);
+        report.stderr_tail = Some(stderr_lines[tail_start..].join("\n"));
+        self.save(&report);
+    }
+
+    fn new_report(&self, kind: &str, message: String) -> CrashReport {
+        let now = Utc::now();
+        let sequence = REPORT_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let log_dir = self
+            .inner
+            .lock()
+            .ok()
+            .and_then(|state| state.log_dir.clone());
+        CrashReport {
+            report_id: format!(
+                "{REPORT_FILE_PREFIX}{}-{}-{sequence:04}",
+                now.format("%Y%m%dT%H%M%SZ"),
+                std::process::id()
+            ),
+            kind: kind.to_string(),
+            created_at: now.to_rfc3339_opts(SecondsFormat::Secs, true),
+            studio_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            message,
+            location: None,
+            backtrace: None,
+            stderr_tail: None,
+            recent_logs: log_dir
+                .map(|log_dir| tail_log_lines(&log_dir, RECENT_LOG_LINES))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Writes `report`, logging rather than returning failures: a crash
+    /// report that cannot be saved must not cause another error.
+    fn save(&self, report: &CrashReport) {
+        let Some(crash_dir) = self.crash_dir() else {
+            return;
+        };
+        tracing::error!(report_id = report.report_id, "{}", report.message);
+        if let Err(error) = write_report(&crash_dir, report) {
+            tracing::warn!("Crash report {} was not saved: {error}", report.report_id);
+        }
+    }
+}
+
+/// Saved crash reports, newest first, optionally only the newest `limit`.
+#[tauri::command]
+pub fn get_crash_reports(
+    limit: Option<usize>,
+    crash_reporter: State<'_, CrashReporter>,
+) -> Result<Vec<CrashReport>, ForgeStudioError> {
+    let Some(crash_dir) = crash_reporter.crash_dir() else {
+        return Ok(Vec::new());
+    };
+    let mut reports = Vec::new();
+    for report_path in report_paths(&crash_dir)?
+        .iter()
+        .rev()
+        .take(limit.unwrap_or(usize::MAX))
+    {
+        let payload = read_json_file(report_path)?;
+        let report = serde_json::from_value(payload).map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Failed to parse crash report {}: {error}. Delete it or clear crash reports.",
+                report_path.display()
+            ))
+        })?;
+        reports.push(report);
+    }
+    Ok(reports)
+}
+
+/// Deletes every saved crash report and returns how many there were.
+#[tauri::command]
+pub fn clear_crash_reports(
+    crash_reporter: State<'_, CrashReporter>,
+) -> Result<usize, ForgeStudioError> {
+    let Some(crash_dir) = crash_reporter.crash_dir() else {
+        return Ok(0);
+    };
+    let report_paths = report_paths(&crash_dir)?;
+    for report_path in &report_paths {
+        fs::remove_file(report_path).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to remove {}: {error}",
+                report_path.display()
+            ))
+        })?;
+    }
+    Ok(report_paths.len())
+}
+
+/// Writes `report` and drops the oldest reports beyond the limit.
+fn write_report(crash_dir: &Path, report: &CrashReport) -> Result<(), ForgeStudioError> {
+    ensure_dir(crash_dir)?;
+    let payload = serde_json::to_value(report).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize crash report: {error}"))
+    })?;
+    write_json_file(
+        &crash_dir.join(format!("{}.json", report.report_id)),
+        &payload,
+    )?;
+    let report_paths = report_paths(crash_dir)?;
+    let excess = report_paths.len().saturating_sub(MAX_CRASH_REPORTS);
+    for report_path in &report_paths[..excess] {
+        let _ = fs::remove_file(report_path);
+    }
+    Ok(())
+}
+
+/// Report files in `crash_dir`, oldest first; names start with their time.
+fn report_paths(crash_dir: &Path) -> Result<Vec<PathBuf>, ForgeStudioError> {
+    if !crash_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let read_dir = fs::read_dir(crash_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", crash_dir.display()))
+    })?;
+    let mut report_paths: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(REPORT_FILE_PREFIX) && name.ends_with(".json"))
+        })
+        .collect();
+    report_paths.sort();
+    Ok(report_paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{report_paths, CrashReporter};
+    use crate::models::CommandTaskStatus;
+    use std::fs;
+
+    fn failed_task(stderr: &str) -> CommandTaskStatus {
+        CommandTaskStatus {
+            task_id: "forge-task-7".to_string(),
+            status: "failed".to_string(),
+            status_label: "Failed".to_string(),
+            command: "train".to_string(),
+            args: vec![
+                "train".to_string(),
+                "--dataset".to_string(),
+                "news".to_string(),
+            ],
+            exit_code: Some(1),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+            elapsed_seconds: 3,
+            estimated_total_seconds: 60,
+            remaining_seconds: 0,
+            progress_percent: 100.0,
+            eta_source: "command_average".to_string(),
+            completed_steps: None,
+            total_steps: None,
+            seconds_per_step: None,
+        }
+    }
+
+    #[test]
+    fn crashed_tasks_are_reported_with_logs_and_plain_failures_are_not() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("forge-crash-reports-{}", std::process::id()));
+        let log_dir = temp_dir.join("logs");
+        fs::create_dir_all(&log_dir).expect("log dir should be created");
+        fs::write(
+            log_dir.join("studio.2000-01-01.log"),
+            "line one\nline two\n",
+        )
+        .expect("log should be written");
+        let reporter = CrashReporter::default();
+        reporter.attach(&temp_dir, &log_dir);
+
+        reporter.record_task_crash(&failed_task("Error: dataset 'news' not found"), false);
+        let traceback =
+            "Traceback (most recent call last):\n  File \"train.py\"\nRuntimeError: CUDA";
+        reporter.record_task_crash(&failed_task(traceback), false);
+        reporter.record_task_crash(&failed_task(""), true);
+
+        let crash_dir = reporter.crash_dir().expect("crash dir should be set");
+        let report_paths = report_paths(&crash_dir).expect("reports should list");
+        assert_eq!(report_paths.len(), 2);
+        let first = fs::read_to_string(&report_paths[0]).expect("report should read");
+        assert!(first.contains("after a Python traceback"));
+        assert!(first.contains("RuntimeError: CUDA") && first.contains("line two"));
+        let second = fs::read_to_string(&report_paths[1]).expect("report should read");
+        assert!(second.contains("was killed by a signal"));
+        fs::remove_dir_all(&temp_dir).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,183 @@
+//! Five-field cron expressions for scheduled pipeline runs.
+//!
+//! Fields are minute, hour, day of month, month, and day of week, each a
+//! `*`, a value, a `a-b` range, or a comma list of those, optionally with a
+//! `/step`. Day of week runs 0-6 from Sunday, with 7 also meaning Sunday.
+//! As in cron, when both day fields are restricted a day matching either
+//! one fires. Expressions are evaluated in UTC so a schedule fires at the
+//! same instant whatever the machine's timezone or daylight saving.
+
+use crate::models::ForgeStudioError;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+
+/// Common shorthands and the expressions they stand for.
+const CRON_MACROS: [(&str, &str); 5] = [
+    ("@hourly", "0 * * * *"),
+    ("@daily", "0 0 * * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@monthly", "0 0 1 * *"),
+    ("@yearly", "0 0 1 1 *"),
+];
+/// Long enough to reach the next February 29th from any date.
+const MAX_SEARCH_DAYS: i64 = 366 * 8;
+
+/// Each field is a bitmask of the values it matches.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub(crate) fn parse(expression: &str) -> Result<Self, ForgeStudioError> {
+        let trimmed = expression.trim();
+        let expanded = CRON_MACROS
+            .iter()
+            .find(|(name, _)| *name == trimmed)
+            .map_or(trimmed, |(_, expanded)| expanded);
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Cron expression '{trimmed}' must have 5 fields (minute hour day month weekday), got {}. Use e.g. '0 2 * * *' for 02:00 UTC daily.",
+                fields.len()
+            )));
+        };
+        let mut days_of_week = parse_field(day_of_week, "day of week", 0, 7)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            minutes: parse_field(minute, "minute", 0, 59)?,
+            hours: parse_field(hour, "hour", 0, 23)?,
+            days_of_month: parse_field(day_of_month, "day of month", 1, 31)?,
+            months: parse_field(month, "month", 1, 12)?,
+            days_of_week,
+            day_of_month_restricted: day_of_month != "*",
+            day_of_week_restricted: day_of_week != "*",
+        })
+    }
+
+    /// The first matching minute strictly after `after`, or `None` if the
+    /// expression never fires (e.g. February 30th).
+    pub(crate) fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let limit = after + Duration::days(MAX_SEARCH_DAYS);
+        let mut candidate = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        while candidate <= limit {
+            let date = candidate.date_naive();
+            if !matches(self.months, date.month()) {
+                let (year, month) = match date.month() {
+                    12 => (date.year() + 1, 1),
+                    month => (date.year(), month + 1),
+                };
+                candidate = start_of_day(NaiveDate::from_ymd_opt(year, month, 1)?);
+            } else if !self.matches_day(date) {
+                candidate = start_of_day(date.succ_opt()?);
+            } else if !matches(self.hours, candidate.hour()) {
+                candidate = candidate.with_minute(0)? + Duration::hours(1);
+            } else if !matches(self.minutes, candidate.minute()) {
+                candidate += Duration::minutes(1);
+            } else {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let by_month_day = matches(self.days_of_month, date.day());
+        let by_weekday = matches(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => by_month_day || by_weekday,
+            _ => by_month_day && by_weekday,
+        }
+    }
+}
+
+fn parse_field(field: &str, name: &str, min: u32, max: u32) -> Result<u64, ForgeStudioError> {
+    let invalid = |reason: &str| {
+        ForgeStudioError::invalid_input(format!(
+            "Cron {name} field '{field}' is invalid: {reason}. Use values {min}-{max}."
+        ))
+    };
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| invalid("step is not a number"))?;
+                if step == 0 {
+                    return Err(invalid("step must be at least 1"));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => {
+                let (start, end) = range.split_once('-').unwrap_or((range, range));
+                let parse = |value: &str| -> Result<u32, ForgeStudioError> {
+                    value.parse().map_err(|_| invalid("not a number"))
+                };
+                (parse(start)?, parse(end)?)
+            }
+        };
+        if start < min || end > max || start > end {
+            return Err(invalid("value out of range"));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn matches(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .map(|midnight| midnight.and_utc())
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CronSchedule;
+    use chrono::{DateTime, Utc};
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp)
+            .expect("timestamp should parse")
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn next_after_finds_matching_minutes() {
+        let nightly = CronSchedule::parse("30 2 * * *").expect("valid cron");
+        assert_eq!(
+            nightly.next_after(at("2026-03-10T02:30:00Z")),
+            Some(at("2026-03-11T02:30:00Z"))
+        );
+        let weekdays = CronSchedule::parse("*/15 9-17 * * 1-5").expect("valid cron");
+        // 2026-03-14 is a Saturday.
+        assert_eq!(
+            weekdays.next_after(at("2026-03-13T17:50:00Z")),
+            Some(at("2026-03-16T09:00:00Z"))
+        );
+        let leap_day = CronSchedule::parse("0 0 29 2 *").expect("valid cron");
+        assert_eq!(
+            leap_day.next_after(at("2026-03-01T00:00:00Z")),
+            Some(at("2028-02-29T00:00:00Z"))
+        );
+        let never = CronSchedule::parse("0 0 30 2 *").expect("valid cron");
+        assert_eq!(never.next_after(at("2026-01-01T00:00:00Z")), None);
+        assert!(CronSchedule::parse("0 24 * * *").is_err());
+        assert!(CronSchedule::parse("@daily").is_ok());
+    }
+}
@@ -0,0 +1,284 @@
+//! Pre-flight checklist for a data root, rendered by the onboarding flow.
+//!
+//! Each check reports `pass`, `warn`, or `fail` with a remediation, instead
+//! of the first problem aborting the command, so a user pointing Studio at a
+//! new folder sees everything to fix at once. Checks that need the directory
+//! itself are `skipped` when it is missing.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::dataset_store_io::{read_catalog, read_child_dirs};
+use crate::commands::forge_process::forge_command;
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{DataRootCheck, DataRootCheckItem, ForgeStudioError};
+use std::fs;
+use std::path::Path;
+use std::process::Stdio;
+use tauri::State;
+
+/// Top-level folders the forge CLI keeps in every data root.
+pub(crate) const DATA_ROOT_DIRS: [&str; 3] = ["datasets", "runs", "lineage"];
+const LOW_DISK_SPACE_BYTES: u64 = 5 * 1024 * 1024 * 1024;
+const MIN_DISK_SPACE_BYTES: u64 = 512 * 1024 * 1024;
+const PROBE_FILE_NAME: &str = ".forge-studio-write-probe";
+
+/// Checks `path` as a data root; `ready` is false when any check failed.
+#[tauri::command]
+pub async fn check_data_root(
+    path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<DataRootCheck, ForgeStudioError> {
+    let forge_binary = settings.current().forge_binary;
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let data_root = resolve_data_root_path(&path, &roots)?;
+        Ok(run_checks(&data_root, &forge_binary))
+    })
+    .await
+}
+
+fn run_checks(data_root: &Path, forge_binary: &str) -> DataRootCheck {
+    let mut checks = vec![check_directory(data_root)];
+    if checks[0].status == "pass" {
+        checks.push(check_layout(data_root));
+        checks.push(check_permissions(data_root));
+        checks.push(check_disk_space(data_root));
+        checks.push(check_catalogs(data_root));
+    } else {
+        for (check_id, label) in [
+            ("layout", "Directory layout"),
+            ("permissions", "Read and write access"),
+            ("disk_space", "Free disk space"),
+            ("catalogs", "Dataset catalogs"),
+        ] {
+            checks.push(item(
+                check_id,
+                label,
+                "skipped",
+                "Needs the data root directory.",
+                None,
+            ));
+        }
+    }
+    checks.push(check_forge_binary(forge_binary));
+    DataRootCheck {
+        data_root: data_root.display().to_string(),
+        ready: checks.iter().all(|check| check.status != "fail"),
+        checks,
+    }
+}
+
+fn check_directory(data_root: &Path) -> DataRootCheckItem {
+    let label = "Data root directory";
+    if data_root.is_dir() {
+        return item("directory", label, "pass", "Directory exists.", None);
+    }
+    let (detail, remediation) = if data_root.exists() {
+        (
+            "Path exists but is not a directory.",
+            "Choose a folder instead of a file.",
+        )
+    } else {
+        (
+            "Directory does not exist.",
+            "Create the folder or initialize a new data root there.",
+        )
+    };
+    item("directory", label, "fail", detail, Some(remediation))
+}
+
+fn check_layout(data_root: &Path) -> DataRootCheckItem {
+    let label = "Directory layout";
+    let blocked: Vec<&str> = DATA_ROOT_DIRS
+        .into_iter()
+        .filter(|name| data_root.join(name).exists() && !data_root.join(name).is_dir())
+        .collect();
+    if !blocked.is_empty() {
+        let detail = format!("{} exist but are not directories.", blocked.join(", "));
+        return item(
+            "layout",
+            label,
+            "fail",
+            &detail,
+            Some("Move those files out of the data root."),
+        );
+    }
+    let missing: Vec<&str> = DATA_ROOT_DIRS
+        .into_iter()
+        .filter(|name| !data_root.join(name).is_dir())
+        .collect();
+    if missing.is_empty() {
+        return item(
+            "layout",
+            label,
+            "pass",
+            "datasets, runs, and lineage folders exist.",
+            None,
+        );
+    }
+    let detail = format!("Missing {}.", missing.join(", "));
+    let remediation =
+        "Forge creates them on first use; initialize the data root to create them now.";
+    item("layout", label, "warn", &detail, Some(remediation))
+}
+
+fn check_permissions(data_root: &Path) -> DataRootCheckItem {
+    let label = "Read and write access";
+    let remediation = "Grant your user read and write access to the folder, or choose another one.";
+    if let Err(error) = fs::read_dir(data_root) {
+        let detail = format!("Cannot list the folder: {error}.");
+        return item("permissions", label, "fail", &detail, Some(remediation));
+    }
+    let probe_path = data_root.join(PROBE_FILE_NAME);
+    let written = fs::write(&probe_path, b"probe").and_then(|()| fs::remove_file(&probe_path));
+    match written {
+        Ok(()) => item(
+            "permissions",
+            label,
+            "pass",
+            "Folder is readable and writable.",
+            None,
+        ),
+        Err(error) => {
+            let detail = format!("Cannot write to the folder: {error}.");
+            item("permissions", label, "fail", &detail, Some(remediation))
+        }
+    }
+}
+
+fn check_disk_space(data_root: &Path) -> DataRootCheckItem {
+    let label = "Free disk space";
+    let available = match fs4::available_space(data_root) {
+        Ok(available) => available,
+        Err(error) => {
+            let detail = format!("Could not read free space: {error}.");
+            return item("disk_space", label, "warn", &detail, None);
+        }
+    };
+    let detail = format!(
+        "{:.1} GiB available.",
+        available as f64 / (1024.0 * 1024.0 * 1024.0)
+    );
+    let remediation = "Free up space or choose a data root on a larger disk.";
+    let status = if available < MIN_DISK_SPACE_BYTES {
+        "fail"
+    } else if available < LOW_DISK_SPACE_BYTES {
+        "warn"
+    } else {
+        return item("disk_space", label, "pass", &detail, None);
+    };
+    item("disk_space", label, status, &detail, Some(remediation))
+}
+
+fn check_catalogs(data_root: &Path) -> DataRootCheckItem {
+    let label = "Dataset catalogs";
+    let datasets_dir = data_root.join("datasets");
+    let dataset_names = if datasets_dir.is_dir() {
+        match read_child_dirs(&datasets_dir) {
+            Ok(names) => names,
+            Err(error) => return item("catalogs", label, "fail", &error.to_string(), None),
+        }
+    } else {
+        Vec::new()
+    };
+    let unreadable: Vec<String> = dataset_names
+        .iter()
+        .filter(|name| read_catalog(&datasets_dir.join(name)).is_err())
+        .cloned()
+        .collect();
+    if unreadable.is_empty() {
+        let detail = format!("{} dataset catalog(s) parse.", dataset_names.len());
+        return item("catalogs", label, "pass", &detail, None);
+    }
+    let detail = format!("Unreadable catalog.json in {}.", unreadable.join(", "));
+    let remediation = "Restore those catalogs from a backup or re-ingest the datasets.";
+    item("catalogs", label, "fail", &detail, Some(remediation))
+}
+
+fn check_forge_binary(forge_binary: &str) -> DataRootCheckItem {
+    let label = "Forge CLI";
+    let remediation = "Install forge in the workspace environment, or set forge_binary in settings to its full path.";
+    let output = forge_command(forge_binary)
+        .arg("--help")
+        .stdin(Stdio::null())
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            let detail = format!("`{forge_binary}` runs.");
+            item("forge_binary", label, "pass", &detail, None)
+        }
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let last_line = stderr.lines().last().unwrap_or_default();
+            let detail = format!(
+                "`{forge_binary} --help` exited with {}: {last_line}",
+                output.status
+            );
+            item("forge_binary", label, "fail", &detail, Some(remediation))
+        }
+        Err(error) => {
+            let detail = format!("Could not run `{forge_binary}`: {error}.");
+            item("forge_binary", label, "fail", &detail, Some(remediation))
+        }
+    }
+}
+
+fn item(
+    check_id: &str,
+    label: &str,
+    status: &str,
+    detail: &str,
+    remediation: Option<&str>,
+) -> DataRootCheckItem {
+    DataRootCheckItem {
+        check_id: check_id.to_string(),
+        label: label.to_string(),
+        status: status.to_string(),
+        detail: detail.to_string(),
+        remediation: remediation.map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_checks;
+    use std::fs;
+
+    #[test]
+    fn checklist_reports_every_problem_without_stopping() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-data-root-check-{}", std::process::id()));
+        fs::create_dir_all(data_root.join("datasets/news")).expect("dataset dir should be created");
+        fs::write(data_root.join("datasets/news/catalog.json"), "{").expect("catalog write");
+        fs::write(data_root.join("lineage"), "not a dir").expect("lineage file write");
+
+        let report = run_checks(&data_root, "forge-binary-that-does-not-exist");
+        let statuses: Vec<(&str, &str)> = report
+            .checks
+            .iter()
+            .map(|check| (check.check_id.as_str(), check.status.as_str()))
+            .collect();
+        assert_eq!(
+            statuses[..3],
+            [
+                ("directory", "pass"),
+                ("layout", "fail"),
+                ("permissions", "pass")
+            ]
+        );
+        assert_eq!(
+            statuses[4..],
+            [("catalogs", "fail"), ("forge_binary", "fail")]
+        );
+        assert!(report.checks[4].detail.contains("news"));
+        assert!(!report.ready);
+        assert!(!data_root.join(".forge-studio-write-probe").exists());
+
+        let missing = run_checks(
+            &data_root.join("missing"),
+            "forge-binary-that-does-not-exist",
+        );
+        assert_eq!(missing.checks[1].status, "skipped");
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
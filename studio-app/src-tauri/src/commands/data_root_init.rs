@@ -0,0 +1,151 @@
+//! Scaffolds a new data root so Studio works before any CLI command ran.
+//!
+//! The forge CLI creates folders and index files lazily, the first time a
+//! command needs them, so a fresh folder shows empty or erroring panels until
+//! then. `init_data_root` creates the layout and the empty run index and
+//! lineage graph up front. Anything already present is kept as is, so running
+//! it on an existing data root only fills in what is missing.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::data_root_check::DATA_ROOT_DIRS;
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::lineage_store_io::lineage_graph_path;
+use crate::commands::run_store_io::{
+    resolve_data_root_path, resolve_workspace_path, write_json_file,
+};
+use crate::models::{DataRootInit, DataRootInitOptions, ForgeStudioError};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use tauri::State;
+
+/// Conventional home for run and export outputs, as `forge verify` uses.
+pub(crate) const OUTPUTS_DIR_NAME: &str = "outputs";
+
+#[tauri::command]
+pub fn init_data_root(
+    path: String,
+    options: Option<DataRootInitOptions>,
+    settings: State<'_, SettingsStore>,
+) -> Result<DataRootInit, ForgeStudioError> {
+    let options = options.unwrap_or_default();
+    if options.add_workspace_root {
+        add_workspace_root(&path, &settings)?;
+    }
+    let data_root = resolve_data_root_path(&path, &settings.workspace_roots())?;
+    let init = scaffold_data_root(&data_root)?;
+    if options.set_default {
+        settings.update(json!({"default_data_root": init.data_root}))?;
+    }
+    Ok(init)
+}
+
+fn add_workspace_root(path: &str, settings: &SettingsStore) -> Result<(), ForgeStudioError> {
+    let folder = resolve_workspace_path(path);
+    fs::create_dir_all(&folder).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to create {}: {error}", folder.display()))
+    })?;
+    let mut workspace_roots = settings.current().workspace_roots;
+    let folder_text = folder.display().to_string();
+    if !workspace_roots.contains(&folder_text) {
+        workspace_roots.push(folder_text);
+        settings.update(json!({"workspace_roots": workspace_roots}))?;
+    }
+    Ok(())
+}
+
+fn scaffold_data_root(data_root: &Path) -> Result<DataRootInit, ForgeStudioError> {
+    let mut init = DataRootInit {
+        data_root: data_root.display().to_string(),
+        created: Vec::new(),
+        existing: Vec::new(),
+    };
+    for dir_name in DATA_ROOT_DIRS.into_iter().chain([OUTPUTS_DIR_NAME]) {
+        let dir = data_root.join(dir_name);
+        if dir.is_dir() {
+            init.existing.push(dir_name.to_string());
+            continue;
+        }
+        fs::create_dir_all(&dir).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to create {}: {error}. Check the folder's permissions with check_data_root.",
+                dir.display()
+            ))
+        })?;
+        init.created.push(dir_name.to_string());
+    }
+    let index_files = [
+        (
+            data_root.join("runs").join("index.json"),
+            json!({"runs": []}),
+        ),
+        (
+            lineage_graph_path(data_root),
+            json!({"runs": {}, "edges": []}),
+        ),
+    ];
+    for (index_path, empty_payload) in index_files {
+        let relative_path = index_path
+            .strip_prefix(data_root)
+            .unwrap_or(&index_path)
+            .display()
+            .to_string();
+        if write_if_missing(&index_path, &empty_payload)? {
+            init.created.push(relative_path);
+        } else {
+            init.existing.push(relative_path);
+        }
+    }
+    Ok(init)
+}
+
+/// Writes `payload` unless the file exists; the lock keeps a forge command
+/// creating the same file meanwhile from being overwritten.
+fn write_if_missing(path: &Path, payload: &Value) -> Result<bool, ForgeStudioError> {
+    with_file_lock(path, || {
+        if path.exists() {
+            return Ok(false);
+        }
+        write_json_file(path, payload)?;
+        Ok(true)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scaffold_data_root;
+    use crate::commands::lineage_store_io::read_lineage_graph;
+    use crate::commands::run_index::read_run_index;
+    use std::fs;
+
+    #[test]
+    fn scaffolds_missing_layout_and_keeps_existing_files() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-data-root-init-{}", std::process::id()));
+        fs::create_dir_all(data_root.join("runs")).expect("runs dir should be created");
+        fs::write(data_root.join("runs/index.json"), r#"{"runs": ["run-a"]}"#)
+            .expect("index should be written");
+
+        let init = scaffold_data_root(&data_root).expect("scaffold should succeed");
+        assert_eq!(
+            init.created,
+            [
+                "datasets",
+                "lineage",
+                "outputs",
+                "lineage/model_lineage.json"
+            ]
+        );
+        assert_eq!(init.existing, ["runs", "runs/index.json"]);
+        assert_eq!(
+            read_run_index(&data_root).expect("index should read"),
+            ["run-a"]
+        );
+        let graph = read_lineage_graph(&data_root).expect("graph should read");
+        assert!(graph.runs.is_empty() && graph.edges.is_empty());
+
+        let again = scaffold_data_root(&data_root).expect("rerun should succeed");
+        assert!(again.created.is_empty());
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
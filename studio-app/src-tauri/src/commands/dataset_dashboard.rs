@@ -0,0 +1,117 @@
+//! Per-version dataset dashboard: language mix, quality spread, top sources.
+
+use crate::commands::dataset_store_io::{float_field, read_records, resolve_version, string_field};
+use crate::models::{DatasetDashboard, ForgeStudioError, LanguageQualityStats, SourceCount};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// `data_root` is already resolved.
+pub(crate) fn build_dataset_dashboard(
+    data_root: &str,
+    dataset_name: String,
+    version_id: Option<String>,
+) -> Result<DatasetDashboard, ForgeStudioError> {
+    let selected_version = resolve_version(data_root, &dataset_name, version_id)?;
+    let records = read_records(data_root, &dataset_name, &selected_version)?;
+    if records.is_empty() {
+        return Err(ForgeStudioError::not_found(
+            "Dataset version has no records",
+        ));
+    }
+    let record_count = records.len() as u64;
+    let mut language_counts: BTreeMap<String, u64> = BTreeMap::new();
+    let mut source_counts: HashMap<String, u64> = HashMap::new();
+    let mut overall_quality = QualityAccumulator::default();
+    let mut language_quality: BTreeMap<String, QualityAccumulator> = BTreeMap::new();
+    for record in &records {
+        let metadata = record
+            .get("metadata")
+            .and_then(Value::as_object)
+            .ok_or_else(|| ForgeStudioError::parse("Record metadata is missing"))?;
+        let language = string_field(metadata, "language")?;
+        let source_uri = string_field(metadata, "source_uri")?;
+        *source_counts.entry(source_uri).or_insert(0) += 1;
+        let quality = float_field(metadata, "quality_score")?;
+        overall_quality.add(quality);
+        language_quality
+            .entry(language.clone())
+            .or_default()
+            .add(quality);
+        *language_counts.entry(language).or_insert(0) += 1;
+    }
+    let overall_stats = overall_quality.into_stats();
+    let mut source_rows: Vec<SourceCount> = source_counts
+        .into_iter()
+        .map(|(source, count)| SourceCount { source, count })
+        .collect();
+    source_rows.sort_by_key(|row| std::cmp::Reverse(row.count));
+    source_rows.truncate(12);
+    Ok(DatasetDashboard {
+        dataset_name,
+        version_id: selected_version,
+        record_count,
+        average_quality: overall_stats.average_quality,
+        min_quality: overall_stats.min_quality,
+        max_quality: overall_stats.max_quality,
+        language_counts,
+        language_quality: language_quality
+            .into_iter()
+            .map(|(language, accumulator)| (language, accumulator.into_stats()))
+            .collect(),
+        source_counts: source_rows,
+    })
+}
+
+struct QualityAccumulator {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for QualityAccumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl QualityAccumulator {
+    fn add(&mut self, quality: f64) {
+        self.count += 1;
+        self.sum += quality;
+        self.min = self.min.min(quality);
+        self.max = self.max.max(quality);
+    }
+
+    fn into_stats(self) -> LanguageQualityStats {
+        LanguageQualityStats {
+            record_count: self.count,
+            average_quality: self.sum / self.count.max(1) as f64,
+            min_quality: self.min,
+            max_quality: self.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QualityAccumulator;
+
+    #[test]
+    fn quality_accumulator_tracks_average_and_bounds() {
+        let mut accumulator = QualityAccumulator::default();
+        for quality in [0.2, 0.8, 0.5] {
+            accumulator.add(quality);
+        }
+        let stats = accumulator.into_stats();
+        assert_eq!(
+            (stats.record_count, stats.min_quality, stats.max_quality),
+            (3, 0.2, 0.8)
+        );
+    }
+}
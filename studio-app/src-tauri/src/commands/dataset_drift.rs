@@ -0,0 +1,238 @@
+//! Distribution drift between a run's training data and a newer version.
+//!
+//! Each record attribute (language, source, quality, length) becomes a
+//! categorical distribution per version, and drift is their Jensen-Shannon
+//! divergence in bits. JSD is bounded to [0, 1] and symmetric, so scores
+//! compare across dimensions and a category missing on one side does not
+//! produce an infinite score the way KL divergence would.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::{parse_record_sample, read_records, resolve_version};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::models::{DatasetDriftReport, DriftDimension, ForgeStudioError, RecordSample};
+use std::collections::{BTreeMap, HashSet};
+use tauri::State;
+
+const MODERATE_DRIFT_SCORE: f64 = 0.05;
+const HIGH_DRIFT_SCORE: f64 = 0.15;
+/// Retraining is also worth it when this share of records is new, even if distributions hold.
+const RETRAIN_NEW_RECORD_FRACTION: f64 = 0.3;
+const QUALITY_BUCKETS: usize = 10;
+/// Categories listed in a dimension's detail text.
+const MAX_DETAIL_SHIFTS: usize = 3;
+
+type Distribution = BTreeMap<String, f64>;
+
+#[tauri::command]
+pub fn detect_dataset_drift(
+    data_root: String,
+    run_id: String,
+    current_version: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<DatasetDriftReport, ForgeStudioError> {
+    let lifecycle = load_run_lifecycle(
+        &resolve_data_root_path(&data_root, &settings.workspace_roots())?,
+        &run_id,
+    )?;
+    let dataset_name = lifecycle.dataset_name;
+    let training_version = lifecycle.dataset_version_id;
+    let current_version = resolve_version(&data_root, &dataset_name, current_version)?;
+    let training = load_samples(&data_root, &dataset_name, &training_version)?;
+    let current = load_samples(&data_root, &dataset_name, &current_version)?;
+    let training_ids: HashSet<&str> = training
+        .iter()
+        .map(|sample| sample.record_id.as_str())
+        .collect();
+    let new_records = current
+        .iter()
+        .filter(|sample| !training_ids.contains(sample.record_id.as_str()))
+        .count();
+    let new_record_fraction = new_records as f64 / current.len().max(1) as f64;
+    let dimensions = vec![
+        compare("language", &training, &current, |sample| {
+            sample.language.clone()
+        }),
+        compare("source", &training, &current, |sample| {
+            source_key(&sample.source_uri)
+        }),
+        compare("quality", &training, &current, quality_bucket),
+        compare("length", &training, &current, length_bucket),
+    ];
+    let overall_score = dimensions.iter().map(|row| row.score).fold(0.0, f64::max);
+    Ok(DatasetDriftReport {
+        run_id,
+        dataset_name,
+        training_version,
+        current_version,
+        training_record_count: training.len() as u64,
+        current_record_count: current.len() as u64,
+        new_record_fraction,
+        overall_score,
+        retrain_recommended: overall_score >= HIGH_DRIFT_SCORE
+            || new_record_fraction >= RETRAIN_NEW_RECORD_FRACTION,
+        dimensions,
+    })
+}
+
+fn load_samples(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: &str,
+) -> Result<Vec<RecordSample>, ForgeStudioError> {
+    let samples = read_records(data_root, dataset_name, version_id)?
+        .iter()
+        .map(parse_record_sample)
+        .collect::<Result<Vec<_>, _>>()?;
+    if samples.is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Version '{version_id}' of {dataset_name} has no records to compare. \
+             Pick a non-empty version."
+        )));
+    }
+    Ok(samples)
+}
+
+fn compare(
+    dimension: &str,
+    training: &[RecordSample],
+    current: &[RecordSample],
+    key: impl Fn(&RecordSample) -> String,
+) -> DriftDimension {
+    let before = distribution(training.iter().map(&key));
+    let after = distribution(current.iter().map(&key));
+    let score = jensen_shannon(&before, &after);
+    let level = if score >= HIGH_DRIFT_SCORE {
+        "high"
+    } else if score >= MODERATE_DRIFT_SCORE {
+        "moderate"
+    } else {
+        "stable"
+    };
+    DriftDimension {
+        dimension: dimension.to_string(),
+        score,
+        level: level.to_string(),
+        detail: largest_shifts(&before, &after),
+    }
+}
+
+fn distribution(keys: impl Iterator<Item = String>) -> Distribution {
+    let mut counts = Distribution::new();
+    for key in keys {
+        *counts.entry(key).or_insert(0.0) += 1.0;
+    }
+    let total: f64 = counts.values().sum();
+    counts
+        .values_mut()
+        .for_each(|count| *count /= total.max(1.0));
+    counts
+}
+
+/// JSD in bits over the union of categories.
+fn jensen_shannon(left: &Distribution, right: &Distribution) -> f64 {
+    let keys: HashSet<&String> = left.keys().chain(right.keys()).collect();
+    let divergence: f64 = keys
+        .into_iter()
+        .map(|key| {
+            let p = left.get(key).copied().unwrap_or(0.0);
+            let q = right.get(key).copied().unwrap_or(0.0);
+            let mean = (p + q) / 2.0;
+            let term = |value: f64| {
+                if value > 0.0 {
+                    value * (value / mean).log2()
+                } else {
+                    0.0
+                }
+            };
+            (term(p) + term(q)) / 2.0
+        })
+        .sum();
+    divergence.clamp(0.0, 1.0)
+}
+
+/// Categories with the largest share change, e.g. `fr 10% -> 35%`.
+fn largest_shifts(before: &Distribution, after: &Distribution) -> String {
+    let mut shifts: Vec<(&String, f64, f64)> = before
+        .keys()
+        .chain(after.keys().filter(|key| !before.contains_key(*key)))
+        .map(|key| {
+            let old = before.get(key).copied().unwrap_or(0.0);
+            let new = after.get(key).copied().unwrap_or(0.0);
+            (key, old, new)
+        })
+        .collect();
+    shifts.sort_by(|left, right| {
+        (right.2 - right.1)
+            .abs()
+            .total_cmp(&(left.2 - left.1).abs())
+    });
+    shifts
+        .into_iter()
+        .take(MAX_DETAIL_SHIFTS)
+        .map(|(key, old, new)| format!("{key} {:.0}% -> {:.0}%", old * 100.0, new * 100.0))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Scheme and host for URLs, parent directory for file paths.
+fn source_key(source_uri: &str) -> String {
+    if let Some((scheme, rest)) = source_uri.split_once("://") {
+        let host = rest.split('/').next().unwrap_or(rest);
+        return format!("{scheme}://{host}");
+    }
+    source_uri
+        .rsplit_once('/')
+        .map(|(parent, _)| parent.to_string())
+        .unwrap_or_else(|| source_uri.to_string())
+}
+
+fn quality_bucket(sample: &RecordSample) -> String {
+    let bucket = (sample.quality_score.clamp(0.0, 1.0) * QUALITY_BUCKETS as f64) as usize;
+    let lower = bucket.min(QUALITY_BUCKETS - 1) as f64 / QUALITY_BUCKETS as f64;
+    format!("{lower:.1}-{:.1}", lower + 1.0 / QUALITY_BUCKETS as f64)
+}
+
+/// Power-of-two word-count buckets, so length drift is measured on a log scale.
+fn length_bucket(sample: &RecordSample) -> String {
+    let words = sample.text.split_whitespace().count().max(1);
+    let lower = 1_usize << words.ilog2();
+    format!("{lower}-{} words", lower * 2 - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{distribution, jensen_shannon, length_bucket, source_key};
+    use crate::models::RecordSample;
+
+    fn keys(values: &[&str]) -> super::Distribution {
+        distribution(values.iter().map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn jensen_shannon_is_zero_for_equal_and_one_for_disjoint() {
+        assert_eq!(
+            jensen_shannon(&keys(&["en", "fr"]), &keys(&["fr", "en"])),
+            0.0
+        );
+        assert!((jensen_shannon(&keys(&["en"]), &keys(&["fr"])) - 1.0).abs() < 1e-12);
+        let partial = jensen_shannon(&keys(&["en", "en", "fr"]), &keys(&["en", "fr", "fr"]));
+        assert!(partial > 0.0 && partial < 0.1);
+    }
+
+    #[test]
+    fn buckets_group_sources_and_lengths() {
+        assert_eq!(
+            source_key("https://example.com/a/b.html"),
+            "https://example.com"
+        );
+        assert_eq!(source_key("/data/raw/part-1.jsonl"), "/data/raw");
+        let sample = RecordSample {
+            record_id: "r1".to_string(),
+            source_uri: "s".to_string(),
+            language: "en".to_string(),
+            quality_score: 0.5,
+            text: "one two three four five".to_string(),
+        };
+        assert_eq!(length_bucket(&sample), "4-7 words");
+    }
+}
@@ -0,0 +1,195 @@
+//! Individual record-level checks behind the dataset health report.
+//!
+//! Scans a version's records file leniently so malformed lines are counted
+//! instead of aborting the scan, then applies the duplicate, PII, length
+//! outlier, and language consistency heuristics to the parseable records.
+
+use crate::commands::dataset_store_io::parse_record_sample;
+use crate::commands::record_outliers::{token_length, DistributionStats};
+use crate::models::{ForgeStudioError, RecordSample};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+const ENGLISH_LANGUAGE_CODE: &str = "en";
+const UNKNOWN_LANGUAGE_CODE: &str = "unknown";
+// Thresholds mirror `transforms/language_detection.py` so Studio and the CLI agree.
+const ENGLISH_MIN_ASCII_RATIO: f64 = 0.9;
+const ENGLISH_MIN_STOPWORD_RATIO: f64 = 0.05;
+const ENGLISH_STOPWORDS: [&str; 15] = [
+    "the", "and", "is", "in", "to", "of", "that", "for", "with", "on", "as", "this", "it", "by",
+    "an",
+];
+const PHONE_MIN_DIGITS: usize = 10;
+const PHONE_MAX_DIGITS: usize = 15;
+
+/// Raw counts produced by one health scan over a version.
+#[derive(Debug, Default)]
+pub(crate) struct HealthScan {
+    pub total_lines: u64,
+    pub malformed_lines: u64,
+    pub duplicate_records: u64,
+    pub pii_records: u64,
+    pub length_outliers: u64,
+    pub language_mismatches: u64,
+}
+
+pub(crate) fn scan_version_health(records_path: &Path) -> Result<HealthScan, ForgeStudioError> {
+    let payload = fs::read_to_string(records_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read records {}: {error}",
+            records_path.display()
+        ))
+    })?;
+    let mut scan = HealthScan::default();
+    let mut samples = Vec::new();
+    for line in payload.lines().filter(|line| !line.trim().is_empty()) {
+        scan.total_lines += 1;
+        let parsed = serde_json::from_str::<Value>(line)
+            .map_err(|error| ForgeStudioError::parse(error.to_string()))
+            .and_then(|record| parse_record_sample(&record));
+        match parsed {
+            Ok(sample) => samples.push(sample),
+            Err(_) => scan.malformed_lines += 1,
+        }
+    }
+    let unique_ids: HashSet<&str> = samples
+        .iter()
+        .map(|sample| sample.record_id.as_str())
+        .collect();
+    scan.duplicate_records = (samples.len() - unique_ids.len()) as u64;
+    scan.pii_records = samples
+        .iter()
+        .filter(|sample| contains_pii(&sample.text))
+        .count() as u64;
+    scan.language_mismatches = samples
+        .iter()
+        .filter(|sample| has_language_mismatch(sample))
+        .count() as u64;
+    scan.length_outliers = count_length_outliers(&samples);
+    Ok(scan)
+}
+
+fn count_length_outliers(samples: &[RecordSample]) -> u64 {
+    let lengths: Vec<f64> = samples
+        .iter()
+        .map(|sample| token_length(&sample.text))
+        .collect();
+    let stats = DistributionStats::from_values(&lengths);
+    lengths
+        .iter()
+        .filter(|length| stats.is_outlier(**length))
+        .count() as u64
+}
+
+/// Returns true when an email address, phone number, or SSN-shaped token appears.
+pub(crate) fn contains_pii(text: &str) -> bool {
+    text.split_whitespace()
+        .any(|token| is_email(token) || is_ssn(token))
+        || contains_phone_number(text)
+}
+
+fn is_email(token: &str) -> bool {
+    let trimmed = token.trim_matches(|character: char| !character.is_alphanumeric());
+    let Some((local_part, domain)) = trimmed.split_once('@') else {
+        return false;
+    };
+    let Some((host, top_level)) = domain.rsplit_once('.') else {
+        return false;
+    };
+    !local_part.is_empty() && !host.is_empty() && top_level.len() >= 2
+}
+
+fn is_ssn(token: &str) -> bool {
+    let trimmed = token.trim_matches(|character: char| !character.is_ascii_digit());
+    let groups: Vec<&str> = trimmed.split('-').collect();
+    groups.len() == 3
+        && groups.iter().zip([3, 2, 4]).all(|(group, length)| {
+            group.len() == length && group.chars().all(|character| character.is_ascii_digit())
+        })
+}
+
+fn contains_phone_number(text: &str) -> bool {
+    let mut digit_run = 0;
+    for character in text.chars() {
+        if character.is_ascii_digit() {
+            digit_run += 1;
+        } else if " -().+".contains(character) {
+            continue;
+        } else {
+            if (PHONE_MIN_DIGITS..=PHONE_MAX_DIGITS).contains(&digit_run) {
+                return true;
+            }
+            digit_run = 0;
+        }
+    }
+    (PHONE_MIN_DIGITS..=PHONE_MAX_DIGITS).contains(&digit_run)
+}
+
+fn has_language_mismatch(sample: &RecordSample) -> bool {
+    let detected_english = detect_language(&sample.text) == ENGLISH_LANGUAGE_CODE;
+    let declared_english = sample.language == ENGLISH_LANGUAGE_CODE;
+    detected_english != declared_english
+}
+
+fn detect_language(text: &str) -> &'static str {
+    let cleaned: String = text
+        .chars()
+        .map(|character| {
+            if character.is_alphabetic() {
+                character
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    let lowered = cleaned.to_lowercase();
+    let tokens: Vec<&str> = lowered.split_whitespace().collect();
+    if tokens.is_empty() {
+        return UNKNOWN_LANGUAGE_CODE;
+    }
+    let character_count = text.chars().count().max(1) as f64;
+    let ascii_ratio = text.chars().filter(char::is_ascii).count() as f64 / character_count;
+    let stopword_hits = tokens
+        .iter()
+        .filter(|token| ENGLISH_STOPWORDS.contains(token))
+        .count();
+    if ascii_ratio >= ENGLISH_MIN_ASCII_RATIO
+        && stopword_hits as f64 / tokens.len() as f64 >= ENGLISH_MIN_STOPWORD_RATIO
+    {
+        ENGLISH_LANGUAGE_CODE
+    } else {
+        UNKNOWN_LANGUAGE_CODE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_pii, detect_language};
+
+    #[test]
+    fn contains_pii_detects_emails_and_phone_numbers() {
+        assert!(contains_pii("reach me at jane.doe@example.com today"));
+        assert!(contains_pii("call (555) 123-4567 ext"));
+    }
+
+    #[test]
+    fn contains_pii_ignores_plain_prose() {
+        assert!(!contains_pii(
+            "In 1967 the country was in turmoil over 2 wars."
+        ));
+    }
+
+    #[test]
+    fn detect_language_matches_cli_heuristic() {
+        assert_eq!(
+            detect_language("The cat sat on the mat and it was happy."),
+            "en"
+        );
+        assert_eq!(
+            detect_language("Der Hund läuft schnell über die Straße."),
+            "unknown"
+        );
+    }
+}
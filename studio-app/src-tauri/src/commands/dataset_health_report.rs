@@ -0,0 +1,214 @@
+//! One-shot dataset health report with a pre-training go/no-go verdict.
+//!
+//! Composes the record-level checks into weighted findings, a 0-100 score,
+//! and optionally renders the report to a markdown or HTML file.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::atomic_write::write_file_atomic;
+use crate::commands::dataset_health_checks::{scan_version_health, HealthScan};
+use crate::commands::dataset_store_io::{records_path, resolve_version};
+use crate::commands::message_catalog::message;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{DatasetHealthReport, ForgeStudioError, HealthFinding};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const HEALTH_REPORT_DIR: &str = "outputs/reports";
+const GO_MIN_SCORE: f64 = 80.0;
+const REVIEW_MIN_SCORE: f64 = 60.0;
+
+/// (check name, penalty per unit rate, maximum penalty) for each finding.
+const CHECK_WEIGHTS: [(&str, f64, f64); 5] = [
+    ("malformed_lines", 500.0, 25.0),
+    ("duplicates", 100.0, 30.0),
+    ("pii", 200.0, 25.0),
+    ("length_outliers", 100.0, 10.0),
+    ("language_mismatches", 50.0, 10.0),
+];
+
+#[tauri::command]
+pub fn generate_dataset_health_report(
+    data_root: String,
+    dataset_name: String,
+    version_id: Option<String>,
+    output_format: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<DatasetHealthReport, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let selected_version = resolve_version(&data_root, &dataset_name, version_id)?;
+    let scan = scan_version_health(&records_path(&data_root, &dataset_name, &selected_version))?;
+    let findings = build_findings(&scan);
+    let score = (100.0 - findings.iter().map(|finding| finding.penalty).sum::<f64>()).max(0.0);
+    let verdict = verdict_for_score(score);
+    let mut report = DatasetHealthReport {
+        dataset_name,
+        version_id: selected_version,
+        record_count: scan.total_lines,
+        score,
+        verdict: verdict.to_string(),
+        verdict_label: message(&format!("health.verdict.{verdict}"), &[]),
+        findings,
+        report_path: None,
+    };
+    if let Some(format) = output_format {
+        let report_path = write_report_file(&data_root, &report, &format)?;
+        report.report_path = Some(report_path.display().to_string());
+    }
+    Ok(report)
+}
+
+fn build_findings(scan: &HealthScan) -> Vec<HealthFinding> {
+    let counts = [
+        scan.malformed_lines,
+        scan.duplicate_records,
+        scan.pii_records,
+        scan.length_outliers,
+        scan.language_mismatches,
+    ];
+    let total = scan.total_lines.max(1) as f64;
+    let total_text = scan.total_lines.to_string();
+    CHECK_WEIGHTS
+        .iter()
+        .zip(counts)
+        .map(|((check, weight, max_penalty), affected_records)| {
+            let rate = affected_records as f64 / total;
+            let affected_text = affected_records.to_string();
+            let summary_args = [("affected", affected_text.as_str()), ("total", &total_text)];
+            HealthFinding {
+                check: check.to_string(),
+                summary: message(&format!("health.{check}"), &summary_args),
+                affected_records,
+                rate,
+                penalty: (rate * weight).min(*max_penalty),
+            }
+        })
+        .collect()
+}
+
+fn verdict_for_score(score: f64) -> &'static str {
+    if score >= GO_MIN_SCORE {
+        "go"
+    } else if score >= REVIEW_MIN_SCORE {
+        "review"
+    } else {
+        "no-go"
+    }
+}
+
+fn write_report_file(
+    data_root: &str,
+    report: &DatasetHealthReport,
+    format: &str,
+) -> Result<PathBuf, ForgeStudioError> {
+    let (extension, content) = match format {
+        "markdown" => ("md", render_markdown(report)),
+        "html" => ("html", render_html(report)),
+        other => {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Unsupported health report format '{other}'. Use 'markdown' or 'html'."
+            )))
+        }
+    };
+    let report_dir = Path::new(data_root).join(HEALTH_REPORT_DIR);
+    fs::create_dir_all(&report_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to create report directory {}: {error}",
+            report_dir.display()
+        ))
+    })?;
+    let report_path = report_dir.join(format!("{}-health.{extension}", report.version_id));
+    write_file_atomic(&report_path, content.as_bytes())?;
+    Ok(report_path)
+}
+
+fn render_markdown(report: &DatasetHealthReport) -> String {
+    let mut lines = vec![
+        format!(
+            "# Dataset health: {} @ {}",
+            report.dataset_name, report.version_id
+        ),
+        String::new(),
+        format!("- Records: {}", report.record_count),
+        format!("- Score: {:.1} / 100", report.score),
+        format!("- Verdict: **{}**", report.verdict_label),
+        String::new(),
+        "| Check | Affected | Rate | Penalty |".to_string(),
+        "| --- | ---: | ---: | ---: |".to_string(),
+    ];
+    for finding in &report.findings {
+        lines.push(format!(
+            "| {} | {} | {:.2}% | {:.1} |",
+            finding.check,
+            finding.affected_records,
+            finding.rate * 100.0,
+            finding.penalty
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn render_html(report: &DatasetHealthReport) -> String {
+    let rows: String = report
+        .findings
+        .iter()
+        .map(|finding| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{:.2}%</td><td>{:.1}</td></tr>",
+                finding.check,
+                finding.affected_records,
+                finding.rate * 100.0,
+                finding.penalty
+            )
+        })
+        .collect();
+    format!(
+        "<!doctype html>\n<html><head><meta charset=\"utf-8\"><title>Dataset health</title></head><body>\n\
+         <h1>Dataset health: {} @ {}</h1>\n<p>Records: {} &middot; Score: {:.1} / 100 &middot; Verdict: <strong>{}</strong></p>\n\
+         <table><thead><tr><th>Check</th><th>Affected</th><th>Rate</th><th>Penalty</th></tr></thead><tbody>{rows}</tbody></table>\n\
+         </body></html>\n",
+        html_escape(&report.dataset_name),
+        html_escape(&report.version_id),
+        report.record_count,
+        report.score,
+        html_escape(&report.verdict_label)
+    )
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_findings, verdict_for_score};
+    use crate::commands::dataset_health_checks::HealthScan;
+
+    #[test]
+    fn build_findings_caps_penalty_per_check() {
+        let scan = HealthScan {
+            total_lines: 10,
+            malformed_lines: 10,
+            ..HealthScan::default()
+        };
+        let findings = build_findings(&scan);
+        assert_eq!(findings[0].penalty, 25.0);
+        assert_eq!(findings[0].summary, "10 of 10 lines are not valid records.");
+    }
+
+    #[test]
+    fn verdict_for_score_uses_thresholds() {
+        assert_eq!(
+            [
+                verdict_for_score(95.0),
+                verdict_for_score(70.0),
+                verdict_for_score(10.0)
+            ],
+            ["go", "review", "no-go"]
+        );
+    }
+}
@@ -1,88 +1,62 @@
 //! Dataset query commands used by Studio panels.
 
-use crate::models::{DatasetDashboard, RecordSample, SourceCount, TrainingHistory, VersionDiff, VersionSummary};
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::dataset_dashboard::build_dataset_dashboard;
+use crate::commands::dataset_store_io::{
+    dataset_root, parse_record_sample, read_catalog, read_child_dirs, read_records,
+    resolve_version, string_field,
+};
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::commands::version_duplicates::cached_duplicate_rate;
+use crate::models::{
+    DatasetDashboard, DatasetListing, ForgeStudioError, RecordSample, VersionDiff, VersionSummary,
+};
 use serde_json::Value;
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::fs;
-use std::path::{Path, PathBuf};
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::Path;
+use tauri::State;
 
+/// Most records one `sample_records` call returns.
+pub(crate) const MAX_SAMPLE_LIMIT: usize = 200;
+
+/// Datasets under `data_root` and every federated root in the `data_roots`
+/// setting; open each one with the `data_root` its listing carries.
 #[tauri::command]
-pub fn list_datasets(data_root: String) -> Result<Vec<String>, String> {
-    let datasets_dir = Path::new(&data_root).join("datasets");
-    if !datasets_dir.exists() {
-        return Ok(vec![]);
-    }
-    let mut names = read_child_dirs(&datasets_dir)?;
-    names.sort();
-    Ok(names)
+pub fn list_datasets(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<DatasetListing>, ForgeStudioError> {
+    let federated_roots = settings.current().data_roots;
+    dataset_listings(data_root, &federated_roots, &settings.workspace_roots())
 }
 
 #[tauri::command]
-pub fn list_versions(data_root: String, dataset_name: String) -> Result<Vec<VersionSummary>, String> {
-    let catalog = read_catalog(&dataset_root(&data_root, &dataset_name))?;
-    let versions = catalog
-        .get("versions")
-        .and_then(Value::as_array)
-        .ok_or_else(|| "Catalog is missing versions array".to_string())?;
-    let mut summaries = Vec::with_capacity(versions.len());
-    for version in versions {
-        summaries.push(parse_version_summary(version)?);
-    }
-    Ok(summaries)
+pub fn list_versions(
+    data_root: String,
+    dataset_name: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<VersionSummary>, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    version_summaries(&data_root, &dataset_name)
 }
 
+/// Reads every record of the version, so it runs on the blocking pool.
 #[tauri::command]
-pub fn get_dataset_dashboard(
+pub async fn get_dataset_dashboard(
     data_root: String,
     dataset_name: String,
     version_id: Option<String>,
-) -> Result<DatasetDashboard, String> {
-    let selected_version = resolve_version(&data_root, &dataset_name, version_id)?;
-    let records = read_records(&data_root, &dataset_name, &selected_version)?;
-    if records.is_empty() {
-        return Err("Dataset version has no records".to_string());
-    }
-    let record_count = records.len() as u64;
-    let mut language_counts: BTreeMap<String, u64> = BTreeMap::new();
-    let mut source_counts: HashMap<String, u64> = HashMap::new();
-    let mut quality_sum = 0.0;
-    let mut min_quality = f64::INFINITY;
-    let mut max_quality = f64::NEG_INFINITY;
-    for record in &records {
-        let metadata = record
-            .get("metadata")
-            .and_then(Value::as_object)
-            .ok_or_else(|| "Record metadata is missing".to_string())?;
-        let language = string_field(metadata, "language")?;
-        *language_counts.entry(language).or_insert(0) += 1;
-        let source_uri = string_field(metadata, "source_uri")?;
-        *source_counts.entry(source_uri).or_insert(0) += 1;
-        let quality = float_field(metadata, "quality_score")?;
-        quality_sum += quality;
-        if quality < min_quality {
-            min_quality = quality;
-        }
-        if quality > max_quality {
-            max_quality = quality;
-        }
-    }
-    let average_quality = quality_sum / record_count as f64;
-    let mut source_rows: Vec<SourceCount> = source_counts
-        .into_iter()
-        .map(|(source, count)| SourceCount { source, count })
-        .collect();
-    source_rows.sort_by(|left, right| right.count.cmp(&left.count));
-    source_rows.truncate(12);
-    Ok(DatasetDashboard {
-        dataset_name,
-        version_id: selected_version,
-        record_count,
-        average_quality,
-        min_quality,
-        max_quality,
-        language_counts,
-        source_counts: source_rows,
+    settings: State<'_, SettingsStore>,
+) -> Result<DatasetDashboard, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        build_dataset_dashboard(&data_root, dataset_name, version_id)
     })
+    .await
 }
 
 #[tauri::command]
@@ -92,39 +66,100 @@ pub fn sample_records(
     version_id: Option<String>,
     offset: usize,
     limit: usize,
-) -> Result<Vec<RecordSample>, String> {
-    let selected_version = resolve_version(&data_root, &dataset_name, version_id)?;
-    let records = read_records(&data_root, &dataset_name, &selected_version)?;
-    let safe_limit = limit.min(200);
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<RecordSample>, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let window = offset..offset.saturating_add(limit.min(MAX_SAMPLE_LIMIT));
+    record_samples(&data_root, &dataset_name, version_id, window)
+}
+
+/// Reads both versions' records, so it runs on the blocking pool.
+#[tauri::command]
+pub async fn version_diff(
+    data_root: String,
+    dataset_name: String,
+    base_version: String,
+    target_version: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<VersionDiff, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        diff_versions(&data_root, dataset_name, base_version, target_version)
+    })
+    .await
+}
+
+/// Datasets under the primary root and each federated root, each tagged with
+/// its resolved root; a root reached twice under different spellings lists once.
+pub(crate) fn dataset_listings(
+    data_root: String,
+    federated_roots: &[String],
+    roots: &WorkspaceRoots,
+) -> Result<Vec<DatasetListing>, ForgeStudioError> {
+    let mut listings = Vec::new();
+    let mut listed_roots = HashSet::new();
+    for root in registered_data_roots(data_root, federated_roots) {
+        let root = resolve_data_root_text(&root, roots)?;
+        let datasets_dir = Path::new(&root).join("datasets");
+        if !listed_roots.insert(root.clone()) || !datasets_dir.exists() {
+            continue;
+        }
+        let mut names = read_child_dirs(&datasets_dir)?;
+        names.sort();
+        listings.extend(names.into_iter().map(|dataset_name| DatasetListing {
+            dataset_name,
+            data_root: root.clone(),
+        }));
+    }
+    Ok(listings)
+}
+
+/// `data_root` is already resolved.
+pub(crate) fn version_summaries(
+    data_root: &str,
+    dataset_name: &str,
+) -> Result<Vec<VersionSummary>, ForgeStudioError> {
+    let catalog = read_catalog(&dataset_root(data_root, dataset_name))?;
+    let versions = catalog
+        .get("versions")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ForgeStudioError::parse("Catalog is missing versions array"))?;
+    let mut summaries = Vec::with_capacity(versions.len());
+    for version in versions {
+        let mut summary = parse_version_summary(version)?;
+        // Unset until `get_version_duplicate_rate` has computed it once.
+        summary.duplicate_rate =
+            cached_duplicate_rate(data_root, dataset_name, &summary.version_id);
+        summaries.push(summary);
+    }
+    Ok(summaries)
+}
+
+/// Records in `window` of the version, read from an already resolved `data_root`.
+pub(crate) fn record_samples(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: Option<String>,
+    window: Range<usize>,
+) -> Result<Vec<RecordSample>, ForgeStudioError> {
+    let selected_version = resolve_version(data_root, dataset_name, version_id)?;
+    let records = read_records(data_root, dataset_name, &selected_version)?;
     let mut samples: Vec<RecordSample> = Vec::new();
-    for record in records.iter().skip(offset).take(safe_limit) {
-        let record_object = record
-            .as_object()
-            .ok_or_else(|| "Record entry is not an object".to_string())?;
-        let metadata = record
-            .get("metadata")
-            .and_then(Value::as_object)
-            .ok_or_else(|| "Record metadata is missing".to_string())?;
-        samples.push(RecordSample {
-            record_id: string_field(record_object, "record_id")?,
-            source_uri: string_field(metadata, "source_uri")?,
-            language: string_field(metadata, "language")?,
-            quality_score: float_field(metadata, "quality_score")?,
-            text: string_field(record_object, "text")?,
-        });
+    for record in records.iter().skip(window.start).take(window.len()) {
+        samples.push(parse_record_sample(record)?);
     }
     Ok(samples)
 }
 
-#[tauri::command]
-pub fn version_diff(
-    data_root: String,
+pub(crate) fn diff_versions(
+    data_root: &str,
     dataset_name: String,
     base_version: String,
     target_version: String,
-) -> Result<VersionDiff, String> {
-    let base_ids = record_id_set(&data_root, &dataset_name, &base_version)?;
-    let target_ids = record_id_set(&data_root, &dataset_name, &target_version)?;
+) -> Result<VersionDiff, ForgeStudioError> {
+    let base_ids = record_id_set(data_root, &dataset_name, &base_version)?;
+    let target_ids = record_id_set(data_root, &dataset_name, &target_version)?;
     let shared_records = base_ids.intersection(&target_ids).count() as u64;
     let removed_records = base_ids.difference(&target_ids).count() as u64;
     let added_records = target_ids.difference(&base_ids).count() as u64;
@@ -138,120 +173,108 @@ pub fn version_diff(
     })
 }
 
-#[tauri::command]
-pub fn load_training_history(history_path: String) -> Result<TrainingHistory, String> {
-    let payload = fs::read_to_string(&history_path)
-        .map_err(|error| format!("Failed to read history file {history_path}: {error}"))?;
-    serde_json::from_str(&payload)
-        .map_err(|error| format!("Failed to parse history file {history_path}: {error}"))
-}
-
-fn dataset_root(data_root: &str, dataset_name: &str) -> PathBuf {
-    Path::new(data_root).join("datasets").join(dataset_name)
-}
-
-fn records_path(data_root: &str, dataset_name: &str, version_id: &str) -> PathBuf {
-    dataset_root(data_root, dataset_name)
-        .join("versions")
-        .join(version_id)
-        .join("records.jsonl")
-}
-
-fn read_catalog(dataset_root: &Path) -> Result<Value, String> {
-    let catalog_path = dataset_root.join("catalog.json");
-    let payload = fs::read_to_string(&catalog_path)
-        .map_err(|error| format!("Failed to read catalog {}: {error}", catalog_path.display()))?;
-    serde_json::from_str::<Value>(&payload)
-        .map_err(|error| format!("Failed to parse catalog {}: {error}", catalog_path.display()))
-}
-
-fn resolve_version(
-    data_root: &str,
-    dataset_name: &str,
-    explicit_version: Option<String>,
-) -> Result<String, String> {
-    if let Some(version_id) = explicit_version {
-        return Ok(version_id);
-    }
-    let catalog = read_catalog(&dataset_root(data_root, dataset_name))?;
-    catalog
-        .get("latest_version")
-        .and_then(Value::as_str)
-        .map(str::to_string)
-        .ok_or_else(|| "Catalog is missing latest_version".to_string())
-}
-
-fn read_records(data_root: &str, dataset_name: &str, version_id: &str) -> Result<Vec<Value>, String> {
-    let records_path = records_path(data_root, dataset_name, version_id);
-    let payload = fs::read_to_string(&records_path)
-        .map_err(|error| format!("Failed to read records {}: {error}", records_path.display()))?;
-    let mut rows = Vec::new();
-    for line in payload.lines() {
-        if line.trim().is_empty() {
-            continue;
+/// Orders the primary root first, then federated roots, dropping blanks and duplicates.
+fn registered_data_roots(primary_root: String, federated_roots: &[String]) -> Vec<String> {
+    let mut roots = vec![primary_root];
+    for root in federated_roots {
+        let trimmed_root = root.trim();
+        if !trimmed_root.is_empty() && !roots.iter().any(|existing| existing == trimmed_root) {
+            roots.push(trimmed_root.to_string());
         }
-        let row = serde_json::from_str::<Value>(line)
-            .map_err(|error| format!("Failed to parse record json in {}: {error}", records_path.display()))?;
-        rows.push(row);
     }
-    Ok(rows)
+    roots
 }
 
-fn record_id_set(data_root: &str, dataset_name: &str, version_id: &str) -> Result<HashSet<String>, String> {
+fn record_id_set(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: &str,
+) -> Result<HashSet<String>, ForgeStudioError> {
     let records = read_records(data_root, dataset_name, version_id)?;
     let mut ids = HashSet::with_capacity(records.len());
     for record in records {
         let id = record
             .get("record_id")
             .and_then(Value::as_str)
-            .ok_or_else(|| "Record is missing record_id".to_string())?;
+            .ok_or_else(|| ForgeStudioError::parse("Record is missing record_id"))?;
         ids.insert(id.to_string());
     }
     Ok(ids)
 }
 
-fn parse_version_summary(raw: &Value) -> Result<VersionSummary, String> {
+pub(crate) fn parse_version_summary(raw: &Value) -> Result<VersionSummary, ForgeStudioError> {
     let object = raw
         .as_object()
-        .ok_or_else(|| "Version entry is not an object".to_string())?;
-    let parent = object.get("parent_version").and_then(Value::as_str).map(str::to_string);
+        .ok_or_else(|| ForgeStudioError::parse("Version entry is not an object"))?;
+    let parent = object
+        .get("parent_version")
+        .and_then(Value::as_str)
+        .map(str::to_string);
     let record_count = object
         .get("record_count")
         .and_then(Value::as_u64)
-        .ok_or_else(|| "Version record_count is missing".to_string())?;
+        .ok_or_else(|| ForgeStudioError::parse("Version record_count is missing"))?;
     Ok(VersionSummary {
         version_id: string_field(object, "version_id")?,
         record_count,
         created_at: string_field(object, "created_at")?,
         parent_version: parent,
+        duplicate_rate: None,
     })
 }
 
-fn read_child_dirs(parent: &Path) -> Result<Vec<String>, String> {
-    let entries = fs::read_dir(parent)
-        .map_err(|error| format!("Failed to read {}: {error}", parent.display()))?;
-    let mut rows = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|error| format!("Failed to read dir entry: {error}"))?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(name) = path.file_name().and_then(|value| value.to_str()) {
-                rows.push(name.to_string());
-            }
-        }
-    }
-    Ok(rows)
-}
+#[cfg(test)]
+mod tests {
+    use super::{dataset_listings, registered_data_roots};
+    use crate::commands::path_safety::WorkspaceRoots;
+    use std::fs;
 
-fn string_field(map: &serde_json::Map<String, Value>, key: &str) -> Result<String, String> {
-    map.get(key)
-        .and_then(Value::as_str)
-        .map(str::to_string)
-        .ok_or_else(|| format!("Field '{key}' is missing or invalid"))
-}
+    #[test]
+    fn registered_data_roots_keeps_primary_first_and_drops_duplicates() {
+        let roots = registered_data_roots(
+            "/data/local".to_string(),
+            &[
+                "/mnt/nas".to_string(),
+                " ".to_string(),
+                "/data/local".to_string(),
+                "/mnt/nas".to_string(),
+            ],
+        );
+        assert_eq!(
+            roots,
+            vec!["/data/local".to_string(), "/mnt/nas".to_string()]
+        );
+    }
 
-fn float_field(map: &serde_json::Map<String, Value>, key: &str) -> Result<f64, String> {
-    map.get(key)
-        .and_then(Value::as_f64)
-        .ok_or_else(|| format!("Field '{key}' is missing or invalid"))
+    #[test]
+    fn dataset_listings_tag_same_named_datasets_with_their_roots() {
+        let temp_root =
+            std::env::temp_dir().join(format!("forge-dataset-listings-{}", std::process::id()));
+        let (local, nas) = (temp_root.join("local"), temp_root.join("nas"));
+        for root in [&local, &nas] {
+            fs::create_dir_all(root.join("datasets").join("demo")).expect("dataset created");
+        }
+        let temp_root = temp_root
+            .canonicalize()
+            .expect("temp dir should canonicalize");
+        let (local, nas) = (temp_root.join("local"), temp_root.join("nas"));
+        let nas_text = nas.display().to_string();
+        let roots = WorkspaceRoots::new(&[temp_root.display().to_string()]);
+        let listings = dataset_listings(
+            local.display().to_string(),
+            &[nas_text.clone(), local.display().to_string()],
+            &roots,
+        )
+        .expect("datasets should list");
+        let keys: Vec<(&str, &str)> = listings
+            .iter()
+            .map(|listing| (listing.data_root.as_str(), listing.dataset_name.as_str()))
+            .collect();
+        let local_text = local.display().to_string();
+        assert_eq!(
+            keys,
+            [(local_text.as_str(), "demo"), (nas_text.as_str(), "demo")]
+        );
+        fs::remove_dir_all(&temp_root).expect("temp dir should be removed");
+    }
 }
@@ -0,0 +1,155 @@
+//! Dataset catalog and records file IO shared by dataset commands.
+
+use crate::models::{ForgeStudioError, RecordSample};
+use serde_json::Value;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub(crate) fn dataset_root(data_root: &str, dataset_name: &str) -> PathBuf {
+    Path::new(data_root).join("datasets").join(dataset_name)
+}
+
+pub(crate) fn version_dir(data_root: &str, dataset_name: &str, version_id: &str) -> PathBuf {
+    dataset_root(data_root, dataset_name)
+        .join("versions")
+        .join(version_id)
+}
+
+pub(crate) fn records_path(data_root: &str, dataset_name: &str, version_id: &str) -> PathBuf {
+    version_dir(data_root, dataset_name, version_id).join("records.jsonl")
+}
+
+pub(crate) fn read_catalog(dataset_root: &Path) -> Result<Value, ForgeStudioError> {
+    let catalog_path = dataset_root.join("catalog.json");
+    let payload = fs::read_to_string(&catalog_path).map_err(|error| {
+        if error.kind() == ErrorKind::NotFound {
+            let dataset_name = dataset_root
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            return ForgeStudioError::not_found(format!(
+                "Dataset '{dataset_name}' has no catalog at {}. Ingest the dataset first.",
+                catalog_path.display()
+            ));
+        }
+        ForgeStudioError::io(format!(
+            "Failed to read catalog {}: {error}",
+            catalog_path.display()
+        ))
+    })?;
+    serde_json::from_str::<Value>(&payload).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse catalog {}: {error}",
+            catalog_path.display()
+        ))
+    })
+}
+
+pub(crate) fn resolve_version(
+    data_root: &str,
+    dataset_name: &str,
+    explicit_version: Option<String>,
+) -> Result<String, ForgeStudioError> {
+    if let Some(version_id) = explicit_version {
+        return Ok(version_id);
+    }
+    let catalog = read_catalog(&dataset_root(data_root, dataset_name))?;
+    catalog
+        .get("latest_version")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Dataset '{dataset_name}' has no versions yet. Ingest data into it first."
+            ))
+        })
+}
+
+pub(crate) fn read_records(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: &str,
+) -> Result<Vec<Value>, ForgeStudioError> {
+    let records_path = records_path(data_root, dataset_name, version_id);
+    let payload = fs::read_to_string(&records_path).map_err(|error| {
+        if error.kind() == ErrorKind::NotFound {
+            return ForgeStudioError::not_found(format!(
+                "Version '{version_id}' of {dataset_name} has no records file. \
+                 Pick a version listed in the dataset catalog."
+            ));
+        }
+        ForgeStudioError::io(format!(
+            "Failed to read records {}: {error}",
+            records_path.display()
+        ))
+    })?;
+    let mut rows = Vec::new();
+    for line in payload.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row = serde_json::from_str::<Value>(line).map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Failed to parse record json in {}: {error}",
+                records_path.display()
+            ))
+        })?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+pub(crate) fn parse_record_sample(record: &Value) -> Result<RecordSample, ForgeStudioError> {
+    let record_object = record
+        .as_object()
+        .ok_or_else(|| ForgeStudioError::parse("Record entry is not an object"))?;
+    let metadata = record
+        .get("metadata")
+        .and_then(Value::as_object)
+        .ok_or_else(|| ForgeStudioError::parse("Record metadata is missing"))?;
+    Ok(RecordSample {
+        record_id: string_field(record_object, "record_id")?,
+        source_uri: string_field(metadata, "source_uri")?,
+        language: string_field(metadata, "language")?,
+        quality_score: float_field(metadata, "quality_score")?,
+        text: string_field(record_object, "text")?,
+    })
+}
+
+pub(crate) fn read_child_dirs(parent: &Path) -> Result<Vec<String>, ForgeStudioError> {
+    let entries = fs::read_dir(parent).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", parent.display()))
+    })?;
+    let mut rows = Vec::new();
+    for entry in entries {
+        let entry = entry
+            .map_err(|error| ForgeStudioError::io(format!("Failed to read dir entry: {error}")))?;
+        let path = entry.path();
+        if path.is_dir() {
+            if let Some(name) = path.file_name().and_then(|value| value.to_str()) {
+                rows.push(name.to_string());
+            }
+        }
+    }
+    Ok(rows)
+}
+
+pub(crate) fn string_field(
+    map: &serde_json::Map<String, Value>,
+    key: &str,
+) -> Result<String, ForgeStudioError> {
+    map.get(key)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| ForgeStudioError::parse(format!("Field '{key}' is missing or invalid")))
+}
+
+pub(crate) fn float_field(
+    map: &serde_json::Map<String, Value>,
+    key: &str,
+) -> Result<f64, ForgeStudioError> {
+    map.get(key)
+        .and_then(Value::as_f64)
+        .ok_or_else(|| ForgeStudioError::parse(format!("Field '{key}' is missing or invalid")))
+}
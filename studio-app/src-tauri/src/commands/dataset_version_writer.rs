@@ -0,0 +1,159 @@
+//! Derived dataset version persistence matching the forge snapshot layout.
+//!
+//! Writes `records.jsonl`, a version `manifest.json`, and appends the version
+//! to `catalog.json` exactly as the CLI snapshot store does, so versions
+//! created from Studio are indistinguishable from CLI-created ones. The
+//! catalog is updated under the lock the CLI takes for the same file, and
+//! each entry records the records file checksum integrity checks compare.
+
+use crate::commands::atomic_write::write_file_atomic;
+use crate::commands::content_hash::sha256_hex;
+use crate::commands::dataset_store_io::{dataset_root, read_catalog, version_dir};
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::run_store_io::write_json_file;
+use crate::commands::version_integrity::CATALOG_CHECKSUM_FIELD;
+use crate::models::ForgeStudioError;
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+const VERSION_DIGEST_LENGTH: usize = 10;
+
+/// Inputs for one derived version written on top of an existing parent version.
+pub(crate) struct DerivedVersionRequest<'a> {
+    pub data_root: &'a str,
+    pub dataset_name: &'a str,
+    pub parent_version: &'a str,
+    pub recipe_step: String,
+    pub records: Vec<Value>,
+}
+
+/// Persists a new immutable version and returns its version id.
+pub(crate) fn write_derived_version(
+    request: DerivedVersionRequest<'_>,
+) -> Result<String, ForgeStudioError> {
+    let version_id = build_version_id(request.dataset_name, &request.records)?;
+    let target_dir = version_dir(request.data_root, request.dataset_name, &version_id);
+    fs::create_dir_all(target_dir.parent().unwrap_or(&target_dir)).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to create versions directory: {error}"))
+    })?;
+    fs::create_dir(&target_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to create version directory {}: {error}",
+            target_dir.display()
+        ))
+    })?;
+    let content_checksum = write_records_file(&target_dir, &request.records)?;
+    let catalog_root = dataset_root(request.data_root, request.dataset_name);
+    let catalog_path = catalog_root.join("catalog.json");
+    // Held from read to write so a version the CLI appends meanwhile survives.
+    with_file_lock(&catalog_path, || {
+        let mut catalog = read_catalog(&catalog_root)?;
+        let mut manifest = build_manifest(&request, &version_id, &catalog)?;
+        manifest[CATALOG_CHECKSUM_FIELD] = Value::String(content_checksum);
+        write_json_file(
+            &target_dir.join("manifest.json"),
+            &manifest_with_lance_flag(&manifest),
+        )?;
+        append_catalog_version(&mut catalog, manifest, &version_id)?;
+        write_json_file(&catalog_path, &catalog)
+    })?;
+    Ok(version_id)
+}
+
+fn build_version_id(dataset_name: &str, records: &[Value]) -> Result<String, ForgeStudioError> {
+    // Mirrors `catalog_io.build_version_id`: timestamp plus a digest of record ids.
+    let mut record_ids = Vec::with_capacity(records.len());
+    for record in records {
+        let record_id = record
+            .get("record_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ForgeStudioError::parse("Record is missing record_id"))?;
+        record_ids.push(record_id);
+    }
+    let digest_hex = sha256_hex(record_ids.join("|").as_bytes());
+    let timestamp = Utc::now().format("%Y%m%dT%H%M%S%6fZ");
+    Ok(format!(
+        "{dataset_name}-{timestamp}-{}",
+        &digest_hex[..VERSION_DIGEST_LENGTH]
+    ))
+}
+
+fn build_manifest(
+    request: &DerivedVersionRequest<'_>,
+    version_id: &str,
+    catalog: &Value,
+) -> Result<Value, ForgeStudioError> {
+    let parent_entry = catalog
+        .get("versions")
+        .and_then(Value::as_array)
+        .and_then(|versions| {
+            versions.iter().find(|entry| {
+                entry.get("version_id").and_then(Value::as_str) == Some(request.parent_version)
+            })
+        })
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Parent version '{}' is not in the {} catalog.",
+                request.parent_version, request.dataset_name
+            ))
+        })?;
+    let mut recipe_steps = parent_entry
+        .get("recipe_steps")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    recipe_steps.push(Value::String(request.recipe_step.clone()));
+    Ok(json!({
+        "dataset_name": request.dataset_name,
+        "version_id": version_id,
+        "created_at": Utc::now().to_rfc3339_opts(SecondsFormat::Micros, false),
+        "parent_version": request.parent_version,
+        "recipe_steps": recipe_steps,
+        "record_count": request.records.len(),
+    }))
+}
+
+fn manifest_with_lance_flag(manifest: &Value) -> Value {
+    let mut payload = manifest.clone();
+    if let Some(object) = payload.as_object_mut() {
+        object.insert("lance_written".to_string(), Value::Bool(false));
+    }
+    payload
+}
+
+fn append_catalog_version(
+    catalog: &mut Value,
+    manifest: Value,
+    version_id: &str,
+) -> Result<(), ForgeStudioError> {
+    let catalog_object = catalog
+        .as_object_mut()
+        .ok_or_else(|| ForgeStudioError::parse("Catalog must be a JSON object"))?;
+    catalog_object
+        .get_mut("versions")
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| ForgeStudioError::parse("Catalog is missing versions array"))?
+        .push(manifest);
+    catalog_object.insert(
+        "latest_version".to_string(),
+        Value::String(version_id.to_string()),
+    );
+    Ok(())
+}
+
+/// Returns the SHA-256 of the written file.
+fn write_records_file(target_dir: &Path, records: &[Value]) -> Result<String, ForgeStudioError> {
+    let mut payload = String::new();
+    for record in records {
+        let line = serde_json::to_string(record).map_err(|error| {
+            ForgeStudioError::internal(format!("Failed to serialize record: {error}"))
+        })?;
+        payload.push_str(&line);
+        payload.push('\n');
+    }
+    let records_path = target_dir.join("records.jsonl");
+    write_file_atomic(&records_path, payload.as_bytes())?;
+    Ok(sha256_hex(payload.as_bytes()))
+}
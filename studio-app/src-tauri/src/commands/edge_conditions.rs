@@ -0,0 +1,192 @@
+//! Conditional edges, added in canvas format v2.
+//!
+//! An edge may carry a condition on a metric of its source node's output,
+//! such as the record count of the dataset version an upstream filter wrote.
+//! Validation rejects conditions the source node can never satisfy, so a typo
+//! fails before the run instead of silently skipping half the pipeline. The
+//! runner evaluates a node's conditions once all its upstream nodes have
+//! succeeded; an unmet one skips the node and everything downstream of it,
+//! which is a planned outcome rather than a failure.
+
+use crate::commands::canvas_validation::issue;
+use crate::commands::dataset_store_io::{dataset_root, read_catalog};
+use crate::models::{
+    PipelineCanvasIssue, PipelineEdgeCondition, PipelineEdgeSnapshot, PipelineNodeSnapshot,
+    PipelineRunNode,
+};
+use serde_json::Value;
+use std::path::Path;
+
+/// Metrics a condition can test, with the node types whose output has them.
+const CONDITION_METRICS: [(&str, &[&str]); 1] = [("record_count", &["ingest", "filter"])];
+const CONDITION_OPERATORS: [&str; 6] = [">", ">=", "<", "<=", "==", "!="];
+
+/// An `invalid_condition` error for every condition that cannot be evaluated.
+pub(crate) fn check_edge_conditions(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+) -> Vec<PipelineCanvasIssue> {
+    let mut issues = Vec::new();
+    for edge in edges {
+        let Some(condition) = &edge.condition else {
+            continue;
+        };
+        let source_type = nodes
+            .iter()
+            .find(|node| node.id == edge.source_node_id)
+            .map_or("", |node| node.node_type.as_str());
+        let problem = condition_problem(condition, source_type);
+        if let Some(problem) = problem {
+            let node_ids = vec![edge.source_node_id.as_str(), edge.target_node_id.as_str()];
+            let message = format!("Condition on edge '{}' {problem}", edge.id);
+            issues.push(PipelineCanvasIssue {
+                edge_id: Some(edge.id.clone()),
+                ..issue("invalid_condition", node_ids, message)
+            });
+        }
+    }
+    issues
+}
+
+/// What makes `condition` unusable on an edge leaving a `source_type` node.
+fn condition_problem(condition: &PipelineEdgeCondition, source_type: &str) -> Option<String> {
+    let Some((metric, source_types)) = CONDITION_METRICS
+        .iter()
+        .find(|(metric, _)| *metric == condition.metric)
+    else {
+        return Some(format!(
+            "tests unknown metric '{}'. Use one of: {}.",
+            condition.metric,
+            metric_names().join(", ")
+        ));
+    };
+    if !source_types.contains(&source_type) {
+        return Some(format!(
+            "tests {metric}, which a '{source_type}' node does not produce. Move it to an edge leaving a {} node.",
+            source_types.join(" or ")
+        ));
+    }
+    if !CONDITION_OPERATORS.contains(&condition.operator.as_str()) {
+        return Some(format!(
+            "uses unknown operator '{}'. Use one of: {}.",
+            condition.operator,
+            CONDITION_OPERATORS.join(" ")
+        ));
+    }
+    (!condition.value.is_finite())
+        .then(|| "compares against a non-finite value. Use a number.".to_string())
+}
+
+/// Why `node` must be skipped, if one of its incoming conditions is not met.
+pub(crate) fn unmet_condition(
+    data_root: &Path,
+    node: &PipelineRunNode,
+    nodes: &[PipelineRunNode],
+) -> Option<String> {
+    for (upstream_id, condition) in &node.conditions {
+        let Some(upstream) = nodes.iter().find(|other| &other.node_id == upstream_id) else {
+            continue;
+        };
+        let Some(actual) = metric_value(data_root, upstream, &condition.metric) else {
+            return Some(format!(
+                "Skipped because upstream node '{upstream_id}' reported no {}, so its edge condition cannot pass. Check that node's output.",
+                condition.metric
+            ));
+        };
+        if !passes(actual, condition) {
+            return Some(format!(
+                "Skipped because upstream node '{upstream_id}' has {} {actual}, which is not {} {}.",
+                condition.metric, condition.operator, condition.value
+            ));
+        }
+    }
+    None
+}
+
+/// Record count of the version the upstream node wrote, from its dataset catalog.
+fn metric_value(data_root: &Path, upstream: &PipelineRunNode, metric: &str) -> Option<f64> {
+    if metric != "record_count" {
+        return None;
+    }
+    let dataset_name = upstream.outputs.dataset_name.as_deref()?;
+    let version_id = upstream.outputs.dataset_version_id.as_deref()?;
+    let catalog = read_catalog(&dataset_root(&data_root.to_string_lossy(), dataset_name)).ok()?;
+    catalog
+        .get("versions")?
+        .as_array()?
+        .iter()
+        .find(|version| version.get("version_id").and_then(Value::as_str) == Some(version_id))?
+        .get("record_count")?
+        .as_f64()
+}
+
+fn passes(actual: f64, condition: &PipelineEdgeCondition) -> bool {
+    let expected = condition.value;
+    match condition.operator.as_str() {
+        ">" => actual > expected,
+        ">=" => actual >= expected,
+        "<" => actual < expected,
+        "<=" => actual <= expected,
+        "==" => actual == expected,
+        "!=" => actual != expected,
+        _ => false,
+    }
+}
+
+fn metric_names() -> Vec<&'static str> {
+    CONDITION_METRICS
+        .iter()
+        .map(|(metric, _)| *metric)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_edge_conditions, passes};
+    use crate::models::{PipelineEdgeCondition, PipelineEdgeSnapshot, PipelineNodeSnapshot};
+    use std::collections::BTreeMap;
+
+    fn node(id: &str, node_type: &str) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    fn gated_edge(source: &str, metric: &str, operator: &str) -> PipelineEdgeSnapshot {
+        PipelineEdgeSnapshot {
+            id: format!("{source}->train"),
+            source_node_id: source.to_string(),
+            target_node_id: "train".to_string(),
+            condition: Some(PipelineEdgeCondition {
+                metric: metric.to_string(),
+                operator: operator.to_string(),
+                value: 1000.0,
+            }),
+        }
+    }
+
+    #[test]
+    fn conditions_are_checked_against_their_source() {
+        let nodes = vec![
+            node("filter", "filter"),
+            node("chat", "chat"),
+            node("train", "train"),
+        ];
+        let edges = vec![
+            gated_edge("filter", "record_count", ">"),
+            gated_edge("chat", "record_count", ">"),
+            gated_edge("filter", "loss", ">"),
+            gated_edge("filter", "record_count", "=>"),
+        ];
+        let issues = check_edge_conditions(&nodes, &edges);
+        assert_eq!(issues.len(), 3);
+        assert!(issues.iter().all(|issue| issue.kind == "invalid_condition"));
+        assert!(passes(1200.0, edges[0].condition.as_ref().expect("gated")));
+        assert!(!passes(1000.0, edges[0].condition.as_ref().expect("gated")));
+    }
+}
@@ -0,0 +1,164 @@
+//! Per-output-dir power log and its integration into energy and GPU-hours.
+//!
+//! The telemetry sampler does not know run ids (the CLI assigns them after
+//! launch), so readings are appended to the `--output-dir` of every running
+//! `train` task. A run later integrates only the samples inside its own
+//! lifecycle window, which keeps resumed runs sharing an output dir apart.
+
+use crate::commands::forge_commands::{flag_value, OUTPUT_DIR_FLAG};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_store_io::resolve_workspace_path;
+use crate::models::{PowerSample, RunLifecycleRecord, TelemetrySnapshot};
+use chrono::{DateTime, FixedOffset};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+const ENERGY_SAMPLES_FILE_NAME: &str = "energy_samples.jsonl";
+const TRAIN_COMMAND: &str = "train";
+/// Longer gaps mean sampling stopped (app closed), so they are not billed.
+const MAX_SAMPLE_GAP_SECONDS: f64 = 15.0;
+const SECONDS_PER_HOUR: f64 = 3600.0;
+const WATT_HOURS_PER_KWH: f64 = 1000.0;
+
+/// Integrated usage over one run's samples.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct EnergyUsage {
+    pub sample_count: usize,
+    pub measured_hours: f64,
+    pub gpu_hours: f64,
+    pub energy_kwh: Option<f64>,
+}
+
+/// Appends the snapshot to the output dir of every running train task; best effort.
+pub(crate) fn record_power_samples(task_store: &CommandTaskStore, snapshot: &TelemetrySnapshot) {
+    let reported: Vec<f64> = snapshot
+        .devices
+        .iter()
+        .filter_map(|device| device.power_watts)
+        .collect();
+    let sample = PowerSample {
+        sampled_at: snapshot.sampled_at.clone(),
+        device_count: snapshot.devices.len() as u32,
+        power_watts: (!reported.is_empty()).then(|| reported.iter().sum()),
+    };
+    let Ok(line) = serde_json::to_string(&sample) else {
+        return;
+    };
+    for args in task_store.running_command_args(TRAIN_COMMAND) {
+        let Some(output_dir) = flag_value(&args, OUTPUT_DIR_FLAG) else {
+            continue;
+        };
+        let output_path = resolve_workspace_path(output_dir);
+        let _ = fs::create_dir_all(&output_path);
+        let appended = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(output_path.join(ENERGY_SAMPLES_FILE_NAME))
+            .and_then(|mut file| writeln!(file, "{line}"));
+        if let Err(error) = appended {
+            tracing::warn!("Failed to record power sample for {output_dir}: {error}");
+        }
+    }
+}
+
+/// Integrates the samples recorded in the run's output dir during its lifetime.
+pub(crate) fn load_run_energy_usage(lifecycle: &RunLifecycleRecord) -> EnergyUsage {
+    let samples_path = resolve_workspace_path(&lifecycle.output_dir).join(ENERGY_SAMPLES_FILE_NAME);
+    let samples = read_power_samples(&samples_path);
+    let window_start = parse_timestamp(&lifecycle.created_at);
+    // A running run's `updated_at` lags its latest samples, so its window stays open.
+    let window_end = (lifecycle.state != "running")
+        .then(|| parse_timestamp(&lifecycle.updated_at))
+        .flatten();
+    let mut in_window: Vec<(DateTime<FixedOffset>, PowerSample)> = samples
+        .into_iter()
+        .filter_map(|sample| Some((parse_timestamp(&sample.sampled_at)?, sample)))
+        .filter(|(at, _)| window_start.is_none_or(|start| *at >= start))
+        .filter(|(at, _)| window_end.is_none_or(|end| *at <= end))
+        .collect();
+    in_window.sort_by_key(|(at, _)| *at);
+    integrate_samples(&in_window)
+}
+
+/// Trapezoidal integration between consecutive samples, skipping long gaps.
+fn integrate_samples(samples: &[(DateTime<FixedOffset>, PowerSample)]) -> EnergyUsage {
+    let mut usage = EnergyUsage {
+        sample_count: samples.len(),
+        ..EnergyUsage::default()
+    };
+    let mut watt_hours: Option<f64> = None;
+    for pair in samples.windows(2) {
+        let (start, first) = &pair[0];
+        let (end, second) = &pair[1];
+        let seconds = (*end - *start).num_milliseconds() as f64 / 1000.0;
+        if seconds <= 0.0 || seconds > MAX_SAMPLE_GAP_SECONDS {
+            continue;
+        }
+        let hours = seconds / SECONDS_PER_HOUR;
+        usage.measured_hours += hours;
+        usage.gpu_hours += hours * f64::from(first.device_count.max(second.device_count));
+        if let (Some(left), Some(right)) = (first.power_watts, second.power_watts) {
+            *watt_hours.get_or_insert(0.0) += (left + right) / 2.0 * hours;
+        }
+    }
+    usage.energy_kwh = watt_hours.map(|value| value / WATT_HOURS_PER_KWH);
+    usage
+}
+
+/// Malformed lines (e.g. a write cut short by a crash) are skipped.
+fn read_power_samples(samples_path: &Path) -> Vec<PowerSample> {
+    let Ok(payload) = fs::read_to_string(samples_path) else {
+        return Vec::new();
+    };
+    payload
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn parse_timestamp(raw_value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(raw_value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{integrate_samples, parse_timestamp};
+    use crate::models::PowerSample;
+    use chrono::{DateTime, FixedOffset};
+
+    fn sample(at: &str, power_watts: Option<f64>) -> (DateTime<FixedOffset>, PowerSample) {
+        let sample = PowerSample {
+            sampled_at: at.to_string(),
+            device_count: 2,
+            power_watts,
+        };
+        (parse_timestamp(at).expect("timestamp should parse"), sample)
+    }
+
+    #[test]
+    fn integrate_samples_skips_gaps_and_averages_power() {
+        let samples = vec![
+            sample("2026-01-01T00:00:00+00:00", Some(300.0)),
+            sample("2026-01-01T00:00:10+00:00", Some(500.0)),
+            sample("2026-01-01T01:00:00+00:00", Some(500.0)),
+        ];
+        let usage = integrate_samples(&samples);
+        assert_eq!(usage.sample_count, 3);
+        let hours = 10.0 / 3600.0;
+        assert!((usage.gpu_hours - 2.0 * hours).abs() < 1e-12);
+        let expected_kwh = 400.0 * hours / 1000.0;
+        assert!((usage.energy_kwh.expect("power was reported") - expected_kwh).abs() < 1e-12);
+    }
+
+    #[test]
+    fn integrate_samples_leaves_energy_unknown_without_power() {
+        let samples = vec![
+            sample("2026-01-01T00:00:00Z", None),
+            sample("2026-01-01T00:00:05Z", None),
+        ];
+        let usage = integrate_samples(&samples);
+        assert!(usage.gpu_hours > 0.0);
+        assert_eq!(usage.energy_kwh, None);
+    }
+}
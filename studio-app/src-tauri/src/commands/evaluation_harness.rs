@@ -0,0 +1,221 @@
+//! Evaluation harness: run `forge eval` and persist its scores.
+//!
+//! The CLI prints each score as a `metric=value` line like its other
+//! commands, so the watcher reads the requested metrics from the finished
+//! task's stdout rather than depending on an output file layout. Scores go
+//! into the run's eval store and the evaluation becomes a lineage node
+//! linked to both the evaluated run and the dataset version it scored on.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::resolve_version;
+use crate::commands::forge_commands::validate_args;
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::lineage_store_io::{
+    dataset_node_id, eval_node_id, run_node_id, update_lineage_graph,
+};
+use crate::commands::run_details::resolve_run_artifacts;
+use crate::commands::run_evaluations::append_eval_result;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, resolve_data_root_path, resolve_workspace_path,
+};
+use crate::models::{
+    EvalResult, EvalResultInput, EvaluationRequest, EvaluationStart, ForgeStudioError,
+};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tauri::State;
+
+const SUPPORTED_EVAL_METRICS: [&str; 3] = ["perplexity", "loss", "accuracy"];
+const EVAL_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const EVALUATED_EDGE_TYPE: &str = "evaluated";
+const EVALUATED_ON_EDGE_TYPE: &str = "evaluated_on";
+
+struct EvaluationWatch {
+    data_root: PathBuf,
+    task_store: CommandTaskStore,
+    task_id: String,
+    start: EvaluationStart,
+    model_path: String,
+}
+
+#[tauri::command]
+pub fn start_evaluation(
+    data_root: String,
+    request: EvaluationRequest,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<EvaluationStart, ForgeStudioError> {
+    let metrics = normalize_metrics(&request.metrics)?;
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &request.run_id)?;
+    let model_path = match request.model_path.as_deref() {
+        Some(path) => Some(resolve_workspace_path(path).display().to_string()),
+        None => resolve_run_artifacts(&lifecycle)?.model_path,
+    }
+    .filter(|path| Path::new(path).is_file())
+    .ok_or_else(|| {
+        ForgeStudioError::not_found(format!(
+            "Run '{}' has no model file to evaluate. Pass a checkpoint path.",
+            request.run_id
+        ))
+    })?;
+    let version_id = resolve_version(&data_root, &request.dataset_name, request.version_id)?;
+    let args = vec![
+        "eval".to_string(),
+        "--model-path".to_string(),
+        model_path.clone(),
+        "--dataset".to_string(),
+        request.dataset_name.clone(),
+        "--version-id".to_string(),
+        version_id.clone(),
+        "--metrics".to_string(),
+        metrics.join(","),
+    ];
+    validate_args(&args)?;
+    let task = task_store.start_task(data_root, args);
+    let start = EvaluationStart {
+        task_id: task.task_id.clone(),
+        estimated_total_seconds: task.estimated_total_seconds,
+        run_id: request.run_id,
+        dataset_name: request.dataset_name,
+        version_id,
+        metrics,
+    };
+    spawn_evaluation_watch(EvaluationWatch {
+        data_root: resolved_data_root,
+        task_store: task_store.inner().clone(),
+        task_id: task.task_id,
+        start: start.clone(),
+        model_path,
+    });
+    Ok(start)
+}
+
+fn normalize_metrics(requested: &[String]) -> Result<Vec<String>, ForgeStudioError> {
+    let mut metrics: Vec<String> = Vec::new();
+    for metric in requested.iter().map(|value| value.trim().to_lowercase()) {
+        if !SUPPORTED_EVAL_METRICS.contains(&metric.as_str()) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Unsupported eval metric '{metric}'. Use one of: {}.",
+                SUPPORTED_EVAL_METRICS.join(", ")
+            )));
+        }
+        if !metrics.contains(&metric) {
+            metrics.push(metric);
+        }
+    }
+    if metrics.is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "No eval metrics requested. Pick at least one, e.g. perplexity.",
+        ));
+    }
+    Ok(metrics)
+}
+
+fn spawn_evaluation_watch(watch: EvaluationWatch) {
+    thread::spawn(move || loop {
+        let Ok(status) = watch.task_store.get_task_status(&watch.task_id) else {
+            return;
+        };
+        if status.status == task_status_name(TaskLifecycleStatus::Running) {
+            thread::sleep(EVAL_WATCH_POLL_INTERVAL);
+            continue;
+        }
+        if status.status != task_status_name(TaskLifecycleStatus::Completed) {
+            return;
+        }
+        let scores = parse_metric_lines(&status.stdout, &watch.start.metrics);
+        if scores.is_empty() {
+            tracing::warn!(
+                "Evaluation task {} printed none of {}, so nothing was recorded. Check its log.",
+                watch.task_id,
+                watch.start.metrics.join(", ")
+            );
+        } else if let Err(error) = persist_evaluation(&watch, scores) {
+            tracing::warn!(
+                "Failed to record evaluation task {}: {error}",
+                watch.task_id
+            );
+        }
+        return;
+    });
+}
+
+/// Reads `metric=value` lines for the requested metrics, ignoring other CLI output.
+fn parse_metric_lines(stdout: &str, metrics: &[String]) -> BTreeMap<String, f64> {
+    stdout
+        .lines()
+        .filter_map(|line| line.trim().split_once('='))
+        .filter(|(key, _)| metrics.iter().any(|metric| metric == key.trim()))
+        .filter_map(|(key, value)| {
+            let score = value.trim().parse::<f64>().ok()?;
+            Some((key.trim().to_string(), score))
+        })
+        .collect()
+}
+
+fn persist_evaluation(
+    watch: &EvaluationWatch,
+    scores: BTreeMap<String, f64>,
+) -> Result<EvalResult, ForgeStudioError> {
+    let start = &watch.start;
+    let result = append_eval_result(
+        &watch.data_root,
+        &start.run_id,
+        EvalResultInput {
+            suite: start.dataset_name.clone(),
+            metrics: scores,
+            model_path: Some(watch.model_path.clone()),
+            dataset_version_id: Some(start.version_id.clone()),
+            details: Some(json!({"task_id": watch.task_id, "source": "forge eval"})),
+        },
+    )?;
+    let eval_node = eval_node_id(&start.run_id, &result.eval_id);
+    update_lineage_graph(&watch.data_root, |graph| {
+        graph.edges.push(json!({
+            "from": run_node_id(&start.run_id),
+            "to": eval_node,
+            "type": EVALUATED_EDGE_TYPE,
+        }));
+        graph.edges.push(json!({
+            "from": dataset_node_id(&start.dataset_name, &start.version_id),
+            "to": eval_node,
+            "type": EVALUATED_ON_EDGE_TYPE,
+        }));
+        Ok(((), true))
+    })?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_metrics, parse_metric_lines};
+
+    #[test]
+    fn parse_metric_lines_keeps_only_requested_numeric_metrics() {
+        let stdout = "run_id=run-a\nperplexity=41.5\nloss = 3.72\naccuracy=n/a\n";
+        let metrics = vec![
+            "perplexity".to_string(),
+            "loss".to_string(),
+            "accuracy".to_string(),
+        ];
+        let scores = parse_metric_lines(stdout, &metrics);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores.get("loss"), Some(&3.72));
+    }
+
+    #[test]
+    fn normalize_metrics_dedupes_and_rejects_unknown_names() {
+        let requested = vec!["Perplexity".to_string(), "perplexity".to_string()];
+        assert_eq!(
+            normalize_metrics(&requested).expect("valid"),
+            vec!["perplexity"]
+        );
+        assert!(normalize_metrics(&["bleu".to_string()]).is_err());
+        assert!(normalize_metrics(&[]).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+//! Local WebSocket endpoint that broadcasts the backend event bus, so
+//! dashboards, notebooks, and editor plugins can follow live Forge activity.
+//!
+//! The `event_bridge` setting starts it at launch on 127.0.0.1. Each message
+//! is one text frame holding the same JSON envelope the frontend receives as
+//! a `backend-event`: `sequence`, `published_at`, and the event, whose `kind`
+//! is e.g. `task_progress`, `pipeline_run_updated`, or `dataset_changed`.
+//! Connecting to `ws://127.0.0.1:<port>/?after=<sequence>` first replays the
+//! buffered events newer than `sequence`, so a client that reconnects misses
+//! nothing the bus still holds. A client that falls a full buffer behind is
+//! disconnected and reconnects that way. The bridge only sends; what clients
+//! send is ignored. Browsers always send `Origin`, so web pages connect only
+//! from an origin listed in `allowed_origins`; scripts send none and are
+//! always allowed.
+
+use crate::commands::event_bus::EventBus;
+use crate::models::{BackendEventEnvelope, EventBridgeSettings};
+use std::io::ErrorKind;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+use tungstenite::handshake::server::{Callback, ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::{Error, Message, WebSocket};
+
+/// How long a connection waits on its client before sending queued events.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Starts the endpoint on a background thread of the running app.
+pub(crate) fn spawn_event_bridge(event_bus: EventBus, settings: EventBridgeSettings) {
+    thread::spawn(move || {
+        let port = settings.port;
+        let listener = match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(listener) => listener,
+            Err(error) => {
+                tracing::warn!(
+                    "Event bridge could not listen on 127.0.0.1:{port}: {error}. Pick another port."
+                );
+                return;
+            }
+        };
+        tracing::info!("Event bridge listening on ws://127.0.0.1:{port}");
+        for stream in listener.incoming().flatten() {
+            let event_bus = event_bus.clone();
+            let allowed_origins = settings.allowed_origins.clone();
+            thread::spawn(move || {
+                if let Err(error) = bridge_connection(stream, &event_bus, &allowed_origins) {
+                    tracing::debug!("Event bridge connection closed: {error}");
+                }
+            });
+        }
+    });
+}
+
+fn bridge_connection(
+    stream: TcpStream,
+    event_bus: &EventBus,
+    allowed_origins: &[String],
+) -> Result<(), String> {
+    let mut after_sequence = 0;
+    let handshake = HandshakeCheck {
+        allowed_origins,
+        after_sequence: &mut after_sequence,
+    };
+    let mut socket = tungstenite::accept_hdr(stream, handshake)
+        .map_err(|error| format!("handshake failed: {error}"))?;
+    socket
+        .get_ref()
+        .set_read_timeout(Some(POLL_INTERVAL))
+        .map_err(|error| error.to_string())?;
+    // Subscribing before the replay means no event falls between the two.
+    let events = event_bus.subscribe();
+    let replay = event_bus
+        .events_after(after_sequence)
+        .map_err(|error| error.to_string())?;
+    let mut last_sent = after_sequence;
+    for envelope in replay {
+        send_envelope(&mut socket, &envelope, &mut last_sent)?;
+    }
+    loop {
+        match socket.read() {
+            Ok(Message::Close(_)) => return Ok(()),
+            Ok(_) => {}
+            Err(Error::Io(error))
+                if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(error) => return Err(error.to_string()),
+        }
+        forward_events(&mut socket, &events, &mut last_sent)?;
+    }
+}
+
+/// Sends every queued event; errs once the bus has dropped the subscription.
+fn forward_events(
+    socket: &mut WebSocket<TcpStream>,
+    events: &Receiver<BackendEventEnvelope>,
+    last_sent: &mut u64,
+) -> Result<(), String> {
+    loop {
+        match events.try_recv() {
+            Ok(envelope) => send_envelope(socket, &envelope, last_sent)?,
+            Err(TryRecvError::Empty) => return Ok(()),
+            Err(TryRecvError::Disconnected) => {
+                let _ = socket.close(None);
+                return Err("client fell behind the event bus".to_string());
+            }
+        }
+    }
+}
+
+/// Skips events the replay already sent.
+fn send_envelope(
+    socket: &mut WebSocket<TcpStream>,
+    envelope: &BackendEventEnvelope,
+    last_sent: &mut u64,
+) -> Result<(), String> {
+    if envelope.sequence <= *last_sent {
+        return Ok(());
+    }
+    let payload = serde_json::to_string(envelope).map_err(|error| error.to_string())?;
+    socket
+        .send(Message::Text(payload))
+        .map_err(|error| error.to_string())?;
+    *last_sent = envelope.sequence;
+    Ok(())
+}
+
+/// Answers the upgrade request, recording where the replay starts.
+struct HandshakeCheck<'a> {
+    allowed_origins: &'a [String],
+    after_sequence: &'a mut u64,
+}
+
+impl Callback for HandshakeCheck<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        match check_handshake(request, self.allowed_origins) {
+            Ok(after_sequence) => {
+                *self.after_sequence = after_sequence;
+                Ok(response)
+            }
+            Err((status, reason)) => {
+                let mut response = ErrorResponse::new(Some(reason.to_string()));
+                *response.status_mut() = status;
+                Err(response)
+            }
+        }
+    }
+}
+
+/// Refuses unlisted web origins and returns the `after` query's sequence.
+fn check_handshake(
+    request: &Request,
+    allowed_origins: &[String],
+) -> Result<u64, (StatusCode, &'static str)> {
+    if let Some(origin) = request.headers().get("Origin") {
+        let origin = origin.to_str().unwrap_or_default();
+        if !allowed_origins.iter().any(|allowed| allowed == origin) {
+            return Err((
+                StatusCode::FORBIDDEN,
+                "Origin is not allowed. Add it to event_bridge allowed_origins.",
+            ));
+        }
+    }
+    let after = request
+        .uri()
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("after="));
+    match after {
+        Some(sequence) => sequence.parse().map_err(|_| {
+            (
+                StatusCode::BAD_REQUEST,
+                "after must be an event sequence number.",
+            )
+        }),
+        None => Ok(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_handshake;
+    use tungstenite::handshake::server::Request;
+
+    #[test]
+    fn handshakes_check_origin_and_read_the_replay_point() {
+        let request = |uri: &str, origin: Option<&str>| {
+            let mut builder = Request::builder().uri(uri);
+            if let Some(origin) = origin {
+                builder = builder.header("Origin", origin);
+            }
+            builder.body(()).expect("request should build")
+        };
+        let allowed = vec!["http://localhost:8888".to_string()];
+
+        assert_eq!(check_handshake(&request("/", None), &allowed).ok(), Some(0));
+        assert_eq!(
+            check_handshake(&request("/?after=42", None), &allowed).ok(),
+            Some(42)
+        );
+        assert!(check_handshake(&request("/?after=soon", None), &allowed).is_err());
+        let notebook = request("/", Some("http://localhost:8888"));
+        assert!(check_handshake(&notebook, &allowed).is_ok());
+        let page = check_handshake(&request("/", Some("https://example.com")), &allowed)
+            .expect_err("unlisted origin");
+        assert_eq!(page.0, 403);
+    }
+}
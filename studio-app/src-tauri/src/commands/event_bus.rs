@@ -0,0 +1,134 @@
+//! Central bus every backend subsystem publishes its events through.
+//!
+//! The task store, pipeline runner, watchers, and telemetry sampler publish
+//! typed `BackendEvent`s here instead of emitting on their own channels, and
+//! the bus forwards each one to the frontend as a `backend-event`, so the UI
+//! reacts to changes rather than polling for them. Events are numbered and
+//! the newest are kept in memory, so a window that reloads or notices a gap
+//! in the sequence catches up with `list_backend_events`. Other consumers,
+//! such as the WebSocket bridge, `subscribe` to a bounded channel; one that
+//! falls a full buffer behind is dropped and catches up the same way. Chat
+//! tokens stay on their per-session channels: they are a stream, not state
+//! changes.
+
+use crate::models::{BackendEvent, BackendEventEnvelope, ForgeStudioError};
+use chrono::{SecondsFormat, Utc};
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, State};
+
+pub const BACKEND_EVENT: &str = "backend-event";
+const MAX_BUFFERED_EVENTS: usize = 500;
+
+#[derive(Clone, Default)]
+pub struct EventBus {
+    inner: Arc<Mutex<EventBusState>>,
+}
+
+#[derive(Default)]
+struct EventBusState {
+    /// Unset until app setup attaches it; events are still buffered before then.
+    app: Option<AppHandle>,
+    next_sequence: u64,
+    recent: VecDeque<BackendEventEnvelope>,
+    subscribers: Vec<SyncSender<BackendEventEnvelope>>,
+}
+
+impl EventBus {
+    pub fn attach(&self, app: AppHandle) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.app = Some(app);
+        }
+    }
+
+    /// Numbers `event`, buffers it, and forwards it to the frontend.
+    pub fn publish(&self, event: BackendEvent) {
+        let Ok(mut state) = self.inner.lock() else {
+            return;
+        };
+        state.next_sequence += 1;
+        let envelope = BackendEventEnvelope {
+            sequence: state.next_sequence,
+            published_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            event,
+        };
+        if state.recent.len() == MAX_BUFFERED_EVENTS {
+            state.recent.pop_front();
+        }
+        state.recent.push_back(envelope.clone());
+        state
+            .subscribers
+            .retain(|subscriber| subscriber.try_send(envelope.clone()).is_ok());
+        // Emitting under the lock keeps events reaching the frontend in sequence order.
+        if let Some(app) = &state.app {
+            let _ = app.emit(BACKEND_EVENT, envelope);
+        }
+    }
+
+    /// Receives every event published from now on, until the receiver is
+    /// dropped or stops keeping up.
+    pub fn subscribe(&self) -> Receiver<BackendEventEnvelope> {
+        let (sender, receiver) = mpsc::sync_channel(MAX_BUFFERED_EVENTS);
+        if let Ok(mut state) = self.inner.lock() {
+            state.subscribers.push(sender);
+        }
+        receiver
+    }
+
+    pub(crate) fn events_after(
+        &self,
+        after_sequence: u64,
+    ) -> Result<Vec<BackendEventEnvelope>, ForgeStudioError> {
+        let state = self
+            .inner
+            .lock()
+            .map_err(|_| ForgeStudioError::internal("Event bus lock poisoned. Restart Studio."))?;
+        Ok(state
+            .recent
+            .iter()
+            .filter(|envelope| envelope.sequence > after_sequence)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Buffered events newer than `after_sequence`, oldest first.
+#[tauri::command]
+pub fn list_backend_events(
+    after_sequence: Option<u64>,
+    event_bus: State<'_, EventBus>,
+) -> Result<Vec<BackendEventEnvelope>, ForgeStudioError> {
+    event_bus.events_after(after_sequence.unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventBus, MAX_BUFFERED_EVENTS};
+    use crate::models::BackendEvent;
+
+    #[test]
+    fn events_are_numbered_and_the_newest_kept() {
+        let event_bus = EventBus::default();
+        let subscriber = event_bus.subscribe();
+        for number in 0..MAX_BUFFERED_EVENTS + 2 {
+            event_bus.publish(BackendEvent::PipelineRunUpdated {
+                pipeline_run_id: format!("run-{number}"),
+                status: "running".to_string(),
+                overall_percent: 0.0,
+            });
+        }
+        let all = event_bus.events_after(0).expect("events should list");
+        assert_eq!(all.len(), MAX_BUFFERED_EVENTS);
+        assert_eq!(all[0].sequence, 3);
+        let newest = event_bus
+            .events_after(MAX_BUFFERED_EVENTS as u64 + 1)
+            .expect("events should list");
+        assert_eq!(newest.len(), 1);
+        let payload = serde_json::to_value(&newest[0]).expect("event should serialize");
+        assert_eq!(payload["kind"], "pipeline_run_updated");
+        assert_eq!(payload["sequence"], MAX_BUFFERED_EVENTS as u64 + 2);
+        // It fell a full buffer behind, so it got that much and was dropped.
+        assert_eq!(subscriber.try_iter().count(), MAX_BUFFERED_EVENTS);
+    }
+}
@@ -0,0 +1,101 @@
+//! Advisory locks shared with the forge CLI for data root files.
+//!
+//! `catalog.json`, `runs/index.json`, and the lineage graph are rewritten in
+//! full by whichever process appends to them, so Studio and a concurrently
+//! running forge command could each read the file, add their entry, and drop
+//! the other's. Every read-modify-write of those files holds an exclusive lock
+//! on a sidecar `<file>.lock`, the same file the CLI locks in
+//! `core.file_lock`. The sidecar, not the data file, is locked so atomic
+//! replacement of the data file by rename keeps working. Locks are taken one
+//! file at a time and never nested, so the two processes cannot deadlock.
+
+use crate::models::ForgeStudioError;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const LOCK_SUFFIX: &str = "lock";
+/// Forge commands hold a lock only for one file rewrite, so this is generous.
+const LOCK_WAIT: Duration = Duration::from_secs(5);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Runs `update` while holding the lock for `path`; released when it returns.
+pub(crate) fn with_file_lock<T>(
+    path: &Path,
+    update: impl FnOnce() -> Result<T, ForgeStudioError>,
+) -> Result<T, ForgeStudioError> {
+    let _lock = lock_with_wait(path, LOCK_WAIT)?;
+    update()
+}
+
+fn lock_path(path: &Path) -> PathBuf {
+    let mut lock_name = path.file_name().unwrap_or_default().to_os_string();
+    lock_name.push(format!(".{LOCK_SUFFIX}"));
+    path.with_file_name(lock_name)
+}
+
+/// Opens and locks the sidecar of `path`, retrying until `wait` has passed.
+fn lock_with_wait(path: &Path, wait: Duration) -> Result<File, ForgeStudioError> {
+    let lock_path = lock_path(path);
+    if let Some(parent) = lock_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let lock_file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to open lock file {}: {error}",
+                lock_path.display()
+            ))
+        })?;
+    let deadline = Instant::now() + wait;
+    loop {
+        match lock_file.try_lock() {
+            Ok(()) => return Ok(lock_file),
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                thread::sleep(LOCK_RETRY_INTERVAL);
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err(ForgeStudioError::conflict(format!(
+                    "{} is in use by another process, likely a running forge command. Retry once it finishes.",
+                    path.display()
+                )));
+            }
+            Err(TryLockError::Error(error)) => {
+                return Err(ForgeStudioError::io(format!(
+                    "Failed to lock {}: {error}",
+                    lock_path.display()
+                )));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lock_with_wait, with_file_lock};
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn held_lock_blocks_other_writers_until_released() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-file-locks-{}", std::process::id()));
+        let index_path = data_root.join("runs/index.json");
+        let held = lock_with_wait(&index_path, Duration::ZERO).expect("first lock should succeed");
+        assert!(data_root.join("runs/index.json.lock").is_file());
+        let error = lock_with_wait(&index_path, Duration::from_millis(120))
+            .expect_err("second lock should time out");
+        assert_eq!(error.code(), "conflict");
+        assert!(error.to_string().contains("is in use by another process"));
+        drop(held);
+        assert_eq!(with_file_lock(&index_path, || Ok(7)), Ok(7));
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
@@ -0,0 +1,158 @@
+//! Per-record explanation of `forge filter` metadata rules.
+//!
+//! Mirrors the rule order of the CLI's metadata filter so users can see which
+//! constraint drops a specific record before running the filter for real.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::{parse_record_sample, read_records, resolve_version};
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{
+    FilterExplanation, FilterRuleOutcome, ForgeStudioError, MetadataFilterConfig, RecordSample,
+};
+use serde_json::Value;
+use tauri::State;
+
+#[tauri::command]
+pub fn explain_filter_for_record(
+    data_root: String,
+    dataset_name: String,
+    version_id: Option<String>,
+    record_id: String,
+    filter_config: MetadataFilterConfig,
+    settings: State<'_, SettingsStore>,
+) -> Result<FilterExplanation, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let selected_version = resolve_version(&data_root, &dataset_name, version_id)?;
+    let records = read_records(&data_root, &dataset_name, &selected_version)?;
+    let record = records
+        .iter()
+        .find(|record| record.get("record_id").and_then(Value::as_str) == Some(record_id.as_str()))
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Record '{record_id}' was not found in {dataset_name}@{selected_version}"
+            ))
+        })?;
+    let sample = parse_record_sample(record)?;
+    let rule_outcomes = evaluate_filter_rules(&sample, &filter_config);
+    let kept = rule_outcomes.iter().all(|outcome| outcome.passed);
+    Ok(FilterExplanation {
+        record_id,
+        version_id: selected_version,
+        kept,
+        rule_outcomes,
+    })
+}
+
+fn evaluate_filter_rules(
+    sample: &RecordSample,
+    filter_config: &MetadataFilterConfig,
+) -> Vec<FilterRuleOutcome> {
+    let mut outcomes = Vec::new();
+    if let Some(language) = non_empty(&filter_config.language) {
+        outcomes.push(language_rule_outcome(sample, language));
+    }
+    if let Some(min_quality) = filter_config.min_quality {
+        outcomes.push(quality_rule_outcome(sample, min_quality));
+    }
+    if let Some(source_prefix) = non_empty(&filter_config.source_prefix) {
+        outcomes.push(source_prefix_rule_outcome(sample, source_prefix));
+    }
+    outcomes
+}
+
+fn language_rule_outcome(sample: &RecordSample, language: &str) -> FilterRuleOutcome {
+    let passed = sample.language == language;
+    FilterRuleOutcome {
+        rule: "language".to_string(),
+        configured_value: language.to_string(),
+        record_value: sample.language.clone(),
+        passed,
+        reason: if passed {
+            "Record language matches the requested language.".to_string()
+        } else {
+            format!("Record language '{}' is not '{language}'.", sample.language)
+        },
+    }
+}
+
+fn quality_rule_outcome(sample: &RecordSample, min_quality: f64) -> FilterRuleOutcome {
+    let passed = sample.quality_score >= min_quality;
+    FilterRuleOutcome {
+        rule: "min_quality".to_string(),
+        configured_value: min_quality.to_string(),
+        record_value: sample.quality_score.to_string(),
+        passed,
+        reason: if passed {
+            "Quality score meets the minimum threshold.".to_string()
+        } else {
+            format!(
+                "Quality score {} is below the minimum {min_quality}.",
+                sample.quality_score
+            )
+        },
+    }
+}
+
+fn source_prefix_rule_outcome(sample: &RecordSample, source_prefix: &str) -> FilterRuleOutcome {
+    let passed = sample.source_uri.starts_with(source_prefix);
+    FilterRuleOutcome {
+        rule: "source_prefix".to_string(),
+        configured_value: source_prefix.to_string(),
+        record_value: sample.source_uri.clone(),
+        passed,
+        reason: if passed {
+            "Source URI starts with the requested prefix.".to_string()
+        } else {
+            format!("Source URI does not start with '{source_prefix}'.")
+        },
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evaluate_filter_rules;
+    use crate::models::{MetadataFilterConfig, RecordSample};
+
+    fn sample_record() -> RecordSample {
+        RecordSample {
+            record_id: "abc".to_string(),
+            source_uri: "s3://corpus/news/1.txt".to_string(),
+            language: "de".to_string(),
+            quality_score: 0.2,
+            text: "hallo welt".to_string(),
+        }
+    }
+
+    #[test]
+    fn evaluate_filter_rules_reports_each_failed_rule() {
+        let filter_config = MetadataFilterConfig {
+            language: Some("en".to_string()),
+            min_quality: Some(0.35),
+            source_prefix: Some("s3://corpus/news".to_string()),
+        };
+        let outcomes = evaluate_filter_rules(&sample_record(), &filter_config);
+        let failed: Vec<&str> = outcomes
+            .iter()
+            .filter(|outcome| !outcome.passed)
+            .map(|outcome| outcome.rule.as_str())
+            .collect();
+        assert_eq!(failed, vec!["language", "min_quality"]);
+    }
+
+    #[test]
+    fn evaluate_filter_rules_skips_blank_constraints() {
+        let filter_config = MetadataFilterConfig {
+            language: Some("  ".to_string()),
+            min_quality: None,
+            source_prefix: None,
+        };
+        assert!(evaluate_filter_rules(&sample_record(), &filter_config).is_empty());
+    }
+}
@@ -1,38 +1,104 @@
 //! Forge command execution helpers for Studio.
 
+use crate::commands::app_settings::SettingsStore;
 use crate::commands::forge_task_store::CommandTaskStore;
-use crate::models::{CommandTaskStart, CommandTaskStatus};
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::run_store_io::{resolve_data_root_text, resolve_workspace_path};
+use crate::commands::train_task_log::spawn_train_log_recorder;
+use crate::models::{CommandTaskStart, CommandTaskStatus, ForgeStudioError};
 use tauri::State;
 
-const ALLOWED_COMMANDS: [&str; 6] = ["ingest", "filter", "train", "export-training", "versions", "chat"];
+const ALLOWED_COMMANDS: [&str; 8] = [
+    "ingest",
+    "filter",
+    "train",
+    "export-training",
+    "export-model",
+    "eval",
+    "versions",
+    "chat",
+];
+pub(crate) const RESUME_CHECKPOINT_FLAG: &str = "--resume-checkpoint-path";
+pub(crate) const OUTPUT_DIR_FLAG: &str = "--output-dir";
 
 #[tauri::command]
 pub fn start_forge_command(
     data_root: String,
     args: Vec<String>,
     task_store: State<'_, CommandTaskStore>,
-) -> Result<CommandTaskStart, String> {
+    settings: State<'_, SettingsStore>,
+) -> Result<CommandTaskStart, ForgeStudioError> {
+    start_forge_task(&data_root, args, &task_store, &settings.workspace_roots())
+}
+
+/// `start_forge_command` without Tauri state, for the RPC server.
+pub(crate) fn start_forge_task(
+    data_root: &str,
+    args: Vec<String>,
+    task_store: &CommandTaskStore,
+    roots: &WorkspaceRoots,
+) -> Result<CommandTaskStart, ForgeStudioError> {
+    let data_root = resolve_data_root_text(data_root, roots)?;
     validate_args(&args)?;
-    Ok(task_store.start_task(data_root, args))
+    let task = task_store.start_task(data_root, args.clone());
+    spawn_train_log_recorder(task_store.clone(), task.task_id.clone(), &args);
+    Ok(task)
 }
 
 #[tauri::command]
 pub fn get_forge_command_status(
     task_id: String,
     task_store: State<'_, CommandTaskStore>,
-) -> Result<CommandTaskStatus, String> {
+) -> Result<CommandTaskStatus, ForgeStudioError> {
     task_store.get_task_status(&task_id)
 }
 
-fn validate_args(args: &[String]) -> Result<(), String> {
+pub(crate) fn validate_args(args: &[String]) -> Result<(), ForgeStudioError> {
     if args.is_empty() {
-        return Err("Forge args must include a command".to_string());
+        return Err(ForgeStudioError::invalid_input(
+            "Forge args must include a command",
+        ));
     }
     let command = args[0].as_str();
-    if ALLOWED_COMMANDS.contains(&command) {
+    if !ALLOWED_COMMANDS.contains(&command) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Unsupported command '{command}' for Studio execution"
+        )));
+    }
+    validate_resume_args(args)
+}
+
+/// Value following `flag`, e.g. the path after `--output-dir`.
+pub(crate) fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let position = args.iter().position(|arg| arg == flag)?;
+    args.get(position + 1).map(String::as_str)
+}
+
+/// Resume is only meaningful for `train` and must point at an existing checkpoint file.
+fn validate_resume_args(args: &[String]) -> Result<(), ForgeStudioError> {
+    let Some(flag_index) = args.iter().position(|arg| arg == RESUME_CHECKPOINT_FLAG) else {
+        return Ok(());
+    };
+    if args[0] != "train" {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "{RESUME_CHECKPOINT_FLAG} is only supported for 'train', got '{}'",
+            args[0]
+        )));
+    }
+    let checkpoint_path = args
+        .get(flag_index + 1)
+        .filter(|value| !value.starts_with("--"))
+        .ok_or_else(|| {
+            ForgeStudioError::invalid_input(format!(
+                "{RESUME_CHECKPOINT_FLAG} requires a checkpoint path value"
+            ))
+        })?;
+    if resolve_workspace_path(checkpoint_path).is_file() {
         Ok(())
     } else {
-        Err(format!("Unsupported command '{command}' for Studio execution"))
+        Err(ForgeStudioError::not_found(format!(
+            "Resume checkpoint {checkpoint_path} does not exist. Pick a checkpoint from the run's checkpoints directory."
+        )))
     }
 }
 
@@ -42,7 +108,11 @@ mod tests {
 
     #[test]
     fn validate_args_accepts_supported_command() {
-        let args = vec!["train".to_string(), "--dataset".to_string(), "demo".to_string()];
+        let args = vec![
+            "train".to_string(),
+            "--dataset".to_string(),
+            "demo".to_string(),
+        ];
         assert!(validate_args(&args).is_ok());
     }
 
@@ -52,6 +122,22 @@ mod tests {
         assert!(validate_args(&args).is_err());
     }
 
+    #[test]
+    fn validate_args_rejects_resume_without_checkpoint_value() {
+        let args = vec!["train".to_string(), "--resume-checkpoint-path".to_string()];
+        assert!(validate_args(&args).is_err());
+    }
+
+    #[test]
+    fn validate_args_rejects_resume_for_non_train_commands() {
+        let args = vec![
+            "filter".to_string(),
+            "--resume-checkpoint-path".to_string(),
+            "checkpoints/epoch-0001.pt".to_string(),
+        ];
+        assert!(validate_args(&args).is_err());
+    }
+
     #[test]
     fn validate_args_rejects_unsupported_command() {
         let args = vec!["shell".to_string()];
@@ -0,0 +1,47 @@
+//! How Studio starts the forge CLI.
+//!
+//! Every forge subprocess goes through `forge_command`, so the
+//! `forge_binary` setting picks the CLI for tasks, chat, probes, and checks
+//! alike, and each one runs from the workspace root like a terminal user's.
+//! Pipes not streamed by the caller are drained on their own threads, so a
+//! child blocked writing a full pipe cannot deadlock the reader.
+
+use crate::commands::run_store_io::workspace_root_dir;
+use std::io::{self, Read};
+use std::process::Command;
+use std::thread::{self, JoinHandle};
+
+/// `forge_binary`, the configured forge CLI, ready for arguments.
+pub(crate) fn forge_command(forge_binary: &str) -> Command {
+    let mut command = Command::new(forge_binary);
+    command.current_dir(workspace_root_dir());
+    command
+}
+
+/// Reads `pipe` to the end on a new thread; a missing pipe reads as empty.
+pub(crate) fn read_pipe_in_background<R: Read + Send + 'static>(
+    pipe: Option<R>,
+) -> JoinHandle<io::Result<String>> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_string(&mut text)?;
+        }
+        Ok(text)
+    })
+}
+
+/// Waits for a `read_pipe_in_background` reader, logging a failed read.
+pub(crate) fn join_pipe_reader(reader: JoinHandle<io::Result<String>>, pipe_name: &str) -> String {
+    match reader.join() {
+        Ok(Ok(text)) => text,
+        Ok(Err(error)) => {
+            tracing::warn!("Could not read {pipe_name}: {error}");
+            String::new()
+        }
+        Err(_) => {
+            tracing::warn!("The {pipe_name} reader thread panicked.");
+            String::new()
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Duration estimates for forge commands.
+//!
+//! Each finished task folds its elapsed time into an exponential moving
+//! average for its command, weighted toward history so one unusually fast
+//! or slow run does not swing the estimate. Commands that have not finished
+//! yet in this session use a per-command default.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub(crate) const MIN_ESTIMATE_SECONDS: u64 = 5;
+/// Weight of the newest observation in the running average.
+const OBSERVATION_WEIGHT: f64 = 0.3;
+
+#[derive(Default)]
+pub(crate) struct DurationEstimates {
+    average_seconds: Mutex<HashMap<String, f64>>,
+}
+
+impl DurationEstimates {
+    /// Running average duration of `command_name`, or a per-command default.
+    pub(crate) fn estimate(&self, command_name: &str) -> u64 {
+        if let Ok(estimates) = self.average_seconds.lock() {
+            if let Some(average_seconds) = estimates.get(command_name) {
+                return average_seconds.round().max(MIN_ESTIMATE_SECONDS as f64) as u64;
+            }
+        }
+        default_estimate_seconds(command_name)
+    }
+
+    pub(crate) fn record(&self, command_name: &str, observed_seconds: f64) {
+        if let Ok(mut estimates) = self.average_seconds.lock() {
+            let next_average = match estimates.get(command_name).copied() {
+                Some(current_average) => {
+                    current_average * (1.0 - OBSERVATION_WEIGHT)
+                        + observed_seconds * OBSERVATION_WEIGHT
+                }
+                None => observed_seconds,
+            };
+            estimates.insert(command_name.to_string(), next_average);
+        }
+    }
+}
+
+fn default_estimate_seconds(command_name: &str) -> u64 {
+    match command_name {
+        "ingest" => 60,
+        "filter" => 30,
+        "train" => 240,
+        "export-training" => 60,
+        "versions" => 8,
+        "chat" => 20,
+        _ => 30,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_estimate_seconds, DurationEstimates};
+
+    #[test]
+    fn default_estimate_returns_expected_values() {
+        assert_eq!(default_estimate_seconds("train"), 240);
+        assert_eq!(default_estimate_seconds("versions"), 8);
+        assert_eq!(default_estimate_seconds("chat"), 20);
+        assert_eq!(default_estimate_seconds("unknown"), 30);
+    }
+
+    #[test]
+    fn recorded_durations_replace_the_default() {
+        let estimates = DurationEstimates::default();
+        estimates.record("train", 100.0);
+        estimates.record("train", 200.0);
+        assert_eq!(estimates.estimate("train"), 130);
+    }
+}
@@ -0,0 +1,80 @@
+//! What a finished forge task tells the rest of Studio.
+//!
+//! Every finish is logged and published on the event bus. Successful
+//! commands that add a version also publish a dataset change, and a desktop
+//! notification is raised when the `notifications` settings ask for one.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::event_bus::EventBus;
+use crate::commands::forge_commands::flag_value;
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::os_notification::send_os_notification;
+use crate::models::{BackendEvent, CommandTaskStatus};
+
+/// Commands whose success adds a version to their `--dataset`.
+const DATASET_WRITING_COMMANDS: [&str; 2] = ["filter", "ingest"];
+
+pub(crate) fn announce_task_finished(
+    event_bus: &EventBus,
+    settings: &SettingsStore,
+    status: &CommandTaskStatus,
+) {
+    let task_id = status.task_id.as_str();
+    let command_name = status.command.as_str();
+    let completed = status.status == task_status_name(TaskLifecycleStatus::Completed);
+    if completed {
+        tracing::info!(task_id, command = command_name, "Forge task finished");
+    } else {
+        tracing::warn!(
+            task_id,
+            command = command_name,
+            exit_code = status.exit_code,
+            stderr = last_stderr_line(status),
+            "Forge task failed"
+        );
+    }
+    notify_finished(settings, status, completed);
+    event_bus.publish(BackendEvent::TaskFinished {
+        task_id: task_id.to_string(),
+        command: command_name.to_string(),
+        status: status.status.clone(),
+        exit_code: status.exit_code,
+    });
+    let dataset_name = flag_value(&status.args, "--dataset")
+        .filter(|_| completed && DATASET_WRITING_COMMANDS.contains(&command_name));
+    if let Some(dataset_name) = dataset_name {
+        event_bus.publish(BackendEvent::DatasetChanged {
+            dataset_name: dataset_name.to_string(),
+            command: command_name.to_string(),
+            task_id: task_id.to_string(),
+        });
+    }
+}
+
+/// Raises a desktop notification when the settings ask for this outcome.
+fn notify_finished(settings: &SettingsStore, status: &CommandTaskStatus, completed: bool) {
+    let notifications = settings.current().notifications;
+    let wanted = if completed {
+        notifications.task_completed
+    } else {
+        notifications.task_failed
+    };
+    if !wanted {
+        return;
+    }
+    let title = format!("Forge {} {}", status.command, status.status);
+    let body = match last_stderr_line(status) {
+        Some(error) if !completed => format!("{}: {}", status.task_id, error.trim()),
+        _ => status.task_id.clone(),
+    };
+    send_os_notification(&title, &body);
+}
+
+/// The last stderr line is usually the forge error itself.
+fn last_stderr_line(status: &CommandTaskStatus) -> Option<&str> {
+    status
+        .stderr
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+}
@@ -0,0 +1,69 @@
+//! Process slots that cap how many forge commands run at once.
+//!
+//! The limit is read from `max_concurrent_tasks` on every check, so raising
+//! it in settings lets queued tasks start without waiting for a running one
+//! to finish.
+
+use crate::commands::app_settings::SettingsStore;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+/// How often a queued task rechecks the limit, which settings may raise.
+const SLOT_RECHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+pub(crate) struct TaskSlots {
+    /// Forge processes currently running.
+    running_processes: Mutex<usize>,
+    slot_freed: Condvar,
+}
+
+/// A claimed process slot, given back when the task's process has exited.
+pub(crate) struct TaskSlot {
+    slots: Arc<TaskSlots>,
+}
+
+impl Drop for TaskSlot {
+    fn drop(&mut self) {
+        if let Ok(mut running) = self.slots.running_processes.lock() {
+            *running = running.saturating_sub(1);
+        }
+        self.slots.slot_freed.notify_one();
+    }
+}
+
+/// Blocks until fewer than `max_concurrent_tasks` forge processes run.
+/// `on_dequeued` runs if the task had to wait for its slot.
+pub(crate) fn acquire_task_slot(
+    slots: &Arc<TaskSlots>,
+    settings: &SettingsStore,
+    task_id: &str,
+    on_dequeued: impl FnOnce(),
+) -> TaskSlot {
+    let slot = TaskSlot {
+        slots: Arc::clone(slots),
+    };
+    let Ok(mut running) = slots.running_processes.lock() else {
+        return slot;
+    };
+    let mut queued = false;
+    while *running >= settings.current().max_concurrent_tasks {
+        if !queued {
+            tracing::info!(task_id, "Queued forge task until a running task finishes");
+            queued = true;
+        }
+        running = match slots
+            .slot_freed
+            .wait_timeout(running, SLOT_RECHECK_INTERVAL)
+        {
+            Ok((running, _)) => running,
+            Err(_) => return slot,
+        };
+    }
+    *running += 1;
+    drop(running);
+    if queued {
+        on_dequeued();
+    }
+    slot
+}
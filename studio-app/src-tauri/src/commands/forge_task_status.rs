@@ -0,0 +1,114 @@
+//! Forge task records and the status view the frontend polls.
+//!
+//! Progress for a running task is its elapsed time over the command's
+//! estimated duration, capped below 100 until the process exits; a train
+//! task that logs step timing gets a step-based ETA instead.
+
+use crate::commands::forge_task_estimates::MIN_ESTIMATE_SECONDS;
+use crate::commands::message_catalog::message;
+use crate::commands::training_eta::{apply_step_timing_eta, COMMAND_AVERAGE_ETA_SOURCE};
+use crate::models::CommandTaskStatus;
+use std::collections::HashMap;
+use std::time::Instant;
+
+const MAX_RUNNING_PROGRESS: f64 = 99.0;
+
+#[derive(Clone)]
+pub(crate) struct TaskRecord {
+    pub(crate) task_id: String,
+    pub(crate) command: String,
+    pub(crate) args: Vec<String>,
+    pub(crate) status: TaskLifecycleStatus,
+    pub(crate) started_at: Instant,
+    pub(crate) estimated_total_seconds: u64,
+    pub(crate) stdout: String,
+    pub(crate) stderr: String,
+    pub(crate) exit_code: Option<i32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TaskLifecycleStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Wire name of a task status. Records that follow a task, such as batches,
+/// exports, and sweep trials, store their status with the same names.
+pub(crate) fn task_status_name(status: TaskLifecycleStatus) -> &'static str {
+    match status {
+        TaskLifecycleStatus::Running => "running",
+        TaskLifecycleStatus::Completed => "completed",
+        TaskLifecycleStatus::Failed => "failed",
+    }
+}
+
+/// Drops the oldest finished tasks once more than `max_tasks` are kept.
+pub(crate) fn prune_finished_tasks(tasks: &mut HashMap<String, TaskRecord>, max_tasks: usize) {
+    if tasks.len() <= max_tasks {
+        return;
+    }
+    let mut removable: Vec<String> = tasks
+        .iter()
+        .filter(|(_, task)| task.status != TaskLifecycleStatus::Running)
+        .map(|(task_id, _)| task_id.clone())
+        .collect();
+    removable.sort();
+    let excess = tasks.len().saturating_sub(max_tasks);
+    for task_id in removable.into_iter().take(excess) {
+        tasks.remove(&task_id);
+    }
+}
+
+pub(crate) fn task_to_status(task: TaskRecord) -> CommandTaskStatus {
+    let elapsed_seconds = task.started_at.elapsed().as_secs();
+    let status = task_status_name(task.status).to_string();
+    let remaining_seconds = if task.status == TaskLifecycleStatus::Running {
+        task.estimated_total_seconds.saturating_sub(elapsed_seconds)
+    } else {
+        0
+    };
+    let progress_percent = match task.status {
+        TaskLifecycleStatus::Running => {
+            running_progress_percent(elapsed_seconds, task.estimated_total_seconds)
+        }
+        TaskLifecycleStatus::Completed | TaskLifecycleStatus::Failed => 100.0,
+    };
+    let mut task_status = CommandTaskStatus {
+        task_id: task.task_id,
+        status_label: message(&format!("task_status.{status}"), &[]),
+        status,
+        command: task.command,
+        args: task.args,
+        exit_code: task.exit_code,
+        stdout: task.stdout,
+        stderr: task.stderr,
+        elapsed_seconds,
+        estimated_total_seconds: task.estimated_total_seconds,
+        remaining_seconds,
+        progress_percent,
+        eta_source: COMMAND_AVERAGE_ETA_SOURCE.to_string(),
+        completed_steps: None,
+        total_steps: None,
+        seconds_per_step: None,
+    };
+    apply_step_timing_eta(&mut task_status, MAX_RUNNING_PROGRESS);
+    task_status
+}
+
+fn running_progress_percent(elapsed_seconds: u64, estimated_total_seconds: u64) -> f64 {
+    let estimate = estimated_total_seconds.max(MIN_ESTIMATE_SECONDS);
+    let raw = (elapsed_seconds as f64 / estimate as f64) * 100.0;
+    raw.clamp(1.0, MAX_RUNNING_PROGRESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::running_progress_percent;
+
+    #[test]
+    fn running_progress_is_bounded_before_completion() {
+        let progress = running_progress_percent(1, 600);
+        assert!((1.0..100.0).contains(&progress));
+    }
+}
@@ -1,18 +1,30 @@
 //! Background Forge command task store and execution worker helpers.
+//!
+//! At most `max_concurrent_tasks` forge processes run at once; later tasks
+//! are reported as running but wait for a free slot before they spawn, and
+//! their elapsed time starts when they do. Finished tasks are kept for
+//! status queries up to the `finished_tasks` cache size. Status views,
+//! duration estimates, process slots, and finish notices live in the
+//! `forge_task_*` modules beside this one.
 
-use crate::models::{CommandTaskStart, CommandTaskStatus};
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::crash_reports::CrashReporter;
+use crate::commands::event_bus::EventBus;
+use crate::commands::forge_process::{forge_command, join_pipe_reader, read_pipe_in_background};
+use crate::commands::forge_task_estimates::DurationEstimates;
+use crate::commands::forge_task_notices::announce_task_finished;
+use crate::commands::forge_task_slots::{acquire_task_slot, TaskSlots};
+use crate::commands::forge_task_status::{
+    prune_finished_tasks, task_to_status, TaskLifecycleStatus, TaskRecord,
+};
+use crate::models::{BackendEvent, CommandTaskStart, CommandTaskStatus, ForgeStudioError};
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
 use std::io::Read;
-use std::process::{Command, Stdio};
+use std::process::{Child, Stdio};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-const MAX_TASKS: usize = 200;
-const MIN_ESTIMATE_SECONDS: u64 = 5;
-const MAX_RUNNING_PROGRESS: f64 = 99.0;
-
 #[derive(Clone)]
 pub struct CommandTaskStore {
     inner: Arc<CommandTaskStoreInner>,
@@ -20,43 +32,56 @@ pub struct CommandTaskStore {
 
 struct CommandTaskStoreInner {
     tasks: Mutex<HashMap<String, TaskRecord>>,
-    duration_estimates: Mutex<HashMap<String, f64>>,
+    duration_estimates: DurationEstimates,
     next_task_id: AtomicU64,
-}
-
-#[derive(Clone)]
-struct TaskRecord {
-    task_id: String,
-    command: String,
-    args: Vec<String>,
-    status: TaskLifecycleStatus,
-    started_at: Instant,
-    estimated_total_seconds: u64,
-    stdout: String,
-    stderr: String,
-    exit_code: Option<i32>,
-}
-
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum TaskLifecycleStatus {
-    Running,
-    Completed,
-    Failed,
+    event_bus: EventBus,
+    crash_reporter: CrashReporter,
+    settings: SettingsStore,
+    slots: Arc<TaskSlots>,
 }
 
 impl Default for CommandTaskStore {
     fn default() -> Self {
+        Self::new(
+            EventBus::default(),
+            CrashReporter::default(),
+            SettingsStore::default(),
+        )
+    }
+}
+
+impl CommandTaskStore {
+    /// A store that publishes task starts and finishes on `event_bus`,
+    /// reports crashed tasks to `crash_reporter`, and reads its limits and
+    /// forge binary from `settings` as each task runs.
+    pub fn new(
+        event_bus: EventBus,
+        crash_reporter: CrashReporter,
+        settings: SettingsStore,
+    ) -> Self {
         Self {
             inner: Arc::new(CommandTaskStoreInner {
                 tasks: Mutex::new(HashMap::new()),
-                duration_estimates: Mutex::new(HashMap::new()),
+                duration_estimates: DurationEstimates::default(),
                 next_task_id: AtomicU64::new(1),
+                event_bus,
+                crash_reporter,
+                settings,
+                slots: Arc::default(),
             }),
         }
     }
-}
 
-impl CommandTaskStore {
+    /// The bus this store publishes on, for subsystems driven by its tasks.
+    pub fn event_bus(&self) -> &EventBus {
+        &self.inner.event_bus
+    }
+
+    /// The settings this store runs tasks with, for subsystems driven by its tasks.
+    pub(crate) fn settings(&self) -> &SettingsStore {
+        &self.inner.settings
+    }
+
     pub fn start_task(&self, data_root: String, args: Vec<String>) -> CommandTaskStart {
         let command_name = args[0].clone();
         let task_id = self.generate_task_id();
@@ -67,6 +92,12 @@ impl CommandTaskStore {
             args.clone(),
             estimated_total_seconds,
         );
+        tracing::info!(task_id, command = command_name, ?args, "Started forge task");
+        self.inner.event_bus.publish(BackendEvent::TaskStarted {
+            task_id: task_id.clone(),
+            command: command_name.clone(),
+            args: args.clone(),
+        });
 
         let task_store = self.clone();
         let task_id_for_thread = task_id.clone();
@@ -80,25 +111,56 @@ impl CommandTaskStore {
         }
     }
 
-    pub fn get_task_status(&self, task_id: &str) -> Result<CommandTaskStatus, String> {
+    pub fn get_task_status(&self, task_id: &str) -> Result<CommandTaskStatus, ForgeStudioError> {
         let task = {
             let tasks = self
                 .inner
                 .tasks
                 .lock()
-                .map_err(|_| "Task store lock poisoned".to_string())?;
-            tasks
-                .get(task_id)
-                .cloned()
-                .ok_or_else(|| format!("Unknown task id '{task_id}'"))?
+                .map_err(|_| ForgeStudioError::internal("Task store lock poisoned"))?;
+            tasks.get(task_id).cloned().ok_or_else(|| {
+                ForgeStudioError::not_found(format!("Unknown task id '{task_id}'"))
+            })?
         };
         Ok(task_to_status(task))
     }
 
-    fn execute_task(&self, task_id: String, data_root: String, command_name: String, args: Vec<String>) {
-        let working_directory = workspace_root_dir();
-        let spawn_result = Command::new("forge")
-            .current_dir(working_directory)
+    /// True while any task for `command_name` is still running.
+    pub fn has_running_command(&self, command_name: &str) -> bool {
+        !self.running_command_args(command_name).is_empty()
+    }
+
+    /// Arguments of every running task for `command_name`.
+    pub fn running_command_args(&self, command_name: &str) -> Vec<Vec<String>> {
+        let Ok(tasks) = self.inner.tasks.lock() else {
+            return Vec::new();
+        };
+        tasks
+            .values()
+            .filter(|task| {
+                task.command == command_name && task.status == TaskLifecycleStatus::Running
+            })
+            .map(|task| task.args.clone())
+            .collect()
+    }
+
+    fn execute_task(
+        &self,
+        task_id: String,
+        data_root: String,
+        command_name: String,
+        args: Vec<String>,
+    ) {
+        // A task that waited for its slot starts its elapsed time now.
+        let settings = &self.inner.settings;
+        let _slot = acquire_task_slot(&self.inner.slots, settings, &task_id, || {
+            if let Ok(mut tasks) = self.inner.tasks.lock() {
+                if let Some(task) = tasks.get_mut(&task_id) {
+                    task.started_at = Instant::now();
+                }
+            }
+        });
+        let spawn_result = forge_command(&settings.current().forge_binary)
             .arg("--data-root")
             .arg(&data_root)
             .args(args)
@@ -108,8 +170,12 @@ impl CommandTaskStore {
 
         match spawn_result {
             Ok(mut child) => {
+                // stderr drains on its own thread so a chatty command cannot
+                // fill the pipe and stall while stdout is being streamed.
+                let stderr = read_pipe_in_background(child.stderr.take());
                 self.stream_child_output(&task_id, &mut child);
-                self.finalize_child(&task_id, &command_name, &mut child);
+                let stderr_text = join_pipe_reader(stderr, "forge task stderr");
+                self.finalize_child(&task_id, &command_name, &mut child, stderr_text);
             }
             Err(error) => {
                 self.fail_task(&task_id, &command_name, error.to_string());
@@ -117,7 +183,7 @@ impl CommandTaskStore {
         }
     }
 
-    fn stream_child_output(&self, task_id: &str, child: &mut std::process::Child) {
+    fn stream_child_output(&self, task_id: &str, child: &mut Child) {
         let Some(mut stdout) = child.stdout.take() else {
             return;
         };
@@ -133,29 +199,43 @@ impl CommandTaskStore {
                         }
                     }
                 }
-                Err(_) => break,
+                Err(error) => {
+                    tracing::warn!(task_id, "Could not read forge task stdout: {error}");
+                    break;
+                }
             }
         }
     }
 
-    fn finalize_child(&self, task_id: &str, command_name: &str, child: &mut std::process::Child) {
+    fn finalize_child(
+        &self,
+        task_id: &str,
+        command_name: &str,
+        child: &mut Child,
+        stderr_text: String,
+    ) {
         let exit_status = child.wait();
-        let stderr_text = child
-            .stderr
-            .take()
-            .map(|mut s| {
-                let mut buf = String::new();
-                let _ = s.read_to_string(&mut buf);
-                buf
-            })
-            .unwrap_or_default();
+        // No exit code means a signal ended the process.
+        let killed = exit_status
+            .as_ref()
+            .is_ok_and(|status| status.code().is_none());
+        let exit_code = exit_status.map_or(-1, |status| status.code().unwrap_or(-1));
+        self.finish_task(task_id, command_name, exit_code, stderr_text);
+        if let Ok(status) = self.get_task_status(task_id) {
+            self.inner.crash_reporter.record_task_crash(&status, killed);
+        }
+    }
 
+    fn fail_task(&self, task_id: &str, command_name: &str, error_message: String) {
+        let stderr_text = format!("Failed to run forge command: {error_message}");
+        self.finish_task(task_id, command_name, -1, stderr_text);
+    }
+
+    /// Records the exit, feeds the duration estimate, and announces the finish.
+    fn finish_task(&self, task_id: &str, command_name: &str, exit_code: i32, stderr_text: String) {
         let mut observed_elapsed_seconds = None;
         if let Ok(mut tasks) = self.inner.tasks.lock() {
             if let Some(task) = tasks.get_mut(task_id) {
-                let exit_code = exit_status
-                    .map(|s| s.code().unwrap_or(-1))
-                    .unwrap_or(-1);
                 task.exit_code = Some(exit_code);
                 task.stderr = stderr_text;
                 task.status = if exit_code == 0 {
@@ -167,22 +247,16 @@ impl CommandTaskStore {
             }
         }
         if let Some(observed_seconds) = observed_elapsed_seconds {
-            self.update_duration_estimate(command_name, observed_seconds);
+            self.inner
+                .duration_estimates
+                .record(command_name, observed_seconds);
         }
+        self.publish_finished(task_id);
     }
 
-    fn fail_task(&self, task_id: &str, command_name: &str, error_message: String) {
-        let mut observed_elapsed_seconds = None;
-        if let Ok(mut tasks) = self.inner.tasks.lock() {
-            if let Some(task) = tasks.get_mut(task_id) {
-                task.exit_code = Some(-1);
-                task.status = TaskLifecycleStatus::Failed;
-                task.stderr = format!("Failed to run forge command: {error_message}");
-                observed_elapsed_seconds = Some(task.started_at.elapsed().as_secs_f64().max(1.0));
-            }
-        }
-        if let Some(observed_seconds) = observed_elapsed_seconds {
-            self.update_duration_estimate(command_name, observed_seconds);
+    fn publish_finished(&self, task_id: &str) {
+        if let Ok(status) = self.get_task_status(task_id) {
+            announce_task_finished(&self.inner.event_bus, &self.inner.settings, &status);
         }
     }
 
@@ -213,124 +287,13 @@ impl CommandTaskStore {
                     exit_code: None,
                 },
             );
-            prune_finished_tasks(&mut tasks);
-        }
-    }
-
-    fn estimate_for_command(&self, command_name: &str) -> u64 {
-        let default_seconds = default_estimate_seconds(command_name);
-        let guard = self.inner.duration_estimates.lock();
-        if let Ok(estimates) = guard {
-            if let Some(average_seconds) = estimates.get(command_name) {
-                return average_seconds.round().max(MIN_ESTIMATE_SECONDS as f64) as u64;
-            }
-        }
-        default_seconds
-    }
-
-    fn update_duration_estimate(&self, command_name: &str, observed_seconds: f64) {
-        if let Ok(mut estimates) = self.inner.duration_estimates.lock() {
-            let next_average = if let Some(current_average) = estimates.get(command_name).copied() {
-                current_average * 0.7 + observed_seconds * 0.3
-            } else {
-                observed_seconds
-            };
-            estimates.insert(command_name.to_string(), next_average);
-        }
-    }
-}
-
-fn prune_finished_tasks(tasks: &mut HashMap<String, TaskRecord>) {
-    if tasks.len() <= MAX_TASKS {
-        return;
-    }
-    let mut removable: Vec<String> = tasks
-        .iter()
-        .filter(|(_, task)| task.status != TaskLifecycleStatus::Running)
-        .map(|(task_id, _)| task_id.clone())
-        .collect();
-    removable.sort();
-    let excess = tasks.len().saturating_sub(MAX_TASKS);
-    for task_id in removable.into_iter().take(excess) {
-        tasks.remove(&task_id);
-    }
-}
-
-fn task_to_status(task: TaskRecord) -> CommandTaskStatus {
-    let elapsed_seconds = task.started_at.elapsed().as_secs();
-    let status = task_status_name(task.status).to_string();
-    let remaining_seconds = if task.status == TaskLifecycleStatus::Running {
-        task.estimated_total_seconds.saturating_sub(elapsed_seconds)
-    } else {
-        0
-    };
-    let progress_percent = match task.status {
-        TaskLifecycleStatus::Running => {
-            running_progress_percent(elapsed_seconds, task.estimated_total_seconds)
+            let max_finished = self.inner.settings.current().cache_sizes.finished_tasks;
+            prune_finished_tasks(&mut tasks, max_finished);
         }
-        TaskLifecycleStatus::Completed | TaskLifecycleStatus::Failed => 100.0,
-    };
-    CommandTaskStatus {
-        task_id: task.task_id,
-        status,
-        command: task.command,
-        args: task.args,
-        exit_code: task.exit_code,
-        stdout: task.stdout,
-        stderr: task.stderr,
-        elapsed_seconds,
-        estimated_total_seconds: task.estimated_total_seconds,
-        remaining_seconds,
-        progress_percent,
-    }
-}
-
-fn running_progress_percent(elapsed_seconds: u64, estimated_total_seconds: u64) -> f64 {
-    let estimate = estimated_total_seconds.max(MIN_ESTIMATE_SECONDS);
-    let raw = (elapsed_seconds as f64 / estimate as f64) * 100.0;
-    raw.clamp(1.0, MAX_RUNNING_PROGRESS)
-}
-
-fn task_status_name(status: TaskLifecycleStatus) -> &'static str {
-    match status {
-        TaskLifecycleStatus::Running => "running",
-        TaskLifecycleStatus::Completed => "completed",
-        TaskLifecycleStatus::Failed => "failed",
-    }
-}
-
-fn default_estimate_seconds(command_name: &str) -> u64 {
-    match command_name {
-        "ingest" => 60,
-        "filter" => 30,
-        "train" => 240,
-        "export-training" => 60,
-        "versions" => 8,
-        "chat" => 20,
-        _ => 30,
-    }
-}
-
-fn workspace_root_dir() -> PathBuf {
-    // `CARGO_MANIFEST_DIR` points to `studio-app/src-tauri`.
-    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
-}
-
-#[cfg(test)]
-mod tests {
-    use super::{default_estimate_seconds, running_progress_percent};
-
-    #[test]
-    fn running_progress_is_bounded_before_completion() {
-        let progress = running_progress_percent(1, 600);
-        assert!(progress >= 1.0 && progress < 100.0);
     }
 
-    #[test]
-    fn default_estimate_returns_expected_values() {
-        assert_eq!(default_estimate_seconds("train"), 240);
-        assert_eq!(default_estimate_seconds("versions"), 8);
-        assert_eq!(default_estimate_seconds("chat"), 20);
-        assert_eq!(default_estimate_seconds("unknown"), 30);
+    /// Running average duration of `command_name`, or a per-command default.
+    pub(crate) fn estimate_for_command(&self, command_name: &str) -> u64 {
+        self.inner.duration_estimates.estimate(command_name)
     }
 }
@@ -0,0 +1,246 @@
+//! Checks the installed forge CLI against new releases and this Studio.
+//!
+//! `check_forge_updates` reads `forge --version`, asks the release feed for
+//! the newest release, and looks both versions up in the compatibility
+//! matrix below, which each Studio release maintains for the forge versions
+//! it was tested with. Combinations known not to work are reported before a
+//! command fails with an unfamiliar flag or file layout. An unreachable feed
+//! or unreadable version is a warning, so the check works offline.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::forge_process::forge_command;
+use crate::commands::run_store_io::workspace_root_dir;
+use crate::models::{ForgeStudioError, ForgeUpdateCheck};
+use serde_json::Value;
+use std::fs;
+use std::process::Stdio;
+use std::time::Duration;
+use tauri::State;
+
+const RELEASE_FEED_URL: &str = "https://api.github.com/repos/tomalmog/forge/releases/latest";
+const FEED_TIMEOUT: Duration = Duration::from_secs(10);
+const STUDIO_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Forge versions from `min` up to, not including, `below`.
+struct CompatibilityRule {
+    min: [u64; 3],
+    below: [u64; 3],
+    status: &'static str,
+    note: &'static str,
+}
+
+/// Forge versions this Studio release knows about; versions no rule covers
+/// are `untested`. Update it whenever a forge release changes the CLI.
+const COMPATIBILITY_MATRIX: [CompatibilityRule; 2] = [
+    CompatibilityRule {
+        min: [0, 0, 0],
+        below: [0, 1, 0],
+        status: "incompatible",
+        note: "predates the run index and lineage files Studio reads",
+    },
+    CompatibilityRule {
+        min: [0, 1, 0],
+        below: [0, 2, 0],
+        status: "compatible",
+        note: "tested with this Studio release",
+    },
+];
+
+/// The newest forge release in the feed.
+struct ForgeRelease {
+    version: String,
+    url: Option<String>,
+}
+
+#[tauri::command]
+pub async fn check_forge_updates(
+    settings: State<'_, SettingsStore>,
+) -> Result<ForgeUpdateCheck, ForgeStudioError> {
+    let forge_binary = settings.current().forge_binary;
+    run_blocking(move || {
+        let installed_version = read_forge_version(&forge_binary);
+        Ok(build_update_check(
+            installed_version,
+            fetch_latest_release(),
+        ))
+    })
+    .await
+}
+
+/// `forge --version`, else the workspace's `pyproject.toml` for forge
+/// releases older than the flag.
+pub(crate) fn read_forge_version(forge_binary: &str) -> Option<String> {
+    let output = forge_command(forge_binary)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success());
+    output
+        .and_then(|output| {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let version = stdout.trim().trim_start_matches("forge").trim();
+            parse_version(version).map(|_| version.to_string())
+        })
+        .or_else(workspace_forge_version)
+}
+
+/// `version` from the `[project]` table of the workspace's `pyproject.toml`.
+fn workspace_forge_version() -> Option<String> {
+    let text = fs::read_to_string(workspace_root_dir().join("pyproject.toml")).ok()?;
+    let mut in_project = false;
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_project = line == "[project]";
+            continue;
+        }
+        let value = line
+            .strip_prefix("version")
+            .and_then(|rest| rest.trim_start().strip_prefix('='));
+        if let Some(value) = value.filter(|_| in_project) {
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+fn fetch_latest_release() -> Result<ForgeRelease, ForgeStudioError> {
+    let feed_error = |error: String| {
+        ForgeStudioError::io(format!(
+            "Could not check for forge releases at {RELEASE_FEED_URL}: {error}."
+        ))
+    };
+    let release: Value = ureq::get(RELEASE_FEED_URL)
+        .timeout(FEED_TIMEOUT)
+        .set("User-Agent", &format!("forge-studio/{STUDIO_VERSION}"))
+        .call()
+        .map_err(|error| feed_error(error.to_string()))?
+        .into_json()
+        .map_err(|error| feed_error(error.to_string()))?;
+    let version = release
+        .get("tag_name")
+        .and_then(Value::as_str)
+        .map(|tag| tag.trim_start_matches('v').to_string())
+        .filter(|version| parse_version(version).is_some())
+        .ok_or_else(|| feed_error("the latest release has no version tag".to_string()))?;
+    Ok(ForgeRelease {
+        version,
+        url: release
+            .get("html_url")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    })
+}
+
+fn build_update_check(
+    installed_version: Option<String>,
+    latest_release: Result<ForgeRelease, ForgeStudioError>,
+) -> ForgeUpdateCheck {
+    let mut warnings = Vec::new();
+    let installed = installed_version.as_deref().and_then(parse_version);
+    let compatibility = match installed {
+        Some(installed) => {
+            let (status, note) = compatibility_for(installed);
+            if status != "compatible" {
+                let installed_version = installed_version.as_deref().unwrap_or_default();
+                warnings.push(format!(
+                    "forge {installed_version} is {status} with Studio {STUDIO_VERSION}: {note}. Install a forge version this Studio supports."
+                ));
+            }
+            status
+        }
+        None => {
+            warnings.push("Could not read the installed forge version. Check that forge_binary in settings runs `forge --version`.".to_string());
+            "unknown"
+        }
+    };
+    let latest_release = latest_release
+        .map_err(|error| warnings.push(error.to_string()))
+        .ok();
+    let latest = latest_release
+        .as_ref()
+        .and_then(|release| parse_version(&release.version));
+    let update_available =
+        matches!((installed, latest), (Some(installed), Some(latest)) if latest > installed);
+    if let Some(release) = latest_release.as_ref().filter(|_| update_available) {
+        let (status, _) = compatibility_for(latest.unwrap_or_default());
+        if status != "compatible" {
+            warnings.push(format!(
+                "forge {} is available but {status} with Studio {STUDIO_VERSION}. Update Studio before updating forge.",
+                release.version
+            ));
+        }
+    }
+    ForgeUpdateCheck {
+        studio_version: STUDIO_VERSION.to_string(),
+        installed_version,
+        latest_version: latest_release
+            .as_ref()
+            .map(|release| release.version.clone()),
+        release_url: latest_release.and_then(|release| release.url),
+        update_available,
+        compatibility: compatibility.to_string(),
+        warnings,
+    }
+}
+
+fn compatibility_for(version: [u64; 3]) -> (&'static str, &'static str) {
+    COMPATIBILITY_MATRIX
+        .iter()
+        .find(|rule| rule.min <= version && version < rule.below)
+        .map_or(
+            (
+                "untested",
+                "newer than the forge versions this Studio release was tested with",
+            ),
+            |rule| (rule.status, rule.note),
+        )
+}
+
+/// `major.minor.patch`, ignoring any pre-release or build suffix.
+fn parse_version(text: &str) -> Option<[u64; 3]> {
+    let core = text.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    let version = [parts.next()??, parts.next()??, parts.next()??];
+    parts.next().is_none().then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_update_check, parse_version, ForgeRelease};
+    use crate::models::ForgeStudioError;
+
+    fn release(version: &str) -> Result<ForgeRelease, ForgeStudioError> {
+        Ok(ForgeRelease {
+            version: version.to_string(),
+            url: None,
+        })
+    }
+
+    #[test]
+    fn update_check_flags_new_releases_and_incompatible_versions() {
+        assert_eq!(parse_version("0.1.2-rc.1"), Some([0, 1, 2]));
+        assert_eq!(parse_version("unknown"), None);
+
+        let current = build_update_check(Some("0.1.0".to_string()), release("0.1.3"));
+        assert!(current.update_available && current.warnings.is_empty());
+        assert_eq!(current.compatibility, "compatible");
+
+        let untested_update = build_update_check(Some("0.1.3".to_string()), release("0.2.0"));
+        assert!(untested_update.update_available);
+        assert!(untested_update.warnings[0].contains("Update Studio before updating forge"));
+
+        let offline = build_update_check(
+            Some("0.0.9".to_string()),
+            Err(ForgeStudioError::invalid_input("offline")),
+        );
+        assert_eq!(offline.compatibility, "incompatible");
+        assert_eq!(offline.warnings.len(), 2);
+        assert!(!offline.update_available && offline.latest_version.is_none());
+
+        let unreadable = build_update_check(None, release("0.1.0"));
+        assert_eq!(unreadable.compatibility, "unknown");
+        assert!(!unreadable.update_available);
+    }
+}
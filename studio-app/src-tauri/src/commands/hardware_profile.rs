@@ -0,0 +1,163 @@
+//! Cached hardware profile from `forge hardware-profile --json`.
+//!
+//! Detection imports torch and queries every device, which takes seconds,
+//! while the hardware itself rarely changes during a session. The profile is
+//! therefore cached per data root for a fixed TTL; `refresh_hardware_profile`
+//! bypasses the cache after drivers or devices change.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::forge_process::forge_command;
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{ForgeStudioError, HardwareProfile};
+use chrono::{SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::State;
+
+const HARDWARE_PROFILE_TTL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Clone, Default)]
+pub struct HardwareProfileCache {
+    entries: Arc<Mutex<HashMap<PathBuf, (Instant, HardwareProfile)>>>,
+}
+
+impl HardwareProfileCache {
+    fn fresh(&self, data_root: &Path) -> Option<HardwareProfile> {
+        let entries = self.entries.lock().ok()?;
+        let (loaded_at, profile) = entries.get(data_root)?;
+        (loaded_at.elapsed() < HARDWARE_PROFILE_TTL).then(|| profile.clone())
+    }
+
+    fn store(&self, data_root: PathBuf, profile: HardwareProfile) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(data_root, (Instant::now(), profile));
+        }
+    }
+}
+
+/// Returns the cached profile while it is younger than the TTL, detecting it
+/// otherwise. Detection waits on a forge subprocess, so it runs on the
+/// blocking pool.
+#[tauri::command]
+pub async fn get_hardware_profile(
+    data_root: String,
+    cache: State<'_, HardwareProfileCache>,
+    settings: State<'_, SettingsStore>,
+) -> Result<HardwareProfile, ForgeStudioError> {
+    let cache = cache.inner().clone();
+    let roots = settings.workspace_roots();
+    let forge_binary = settings.current().forge_binary;
+    run_blocking(move || {
+        let resolved_data_root = resolve_data_root_path(&data_root, &roots)?;
+        load_cached_hardware_profile(resolved_data_root, &cache, &forge_binary)
+    })
+    .await
+}
+
+#[tauri::command]
+pub fn refresh_hardware_profile(
+    data_root: String,
+    cache: State<'_, HardwareProfileCache>,
+    settings: State<'_, SettingsStore>,
+) -> Result<HardwareProfile, ForgeStudioError> {
+    load_and_cache(
+        resolve_data_root_path(&data_root, &settings.workspace_roots())?,
+        &cache,
+        &settings.current().forge_binary,
+    )
+}
+
+pub(crate) fn load_cached_hardware_profile(
+    resolved_data_root: PathBuf,
+    cache: &HardwareProfileCache,
+    forge_binary: &str,
+) -> Result<HardwareProfile, ForgeStudioError> {
+    if let Some(profile) = cache.fresh(&resolved_data_root) {
+        return Ok(profile);
+    }
+    load_and_cache(resolved_data_root, cache, forge_binary)
+}
+
+fn load_and_cache(
+    resolved_data_root: PathBuf,
+    cache: &HardwareProfileCache,
+    forge_binary: &str,
+) -> Result<HardwareProfile, ForgeStudioError> {
+    let profile = detect_hardware_profile(&resolved_data_root, forge_binary)?;
+    cache.store(resolved_data_root, profile.clone());
+    Ok(profile)
+}
+
+fn detect_hardware_profile(
+    resolved_data_root: &Path,
+    forge_binary: &str,
+) -> Result<HardwareProfile, ForgeStudioError> {
+    let output = forge_command(forge_binary)
+        .arg("--data-root")
+        .arg(resolved_data_root.as_os_str())
+        .args(["hardware-profile", "--json"])
+        .output()
+        .map_err(|error| {
+            ForgeStudioError::io(format!("Failed to run forge hardware-profile: {error}"))
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(ForgeStudioError::io(format!(
+            "forge hardware-profile failed with status {}: {}",
+            output.status.code().unwrap_or(-1),
+            stderr
+        )));
+    }
+    let mut profile = parse_hardware_profile_json(&String::from_utf8_lossy(&output.stdout))?;
+    profile.detected_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    Ok(profile)
+}
+
+fn parse_hardware_profile_json(stdout: &str) -> Result<HardwareProfile, ForgeStudioError> {
+    serde_json::from_str(stdout.trim()).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse forge hardware-profile JSON: {error}. \
+             Upgrade the forge CLI to a version that supports --json."
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_hardware_profile_json, HardwareProfileCache};
+    use std::path::{Path, PathBuf};
+
+    const CUDA_PROFILE_JSON: &str = concat!(
+        r#"{"accelerator": "cuda", "bf16_supported": true, "gpu_count": 1, "gpus": "#,
+        r#"[{"capability": "8.0", "index": 0, "name": "A100", "total_memory_gb": 40.0}], "#,
+        r#""recommended_batch_size": 32, "recommended_precision_mode": "bf16", "#,
+        r#""suggested_profile": "a100"}"#
+    );
+
+    #[test]
+    fn parse_hardware_profile_json_reads_nested_gpus() {
+        let profile = parse_hardware_profile_json(CUDA_PROFILE_JSON).expect("profile should parse");
+        assert_eq!(profile.gpu_count, 1);
+        assert_eq!(profile.gpus[0].capability.as_deref(), Some("8.0"));
+        assert!(profile.detected_at.is_empty());
+    }
+
+    #[test]
+    fn parse_hardware_profile_json_rejects_key_value_output() {
+        let error = parse_hardware_profile_json("accelerator=cpu\ngpu_count=0\n")
+            .expect_err("legacy output should be rejected");
+        assert!(error.to_string().contains("--json"));
+    }
+
+    #[test]
+    fn cache_returns_stored_profile_per_data_root() {
+        let cache = HardwareProfileCache::default();
+        let profile = parse_hardware_profile_json(CUDA_PROFILE_JSON).expect("profile should parse");
+        cache.store(PathBuf::from("/data/a"), profile);
+        assert!(cache.fresh(Path::new("/data/a")).is_some());
+        assert!(cache.fresh(Path::new("/data/b")).is_none());
+    }
+}
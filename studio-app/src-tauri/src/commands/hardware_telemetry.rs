@@ -0,0 +1,95 @@
+//! Live accelerator telemetry while training runs.
+//!
+//! One sampler thread lives for the whole app and only probes hardware
+//! while a `train` task is running, because vendor tools cost a process
+//! spawn per poll. Samples are published on the event bus and
+//! kept in a bounded in-memory history so a freshly opened monitoring panel
+//! can backfill its charts. Power readings are also persisted per training
+//! output dir for energy and cost accounting.
+
+use crate::commands::accelerator_probes::sample_accelerators;
+use crate::commands::energy_ledger::record_power_samples;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::models::{BackendEvent, ForgeStudioError, TelemetrySnapshot};
+use chrono::{SecondsFormat, Utc};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::State;
+
+const TELEMETRY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// One hour of history at the poll interval.
+const MAX_TELEMETRY_SNAPSHOTS: usize = 720;
+const TRAIN_COMMAND: &str = "train";
+
+#[derive(Clone, Default)]
+pub struct TelemetryStore {
+    snapshots: Arc<Mutex<VecDeque<TelemetrySnapshot>>>,
+}
+
+impl TelemetryStore {
+    fn push(&self, snapshot: TelemetrySnapshot) {
+        if let Ok(mut snapshots) = self.snapshots.lock() {
+            if snapshots.len() == MAX_TELEMETRY_SNAPSHOTS {
+                snapshots.pop_front();
+            }
+            snapshots.push_back(snapshot);
+        }
+    }
+}
+
+/// Returns buffered snapshots oldest first, optionally only the newest `limit`.
+#[tauri::command]
+pub fn get_hardware_telemetry_history(
+    limit: Option<usize>,
+    telemetry_store: State<'_, TelemetryStore>,
+) -> Result<Vec<TelemetrySnapshot>, ForgeStudioError> {
+    let snapshots = telemetry_store
+        .snapshots
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("Telemetry store lock poisoned"))?;
+    let skip = limit.map_or(0, |limit| snapshots.len().saturating_sub(limit));
+    Ok(snapshots.iter().skip(skip).cloned().collect())
+}
+
+pub fn spawn_telemetry_sampler(task_store: CommandTaskStore, telemetry_store: TelemetryStore) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(TELEMETRY_POLL_INTERVAL);
+        if !task_store.has_running_command(TRAIN_COMMAND) {
+            continue;
+        }
+        let devices = sample_accelerators();
+        if devices.is_empty() {
+            continue;
+        }
+        let snapshot = TelemetrySnapshot {
+            sampled_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            devices,
+        };
+        record_power_samples(&task_store, &snapshot);
+        telemetry_store.push(snapshot.clone());
+        task_store
+            .event_bus()
+            .publish(BackendEvent::HardwareTelemetry(snapshot));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TelemetryStore, MAX_TELEMETRY_SNAPSHOTS};
+    use crate::models::TelemetrySnapshot;
+
+    #[test]
+    fn telemetry_store_drops_oldest_snapshot_when_full() {
+        let store = TelemetryStore::default();
+        for index in 0..=MAX_TELEMETRY_SNAPSHOTS {
+            store.push(TelemetrySnapshot {
+                sampled_at: format!("t{index}"),
+                devices: vec![],
+            });
+        }
+        let snapshots = store.snapshots.lock().expect("lock should not be poisoned");
+        assert_eq!(snapshots.len(), MAX_TELEMETRY_SNAPSHOTS);
+        assert_eq!(snapshots[0].sampled_at, "t1");
+    }
+}
@@ -0,0 +1,93 @@
+//! Largest-triangle-three-buckets downsampling for batch loss curves.
+//!
+//! Long runs log hundreds of thousands of batch losses, more than the chart
+//! can draw or IPC can ship without stalling the UI. LTTB keeps the points
+//! that shape the curve, so loss spikes survive where striding or bucket
+//! averaging would drop or flatten them.
+
+use crate::models::TrainingBatchLoss;
+
+/// Fewest points that still keep both endpoints plus one bucket.
+pub(crate) const MIN_DOWNSAMPLE_POINTS: usize = 3;
+
+/// Indices of at most `max_points` rows to keep, always including the first and last.
+pub(crate) fn downsample_indices(rows: &[TrainingBatchLoss], max_points: usize) -> Vec<usize> {
+    if max_points < MIN_DOWNSAMPLE_POINTS || rows.len() <= max_points {
+        return (0..rows.len()).collect();
+    }
+    let bucket_size = (rows.len() - 2) as f64 / (max_points - 2) as f64;
+    let bucket_bound = |bucket: usize| ((bucket as f64 * bucket_size) as usize + 1).min(rows.len());
+    let mut kept = Vec::with_capacity(max_points);
+    kept.push(0);
+    let mut anchor = point(&rows[0]);
+    for bucket in 0..max_points - 2 {
+        let start = bucket_bound(bucket);
+        let end = bucket_bound(bucket + 1).min(rows.len() - 1);
+        let next = average(&rows[end..bucket_bound(bucket + 2)]);
+        let mut best_index = start;
+        let mut best_area = f64::NEG_INFINITY;
+        for (index, row) in rows.iter().enumerate().take(end).skip(start) {
+            let area = triangle_area(anchor, point(row), next);
+            if area > best_area {
+                best_area = area;
+                best_index = index;
+            }
+        }
+        kept.push(best_index);
+        anchor = point(&rows[best_index]);
+    }
+    kept.push(rows.len() - 1);
+    kept
+}
+
+fn point(row: &TrainingBatchLoss) -> (f64, f64) {
+    (row.global_step as f64, row.train_loss)
+}
+
+fn average(rows: &[TrainingBatchLoss]) -> (f64, f64) {
+    let count = rows.len().max(1) as f64;
+    let (x_sum, y_sum) = rows
+        .iter()
+        .map(point)
+        .fold((0.0, 0.0), |(x_sum, y_sum), (x, y)| (x_sum + x, y_sum + y));
+    (x_sum / count, y_sum / count)
+}
+
+/// Twice the triangle area; only the ordering matters.
+fn triangle_area(left: (f64, f64), middle: (f64, f64), right: (f64, f64)) -> f64 {
+    ((left.0 - right.0) * (middle.1 - left.1) - (left.0 - middle.0) * (right.1 - left.1)).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::downsample_indices;
+    use crate::models::TrainingBatchLoss;
+
+    fn row(global_step: u64, train_loss: f64) -> TrainingBatchLoss {
+        TrainingBatchLoss {
+            epoch: 1,
+            batch_index: global_step,
+            global_step,
+            train_loss,
+            learning_rate: None,
+            gradient_norm: None,
+            tokens_per_second: None,
+            gpu_memory_mb: None,
+        }
+    }
+
+    #[test]
+    fn downsample_keeps_endpoints_and_spikes() {
+        let loss = |step: u64| match step {
+            437 => 9.0,
+            _ => 2.0 - step as f64 / 1_000.0,
+        };
+        let rows: Vec<TrainingBatchLoss> = (0..1_000).map(|step| row(step, loss(step))).collect();
+        let kept = downsample_indices(&rows, 50);
+        assert_eq!(kept.len(), 50);
+        assert_eq!((kept[0], kept[49]), (0, 999));
+        assert!(kept.contains(&437));
+        assert!(kept.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(downsample_indices(&rows[..10], 50).len(), 10);
+    }
+}
@@ -0,0 +1,51 @@
+//! Exponential moving average of batch train loss for chart overlays.
+//!
+//! The window is a span: `alpha = 2 / (window + 1)`, seeded with the first
+//! loss, which matches `pandas.Series.ewm(span=window, adjust=False)` so a
+//! notebook can reproduce the curve Studio draws. Non-finite losses hold the
+//! previous average instead of poisoning every later point.
+
+use crate::models::TrainingBatchLoss;
+
+/// Smoothed train loss per row, aligned with `rows`.
+pub(crate) fn ema_train_loss(rows: &[TrainingBatchLoss], window: usize) -> Vec<f64> {
+    let alpha = 2.0 / (window as f64 + 1.0);
+    let mut average: Option<f64> = None;
+    rows.iter()
+        .map(|row| {
+            let loss = row.train_loss;
+            if loss.is_finite() {
+                average = Some(average.map_or(loss, |prior| prior + alpha * (loss - prior)));
+            }
+            average.unwrap_or(loss)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ema_train_loss;
+    use crate::models::TrainingBatchLoss;
+
+    fn row(train_loss: f64) -> TrainingBatchLoss {
+        TrainingBatchLoss {
+            epoch: 1,
+            batch_index: 0,
+            global_step: 0,
+            train_loss,
+            learning_rate: None,
+            gradient_norm: None,
+            tokens_per_second: None,
+            gpu_memory_mb: None,
+        }
+    }
+
+    #[test]
+    fn ema_uses_span_alpha_and_skips_non_finite_losses() {
+        let rows = vec![row(4.0), row(1.0), row(f64::NAN), row(1.0)];
+        let smoothed = ema_train_loss(&rows, 2);
+        assert_eq!(smoothed[..3], [4.0, 2.0, 2.0]);
+        assert!((smoothed[3] - 4.0 / 3.0).abs() < 1e-12);
+        assert_eq!(ema_train_loss(&rows[..2], 1), vec![4.0, 1.0]);
+    }
+}
@@ -0,0 +1,219 @@
+//! Live tailing of training `history.json` files.
+//!
+//! The CLI rewrites the history after every epoch. Each watch runs on its own
+//! thread, polls the file's modification time, and publishes only the epochs
+//! and batch losses appended since the previous poll as
+//! `training_history_updated` events, so charts update without re-invoking
+//! `load_training_history`. A new watch starts from an empty cursor and sends
+//! the whole file first, and a history that no longer extends what was sent,
+//! such as a run restarted or resumed from a checkpoint, is sent again whole.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::event_bus::EventBus;
+use crate::commands::path_safety::confine_path;
+use crate::models::{
+    BackendEvent, ForgeStudioError, HistoryWatchStart, TrainingHistory, TrainingHistoryUpdate,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tauri::State;
+
+const HISTORY_POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+#[derive(Clone, Default)]
+pub struct HistoryWatchStore {
+    inner: Arc<HistoryWatchStoreInner>,
+}
+
+#[derive(Default)]
+struct HistoryWatchStoreInner {
+    stop_senders: Mutex<HashMap<String, Sender<()>>>,
+    next_watch_id: AtomicU64,
+}
+
+#[derive(Default)]
+struct TailCursor {
+    last_modified: Option<SystemTime>,
+    epochs_seen: usize,
+    batch_losses_seen: usize,
+    /// Epoch number of the last row sent, to spot a rewritten history.
+    last_epoch: Option<u64>,
+}
+
+#[tauri::command]
+pub fn start_history_watch(
+    history_path: String,
+    watch_store: State<'_, HistoryWatchStore>,
+    event_bus: State<'_, EventBus>,
+    settings: State<'_, SettingsStore>,
+) -> Result<HistoryWatchStart, ForgeStudioError> {
+    let history_path = confine_path(
+        Path::new(&history_path),
+        "History file",
+        &settings.workspace_roots(),
+    )?
+    .display()
+    .to_string();
+    let watch_id = format!(
+        "history-watch-{}",
+        watch_store
+            .inner
+            .next_watch_id
+            .fetch_add(1, Ordering::Relaxed)
+            + 1
+    );
+    let (stop_sender, stop_receiver) = mpsc::channel::<()>();
+    watch_store
+        .inner
+        .stop_senders
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("History watch store lock poisoned"))?
+        .insert(watch_id.clone(), stop_sender);
+    let thread_watch_id = watch_id.clone();
+    let event_bus = event_bus.inner().clone();
+    std::thread::spawn(move || {
+        let mut cursor = TailCursor::default();
+        // The stop channel doubles as the poll timer: a timeout means "poll again",
+        // while a message or a dropped sender ends the watch.
+        while let Err(RecvTimeoutError::Timeout) = stop_receiver.recv_timeout(HISTORY_POLL_INTERVAL)
+        {
+            let Some(update) = poll_history(&thread_watch_id, &history_path, &mut cursor) else {
+                continue;
+            };
+            event_bus.publish(BackendEvent::TrainingHistoryUpdated(update));
+        }
+    });
+    Ok(HistoryWatchStart { watch_id })
+}
+
+#[tauri::command]
+pub fn stop_history_watch(
+    watch_id: String,
+    watch_store: State<'_, HistoryWatchStore>,
+) -> Result<(), ForgeStudioError> {
+    let removed = watch_store
+        .inner
+        .stop_senders
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("History watch store lock poisoned"))?
+        .remove(&watch_id);
+    match removed {
+        Some(stop_sender) => {
+            // A send error means the watch thread already exited; nothing left to stop.
+            let _ = stop_sender.send(());
+            Ok(())
+        }
+        None => Err(ForgeStudioError::not_found(format!(
+            "Unknown history watch id '{watch_id}'"
+        ))),
+    }
+}
+
+fn poll_history(
+    watch_id: &str,
+    history_path: &str,
+    cursor: &mut TailCursor,
+) -> Option<TrainingHistoryUpdate> {
+    let modified = fs::metadata(PathBuf::from(history_path))
+        .and_then(|meta| meta.modified())
+        .ok()?;
+    if cursor.last_modified == Some(modified) {
+        return None;
+    }
+    // The CLI rewrites history.json in place; a half-written file fails to parse
+    // and is simply retried on the next poll.
+    let payload = fs::read_to_string(history_path).ok()?;
+    let history: TrainingHistory = serde_json::from_str(&payload).ok()?;
+    cursor.last_modified = Some(modified);
+    Some(diff_history(watch_id, history_path, history, cursor))
+        .filter(|update| !update.new_epochs.is_empty() || !update.new_batch_losses.is_empty())
+}
+
+fn diff_history(
+    watch_id: &str,
+    history_path: &str,
+    history: TrainingHistory,
+    cursor: &mut TailCursor,
+) -> TrainingHistoryUpdate {
+    // A history that does not extend what was sent came from a restarted or
+    // resumed run; resend everything.
+    let last_sent_epoch = cursor
+        .epochs_seen
+        .checked_sub(1)
+        .and_then(|index| history.epochs.get(index))
+        .map(|row| row.epoch);
+    if last_sent_epoch != cursor.last_epoch || history.batch_losses.len() < cursor.batch_losses_seen
+    {
+        *cursor = TailCursor {
+            last_modified: cursor.last_modified,
+            ..TailCursor::default()
+        };
+    }
+    cursor.last_epoch = history.epochs.last().map(|row| row.epoch);
+    let total_epochs = history.epochs.len();
+    let total_batch_losses = history.batch_losses.len();
+    let new_epochs = history
+        .epochs
+        .into_iter()
+        .skip(cursor.epochs_seen)
+        .collect();
+    let new_batch_losses = history
+        .batch_losses
+        .into_iter()
+        .skip(cursor.batch_losses_seen)
+        .collect();
+    cursor.epochs_seen = total_epochs;
+    cursor.batch_losses_seen = total_batch_losses;
+    TrainingHistoryUpdate {
+        watch_id: watch_id.to_string(),
+        history_path: history_path.to_string(),
+        new_epochs,
+        new_batch_losses,
+        total_epochs: total_epochs as u64,
+        total_batch_losses: total_batch_losses as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_history, TailCursor};
+    use crate::models::TrainingHistory;
+
+    fn history_with_epochs(epoch_count: u64) -> TrainingHistory {
+        history_between(1, epoch_count)
+    }
+
+    fn history_between(first_epoch: u64, last_epoch: u64) -> TrainingHistory {
+        let epochs: Vec<String> = (first_epoch..=last_epoch)
+            .map(|epoch| {
+                format!(r#"{{"epoch": {epoch}, "train_loss": 1.0, "validation_loss": 1.0}}"#)
+            })
+            .collect();
+        serde_json::from_str(&format!(r#"{{"epochs": [{}]}}"#, epochs.join(",")))
+            .expect("history should parse")
+    }
+
+    #[test]
+    fn diff_history_emits_only_new_epochs() {
+        let mut cursor = TailCursor::default();
+        diff_history("w", "h", history_with_epochs(2), &mut cursor);
+        let update = diff_history("w", "h", history_with_epochs(3), &mut cursor);
+        assert_eq!(update.new_epochs.len(), 1);
+    }
+
+    #[test]
+    fn diff_history_resends_everything_after_restart() {
+        let mut cursor = TailCursor::default();
+        diff_history("w", "h", history_with_epochs(5), &mut cursor);
+        let update = diff_history("w", "h", history_with_epochs(2), &mut cursor);
+        assert_eq!(update.new_epochs.len(), 2);
+        // A run resumed at epoch 3 rewrites the file with later epochs only.
+        let update = diff_history("w", "h", history_between(3, 5), &mut cursor);
+        assert_eq!(update.new_epochs.len(), 3);
+    }
+}
@@ -0,0 +1,136 @@
+//! Cross-run leaderboard over persisted evaluation scores.
+//!
+//! Each run contributes its single best score for the metric so repeated
+//! evaluations of one run cannot crowd the table. Ranking direction is
+//! inferred from the metric name because eval rows store bare numbers.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_details::{read_optional_json, resolve_run_artifacts};
+use crate::commands::run_evaluations::load_eval_results;
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::models::{EvalResult, ForgeStudioError, Leaderboard, LeaderboardEntry};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::State;
+
+/// Metric name fragments where a smaller value is the better model.
+const LOWER_IS_BETTER_MARKERS: [&str; 4] = ["loss", "perplexity", "error", "wer"];
+
+/// Training config keys shown beside each entry to explain score differences.
+const CONFIG_SUMMARY_KEYS: [&str; 8] = [
+    "epochs",
+    "learning_rate",
+    "batch_size",
+    "hidden_dim",
+    "num_layers",
+    "attention_heads",
+    "optimizer_type",
+    "precision_mode",
+];
+
+/// `suite` filters by eval suite; harness evaluations use the dataset name as their suite.
+#[tauri::command]
+pub fn get_leaderboard(
+    data_root: String,
+    metric: String,
+    suite: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Leaderboard, ForgeStudioError> {
+    let metric = metric.trim().to_string();
+    if metric.is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Leaderboard metric is empty. Name a recorded metric, e.g. perplexity.",
+        ));
+    }
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lower_is_better = metric_lower_is_better(&metric);
+    let mut best_by_run: Vec<(f64, EvalResult)> = Vec::new();
+    for run_id in read_run_index(&resolved_data_root)? {
+        let results = load_eval_results(&resolved_data_root, &run_id)?;
+        let candidates = results
+            .into_iter()
+            .filter(|row| suite.as_deref().is_none_or(|name| row.suite == name))
+            .filter_map(|row| Some((*row.metrics.get(&metric)?, row)));
+        let best =
+            candidates.min_by(|left, right| compare_scores(left.0, right.0, lower_is_better));
+        best_by_run.extend(best);
+    }
+    best_by_run.sort_by(|left, right| compare_scores(left.0, right.0, lower_is_better));
+    let mut entries = Vec::with_capacity(best_by_run.len());
+    for (index, (score, row)) in best_by_run.into_iter().enumerate() {
+        entries.push(LeaderboardEntry {
+            rank: index as u32 + 1,
+            config_summary: load_config_summary(&resolved_data_root, &row.run_id),
+            run_id: row.run_id,
+            score,
+            eval_id: row.eval_id,
+            suite: row.suite,
+            dataset_version_id: row.dataset_version_id,
+            model_path: row.model_path,
+            recorded_at: row.recorded_at,
+        });
+    }
+    Ok(Leaderboard {
+        metric,
+        suite,
+        lower_is_better,
+        entries,
+    })
+}
+
+fn metric_lower_is_better(metric: &str) -> bool {
+    let lowered = metric.to_lowercase();
+    LOWER_IS_BETTER_MARKERS
+        .iter()
+        .any(|marker| lowered.contains(marker))
+}
+
+/// Orders better scores first.
+fn compare_scores(left: f64, right: f64, lower_is_better: bool) -> Ordering {
+    if lower_is_better {
+        left.total_cmp(&right)
+    } else {
+        right.total_cmp(&left)
+    }
+}
+
+/// Missing or unreadable configs yield an empty summary rather than hiding the run.
+fn load_config_summary(data_root: &Path, run_id: &str) -> BTreeMap<String, Value> {
+    let config = load_run_lifecycle(data_root, run_id)
+        .and_then(|lifecycle| resolve_run_artifacts(&lifecycle))
+        .and_then(|artifacts| read_optional_json(artifacts.training_config_path.as_deref()))
+        .ok()
+        .flatten();
+    let Some(config) = config.as_ref().and_then(Value::as_object) else {
+        return BTreeMap::new();
+    };
+    CONFIG_SUMMARY_KEYS
+        .iter()
+        .filter_map(|key| Some((key.to_string(), config.get(*key)?.clone())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare_scores, metric_lower_is_better};
+
+    #[test]
+    fn metric_direction_follows_metric_name() {
+        assert!(metric_lower_is_better("val_perplexity"));
+        assert!(metric_lower_is_better("Loss"));
+        assert!(!metric_lower_is_better("accuracy"));
+    }
+
+    #[test]
+    fn compare_scores_puts_better_scores_first() {
+        let mut losses = vec![2.5, 1.2, 3.0];
+        losses.sort_by(|left, right| compare_scores(*left, *right, true));
+        assert_eq!(losses, vec![1.2, 2.5, 3.0]);
+        let mut accuracies = vec![0.5, 0.9, 0.7];
+        accuracies.sort_by(|left, right| compare_scores(*left, *right, false));
+        assert_eq!(accuracies, vec![0.9, 0.7, 0.5]);
+    }
+}
@@ -0,0 +1,156 @@
+//! Lineage graph integrity audit.
+//!
+//! Cross-checks `lineage/model_lineage.json` against the runs index, run
+//! directories, and model files on disk. Findings carry a suggested repair
+//! instead of being fixed automatically because lineage is provenance: a
+//! silent rewrite could erase the only record of how a model was produced.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::lineage_store_io::{edge_field, read_lineage_graph, LineageGraphFile};
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_store_io::{resolve_data_root_path, resolve_workspace_path, run_dir};
+use crate::models::{ForgeStudioError, LineageAuditIssue, LineageAuditReport};
+use serde_json::Value;
+use std::collections::HashSet;
+use tauri::State;
+
+/// Existence probes injected so the audit rules are testable without fixture files.
+struct AuditProbes<'a> {
+    indexed_run_ids: &'a [String],
+    lifecycle_exists: &'a dyn Fn(&str) -> bool,
+    model_exists: &'a dyn Fn(&str) -> bool,
+}
+
+#[tauri::command]
+pub fn audit_lineage(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<LineageAuditReport, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let graph = read_lineage_graph(&resolved_data_root)?;
+    let indexed_run_ids = read_run_index(&resolved_data_root)?;
+    let lifecycle_exists = |run_id: &str| {
+        run_dir(&resolved_data_root, run_id)
+            .join("lifecycle.json")
+            .exists()
+    };
+    let model_exists = |model_path: &str| resolve_workspace_path(model_path).exists();
+    let issues = audit_graph(
+        &graph,
+        &AuditProbes {
+            indexed_run_ids: &indexed_run_ids,
+            lifecycle_exists: &lifecycle_exists,
+            model_exists: &model_exists,
+        },
+    );
+    Ok(LineageAuditReport {
+        checked_runs: graph.runs.len() as u64,
+        checked_edges: graph.edges.len() as u64,
+        healthy: issues.is_empty(),
+        issues,
+    })
+}
+
+fn audit_graph(graph: &LineageGraphFile, probes: &AuditProbes<'_>) -> Vec<LineageAuditIssue> {
+    let mut issues = dangling_edge_issues(graph);
+    for (run_id, entry) in &graph.runs {
+        if !(probes.lifecycle_exists)(run_id) {
+            issues.push(issue(
+                "run_without_lifecycle",
+                run_id,
+                "Lineage lists this run but runs/<run_id>/lifecycle.json is missing.",
+                "Restore the run directory from backup, or archive the run to detach it from lineage.",
+            ));
+        }
+        let model_path = entry.get("model_path").and_then(Value::as_str);
+        if let Some(model_path) = model_path.filter(|path| !(probes.model_exists)(path)) {
+            issues.push(issue(
+                "missing_model_file",
+                run_id,
+                &format!("Model file {model_path} no longer exists on disk."),
+                "Restore the model file, or retrain the run to regenerate it.",
+            ));
+        }
+    }
+    for run_id in probes.indexed_run_ids {
+        if !graph.runs.contains_key(run_id) {
+            issues.push(issue(
+                "run_missing_from_graph",
+                run_id,
+                "Run is in runs/index.json but has no lineage entry.",
+                "Copy the run fields from runs/<run_id>/lifecycle.json into the lineage runs map.",
+            ));
+        }
+    }
+    issues
+}
+
+/// Flags edges whose `run:` endpoint has no entry in the graph's runs map.
+fn dangling_edge_issues(graph: &LineageGraphFile) -> Vec<LineageAuditIssue> {
+    let mut reported: HashSet<String> = HashSet::new();
+    let mut issues = Vec::new();
+    for edge in &graph.edges {
+        let endpoints = [edge_field(edge, "from"), edge_field(edge, "to")];
+        for endpoint in endpoints.into_iter().flatten() {
+            let Some(run_id) = endpoint.strip_prefix("run:") else {
+                continue;
+            };
+            if graph.runs.contains_key(run_id) || !reported.insert(run_id.to_string()) {
+                continue;
+            }
+            issues.push(issue(
+                "edge_to_missing_run",
+                run_id,
+                &format!("Edge endpoint '{endpoint}' references a run absent from lineage."),
+                "Remove the dangling edges from lineage/model_lineage.json.",
+            ));
+        }
+    }
+    issues
+}
+
+fn issue(kind: &str, subject: &str, detail: &str, suggested_repair: &str) -> LineageAuditIssue {
+    LineageAuditIssue {
+        kind: kind.to_string(),
+        subject: subject.to_string(),
+        detail: detail.to_string(),
+        suggested_repair: suggested_repair.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{audit_graph, AuditProbes};
+    use crate::commands::lineage_store_io::LineageGraphFile;
+    use serde_json::json;
+
+    #[test]
+    fn audit_graph_reports_each_issue_kind() {
+        let runs = json!({"run-a": {"model_path": "out/a/model.pt"}});
+        let graph = LineageGraphFile {
+            runs: runs.as_object().cloned().unwrap_or_default(),
+            edges: vec![json!({
+                "from": "model:out/a/model.pt", "to": "run:ghost", "type": "initialized_from"
+            })],
+        };
+        let indexed_run_ids = vec!["run-a".to_string(), "run-b".to_string()];
+        let issues = audit_graph(
+            &graph,
+            &AuditProbes {
+                indexed_run_ids: &indexed_run_ids,
+                lifecycle_exists: &|_| false,
+                model_exists: &|_| false,
+            },
+        );
+        let kinds: Vec<&str> = issues.iter().map(|issue| issue.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "edge_to_missing_run",
+                "run_without_lifecycle",
+                "missing_model_file",
+                "run_missing_from_graph"
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Dataset version nodes for the bipartite lineage view.
+//!
+//! The CLI records training inputs as `dataset -> run` `trained_on` edges.
+//! The summary re-expresses every run/dataset relation as a `run -> dataset`
+//! edge typed `consumed` or `produced`, and adds a node per dataset version so
+//! provenance is visible next to the model-to-model fine-tuning chain. Runs
+//! from graphs written before those edges existed still get a `consumed` edge
+//! from their recorded dataset version.
+
+use crate::commands::dataset_store_io::read_catalog;
+use crate::commands::lineage_store_io::{dataset_node_id, run_node_id};
+use crate::models::{LineageDatasetNode, LineageEdge, LineageGraphSummary};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+pub(crate) const CONSUMED_EDGE_TYPE: &str = "consumed";
+const PRODUCED_EDGE_TYPE: &str = "produced";
+const TRAINED_ON_EDGE_TYPE: &str = "trained_on";
+const RUN_NODE_PREFIX: &str = "run:";
+const DATASET_NODE_PREFIX: &str = "dataset:";
+
+/// Catalog version metadata keyed by version id.
+type CatalogVersions = HashMap<String, (Option<String>, Option<u64>)>;
+
+/// Replaces raw run/dataset edges with typed `run -> dataset` edges and fills `datasets`.
+pub(crate) fn attach_dataset_nodes(data_root: &Path, summary: &mut LineageGraphSummary) {
+    let relations = take_run_dataset_relations(summary);
+    let mut datasets: BTreeMap<String, LineageDatasetNode> = BTreeMap::new();
+    let other_dataset_nodes: Vec<String> = summary
+        .edges
+        .iter()
+        .flat_map(|edge| [&edge.from, &edge.to])
+        .filter(|node| node.starts_with(DATASET_NODE_PREFIX))
+        .cloned()
+        .collect();
+    for node_id in other_dataset_nodes {
+        dataset_entry(&mut datasets, &node_id);
+    }
+    for (run_id, dataset_node, edge_type) in relations {
+        if let Some(dataset) = dataset_entry(&mut datasets, &dataset_node) {
+            match edge_type {
+                PRODUCED_EDGE_TYPE => dataset.produced_by_run_ids.push(run_id.clone()),
+                _ => dataset.consumed_by_run_ids.push(run_id.clone()),
+            }
+        }
+        summary.edges.push(LineageEdge {
+            from: run_node_id(&run_id),
+            to: dataset_node,
+            edge_type: edge_type.to_string(),
+        });
+    }
+    fill_catalog_metadata(data_root, &mut datasets);
+    summary.datasets = datasets.into_values().collect();
+    summary.dataset_count = summary.datasets.len() as u64;
+    summary.edge_count = summary.edges.len() as u64;
+}
+
+/// Removes run/dataset edges from the summary, plus one `consumed` relation per run.
+fn take_run_dataset_relations(
+    summary: &mut LineageGraphSummary,
+) -> BTreeSet<(String, String, &'static str)> {
+    let mut relations = BTreeSet::new();
+    summary.edges.retain(|edge| match relation_of(edge) {
+        Some((run_id, dataset_node, edge_type)) => {
+            relations.insert((run_id.to_string(), dataset_node.to_string(), edge_type));
+            false
+        }
+        None => true,
+    });
+    for run in &summary.runs {
+        let dataset_node = dataset_node_id(&run.dataset_name, &run.dataset_version_id);
+        relations.insert((run.run_id.clone(), dataset_node, CONSUMED_EDGE_TYPE));
+    }
+    relations
+}
+
+/// `(run_id, dataset_node, edge_type)` when the edge links a run and a dataset version.
+fn relation_of(edge: &LineageEdge) -> Option<(&str, &str, &'static str)> {
+    if edge.from.starts_with(DATASET_NODE_PREFIX) && edge.edge_type == TRAINED_ON_EDGE_TYPE {
+        let run_id = edge.to.strip_prefix(RUN_NODE_PREFIX)?;
+        return Some((run_id, &edge.from, CONSUMED_EDGE_TYPE));
+    }
+    let run_id = edge.from.strip_prefix(RUN_NODE_PREFIX)?;
+    if !edge.to.starts_with(DATASET_NODE_PREFIX) {
+        return None;
+    }
+    let edge_type = match edge.edge_type.as_str() {
+        CONSUMED_EDGE_TYPE => CONSUMED_EDGE_TYPE,
+        _ => PRODUCED_EDGE_TYPE,
+    };
+    Some((run_id, &edge.to, edge_type))
+}
+
+fn dataset_entry<'a>(
+    datasets: &'a mut BTreeMap<String, LineageDatasetNode>,
+    node_id: &str,
+) -> Option<&'a mut LineageDatasetNode> {
+    let (dataset_name, version_id) = node_id.strip_prefix(DATASET_NODE_PREFIX)?.split_once(':')?;
+    Some(
+        datasets
+            .entry(node_id.to_string())
+            .or_insert_with(|| LineageDatasetNode {
+                node_id: node_id.to_string(),
+                dataset_name: dataset_name.to_string(),
+                version_id: version_id.to_string(),
+                created_at: None,
+                record_count: None,
+                consumed_by_run_ids: Vec::new(),
+                produced_by_run_ids: Vec::new(),
+            }),
+    )
+}
+
+fn fill_catalog_metadata(data_root: &Path, datasets: &mut BTreeMap<String, LineageDatasetNode>) {
+    let mut catalogs: HashMap<String, CatalogVersions> = HashMap::new();
+    for dataset in datasets.values_mut() {
+        let versions = catalogs
+            .entry(dataset.dataset_name.clone())
+            .or_insert_with(|| load_catalog_versions(data_root, &dataset.dataset_name));
+        if let Some((created_at, record_count)) = versions.get(&dataset.version_id) {
+            dataset.created_at = created_at.clone();
+            dataset.record_count = *record_count;
+        }
+    }
+}
+
+/// Deleted or unreadable datasets still appear as nodes, just without metadata.
+fn load_catalog_versions(data_root: &Path, dataset_name: &str) -> CatalogVersions {
+    let Ok(catalog) = read_catalog(&data_root.join("datasets").join(dataset_name)) else {
+        return CatalogVersions::new();
+    };
+    catalog
+        .get("versions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let version_id = entry.get("version_id")?.as_str()?.to_string();
+            let created_at = entry
+                .get("created_at")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            let record_count = entry.get("record_count").and_then(Value::as_u64);
+            Some((version_id, (created_at, record_count)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::attach_dataset_nodes;
+    use crate::models::{LineageEdge, LineageGraphSummary, LineageRunNode};
+    use std::path::Path;
+
+    fn edge(from: &str, to: &str, edge_type: &str) -> LineageEdge {
+        LineageEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            edge_type: edge_type.to_string(),
+        }
+    }
+
+    fn run(run_id: &str, version_id: &str) -> LineageRunNode {
+        LineageRunNode {
+            run_id: run_id.to_string(),
+            dataset_name: "demo".to_string(),
+            dataset_version_id: version_id.to_string(),
+            output_dir: "out".to_string(),
+            parent_model_path: None,
+            model_path: None,
+            config_hash: "abc".to_string(),
+            created_at: "2026-01-01T00:00:00+00:00".to_string(),
+            artifact_contract_path: None,
+        }
+    }
+
+    #[test]
+    fn attach_dataset_nodes_rewrites_trained_on_as_consumed_edges() {
+        let mut summary = LineageGraphSummary {
+            run_count: 2,
+            dataset_count: 0,
+            edge_count: 2,
+            runs: vec![run("run-a", "v1"), run("run-b", "v2")],
+            datasets: vec![],
+            edges: vec![
+                edge("dataset:demo:v1", "run:run-a", "trained_on"),
+                edge("run:run-a", "dataset:demo:v2", "produced"),
+            ],
+        };
+        attach_dataset_nodes(Path::new("/nonexistent-forge-root"), &mut summary);
+        assert_eq!(summary.dataset_count, 2);
+        assert_eq!(
+            summary.datasets[0].consumed_by_run_ids,
+            vec!["run-a".to_string()]
+        );
+        let second = &summary.datasets[1];
+        assert_eq!(second.produced_by_run_ids, vec!["run-a".to_string()]);
+        assert_eq!(second.consumed_by_run_ids, vec!["run-b".to_string()]);
+        assert!(summary.edges.iter().all(|row| row.from.starts_with("run:")));
+        assert_eq!(summary.edge_count, 3);
+    }
+}
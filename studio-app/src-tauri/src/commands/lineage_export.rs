@@ -0,0 +1,257 @@
+//! Lineage graph export to DOT, Mermaid, and GraphML documents.
+//!
+//! Exports render the same parsed graph `get_lineage_graph` returns, so the
+//! exported picture always matches what Studio displays.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::atomic_write::write_file_atomic;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::commands::runtime_queries::load_lineage_summary;
+use crate::models::{ForgeStudioError, LineageExportResult, LineageGraphSummary};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const LINEAGE_EXPORT_DIR: &str = "outputs/lineage";
+const LINEAGE_EXPORT_FILE_STEM: &str = "model-lineage";
+
+#[derive(Clone, Copy)]
+enum LineageExportFormat {
+    Dot,
+    Mermaid,
+    GraphMl,
+}
+
+impl LineageExportFormat {
+    fn parse(raw_format: &str) -> Result<Self, ForgeStudioError> {
+        match raw_format.trim().to_lowercase().as_str() {
+            "dot" | "graphviz" => Ok(Self::Dot),
+            "mermaid" | "mmd" => Ok(Self::Mermaid),
+            "graphml" => Ok(Self::GraphMl),
+            other => Err(ForgeStudioError::invalid_input(format!(
+                "Unsupported lineage export format '{other}'. Use dot, mermaid, or graphml."
+            ))),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::Mermaid => "mermaid",
+            Self::GraphMl => "graphml",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::Mermaid => "mmd",
+            Self::GraphMl => "graphml",
+        }
+    }
+}
+
+#[tauri::command]
+pub fn export_lineage_graph(
+    data_root: String,
+    format: String,
+    output_path: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<LineageExportResult, ForgeStudioError> {
+    let export_format = LineageExportFormat::parse(&format)?;
+    let roots = settings.workspace_roots();
+    let resolved_data_root = resolve_data_root_path(&data_root, &roots)?;
+    let summary = load_lineage_summary(&resolved_data_root)?;
+    let node_ids = collect_node_ids(&summary);
+    let document = match export_format {
+        LineageExportFormat::Dot => render_dot(&node_ids, &summary),
+        LineageExportFormat::Mermaid => render_mermaid(&node_ids, &summary),
+        LineageExportFormat::GraphMl => render_graphml(&node_ids, &summary),
+    };
+    let target_path = resolve_export_path(&resolved_data_root, output_path, export_format, &roots)?;
+    if let Some(parent_dir) = target_path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to create {}: {error}",
+                parent_dir.display()
+            ))
+        })?;
+    }
+    write_file_atomic(&target_path, document.as_bytes())?;
+    Ok(LineageExportResult {
+        format: export_format.name().to_string(),
+        output_path: target_path.display().to_string(),
+        node_count: node_ids.len() as u64,
+        edge_count: summary.edges.len() as u64,
+    })
+}
+
+/// Relative paths resolve against the data root, matching canvas export.
+fn resolve_export_path(
+    data_root: &Path,
+    output_path: Option<String>,
+    export_format: LineageExportFormat,
+    roots: &WorkspaceRoots,
+) -> Result<PathBuf, ForgeStudioError> {
+    let requested = output_path
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    let mut target_path = match requested {
+        Some(value) if Path::new(&value).is_absolute() => PathBuf::from(value),
+        Some(value) => data_root.join(value),
+        None => data_root
+            .join(LINEAGE_EXPORT_DIR)
+            .join(LINEAGE_EXPORT_FILE_STEM),
+    };
+    if target_path.extension().is_none() {
+        target_path.set_extension(export_format.extension());
+    }
+    confine_path(&target_path, "Output path", roots)
+}
+
+/// Edge endpoints plus every run and dataset node, so nodes without edges still appear.
+fn collect_node_ids(summary: &LineageGraphSummary) -> Vec<String> {
+    let mut node_ids: BTreeSet<String> = summary
+        .runs
+        .iter()
+        .map(|run| format!("run:{}", run.run_id))
+        .collect();
+    node_ids.extend(
+        summary
+            .datasets
+            .iter()
+            .map(|dataset| dataset.node_id.clone()),
+    );
+    for edge in &summary.edges {
+        node_ids.insert(edge.from.clone());
+        node_ids.insert(edge.to.clone());
+    }
+    node_ids.into_iter().collect()
+}
+
+fn node_kind(node_id: &str) -> &str {
+    node_id
+        .split_once(':')
+        .map(|(kind, _)| kind)
+        .unwrap_or("node")
+}
+
+fn render_dot(node_ids: &[String], summary: &LineageGraphSummary) -> String {
+    let quote = |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+    let mut lines = vec![
+        "digraph forge_lineage {".to_string(),
+        "  rankdir=LR;".to_string(),
+    ];
+    for node_id in node_ids {
+        let shape = match node_kind(node_id) {
+            "dataset" => "cylinder",
+            "model" => "box3d",
+            _ => "box",
+        };
+        lines.push(format!("  {} [shape={shape}];", quote(node_id)));
+    }
+    for edge in &summary.edges {
+        let (from, to, label) = (quote(&edge.from), quote(&edge.to), quote(&edge.edge_type));
+        lines.push(format!("  {from} -> {to} [label={label}];"));
+    }
+    lines.push("}".to_string());
+    lines.join("\n") + "\n"
+}
+
+/// Mermaid ids must be plain identifiers, so nodes get positional ids with quoted labels.
+fn render_mermaid(node_ids: &[String], summary: &LineageGraphSummary) -> String {
+    let mermaid_id = |node_id: &str| {
+        node_ids
+            .iter()
+            .position(|candidate| candidate == node_id)
+            .map(|index| format!("n{index}"))
+            .unwrap_or_else(|| "unknown".to_string())
+    };
+    let mut lines = vec!["flowchart LR".to_string()];
+    for (index, node_id) in node_ids.iter().enumerate() {
+        lines.push(format!(
+            "  n{index}[\"{}\"]",
+            node_id.replace('"', "#quot;")
+        ));
+    }
+    for edge in &summary.edges {
+        let (from, to) = (mermaid_id(&edge.from), mermaid_id(&edge.to));
+        lines.push(format!("  {from} -->|{}| {to}", edge.edge_type));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn render_graphml(node_ids: &[String], summary: &LineageGraphSummary) -> String {
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#.to_string(),
+        r#"  <key id="kind" for="node" attr.name="kind" attr.type="string"/>"#.to_string(),
+        r#"  <key id="type" for="edge" attr.name="type" attr.type="string"/>"#.to_string(),
+        r#"  <graph id="forge_lineage" edgedefault="directed">"#.to_string(),
+    ];
+    for node_id in node_ids {
+        lines.push(format!(
+            "    <node id=\"{}\"><data key=\"kind\">{}</data></node>",
+            xml_escape(node_id),
+            xml_escape(node_kind(node_id))
+        ));
+    }
+    for (index, edge) in summary.edges.iter().enumerate() {
+        lines.push(format!(
+            "    <edge id=\"e{index}\" source=\"{}\" target=\"{}\"><data key=\"type\">{}</data></edge>",
+            xml_escape(&edge.from),
+            xml_escape(&edge.to),
+            xml_escape(&edge.edge_type)
+        ));
+    }
+    lines.push("  </graph>".to_string());
+    lines.push("</graphml>".to_string());
+    lines.join("\n") + "\n"
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_node_ids, render_dot, render_mermaid};
+    use crate::models::{LineageEdge, LineageGraphSummary};
+
+    fn sample_summary() -> LineageGraphSummary {
+        LineageGraphSummary {
+            run_count: 0,
+            dataset_count: 0,
+            edge_count: 1,
+            runs: vec![],
+            datasets: vec![],
+            edges: vec![LineageEdge {
+                from: "dataset:demo:v1".to_string(),
+                to: "run:run-a".to_string(),
+                edge_type: "trained_on".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn render_dot_quotes_node_ids_and_labels_edges() {
+        let summary = sample_summary();
+        let dot = render_dot(&collect_node_ids(&summary), &summary);
+        assert!(dot.contains("\"dataset:demo:v1\" [shape=cylinder];"));
+        assert!(dot.contains("\"dataset:demo:v1\" -> \"run:run-a\" [label=\"trained_on\"];"));
+    }
+
+    #[test]
+    fn render_mermaid_uses_positional_ids() {
+        let summary = sample_summary();
+        let mermaid = render_mermaid(&collect_node_ids(&summary), &summary);
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("  n0 -->|trained_on| n1"));
+    }
+}
@@ -0,0 +1,99 @@
+//! Read/write access to the model lineage graph written by the forge CLI.
+//!
+//! The graph lives at `<data_root>/lineage/model_lineage.json` as
+//! `{runs: {run_id: {...}}, edges: [{from, to, type}]}`; a missing file is an
+//! empty graph because the CLI only creates it on the first training run.
+//! Changes go through `update_lineage_graph`, which holds the file lock the
+//! CLI also takes, so edges written by a concurrent forge command survive.
+
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::run_store_io::{read_json_file, write_json_file};
+use crate::models::ForgeStudioError;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) struct LineageGraphFile {
+    pub runs: Map<String, Value>,
+    pub edges: Vec<Value>,
+}
+
+pub(crate) fn lineage_graph_path(data_root: &Path) -> PathBuf {
+    data_root.join("lineage").join("model_lineage.json")
+}
+
+pub(crate) fn read_lineage_graph(data_root: &Path) -> Result<LineageGraphFile, ForgeStudioError> {
+    let graph_path = lineage_graph_path(data_root);
+    if !graph_path.exists() {
+        return Ok(LineageGraphFile {
+            runs: Map::new(),
+            edges: vec![],
+        });
+    }
+    let payload = read_json_file(&graph_path)?;
+    let root = payload
+        .as_object()
+        .ok_or_else(|| ForgeStudioError::parse("Lineage payload must be a JSON object"))?;
+    let runs = root
+        .get("runs")
+        .and_then(Value::as_object)
+        .cloned()
+        .ok_or_else(|| ForgeStudioError::parse("Lineage payload missing runs map"))?;
+    let edges = root
+        .get("edges")
+        .and_then(Value::as_array)
+        .cloned()
+        .ok_or_else(|| ForgeStudioError::parse("Lineage payload missing edges array"))?;
+    Ok(LineageGraphFile { runs, edges })
+}
+
+/// Reads the graph, applies `update`, and writes it back under the lineage
+/// lock. `update` returns its result and whether it changed the graph.
+pub(crate) fn update_lineage_graph<T>(
+    data_root: &Path,
+    update: impl FnOnce(&mut LineageGraphFile) -> Result<(T, bool), ForgeStudioError>,
+) -> Result<T, ForgeStudioError> {
+    with_file_lock(&lineage_graph_path(data_root), || {
+        let mut graph = read_lineage_graph(data_root)?;
+        let (output, changed) = update(&mut graph)?;
+        if changed {
+            write_lineage_graph(data_root, &graph)?;
+        }
+        Ok(output)
+    })
+}
+
+fn write_lineage_graph(data_root: &Path, graph: &LineageGraphFile) -> Result<(), ForgeStudioError> {
+    let graph_path = lineage_graph_path(data_root);
+    if let Some(parent) = graph_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::json!({"runs": graph.runs, "edges": graph.edges});
+    write_json_file(&graph_path, &payload)
+}
+
+pub(crate) fn edge_field<'a>(edge: &'a Value, key: &str) -> Option<&'a str> {
+    edge.get(key).and_then(Value::as_str)
+}
+
+pub(crate) fn run_node_id(run_id: &str) -> String {
+    format!("run:{run_id}")
+}
+
+pub(crate) fn model_node_id(model_path: &str) -> String {
+    format!("model:{model_path}")
+}
+
+pub(crate) fn dataset_node_id(dataset_name: &str, version_id: &str) -> String {
+    format!("dataset:{dataset_name}:{version_id}")
+}
+
+pub(crate) fn pipeline_node_id(pipeline_run_id: &str) -> String {
+    format!("pipeline:{pipeline_run_id}")
+}
+
+pub(crate) fn eval_node_id(run_id: &str, eval_id: &str) -> String {
+    format!("eval:{run_id}/{eval_id}")
+}
@@ -0,0 +1,125 @@
+//! Backend subgraph selection for the lineage view.
+//!
+//! With hundreds of runs the full graph is too heavy for the canvas, so the
+//! reachable ancestry and descendants of one run are computed here. The
+//! traversal follows edge direction separately upstream and downstream;
+//! walking undirected would pull in every sibling run that shares a dataset.
+//! `consumed` edges point from a run to its input dataset, so they are
+//! walked in data-flow order (dataset first) like every other edge.
+
+use crate::commands::lineage_datasets::CONSUMED_EDGE_TYPE;
+use crate::models::{ForgeStudioError, LineageEdge, LineageGraphFilter, LineageGraphSummary};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub(crate) fn filter_lineage_summary(
+    summary: LineageGraphSummary,
+    filter: &LineageGraphFilter,
+) -> Result<LineageGraphSummary, ForgeStudioError> {
+    let mut runs = summary.runs;
+    let mut datasets = summary.datasets;
+    let mut edges = summary.edges;
+    if let Some(root_run_id) = filter.root_run_id.as_deref() {
+        if !runs.iter().any(|run| run.run_id == root_run_id) {
+            return Err(ForgeStudioError::not_found(format!(
+                "Lineage root run '{root_run_id}' is not in the lineage graph. \
+                 Pick a run from the runs list."
+            )));
+        }
+        let reachable = reachable_nodes(&edges, &format!("run:{root_run_id}"), filter.max_depth);
+        runs.retain(|run| reachable.contains(&format!("run:{}", run.run_id)));
+        datasets.retain(|dataset| reachable.contains(&dataset.node_id));
+        edges.retain(|edge| reachable.contains(&edge.from) && reachable.contains(&edge.to));
+    }
+    if let Some(dataset_name) = filter.dataset_name.as_deref() {
+        runs.retain(|run| run.dataset_name == dataset_name);
+        let kept_runs: HashSet<String> = runs
+            .iter()
+            .map(|run| format!("run:{}", run.run_id))
+            .collect();
+        edges.retain(|edge| kept_runs.contains(&edge.from) || kept_runs.contains(&edge.to));
+        datasets.retain(|dataset| {
+            edges
+                .iter()
+                .any(|edge| edge.from == dataset.node_id || edge.to == dataset.node_id)
+        });
+    }
+    Ok(LineageGraphSummary {
+        run_count: runs.len() as u64,
+        dataset_count: datasets.len() as u64,
+        edge_count: edges.len() as u64,
+        runs,
+        datasets,
+        edges,
+    })
+}
+
+/// Collects the root plus every node within `max_depth` hops upstream or downstream.
+fn reachable_nodes(
+    edges: &[LineageEdge],
+    root_node: &str,
+    max_depth: Option<u32>,
+) -> HashSet<String> {
+    let mut downstream: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut upstream: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges {
+        let (source, target) = match edge.edge_type.as_str() {
+            CONSUMED_EDGE_TYPE => (edge.to.as_str(), edge.from.as_str()),
+            _ => (edge.from.as_str(), edge.to.as_str()),
+        };
+        downstream.entry(source).or_default().push(target);
+        upstream.entry(target).or_default().push(source);
+    }
+    let mut reachable = walk(&downstream, root_node, max_depth);
+    reachable.extend(walk(&upstream, root_node, max_depth));
+    reachable
+}
+
+fn walk(
+    adjacency: &HashMap<&str, Vec<&str>>,
+    root_node: &str,
+    max_depth: Option<u32>,
+) -> HashSet<String> {
+    let mut visited = HashSet::from([root_node.to_string()]);
+    let mut queue = VecDeque::from([(root_node, 0_u32)]);
+    while let Some((node, depth)) = queue.pop_front() {
+        if max_depth.is_some_and(|limit| depth >= limit) {
+            continue;
+        }
+        for next in adjacency.get(node).into_iter().flatten() {
+            if visited.insert(next.to_string()) {
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reachable_nodes;
+    use crate::models::LineageEdge;
+
+    fn edge(from: &str, to: &str, edge_type: &str) -> LineageEdge {
+        LineageEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            edge_type: edge_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn reachable_nodes_follows_direction_and_depth() {
+        let edges = vec![
+            edge("run:a", "dataset:demo:v1", "consumed"),
+            edge("run:a", "model:a.pt", "produced"),
+            edge("model:a.pt", "run:b", "initialized_from"),
+            edge("run:sibling", "dataset:demo:v1", "consumed"),
+        ];
+        let unlimited = reachable_nodes(&edges, "run:b", None);
+        assert!(unlimited.contains("dataset:demo:v1"));
+        assert!(!unlimited.contains("run:sibling"));
+        let one_hop = reachable_nodes(&edges, "run:b", Some(1));
+        assert!(one_hop.contains("model:a.pt"));
+        assert!(!one_hop.contains("run:a"));
+    }
+}
@@ -0,0 +1,126 @@
+//! Loss-spike detection for training curve analysis.
+//!
+//! A batch loss is a spike when it is far above the median of the steps just
+//! before it, so a slowly falling curve never trips the threshold. Runs that
+//! only logged epoch losses fall back to comparing consecutive epochs.
+
+use crate::commands::training_curve_analysis::epoch_finding;
+use crate::models::{CurveFinding, TrainingBatchLoss, TrainingEpoch};
+
+/// Batch losses compared against the median of this many preceding steps.
+const SPIKE_BASELINE_STEPS: usize = 20;
+const SPIKE_RATIO: f64 = 2.0;
+const CRITICAL_SPIKE_RATIO: f64 = 5.0;
+/// Spikes this close together are reported as one region.
+const SPIKE_MERGE_STEPS: u64 = 5;
+/// Epoch-to-epoch train loss jump treated as a spike when no batch losses exist.
+const EPOCH_SPIKE_RATIO: f64 = 1.5;
+
+/// Batch losses far above the median of the preceding steps, merged into regions.
+pub(crate) fn detect_batch_spikes(batch_losses: &[TrainingBatchLoss]) -> Vec<CurveFinding> {
+    let mut regions: Vec<(usize, usize, f64)> = Vec::new();
+    for index in SPIKE_BASELINE_STEPS..batch_losses.len() {
+        let baseline = median(
+            batch_losses[index - SPIKE_BASELINE_STEPS..index]
+                .iter()
+                .map(|row| row.train_loss),
+        );
+        let ratio = batch_losses[index].train_loss / baseline.max(1e-12);
+        if ratio < SPIKE_RATIO {
+            continue;
+        }
+        match regions.last_mut() {
+            Some((_, end, peak))
+                if batch_losses[index].global_step - batch_losses[*end].global_step
+                    <= SPIKE_MERGE_STEPS =>
+            {
+                *end = index;
+                *peak = peak.max(ratio);
+            }
+            _ => regions.push((index, index, ratio)),
+        }
+    }
+    regions
+        .into_iter()
+        .map(|(start, end, peak_ratio)| {
+            let (first, last) = (&batch_losses[start], &batch_losses[end]);
+            CurveFinding {
+                kind: "loss_spike".to_string(),
+                severity: spike_severity(peak_ratio).to_string(),
+                start_epoch: first.epoch,
+                end_epoch: last.epoch,
+                start_step: Some(first.global_step),
+                end_step: Some(last.global_step),
+                message: format!(
+                    "Train loss spiked to {peak_ratio:.1}x the recent median at steps {}-{}. \
+                     Lower learning_rate or add gradient clipping.",
+                    first.global_step, last.global_step
+                ),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn detect_epoch_spikes(epochs: &[TrainingEpoch]) -> Vec<CurveFinding> {
+    epochs
+        .windows(2)
+        .filter_map(|pair| {
+            let ratio = pair[1].train_loss / pair[0].train_loss.max(1e-12);
+            (ratio >= EPOCH_SPIKE_RATIO).then(|| {
+                epoch_finding(
+                    "loss_spike",
+                    spike_severity(ratio),
+                    (pair[1].epoch, pair[1].epoch),
+                    format!(
+                        "Train loss jumped {ratio:.1}x at epoch {}. \
+                         Lower learning_rate or add gradient clipping.",
+                        pair[1].epoch
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+fn spike_severity(ratio: f64) -> &'static str {
+    if ratio >= CRITICAL_SPIKE_RATIO {
+        "critical"
+    } else {
+        "warning"
+    }
+}
+
+fn median(values: impl Iterator<Item = f64>) -> f64 {
+    let mut sorted: Vec<f64> = values.collect();
+    sorted.sort_by(f64::total_cmp);
+    sorted.get(sorted.len() / 2).copied().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect_batch_spikes;
+    use crate::models::TrainingBatchLoss;
+
+    #[test]
+    fn detect_batch_spikes_merges_adjacent_spike_steps() {
+        let losses: Vec<TrainingBatchLoss> = (0..40_u64)
+            .map(|step| {
+                let train_loss = if step == 30 || step == 32 { 9.0 } else { 2.0 };
+                serde_json::from_value(serde_json::json!({
+                    "epoch": 1,
+                    "batch_index": step,
+                    "global_step": step,
+                    "train_loss": train_loss,
+                }))
+                .expect("batch loss should parse")
+            })
+            .collect();
+        let findings = detect_batch_spikes(&losses);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            (findings[0].start_step, findings[0].end_step),
+            (Some(30), Some(32))
+        );
+        assert_eq!(findings[0].severity, "warning");
+    }
+}
@@ -0,0 +1,231 @@
+//! Message catalogs for user-facing text the backend formats itself.
+//!
+//! Error titles, task status names, dataset health findings, and canvas
+//! validation issues are formatted from templates keyed by a stable id such
+//! as `error.not_found` or `task_status.running`, with `{name}` placeholders
+//! filled per message. English is built in. Other locales are flat JSON
+//! objects of the same keys in `locales/<locale>.json` under the app config
+//! directory; keys a catalog lacks fall back to English, so a partial
+//! translation still works. Machine-readable fields such as error codes,
+//! status names, and issue kinds are never localized, only the text next to
+//! them, so the frontend keeps keying on them whatever the locale.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_store_io::read_json_file;
+use crate::models::ForgeStudioError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+use tauri::State;
+
+const DEFAULT_LOCALE: &str = "en";
+const LOCALES_DIR_NAME: &str = "locales";
+
+const ENGLISH_MESSAGES: [(&str, &str); 25] = [
+    ("error.conflict", "Already exists or in use"),
+    ("error.internal", "Studio error"),
+    ("error.invalid_input", "Invalid input"),
+    ("error.io", "File access failed"),
+    ("error.not_found", "Not found"),
+    ("error.parse", "Unreadable file"),
+    ("error.read_only", "Read-only mode"),
+    (
+        "health.duplicates",
+        "{affected} of {total} records repeat another record's id.",
+    ),
+    (
+        "health.language_mismatches",
+        "{affected} of {total} records are not in their labelled language.",
+    ),
+    (
+        "health.length_outliers",
+        "{affected} of {total} records are unusually short or long.",
+    ),
+    (
+        "health.malformed_lines",
+        "{affected} of {total} lines are not valid records.",
+    ),
+    (
+        "health.pii",
+        "{affected} of {total} records contain an email address or phone number.",
+    ),
+    ("health.verdict.go", "Go"),
+    ("health.verdict.no-go", "No-go"),
+    ("health.verdict.review", "Review"),
+    ("task_status.completed", "Completed"),
+    ("task_status.failed", "Failed"),
+    ("task_status.running", "Running"),
+    (
+        "validation.cycle",
+        "Nodes {node_ids} form a cycle. Remove one of the circular connections.",
+    ),
+    (
+        "validation.duplicate_node_id",
+        "Node id '{node_id}' is used more than once.",
+    ),
+    (
+        "validation.empty_node_id",
+        "A '{node_type}' node has an empty id.",
+    ),
+    (
+        "validation.missing_edge_nodes",
+        "Edge '{edge_id}' references missing node(s) {node_ids}. Reconnect or delete the edge.",
+    ),
+    (
+        "validation.missing_start_node",
+        "Start node '{node_id}' is not in the canvas. Pick another.",
+    ),
+    (
+        "validation.unknown_node_type",
+        "Node '{node_id}' has unknown type '{node_type}'. Use one of: {node_types}.",
+    ),
+    (
+        "validation.unreachable_node",
+        "Nodes {node_ids} are not reachable from start node '{node_id}' and will not run.",
+    ),
+];
+
+/// Translations for the active locale; `None` while it is English.
+static ACTIVE_CATALOG: RwLock<Option<LocaleCatalog>> = RwLock::new(None);
+
+struct LocaleCatalog {
+    locale: String,
+    messages: HashMap<String, String>,
+}
+
+/// Switches the catalog backend messages are formatted with, saves the
+/// choice as the `locale` setting, and returns the locale now active.
+#[tauri::command]
+pub fn set_locale(
+    locale: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<String, ForgeStudioError> {
+    let config_dir = settings.config_dir().ok_or_else(|| {
+        ForgeStudioError::internal("Settings are not loaded yet. Restart Studio.")
+    })?;
+    let catalog = load_catalog(&config_dir, locale.trim())?;
+    settings.update(json!({ "locale": locale.trim() }))?;
+    let active = catalog
+        .as_ref()
+        .map_or(DEFAULT_LOCALE, |catalog| catalog.locale.as_str())
+        .to_string();
+    install_catalog(catalog);
+    Ok(active)
+}
+
+/// Activates `locale` from the catalogs in `config_dir` at startup.
+pub(crate) fn activate_locale(config_dir: &Path, locale: &str) -> Result<(), ForgeStudioError> {
+    install_catalog(load_catalog(config_dir, locale)?);
+    Ok(())
+}
+
+/// Formats the message `key` in the active locale, replacing each `{name}`
+/// in its template with the matching value in `args`.
+pub(crate) fn message(key: &str, args: &[(&str, &str)]) -> String {
+    let translated = ACTIVE_CATALOG.read().ok().and_then(|catalog| {
+        catalog
+            .as_ref()
+            .and_then(|catalog| catalog.messages.get(key).cloned())
+    });
+    let template = translated
+        .or_else(|| english_template(key).map(str::to_string))
+        .unwrap_or_else(|| key.to_string());
+    args.iter().fold(template, |text, (name, value)| {
+        text.replace(&format!("{{{name}}}"), value)
+    })
+}
+
+fn english_template(key: &str) -> Option<&'static str> {
+    ENGLISH_MESSAGES
+        .iter()
+        .find(|(known, _)| *known == key)
+        .map(|(_, template)| *template)
+}
+
+fn install_catalog(catalog: Option<LocaleCatalog>) {
+    if let Ok(mut active) = ACTIVE_CATALOG.write() {
+        *active = catalog;
+    }
+}
+
+/// `None` for English, which needs no file.
+fn load_catalog(
+    config_dir: &Path,
+    locale: &str,
+) -> Result<Option<LocaleCatalog>, ForgeStudioError> {
+    let valid = !locale.is_empty()
+        && locale
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '-');
+    if !valid {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Locale '{locale}' is not a locale name. Use a tag such as en or pt-BR."
+        )));
+    }
+    if locale == DEFAULT_LOCALE {
+        return Ok(None);
+    }
+    let catalog_path = config_dir
+        .join(LOCALES_DIR_NAME)
+        .join(format!("{locale}.json"));
+    if !catalog_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Message catalog for locale '{locale}' does not exist at {}. Add the catalog file or pick another locale.",
+            catalog_path.display()
+        )));
+    }
+    let messages = match read_json_file(&catalog_path)? {
+        Value::Object(entries) => entries
+            .into_iter()
+            .map(|(key, value)| Some((key, value.as_str()?.to_string())))
+            .collect::<Option<HashMap<String, String>>>(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse message catalog {}: values must be strings. Fix the catalog file.",
+            catalog_path.display()
+        ))
+    })?;
+    Ok(Some(LocaleCatalog {
+        locale: locale.to_string(),
+        messages,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_catalog, message, ENGLISH_MESSAGES};
+    use std::fs;
+
+    #[test]
+    fn catalogs_load_from_the_config_dir_and_fall_back_to_english() {
+        assert_eq!(
+            message("validation.duplicate_node_id", &[("node_id", "train")]),
+            "Node id 'train' is used more than once."
+        );
+        assert!(ENGLISH_MESSAGES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+
+        let config_dir =
+            std::env::temp_dir().join(format!("forge-message-catalog-{}", std::process::id()));
+        fs::create_dir_all(config_dir.join("locales")).expect("locales dir should be created");
+        fs::write(
+            config_dir.join("locales/de.json"),
+            r#"{"task_status.running": "Läuft"}"#,
+        )
+        .expect("catalog should be written");
+        let catalog = load_catalog(&config_dir, "de")
+            .expect("catalog should load")
+            .expect("de is not built in");
+        assert_eq!(catalog.messages["task_status.running"], "Läuft");
+        assert!(load_catalog(&config_dir, "en")
+            .expect("en is built in")
+            .is_none());
+        assert!(load_catalog(&config_dir, "fr").is_err());
+        assert!(load_catalog(&config_dir, "../de").is_err());
+        fs::remove_dir_all(&config_dir).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,236 @@
+//! SQLite index mirroring dataset catalogs, runs, and the lineage graph.
+//!
+//! Listing and searching runs used to re-read every run's JSON files on each
+//! call. The index at `<data_root>/index/metadata.sqlite` mirrors catalogs,
+//! version summaries, run lifecycles and annotations, and lineage into
+//! tables. Each sync stats the source files and re-reads only those changed
+//! since the previous sync. The JSON files stay the source of truth, so the
+//! index is a cache: an outdated schema or `rebuild_metadata_index` simply
+//! recreates it from them.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::metadata_index_sources::{
+    clear_rows, insert_rows, read_source_rows, scan_sources, IndexSource,
+};
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{ForgeStudioError, MetadataIndexSync};
+use rusqlite::{params, Connection, OpenFlags, Transaction};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::State;
+
+const METADATA_INDEX_DIR_NAME: &str = "index";
+const METADATA_INDEX_FILE_NAME: &str = "metadata.sqlite";
+/// Bump when the tables change; an index with another version is recreated.
+const SCHEMA_VERSION: i32 = 1;
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+const SCHEMA_SQL: &str = "
+    CREATE TABLE sources (key TEXT PRIMARY KEY, modified_ns INTEGER NOT NULL);
+    CREATE TABLE datasets (dataset_name TEXT PRIMARY KEY, latest_version TEXT);
+    CREATE TABLE versions (
+        dataset_name TEXT NOT NULL, version_id TEXT NOT NULL, record_count INTEGER NOT NULL,
+        created_at TEXT NOT NULL, parent_version TEXT, PRIMARY KEY (dataset_name, version_id)
+    );
+    CREATE TABLE runs (
+        run_id TEXT PRIMARY KEY, dataset_name TEXT NOT NULL, dataset_version_id TEXT NOT NULL,
+        state TEXT NOT NULL, created_at TEXT NOT NULL, updated_at TEXT NOT NULL,
+        output_dir TEXT NOT NULL, notes TEXT NOT NULL
+    );
+    CREATE INDEX runs_by_created_at ON runs (created_at);
+    CREATE TABLE run_tags (run_id TEXT NOT NULL, tag TEXT NOT NULL);
+    CREATE INDEX run_tags_by_run_id ON run_tags (run_id);
+    CREATE TABLE lineage_edges (from_node TEXT NOT NULL, to_node TEXT NOT NULL,
+        edge_type TEXT NOT NULL);
+    CREATE TABLE lineage_models (run_id TEXT PRIMARY KEY, model_path TEXT NOT NULL);
+";
+/// Recreates the index from scratch, e.g. after it was copied between machines.
+#[tauri::command]
+pub fn rebuild_metadata_index(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<MetadataIndexSync, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let index_path = metadata_index_path(&resolved_data_root);
+    if index_path.exists() {
+        fs::remove_file(&index_path).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to remove metadata index {}: {error}. Close other Studio windows and retry.",
+                index_path.display()
+            ))
+        })?;
+    }
+    let mut connection = open_metadata_index(&resolved_data_root)?;
+    sync_metadata_index(&mut connection, &resolved_data_root)
+}
+
+pub(crate) fn metadata_index_path(data_root: &Path) -> PathBuf {
+    data_root
+        .join(METADATA_INDEX_DIR_NAME)
+        .join(METADATA_INDEX_FILE_NAME)
+}
+
+/// Opens the index, creating it, or recreating it when its schema is outdated.
+pub(crate) fn open_metadata_index(data_root: &Path) -> Result<Connection, ForgeStudioError> {
+    let index_path = metadata_index_path(data_root);
+    if let Some(index_dir) = index_path.parent() {
+        fs::create_dir_all(index_dir).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", index_dir.display()))
+        })?;
+    }
+    let mut connection = connect(&index_path)?;
+    let schema_version: i32 = connection
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(index_error)?;
+    if schema_version == SCHEMA_VERSION {
+        return Ok(connection);
+    }
+    if schema_version != 0 {
+        drop(connection);
+        fs::remove_file(&index_path).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to replace outdated metadata index {}: {error}",
+                index_path.display()
+            ))
+        })?;
+        connection = connect(&index_path)?;
+    }
+    connection.execute_batch(SCHEMA_SQL).map_err(index_error)?;
+    connection
+        .pragma_update(None, "user_version", SCHEMA_VERSION)
+        .map_err(index_error)?;
+    Ok(connection)
+}
+
+/// Re-reads changed sources and drops rows of deleted ones, in one transaction.
+pub(crate) fn sync_metadata_index(
+    connection: &mut Connection,
+    data_root: &Path,
+) -> Result<MetadataIndexSync, ForgeStudioError> {
+    let sources = scan_sources(data_root)?;
+    let transaction = connection.transaction().map_err(index_error)?;
+    let stored = stored_sources(&transaction)?;
+    let mut refreshed_source_count = 0;
+    let mut unreadable_sources = Vec::new();
+    for source in &sources {
+        if stored.get(&source.key) == Some(&source.modified_ns) {
+            continue;
+        }
+        if refresh_source(&transaction, data_root, source)? {
+            refreshed_source_count += 1;
+        } else {
+            unreadable_sources.push(source.key.clone());
+        }
+    }
+    let current_keys: HashSet<&str> = sources.iter().map(|source| source.key.as_str()).collect();
+    let mut removed_source_count = 0;
+    for (key, _) in stored
+        .iter()
+        .filter(|(key, _)| !current_keys.contains(key.as_str()))
+    {
+        transaction
+            .execute("DELETE FROM sources WHERE key = ?1", [key])
+            .map_err(index_error)?;
+        clear_rows(&transaction, key)?;
+        removed_source_count += 1;
+    }
+    let count = |table: &str| -> Result<u64, ForgeStudioError> {
+        transaction
+            .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                row.get(0)
+            })
+            .map_err(index_error)
+    };
+    let sync = MetadataIndexSync {
+        index_path: metadata_index_path(data_root).display().to_string(),
+        dataset_count: count("datasets")?,
+        version_count: count("versions")?,
+        run_count: count("runs")?,
+        lineage_edge_count: count("lineage_edges")?,
+        refreshed_source_count,
+        removed_source_count,
+        unreadable_sources,
+    };
+    transaction.commit().map_err(index_error)?;
+    Ok(sync)
+}
+
+pub(crate) fn index_error(error: rusqlite::Error) -> ForgeStudioError {
+    ForgeStudioError::io(format!(
+        "Metadata index error: {error}. Run rebuild_metadata_index to recreate it."
+    ))
+}
+
+/// Replaces `source`'s rows; `false` when the source could not be read.
+fn refresh_source(
+    transaction: &Transaction,
+    data_root: &Path,
+    source: &IndexSource,
+) -> Result<bool, ForgeStudioError> {
+    clear_rows(transaction, &source.key)?;
+    let Ok(rows) = read_source_rows(data_root, &source.kind) else {
+        // Forgetting the source makes the next sync retry it.
+        transaction
+            .execute("DELETE FROM sources WHERE key = ?1", [&source.key])
+            .map_err(index_error)?;
+        return Ok(false);
+    };
+    insert_rows(transaction, rows)?;
+    transaction
+        .execute(
+            "INSERT OR REPLACE INTO sources (key, modified_ns) VALUES (?1, ?2)",
+            params![source.key, source.modified_ns],
+        )
+        .map_err(index_error)?;
+    Ok(true)
+}
+
+/// Opens the index as last synced without writing to the data root, for
+/// read-only mode; fails when it was never built or is outdated.
+pub(crate) fn open_existing_metadata_index(
+    data_root: &Path,
+) -> Result<Connection, ForgeStudioError> {
+    let index_path = metadata_index_path(data_root);
+    let not_built = || {
+        ForgeStudioError::read_only(format!(
+            "Metadata index {} is not built for this Studio version, and read-only mode does not build it. Turn off read_only in settings to build it.",
+            index_path.display()
+        ))
+    };
+    if !index_path.is_file() {
+        return Err(not_built());
+    }
+    let connection = Connection::open_with_flags(&index_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(index_error)?;
+    let schema_version: i32 = connection
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(index_error)?;
+    if schema_version != SCHEMA_VERSION {
+        return Err(not_built());
+    }
+    connection.busy_timeout(BUSY_TIMEOUT).map_err(index_error)?;
+    Ok(connection)
+}
+
+fn connect(index_path: &Path) -> Result<Connection, ForgeStudioError> {
+    let connection = Connection::open(index_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to open metadata index {}: {error}",
+            index_path.display()
+        ))
+    })?;
+    // Two windows may sync at once; the second waits instead of failing.
+    connection.busy_timeout(BUSY_TIMEOUT).map_err(index_error)?;
+    Ok(connection)
+}
+
+fn stored_sources(transaction: &Transaction) -> Result<HashMap<String, i64>, ForgeStudioError> {
+    let mut statement = transaction
+        .prepare("SELECT key, modified_ns FROM sources")
+        .map_err(index_error)?;
+    let rows = statement
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(index_error)?;
+    rows.collect::<Result<_, _>>().map_err(index_error)
+}
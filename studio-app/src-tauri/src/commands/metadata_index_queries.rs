@@ -0,0 +1,195 @@
+//! Queries answered from the metadata index instead of the JSON files.
+//!
+//! Every query syncs the index first, which only stats files, so results are
+//! as fresh as reading the files directly.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::metadata_index::{
+    index_error, open_existing_metadata_index, open_metadata_index, sync_metadata_index,
+};
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{ForgeStudioError, MetadataSearchHit, TrainingRunQuery};
+use rusqlite::{params, Connection};
+use std::path::Path;
+use tauri::State;
+
+const DEFAULT_SEARCH_LIMIT: usize = 50;
+/// Filters mirror `matches_run_query`, so both listing paths agree.
+const RUN_QUERY_SQL: &str = "
+    SELECT run_id FROM runs
+    WHERE (?1 IS NULL OR dataset_name = ?1)
+      AND (?2 IS NULL OR state = ?2)
+      AND (?3 IS NULL OR created_at >= ?3)
+      AND (?4 IS NULL OR substr(created_at, 1, length(?4)) <= ?4)
+      AND (?5 IS NULL OR EXISTS (
+          SELECT 1 FROM run_tags WHERE run_tags.run_id = runs.run_id AND tag = ?5))
+      AND (?6 IS NULL OR instr(lower(run_id), ?6) > 0 OR instr(lower(notes), ?6) > 0
+          OR EXISTS (SELECT 1 FROM run_tags
+              WHERE run_tags.run_id = runs.run_id AND instr(lower(tag), ?6) > 0))
+    ORDER BY created_at DESC, run_id DESC
+    LIMIT ?7 OFFSET ?8";
+const SEARCH_SQL: &str = "
+    SELECT 'dataset', dataset_name, dataset_name, NULL, NULL FROM datasets
+    WHERE instr(lower(dataset_name), ?1) > 0
+    UNION ALL
+    SELECT 'version', version_id, dataset_name, NULL, created_at FROM versions
+    WHERE instr(lower(version_id), ?1) > 0
+    UNION ALL
+    SELECT 'run', run_id, dataset_name, run_id, created_at FROM runs
+    WHERE instr(lower(run_id), ?1) > 0 OR instr(lower(notes), ?1) > 0
+        OR EXISTS (SELECT 1 FROM run_tags
+            WHERE run_tags.run_id = runs.run_id AND instr(lower(tag), ?1) > 0)
+    UNION ALL
+    SELECT 'model', model_path, NULL, run_id, NULL FROM lineage_models
+    WHERE instr(lower(model_path), ?1) > 0
+    LIMIT ?2";
+
+/// Datasets, versions, runs, and models whose name contains `text`.
+#[tauri::command]
+pub fn search_metadata_index(
+    data_root: String,
+    text: String,
+    limit: Option<usize>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<MetadataSearchHit>, ForgeStudioError> {
+    let needle = text.trim().to_lowercase();
+    if needle.is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Search text is empty. Type part of a dataset, version, run, or model name.",
+        ));
+    }
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let connection = synced_index(&resolved_data_root, settings.current().read_only)?;
+    let limit = sql_limit(Some(limit.unwrap_or(DEFAULT_SEARCH_LIMIT)));
+    let mut statement = connection.prepare(SEARCH_SQL).map_err(index_error)?;
+    let hits = statement
+        .query_map(params![needle, limit], |row| {
+            Ok(MetadataSearchHit {
+                kind: row.get(0)?,
+                id: row.get(1)?,
+                dataset_name: row.get(2)?,
+                run_id: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })
+        .map_err(index_error)?;
+    hits.collect::<Result<_, _>>().map_err(index_error)
+}
+
+/// Ids of runs matching `query`, newest first, paged by its offset and limit.
+pub(crate) fn indexed_run_ids(
+    data_root: &Path,
+    query: &TrainingRunQuery,
+    read_only: bool,
+) -> Result<Vec<String>, ForgeStudioError> {
+    let connection = synced_index(data_root, read_only)?;
+    let search = query
+        .search
+        .as_deref()
+        .map(|needle| needle.trim().to_lowercase())
+        .filter(|needle| !needle.is_empty());
+    let offset = sql_limit(Some(query.offset.unwrap_or(0)));
+    let mut statement = connection.prepare(RUN_QUERY_SQL).map_err(index_error)?;
+    let run_ids = statement
+        .query_map(
+            params![
+                query.dataset_name,
+                query.state,
+                query.created_from,
+                query.created_to,
+                query.tag,
+                search,
+                sql_limit(query.limit),
+                offset
+            ],
+            |row| row.get(0),
+        )
+        .map_err(index_error)?;
+    run_ids.collect::<Result<_, _>>().map_err(index_error)
+}
+
+fn synced_index(data_root: &Path, read_only: bool) -> Result<Connection, ForgeStudioError> {
+    // Syncing writes the index file, so read-only mode queries it as last synced.
+    if read_only {
+        return open_existing_metadata_index(data_root);
+    }
+    let mut connection = open_metadata_index(data_root)?;
+    sync_metadata_index(&mut connection, data_root)?;
+    Ok(connection)
+}
+
+/// SQLite reads a negative `LIMIT` as "no limit".
+fn sql_limit(limit: Option<usize>) -> i64 {
+    limit.map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::indexed_run_ids;
+    use crate::commands::metadata_index::{open_metadata_index, sync_metadata_index};
+    use crate::models::TrainingRunQuery;
+    use serde_json::json;
+    use std::fs;
+    use std::path::Path;
+
+    fn write_run(data_root: &Path, run_id: &str, state: &str, created_at: &str) {
+        let run_dir = data_root.join("runs").join(run_id);
+        fs::create_dir_all(&run_dir).expect("run dir should be created");
+        let lifecycle = json!({
+            "run_id": run_id, "dataset_name": "demo", "dataset_version_id": "demo-v1",
+            "output_dir": "/tmp/out", "parent_model_path": null, "config_hash": "abc",
+            "state": state, "created_at": created_at, "updated_at": created_at,
+            "artifact_contract_path": null, "error_message": null
+        });
+        fs::write(run_dir.join("lifecycle.json"), lifecycle.to_string())
+            .expect("lifecycle should be written");
+    }
+
+    #[test]
+    fn sync_refreshes_only_changed_sources_and_queries_filter() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-metadata-index-{}", std::process::id()));
+        write_run(
+            &data_root,
+            "run-a",
+            "completed",
+            "2026-03-01T00:00:00+00:00",
+        );
+        write_run(&data_root, "run-b", "failed", "2026-03-02T00:00:00+00:00");
+        fs::write(
+            data_root.join("runs/run-b/meta.json"),
+            r#"{"tags": ["Baseline"]}"#,
+        )
+        .expect("meta should be written");
+        fs::create_dir_all(data_root.join("runs/run-c")).expect("run dir should be created");
+        fs::write(data_root.join("runs/run-c/lifecycle.json"), "{").expect("lifecycle write");
+
+        let mut connection = open_metadata_index(&data_root).expect("index should open");
+        let first = sync_metadata_index(&mut connection, &data_root).expect("sync should run");
+        assert_eq!((first.run_count, first.refreshed_source_count), (2, 2));
+        assert_eq!(first.unreadable_sources, ["run:run-c"]);
+        fs::remove_dir_all(data_root.join("runs/run-a")).expect("run should be removed");
+        let second = sync_metadata_index(&mut connection, &data_root).expect("sync should run");
+        assert_eq!(
+            (second.refreshed_source_count, second.removed_source_count),
+            (0, 1)
+        );
+
+        write_run(
+            &data_root,
+            "run-a",
+            "completed",
+            "2026-03-01T00:00:00+00:00",
+        );
+        let all = indexed_run_ids(&data_root, &TrainingRunQuery::default(), false)
+            .expect("runs should list");
+        assert_eq!(all, ["run-b", "run-a"]);
+        let query = TrainingRunQuery {
+            search: Some(" baseline ".to_string()),
+            ..TrainingRunQuery::default()
+        };
+        let searched = indexed_run_ids(&data_root, &query, false).expect("runs should list");
+        assert_eq!(searched, ["run-b"]);
+        fs::remove_dir_all(&data_root).expect("temp data root should be removed");
+    }
+}
@@ -0,0 +1,275 @@
+//! Source files mirrored by the metadata index, and their rows in it.
+//!
+//! A source is one dataset catalog, one run (its `lifecycle.json` and
+//! `meta.json` together), or the lineage graph. Sources are compared by
+//! modification time alone, so a sync stats files without opening them and
+//! only changed sources are parsed.
+
+use crate::commands::dataset_queries::parse_version_summary;
+use crate::commands::dataset_store_io::{read_catalog, read_child_dirs};
+use crate::commands::lineage_store_io::{edge_field, lineage_graph_path, read_lineage_graph};
+use crate::commands::metadata_index::index_error;
+use crate::commands::run_annotations::{load_run_meta, run_meta_path};
+use crate::commands::run_store_io::{load_run_lifecycle, optional_string, run_dir};
+use crate::models::{ForgeStudioError, RunLifecycleRecord, RunUserMetadata, VersionSummary};
+use rusqlite::{params, ToSql, Transaction};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+const LINEAGE_SOURCE_KEY: &str = "lineage";
+const INSERT_DATASET_SQL: &str =
+    "INSERT INTO datasets (dataset_name, latest_version) VALUES (?1, ?2)";
+const INSERT_VERSION_SQL: &str = "INSERT OR REPLACE INTO versions
+    (dataset_name, version_id, record_count, created_at, parent_version)
+    VALUES (?1, ?2, ?3, ?4, ?5)";
+const INSERT_RUN_SQL: &str = "INSERT INTO runs
+    (run_id, dataset_name, dataset_version_id, state, created_at, updated_at, output_dir, notes)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)";
+const INSERT_RUN_TAG_SQL: &str = "INSERT INTO run_tags (run_id, tag) VALUES (?1, ?2)";
+const INSERT_LINEAGE_EDGE_SQL: &str =
+    "INSERT INTO lineage_edges (from_node, to_node, edge_type) VALUES (?1, ?2, ?3)";
+const INSERT_LINEAGE_MODEL_SQL: &str =
+    "INSERT OR REPLACE INTO lineage_models (run_id, model_path) VALUES (?1, ?2)";
+
+pub(crate) enum SourceKind {
+    Dataset(String),
+    Run(String),
+    Lineage,
+}
+
+pub(crate) struct IndexSource {
+    /// `dataset:<name>`, `run:<run_id>`, or `lineage`.
+    pub key: String,
+    pub kind: SourceKind,
+    pub modified_ns: i64,
+}
+
+/// What one source contributes to the index tables.
+pub(crate) enum SourceRows {
+    Dataset {
+        dataset_name: String,
+        latest_version: Option<String>,
+        versions: Vec<VersionSummary>,
+    },
+    Run {
+        lifecycle: Box<RunLifecycleRecord>,
+        meta: RunUserMetadata,
+    },
+    Lineage {
+        /// `(from, to, type)` per edge.
+        edges: Vec<(String, String, String)>,
+        /// `(run_id, model_path)` per run that produced a model.
+        models: Vec<(String, String)>,
+    },
+}
+
+/// Every source currently on disk under `data_root`.
+pub(crate) fn scan_sources(data_root: &Path) -> Result<Vec<IndexSource>, ForgeStudioError> {
+    let mut sources = Vec::new();
+    let datasets_dir = data_root.join("datasets");
+    if datasets_dir.is_dir() {
+        for dataset_name in read_child_dirs(&datasets_dir)? {
+            let catalog_path = datasets_dir.join(&dataset_name).join("catalog.json");
+            if let Some(modified_ns) = modified_ns(&catalog_path) {
+                sources.push(IndexSource {
+                    key: format!("dataset:{dataset_name}"),
+                    kind: SourceKind::Dataset(dataset_name),
+                    modified_ns,
+                });
+            }
+        }
+    }
+    let runs_dir = data_root.join("runs");
+    if runs_dir.is_dir() {
+        for run_id in read_child_dirs(&runs_dir)? {
+            let lifecycle_path = run_dir(data_root, &run_id).join("lifecycle.json");
+            let Some(lifecycle_ns) = modified_ns(&lifecycle_path) else {
+                continue;
+            };
+            let meta_ns = modified_ns(&run_meta_path(data_root, &run_id)).unwrap_or(0);
+            sources.push(IndexSource {
+                key: format!("run:{run_id}"),
+                kind: SourceKind::Run(run_id),
+                modified_ns: lifecycle_ns.max(meta_ns),
+            });
+        }
+    }
+    if let Some(modified_ns) = modified_ns(&lineage_graph_path(data_root)) {
+        sources.push(IndexSource {
+            key: LINEAGE_SOURCE_KEY.to_string(),
+            kind: SourceKind::Lineage,
+            modified_ns,
+        });
+    }
+    Ok(sources)
+}
+
+/// Parses `source` into rows; errors mean the source is unreadable right now.
+pub(crate) fn read_source_rows(
+    data_root: &Path,
+    kind: &SourceKind,
+) -> Result<SourceRows, ForgeStudioError> {
+    match kind {
+        SourceKind::Dataset(dataset_name) => {
+            let catalog = read_catalog(&data_root.join("datasets").join(dataset_name))?;
+            let versions = catalog
+                .get("versions")
+                .and_then(Value::as_array)
+                .ok_or_else(|| ForgeStudioError::parse("Catalog is missing versions array"))?
+                .iter()
+                .map(parse_version_summary)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(SourceRows::Dataset {
+                dataset_name: dataset_name.clone(),
+                latest_version: catalog
+                    .get("latest_version")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                versions,
+            })
+        }
+        SourceKind::Run(run_id) => Ok(SourceRows::Run {
+            lifecycle: Box::new(load_run_lifecycle(data_root, run_id)?),
+            meta: load_run_meta(data_root, run_id)?,
+        }),
+        SourceKind::Lineage => {
+            let graph = read_lineage_graph(data_root)?;
+            let edges = graph
+                .edges
+                .iter()
+                .filter_map(|edge| {
+                    let field = |key| edge_field(edge, key).map(str::to_string);
+                    Some((field("from")?, field("to")?, field("type")?))
+                })
+                .collect();
+            let models = graph
+                .runs
+                .iter()
+                .filter_map(|(run_id, run)| {
+                    let model_path = optional_string(run.as_object()?, "model_path")?;
+                    Some((run_id.clone(), model_path))
+                })
+                .collect();
+            Ok(SourceRows::Lineage { edges, models })
+        }
+    }
+}
+
+/// Deletes the rows the source with `key` contributed.
+pub(crate) fn clear_rows(transaction: &Transaction, key: &str) -> Result<(), ForgeStudioError> {
+    let (statements, id): (&[&str], Option<&str>) = match key.split_once(':') {
+        Some(("dataset", dataset_name)) => (
+            &[
+                "DELETE FROM datasets WHERE dataset_name = ?1",
+                "DELETE FROM versions WHERE dataset_name = ?1",
+            ],
+            Some(dataset_name),
+        ),
+        Some(("run", run_id)) => (
+            &[
+                "DELETE FROM runs WHERE run_id = ?1",
+                "DELETE FROM run_tags WHERE run_id = ?1",
+            ],
+            Some(run_id),
+        ),
+        _ => (
+            &["DELETE FROM lineage_edges", "DELETE FROM lineage_models"],
+            None,
+        ),
+    };
+    for sql in statements {
+        match id {
+            Some(id) => transaction.execute(sql, [id]),
+            None => transaction.execute(sql, []),
+        }
+        .map_err(index_error)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn insert_rows(
+    transaction: &Transaction,
+    rows: SourceRows,
+) -> Result<(), ForgeStudioError> {
+    match rows {
+        SourceRows::Dataset {
+            dataset_name,
+            latest_version,
+            versions,
+        } => {
+            let values = params![dataset_name, latest_version];
+            execute(transaction, INSERT_DATASET_SQL, values)?;
+            for version in versions {
+                let values = params![
+                    dataset_name,
+                    version.version_id,
+                    version.record_count,
+                    version.created_at,
+                    version.parent_version
+                ];
+                execute(transaction, INSERT_VERSION_SQL, values)?;
+            }
+        }
+        SourceRows::Run { lifecycle, meta } => insert_run_rows(transaction, &lifecycle, &meta)?,
+        SourceRows::Lineage { edges, models } => {
+            for (from_node, to_node, edge_type) in edges {
+                let values = params![from_node, to_node, edge_type];
+                execute(transaction, INSERT_LINEAGE_EDGE_SQL, values)?;
+            }
+            for (run_id, model_path) in models {
+                execute(
+                    transaction,
+                    INSERT_LINEAGE_MODEL_SQL,
+                    params![run_id, model_path],
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn insert_run_rows(
+    transaction: &Transaction,
+    lifecycle: &RunLifecycleRecord,
+    meta: &RunUserMetadata,
+) -> Result<(), ForgeStudioError> {
+    let values = params![
+        lifecycle.run_id,
+        lifecycle.dataset_name,
+        lifecycle.dataset_version_id,
+        lifecycle.state,
+        lifecycle.created_at,
+        lifecycle.updated_at,
+        lifecycle.output_dir,
+        meta.notes
+    ];
+    execute(transaction, INSERT_RUN_SQL, values)?;
+    for tag in &meta.tags {
+        execute(
+            transaction,
+            INSERT_RUN_TAG_SQL,
+            params![lifecycle.run_id, tag],
+        )?;
+    }
+    Ok(())
+}
+
+fn execute(
+    transaction: &Transaction,
+    sql: &str,
+    values: &[&dyn ToSql],
+) -> Result<(), ForgeStudioError> {
+    transaction
+        .execute(sql, values)
+        .map(drop)
+        .map_err(index_error)
+}
+
+/// Nanoseconds since the epoch, or `None` when the file does not exist.
+fn modified_ns(path: &Path) -> Option<i64> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    let since_epoch = modified.duration_since(UNIX_EPOCH).ok()?;
+    Some(i64::try_from(since_epoch.as_nanos()).unwrap_or(i64::MAX))
+}
@@ -1,7 +1,148 @@
 //! Studio command module exports.
 
+pub mod accelerator_probes;
+pub mod app_logging;
+pub mod app_settings;
+pub mod artifact_contract_verification;
+pub mod atomic_write;
+pub mod audit_log;
+pub mod batch_inference;
+pub mod batch_inference_store;
+pub mod blocking_pool;
+pub mod canvas_bundle;
+pub mod canvas_bundle_import;
+pub mod canvas_dataset_refs;
+pub mod canvas_diff;
+pub mod canvas_edit_journal;
 pub mod canvas_export;
+pub mod canvas_export_files;
+pub mod canvas_groups;
+pub mod canvas_import;
+pub mod canvas_lint;
+pub mod canvas_merge;
+pub mod canvas_merge_items;
+pub mod canvas_node_checks;
+pub mod canvas_provenance;
+pub mod canvas_render;
+pub mod canvas_run_spec;
+pub mod canvas_snapshots;
+pub mod canvas_validation;
+pub mod canvas_variables;
+pub mod chat_process;
+pub mod chat_sessions;
+pub mod content_hash;
+pub mod crash_reports;
+pub mod cron_schedule;
+pub mod data_root_check;
+pub mod data_root_init;
+pub mod dataset_dashboard;
+pub mod dataset_drift;
+pub mod dataset_health_checks;
+pub mod dataset_health_report;
 pub mod dataset_queries;
+pub mod dataset_store_io;
+pub mod dataset_version_writer;
+pub mod edge_conditions;
+pub mod energy_ledger;
+pub mod evaluation_harness;
+pub mod event_bridge;
+pub mod event_bus;
+pub mod file_locks;
+pub mod filter_explanation;
 pub mod forge_commands;
+pub mod forge_process;
+pub mod forge_task_estimates;
+pub mod forge_task_notices;
+pub mod forge_task_slots;
+pub mod forge_task_status;
 pub mod forge_task_store;
+pub mod forge_updates;
+pub mod hardware_profile;
+pub mod hardware_telemetry;
+pub mod history_downsampling;
+pub mod history_smoothing;
+pub mod history_watcher;
+pub mod leaderboard;
+pub mod lineage_audit;
+pub mod lineage_datasets;
+pub mod lineage_export;
+pub mod lineage_store_io;
+pub mod lineage_subgraph;
+pub mod loss_spike_detection;
+pub mod message_catalog;
+pub mod metadata_index;
+pub mod metadata_index_queries;
+pub mod metadata_index_sources;
+pub mod model_export;
+pub mod model_export_comparison;
+pub mod model_export_store;
+pub mod model_inspection;
+pub mod model_registry;
+pub mod new_run_linker;
+pub mod node_config_validation;
+pub mod os_notification;
+pub mod path_safety;
+pub mod pipeline_cache;
+pub mod pipeline_config_import;
+pub mod pipeline_dry_run;
+pub mod pipeline_history;
+pub mod pipeline_lineage;
+pub mod pipeline_node_args;
+pub mod pipeline_node_outcomes;
+pub mod pipeline_node_readiness;
+pub mod pipeline_node_schema;
+pub mod pipeline_node_types;
+pub mod pipeline_plan;
+pub mod pipeline_run_scheduler;
+pub mod pipeline_run_store;
+pub mod pipeline_runs;
+pub mod pipeline_schedules;
+pub mod plugin_protocol;
+pub mod plugin_registry;
+pub mod quality_expression;
+pub mod quality_recalibration;
+pub mod read_only_mode;
+pub mod record_arrow_ipc;
+pub mod record_outliers;
+pub mod rpc_methods;
+pub mod rpc_server;
+pub mod run_annotations;
+pub mod run_attachments;
+pub mod run_clone;
+pub mod run_comparison;
+pub mod run_config_diff;
+pub mod run_cost;
+pub mod run_details;
+pub mod run_evaluations;
+pub mod run_failure_diagnosis;
+pub mod run_gc;
+pub mod run_heartbeat;
+pub mod run_index;
+pub mod run_list_filter;
+pub mod run_output_dirs;
+pub mod run_removal;
+pub mod run_resume;
+pub mod run_spec_file;
+pub mod run_spec_yaml;
+pub mod run_state_watcher;
+pub mod run_store_io;
 pub mod runtime_queries;
+pub mod settings_validation;
+pub mod sweep_configs;
+pub mod sweep_plan;
+pub mod sweep_scheduler;
+pub mod sweeps;
+pub mod tensorboard_events;
+pub mod tensorboard_export;
+pub mod torch_archive_reader;
+pub mod train_task_log;
+pub mod training_curve_analysis;
+pub mod training_estimates;
+pub mod training_eta;
+pub mod training_feasibility;
+pub mod training_history;
+pub mod usage_telemetry;
+pub mod version_duplicates;
+pub mod version_integrity;
+pub mod workspace_backup;
+pub mod workspace_restore;
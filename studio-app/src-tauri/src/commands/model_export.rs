@@ -0,0 +1,239 @@
+//! Model export orchestration to ONNX.
+//!
+//! Exports run as `forge export-model` tasks so they share the task store's
+//! progress and log streaming with training. Each request is tracked in
+//! `runs/<run_id>/exports.json` and writes its own file, named after its
+//! export id, so a re-export never overwrites an earlier artifact; a watcher
+//! thread settles the record when the task exits and, only if the artifact
+//! actually landed on disk, adds an `exported` lineage edge from the source
+//! run to the exported file. GGUF is not offered: `forge export-model` only
+//! writes ONNX.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::forge_commands::validate_args;
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::lineage_store_io::{model_node_id, run_node_id, update_lineage_graph};
+use crate::commands::model_export_store::{
+    finish_export_record, load_model_exports, register_export,
+};
+use crate::commands::run_details::resolve_run_artifacts;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, resolve_data_root_path, resolve_workspace_path,
+};
+use crate::models::{ForgeStudioError, ModelExportRecord, ModelExportStart};
+use chrono::{SecondsFormat, Utc};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tauri::State;
+
+const EXPORTS_DIR_NAME: &str = "exports";
+const EXPORT_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const EXPORTED_EDGE_TYPE: &str = "exported";
+
+/// Export formats with the quantization schemes `forge export-model` accepts.
+const EXPORT_FORMATS: [(&str, &str, &[&str]); 1] = [("onnx", "onnx", &["fp16", "int8"])];
+
+#[tauri::command]
+pub fn export_model(
+    data_root: String,
+    run_id: String,
+    format: String,
+    quantization: Option<String>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<ModelExportStart, ForgeStudioError> {
+    let (format, extension) = resolve_export_format(&format, quantization.as_deref())?;
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let source_model_path = resolve_run_artifacts(&lifecycle)?
+        .model_path
+        .filter(|path| Path::new(path).is_file())
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{run_id}' has no model file to export. Wait for training to complete."
+            ))
+        })?;
+    let quantization = quantization.map(|value| value.trim().to_lowercase());
+    let output_file = format!("{}.{extension}", quantization.as_deref().unwrap_or("full"));
+    let unnumbered_output = resolve_workspace_path(&lifecycle.output_dir)
+        .join(EXPORTS_DIR_NAME)
+        .join(output_file);
+    let requested = ModelExportRecord {
+        export_id: String::new(),
+        run_id,
+        format,
+        quantization,
+        source_model_path,
+        output_path: String::new(),
+        task_id: String::new(),
+        status: task_status_name(TaskLifecycleStatus::Running).to_string(),
+        requested_at: timestamp_now(),
+        finished_at: None,
+    };
+    let (record, task) = register_export(
+        &resolved_data_root,
+        requested,
+        &unnumbered_output,
+        |record| {
+            let args = build_export_args(record);
+            validate_args(&args)?;
+            Ok(task_store.start_task(data_root, args))
+        },
+    )?;
+    spawn_export_watcher(
+        resolved_data_root,
+        task_store.inner().clone(),
+        record.clone(),
+    );
+    Ok(ModelExportStart {
+        estimated_total_seconds: task.estimated_total_seconds,
+        export: record,
+    })
+}
+
+#[tauri::command]
+pub fn list_model_exports(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<ModelExportRecord>, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    load_run_lifecycle(&resolved_data_root, &run_id)?;
+    load_model_exports(&resolved_data_root, &run_id)
+}
+
+/// Returns the canonical format name and file extension after checking quantization.
+fn resolve_export_format(
+    format: &str,
+    quantization: Option<&str>,
+) -> Result<(String, &'static str), ForgeStudioError> {
+    let requested = format.trim().to_lowercase();
+    let (name, extension, schemes) = EXPORT_FORMATS
+        .iter()
+        .find(|(name, _, _)| *name == requested)
+        .ok_or_else(|| {
+            ForgeStudioError::invalid_input(format!(
+                "Unsupported export format '{format}'. Use onnx; forge export-model does not write other formats."
+            ))
+        })?;
+    let scheme = quantization.map(|value| value.trim().to_lowercase());
+    if let Some(scheme) = scheme.filter(|scheme| !schemes.contains(&scheme.as_str())) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Quantization '{scheme}' is not supported for {name} exports. Use one of: {}.",
+            schemes.join(", ")
+        )));
+    }
+    Ok((name.to_string(), extension))
+}
+
+fn build_export_args(record: &ModelExportRecord) -> Vec<String> {
+    let mut args = vec![
+        "export-model".to_string(),
+        "--model-path".to_string(),
+        record.source_model_path.clone(),
+        "--format".to_string(),
+        record.format.clone(),
+        "--output-path".to_string(),
+        record.output_path.clone(),
+    ];
+    if let Some(quantization) = record.quantization.as_ref() {
+        args.push("--quantization".to_string());
+        args.push(quantization.clone());
+    }
+    args
+}
+
+fn spawn_export_watcher(
+    data_root: PathBuf,
+    task_store: CommandTaskStore,
+    record: ModelExportRecord,
+) {
+    thread::spawn(move || loop {
+        let status = task_store
+            .get_task_status(&record.task_id)
+            .map(|status| status.status)
+            .unwrap_or_else(|_| task_status_name(TaskLifecycleStatus::Failed).to_string());
+        if status == task_status_name(TaskLifecycleStatus::Running) {
+            thread::sleep(EXPORT_WATCH_POLL_INTERVAL);
+            continue;
+        }
+        let produced = status == task_status_name(TaskLifecycleStatus::Completed)
+            && Path::new(&record.output_path).is_file();
+        if let Err(error) = settle_export(&data_root, &record, produced) {
+            tracing::warn!("Failed to record export {}: {error}", record.export_id);
+        }
+        return;
+    });
+}
+
+fn settle_export(
+    data_root: &Path,
+    record: &ModelExportRecord,
+    produced: bool,
+) -> Result<(), ForgeStudioError> {
+    let status = if produced {
+        TaskLifecycleStatus::Completed
+    } else {
+        TaskLifecycleStatus::Failed
+    };
+    finish_export_record(data_root, record, task_status_name(status), timestamp_now())?;
+    if !produced {
+        return Ok(());
+    }
+    let edge = json!({
+        "from": run_node_id(&record.run_id),
+        "to": model_node_id(&record.output_path),
+        "type": EXPORTED_EDGE_TYPE,
+    });
+    // Each export has its own file, so a matching edge only means this one settled twice.
+    update_lineage_graph(data_root, |graph| {
+        if graph.edges.contains(&edge) {
+            return Ok(((), false));
+        }
+        graph.edges.push(edge);
+        Ok(((), true))
+    })
+}
+
+fn timestamp_now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_export_args, resolve_export_format};
+    use crate::models::ModelExportRecord;
+
+    #[test]
+    fn resolve_export_format_checks_quantization_per_format() {
+        assert_eq!(
+            resolve_export_format("ONNX", Some("INT8")).expect("valid onnx scheme"),
+            ("onnx".to_string(), "onnx")
+        );
+        assert!(resolve_export_format("onnx", Some("q4_k_m")).is_err());
+        assert!(resolve_export_format("gguf", None).is_err());
+        assert!(resolve_export_format("tflite", None).is_err());
+    }
+
+    #[test]
+    fn build_export_args_appends_quantization_flag() {
+        let record = ModelExportRecord {
+            export_id: "export-0001".to_string(),
+            run_id: "run-a".to_string(),
+            format: "onnx".to_string(),
+            quantization: Some("int8".to_string()),
+            source_model_path: "out/model.pt".to_string(),
+            output_path: "out/exports/export-0001-int8.onnx".to_string(),
+            task_id: "task-1".to_string(),
+            status: "running".to_string(),
+            requested_at: "t0".to_string(),
+            finished_at: None,
+        };
+        let args = build_export_args(&record);
+        assert_eq!(args[0], "export-model");
+        assert_eq!(args[args.len() - 2..], ["--quantization", "int8"]);
+    }
+}
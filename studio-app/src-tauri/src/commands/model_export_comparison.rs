@@ -0,0 +1,214 @@
+//! Side-by-side comparison of a run's source model and its exports.
+//!
+//! Picking between full, fp16, and int8 exports is a size versus
+//! quality trade, so each completed export is listed with its size ratio,
+//! a disk read time, and eval deltas against the source model. Eval rows
+//! are matched to an artifact by their `model_path`; rows without one were
+//! recorded against the run's own model and count toward the source.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::model_export_store::load_model_exports;
+use crate::commands::run_details::resolve_run_artifacts;
+use crate::commands::run_evaluations::load_eval_results;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, resolve_data_root_path, resolve_workspace_path,
+};
+use crate::models::{
+    EvalResult, ForgeStudioError, ModelExportComparison, ModelExportRecord, ModelExportVariant,
+};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::time::Instant;
+use tauri::State;
+
+const SOURCE_LABEL: &str = "source";
+
+/// Artifact identity before measurement.
+struct VariantSource {
+    label: String,
+    format: String,
+    quantization: Option<String>,
+    path: String,
+}
+
+/// Reads every artifact in full to time it, so it runs on the blocking pool.
+#[tauri::command]
+pub async fn compare_model_exports(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<ModelExportComparison, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    run_blocking(move || compare_exports(&resolve_data_root_path(&data_root, &roots)?, run_id))
+        .await
+}
+
+fn compare_exports(
+    resolved_data_root: &Path,
+    run_id: String,
+) -> Result<ModelExportComparison, ForgeStudioError> {
+    let lifecycle = load_run_lifecycle(resolved_data_root, &run_id)?;
+    let source_path = resolve_run_artifacts(&lifecycle)?
+        .model_path
+        .filter(|path| Path::new(path).is_file())
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{run_id}' has no model file to compare. Wait for training to complete."
+            ))
+        })?;
+    let exports = load_model_exports(resolved_data_root, &run_id)?;
+    let evals = load_eval_results(resolved_data_root, &run_id)?;
+    let mut notes = Vec::new();
+    let mut sources = vec![VariantSource {
+        label: SOURCE_LABEL.to_string(),
+        format: file_extension(&source_path),
+        quantization: None,
+        path: source_path.clone(),
+    }];
+    sources.extend(completed_export_sources(exports, &mut notes));
+    if sources.len() == 1 {
+        notes.push("Run has no completed exports yet. Export to ONNX first.".to_string());
+    }
+    let source_metrics = artifact_metrics(&evals, &source_path, true);
+    let source_size = file_size(&source_path);
+    let variants = sources
+        .into_iter()
+        .map(|source| measure_variant(source, &evals, source_size, &source_metrics))
+        .collect();
+    Ok(ModelExportComparison {
+        run_id,
+        variants,
+        notes,
+    })
+}
+
+/// Completed exports whose file is still on disk, noting the ones that vanished.
+fn completed_export_sources(
+    exports: Vec<ModelExportRecord>,
+    notes: &mut Vec<String>,
+) -> Vec<VariantSource> {
+    let mut sources = Vec::new();
+    for export in exports
+        .into_iter()
+        .filter(|row| row.status == task_status_name(TaskLifecycleStatus::Completed))
+    {
+        if !Path::new(&export.output_path).is_file() {
+            notes.push(format!(
+                "{} is missing on disk and was skipped.",
+                export.output_path
+            ));
+            continue;
+        }
+        let scheme = export.quantization.as_deref().unwrap_or("full");
+        sources.push(VariantSource {
+            label: format!("{}-{scheme}", export.format),
+            format: export.format,
+            quantization: export.quantization,
+            path: export.output_path,
+        });
+    }
+    sources
+}
+
+fn measure_variant(
+    source: VariantSource,
+    evals: &[EvalResult],
+    source_size: u64,
+    source_metrics: &BTreeMap<String, f64>,
+) -> ModelExportVariant {
+    let size_bytes = file_size(&source.path);
+    let metrics = artifact_metrics(evals, &source.path, source.label == SOURCE_LABEL);
+    let metric_deltas = metrics
+        .iter()
+        .filter_map(|(key, value)| Some((key.clone(), value - source_metrics.get(key)?)))
+        .collect();
+    ModelExportVariant {
+        size_ratio: (source_size > 0).then(|| size_bytes as f64 / source_size as f64),
+        disk_read_seconds: disk_read_seconds(&source.path),
+        label: source.label,
+        format: source.format,
+        quantization: source.quantization,
+        path: source.path,
+        size_bytes,
+        metrics,
+        metric_deltas,
+    }
+}
+
+/// Latest score per `<suite>/<metric>` among evals of `artifact_path`.
+fn artifact_metrics(
+    evals: &[EvalResult],
+    artifact_path: &str,
+    is_source: bool,
+) -> BTreeMap<String, f64> {
+    let matches = |eval: &EvalResult| match eval.model_path.as_deref() {
+        Some(model_path) => resolve_workspace_path(model_path) == Path::new(artifact_path),
+        None => is_source,
+    };
+    // Results are stored in recording order, so later rows overwrite earlier ones.
+    let mut metrics = BTreeMap::new();
+    for eval in evals.iter().filter(|eval| matches(eval)) {
+        for (metric, value) in &eval.metrics {
+            metrics.insert(format!("{}/{metric}", eval.suite), *value);
+        }
+    }
+    metrics
+}
+
+/// Reads the whole file, so callers stay off the IPC thread.
+fn disk_read_seconds(path: &str) -> Option<f64> {
+    let started = Instant::now();
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    io::copy(&mut reader, &mut io::sink()).ok()?;
+    Some(started.elapsed().as_secs_f64())
+}
+
+fn file_size(path: &str) -> u64 {
+    fs::metadata(path).map(|meta| meta.len()).unwrap_or(0)
+}
+
+fn file_extension(path: &str) -> String {
+    Path::new(path)
+        .extension()
+        .and_then(|value| value.to_str())
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::artifact_metrics;
+    use crate::models::EvalResult;
+    use std::collections::BTreeMap;
+
+    fn eval(model_path: Option<&str>, perplexity: f64) -> EvalResult {
+        EvalResult {
+            eval_id: "eval-0001".to_string(),
+            run_id: "run-a".to_string(),
+            suite: "wikitext".to_string(),
+            metrics: BTreeMap::from([("perplexity".to_string(), perplexity)]),
+            model_path: model_path.map(str::to_string),
+            dataset_version_id: None,
+            details: None,
+            recorded_at: "t0".to_string(),
+        }
+    }
+
+    #[test]
+    fn artifact_metrics_keeps_latest_score_per_artifact() {
+        let evals = vec![
+            eval(None, 12.0),
+            eval(Some("/out/exports/model-int8.onnx"), 14.5),
+            eval(None, 11.0),
+        ];
+        let source = artifact_metrics(&evals, "/out/model.pt", true);
+        let quantized = artifact_metrics(&evals, "/out/exports/model-int8.onnx", false);
+        assert_eq!(source.get("wikitext/perplexity"), Some(&11.0));
+        assert_eq!(quantized.get("wikitext/perplexity"), Some(&14.5));
+        assert!(artifact_metrics(&evals, "/out/exports/model.onnx", false).is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+//! `runs/<run_id>/exports.json`, the record of a run's model exports.
+//!
+//! Rows are only changed under the file's lock, and export ids are one past
+//! the highest existing one, so two exports requested at once never share an
+//! id or an output file.
+
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::run_store_io::{read_json_file, run_dir, write_json_file};
+use crate::models::{CommandTaskStart, ForgeStudioError, ModelExportRecord};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+const EXPORTS_FILE_NAME: &str = "exports.json";
+
+/// Assigns the next export id, prefixes it to the file name of
+/// `unnumbered_output`, starts the task, and records it, all under the
+/// `exports.json` lock so concurrent exports get distinct ids and files.
+pub(crate) fn register_export(
+    data_root: &Path,
+    mut record: ModelExportRecord,
+    unnumbered_output: &Path,
+    start_task: impl FnOnce(&ModelExportRecord) -> Result<CommandTaskStart, ForgeStudioError>,
+) -> Result<(ModelExportRecord, CommandTaskStart), ForgeStudioError> {
+    with_file_lock(&exports_path(data_root, &record.run_id), || {
+        let mut exports = load_model_exports(data_root, &record.run_id)?;
+        record.export_id = next_export_id(&exports);
+        let file_name = unnumbered_output.file_name().unwrap_or_default();
+        let numbered_name = format!("{}-{}", record.export_id, file_name.to_string_lossy());
+        record.output_path = unnumbered_output
+            .with_file_name(numbered_name)
+            .display()
+            .to_string();
+        let task = start_task(&record)?;
+        record.task_id = task.task_id.clone();
+        exports.push(record.clone());
+        save_model_exports(data_root, &record.run_id, &exports)?;
+        Ok((record, task))
+    })
+}
+
+/// One past the highest existing export number, so ids survive removed rows.
+fn next_export_id(exports: &[ModelExportRecord]) -> String {
+    let last_number = exports
+        .iter()
+        .filter_map(|row| row.export_id.strip_prefix("export-")?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    format!("export-{:04}", last_number + 1)
+}
+
+/// Sets the final `status` of the export's row, once its task has exited.
+pub(crate) fn finish_export_record(
+    data_root: &Path,
+    record: &ModelExportRecord,
+    status: &str,
+    finished_at: String,
+) -> Result<(), ForgeStudioError> {
+    with_file_lock(&exports_path(data_root, &record.run_id), || {
+        let mut exports = load_model_exports(data_root, &record.run_id)?;
+        if let Some(row) = exports
+            .iter_mut()
+            .find(|row| row.export_id == record.export_id)
+        {
+            row.status = status.to_string();
+            row.finished_at = Some(finished_at);
+        }
+        save_model_exports(data_root, &record.run_id, &exports)
+    })
+}
+
+/// Reads tracked exports; a run without `exports.json` has never been exported.
+pub(crate) fn load_model_exports(
+    data_root: &Path,
+    run_id: &str,
+) -> Result<Vec<ModelExportRecord>, ForgeStudioError> {
+    let payload_path = exports_path(data_root, run_id);
+    if !payload_path.exists() {
+        return Ok(vec![]);
+    }
+    let payload = read_json_file(&payload_path)?;
+    let rows = payload
+        .get("exports")
+        .cloned()
+        .unwrap_or(Value::Array(vec![]));
+    serde_json::from_value(rows).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse exports {}: {error}",
+            payload_path.display()
+        ))
+    })
+}
+
+fn save_model_exports(
+    data_root: &Path,
+    run_id: &str,
+    exports: &[ModelExportRecord],
+) -> Result<(), ForgeStudioError> {
+    let serialized = serde_json::to_value(exports).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize exports for '{run_id}': {error}"
+        ))
+    })?;
+    write_json_file(
+        &exports_path(data_root, run_id),
+        &json!({"exports": serialized}),
+    )
+}
+
+fn exports_path(data_root: &Path, run_id: &str) -> PathBuf {
+    run_dir(data_root, run_id).join(EXPORTS_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_export_id, register_export};
+    use crate::models::{CommandTaskStart, ModelExportRecord};
+    use std::fs;
+    use std::path::Path;
+
+    fn sample_export_record() -> ModelExportRecord {
+        ModelExportRecord {
+            export_id: "export-0001".to_string(),
+            run_id: "run-a".to_string(),
+            format: "onnx".to_string(),
+            quantization: Some("int8".to_string()),
+            source_model_path: "out/model.pt".to_string(),
+            output_path: "out/exports/model-int8.onnx".to_string(),
+            task_id: "task-1".to_string(),
+            status: "running".to_string(),
+            requested_at: "t0".to_string(),
+            finished_at: None,
+        }
+    }
+
+    #[test]
+    fn registered_exports_get_distinct_ids_and_files() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-model-export-{}", std::process::id()));
+        fs::create_dir_all(data_root.join("runs").join("run-a")).expect("run dir created");
+        let unnumbered_output = Path::new("out/exports/int8.onnx");
+        let start_task = |_: &ModelExportRecord| {
+            Ok(CommandTaskStart {
+                task_id: "task-1".to_string(),
+                estimated_total_seconds: 0,
+            })
+        };
+        let mut output_paths = Vec::new();
+        for _ in 0..2 {
+            let (record, _) = register_export(
+                &data_root,
+                sample_export_record(),
+                unnumbered_output,
+                start_task,
+            )
+            .expect("export should register");
+            output_paths.push(record.output_path);
+        }
+        assert_eq!(
+            output_paths,
+            [
+                "out/exports/export-0001-int8.onnx",
+                "out/exports/export-0002-int8.onnx"
+            ]
+        );
+        let mut gapped = sample_export_record();
+        gapped.export_id = "export-0007".to_string();
+        assert_eq!(next_export_id(&[gapped]), "export-0008");
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,235 @@
+//! Model artifact inspection for a quick sanity check before serving.
+//!
+//! Safetensors files carry a JSON header with every tensor's dtype and
+//! shape, so their parameter count is exact. Torch `.pt` archives store
+//! pickled metadata, which is not parsed here; their count is estimated
+//! from raw storage bytes. The artifact contract beside the model, when
+//! present, ties the file back to its training run.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::path_safety::confine_path;
+use crate::commands::run_details::ARTIFACT_CONTRACT_FILE_NAME;
+use crate::commands::run_store_io::{optional_string, read_json_file};
+use crate::commands::torch_archive_reader::list_archive_entries;
+use crate::models::{ForgeStudioError, ModelArtifactInspection, ModelTensorInfo};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use tauri::State;
+
+const SAFETENSORS_HEADER_LENGTH_BYTES: usize = 8;
+const MAX_SAFETENSORS_HEADER_BYTES: u64 = 100 * 1024 * 1024;
+const SAFETENSORS_METADATA_KEY: &str = "__metadata__";
+const TORCH_STORAGE_DIR_MARKER: &str = "/data/";
+const ASSUMED_TORCH_BYTES_PER_PARAMETER: u64 = 4;
+
+#[tauri::command]
+pub fn inspect_model_artifact(
+    model_path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<ModelArtifactInspection, ForgeStudioError> {
+    let resolved_path = confine_path(
+        Path::new(&model_path),
+        "Model path",
+        &settings.workspace_roots(),
+    )?;
+    let metadata = fs::metadata(&resolved_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read model file {}: {error}",
+            resolved_path.display()
+        ))
+    })?;
+    if !metadata.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Model path {} is not a file. Pick a .pt, .safetensors, or .onnx file.",
+            resolved_path.display()
+        )));
+    }
+    let extension = resolved_path
+        .extension()
+        .and_then(|value| value.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let mut inspection = empty_inspection(&resolved_path, &extension, metadata.len());
+    match extension.as_str() {
+        "safetensors" => apply_safetensors_tensors(&mut inspection, &resolved_path)?,
+        "pt" | "pth" => apply_torch_storage_estimate(&mut inspection, &resolved_path)?,
+        _ => inspection.notes.push(format!(
+            "Tensor layout is not inspected for .{extension} files."
+        )),
+    }
+    apply_artifact_contract(&mut inspection, &resolved_path)?;
+    Ok(inspection)
+}
+
+fn empty_inspection(model_path: &Path, extension: &str, size: u64) -> ModelArtifactInspection {
+    let format = match extension {
+        "safetensors" => "safetensors",
+        "pt" | "pth" => "torch",
+        "onnx" => "onnx",
+        _ => "unknown",
+    };
+    ModelArtifactInspection {
+        model_path: model_path.display().to_string(),
+        format: format.to_string(),
+        file_size_bytes: size,
+        parameter_count: None,
+        parameter_count_is_estimate: false,
+        dtype_tensor_counts: BTreeMap::new(),
+        tensors: vec![],
+        run_id: None,
+        config_hash: None,
+        notes: vec![],
+    }
+}
+
+fn apply_safetensors_tensors(
+    inspection: &mut ModelArtifactInspection,
+    model_path: &Path,
+) -> Result<(), ForgeStudioError> {
+    let header = read_safetensors_header(model_path)?;
+    let tensors = parse_safetensors_tensors(&header).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Invalid safetensors header in {}: {error}",
+            model_path.display()
+        ))
+    })?;
+    for tensor in &tensors {
+        *inspection
+            .dtype_tensor_counts
+            .entry(tensor.dtype.clone())
+            .or_default() += 1;
+    }
+    inspection.parameter_count = Some(tensors.iter().map(|tensor| tensor.parameter_count).sum());
+    inspection.tensors = tensors;
+    Ok(())
+}
+
+fn read_safetensors_header(model_path: &Path) -> Result<Value, ForgeStudioError> {
+    let read_error = |error: std::io::Error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", model_path.display()))
+    };
+    let mut file = File::open(model_path).map_err(read_error)?;
+    let mut length_bytes = [0_u8; SAFETENSORS_HEADER_LENGTH_BYTES];
+    file.read_exact(&mut length_bytes).map_err(read_error)?;
+    let header_length = u64::from_le_bytes(length_bytes);
+    if header_length > MAX_SAFETENSORS_HEADER_BYTES {
+        return Err(ForgeStudioError::parse(format!(
+            "Safetensors header in {} claims {header_length} bytes, which exceeds the \
+             {MAX_SAFETENSORS_HEADER_BYTES}-byte limit. The file is likely corrupt.",
+            model_path.display()
+        )));
+    }
+    let mut header_bytes = vec![0_u8; header_length as usize];
+    file.read_exact(&mut header_bytes).map_err(read_error)?;
+    serde_json::from_slice(&header_bytes).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Safetensors header in {} is not valid JSON: {error}",
+            model_path.display()
+        ))
+    })
+}
+
+/// Tensors are returned in name order so repeated inspections diff cleanly.
+fn parse_safetensors_tensors(header: &Value) -> Result<Vec<ModelTensorInfo>, ForgeStudioError> {
+    let entries = header
+        .as_object()
+        .ok_or_else(|| ForgeStudioError::parse("header must be a JSON object"))?;
+    let mut tensors = Vec::with_capacity(entries.len());
+    for (name, entry) in entries {
+        if name == SAFETENSORS_METADATA_KEY {
+            continue;
+        }
+        let dtype = entry
+            .get("dtype")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ForgeStudioError::parse(format!("tensor '{name}' has no dtype")))?;
+        let shape = entry
+            .get("shape")
+            .and_then(Value::as_array)
+            .and_then(|dims| dims.iter().map(Value::as_u64).collect::<Option<Vec<u64>>>())
+            .ok_or_else(|| {
+                ForgeStudioError::parse(format!("tensor '{name}' has no integer shape"))
+            })?;
+        tensors.push(ModelTensorInfo {
+            name: name.clone(),
+            dtype: dtype.to_string(),
+            parameter_count: shape.iter().product(),
+            shape,
+        });
+    }
+    tensors.sort_by(|left, right| left.name.cmp(&right.name));
+    Ok(tensors)
+}
+
+fn apply_torch_storage_estimate(
+    inspection: &mut ModelArtifactInspection,
+    model_path: &Path,
+) -> Result<(), ForgeStudioError> {
+    let storage_bytes: u64 = list_archive_entries(model_path)?
+        .iter()
+        .filter(|entry| entry.name.contains(TORCH_STORAGE_DIR_MARKER))
+        .map(|entry| entry.uncompressed_size)
+        .sum();
+    inspection.parameter_count = Some(storage_bytes / ASSUMED_TORCH_BYTES_PER_PARAMETER);
+    inspection.parameter_count_is_estimate = true;
+    inspection.notes.push(format!(
+        "Parameter count assumes float32 storages ({storage_bytes} storage bytes); \
+         per-tensor dtypes and shapes need the safetensors format."
+    ));
+    Ok(())
+}
+
+/// Mirrors the CLI lookup: the contract lives in the model's output directory.
+fn apply_artifact_contract(
+    inspection: &mut ModelArtifactInspection,
+    model_path: &Path,
+) -> Result<(), ForgeStudioError> {
+    let Some(contract_path) = model_path
+        .parent()
+        .map(|dir| dir.join(ARTIFACT_CONTRACT_FILE_NAME))
+        .filter(|path| path.is_file())
+    else {
+        inspection
+            .notes
+            .push("No artifact contract found beside the model.".to_string());
+        return Ok(());
+    };
+    let payload = read_json_file(&contract_path)?;
+    let contract: &Map<String, Value> = payload.as_object().ok_or_else(|| {
+        ForgeStudioError::parse(format!(
+            "Artifact contract {} must be a JSON object",
+            contract_path.display()
+        ))
+    })?;
+    inspection.run_id = optional_string(contract, "run_id");
+    inspection.config_hash = optional_string(contract, "config_hash");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_safetensors_tensors;
+    use serde_json::json;
+
+    #[test]
+    fn parse_safetensors_tensors_counts_parameters_and_skips_metadata() {
+        let header = json!({
+            "__metadata__": {"format": "pt"},
+            "lm_head.weight": {"dtype": "F32", "shape": [32, 16], "data_offsets": [0, 2048]},
+            "embed.weight": {"dtype": "BF16", "shape": [32, 16], "data_offsets": [2048, 3072]}
+        });
+        let tensors = parse_safetensors_tensors(&header).expect("header should parse");
+        assert_eq!(tensors.len(), 2);
+        assert_eq!(tensors[0].name, "embed.weight");
+        assert_eq!(tensors[0].parameter_count, 512);
+    }
+
+    #[test]
+    fn parse_safetensors_tensors_rejects_missing_shape() {
+        let header = json!({"w": {"dtype": "F32"}});
+        assert!(parse_safetensors_tensors(&header).is_err());
+    }
+}
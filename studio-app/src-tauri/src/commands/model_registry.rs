@@ -0,0 +1,264 @@
+//! Named model registry with versions and deployment stages.
+//!
+//! The registry is the authoritative answer to "which model is prod?": each
+//! name holds numbered versions pointing at a run's model file, and at most
+//! one version per name is in `production` because promoting a version
+//! retires the previous production version. Registrations and stage changes
+//! are stamped with the settings identity and recorded in the audit log.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::audit_log::record_audit_entry;
+use crate::commands::run_details::resolve_run_artifacts;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, write_json_file,
+};
+use crate::models::{ForgeStudioError, ModelRegistryFile, RegisteredModel, RegisteredModelVersion};
+use chrono::{SecondsFormat, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const MODEL_STAGES: [&str; 3] = ["staging", "production", "retired"];
+const INITIAL_STAGE: &str = "staging";
+const PRODUCTION_STAGE: &str = "production";
+const RETIRED_STAGE: &str = "retired";
+
+#[tauri::command]
+pub fn register_model(
+    data_root: String,
+    name: String,
+    run_id: String,
+    description: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RegisteredModelVersion, ForgeStudioError> {
+    let model_name = validate_model_name(&name)?;
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let model_path = resolve_run_artifacts(&lifecycle)?
+        .model_path
+        .filter(|path| Path::new(path).is_file())
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{run_id}' has no model file to register. Wait for training to complete."
+            ))
+        })?;
+    let mut registry = load_registry(&resolved_data_root)?;
+    let now = timestamp_now();
+    let model = registry
+        .models
+        .entry(model_name.clone())
+        .or_insert_with(|| RegisteredModel {
+            name: model_name.clone(),
+            created_at: now.clone(),
+            versions: vec![],
+        });
+    let next_version = model
+        .versions
+        .iter()
+        .map(|row| row.version)
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let author = settings.author();
+    let registered = RegisteredModelVersion {
+        name: model_name,
+        version: next_version,
+        run_id,
+        model_path,
+        stage: INITIAL_STAGE.to_string(),
+        description,
+        registered_at: now.clone(),
+        stage_updated_at: now,
+        registered_by: author.clone(),
+        stage_updated_by: author.clone(),
+    };
+    model.versions.push(registered.clone());
+    save_registry(&resolved_data_root, &registry)?;
+    let target = format!("{}@{next_version}", registered.name);
+    record_audit_entry(&resolved_data_root, author, "register_model", &target);
+    Ok(registered)
+}
+
+#[tauri::command]
+pub fn set_model_stage(
+    data_root: String,
+    name: String,
+    version: u32,
+    stage: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RegisteredModelVersion, ForgeStudioError> {
+    let stage = stage.trim().to_lowercase();
+    if !MODEL_STAGES.contains(&stage.as_str()) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Unknown model stage '{stage}'. Use one of: {}.",
+            MODEL_STAGES.join(", ")
+        )));
+    }
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let mut registry = load_registry(&resolved_data_root)?;
+    let model = registry.models.get_mut(&name).ok_or_else(|| {
+        ForgeStudioError::not_found(format!(
+            "Model '{name}' is not registered. Register a run's model first."
+        ))
+    })?;
+    let author = settings.author();
+    let now = timestamp_now();
+    let updated = apply_stage(
+        &mut model.versions,
+        version,
+        &stage,
+        &now,
+        author.as_deref(),
+    )
+    .ok_or_else(|| {
+        ForgeStudioError::not_found(format!("Model '{name}' has no version {version}."))
+    })?;
+    save_registry(&resolved_data_root, &registry)?;
+    let target = format!("{name}@{version}");
+    record_audit_entry(&resolved_data_root, author, "set_model_stage", &target);
+    Ok(updated)
+}
+
+#[tauri::command]
+pub fn list_registered_models(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<RegisteredModel>, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    Ok(load_registry(&resolved_data_root)?
+        .models
+        .into_values()
+        .collect())
+}
+
+/// Returns the newest version of `name` currently in `stage`, if any.
+#[tauri::command]
+pub fn get_model_by_stage(
+    data_root: String,
+    name: String,
+    stage: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Option<RegisteredModelVersion>, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let registry = load_registry(&resolved_data_root)?;
+    Ok(registry.models.get(&name).and_then(|model| {
+        model
+            .versions
+            .iter()
+            .filter(|row| row.stage == stage)
+            .max_by_key(|row| row.version)
+            .cloned()
+    }))
+}
+
+/// Sets the stage and retires any other production version when promoting,
+/// stamping each changed row with the time and author of the change.
+fn apply_stage(
+    versions: &mut [RegisteredModelVersion],
+    version: u32,
+    stage: &str,
+    now: &str,
+    author: Option<&str>,
+) -> Option<RegisteredModelVersion> {
+    versions.iter().position(|row| row.version == version)?;
+    for row in versions.iter_mut() {
+        let new_stage = if row.version == version {
+            stage
+        } else if stage == PRODUCTION_STAGE && row.stage == PRODUCTION_STAGE {
+            RETIRED_STAGE
+        } else {
+            continue;
+        };
+        row.stage = new_stage.to_string();
+        row.stage_updated_at = now.to_string();
+        row.stage_updated_by = author.map(str::to_string);
+    }
+    versions.iter().find(|row| row.version == version).cloned()
+}
+
+fn validate_model_name(name: &str) -> Result<String, ForgeStudioError> {
+    let trimmed = name.trim();
+    let valid = !trimmed.is_empty()
+        && trimmed
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || "-_.".contains(character));
+    if valid {
+        Ok(trimmed.to_string())
+    } else {
+        Err(ForgeStudioError::invalid_input(format!(
+            "Model name '{name}' is invalid. Use letters, digits, '-', '_', or '.'."
+        )))
+    }
+}
+
+fn registry_path(data_root: &Path) -> PathBuf {
+    data_root.join("models").join("registry.json")
+}
+
+pub(crate) fn load_registry(data_root: &Path) -> Result<ModelRegistryFile, ForgeStudioError> {
+    let payload_path = registry_path(data_root);
+    if !payload_path.exists() {
+        return Ok(ModelRegistryFile::default());
+    }
+    serde_json::from_value(read_json_file(&payload_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse model registry {}: {error}",
+            payload_path.display()
+        ))
+    })
+}
+
+fn save_registry(data_root: &Path, registry: &ModelRegistryFile) -> Result<(), ForgeStudioError> {
+    let payload_path = registry_path(data_root);
+    if let Some(parent) = payload_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(registry).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize model registry: {error}"))
+    })?;
+    write_json_file(&payload_path, &payload)
+}
+
+fn timestamp_now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_stage, validate_model_name};
+    use crate::models::RegisteredModelVersion;
+
+    fn version(number: u32, stage: &str) -> RegisteredModelVersion {
+        RegisteredModelVersion {
+            name: "chat-small".to_string(),
+            version: number,
+            run_id: format!("run-{number}"),
+            model_path: format!("out/{number}/model.pt"),
+            stage: stage.to_string(),
+            description: None,
+            registered_at: "t0".to_string(),
+            stage_updated_at: "t0".to_string(),
+            registered_by: None,
+            stage_updated_by: None,
+        }
+    }
+
+    #[test]
+    fn apply_stage_retires_previous_production_version() {
+        let mut versions = vec![version(1, "production"), version(2, "staging")];
+        let promoted =
+            apply_stage(&mut versions, 2, "production", "t1", Some("Ada")).expect("version exists");
+        assert_eq!(promoted.stage, "production");
+        assert_eq!(versions[0].stage, "retired");
+        assert_eq!(versions[0].stage_updated_by.as_deref(), Some("Ada"));
+        assert!(apply_stage(&mut versions, 9, "staging", "t2", None).is_none());
+    }
+
+    #[test]
+    fn validate_model_name_rejects_path_characters() {
+        assert!(validate_model_name("chat-small_v1.2").is_ok());
+        assert!(validate_model_name("../prod").is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! Finds the run a launched `train` task registers.
+//!
+//! The CLI assigns a run id only once training starts, so callers that need
+//! to act on the new run poll `runs/index.json` for an unseen run writing
+//! into the expected output dir.
+
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_store_io::load_run_lifecycle;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+const NEW_RUN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Identifies the run a launched `train` task registers, by output dir and unseen id.
+pub(crate) struct NewRunLinkRequest {
+    pub data_root: PathBuf,
+    pub task_store: CommandTaskStore,
+    pub task_id: String,
+    pub output_dir: String,
+    pub known_run_ids: HashSet<String>,
+}
+
+/// Calls `link` with the new run id once the task's run appears in the run index.
+pub(crate) fn spawn_new_run_linker<F>(request: NewRunLinkRequest, link: F)
+where
+    F: FnOnce(&Path, &str) + Send + 'static,
+{
+    thread::spawn(move || loop {
+        // Read status before scanning so a run registered just before exit is still linked.
+        let task_running = request
+            .task_store
+            .get_task_status(&request.task_id)
+            .map(|status| status.status == task_status_name(TaskLifecycleStatus::Running))
+            .unwrap_or(false);
+        if let Some(new_run_id) = find_new_run(&request) {
+            link(&request.data_root, &new_run_id);
+            return;
+        }
+        if !task_running {
+            return;
+        }
+        thread::sleep(NEW_RUN_POLL_INTERVAL);
+    });
+}
+
+fn find_new_run(request: &NewRunLinkRequest) -> Option<String> {
+    read_run_index(&request.data_root)
+        .ok()?
+        .into_iter()
+        .filter(|run_id| !request.known_run_ids.contains(run_id))
+        .find(|run_id| {
+            load_run_lifecycle(&request.data_root, run_id)
+                .map(|lifecycle| lifecycle.output_dir == request.output_dir)
+                .unwrap_or(false)
+        })
+}
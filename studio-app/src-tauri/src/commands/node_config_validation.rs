@@ -0,0 +1,185 @@
+//! Checks pipeline node configs against their node type's parameter schema.
+//!
+//! Blank optional values pass because the CLI defaults apply, and values
+//! holding a `${variable}` are checked once a run substitutes them; anything
+//! else must parse as the parameter's type and fall within its bounds and
+//! choices.
+//! Each problem names the node and field so the canvas can mark the exact
+//! input. Config keys outside the schema are ignored, as the args mapping does.
+
+use crate::commands::canvas_validation::{issue, PIPELINE_NODE_TYPES};
+use crate::commands::canvas_variables::has_placeholder;
+use crate::commands::pipeline_node_schema::bundled_parameters;
+use crate::commands::plugin_registry::plugin_node_type;
+use crate::models::{
+    PipelineCanvasIssue, PipelineNodeParameter, PipelineNodeSnapshot, PipelineParameterRange,
+};
+
+const CONFIG_ISSUE_KIND: &str = "invalid_config";
+
+/// One issue per bad field; nodes of unknown type are left to the graph checks.
+pub(crate) fn check_node_configs(nodes: &[PipelineNodeSnapshot]) -> Vec<PipelineCanvasIssue> {
+    let mut issues = Vec::new();
+    for node in nodes {
+        let parameters = if PIPELINE_NODE_TYPES.contains(&node.node_type.as_str()) {
+            bundled_parameters(&node.node_type)
+        } else if let Some(node_type) = plugin_node_type(&node.node_type) {
+            node_type.parameters
+        } else {
+            continue;
+        };
+        for parameter in parameters {
+            let value = node
+                .config
+                .get(&parameter.name)
+                .map_or("", |value| value.trim());
+            let Some(problem) = field_problem(&parameter, value) else {
+                continue;
+            };
+            let message = format!("Node '{}' field '{}' {problem}", node.id, parameter.name);
+            issues.push(PipelineCanvasIssue {
+                field: Some(parameter.name),
+                ..issue(CONFIG_ISSUE_KIND, vec![node.id.as_str()], message)
+            });
+        }
+    }
+    issues
+}
+
+/// What is wrong with one value, phrased to follow "field 'name'".
+fn field_problem(parameter: &PipelineNodeParameter, value: &str) -> Option<String> {
+    if value.is_empty() {
+        return parameter
+            .required
+            .then(|| "is required. Fill it in before exporting or running.".to_string());
+    }
+    if has_placeholder(value) {
+        return None;
+    }
+    let number = match parse_number(&parameter.value_type, value) {
+        Ok(number) => number,
+        Err(problem) => return Some(problem),
+    };
+    if let (Some(number), Some(range)) = (number, parameter.range) {
+        if !within(number, range) {
+            return Some(format!("must be {}, got {value}.", describe_range(range)));
+        }
+    }
+    if !parameter.allowed_values.is_empty()
+        && !parameter
+            .allowed_values
+            .iter()
+            .any(|allowed| allowed == value)
+    {
+        return Some(format!(
+            "must be one of {}, got '{value}'.",
+            parameter.allowed_values.join(", ")
+        ));
+    }
+    None
+}
+
+/// The numeric value for integer and float parameters; `None` for other types.
+fn parse_number(value_type: &str, value: &str) -> Result<Option<f64>, String> {
+    match value_type {
+        "integer" => value
+            .parse::<i64>()
+            .map(|number| Some(number as f64))
+            .map_err(|_| format!("must be a whole number, got '{value}'.")),
+        "float" => match value.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(Some(number)),
+            _ => Err(format!("must be a number, got '{value}'.")),
+        },
+        "boolean" if !["true", "false"].contains(&value.to_ascii_lowercase().as_str()) => {
+            Err(format!("must be true or false, got '{value}'."))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn within(number: f64, range: PipelineParameterRange) -> bool {
+    range.minimum.is_none_or(|minimum| number >= minimum)
+        && range
+            .exclusive_minimum
+            .is_none_or(|minimum| number > minimum)
+        && range.maximum.is_none_or(|maximum| number <= maximum)
+        && range
+            .exclusive_maximum
+            .is_none_or(|maximum| number < maximum)
+}
+
+fn describe_range(range: PipelineParameterRange) -> String {
+    let bounds = [
+        (">=", range.minimum),
+        (">", range.exclusive_minimum),
+        ("<=", range.maximum),
+        ("<", range.exclusive_maximum),
+    ];
+    let parts: Vec<String> = bounds
+        .iter()
+        .filter_map(|(operator, bound)| bound.map(|bound| format!("{operator} {bound}")))
+        .collect();
+    parts.join(" and ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_node_configs;
+    use crate::models::PipelineNodeSnapshot;
+
+    fn node(id: &str, node_type: &str, config: &[(&str, &str)]) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: config
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn check_node_configs_reports_each_bad_field() {
+        let nodes = vec![
+            node(
+                "train",
+                "train",
+                &[
+                    ("dataset", "demo"),
+                    ("output_dir", " "),
+                    ("epochs", "two"),
+                    ("dropout", "1"),
+                    ("position_embedding_type", "rotary"),
+                    ("vocabulary_size", ""),
+                ],
+            ),
+            node(
+                "ingest",
+                "ingest",
+                &[("source", "./src"), ("dataset", "demo")],
+            ),
+            node("mystery", "deploy", &[]),
+        ];
+        let issues = check_node_configs(&nodes);
+        let fields: Vec<(&str, Option<&str>)> = issues
+            .iter()
+            .map(|issue| (issue.node_ids[0].as_str(), issue.field.as_deref()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("train", Some("output_dir")),
+                ("train", Some("epochs")),
+                ("train", Some("dropout")),
+                ("train", Some("position_embedding_type")),
+            ]
+        );
+        assert_eq!(
+            issues[2].message,
+            "Node 'train' field 'dropout' must be >= 0 and < 1, got 1."
+        );
+    }
+}
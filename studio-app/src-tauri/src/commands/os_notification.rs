@@ -0,0 +1,59 @@
+//! Best-effort desktop notifications through the platform's own tooling.
+//!
+//! Notifications shell out to what each OS ships (`osascript` on macOS,
+//! `notify-send` on Linux, PowerShell on Windows) instead of adding a
+//! notification plugin. A missing tool only means no popup, since callers
+//! emit the in-app event anyway, so failures are ignored rather than reported.
+
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Shows a desktop notification without blocking the caller on the platform tool.
+pub(crate) fn send_os_notification(title: &str, body: &str) {
+    let mut command = platform_command(title, body);
+    command.stdout(Stdio::null()).stderr(Stdio::null());
+    // Waiting on the child reaps it; the Windows balloon script sleeps while it shows.
+    thread::spawn(move || {
+        let _ = command.status();
+    });
+}
+
+#[cfg(target_os = "macos")]
+fn platform_command(title: &str, body: &str) -> Command {
+    let script = format!(
+        "display notification {} with title {}",
+        applescript_string(body),
+        applescript_string(title)
+    );
+    let mut command = Command::new("osascript");
+    command.args(["-e", &script]);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn platform_command(title: &str, body: &str) -> Command {
+    // A balloon tip from a transient tray icon needs no app registration.
+    let script = format!(
+        "Add-Type -AssemblyName System.Windows.Forms; \
+         $icon = New-Object System.Windows.Forms.NotifyIcon; \
+         $icon.Icon = [System.Drawing.SystemIcons]::Information; $icon.Visible = $true; \
+         $icon.ShowBalloonTip(5000, '{}', '{}', 'Info'); Start-Sleep -Seconds 5; $icon.Dispose()",
+        title.replace('\'', "''"),
+        body.replace('\'', "''")
+    );
+    let mut command = Command::new("powershell");
+    command.args(["-NoProfile", "-Command", &script]);
+    command
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn platform_command(title: &str, body: &str) -> Command {
+    let mut command = Command::new("notify-send");
+    command.args(["--app-name=Forge Studio", title, body]);
+    command
+}
+
+#[cfg(target_os = "macos")]
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
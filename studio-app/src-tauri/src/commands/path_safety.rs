@@ -0,0 +1,136 @@
+//! Confines user-supplied paths to the registered workspaces.
+//!
+//! Commands take data roots, history files, and export or import paths
+//! straight from the frontend. Each one is resolved like any other
+//! workspace path, then canonicalized (symlinks and `..` resolved, even for
+//! a tail that does not exist yet) and accepted only when it lies inside the
+//! workspace Studio runs from or a folder listed in the `workspace_roots` or
+//! `data_roots` setting. Commands take the roots from the settings store and
+//! use the canonical path from then on, so a checked path cannot point
+//! somewhere else by the time it is read.
+
+use crate::commands::run_store_io::{resolve_workspace_path, workspace_root_dir};
+use crate::models::ForgeStudioError;
+use std::path::{Component, Path, PathBuf};
+
+/// Folders user-supplied paths may lie in besides the workspace Studio runs from.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WorkspaceRoots {
+    registered: Vec<PathBuf>,
+}
+
+impl WorkspaceRoots {
+    /// Roots from the `workspace_roots` and `data_roots` settings; relative
+    /// ones anchor to the workspace root.
+    pub(crate) fn new(workspace_roots: &[String]) -> Self {
+        Self {
+            registered: workspace_roots
+                .iter()
+                .map(|root| resolve_workspace_path(root))
+                .collect(),
+        }
+    }
+
+    fn allowed_roots(&self) -> Vec<PathBuf> {
+        std::iter::once(workspace_root_dir())
+            .chain(self.registered.iter().cloned())
+            .map(|root| canonicalize_lenient(&root))
+            .collect()
+    }
+}
+
+/// Canonical form of `path` when it lies inside one of `roots`; relative
+/// paths anchor to the workspace root. `label` names the path in the error.
+pub(crate) fn confine_path(
+    path: &Path,
+    label: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PathBuf, ForgeStudioError> {
+    if path.to_string_lossy().trim().is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "{label} is empty. Choose a path inside a workspace."
+        )));
+    }
+    let absolute_path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        workspace_root_dir().join(path)
+    };
+    let canonical_path = canonicalize_lenient(&absolute_path);
+    let roots = roots.allowed_roots();
+    if roots.iter().any(|root| canonical_path.starts_with(root)) {
+        return Ok(canonical_path);
+    }
+    let root_list = roots
+        .iter()
+        .map(|root| root.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(ForgeStudioError::invalid_input(format!(
+        "{label} {} is outside the registered workspaces ({root_list}). Add its folder to workspace_roots in settings, or choose a path inside a workspace.",
+        canonical_path.display()
+    )))
+}
+
+/// Canonicalizes the longest existing prefix of absolute `path`, then applies
+/// the remaining components lexically; they do not exist, so hold no symlinks.
+fn canonicalize_lenient(path: &Path) -> PathBuf {
+    let components: Vec<Component> = path.components().collect();
+    for split in (1..=components.len()).rev() {
+        let prefix: PathBuf = components[..split].iter().collect();
+        let Ok(mut canonical_path) = prefix.canonicalize() else {
+            continue;
+        };
+        for component in &components[split..] {
+            match component {
+                Component::ParentDir => {
+                    canonical_path.pop();
+                }
+                Component::Normal(name) => canonical_path.push(name),
+                _ => {}
+            }
+        }
+        return canonical_path;
+    }
+    path.to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{confine_path, WorkspaceRoots};
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn paths_outside_the_workspaces_are_rejected() {
+        let temp_root =
+            std::env::temp_dir().join(format!("forge-path-safety-{}", std::process::id()));
+        fs::create_dir_all(&temp_root).expect("temp dir should be created");
+        let inside = temp_root.join("runs/../exports/new.json");
+        let roots = WorkspaceRoots::new(&[temp_root.display().to_string()]);
+        assert_eq!(
+            confine_path(&inside, "Output path", &roots),
+            Ok(temp_root
+                .canonicalize()
+                .expect("temp dir should canonicalize")
+                .join("exports/new.json"))
+        );
+        let escaping = temp_root.join("../../../../../../etc/passwd");
+        let error = confine_path(&escaping, "History file", &roots)
+            .expect_err("traversal should be rejected");
+        assert_eq!(error.code(), "invalid_input");
+        assert!(error
+            .to_string()
+            .starts_with("History file /etc/passwd is outside"));
+
+        #[cfg(unix)]
+        {
+            let link = temp_root.join("root-link");
+            std::os::unix::fs::symlink("/", &link).expect("symlink should be created");
+            assert!(confine_path(&link.join("etc/hosts"), "Data root", &roots).is_err());
+        }
+        assert!(confine_path(Path::new(" "), "Data root", &roots).is_err());
+        assert!(confine_path(Path::new(".forge"), "Data root", &roots).is_ok());
+        fs::remove_dir_all(&temp_root).expect("temp dir should be removed");
+    }
+}
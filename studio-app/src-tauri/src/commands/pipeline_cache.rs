@@ -0,0 +1,198 @@
+//! Pipeline step cache so reruns skip nodes whose inputs have not changed.
+//!
+//! A node's fingerprint hashes its forge args, which carry its whole config,
+//! the version id of the dataset it reads, and its upstream nodes'
+//! fingerprints. Catalog version ids are digests of the records, so a dataset
+//! rewritten upstream changes the fingerprint even when no config did.
+//! Fingerprints are taken just before a node would start, once upstream
+//! nodes have written their versions. Ingest and custom nodes read inputs
+//! the cache cannot hash, so they always run.
+//!
+//! Successful executions are kept in `<data_root>/pipeline_runs/cache.json`.
+//! A node that moves its dataset to a new version (filter) is also recorded
+//! under the fingerprint of that new version, so rerunning it against its
+//! own output is a hit rather than a second filtering pass.
+
+use crate::commands::content_hash::sha256_hex;
+use crate::commands::dataset_store_io::{dataset_root, read_catalog};
+use crate::commands::forge_commands::flag_value;
+use crate::commands::run_store_io::{read_json_file, resolve_workspace_path, write_json_file};
+use crate::models::{ForgeStudioError, PipelineCacheEntry, PipelineRunNode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const PIPELINE_CACHE_FILE: &str = "pipeline_runs/cache.json";
+/// Node types whose inputs are not all visible in args and dataset versions.
+const UNCACHEABLE_NODE_TYPES: [&str; 2] = ["ingest", "custom"];
+
+#[derive(Default, Serialize, Deserialize)]
+struct PipelineCacheFile {
+    /// Keyed by fingerprint.
+    entries: BTreeMap<String, PipelineCacheEntry>,
+}
+
+/// Fingerprint of `node` as it would run now; `None` when it cannot be cached.
+pub(crate) fn node_fingerprint(
+    data_root: &Path,
+    node: &PipelineRunNode,
+    nodes: &[PipelineRunNode],
+) -> Option<String> {
+    if UNCACHEABLE_NODE_TYPES.contains(&node.node_type.as_str()) {
+        return None;
+    }
+    let input_version = match flag_value(&node.args, "--dataset") {
+        Some(dataset_name) => Some(input_version(data_root, dataset_name, &node.args)?),
+        None => None,
+    };
+    let upstream: Vec<&str> = nodes
+        .iter()
+        .filter(|other| node.depends_on.contains(&other.node_id))
+        .filter_map(|other| other.fingerprint.as_deref())
+        .collect();
+    let payload = json!({"args": node.args, "input_version": input_version, "upstream": upstream});
+    Some(sha256_hex(payload.to_string().as_bytes()))
+}
+
+/// Earlier successful execution matching `fingerprint` whose outputs still exist.
+pub(crate) fn cached_execution(
+    data_root: &Path,
+    fingerprint: &str,
+    node: &PipelineRunNode,
+) -> Option<PipelineCacheEntry> {
+    let output_missing = flag_value(&node.args, "--output-dir")
+        .is_some_and(|output_dir| !resolve_workspace_path(output_dir).is_dir());
+    if output_missing {
+        return None;
+    }
+    load_cache(data_root).entries.remove(fingerprint)
+}
+
+/// Records a completed node under its start fingerprint and, if its dataset
+/// moved to a new version, under the fingerprint of that version too.
+pub(crate) fn record_execution(
+    data_root: &Path,
+    node: &PipelineRunNode,
+    nodes: &[PipelineRunNode],
+    pipeline_run_id: &str,
+) -> Result<(), ForgeStudioError> {
+    let Some(fingerprint) = node.fingerprint.clone() else {
+        return Ok(());
+    };
+    let entry = PipelineCacheEntry {
+        fingerprint: fingerprint.clone(),
+        node_id: node.node_id.clone(),
+        args: node.args.clone(),
+        pipeline_run_id: pipeline_run_id.to_string(),
+        completed_at: node.finished_at.clone().unwrap_or_default(),
+        artifacts: node.artifacts.clone(),
+        outputs: node.outputs.clone(),
+    };
+    let mut cache = load_cache(data_root);
+    if let Some(output_fingerprint) = node_fingerprint(data_root, node, nodes) {
+        let output_entry = PipelineCacheEntry {
+            fingerprint: output_fingerprint.clone(),
+            ..entry.clone()
+        };
+        cache.entries.insert(output_fingerprint, output_entry);
+    }
+    cache.entries.insert(fingerprint, entry);
+    let cache_path = cache_path(data_root);
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(&cache).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize pipeline cache: {error}"))
+    })?;
+    write_json_file(&cache_path, &payload)
+}
+
+/// The pinned `--version-id`, else the dataset's latest version.
+fn input_version(data_root: &Path, dataset_name: &str, args: &[String]) -> Option<String> {
+    if let Some(version_id) = flag_value(args, "--version-id") {
+        return Some(version_id.to_string());
+    }
+    let catalog = read_catalog(&dataset_root(&data_root.to_string_lossy(), dataset_name)).ok()?;
+    catalog
+        .get("latest_version")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// A missing or unreadable cache is treated as empty; the next write replaces it.
+fn load_cache(data_root: &Path) -> PipelineCacheFile {
+    read_json_file(&cache_path(data_root))
+        .ok()
+        .and_then(|payload| serde_json::from_value(payload).ok())
+        .unwrap_or_default()
+}
+
+fn cache_path(data_root: &Path) -> PathBuf {
+    data_root.join(PIPELINE_CACHE_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cached_execution, node_fingerprint, record_execution};
+    use crate::models::{PipelineNodeOutputs, PipelineRunNode};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::Path;
+
+    fn filter_node() -> PipelineRunNode {
+        PipelineRunNode {
+            node_id: "filter".to_string(),
+            node_type: "filter".to_string(),
+            title: "Filter".to_string(),
+            args: ["filter", "--dataset", "demo", "--language", "en"]
+                .map(str::to_string)
+                .to_vec(),
+            depends_on: vec![],
+            conditions: BTreeMap::new(),
+            group_id: None,
+            status: "completed".to_string(),
+            task_id: None,
+            progress_percent: 100.0,
+            started_at: None,
+            finished_at: Some("2026-01-01T00:00:00Z".to_string()),
+            error: None,
+            fingerprint: None,
+            cached_from_run_id: None,
+            exit_code: None,
+            artifacts: vec![],
+            outputs: PipelineNodeOutputs::default(),
+        }
+    }
+
+    fn set_latest_version(data_root: &Path, version_id: &str) {
+        let dataset_dir = data_root.join("datasets/demo");
+        fs::create_dir_all(&dataset_dir).expect("dataset dir should be created");
+        let catalog = json!({"latest_version": version_id, "versions": []});
+        fs::write(dataset_dir.join("catalog.json"), catalog.to_string())
+            .expect("catalog should be written");
+    }
+
+    #[test]
+    fn filter_rerun_hits_cache_until_dataset_changes() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-pipeline-cache-{}", std::process::id()));
+        set_latest_version(&data_root, "v-raw");
+        let mut node = filter_node();
+        node.fingerprint = node_fingerprint(&data_root, &node, &[]);
+        set_latest_version(&data_root, "v-filtered");
+        record_execution(&data_root, &node, &[], "pipeline-run-0001").expect("cache written");
+
+        let rerun = node_fingerprint(&data_root, &node, &[]).expect("filter is cacheable");
+        let hit = cached_execution(&data_root, &rerun, &node).expect("output version is cached");
+        assert_eq!(hit.pipeline_run_id, "pipeline-run-0001");
+        set_latest_version(&data_root, "v-reingested");
+        let changed = node_fingerprint(&data_root, &node, &[]).expect("filter is cacheable");
+        assert!(cached_execution(&data_root, &changed, &node).is_none());
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
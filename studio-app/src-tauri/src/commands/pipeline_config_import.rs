@@ -0,0 +1,259 @@
+//! Imports forge CLI run specs onto the pipeline canvas.
+//!
+//! This is the reverse of the YAML canvas export: each `forge run-spec` step
+//! becomes a node, chained in step order because run specs execute
+//! sequentially, and laid out on a grid that wraps every few nodes. Step args
+//! use the same keys as node configs, and a step without a dataset takes
+//! `defaults.dataset` as forge does. A step with args its node type has no
+//! field for is imported as a custom step running the same command line, so
+//! no setting is silently dropped. Forge pipelines are YAML only; there is no
+//! TOML pipeline format to import.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_import::resolve_canvas_path;
+use crate::commands::pipeline_node_schema::bundled_parameters;
+use crate::commands::run_spec_file::{read_run_spec, RunSpecStep};
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{
+    ForgeStudioError, PipelineConfigImport, PipelineEdgeSnapshot, PipelineNodeSnapshot,
+};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use tauri::State;
+
+/// Step commands whose canvas node type is not the command name.
+const STEP_NODE_TYPES: [(&str, &str); 3] = [
+    ("export-training", "export"),
+    ("versions", "custom"),
+    ("hardware-profile", "custom"),
+];
+/// Default node titles, matching the ones the editor gives new nodes.
+const NODE_TITLES: [(&str, &str); 6] = [
+    ("ingest", "Ingest"),
+    ("filter", "Filter"),
+    ("train", "Train"),
+    ("export", "Export Training"),
+    ("chat", "Chat"),
+    ("custom", "Custom Step"),
+];
+const LAYOUT_COLUMNS: usize = 4;
+const LAYOUT_ORIGIN: f64 = 80.0;
+/// Node width 190 plus an 80px gap for the edge between neighbours.
+const LAYOUT_COLUMN_SPACING: f64 = 270.0;
+const LAYOUT_ROW_SPACING: f64 = 160.0;
+
+#[tauri::command]
+pub fn import_pipeline_config(
+    data_root: String,
+    path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineConfigImport, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let source_path = resolve_canvas_path(&data_root, &path, &roots)?;
+    import_config_file(&source_path)
+}
+
+/// Reads the run spec at an already confined `source_path`.
+fn import_config_file(source_path: &Path) -> Result<PipelineConfigImport, ForgeStudioError> {
+    if source_path
+        .extension()
+        .is_some_and(|extension| extension == "toml")
+    {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Pipeline config import failed: {} is TOML, but forge pipelines are YAML run specs. Rewrite it as a run spec and import that.",
+            source_path.display()
+        )));
+    }
+    let text = fs::read_to_string(source_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Pipeline config import failed: could not read {}: {error}",
+            source_path.display()
+        ))
+    })?;
+    let failed = |reason: ForgeStudioError| {
+        ForgeStudioError::parse(format!(
+            "Pipeline config import failed: {}: {reason}",
+            source_path.display()
+        ))
+    };
+    let spec = read_run_spec(&text).map_err(failed)?;
+    let mut warnings = Vec::new();
+    if let Some(data_root) = spec.default_data_root {
+        warnings.push(format!(
+            "defaults.data_root '{data_root}' was not imported; the canvas runs against Studio's data root."
+        ));
+    }
+    let mut nodes = Vec::with_capacity(spec.steps.len());
+    for (index, step) in spec.steps.into_iter().enumerate() {
+        let node = step_node(index, step, spec.default_dataset.as_deref(), &mut warnings);
+        nodes.push(node.map_err(failed)?);
+    }
+    let edges = nodes
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| PipelineEdgeSnapshot {
+            id: format!("edge-{}", index + 1),
+            source_node_id: pair[0].id.clone(),
+            target_node_id: pair[1].id.clone(),
+            condition: None,
+        })
+        .collect();
+    Ok(PipelineConfigImport {
+        source_path: source_path.display().to_string(),
+        start_node_id: nodes.first().map(|node| node.id.clone()),
+        nodes,
+        edges,
+        warnings,
+    })
+}
+
+fn step_node(
+    index: usize,
+    step: RunSpecStep,
+    default_dataset: Option<&str>,
+    warnings: &mut Vec<String>,
+) -> Result<PipelineNodeSnapshot, ForgeStudioError> {
+    let context = format!("step #{}", index + 1);
+    let command = step.command.as_str();
+    let mut args = step.args;
+    let mut node_type = STEP_NODE_TYPES
+        .iter()
+        .find(|(step_command, _)| *step_command == command)
+        .map_or(command, |(_, node_type)| node_type);
+    if node_type == "export" {
+        drop_include_metadata(&mut args, &context, warnings);
+    }
+    let unknown = unknown_keys(node_type, &args);
+    if !unknown.is_empty() && node_type != "custom" {
+        warnings.push(format!(
+            "{context} ({command}) sets {}, which the {} node has no field for; it was imported as a Custom Step.",
+            unknown.join(", "),
+            node_title(node_type)
+        ));
+        node_type = "custom";
+    }
+    let config = if node_type == "custom" {
+        let command_line = custom_command_line(command, args, default_dataset, &context)?;
+        BTreeMap::from([("args".to_string(), command_line)])
+    } else {
+        let mut config: BTreeMap<String, String> = args.into_iter().collect();
+        if let Some(dataset_name) = default_dataset.filter(|_| !config.contains_key("dataset")) {
+            config.insert("dataset".to_string(), dataset_name.to_string());
+        }
+        config
+    };
+    let column = (index % LAYOUT_COLUMNS) as f64;
+    let row = (index / LAYOUT_COLUMNS) as f64;
+    Ok(PipelineNodeSnapshot {
+        id: format!("{node_type}-{}", index + 1),
+        node_type: node_type.to_string(),
+        title: node_title(node_type).to_string(),
+        canvas_x: LAYOUT_ORIGIN + column * LAYOUT_COLUMN_SPACING,
+        canvas_y: LAYOUT_ORIGIN + row * LAYOUT_ROW_SPACING,
+        config,
+    })
+}
+
+/// Canvas export nodes always pass --include-metadata, so the setting is dropped.
+fn drop_include_metadata(
+    args: &mut Vec<(String, String)>,
+    context: &str,
+    warnings: &mut Vec<String>,
+) {
+    let Some(position) = args.iter().position(|(key, _)| key == "include_metadata") else {
+        return;
+    };
+    if args.remove(position).1 == "false" {
+        warnings.push(format!(
+            "{context} turns off include_metadata, but Export Training nodes always include it."
+        ));
+    }
+}
+
+/// Arg keys the node type has no config field for.
+fn unknown_keys(node_type: &str, args: &[(String, String)]) -> Vec<String> {
+    let parameters = bundled_parameters(node_type);
+    args.iter()
+        .map(|(key, _)| key.clone())
+        .filter(|key| !parameters.iter().any(|parameter| parameter.name == *key))
+        .collect()
+}
+
+/// The `forge` args a custom node runs; ingest's `source` is positional.
+fn custom_command_line(
+    command: &str,
+    mut args: Vec<(String, String)>,
+    default_dataset: Option<&str>,
+    context: &str,
+) -> Result<String, ForgeStudioError> {
+    let has_dataset = args.iter().any(|(key, _)| key == "dataset");
+    if let Some(dataset_name) = default_dataset.filter(|_| !has_dataset) {
+        if command != "hardware-profile" {
+            args.insert(0, ("dataset".to_string(), dataset_name.to_string()));
+        }
+    }
+    if let Some(position) = args.iter().position(|(key, _)| key == "source") {
+        let source = args.remove(position);
+        args.insert(0, source);
+    }
+    let mut tokens = vec![command.to_string()];
+    for (key, value) in args {
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "{context} sets '{key}' to '{value}', which a Custom Step cannot pass because its args split on spaces. Remove the spaces or use a node type with that field."
+            )));
+        }
+        match (command, key.as_str(), value.as_str()) {
+            ("ingest", "source", _) => tokens.push(value),
+            (_, _, "false") => {}
+            (_, _, "true") => tokens.push(format!("--{}", key.replace('_', "-"))),
+            _ => tokens.extend([format!("--{}", key.replace('_', "-")), value]),
+        }
+    }
+    Ok(tokens.join(" "))
+}
+
+fn node_title(node_type: &str) -> &'static str {
+    NODE_TITLES
+        .iter()
+        .find(|(candidate, _)| *candidate == node_type)
+        .map_or("Custom Step", |(_, title)| title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::import_config_file;
+    use std::fs;
+
+    #[test]
+    fn run_spec_steps_become_chained_nodes() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-config-import-{}", std::process::id()));
+        fs::create_dir_all(&data_root).expect("temp dir should be created");
+        let spec = "version: 1\ndefaults:\n  dataset: demo\nsteps:\n  - command: ingest\n    source: raw/\n  - command: filter\n    min_quality: 0.5\n  - command: train\n    output_dir: out/run\n    weight_decay: 0.01\n  - command: export-training\n    args:\n      output_dir: out/shards\n      shard_size: 1000\n      include_metadata: true\n  - command: versions\n";
+        fs::write(data_root.join("nightly.yaml"), spec).expect("spec should be written");
+
+        let imported =
+            import_config_file(&data_root.join("nightly.yaml")).expect("run spec should import");
+        let types: Vec<&str> = imported
+            .nodes
+            .iter()
+            .map(|node| node.node_type.as_str())
+            .collect();
+        assert_eq!(types, ["ingest", "filter", "custom", "export", "custom"]);
+        assert_eq!(imported.start_node_id.as_deref(), Some("ingest-1"));
+        assert_eq!(imported.edges.len(), 4);
+        assert_eq!(imported.nodes[1].config["dataset"], "demo");
+        assert_eq!(
+            imported.nodes[2].config["args"],
+            "train --dataset demo --output-dir out/run --weight-decay 0.01"
+        );
+        assert!(!imported.nodes[3].config.contains_key("include_metadata"));
+        assert_eq!(imported.nodes[4].config["args"], "versions --dataset demo");
+        assert_eq!(imported.nodes[4].canvas_y, 240.0);
+        assert_eq!(imported.warnings.len(), 1);
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,112 @@
+//! Dry run of a pipeline canvas: the forge commands a run would execute.
+//!
+//! The plan goes through the same variable substitution, validation, and
+//! selection as `run_pipeline_canvas`, so a canvas that plans cleanly starts
+//! cleanly, and nothing is launched or written. Durations come from the task
+//! store's running per-command averages, falling back to its defaults for
+//! commands not run this session. Cache reuse is decided while a run
+//! executes, from what upstream nodes produce, so the plan lists every step.
+
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::pipeline_runs::{checked_plan, left_out_node_ids};
+use crate::models::{
+    ForgeStudioError, PipelineCanvasState, PipelineEdgeSnapshot, PipelineNodeGroup,
+    PipelineNodeSnapshot, PipelinePlanStep, PipelineRunOptions, PipelineRunPlan,
+};
+use std::collections::HashSet;
+use tauri::State;
+
+#[tauri::command]
+pub fn plan_pipeline_canvas(
+    nodes: Vec<PipelineNodeSnapshot>,
+    edges: Vec<PipelineEdgeSnapshot>,
+    start_node_id: String,
+    options: Option<PipelineRunOptions>,
+    groups: Option<Vec<PipelineNodeGroup>>,
+    task_store: State<'_, CommandTaskStore>,
+) -> Result<PipelineRunPlan, ForgeStudioError> {
+    let canvas = PipelineCanvasState {
+        start_node_id: Some(start_node_id),
+        nodes,
+        edges,
+        groups: groups.unwrap_or_default(),
+    };
+    build_run_plan(canvas, options.unwrap_or_default(), task_store.inner())
+}
+
+fn build_run_plan(
+    mut canvas: PipelineCanvasState,
+    mut options: PipelineRunOptions,
+    task_store: &CommandTaskStore,
+) -> Result<PipelineRunPlan, ForgeStudioError> {
+    let start_node_id = canvas.start_node_id.clone().unwrap_or_default();
+    let (selected, planned_ids) = checked_plan(&mut canvas, &start_node_id, &mut options)?;
+    let selected_ids: HashSet<&str> = selected.iter().map(|node| node.node_id.as_str()).collect();
+    let (excluded_node_ids, unreachable_node_ids) =
+        left_out_node_ids(&canvas.nodes, &selected_ids, &planned_ids);
+    let steps: Vec<PipelinePlanStep> = selected
+        .into_iter()
+        .map(|node| PipelinePlanStep {
+            estimated_seconds: node
+                .args
+                .first()
+                .map_or(0, |command| task_store.estimate_for_command(command)),
+            node_id: node.node_id,
+            node_type: node.node_type,
+            title: node.title,
+            args: node.args,
+            depends_on: node.depends_on,
+            group_id: node.group_id,
+        })
+        .collect();
+    Ok(PipelineRunPlan {
+        total_estimated_seconds: steps.iter().map(|step| step.estimated_seconds).sum(),
+        steps,
+        unreachable_node_ids,
+        excluded_node_ids,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_run_plan;
+    use crate::commands::forge_task_store::CommandTaskStore;
+    use crate::models::{PipelineCanvasState, PipelineRunOptions};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn plan_resolves_variables_and_estimates_each_step() {
+        let node = |id: &str, node_type: &str, config| {
+            json!({
+                "id": id, "type": node_type, "title": id,
+                "canvas_x": 0.0, "canvas_y": 0.0, "config": config,
+            })
+        };
+        let canvas: PipelineCanvasState = serde_json::from_value(json!({
+            "start_node_id": "ingest",
+            "nodes": [
+                node("ingest", "ingest", json!({"dataset": "${name}", "source": "raw.jsonl"})),
+                node("filter", "filter", json!({"dataset": "${name}"})),
+                node("orphan", "filter", json!({"dataset": "other"})),
+            ],
+            "edges": [{"id": "e1", "source_node_id": "ingest", "target_node_id": "filter"}],
+        }))
+        .expect("canvas should parse");
+        let options = PipelineRunOptions {
+            variables: BTreeMap::from([("name".to_string(), "demo".to_string())]),
+            ..PipelineRunOptions::default()
+        };
+        let plan = build_run_plan(canvas, options, &CommandTaskStore::default())
+            .expect("plan should build");
+        let order: Vec<&str> = plan
+            .steps
+            .iter()
+            .map(|step| step.node_id.as_str())
+            .collect();
+        assert_eq!(order, ["ingest", "filter"]);
+        assert_eq!(plan.steps[1].args, ["filter", "--dataset", "demo"]);
+        assert_eq!(plan.total_estimated_seconds, 90);
+        assert_eq!(plan.unreachable_node_ids, ["orphan"]);
+    }
+}
@@ -0,0 +1,299 @@
+//! Audit history of pipeline canvas executions.
+//!
+//! The live record under `pipeline_runs/` is scheduler state; history under
+//! `outputs/pipeline-runs/` is what a team reviews later. An entry is written
+//! when a run starts, holding the graph as it runs and any variable values,
+//! and completed with each node's exit state, duration, and artifacts when
+//! the run ends. Each node also records the dataset version or training run
+//! it produced, so the canvas can open the matching dashboard from a node.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::version_dir;
+use crate::commands::forge_commands::flag_value;
+use crate::commands::pipeline_runs::FAILED_STATUS;
+use crate::commands::run_store_io::{read_json_file, resolve_data_root_path, write_json_file};
+use crate::models::{
+    ForgeStudioError, PipelineCanvasState, PipelineNodeOutputs, PipelineRunHistory,
+    PipelineRunHistorySummary, PipelineRunNode, PipelineRunNodeHistory, PipelineRunRecord,
+};
+use chrono::DateTime;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const PIPELINE_HISTORY_DIR: &str = "outputs/pipeline-runs";
+/// `key=value` lines train prints that name files or directories it wrote.
+const TRAIN_ARTIFACT_SUFFIXES: [&str; 2] = ["_path", "_dir"];
+
+#[tauri::command]
+pub fn list_pipeline_runs(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<PipelineRunHistorySummary>, ForgeStudioError> {
+    let data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    pipeline_run_summaries(&data_root)
+}
+
+#[tauri::command]
+pub fn get_pipeline_run(
+    data_root: String,
+    pipeline_run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineRunHistory, ForgeStudioError> {
+    let data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    pipeline_run_history(&data_root, &pipeline_run_id)
+}
+
+/// Newest first, read from an already resolved `data_root`.
+pub(crate) fn pipeline_run_summaries(
+    data_root: &Path,
+) -> Result<Vec<PipelineRunHistorySummary>, ForgeStudioError> {
+    let history_dir = data_root.join(PIPELINE_HISTORY_DIR);
+    if !history_dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let entries = fs::read_dir(&history_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", history_dir.display()))
+    })?;
+    let mut summaries = Vec::new();
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        if entry_path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            summaries.push(summarize(load_history_file(&entry_path)?));
+        }
+    }
+    summaries.sort_by(|left, right| {
+        (&right.created_at, &right.pipeline_run_id).cmp(&(&left.created_at, &left.pipeline_run_id))
+    });
+    Ok(summaries)
+}
+
+pub(crate) fn pipeline_run_history(
+    data_root: &Path,
+    pipeline_run_id: &str,
+) -> Result<PipelineRunHistory, ForgeStudioError> {
+    let history_path = history_path(data_root, pipeline_run_id);
+    if !history_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Pipeline run '{pipeline_run_id}' has no history under {}. Check the id with list_pipeline_runs.",
+            data_root.display()
+        )));
+    }
+    load_history_file(&history_path)
+}
+
+/// Writes the opening history entry with the graph and variable values it runs with.
+pub(crate) fn start_pipeline_history(
+    data_root: &Path,
+    record: &PipelineRunRecord,
+    canvas: PipelineCanvasState,
+    variables: &BTreeMap<String, String>,
+) -> Result<(), ForgeStudioError> {
+    let history = PipelineRunHistory {
+        pipeline_run_id: record.pipeline_run_id.clone(),
+        status: record.status.clone(),
+        created_at: record.created_at.clone(),
+        finished_at: None,
+        duration_seconds: None,
+        canvas,
+        variables: variables.clone(),
+        schedule_id: record.schedule_id.clone(),
+        nodes: record.nodes.iter().map(node_history).collect(),
+    };
+    save_history(data_root, &history)
+}
+
+/// Completes the history entry with the finished run's node outcomes.
+pub(crate) fn finish_pipeline_history(
+    data_root: &Path,
+    record: &PipelineRunRecord,
+) -> Result<(), ForgeStudioError> {
+    let mut history = load_history_file(&history_path(data_root, &record.pipeline_run_id))?;
+    history.status = record.status.clone();
+    history.finished_at = record.finished_at.clone();
+    history.duration_seconds =
+        duration_seconds(Some(&record.created_at), record.finished_at.as_deref());
+    history.nodes = record.nodes.iter().map(node_history).collect();
+    save_history(data_root, &history)
+}
+
+/// Artifacts a successful node reported on stdout, by node type.
+pub(crate) fn node_artifacts(
+    data_root: &Path,
+    node: &PipelineRunNode,
+    stdout: &str,
+) -> Vec<String> {
+    let last_line = stdout.lines().map(str::trim).rfind(|line| !line.is_empty());
+    match (node.node_type.as_str(), last_line) {
+        ("ingest" | "filter", Some(version_id)) => {
+            let dataset_name = flag_value(&node.args, "--dataset").unwrap_or_default();
+            let data_root = data_root.to_string_lossy();
+            vec![version_dir(&data_root, dataset_name, version_id)
+                .display()
+                .to_string()]
+        }
+        ("export", Some(manifest_path)) => vec![manifest_path.to_string()],
+        ("train", _) => stdout
+            .lines()
+            .filter_map(|line| line.trim().split_once('='))
+            .filter(|(key, value)| {
+                *value != "-"
+                    && TRAIN_ARTIFACT_SUFFIXES
+                        .iter()
+                        .any(|suffix| key.ends_with(suffix))
+            })
+            .map(|(_, value)| value.to_string())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Dataset version or training run a successful node produced, from its stdout.
+pub(crate) fn node_outputs(node: &PipelineRunNode, stdout: &str) -> PipelineNodeOutputs {
+    let reported = |wanted: &str| {
+        stdout
+            .lines()
+            .filter_map(|line| line.trim().split_once('='))
+            .find(|(key, value)| *key == wanted && *value != "-")
+            .map(|(_, value)| value.to_string())
+    };
+    match node.node_type.as_str() {
+        "ingest" | "filter" => PipelineNodeOutputs {
+            dataset_name: flag_value(&node.args, "--dataset").map(str::to_string),
+            dataset_version_id: stdout
+                .lines()
+                .map(str::trim)
+                .rfind(|line| !line.is_empty())
+                .map(str::to_string),
+            ..PipelineNodeOutputs::default()
+        },
+        "train" => PipelineNodeOutputs {
+            training_run_id: reported("run_id"),
+            model_path: reported("model_path"),
+            ..PipelineNodeOutputs::default()
+        },
+        _ => PipelineNodeOutputs::default(),
+    }
+}
+
+fn node_history(node: &PipelineRunNode) -> PipelineRunNodeHistory {
+    PipelineRunNodeHistory {
+        node_id: node.node_id.clone(),
+        node_type: node.node_type.clone(),
+        title: node.title.clone(),
+        status: node.status.clone(),
+        exit_code: node.exit_code,
+        started_at: node.started_at.clone(),
+        finished_at: node.finished_at.clone(),
+        duration_seconds: duration_seconds(node.started_at.as_deref(), node.finished_at.as_deref()),
+        artifacts: node.artifacts.clone(),
+        outputs: node.outputs.clone(),
+        cached_from_run_id: node.cached_from_run_id.clone(),
+        error: node.error.clone(),
+    }
+}
+
+fn summarize(history: PipelineRunHistory) -> PipelineRunHistorySummary {
+    PipelineRunHistorySummary {
+        node_count: history.nodes.len(),
+        failed_node_ids: history
+            .nodes
+            .iter()
+            .filter(|node| node.status == FAILED_STATUS)
+            .map(|node| node.node_id.clone())
+            .collect(),
+        pipeline_run_id: history.pipeline_run_id,
+        status: history.status,
+        created_at: history.created_at,
+        finished_at: history.finished_at,
+        duration_seconds: history.duration_seconds,
+        schedule_id: history.schedule_id,
+    }
+}
+
+fn duration_seconds(started_at: Option<&str>, finished_at: Option<&str>) -> Option<i64> {
+    let started_at = DateTime::parse_from_rfc3339(started_at?).ok()?;
+    let finished_at = DateTime::parse_from_rfc3339(finished_at?).ok()?;
+    Some((finished_at - started_at).num_seconds())
+}
+
+fn save_history(data_root: &Path, history: &PipelineRunHistory) -> Result<(), ForgeStudioError> {
+    let history_dir = data_root.join(PIPELINE_HISTORY_DIR);
+    fs::create_dir_all(&history_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to create {}: {error}",
+            history_dir.display()
+        ))
+    })?;
+    let payload = serde_json::to_value(history).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize pipeline run history '{}': {error}",
+            history.pipeline_run_id
+        ))
+    })?;
+    write_json_file(&history_path(data_root, &history.pipeline_run_id), &payload)
+}
+
+fn load_history_file(history_path: &Path) -> Result<PipelineRunHistory, ForgeStudioError> {
+    serde_json::from_value(read_json_file(history_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse pipeline run history {}: {error}",
+            history_path.display()
+        ))
+    })
+}
+
+fn history_path(data_root: &Path, pipeline_run_id: &str) -> PathBuf {
+    data_root
+        .join(PIPELINE_HISTORY_DIR)
+        .join(format!("{pipeline_run_id}.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{duration_seconds, node_artifacts, node_outputs};
+    use crate::models::{PipelineNodeOutputs, PipelineRunNode};
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
+    #[test]
+    fn train_artifacts_come_from_reported_paths() {
+        let node = PipelineRunNode {
+            node_id: "train".to_string(),
+            node_type: "train".to_string(),
+            title: "Train".to_string(),
+            args: vec!["train".to_string()],
+            depends_on: vec![],
+            conditions: BTreeMap::new(),
+            group_id: None,
+            status: "completed".to_string(),
+            task_id: None,
+            progress_percent: 100.0,
+            started_at: None,
+            finished_at: None,
+            error: None,
+            fingerprint: None,
+            cached_from_run_id: None,
+            exit_code: Some(0),
+            artifacts: vec![],
+            outputs: PipelineNodeOutputs::default(),
+        };
+        let stdout = "model_path=out/model.pt\nplot_path=-\nepochs_completed=3\n\
+                      checkpoint_dir=out/ckpt\nrun_id=run-42\n";
+        assert_eq!(
+            node_artifacts(Path::new("/data"), &node, stdout),
+            ["out/model.pt", "out/ckpt"]
+        );
+        let outputs = node_outputs(&node, stdout);
+        assert_eq!(outputs.training_run_id.as_deref(), Some("run-42"));
+        assert_eq!(outputs.model_path.as_deref(), Some("out/model.pt"));
+        assert_eq!(
+            duration_seconds(Some("2026-01-06T02:00:00Z"), Some("2026-01-06T02:03:30Z")),
+            Some(210)
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Pipeline executions as provenance in the model lineage graph.
+//!
+//! When a pipeline run finishes, a `pipeline:<id>` node is linked to the
+//! dataset versions its ingest and filter nodes wrote (`produced`) and the
+//! training runs its train nodes recorded (`executed`), so the lineage view
+//! shows which canvas run a model or dataset came from. The CLI already
+//! records each training run's input version, which completes the chain.
+//! Cached nodes are left out: their outputs belong to the earlier run that
+//! produced them, which recorded its own edges.
+
+use crate::commands::lineage_store_io::{
+    dataset_node_id, pipeline_node_id, run_node_id, update_lineage_graph,
+};
+use crate::commands::pipeline_runs::COMPLETED_STATUS;
+use crate::models::{ForgeStudioError, PipelineRunNode};
+use serde_json::json;
+use std::path::Path;
+
+const PRODUCED_EDGE_TYPE: &str = "produced";
+const EXECUTED_EDGE_TYPE: &str = "executed";
+
+/// Adds the run's edges; rerunning it for the same pipeline run adds nothing.
+pub(crate) fn record_pipeline_lineage(
+    data_root: &Path,
+    pipeline_run_id: &str,
+    nodes: &[PipelineRunNode],
+) -> Result<(), ForgeStudioError> {
+    let pipeline_node = pipeline_node_id(pipeline_run_id);
+    let mut targets = Vec::new();
+    for node in nodes.iter().filter(|node| node.status == COMPLETED_STATUS) {
+        let outputs = &node.outputs;
+        if let (Some(dataset_name), Some(version_id)) =
+            (&outputs.dataset_name, &outputs.dataset_version_id)
+        {
+            let dataset_node = dataset_node_id(dataset_name, version_id);
+            targets.push((dataset_node, PRODUCED_EDGE_TYPE));
+        }
+        if let Some(run_id) = &outputs.training_run_id {
+            targets.push((run_node_id(run_id), EXECUTED_EDGE_TYPE));
+        }
+    }
+    if targets.is_empty() {
+        return Ok(());
+    }
+    update_lineage_graph(data_root, |graph| {
+        let mut changed = false;
+        for (target_node, edge_type) in targets {
+            let edge = json!({"from": pipeline_node, "to": target_node, "type": edge_type});
+            if !graph.edges.contains(&edge) {
+                graph.edges.push(edge);
+                changed = true;
+            }
+        }
+        Ok(((), changed))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::record_pipeline_lineage;
+    use crate::commands::lineage_store_io::read_lineage_graph;
+    use crate::commands::runtime_queries::load_lineage_summary;
+    use crate::models::PipelineRunNode;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn finished_pipeline_links_its_outputs() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-pipeline-lineage-{}", std::process::id()));
+        let node = |node_id: &str, status: &str, outputs| -> PipelineRunNode {
+            serde_json::from_value(json!({
+                "node_id": node_id, "node_type": "custom", "title": node_id,
+                "args": [], "depends_on": [], "status": status,
+                "progress_percent": 100.0, "outputs": outputs,
+            }))
+            .expect("node should parse")
+        };
+        let nodes = vec![
+            node(
+                "filter",
+                "completed",
+                json!({"dataset_name": "demo", "dataset_version_id": "demo-v2"}),
+            ),
+            node("train", "completed", json!({"training_run_id": "run-1"})),
+            node(
+                "reused",
+                "cached",
+                json!({"dataset_name": "demo", "dataset_version_id": "demo-v1"}),
+            ),
+        ];
+        record_pipeline_lineage(&data_root, "pipe-1", &nodes).expect("lineage should be written");
+        record_pipeline_lineage(&data_root, "pipe-1", &nodes).expect("rerun should be a no-op");
+
+        let graph = read_lineage_graph(&data_root).expect("graph should be readable");
+        assert_eq!(graph.edges.len(), 2);
+        let summary = load_lineage_summary(&data_root).expect("summary should load");
+        assert_eq!(summary.datasets.len(), 1);
+        assert_eq!(summary.datasets[0].node_id, "dataset:demo:demo-v2");
+        assert!(summary
+            .edges
+            .iter()
+            .any(|edge| edge.from == "pipeline:pipe-1" && edge.to == "run:run-1"));
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,187 @@
+//! Maps canvas nodes to forge CLI args.
+//!
+//! Mirrors `toForgeArgs` in the frontend's `pipeline.ts` so a canvas run
+//! from the backend issues the same commands as one driven by the UI. Blank
+//! optional config values are left off so the CLI defaults apply. Plugin
+//! node types map through their manifest's parameters.
+
+use crate::commands::plugin_registry::plugin_node_args;
+use crate::models::PipelineNodeSnapshot;
+
+pub(crate) const TRAIN_OPTIONAL_FLAGS: [(&str, &str); 16] = [
+    ("epochs", "--epochs"),
+    ("learning_rate", "--learning-rate"),
+    ("batch_size", "--batch-size"),
+    ("max_token_length", "--max-token-length"),
+    ("validation_split", "--validation-split"),
+    ("hidden_dim", "--hidden-dim"),
+    ("num_layers", "--num-layers"),
+    ("attention_heads", "--attention-heads"),
+    ("mlp_hidden_dim", "--mlp-hidden-dim"),
+    ("mlp_layers", "--mlp-layers"),
+    ("dropout", "--dropout"),
+    ("position_embedding_type", "--position-embedding-type"),
+    ("vocabulary_size", "--vocabulary-size"),
+    ("architecture_file", "--architecture-file"),
+    ("custom_loop_file", "--custom-loop-file"),
+    ("initial_weights_path", "--initial-weights-path"),
+];
+pub(crate) const CHAT_OPTIONAL_FLAGS: [(&str, &str); 14] = [
+    ("max_new_tokens", "--max-new-tokens"),
+    ("temperature", "--temperature"),
+    ("top_k", "--top-k"),
+    ("version_id", "--version-id"),
+    ("architecture_file", "--architecture-file"),
+    ("max_token_length", "--max-token-length"),
+    ("vocabulary_size", "--vocabulary-size"),
+    ("hidden_dim", "--hidden-dim"),
+    ("num_layers", "--num-layers"),
+    ("attention_heads", "--attention-heads"),
+    ("mlp_hidden_dim", "--mlp-hidden-dim"),
+    ("mlp_layers", "--mlp-layers"),
+    ("dropout", "--dropout"),
+    ("position_embedding_type", "--position-embedding-type"),
+];
+pub(crate) const DEFAULT_QUALITY_MODEL: &str = "perplexity";
+pub(crate) const DEFAULT_CUSTOM_COMMAND: &str = "versions";
+
+pub(crate) fn node_forge_args(node: &PipelineNodeSnapshot) -> Vec<String> {
+    let config = |key: &str| config_value(node, key);
+    match node.node_type.as_str() {
+        "ingest" => ingest_args(node),
+        "filter" => {
+            let mut args = strings(&["filter", "--dataset", config("dataset")]);
+            append_optional(&mut args, "--language", config("language"));
+            append_optional(&mut args, "--min-quality", config("min_quality"));
+            args
+        }
+        "train" => {
+            let mut args = strings(&["train", "--dataset", config("dataset")]);
+            args.extend(strings(&["--output-dir", config("output_dir")]));
+            append_flags(&mut args, node, &TRAIN_OPTIONAL_FLAGS);
+            args
+        }
+        "export" => strings(&[
+            "export-training",
+            "--dataset",
+            config("dataset"),
+            "--output-dir",
+            config("output_dir"),
+            "--shard-size",
+            config("shard_size"),
+            "--include-metadata",
+        ]),
+        "chat" => {
+            let mut args = strings(&["chat", "--dataset", config("dataset")]);
+            args.extend(strings(&["--model-path", config("model_path")]));
+            args.extend(strings(&["--prompt", config("prompt")]));
+            append_flags(&mut args, node, &CHAT_OPTIONAL_FLAGS);
+            args
+        }
+        _ => plugin_node_args(node).unwrap_or_else(|| custom_args(config("args"))),
+    }
+}
+
+fn ingest_args(node: &PipelineNodeSnapshot) -> Vec<String> {
+    let quality_model = match config_value(node, "quality_model") {
+        "" => DEFAULT_QUALITY_MODEL,
+        value => value,
+    };
+    let mut args = strings(&["ingest", config_value(node, "source")]);
+    args.extend(strings(&["--dataset", config_value(node, "dataset")]));
+    args.extend(strings(&["--quality-model", quality_model]));
+    for flag in ["incremental", "resume"] {
+        if config_value(node, flag).eq_ignore_ascii_case("true") {
+            args.push(format!("--{flag}"));
+        }
+    }
+    args
+}
+
+/// Custom nodes hold a raw command line; an empty one falls back to `versions`.
+fn custom_args(raw_args: &str) -> Vec<String> {
+    let args: Vec<String> = raw_args.split_whitespace().map(str::to_string).collect();
+    if args.is_empty() {
+        return vec![DEFAULT_CUSTOM_COMMAND.to_string()];
+    }
+    args
+}
+
+fn config_value<'a>(node: &'a PipelineNodeSnapshot, key: &str) -> &'a str {
+    node.config.get(key).map(String::as_str).unwrap_or_default()
+}
+
+fn append_flags(args: &mut Vec<String>, node: &PipelineNodeSnapshot, flags: &[(&str, &str)]) {
+    for (key, flag) in flags {
+        append_optional(args, flag, config_value(node, key));
+    }
+}
+
+fn append_optional(args: &mut Vec<String>, flag: &str, value: &str) {
+    if !value.trim().is_empty() {
+        args.push(flag.to_string());
+        args.push(value.trim().to_string());
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::node_forge_args;
+    use crate::models::PipelineNodeSnapshot;
+    use std::collections::BTreeMap;
+
+    fn node(node_type: &str, config: &[(&str, &str)]) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: "n1".to_string(),
+            node_type: node_type.to_string(),
+            title: "Node".to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: config
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn node_forge_args_matches_frontend_mapping() {
+        let train = node(
+            "train",
+            &[
+                ("dataset", "demo"),
+                ("output_dir", "out"),
+                ("epochs", "2"),
+                ("dropout", " "),
+            ],
+        );
+        assert_eq!(
+            node_forge_args(&train),
+            [
+                "train",
+                "--dataset",
+                "demo",
+                "--output-dir",
+                "out",
+                "--epochs",
+                "2"
+            ]
+        );
+        let ingest = node(
+            "ingest",
+            &[("source", "./src"), ("dataset", "demo"), ("resume", "TRUE")],
+        );
+        assert_eq!(
+            node_forge_args(&ingest).last().map(String::as_str),
+            Some("--resume")
+        );
+        assert_eq!(
+            node_forge_args(&node("custom", &[("args", "  ")])),
+            ["versions"]
+        );
+    }
+}
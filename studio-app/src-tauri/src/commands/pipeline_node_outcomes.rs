@@ -0,0 +1,76 @@
+//! How a pipeline node's turn ends without the scheduler's bookkeeping.
+//!
+//! A node settles from its finished task, is skipped because an incoming
+//! edge condition is not met, or is served from the pipeline cache. Each
+//! helper only rewrites the node records; the scheduler decides when to call
+//! them and saves the run afterwards.
+
+use crate::commands::pipeline_cache::cached_execution;
+use crate::commands::pipeline_history::{node_artifacts, node_outputs};
+use crate::commands::pipeline_node_readiness::skip_downstream;
+use crate::commands::pipeline_runs::{
+    timestamp_now, CACHED_STATUS, COMPLETED_STATUS, FAILED_STATUS, SKIPPED_STATUS,
+};
+use crate::models::{CommandTaskStatus, ForgeStudioError, PipelineRunNode};
+use std::path::Path;
+
+/// Records a finished task's exit state and, on success, the artifacts it reported.
+pub(crate) fn settle_node(
+    data_root: &Path,
+    node: &mut PipelineRunNode,
+    task_status: Result<CommandTaskStatus, ForgeStudioError>,
+) {
+    let (status, error) = match &task_status {
+        Ok(task_status) if task_status.status == COMPLETED_STATUS => {
+            node.artifacts = node_artifacts(data_root, node, &task_status.stdout);
+            node.outputs = node_outputs(node, &task_status.stdout);
+            (COMPLETED_STATUS, None)
+        }
+        Ok(task_status) => {
+            let last_error_line = task_status.stderr.lines().last().unwrap_or_default();
+            let error = format!(
+                "Task exited with code {:?}: {last_error_line}. Fix the node config and rerun.",
+                task_status.exit_code
+            );
+            (FAILED_STATUS, Some(error))
+        }
+        Err(error) => (FAILED_STATUS, Some(error.to_string())),
+    };
+    node.exit_code = task_status
+        .ok()
+        .and_then(|task_status| task_status.exit_code);
+    node.status = status.to_string();
+    node.progress_percent = 100.0;
+    node.finished_at = Some(timestamp_now());
+    node.error = error;
+}
+
+/// Skips the node at `index`, and everything downstream of it, without running it.
+pub(crate) fn skip_unmet_condition(nodes: &mut [PipelineRunNode], index: usize, reason: String) {
+    let node = &mut nodes[index];
+    node.status = SKIPPED_STATUS.to_string();
+    node.finished_at = Some(timestamp_now());
+    node.error = Some(reason);
+    let skipped_node_id = node.node_id.clone();
+    skip_downstream(nodes, &skipped_node_id);
+}
+
+/// Marks `node` cached when an earlier run already produced its outputs,
+/// reporting that run's artifacts as its own.
+pub(crate) fn reuse_cached_execution(data_root: &Path, node: &mut PipelineRunNode) -> bool {
+    let Some(fingerprint) = node.fingerprint.as_deref() else {
+        return false;
+    };
+    let Some(entry) = cached_execution(data_root, fingerprint, node) else {
+        return false;
+    };
+    let now = timestamp_now();
+    node.status = CACHED_STATUS.to_string();
+    node.progress_percent = 100.0;
+    node.started_at = Some(now.clone());
+    node.finished_at = Some(now);
+    node.cached_from_run_id = Some(entry.pipeline_run_id);
+    node.artifacts = entry.artifacts;
+    node.outputs = entry.outputs;
+    true
+}
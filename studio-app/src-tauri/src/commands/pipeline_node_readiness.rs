@@ -0,0 +1,183 @@
+//! Which pipeline run nodes may start next, and which a failure strands.
+//!
+//! Kept apart from the scheduler's task and file handling so the ordering
+//! rules behind parallel branches and fan-in joins can be read, and tested,
+//! on plain node lists.
+
+use crate::commands::forge_commands::flag_value;
+use crate::commands::pipeline_runs::{
+    CACHED_STATUS, COMPLETED_STATUS, PENDING_STATUS, RUNNING_STATUS, SKIPPED_STATUS,
+};
+use crate::models::PipelineRunNode;
+use std::collections::HashSet;
+
+/// Commands that write a new version of the dataset they name.
+const DATASET_WRITER_COMMANDS: [&str; 2] = ["ingest", "filter"];
+
+/// The first pending node in plan order whose upstream nodes all succeeded
+/// and whose dataset no running node is writing or reading while it writes.
+pub(crate) fn next_ready_node(nodes: &[PipelineRunNode]) -> Option<usize> {
+    let succeeded_ids: HashSet<&str> = nodes
+        .iter()
+        .filter(|node| succeeded(node))
+        .map(|node| node.node_id.as_str())
+        .collect();
+    let running: Vec<&PipelineRunNode> = nodes
+        .iter()
+        .filter(|node| node.status == RUNNING_STATUS)
+        .collect();
+    nodes.iter().position(|node| {
+        node.status == PENDING_STATUS
+            && node
+                .depends_on
+                .iter()
+                .all(|upstream| succeeded_ids.contains(upstream.as_str()))
+            && !running.iter().any(|other| dataset_conflict(node, other))
+    })
+}
+
+fn dataset_conflict(left: &PipelineRunNode, right: &PipelineRunNode) -> bool {
+    let writes = |node: &PipelineRunNode| {
+        node.args
+            .first()
+            .is_some_and(|command| DATASET_WRITER_COMMANDS.contains(&command.as_str()))
+    };
+    (writes(left) || writes(right))
+        && flag_value(&left.args, "--dataset")
+            .is_some_and(|dataset_name| flag_value(&right.args, "--dataset") == Some(dataset_name))
+}
+
+/// Cached nodes count as completed for dependents and the run status.
+pub(crate) fn succeeded(node: &PipelineRunNode) -> bool {
+    node.status == COMPLETED_STATUS || node.status == CACHED_STATUS
+}
+
+/// A failed group member takes the group's pending members down with it,
+/// along with everything downstream of any of them.
+pub(crate) fn skip_after_failure(nodes: &mut [PipelineRunNode], failed_node_id: &str) {
+    let group_id = nodes
+        .iter()
+        .find(|node| node.node_id == failed_node_id)
+        .and_then(|node| node.group_id.clone());
+    let mut stopped_ids = vec![failed_node_id.to_string()];
+    for node in nodes.iter_mut().filter(|node| {
+        node.status == PENDING_STATUS && group_id.is_some() && node.group_id == group_id
+    }) {
+        node.error = Some(format!(
+            "Skipped because node '{failed_node_id}' in the same group failed."
+        ));
+        node.status = SKIPPED_STATUS.to_string();
+        stopped_ids.push(node.node_id.clone());
+    }
+    for stopped_id in stopped_ids {
+        skip_downstream(nodes, &stopped_id);
+    }
+}
+
+/// Plan order is topological, so one pass reaches every transitive dependent.
+pub(crate) fn skip_downstream(nodes: &mut [PipelineRunNode], failed_node_id: &str) {
+    let mut blocked = HashSet::from([failed_node_id.to_string()]);
+    for node in nodes
+        .iter_mut()
+        .filter(|node| node.status == PENDING_STATUS)
+    {
+        let Some(upstream) = node
+            .depends_on
+            .iter()
+            .find(|upstream| blocked.contains(*upstream))
+        else {
+            continue;
+        };
+        node.error = Some(format!(
+            "Skipped because upstream node '{upstream}' did not complete."
+        ));
+        node.status = SKIPPED_STATUS.to_string();
+        blocked.insert(node.node_id.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_ready_node, skip_after_failure, skip_downstream};
+    use crate::models::{PipelineNodeOutputs, PipelineRunNode};
+    use std::collections::BTreeMap;
+
+    fn node(node_id: &str, depends_on: &[&str], status: &str) -> PipelineRunNode {
+        PipelineRunNode {
+            node_id: node_id.to_string(),
+            node_type: "custom".to_string(),
+            title: node_id.to_string(),
+            args: ["versions", "--dataset", "demo"]
+                .map(str::to_string)
+                .to_vec(),
+            depends_on: depends_on.iter().map(|id| id.to_string()).collect(),
+            conditions: BTreeMap::new(),
+            group_id: None,
+            status: status.to_string(),
+            task_id: None,
+            progress_percent: 0.0,
+            started_at: None,
+            finished_at: None,
+            error: None,
+            fingerprint: None,
+            cached_from_run_id: None,
+            exit_code: None,
+            artifacts: vec![],
+            outputs: PipelineNodeOutputs::default(),
+        }
+    }
+
+    #[test]
+    fn skip_downstream_spares_independent_branches() {
+        let mut nodes = vec![
+            node("ingest", &[], "completed"),
+            node("filter", &["ingest"], "failed"),
+            node("train", &["filter"], "pending"),
+            node("chat", &["train"], "pending"),
+            node("export", &["ingest"], "pending"),
+        ];
+        skip_downstream(&mut nodes, "filter");
+        let statuses: Vec<&str> = nodes.iter().map(|node| node.status.as_str()).collect();
+        assert_eq!(
+            statuses,
+            ["completed", "failed", "skipped", "skipped", "pending"]
+        );
+    }
+
+    #[test]
+    fn failed_group_member_skips_the_rest_of_its_group() {
+        let mut nodes = vec![
+            node("ingest", &[], "failed"),
+            node("export", &[], "pending"),
+            node("chat", &["export"], "pending"),
+            node("report", &[], "pending"),
+        ];
+        for node in &mut nodes[..2] {
+            node.group_id = Some("prep".to_string());
+        }
+        skip_after_failure(&mut nodes, "ingest");
+        let statuses: Vec<&str> = nodes.iter().map(|node| node.status.as_str()).collect();
+        assert_eq!(statuses, ["failed", "skipped", "skipped", "pending"]);
+    }
+
+    #[test]
+    fn fan_in_waits_for_every_branch_and_writers_run_alone() {
+        let mut nodes = vec![
+            node("ingest", &[], "completed"),
+            node("export", &["ingest"], "running"),
+            node("chat", &["ingest"], "pending"),
+            node("report", &["export", "chat"], "pending"),
+        ];
+        assert_eq!(next_ready_node(&nodes), Some(2));
+        nodes[2].status = "completed".to_string();
+        assert_eq!(next_ready_node(&nodes), None);
+        nodes[1].status = "cached".to_string();
+        assert_eq!(next_ready_node(&nodes), Some(3));
+
+        nodes[3].args[0] = "filter".to_string();
+        nodes[1].status = "running".to_string();
+        nodes[1].depends_on.clear();
+        nodes[3].depends_on.clear();
+        assert_eq!(next_ready_node(&nodes), None);
+    }
+}
@@ -0,0 +1,178 @@
+//! Parameter schema for pipeline node types, bundled with Studio.
+//!
+//! Mirrors the flags `pipeline_node_args` passes and the bounds the forge CLI
+//! enforces, so configs can be checked before any command starts. Used as the
+//! palette fallback when the installed CLI cannot describe itself.
+
+use crate::commands::pipeline_node_args::{
+    CHAT_OPTIONAL_FLAGS, DEFAULT_CUSTOM_COMMAND, DEFAULT_QUALITY_MODEL, TRAIN_OPTIONAL_FLAGS,
+};
+use crate::models::{PipelineNodeParameter, PipelineParameterRange};
+
+/// Value types for parameters that are not plain strings.
+const PARAMETER_VALUE_TYPES: [(&str, &str); 25] = [
+    ("source", "path"),
+    ("incremental", "boolean"),
+    ("resume", "boolean"),
+    ("min_quality", "float"),
+    ("output_dir", "path"),
+    ("shard_size", "integer"),
+    ("epochs", "integer"),
+    ("learning_rate", "float"),
+    ("batch_size", "integer"),
+    ("max_token_length", "integer"),
+    ("validation_split", "float"),
+    ("hidden_dim", "integer"),
+    ("num_layers", "integer"),
+    ("attention_heads", "integer"),
+    ("mlp_hidden_dim", "integer"),
+    ("mlp_layers", "integer"),
+    ("dropout", "float"),
+    ("vocabulary_size", "integer"),
+    ("architecture_file", "path"),
+    ("custom_loop_file", "path"),
+    ("initial_weights_path", "path"),
+    ("model_path", "path"),
+    ("max_new_tokens", "integer"),
+    ("temperature", "float"),
+    ("top_k", "integer"),
+];
+const OPEN_RANGE: PipelineParameterRange = PipelineParameterRange {
+    minimum: None,
+    exclusive_minimum: None,
+    maximum: None,
+    exclusive_maximum: None,
+};
+const AT_LEAST_ONE: PipelineParameterRange = at_least(1.0);
+const NON_NEGATIVE: PipelineParameterRange = at_least(0.0);
+const POSITIVE: PipelineParameterRange = PipelineParameterRange {
+    exclusive_minimum: Some(0.0),
+    ..OPEN_RANGE
+};
+const UNIT_INTERVAL: PipelineParameterRange = PipelineParameterRange {
+    maximum: Some(1.0),
+    ..NON_NEGATIVE
+};
+/// `[0, 1)`, as forge checks validation split and dropout.
+const FRACTION: PipelineParameterRange = PipelineParameterRange {
+    exclusive_maximum: Some(1.0),
+    ..NON_NEGATIVE
+};
+/// Numeric bounds matching forge's own option checks.
+const PARAMETER_RANGES: [(&str, PipelineParameterRange); 17] = [
+    ("min_quality", UNIT_INTERVAL),
+    ("shard_size", AT_LEAST_ONE),
+    ("epochs", AT_LEAST_ONE),
+    ("learning_rate", POSITIVE),
+    ("batch_size", AT_LEAST_ONE),
+    ("max_token_length", AT_LEAST_ONE),
+    ("validation_split", FRACTION),
+    ("hidden_dim", AT_LEAST_ONE),
+    ("num_layers", AT_LEAST_ONE),
+    ("attention_heads", AT_LEAST_ONE),
+    ("mlp_hidden_dim", AT_LEAST_ONE),
+    ("mlp_layers", AT_LEAST_ONE),
+    ("dropout", FRACTION),
+    ("vocabulary_size", at_least(2.0)),
+    ("max_new_tokens", AT_LEAST_ONE),
+    ("temperature", NON_NEGATIVE),
+    ("top_k", NON_NEGATIVE),
+];
+/// Choices forge's argparse accepts for enum-like parameters.
+const PARAMETER_ALLOWED_VALUES: [(&str, &[&str]); 2] = [
+    ("quality_model", &["hybrid", "perplexity"]),
+    ("position_embedding_type", &["learned", "sinusoidal"]),
+];
+
+pub(crate) fn bundled_parameters(node_type: &str) -> Vec<PipelineNodeParameter> {
+    let mut parameters = match node_type {
+        "ingest" => vec![
+            parameter("source", None, true),
+            flagged("dataset", true),
+            with_default(flagged("quality_model", false), DEFAULT_QUALITY_MODEL),
+            flagged("incremental", false),
+            flagged("resume", false),
+        ],
+        "filter" => vec![
+            flagged("dataset", true),
+            flagged("language", false),
+            flagged("min_quality", false),
+        ],
+        "train" => vec![flagged("dataset", true), flagged("output_dir", true)],
+        "export" => vec![
+            flagged("dataset", true),
+            flagged("output_dir", true),
+            flagged("shard_size", true),
+        ],
+        "chat" => vec![
+            flagged("dataset", true),
+            flagged("model_path", true),
+            flagged("prompt", true),
+        ],
+        _ => vec![with_default(
+            parameter("args", None, false),
+            DEFAULT_CUSTOM_COMMAND,
+        )],
+    };
+    let optional_flags: &[(&str, &str)] = match node_type {
+        "train" => &TRAIN_OPTIONAL_FLAGS,
+        "chat" => &CHAT_OPTIONAL_FLAGS,
+        _ => &[],
+    };
+    parameters.extend(
+        optional_flags
+            .iter()
+            .map(|(name, flag)| parameter(name, Some(flag), false)),
+    );
+    parameters
+}
+
+const fn at_least(minimum: f64) -> PipelineParameterRange {
+    PipelineParameterRange {
+        minimum: Some(minimum),
+        ..OPEN_RANGE
+    }
+}
+
+/// A parameter passed as `--kebab-case` of its config key.
+fn flagged(name: &str, required: bool) -> PipelineNodeParameter {
+    let flag = format!("--{}", name.replace('_', "-"));
+    parameter(name, Some(&flag), required)
+}
+
+/// Bundled value type for a config key; keys outside the schema are strings.
+pub(crate) fn parameter_value_type(name: &str) -> &'static str {
+    PARAMETER_VALUE_TYPES
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map_or("string", |(_, value_type)| value_type)
+}
+
+fn parameter(name: &str, flag: Option<&str>, required: bool) -> PipelineNodeParameter {
+    let value_type = parameter_value_type(name);
+    let range = PARAMETER_RANGES
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, range)| *range);
+    let allowed_values = PARAMETER_ALLOWED_VALUES
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, values)| values.iter().map(|value| value.to_string()).collect())
+        .unwrap_or_default();
+    PipelineNodeParameter {
+        name: name.to_string(),
+        flag: flag.map(str::to_string),
+        value_type: value_type.to_string(),
+        required,
+        default_value: None,
+        range,
+        allowed_values,
+    }
+}
+
+fn with_default(parameter: PipelineNodeParameter, default_value: &str) -> PipelineNodeParameter {
+    PipelineNodeParameter {
+        default_value: Some(default_value.to_string()),
+        ..parameter
+    }
+}
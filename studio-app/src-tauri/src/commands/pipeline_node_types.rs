@@ -0,0 +1,191 @@
+//! Node type registry for the pipeline canvas palette.
+//!
+//! The installed forge CLI is asked to describe its commands so the palette
+//! offers exactly the parameters that version accepts, and drops node types
+//! whose command it lacks. CLIs without `--describe-commands` fall back to a
+//! schema bundled with Studio. Node types from loaded plugins follow the
+//! built-in ones either way.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::forge_process::forge_command;
+use crate::commands::pipeline_node_schema::bundled_parameters;
+use crate::commands::plugin_registry::plugin_node_types;
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{
+    ForgeStudioError, PipelineNodeParameter, PipelineNodeType, PipelineNodeTypeCatalog,
+};
+use serde::Deserialize;
+use std::path::Path;
+use tauri::State;
+
+/// Node type, the forge subcommand it runs, and its bundled palette description.
+const NODE_TYPE_COMMANDS: [(&str, Option<&str>, &str); 6] = [
+    ("ingest", Some("ingest"), "Ingest a local path or S3 prefix"),
+    (
+        "filter",
+        Some("filter"),
+        "Create metadata-filtered snapshot",
+    ),
+    (
+        "train",
+        Some("train"),
+        "Train a PyTorch language model on a dataset version",
+    ),
+    (
+        "export",
+        Some("export-training"),
+        "Export a version into sharded local training files",
+    ),
+    (
+        "chat",
+        Some("chat"),
+        "Generate a model response from trained weights and a text prompt",
+    ),
+    ("custom", None, "Run any forge command line"),
+];
+const FORGE_SOURCE: &str = "forge";
+const BUNDLED_SOURCE: &str = "bundled";
+
+/// Output of `forge --describe-commands`.
+#[derive(Deserialize)]
+struct DescribedCommands {
+    commands: Vec<DescribedCommand>,
+}
+
+#[derive(Deserialize)]
+struct DescribedCommand {
+    name: String,
+    description: String,
+    parameters: Vec<PipelineNodeParameter>,
+}
+
+#[tauri::command]
+pub fn get_pipeline_node_types(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> PipelineNodeTypeCatalog {
+    let forge_binary = settings.current().forge_binary;
+    match resolve_data_root_path(&data_root, &settings.workspace_roots())
+        .and_then(|root| describe_forge_commands(&root, &forge_binary))
+    {
+        Ok(described) => PipelineNodeTypeCatalog {
+            source: FORGE_SOURCE.to_string(),
+            fallback_reason: None,
+            node_types: [described_node_types(described), plugin_node_types()].concat(),
+        },
+        Err(reason) => PipelineNodeTypeCatalog {
+            source: BUNDLED_SOURCE.to_string(),
+            fallback_reason: Some(reason.to_string()),
+            node_types: [bundled_node_types(), plugin_node_types()].concat(),
+        },
+    }
+}
+
+fn describe_forge_commands(
+    resolved_data_root: &Path,
+    forge_binary: &str,
+) -> Result<DescribedCommands, ForgeStudioError> {
+    let output = forge_command(forge_binary)
+        .arg("--data-root")
+        .arg(resolved_data_root.as_os_str())
+        .arg("--describe-commands")
+        .output()
+        .map_err(|error| {
+            ForgeStudioError::io(format!("Failed to run forge --describe-commands: {error}"))
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(ForgeStudioError::io(format!(
+            "forge --describe-commands failed with status {}: {}. \
+             Upgrade the forge CLI to list node types from the installed version.",
+            output.status.code().unwrap_or(-1),
+            stderr.lines().last().unwrap_or_default().trim()
+        )));
+    }
+    serde_json::from_slice(&output.stdout).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse forge --describe-commands JSON: {error}. \
+             Upgrade the forge CLI to a version with a matching schema."
+        ))
+    })
+}
+
+/// Node types backed by a described command; custom nodes are always available.
+fn described_node_types(described: DescribedCommands) -> Vec<PipelineNodeType> {
+    NODE_TYPE_COMMANDS
+        .iter()
+        .filter_map(|(node_type, command, description)| {
+            let Some(command) = command else {
+                return Some(node_type_entry(node_type, None, description));
+            };
+            let found = described
+                .commands
+                .iter()
+                .find(|candidate| candidate.name == *command)?;
+            Some(PipelineNodeType {
+                node_type: node_type.to_string(),
+                command: Some(command.to_string()),
+                description: found.description.clone(),
+                parameters: found.parameters.clone(),
+            })
+        })
+        .collect()
+}
+
+fn bundled_node_types() -> Vec<PipelineNodeType> {
+    NODE_TYPE_COMMANDS
+        .iter()
+        .map(|(node_type, command, description)| node_type_entry(node_type, *command, description))
+        .collect()
+}
+
+fn node_type_entry(node_type: &str, command: Option<&str>, description: &str) -> PipelineNodeType {
+    PipelineNodeType {
+        node_type: node_type.to_string(),
+        command: command.map(str::to_string),
+        description: description.to_string(),
+        parameters: bundled_parameters(node_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bundled_node_types, described_node_types, DescribedCommands};
+    use crate::commands::canvas_validation::PIPELINE_NODE_TYPES;
+
+    #[test]
+    fn bundled_node_types_cover_palette_with_typed_parameters() {
+        let node_types = bundled_node_types();
+        let names: Vec<&str> = node_types
+            .iter()
+            .map(|entry| entry.node_type.as_str())
+            .collect();
+        assert_eq!(names, PIPELINE_NODE_TYPES);
+        let train = &node_types[2];
+        let epochs = train
+            .parameters
+            .iter()
+            .find(|parameter| parameter.name == "epochs")
+            .expect("train should expose epochs");
+        assert_eq!(epochs.flag.as_deref(), Some("--epochs"));
+        assert_eq!(epochs.value_type, "integer");
+        assert!(!epochs.required);
+    }
+
+    #[test]
+    fn described_node_types_follow_installed_commands() {
+        let described: DescribedCommands = serde_json::from_str(
+            r#"{"commands": [{"name": "train", "description": "Train", "parameters": [
+                {"name": "dataset", "flag": "--dataset", "value_type": "string", "required": true}
+            ]}]}"#,
+        )
+        .expect("described commands should parse");
+        let node_types = described_node_types(described);
+        let names: Vec<&str> = node_types
+            .iter()
+            .map(|entry| entry.node_type.as_str())
+            .collect();
+        assert_eq!(names, ["train", "custom"]);
+        assert_eq!(node_types[0].parameters.len(), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Execution plan for a pipeline canvas run.
+//!
+//! Only nodes reachable from the start node are planned, sorted
+//! topologically with ties broken by canvas order, which is the same plan
+//! the frontend builds in `pipeline_graph.ts`. Callers validate the canvas
+//! first, so cycles and dangling edges never reach the sort. A selection can
+//! then narrow the plan to a subgraph for partial reruns.
+
+use crate::commands::pipeline_node_args::node_forge_args;
+use crate::commands::pipeline_runs::PENDING_STATUS;
+use crate::models::{
+    ForgeStudioError, PipelineEdgeSnapshot, PipelineNodeOutputs, PipelineNodeSnapshot,
+    PipelineRunNode, PipelineRunOptions,
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Reachable nodes in dependency order, each with its args, upstream ids,
+/// and the conditions on its incoming edges.
+pub(crate) fn plan_pipeline(
+    nodes: &[PipelineNodeSnapshot],
+    edges: &[PipelineEdgeSnapshot],
+    start_node_id: &str,
+) -> Vec<PipelineRunNode> {
+    let outgoing = outgoing_edges(edges);
+    let reachable = reachable_from(&outgoing, start_node_id);
+    let mut depends_on = upstream_ids(&outgoing, &reachable);
+    let order_index: HashMap<&str, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.id.as_str(), index))
+        .collect();
+    // Kahn's algorithm over canvas indices, always releasing the earliest ready node.
+    let mut waiting: HashMap<usize, usize> = HashMap::new();
+    let mut ready = BTreeSet::new();
+    for (index, node) in nodes.iter().enumerate() {
+        let upstream_count = depends_on.get(node.id.as_str()).map_or(0, Vec::len);
+        if !reachable.contains(node.id.as_str()) {
+            continue;
+        } else if upstream_count == 0 {
+            ready.insert(index);
+        } else {
+            waiting.insert(index, upstream_count);
+        }
+    }
+    let mut planned = Vec::with_capacity(reachable.len());
+    while let Some(index) = ready.pop_first() {
+        let node = &nodes[index];
+        for target in outgoing.get(node.id.as_str()).into_iter().flatten() {
+            let Some(&target_index) = order_index.get(target) else {
+                continue;
+            };
+            if let Some(remaining) = waiting.get_mut(&target_index) {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    waiting.remove(&target_index);
+                    ready.insert(target_index);
+                }
+            }
+        }
+        let mut upstream = depends_on.remove(node.id.as_str()).unwrap_or_default();
+        upstream.sort_by_key(|node_id| order_index.get(node_id.as_str()).copied());
+        planned.push(new_run_node(node, upstream, edges));
+    }
+    planned
+}
+
+/// Keeps `start_from_node_id` and its downstream nodes and/or the `only_nodes`
+/// ids, in plan order. Dependencies on nodes left out are dropped: the kept
+/// nodes reuse what an earlier run of those nodes produced.
+pub(crate) fn select_plan(
+    planned: Vec<PipelineRunNode>,
+    options: &PipelineRunOptions,
+) -> Result<Vec<PipelineRunNode>, ForgeStudioError> {
+    let requested = options.start_from_node_id.iter().chain(&options.only_nodes);
+    for node_id in requested {
+        if !planned.iter().any(|node| &node.node_id == node_id) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Pipeline cannot run: selected node '{node_id}' is not reachable from the start node. Select a node in the run."
+            )));
+        }
+    }
+    let mut kept: HashSet<String> = match &options.start_from_node_id {
+        Some(start_from) => {
+            let mut downstream = HashSet::from([start_from.clone()]);
+            for node in &planned {
+                if node
+                    .depends_on
+                    .iter()
+                    .any(|upstream| downstream.contains(upstream))
+                {
+                    downstream.insert(node.node_id.clone());
+                }
+            }
+            downstream
+        }
+        None => planned.iter().map(|node| node.node_id.clone()).collect(),
+    };
+    if !options.only_nodes.is_empty() {
+        kept.retain(|node_id| options.only_nodes.contains(node_id));
+    }
+    if kept.is_empty() {
+        return Err(
+            ForgeStudioError::invalid_input(
+                "Pipeline cannot run: none of only_nodes is downstream of start_from_node_id. Widen the selection.",
+            ),
+        );
+    }
+    Ok(planned
+        .into_iter()
+        .filter(|node| kept.contains(&node.node_id))
+        .map(|mut node| {
+            node.depends_on.retain(|upstream| kept.contains(upstream));
+            node.conditions
+                .retain(|upstream, _| kept.contains(upstream));
+            node
+        })
+        .collect())
+}
+
+/// Deduplicated adjacency lists; self-loops are dropped since validation rejects them.
+fn outgoing_edges(edges: &[PipelineEdgeSnapshot]) -> HashMap<&str, Vec<&str>> {
+    let mut outgoing: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges
+        .iter()
+        .filter(|edge| edge.source_node_id != edge.target_node_id)
+    {
+        let targets = outgoing.entry(edge.source_node_id.as_str()).or_default();
+        if !targets.contains(&edge.target_node_id.as_str()) {
+            targets.push(edge.target_node_id.as_str());
+        }
+    }
+    outgoing
+}
+
+/// Reachable upstream node ids per node.
+fn upstream_ids<'a>(
+    outgoing: &HashMap<&'a str, Vec<&'a str>>,
+    reachable: &HashSet<&str>,
+) -> HashMap<&'a str, Vec<String>> {
+    let mut upstream: HashMap<&str, Vec<String>> = HashMap::new();
+    for (source, targets) in outgoing
+        .iter()
+        .filter(|(source, _)| reachable.contains(*source))
+    {
+        for target in targets {
+            upstream
+                .entry(*target)
+                .or_default()
+                .push(source.to_string());
+        }
+    }
+    upstream
+}
+
+fn reachable_from<'a>(
+    outgoing: &HashMap<&'a str, Vec<&'a str>>,
+    start: &'a str,
+) -> HashSet<&'a str> {
+    let mut reachable = HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(current) = stack.pop() {
+        for next in outgoing.get(current).into_iter().flatten() {
+            if reachable.insert(*next) {
+                stack.push(*next);
+            }
+        }
+    }
+    reachable
+}
+
+fn new_run_node(
+    node: &PipelineNodeSnapshot,
+    depends_on: Vec<String>,
+    edges: &[PipelineEdgeSnapshot],
+) -> PipelineRunNode {
+    let conditions = edges
+        .iter()
+        .filter(|edge| edge.target_node_id == node.id && depends_on.contains(&edge.source_node_id))
+        .filter_map(|edge| Some((edge.source_node_id.clone(), edge.condition.clone()?)))
+        .collect();
+    PipelineRunNode {
+        node_id: node.id.clone(),
+        node_type: node.node_type.clone(),
+        title: node.title.clone(),
+        args: node_forge_args(node),
+        depends_on,
+        conditions,
+        group_id: None,
+        status: PENDING_STATUS.to_string(),
+        task_id: None,
+        progress_percent: 0.0,
+        started_at: None,
+        finished_at: None,
+        error: None,
+        fingerprint: None,
+        cached_from_run_id: None,
+        exit_code: None,
+        artifacts: vec![],
+        outputs: PipelineNodeOutputs::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_pipeline, select_plan};
+    use crate::models::{PipelineEdgeSnapshot, PipelineNodeSnapshot, PipelineRunOptions};
+    use std::collections::BTreeMap;
+
+    fn node(id: &str) -> PipelineNodeSnapshot {
+        PipelineNodeSnapshot {
+            id: id.to_string(),
+            node_type: "custom".to_string(),
+            title: id.to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: BTreeMap::new(),
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> PipelineEdgeSnapshot {
+        PipelineEdgeSnapshot {
+            id: format!("{source}->{target}"),
+            source_node_id: source.to_string(),
+            target_node_id: target.to_string(),
+            condition: None,
+        }
+    }
+
+    #[test]
+    fn plan_pipeline_orders_reachable_nodes_by_dependencies() {
+        let nodes = vec![
+            node("train"),
+            node("filter"),
+            node("ingest"),
+            node("orphan"),
+        ];
+        let edges = vec![
+            edge("ingest", "filter"),
+            edge("filter", "train"),
+            edge("ingest", "train"),
+        ];
+        let planned = plan_pipeline(&nodes, &edges, "ingest");
+        let order: Vec<&str> = planned.iter().map(|node| node.node_id.as_str()).collect();
+        assert_eq!(order, ["ingest", "filter", "train"]);
+        assert_eq!(planned[2].depends_on, ["filter", "ingest"]);
+    }
+
+    #[test]
+    fn select_plan_keeps_downstream_subgraph_without_skipped_upstreams() {
+        let nodes = vec![node("ingest"), node("filter"), node("train"), node("chat")];
+        let edges = vec![
+            edge("ingest", "filter"),
+            edge("filter", "train"),
+            edge("train", "chat"),
+        ];
+        let planned = plan_pipeline(&nodes, &edges, "ingest");
+        let selection = PipelineRunOptions {
+            start_from_node_id: Some("train".to_string()),
+            ..PipelineRunOptions::default()
+        };
+        let selected = select_plan(planned.clone(), &selection).expect("selection should apply");
+        let order: Vec<&str> = selected.iter().map(|node| node.node_id.as_str()).collect();
+        assert_eq!(order, ["train", "chat"]);
+        assert!(selected[0].depends_on.is_empty());
+        let only_chat = PipelineRunOptions {
+            start_from_node_id: Some("train".to_string()),
+            only_nodes: vec!["ingest".to_string()],
+            ..PipelineRunOptions::default()
+        };
+        assert!(select_plan(planned, &only_chat).is_err());
+    }
+}
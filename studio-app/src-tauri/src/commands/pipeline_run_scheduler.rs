@@ -0,0 +1,275 @@
+//! Background executor that walks a pipeline run's plan through the task store.
+//!
+//! Independent branches run side by side, with at most the run's
+//! `max_parallel` node tasks in the task store at once. A node starts once
+//! every upstream node completed or was served from the pipeline cache, so a
+//! fan-in node waits for all of its branches; ready nodes start in plan order
+//! and finished tasks settle in plan order, so which branch finishes first
+//! never changes what runs next or how upstream fingerprints combine. Ingest
+//! and filter write new dataset versions, so a node never runs alongside one
+//! of them on the same dataset. When a node fails, everything downstream of
+//! it is skipped while independent branches keep going. A node whose edge
+//! condition is not met is skipped along with its dependents, which still
+//! lets the run complete. Once nothing is left to run, the history entry is
+//! completed and the run's outputs are linked into the lineage graph, and a
+//! desktop notification is raised when the `pipeline_finished` setting is
+//! on. Each saved change is also published on the event bus. The scheduler
+//! owns the run record while it lives and is its only writer.
+
+use crate::commands::edge_conditions::unmet_condition;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::os_notification::send_os_notification;
+use crate::commands::pipeline_cache::{node_fingerprint, record_execution};
+use crate::commands::pipeline_history::finish_pipeline_history;
+use crate::commands::pipeline_lineage::record_pipeline_lineage;
+use crate::commands::pipeline_node_outcomes::{
+    reuse_cached_execution, settle_node, skip_unmet_condition,
+};
+use crate::commands::pipeline_node_readiness::{next_ready_node, skip_after_failure};
+use crate::commands::pipeline_run_store::save_pipeline_run;
+use crate::commands::pipeline_runs::{
+    overall_percent, timestamp_now, COMPLETED_STATUS, FAILED_STATUS, PENDING_STATUS,
+    RUNNING_STATUS, SKIPPED_STATUS,
+};
+use crate::commands::run_store_io::resolve_workspace_path;
+use crate::commands::train_task_log::spawn_train_log_recorder;
+use crate::models::{BackendEvent, PipelineRunRecord};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+const PIPELINE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) fn spawn_pipeline_run_scheduler(
+    data_root: String,
+    task_store: CommandTaskStore,
+    mut record: PipelineRunRecord,
+) {
+    // The command that started the run already confined `data_root`.
+    let resolved_data_root = resolve_workspace_path(&data_root);
+    thread::spawn(move || loop {
+        let changed = refresh_running_nodes(&resolved_data_root, &task_store, &mut record)
+            | start_ready_nodes(&data_root, &task_store, &mut record);
+        let finished = !record
+            .nodes
+            .iter()
+            .any(|node| node.status == RUNNING_STATUS);
+        if finished {
+            settle_run_status(&mut record);
+        }
+        if changed || finished {
+            record.overall_percent = overall_percent(&record.nodes);
+            if let Err(error) = save_pipeline_run(&resolved_data_root, &record) {
+                tracing::warn!("{error}");
+            }
+            task_store
+                .event_bus()
+                .publish(BackendEvent::PipelineRunUpdated {
+                    pipeline_run_id: record.pipeline_run_id.clone(),
+                    status: record.status.clone(),
+                    overall_percent: record.overall_percent,
+                });
+        }
+        if finished {
+            if let Err(error) = finish_pipeline_history(&resolved_data_root, &record) {
+                tracing::warn!("{error}");
+            }
+            let run_id = &record.pipeline_run_id;
+            if let Err(error) = record_pipeline_lineage(&resolved_data_root, run_id, &record.nodes)
+            {
+                tracing::warn!("{error}");
+            }
+            if task_store
+                .settings()
+                .current()
+                .notifications
+                .pipeline_finished
+            {
+                send_os_notification(&format!("Forge pipeline {}", record.status), run_id);
+            }
+            return;
+        }
+        thread::sleep(PIPELINE_POLL_INTERVAL);
+    });
+}
+
+/// Skips stranded pending nodes and sets the run's final status.
+fn settle_run_status(record: &mut PipelineRunRecord) {
+    // Nothing left can start, so any pending node is stranded.
+    for node in record
+        .nodes
+        .iter_mut()
+        .filter(|node| node.status == PENDING_STATUS)
+    {
+        node.status = SKIPPED_STATUS.to_string();
+    }
+    // Condition skips are planned, so only a failed node fails the run.
+    let any_failed = record.nodes.iter().any(|node| node.status == FAILED_STATUS);
+    record.status = if any_failed {
+        FAILED_STATUS
+    } else {
+        COMPLETED_STATUS
+    }
+    .to_string();
+    record.finished_at = Some(timestamp_now());
+}
+
+/// Settles every finished task in plan order, caching successes and
+/// skipping whatever depended on a failure.
+fn refresh_running_nodes(
+    data_root: &Path,
+    task_store: &CommandTaskStore,
+    record: &mut PipelineRunRecord,
+) -> bool {
+    let mut changed = false;
+    for index in 0..record.nodes.len() {
+        let node = &mut record.nodes[index];
+        let Some(task_id) = node
+            .task_id
+            .clone()
+            .filter(|_| node.status == RUNNING_STATUS)
+        else {
+            continue;
+        };
+        match task_store.get_task_status(&task_id) {
+            Ok(task_status) if task_status.status == RUNNING_STATUS => {
+                node.progress_percent = task_status.progress_percent;
+                continue;
+            }
+            task_status => settle_node(data_root, node, task_status),
+        }
+        changed = true;
+        let node = &record.nodes[index];
+        if node.status == FAILED_STATUS {
+            let failed_node_id = node.node_id.clone();
+            skip_after_failure(&mut record.nodes, &failed_node_id);
+        } else if let Err(error) =
+            record_execution(data_root, node, &record.nodes, &record.pipeline_run_id)
+        {
+            tracing::warn!("{error}");
+        }
+    }
+    changed
+}
+
+/// Starts ready nodes while fewer than `max_parallel` are running. Nodes the
+/// cache already holds are marked `cached` on the way without taking a slot.
+fn start_ready_nodes(
+    data_root: &str,
+    task_store: &CommandTaskStore,
+    record: &mut PipelineRunRecord,
+) -> bool {
+    let resolved_data_root = resolve_workspace_path(data_root);
+    let mut changed = false;
+    loop {
+        let running = record
+            .nodes
+            .iter()
+            .filter(|node| node.status == RUNNING_STATUS)
+            .count();
+        if running >= record.max_parallel.max(1) {
+            return changed;
+        }
+        let Some(index) = next_ready_node(&record.nodes) else {
+            return changed;
+        };
+        changed = true;
+        if let Some(reason) =
+            unmet_condition(&resolved_data_root, &record.nodes[index], &record.nodes)
+        {
+            skip_unmet_condition(&mut record.nodes, index, reason);
+            continue;
+        }
+        let fingerprint =
+            node_fingerprint(&resolved_data_root, &record.nodes[index], &record.nodes);
+        record.nodes[index].fingerprint = fingerprint;
+        if !record.force && reuse_cached_execution(&resolved_data_root, &mut record.nodes[index]) {
+            continue;
+        }
+        let node = &mut record.nodes[index];
+        let task = task_store.start_task(data_root.to_string(), node.args.clone());
+        spawn_train_log_recorder(task_store.clone(), task.task_id.clone(), &node.args);
+        node.status = RUNNING_STATUS.to_string();
+        node.task_id = Some(task.task_id);
+        node.started_at = Some(timestamp_now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{settle_run_status, start_ready_nodes};
+    use crate::commands::forge_task_store::CommandTaskStore;
+    use crate::commands::pipeline_runs::{
+        COMPLETED_STATUS, FAILED_STATUS, PENDING_STATUS, RUNNING_STATUS, SKIPPED_STATUS,
+    };
+    use crate::models::{PipelineRunNode, PipelineRunRecord};
+    use serde_json::json;
+
+    fn record(statuses: &[&str], max_parallel: usize) -> PipelineRunRecord {
+        let nodes: Vec<PipelineRunNode> = statuses
+            .iter()
+            .enumerate()
+            .map(|(index, status)| {
+                serde_json::from_value(json!({
+                    "node_id": format!("node-{index}"), "node_type": "custom",
+                    "title": "Versions", "args": ["versions", "--dataset", format!("d{index}")],
+                    "depends_on": [], "status": status, "task_id": null,
+                    "progress_percent": 0.0, "started_at": null, "finished_at": null,
+                    "error": null,
+                }))
+                .expect("node should parse")
+            })
+            .collect();
+        PipelineRunRecord {
+            pipeline_run_id: "pipeline-run-0001".to_string(),
+            start_node_id: "node-0".to_string(),
+            status: RUNNING_STATUS.to_string(),
+            created_at: "t0".to_string(),
+            finished_at: None,
+            overall_percent: 0.0,
+            force: true,
+            max_parallel,
+            schedule_id: None,
+            nodes,
+        }
+    }
+
+    fn statuses(record: &PipelineRunRecord) -> Vec<&str> {
+        record
+            .nodes
+            .iter()
+            .map(|node| node.status.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn settle_run_status_skips_stranded_nodes_and_fails_on_any_failure() {
+        let mut failed = record(&[COMPLETED_STATUS, PENDING_STATUS, FAILED_STATUS], 2);
+        settle_run_status(&mut failed);
+        assert_eq!(failed.status, FAILED_STATUS);
+        assert_eq!(statuses(&failed)[1], SKIPPED_STATUS);
+        assert!(failed.finished_at.is_some());
+
+        let mut completed = record(&[COMPLETED_STATUS, SKIPPED_STATUS], 2);
+        settle_run_status(&mut completed);
+        assert_eq!(completed.status, COMPLETED_STATUS);
+    }
+
+    #[test]
+    fn start_ready_nodes_stops_at_max_parallel() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-pipeline-scheduler-{}", std::process::id()));
+        std::fs::create_dir_all(&data_root).expect("temp dir should be created");
+        let data_root_text = data_root.display().to_string();
+        let task_store = CommandTaskStore::default();
+        let mut run = record(&[PENDING_STATUS, PENDING_STATUS, PENDING_STATUS], 2);
+
+        assert!(start_ready_nodes(&data_root_text, &task_store, &mut run));
+        assert_eq!(
+            statuses(&run),
+            [RUNNING_STATUS, RUNNING_STATUS, PENDING_STATUS]
+        );
+        assert!(!start_ready_nodes(&data_root_text, &task_store, &mut run));
+        std::fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
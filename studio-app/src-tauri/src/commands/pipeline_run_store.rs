@@ -0,0 +1,159 @@
+//! Pipeline run records in `<data_root>/pipeline_runs/<pipeline_run_id>.json`.
+//!
+//! A new run's id is one past the highest id on disk, allocated while holding
+//! the lock on the `pipeline_runs` directory, and its file is created with
+//! `create_new`, so two runs started at once, from Studio or a schedule,
+//! never share an id or overwrite each other's record.
+
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::run_store_io::{read_json_file, write_json_file};
+use crate::models::{ForgeStudioError, PipelineRunRecord};
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const PIPELINE_RUNS_DIR_NAME: &str = "pipeline_runs";
+/// Run records share their directory with the pipeline cache file.
+const PIPELINE_RUN_ID_PREFIX: &str = "pipeline-run-";
+
+/// Gives `record` the next free pipeline run id and writes its first file.
+pub(crate) fn create_pipeline_run(
+    data_root: &Path,
+    record: &mut PipelineRunRecord,
+) -> Result<(), ForgeStudioError> {
+    let runs_dir = data_root.join(PIPELINE_RUNS_DIR_NAME);
+    fs::create_dir_all(&runs_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to create {}: {error}", runs_dir.display()))
+    })?;
+    with_file_lock(&runs_dir, || {
+        record.pipeline_run_id = next_pipeline_run_id(&runs_dir)?;
+        let payload_path = pipeline_run_path(data_root, &record.pipeline_run_id);
+        let serialized = serde_json::to_string_pretty(&run_payload(record)?).map_err(|error| {
+            ForgeStudioError::internal(format!("Failed to serialize pipeline run: {error}"))
+        })?;
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&payload_path)
+            .and_then(|mut file| file.write_all(serialized.as_bytes()))
+            .map_err(|error| {
+                ForgeStudioError::conflict(format!(
+                    "Failed to create pipeline run {}: {error}. Start the run again.",
+                    payload_path.display()
+                ))
+            })
+    })
+}
+
+pub(crate) fn save_pipeline_run(
+    data_root: &Path,
+    record: &PipelineRunRecord,
+) -> Result<(), ForgeStudioError> {
+    write_json_file(
+        &pipeline_run_path(data_root, &record.pipeline_run_id),
+        &run_payload(record)?,
+    )
+}
+
+pub(crate) fn load_pipeline_run(
+    data_root: &Path,
+    pipeline_run_id: &str,
+) -> Result<PipelineRunRecord, ForgeStudioError> {
+    let payload_path = pipeline_run_path(data_root, pipeline_run_id);
+    if !payload_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Pipeline run '{pipeline_run_id}' does not exist under {}. Check the pipeline run id.",
+            data_root.display()
+        )));
+    }
+    serde_json::from_value(read_json_file(&payload_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse pipeline run {}: {error}",
+            payload_path.display()
+        ))
+    })
+}
+
+fn run_payload(record: &PipelineRunRecord) -> Result<Value, ForgeStudioError> {
+    serde_json::to_value(record).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize pipeline run '{}': {error}",
+            record.pipeline_run_id
+        ))
+    })
+}
+
+fn pipeline_run_path(data_root: &Path, pipeline_run_id: &str) -> PathBuf {
+    data_root
+        .join(PIPELINE_RUNS_DIR_NAME)
+        .join(format!("{pipeline_run_id}.json"))
+}
+
+/// One past the highest run number in `runs_dir`, so ids never repeat after
+/// a run record is removed.
+fn next_pipeline_run_id(runs_dir: &Path) -> Result<String, ForgeStudioError> {
+    let entries = fs::read_dir(runs_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", runs_dir.display()))
+    })?;
+    let last_number = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let run_number = file_name
+                .strip_prefix(PIPELINE_RUN_ID_PREFIX)?
+                .strip_suffix(".json")?;
+            run_number.parse::<u32>().ok()
+        })
+        .max()
+        .unwrap_or(0);
+    Ok(format!("{PIPELINE_RUN_ID_PREFIX}{:04}", last_number + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create_pipeline_run, load_pipeline_run, PIPELINE_RUNS_DIR_NAME};
+    use crate::models::PipelineRunRecord;
+    use std::fs;
+
+    fn empty_record() -> PipelineRunRecord {
+        PipelineRunRecord {
+            pipeline_run_id: String::new(),
+            start_node_id: "ingest".to_string(),
+            status: "running".to_string(),
+            created_at: "t0".to_string(),
+            finished_at: None,
+            overall_percent: 0.0,
+            force: false,
+            max_parallel: 2,
+            schedule_id: None,
+            nodes: vec![],
+        }
+    }
+
+    #[test]
+    fn run_ids_follow_the_highest_existing_id() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-pipeline-run-store-{}", std::process::id()));
+        let runs_dir = data_root.join(PIPELINE_RUNS_DIR_NAME);
+        fs::create_dir_all(&runs_dir).expect("runs dir should be created");
+        // A gap from a removed run must not hand out pipeline-run-0003 again.
+        fs::write(runs_dir.join("pipeline-run-0003.json"), "{}").expect("record written");
+        fs::write(runs_dir.join("cache.json"), "{}").expect("cache written");
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let mut record = empty_record();
+            create_pipeline_run(&data_root, &mut record).expect("run should be created");
+            ids.push(record.pipeline_run_id);
+        }
+        assert_eq!(ids, ["pipeline-run-0004", "pipeline-run-0005"]);
+        let loaded = load_pipeline_run(&data_root, "pipeline-run-0005").expect("run loads");
+        assert_eq!(loaded.start_node_id, "ingest");
+        assert_eq!(
+            fs::read_to_string(runs_dir.join("pipeline-run-0003.json")).expect("still there"),
+            "{}"
+        );
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
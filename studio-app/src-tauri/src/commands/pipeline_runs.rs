@@ -0,0 +1,249 @@
+//! Runs a pipeline canvas as forge tasks in dependency order.
+//!
+//! `${variable}` placeholders are substituted first. The canvas graph and
+//! node configs are then validated and the plan built before anything
+//! starts, and every node's args are checked up front, so a bad node fails
+//! the request instead of the run halfway through. A background scheduler
+//! then executes the plan, running independent branches side by side and
+//! reusing cached node executions unless the run is forced. Node groups run
+//! as one step: selecting a group selects its members, and a failed member
+//! skips the rest of its group. The persisted
+//! record is the source of truth for node state, with live task progress
+//! overlaid when status is requested.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_dataset_refs::check_dataset_references;
+use crate::commands::canvas_groups::{assign_groups, expand_group_ids};
+use crate::commands::canvas_validation::blocking_issue_messages;
+use crate::commands::canvas_variables::substitute_variables;
+use crate::commands::forge_commands::validate_args;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::pipeline_history::start_pipeline_history;
+use crate::commands::pipeline_plan::{plan_pipeline, select_plan};
+use crate::commands::pipeline_run_scheduler::spawn_pipeline_run_scheduler;
+use crate::commands::pipeline_run_store::{create_pipeline_run, load_pipeline_run};
+use crate::commands::run_store_io::{resolve_data_root_path, resolve_data_root_text};
+use crate::models::{
+    ForgeStudioError, PipelineCanvasIssue, PipelineCanvasState, PipelineNodeSnapshot,
+    PipelineRunNode, PipelineRunOptions, PipelineRunRecord, PipelineRunStart,
+};
+use chrono::{SecondsFormat, Utc};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const DEFAULT_MAX_PARALLEL_NODES: usize = 2;
+const MAX_PARALLEL_NODES: usize = 8;
+pub(crate) const PENDING_STATUS: &str = "pending";
+pub(crate) const RUNNING_STATUS: &str = "running";
+pub(crate) const COMPLETED_STATUS: &str = "completed";
+pub(crate) const CACHED_STATUS: &str = "cached";
+pub(crate) const FAILED_STATUS: &str = "failed";
+pub(crate) const SKIPPED_STATUS: &str = "skipped";
+
+#[tauri::command]
+pub fn run_pipeline_canvas(
+    data_root: String,
+    canvas: PipelineCanvasState,
+    options: Option<PipelineRunOptions>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineRunStart, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let options = options.unwrap_or_default();
+    start_pipeline_run(data_root, canvas, options, task_store.inner().clone())
+}
+
+/// Validates, plans, and records a run, then hands it to a background scheduler.
+pub(crate) fn start_pipeline_run(
+    data_root: String,
+    mut canvas: PipelineCanvasState,
+    mut options: PipelineRunOptions,
+    task_store: CommandTaskStore,
+) -> Result<PipelineRunStart, ForgeStudioError> {
+    let start_node_id = canvas.start_node_id.clone().ok_or_else(|| {
+        ForgeStudioError::invalid_input(
+            "Pipeline cannot run: the canvas has no start node. Set one and save the canvas.",
+        )
+    })?;
+    let (selected, planned_ids) = checked_plan(&mut canvas, &start_node_id, &mut options)?;
+    let selected_ids: HashSet<&str> = selected.iter().map(|node| node.node_id.as_str()).collect();
+    let warnings = selected_reference_warnings(&data_root, &canvas.nodes, &selected_ids);
+    let (excluded_node_ids, unreachable_node_ids) =
+        left_out_node_ids(&canvas.nodes, &selected_ids, &planned_ids);
+    let resolved_data_root = PathBuf::from(&data_root);
+    let mut record = PipelineRunRecord {
+        pipeline_run_id: String::new(),
+        start_node_id: start_node_id.clone(),
+        status: RUNNING_STATUS.to_string(),
+        created_at: timestamp_now(),
+        finished_at: None,
+        overall_percent: 0.0,
+        force: options.force,
+        max_parallel: options.max_parallel.unwrap_or(DEFAULT_MAX_PARALLEL_NODES),
+        schedule_id: options.schedule_id,
+        nodes: selected,
+    };
+    create_pipeline_run(&resolved_data_root, &mut record)?;
+    start_pipeline_history(&resolved_data_root, &record, canvas, &options.variables)?;
+    let start = PipelineRunStart {
+        pipeline_run_id: record.pipeline_run_id.clone(),
+        node_count: record.nodes.len(),
+        unreachable_node_ids,
+        excluded_node_ids,
+        warnings,
+    };
+    spawn_pipeline_run_scheduler(data_root, task_store, record);
+    Ok(start)
+}
+
+#[tauri::command]
+pub fn get_pipeline_run_status(
+    data_root: String,
+    pipeline_run_id: String,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineRunRecord, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    pipeline_run_status(&resolved_data_root, &pipeline_run_id, &task_store)
+}
+
+/// The run record with live progress of its running nodes.
+pub(crate) fn pipeline_run_status(
+    resolved_data_root: &Path,
+    pipeline_run_id: &str,
+    task_store: &CommandTaskStore,
+) -> Result<PipelineRunRecord, ForgeStudioError> {
+    let mut record = load_pipeline_run(resolved_data_root, pipeline_run_id)?;
+    for node in &mut record.nodes {
+        let Some(task_id) = node
+            .task_id
+            .as_deref()
+            .filter(|_| node.status == RUNNING_STATUS)
+        else {
+            continue;
+        };
+        if let Ok(task_status) = task_store.get_task_status(task_id) {
+            node.progress_percent = task_status.progress_percent;
+        }
+    }
+    record.overall_percent = overall_percent(&record.nodes);
+    Ok(record)
+}
+
+/// The selected plan with every node's args validated, plus the ids of the
+/// full plan before selection. Variables are substituted into `canvas` and
+/// group ids in the selection replaced by their members first.
+pub(crate) fn checked_plan(
+    canvas: &mut PipelineCanvasState,
+    start_node_id: &str,
+    options: &mut PipelineRunOptions,
+) -> Result<(Vec<PipelineRunNode>, HashSet<String>), ForgeStudioError> {
+    if let Some(max_parallel) = options
+        .max_parallel
+        .filter(|max_parallel| !(1..=MAX_PARALLEL_NODES).contains(max_parallel))
+    {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Pipeline cannot run: max_parallel {max_parallel} is out of range. Use 1 to {MAX_PARALLEL_NODES} nodes."
+        )));
+    }
+    canvas.nodes = substitute_variables(std::mem::take(&mut canvas.nodes), &options.variables)?;
+    options.only_nodes = expand_group_ids(&options.only_nodes, &canvas.groups);
+    let (nodes, edges, groups) = (&canvas.nodes, &canvas.edges, &canvas.groups);
+    let errors = blocking_issue_messages(nodes, edges, groups, start_node_id);
+    if !errors.is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Pipeline cannot run: {}",
+            errors.join(" ")
+        )));
+    }
+    let mut planned = plan_pipeline(nodes, edges, start_node_id);
+    assign_groups(&mut planned, groups);
+    let planned_ids: HashSet<String> = planned.iter().map(|node| node.node_id.clone()).collect();
+    let selected = select_plan(planned, options)?;
+    for node in &selected {
+        validate_args(&node.args).map_err(|error| {
+            ForgeStudioError::invalid_input(format!(
+                "Pipeline node '{}' cannot run: {error}",
+                node.node_id
+            ))
+        })?;
+    }
+    Ok((selected, planned_ids))
+}
+
+/// Nodes a run leaves out, split into those the selection excluded and
+/// those not reachable from the start node.
+pub(crate) fn left_out_node_ids(
+    nodes: &[PipelineNodeSnapshot],
+    selected_ids: &HashSet<&str>,
+    planned_ids: &HashSet<String>,
+) -> (Vec<String>, Vec<String>) {
+    nodes
+        .iter()
+        .filter(|node| !selected_ids.contains(node.id.as_str()))
+        .map(|node| node.id.clone())
+        .partition(|node_id| planned_ids.contains(node_id))
+}
+
+/// Stale dataset references among the nodes this run executes.
+fn selected_reference_warnings(
+    data_root: &str,
+    nodes: &[PipelineNodeSnapshot],
+    selected_ids: &HashSet<&str>,
+) -> Vec<PipelineCanvasIssue> {
+    let selected_nodes: Vec<PipelineNodeSnapshot> = nodes
+        .iter()
+        .filter(|node| selected_ids.contains(node.id.as_str()))
+        .cloned()
+        .collect();
+    check_dataset_references(data_root, &selected_nodes)
+}
+
+/// Mean node progress; cached and skipped nodes count as done, so a failed run can reach 100%.
+pub(crate) fn overall_percent(nodes: &[PipelineRunNode]) -> f64 {
+    if nodes.is_empty() {
+        return 100.0;
+    }
+    let total: f64 = nodes
+        .iter()
+        .map(|node| match node.status.as_str() {
+            PENDING_STATUS => 0.0,
+            RUNNING_STATUS => node.progress_percent,
+            _ => 100.0,
+        })
+        .sum();
+    total / nodes.len() as f64
+}
+
+pub(crate) fn timestamp_now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{overall_percent, CACHED_STATUS, PENDING_STATUS, RUNNING_STATUS, SKIPPED_STATUS};
+    use crate::models::PipelineRunNode;
+
+    fn node(status: &str, progress_percent: f64) -> PipelineRunNode {
+        serde_json::from_value(serde_json::json!({
+            "node_id": "n", "node_type": "custom", "title": "n", "args": [],
+            "depends_on": [], "status": status, "task_id": null,
+            "progress_percent": progress_percent, "started_at": null,
+            "finished_at": null, "error": null,
+        }))
+        .expect("node should parse")
+    }
+
+    #[test]
+    fn overall_percent_counts_cached_and_skipped_nodes_as_done() {
+        assert_eq!(overall_percent(&[]), 100.0);
+        let nodes = [
+            node(PENDING_STATUS, 40.0),
+            node(RUNNING_STATUS, 50.0),
+            node(CACHED_STATUS, 0.0),
+            node(SKIPPED_STATUS, 0.0),
+        ];
+        assert_eq!(overall_percent(&nodes), 62.5);
+    }
+}
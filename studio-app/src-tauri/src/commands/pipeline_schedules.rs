@@ -0,0 +1,298 @@
+//! Cron-scheduled pipeline runs for saved canvases.
+//!
+//! Schedules live in `<data_root>/pipeline_runs/schedules.json`. A ticker
+//! thread per data root, started the first time its schedules are created or
+//! listed, starts due runs through the same path as `run_pipeline_canvas`,
+//! so each scheduled run lands in the pipeline run history tagged with its
+//! schedule. Schedules fire only while Studio is open: a run missed while it
+//! was closed fires once on the next tick rather than once per missed slot.
+//! A schedule whose previous run is still going skips that slot instead of
+//! piling a second run onto the same GPU. In read-only mode the ticker
+//! skips its runs, and due ones fire once the mode is turned off.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::canvas_import::read_canvas_file;
+use crate::commands::canvas_variables::substitute_variables;
+use crate::commands::cron_schedule::CronSchedule;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::pipeline_run_store::load_pipeline_run;
+use crate::commands::pipeline_runs::{start_pipeline_run, timestamp_now, RUNNING_STATUS};
+use crate::commands::run_store_io::{
+    read_json_file, resolve_data_root_path, resolve_data_root_text, write_json_file,
+};
+use crate::models::{ForgeStudioError, PipelineCanvasState, PipelineRunOptions, PipelineSchedule};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::State;
+
+const PIPELINE_SCHEDULES_FILE: &str = "pipeline_runs/schedules.json";
+const SCHEDULE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Default)]
+pub struct PipelineScheduleStore {
+    inner: Arc<PipelineScheduleStoreInner>,
+}
+
+/// What a ticker thread needs to start the due runs of one confined data root.
+struct ScheduleTicker {
+    data_root: String,
+    task_store: CommandTaskStore,
+    settings: SettingsStore,
+}
+
+#[derive(Default)]
+struct PipelineScheduleStoreInner {
+    /// Resolved data roots that already have a ticker thread.
+    ticking: Mutex<HashSet<PathBuf>>,
+    /// Serializes read-modify-write of schedule files between commands and tickers.
+    file_lock: Mutex<()>,
+}
+
+#[tauri::command]
+pub fn create_pipeline_schedule(
+    data_root: String,
+    canvas_path: String,
+    cron: String,
+    variables: Option<BTreeMap<String, String>>,
+    schedule_store: State<'_, PipelineScheduleStore>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<PipelineSchedule, ForgeStudioError> {
+    let next_run_at = CronSchedule::parse(&cron)?
+        .next_after(Utc::now())
+        .ok_or_else(|| {
+            ForgeStudioError::invalid_input(format!(
+                "Cron expression '{cron}' never fires. Check the day and month."
+            ))
+        })?;
+    let variables = variables.unwrap_or_default();
+    let roots = settings.workspace_roots();
+    let data_root = resolve_data_root_text(&data_root, &roots)?;
+    let canvas = read_canvas_file(&data_root, &canvas_path, &roots)?;
+    if canvas.start_node_id.is_none() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Canvas {} has no start node. Set one, save the canvas, and schedule it again.",
+            canvas.source_path
+        )));
+    }
+    substitute_variables(canvas.nodes, &variables)?;
+    let schedule = {
+        let _guard = schedule_store.lock_files()?;
+        let mut schedules = load_schedules(Path::new(&data_root))?;
+        let schedule = PipelineSchedule {
+            schedule_id: format!("pipeline-schedule-{:04}", next_schedule_number(&schedules)),
+            canvas_path,
+            cron,
+            variables,
+            created_at: timestamp_now(),
+            next_run_at: format_timestamp(next_run_at),
+            last_run_at: None,
+            last_pipeline_run_id: None,
+            last_error: None,
+        };
+        schedules.push(schedule.clone());
+        save_schedules(Path::new(&data_root), &schedules)?;
+        schedule
+    };
+    schedule_store.ensure_ticker(ScheduleTicker {
+        data_root,
+        task_store: task_store.inner().clone(),
+        settings: settings.inner().clone(),
+    });
+    Ok(schedule)
+}
+
+#[tauri::command]
+pub fn list_pipeline_schedules(
+    data_root: String,
+    schedule_store: State<'_, PipelineScheduleStore>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<PipelineSchedule>, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let schedules = {
+        let _guard = schedule_store.lock_files()?;
+        load_schedules(Path::new(&data_root))?
+    };
+    schedule_store.ensure_ticker(ScheduleTicker {
+        data_root,
+        task_store: task_store.inner().clone(),
+        settings: settings.inner().clone(),
+    });
+    Ok(schedules)
+}
+
+#[tauri::command]
+pub fn delete_pipeline_schedule(
+    data_root: String,
+    schedule_id: String,
+    schedule_store: State<'_, PipelineScheduleStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<(), ForgeStudioError> {
+    let _guard = schedule_store.lock_files()?;
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let mut schedules = load_schedules(&resolved_data_root)?;
+    let before = schedules.len();
+    schedules.retain(|schedule| schedule.schedule_id != schedule_id);
+    if schedules.len() == before {
+        return Err(ForgeStudioError::not_found(format!(
+            "Pipeline schedule '{schedule_id}' does not exist. Check the id with list_pipeline_schedules."
+        )));
+    }
+    save_schedules(&resolved_data_root, &schedules)
+}
+
+impl PipelineScheduleStore {
+    fn lock_files(&self) -> Result<std::sync::MutexGuard<'_, ()>, ForgeStudioError> {
+        self.inner
+            .file_lock
+            .lock()
+            .map_err(|_| ForgeStudioError::internal("Pipeline schedule store lock poisoned"))
+    }
+
+    fn ensure_ticker(&self, ticker: ScheduleTicker) {
+        let Ok(mut ticking) = self.inner.ticking.lock() else {
+            return;
+        };
+        if !ticking.insert(PathBuf::from(&ticker.data_root)) {
+            return;
+        }
+        let store = self.clone();
+        thread::spawn(move || {
+            // Runs this session started, per schedule, to detect overlap.
+            let mut started_runs: HashMap<String, String> = HashMap::new();
+            loop {
+                let fired = if ticker.settings.current().read_only {
+                    Ok(())
+                } else {
+                    store.fire_due_schedules(&ticker, &mut started_runs)
+                };
+                if let Err(error) = fired {
+                    tracing::warn!("{error}");
+                }
+                thread::sleep(SCHEDULE_POLL_INTERVAL);
+            }
+        });
+    }
+
+    fn fire_due_schedules(
+        &self,
+        ticker: &ScheduleTicker,
+        started_runs: &mut HashMap<String, String>,
+    ) -> Result<(), ForgeStudioError> {
+        let _guard = self.lock_files()?;
+        // Checked again each tick, since the data root's workspace may have been removed.
+        let resolved_data_root =
+            resolve_data_root_path(&ticker.data_root, &ticker.settings.workspace_roots())?;
+        let mut schedules = load_schedules(&resolved_data_root)?;
+        let now = Utc::now();
+        let mut fired = false;
+        for schedule in &mut schedules {
+            let due = DateTime::parse_from_rfc3339(&schedule.next_run_at)
+                .map_or(true, |next_run_at| next_run_at <= now);
+            if !due {
+                continue;
+            }
+            fired = true;
+            schedule.last_run_at = Some(format_timestamp(now));
+            let next_run_at = CronSchedule::parse(&schedule.cron)
+                .ok()
+                .and_then(|cron| cron.next_after(now));
+            // A schedule that can no longer fire is parked a century out rather than dropped.
+            schedule.next_run_at =
+                format_timestamp(next_run_at.unwrap_or(now + chrono::Duration::days(36_500)));
+            match trigger(ticker, schedule, started_runs) {
+                Ok(pipeline_run_id) => {
+                    started_runs.insert(schedule.schedule_id.clone(), pipeline_run_id.clone());
+                    schedule.last_pipeline_run_id = Some(pipeline_run_id);
+                    schedule.last_error = None;
+                }
+                Err(error) => schedule.last_error = Some(error.to_string()),
+            }
+        }
+        if fired {
+            save_schedules(&resolved_data_root, &schedules)?;
+        }
+        Ok(())
+    }
+}
+
+fn trigger(
+    ticker: &ScheduleTicker,
+    schedule: &PipelineSchedule,
+    started_runs: &HashMap<String, String>,
+) -> Result<String, ForgeStudioError> {
+    let data_root = &ticker.data_root;
+    if let Some(previous_run_id) = started_runs.get(&schedule.schedule_id) {
+        let previous = load_pipeline_run(Path::new(data_root), previous_run_id)?;
+        if previous.status == RUNNING_STATUS {
+            return Err(ForgeStudioError::conflict(format!(
+                "Skipped the run due at {}: previous run '{previous_run_id}' is still running. Widen the cron interval if this repeats.",
+                schedule.last_run_at.as_deref().unwrap_or_default()
+            )));
+        }
+    }
+    let roots = ticker.settings.workspace_roots();
+    let canvas = read_canvas_file(data_root, &schedule.canvas_path, &roots)?;
+    let canvas = PipelineCanvasState {
+        start_node_id: canvas.start_node_id,
+        nodes: canvas.nodes,
+        edges: canvas.edges,
+        groups: canvas.groups,
+    };
+    let options = PipelineRunOptions {
+        variables: schedule.variables.clone(),
+        schedule_id: Some(schedule.schedule_id.clone()),
+        ..PipelineRunOptions::default()
+    };
+    let task_store = ticker.task_store.clone();
+    let start = start_pipeline_run(data_root.clone(), canvas, options, task_store)?;
+    Ok(start.pipeline_run_id)
+}
+
+fn next_schedule_number(schedules: &[PipelineSchedule]) -> u32 {
+    schedules
+        .iter()
+        .filter_map(|schedule| schedule.schedule_id.rsplit('-').next()?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn load_schedules(data_root: &Path) -> Result<Vec<PipelineSchedule>, ForgeStudioError> {
+    let schedules_path = data_root.join(PIPELINE_SCHEDULES_FILE);
+    if !schedules_path.is_file() {
+        return Ok(vec![]);
+    }
+    serde_json::from_value(read_json_file(&schedules_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse pipeline schedules {}: {error}",
+            schedules_path.display()
+        ))
+    })
+}
+
+fn save_schedules(
+    data_root: &Path,
+    schedules: &[PipelineSchedule],
+) -> Result<(), ForgeStudioError> {
+    let schedules_path = data_root.join(PIPELINE_SCHEDULES_FILE);
+    if let Some(parent) = schedules_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(schedules).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize pipeline schedules: {error}"))
+    })?;
+    write_json_file(&schedules_path, &payload)
+}
@@ -0,0 +1,275 @@
+//! Subprocess protocol for plugin commands and dataset analyzers.
+//!
+//! Each call runs the plugin's executable once from the workspace root and
+//! writes one JSON request to its stdin:
+//! `{"protocol_version": 1, "kind": "command" | "dataset_analyzer", "name": ..., "params": {...}}`.
+//! The plugin prints one JSON reply on stdout, `{"result": ...}` or
+//! `{"error": "message"}`, and exits 0; stderr is only quoted when it exits
+//! nonzero. A call still running after `PLUGIN_TIMEOUT` is stopped, whether
+//! it is stuck computing or never reads its request. Plugin commands may
+//! write, so in read-only mode only those their manifest marks `read_only`
+//! run. Analyzers get the dataset version's `records_path` and are expected
+//! to only read it.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::dataset_store_io::{records_path, resolve_version};
+use crate::commands::forge_process::{join_pipe_reader, read_pipe_in_background};
+use crate::commands::plugin_registry::{loaded_plugin, PLUGIN_PROTOCOL_VERSION};
+use crate::commands::run_store_io::{resolve_data_root_text, workspace_root_dir};
+use crate::models::{ForgeStudioError, PluginDatasetAnalysis, PluginInfo};
+use serde_json::{json, Value};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tauri::State;
+
+const COMMAND_KIND: &str = "command";
+const ANALYZER_KIND: &str = "dataset_analyzer";
+const PLUGIN_TIMEOUT: Duration = Duration::from_secs(300);
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Runs a plugin command with `params` and returns its result.
+#[tauri::command]
+pub async fn run_plugin_command(
+    plugin_name: String,
+    command: String,
+    params: Option<Value>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Value, ForgeStudioError> {
+    let plugin = loaded_plugin(&plugin_name)?;
+    let entry = plugin
+        .manifest
+        .commands
+        .iter()
+        .find(|entry| entry.name == command)
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Plugin '{plugin_name}' has no command '{command}'. Check list_plugins for its commands."
+            ))
+        })?;
+    if settings.current().read_only && !entry.read_only {
+        return Err(ForgeStudioError::read_only(format!(
+            "Plugin command '{plugin_name}/{command}' is unavailable in read-only mode because it may change the data root. Turn off read_only in settings to run it."
+        )));
+    }
+    let params = params.unwrap_or_else(|| json!({}));
+    run_blocking(move || call_plugin(&plugin, COMMAND_KIND, &command, params)).await
+}
+
+/// Runs a plugin's dataset analyzer on a version, the latest by default.
+#[tauri::command]
+pub async fn run_dataset_analyzer(
+    data_root: String,
+    dataset_name: String,
+    version_id: Option<String>,
+    plugin_name: String,
+    analyzer: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<PluginDatasetAnalysis, ForgeStudioError> {
+    let plugin = loaded_plugin(&plugin_name)?;
+    if !plugin
+        .manifest
+        .dataset_analyzers
+        .iter()
+        .any(|entry| entry.name == analyzer)
+    {
+        return Err(ForgeStudioError::not_found(format!(
+            "Plugin '{plugin_name}' has no dataset analyzer '{analyzer}'. Check list_plugins for its analyzers."
+        )));
+    }
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        let version_id = resolve_version(&data_root, &dataset_name, version_id)?;
+        let records_path = records_path(&data_root, &dataset_name, &version_id);
+        let params = json!({
+            "data_root": data_root,
+            "dataset_name": dataset_name,
+            "version_id": version_id,
+            "records_path": records_path.display().to_string(),
+        });
+        let result = call_plugin(&plugin, ANALYZER_KIND, &analyzer, params)?;
+        Ok(PluginDatasetAnalysis {
+            plugin_name,
+            analyzer,
+            dataset_name,
+            version_id,
+            result,
+        })
+    })
+    .await
+}
+
+/// Sends one request to the plugin's executable and returns its `result`.
+fn call_plugin(
+    plugin: &PluginInfo,
+    kind: &str,
+    name: &str,
+    params: Value,
+) -> Result<Value, ForgeStudioError> {
+    let plugin_name = &plugin.manifest.name;
+    let mut child = spawn_plugin(plugin)?;
+    let request = json!({
+        "protocol_version": PLUGIN_PROTOCOL_VERSION,
+        "kind": kind,
+        "name": name,
+        "params": params,
+    });
+    // Readers start first so a plugin that replies before reading its whole
+    // request never blocks on a full stdout pipe.
+    let stdout = read_pipe_in_background(child.stdout.take());
+    let stderr = read_pipe_in_background(child.stderr.take());
+    let writer = write_in_background(child.stdin.take(), request.to_string());
+    let status = wait_with_timeout(&mut child, plugin_name)?;
+    if !status.success() {
+        let stderr = join_pipe_reader(stderr, "plugin stderr");
+        return Err(ForgeStudioError::io(format!(
+            "Plugin '{plugin_name}' {kind} '{name}' failed with status {}: {}. Check the plugin's output.",
+            status.code().unwrap_or(-1),
+            stderr.lines().last().unwrap_or_default().trim()
+        )));
+    }
+    join_request_writer(writer, plugin_name)?;
+    let stdout = stdout
+        .join()
+        .unwrap_or_else(|_| Err(io::Error::other("the stdout reader thread panicked")));
+    let stdout = stdout.map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read plugin '{plugin_name}' reply: {error}. Check the plugin's output."
+        ))
+    })?;
+    parse_reply(plugin_name, &stdout)
+}
+
+fn spawn_plugin(plugin: &PluginInfo) -> Result<Child, ForgeStudioError> {
+    let executable = plugin.manifest.executable.as_deref().unwrap_or_default();
+    Command::new(Path::new(&plugin.plugin_dir).join(executable))
+        .current_dir(workspace_root_dir())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to start plugin '{}': {error}. Check that its executable is runnable.",
+                plugin.manifest.name
+            ))
+        })
+}
+
+/// Writes the request on its own thread, so `wait_with_timeout` also bounds
+/// a plugin that never reads it; stdin closes once the request is written.
+fn write_in_background(stdin: Option<ChildStdin>, request: String) -> JoinHandle<io::Result<()>> {
+    thread::spawn(move || match stdin {
+        Some(mut stdin) => stdin.write_all(request.as_bytes()),
+        None => Ok(()),
+    })
+}
+
+fn join_request_writer(
+    writer: JoinHandle<io::Result<()>>,
+    plugin_name: &str,
+) -> Result<(), ForgeStudioError> {
+    match writer.join() {
+        Ok(Ok(())) => Ok(()),
+        // A plugin that ignores its request may exit before reading it.
+        Ok(Err(error)) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        Ok(Err(error)) => Err(ForgeStudioError::io(format!(
+            "Failed to send the request to plugin '{plugin_name}': {error}. Check that the plugin reads its stdin."
+        ))),
+        Err(_) => Err(ForgeStudioError::internal(format!(
+            "The request writer for plugin '{plugin_name}' panicked."
+        ))),
+    }
+}
+
+fn wait_with_timeout(child: &mut Child, plugin_name: &str) -> Result<ExitStatus, ForgeStudioError> {
+    let deadline = Instant::now() + PLUGIN_TIMEOUT;
+    loop {
+        let exited = child.try_wait().map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to wait for plugin '{plugin_name}': {error}"
+            ))
+        })?;
+        if let Some(status) = exited {
+            return Ok(status);
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ForgeStudioError::io(format!(
+                "Plugin '{plugin_name}' did not reply within {} seconds and was stopped. Check the plugin or give it less work per call.",
+                PLUGIN_TIMEOUT.as_secs()
+            )));
+        }
+        thread::sleep(WAIT_POLL_INTERVAL);
+    }
+}
+
+fn parse_reply(plugin_name: &str, stdout: &str) -> Result<Value, ForgeStudioError> {
+    let reply: Value = serde_json::from_str(stdout.trim()).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse plugin '{plugin_name}' reply: {error}. Plugins must print one JSON object."
+        ))
+    })?;
+    if let Some(error) = reply.get("error") {
+        let error = error
+            .as_str()
+            .map_or_else(|| error.to_string(), str::to_string);
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Plugin '{plugin_name}' failed: {error}"
+        )));
+    }
+    reply.get("result").cloned().ok_or_else(|| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse plugin '{plugin_name}' reply: it has neither result nor error."
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{call_plugin, COMMAND_KIND};
+    use crate::models::{PluginInfo, PluginManifest};
+
+    #[cfg(unix)]
+    #[test]
+    fn requests_go_to_stdin_and_replies_come_from_stdout() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let plugin_dir =
+            std::env::temp_dir().join(format!("forge-plugin-protocol-{}", std::process::id()));
+        fs::create_dir_all(&plugin_dir).expect("plugin dir should be created");
+        let script = plugin_dir.join("plugin.sh");
+        fs::write(
+            &script,
+            "#!/bin/sh\nread -r request\ncase \"$request\" in\n  *fail*) echo '{\"error\": \"no such thing\"}' ;;\n  *) echo \"{\\\"result\\\": $request}\" ;;\nesac\n",
+        )
+        .expect("plugin script should be written");
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755))
+            .expect("plugin script should be executable");
+        let plugin = PluginInfo {
+            plugin_dir: plugin_dir.display().to_string(),
+            manifest: PluginManifest {
+                name: "echo".to_string(),
+                protocol_version: 1,
+                executable: Some("plugin.sh".to_string()),
+                ..PluginManifest::default()
+            },
+            load_error: None,
+        };
+
+        let echoed = call_plugin(&plugin, COMMAND_KIND, "echo", serde_json::json!({"n": 3}))
+            .expect("plugin should reply");
+        assert_eq!(echoed["kind"], "command");
+        assert_eq!(echoed["params"]["n"], 3);
+        let failed = call_plugin(&plugin, COMMAND_KIND, "fail", serde_json::json!({}))
+            .expect_err("plugin reports an error");
+        assert!(failed.to_string().contains("no such thing"));
+        fs::remove_dir_all(&plugin_dir).expect("temp dir should be removed");
+    }
+}
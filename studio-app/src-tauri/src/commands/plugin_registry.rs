@@ -0,0 +1,284 @@
+//! Plugins discovered at startup, which add Studio commands, dataset
+//! analyzers, and canvas node types.
+//!
+//! Each plugin is a directory under `plugins/` in the app config directory
+//! holding a `plugin.json` manifest. Commands and analyzers run the plugin's
+//! executable through `plugin_protocol`. Node types are declarative: a node
+//! runs the manifest's forge `command` with each filled-in parameter passed
+//! by its flag, so it goes through the same runner, cache, and history as
+//! the built-in nodes. A plugin whose manifest is unreadable, speaks another
+//! protocol version, or claims a name or node type already taken is listed
+//! with its `load_error` and contributes nothing. Plugins are read once at
+//! launch; restart Studio to pick up changes.
+
+use crate::commands::canvas_validation::PIPELINE_NODE_TYPES;
+use crate::commands::dataset_store_io::read_child_dirs;
+use crate::commands::run_store_io::read_json_file;
+use crate::models::{
+    ForgeStudioError, PipelineNodeSnapshot, PipelineNodeType, PluginInfo, PluginManifest,
+};
+use std::collections::HashSet;
+use std::path::{Component, Path};
+use std::sync::RwLock;
+
+pub(crate) const PLUGIN_PROTOCOL_VERSION: u32 = 1;
+const PLUGINS_DIR_NAME: &str = "plugins";
+const MANIFEST_FILE_NAME: &str = "plugin.json";
+
+/// Plugins found at launch, sorted by directory name.
+static DISCOVERED_PLUGINS: RwLock<Vec<PluginInfo>> = RwLock::new(Vec::new());
+
+/// Every plugin found at launch, including those that failed to load.
+#[tauri::command]
+pub fn list_plugins() -> Vec<PluginInfo> {
+    DISCOVERED_PLUGINS
+        .read()
+        .map(|plugins| plugins.clone())
+        .unwrap_or_default()
+}
+
+/// Discovers the plugins in `config_dir` and returns how many loaded.
+pub(crate) fn load_plugins(config_dir: &Path) -> Result<usize, ForgeStudioError> {
+    let plugins = discover_plugins(&config_dir.join(PLUGINS_DIR_NAME))?;
+    for plugin in &plugins {
+        if let Some(error) = &plugin.load_error {
+            tracing::warn!("Plugin {} was not loaded: {error}", plugin.plugin_dir);
+        }
+    }
+    let loaded = plugins
+        .iter()
+        .filter(|plugin| plugin.load_error.is_none())
+        .count();
+    if let Ok(mut discovered) = DISCOVERED_PLUGINS.write() {
+        *discovered = plugins;
+    }
+    Ok(loaded)
+}
+
+/// The loaded plugin called `plugin_name`.
+pub(crate) fn loaded_plugin(plugin_name: &str) -> Result<PluginInfo, ForgeStudioError> {
+    list_plugins()
+        .into_iter()
+        .find(|plugin| plugin.load_error.is_none() && plugin.manifest.name == plugin_name)
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Plugin '{plugin_name}' does not exist or failed to load. Check list_plugins for its load_error."
+            ))
+        })
+}
+
+/// Node types contributed by loaded plugins, for the canvas palette.
+pub(crate) fn plugin_node_types() -> Vec<PipelineNodeType> {
+    list_plugins()
+        .into_iter()
+        .filter(|plugin| plugin.load_error.is_none())
+        .flat_map(|plugin| plugin.manifest.node_types)
+        .collect()
+}
+
+pub(crate) fn plugin_node_type(node_type: &str) -> Option<PipelineNodeType> {
+    plugin_node_types()
+        .into_iter()
+        .find(|candidate| candidate.node_type == node_type)
+}
+
+/// Forge args for a plugin node; `None` when no plugin defines its type.
+pub(crate) fn plugin_node_args(node: &PipelineNodeSnapshot) -> Option<Vec<String>> {
+    let node_type = plugin_node_type(&node.node_type)?;
+    let mut args: Vec<String> = node_type.command.into_iter().collect();
+    for parameter in node_type.parameters {
+        let value = node
+            .config
+            .get(&parameter.name)
+            .map_or("", |value| value.trim());
+        match (parameter.flag, parameter.value_type.as_str()) {
+            (_, _) if value.is_empty() => {}
+            (Some(flag), "boolean") => {
+                if value.eq_ignore_ascii_case("true") {
+                    args.push(flag);
+                }
+            }
+            (Some(flag), _) => args.extend([flag, value.to_string()]),
+            (None, _) => args.push(value.to_string()),
+        }
+    }
+    Some(args)
+}
+
+fn discover_plugins(plugins_dir: &Path) -> Result<Vec<PluginInfo>, ForgeStudioError> {
+    if !plugins_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut dir_names = read_child_dirs(plugins_dir)?;
+    dir_names.sort();
+    let mut names = HashSet::new();
+    let mut node_types: HashSet<String> = PIPELINE_NODE_TYPES
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    Ok(dir_names
+        .into_iter()
+        .map(|dir_name| {
+            let plugin_dir = plugins_dir.join(&dir_name);
+            let loaded = read_manifest(&plugin_dir).and_then(|manifest| {
+                claim_names(&manifest, &mut names, &mut node_types)?;
+                Ok(manifest)
+            });
+            let (manifest, load_error) = match loaded {
+                Ok(manifest) => (manifest, None),
+                Err(error) => {
+                    let manifest = PluginManifest {
+                        name: dir_name,
+                        ..PluginManifest::default()
+                    };
+                    (manifest, Some(error.to_string()))
+                }
+            };
+            PluginInfo {
+                plugin_dir: plugin_dir.display().to_string(),
+                manifest,
+                load_error,
+            }
+        })
+        .collect())
+}
+
+fn read_manifest(plugin_dir: &Path) -> Result<PluginManifest, ForgeStudioError> {
+    let manifest_path = plugin_dir.join(MANIFEST_FILE_NAME);
+    if !manifest_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "{} is missing. Add a {MANIFEST_FILE_NAME} manifest or remove the directory.",
+            manifest_path.display()
+        )));
+    }
+    let manifest: PluginManifest = serde_json::from_value(read_json_file(&manifest_path)?)
+        .map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Failed to parse plugin manifest {}: {error}",
+                manifest_path.display()
+            ))
+        })?;
+    if manifest.protocol_version != PLUGIN_PROTOCOL_VERSION {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Plugin speaks protocol version {}, but Studio speaks {PLUGIN_PROTOCOL_VERSION}. Update the plugin or Studio.",
+            manifest.protocol_version
+        )));
+    }
+    let runs_executable = !manifest.commands.is_empty() || !manifest.dataset_analyzers.is_empty();
+    match manifest.executable.as_deref() {
+        None if runs_executable => Err(ForgeStudioError::invalid_input(
+            "Plugin has commands or analyzers but no executable. Set executable in its manifest.",
+        )),
+        Some(executable) if !is_inside_plugin(plugin_dir, executable) => {
+            Err(ForgeStudioError::invalid_input(format!(
+                "Plugin executable '{executable}' is not a file inside the plugin directory. Use a path relative to it."
+            )))
+        }
+        _ => Ok(manifest),
+    }
+}
+
+/// Executables are relative paths that stay inside the plugin directory.
+fn is_inside_plugin(plugin_dir: &Path, executable: &str) -> bool {
+    let relative = Path::new(executable);
+    relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+        && plugin_dir.join(relative).is_file()
+}
+
+/// Reserves the plugin's name and node types, refusing any already taken.
+fn claim_names(
+    manifest: &PluginManifest,
+    names: &mut HashSet<String>,
+    node_types: &mut HashSet<String>,
+) -> Result<(), ForgeStudioError> {
+    if manifest.name.trim().is_empty() || names.contains(&manifest.name) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Plugin name '{}' is empty or already used by another plugin. Give it a unique name.",
+            manifest.name
+        )));
+    }
+    let mut claimed = HashSet::new();
+    for node_type in &manifest.node_types {
+        if node_types.contains(&node_type.node_type) || !claimed.insert(&node_type.node_type) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Node type '{}' is already defined. Rename it in the plugin manifest.",
+                node_type.node_type
+            )));
+        }
+        if node_type
+            .command
+            .as_deref()
+            .is_none_or(|command| command.trim().is_empty())
+        {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Node type '{}' has no forge command. Set its command in the plugin manifest.",
+                node_type.node_type
+            )));
+        }
+    }
+    names.insert(manifest.name.clone());
+    node_types.extend(claimed.into_iter().cloned());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{list_plugins, load_plugins, plugin_node_args};
+    use crate::models::PipelineNodeSnapshot;
+    use std::fs;
+
+    #[test]
+    fn plugins_load_from_the_config_dir_and_add_node_types() {
+        let config_dir =
+            std::env::temp_dir().join(format!("forge-plugin-registry-{}", std::process::id()));
+        let write_manifest = |dir_name: &str, manifest: &str| {
+            let plugin_dir = config_dir.join("plugins").join(dir_name);
+            fs::create_dir_all(&plugin_dir).expect("plugin dir should be created");
+            fs::write(plugin_dir.join("plugin.json"), manifest)
+                .expect("manifest should be written");
+        };
+        write_manifest(
+            "a-dedupe",
+            r#"{"name": "dedupe", "protocol_version": 1, "node_types": [{
+                "node_type": "plugin-dedupe", "command": "dedupe", "parameters": [
+                    {"name": "dataset", "flag": "--dataset", "value_type": "string", "required": true},
+                    {"name": "fuzzy", "flag": "--fuzzy", "value_type": "boolean", "required": false}
+                ]}]}"#,
+        );
+        write_manifest(
+            "b-clash",
+            r#"{"name": "clash", "protocol_version": 1, "node_types": [{"node_type": "train", "command": "train"}]}"#,
+        );
+        write_manifest("c-future", r#"{"name": "future", "protocol_version": 2}"#);
+        fs::create_dir_all(config_dir.join("plugins/d-empty")).expect("dir should be created");
+
+        assert_eq!(load_plugins(&config_dir), Ok(1));
+        let plugins = list_plugins();
+        let errors: Vec<bool> = plugins
+            .iter()
+            .map(|plugin| plugin.load_error.is_some())
+            .collect();
+        assert_eq!(errors, [false, true, true, true]);
+
+        let node = PipelineNodeSnapshot {
+            id: "dedupe-1".to_string(),
+            node_type: "plugin-dedupe".to_string(),
+            title: "Dedupe".to_string(),
+            canvas_x: 0.0,
+            canvas_y: 0.0,
+            config: serde_json::from_str(r#"{"dataset": "demo", "fuzzy": "true"}"#)
+                .expect("config should parse"),
+        };
+        assert_eq!(
+            plugin_node_args(&node),
+            Some(
+                ["dedupe", "--dataset", "demo", "--fuzzy"]
+                    .map(str::to_string)
+                    .to_vec()
+            )
+        );
+        fs::remove_dir_all(&config_dir).expect("temp dir should be removed");
+    }
+}
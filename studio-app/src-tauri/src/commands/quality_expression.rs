@@ -0,0 +1,298 @@
+//! Parser and evaluator for user-provided quality recalibration formulas.
+//!
+//! Supports arithmetic (`+ - * / ^`), parentheses, a fixed set of math
+//! functions, and the per-record variables listed in `KNOWN_VARIABLES`.
+//! Expressions are parsed once and evaluated per record.
+
+use crate::models::ForgeStudioError;
+
+const KNOWN_VARIABLES: [&str; 4] = ["quality_score", "perplexity", "token_length", "char_length"];
+const KNOWN_FUNCTIONS: [(&str, usize); 8] = [
+    ("abs", 1),
+    ("clamp", 3),
+    ("exp", 1),
+    ("log", 1),
+    ("log10", 1),
+    ("max", 2),
+    ("min", 2),
+    ("sqrt", 1),
+];
+
+/// Metadata values exposed to formulas for one record.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QualityVariables {
+    pub quality_score: f64,
+    pub perplexity: f64,
+    pub token_length: f64,
+    pub char_length: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum QualityExpression {
+    Number(f64),
+    Variable(String),
+    Negate(Box<QualityExpression>),
+    Binary(char, Box<QualityExpression>, Box<QualityExpression>),
+    Call(String, Vec<QualityExpression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    Symbol(char),
+}
+
+/// Parses `source` into an expression tree, validating names and arity.
+pub(crate) fn parse_quality_expression(
+    source: &str,
+) -> Result<QualityExpression, ForgeStudioError> {
+    parse_expression(source).map_err(ForgeStudioError::invalid_input)
+}
+
+fn parse_expression(source: &str) -> Result<QualityExpression, String> {
+    let tokens = tokenize(source)?;
+    let mut parser = ExpressionParser {
+        tokens,
+        position: 0,
+    };
+    let expression = parser.parse_sum()?;
+    if let Some(token) = parser.peek() {
+        return Err(format!(
+            "Unexpected token {token:?} in quality expression '{source}'."
+        ));
+    }
+    Ok(expression)
+}
+
+impl QualityExpression {
+    pub(crate) fn evaluate(&self, variables: &QualityVariables) -> f64 {
+        match self {
+            Self::Number(value) => *value,
+            Self::Variable(name) => variable_value(name, variables),
+            Self::Negate(inner) => -inner.evaluate(variables),
+            Self::Binary(operator, left, right) => {
+                let left_value = left.evaluate(variables);
+                let right_value = right.evaluate(variables);
+                match operator {
+                    '+' => left_value + right_value,
+                    '-' => left_value - right_value,
+                    '*' => left_value * right_value,
+                    '/' => left_value / right_value,
+                    _ => left_value.powf(right_value),
+                }
+            }
+            Self::Call(name, arguments) => {
+                let values: Vec<f64> = arguments
+                    .iter()
+                    .map(|arg| arg.evaluate(variables))
+                    .collect();
+                call_function(name, &values)
+            }
+        }
+    }
+}
+
+fn variable_value(name: &str, variables: &QualityVariables) -> f64 {
+    match name {
+        "quality_score" => variables.quality_score,
+        "perplexity" => variables.perplexity,
+        "token_length" => variables.token_length,
+        _ => variables.char_length,
+    }
+}
+
+fn call_function(name: &str, values: &[f64]) -> f64 {
+    match name {
+        "abs" => values[0].abs(),
+        "clamp" => values[0].max(values[1]).min(values[2]),
+        "exp" => values[0].exp(),
+        "log" => values[0].ln(),
+        "log10" => values[0].log10(),
+        "max" => values[0].max(values[1]),
+        "min" => values[0].min(values[1]),
+        _ => values[0].sqrt(),
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let characters: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < characters.len() {
+        let current = characters[index];
+        if current.is_whitespace() {
+            index += 1;
+        } else if current.is_ascii_digit() || current == '.' {
+            let start = index;
+            while index < characters.len()
+                && (characters[index].is_ascii_digit() || characters[index] == '.')
+            {
+                index += 1;
+            }
+            let literal: String = characters[start..index].iter().collect();
+            let value = literal
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number '{literal}' in quality expression."))?;
+            tokens.push(Token::Number(value));
+        } else if current.is_ascii_alphabetic() || current == '_' {
+            let start = index;
+            while index < characters.len()
+                && (characters[index].is_ascii_alphanumeric() || characters[index] == '_')
+            {
+                index += 1;
+            }
+            tokens.push(Token::Identifier(characters[start..index].iter().collect()));
+        } else if "+-*/^(),".contains(current) {
+            tokens.push(Token::Symbol(current));
+            index += 1;
+        } else {
+            return Err(format!(
+                "Unsupported character '{current}' in quality expression. Use numbers, variables, functions, and + - * / ^."
+            ));
+        }
+    }
+    Ok(tokens)
+}
+
+struct ExpressionParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl ExpressionParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next_symbol_in(&mut self, symbols: &str) -> Option<char> {
+        if let Some(Token::Symbol(symbol)) = self.peek() {
+            if symbols.contains(*symbol) {
+                let matched = *symbol;
+                self.position += 1;
+                return Some(matched);
+            }
+        }
+        None
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), String> {
+        self.next_symbol_in(&symbol.to_string())
+            .map(|_| ())
+            .ok_or_else(|| format!("Expected '{symbol}' in quality expression."))
+    }
+
+    fn parse_sum(&mut self) -> Result<QualityExpression, String> {
+        let mut expression = self.parse_product()?;
+        while let Some(operator) = self.next_symbol_in("+-") {
+            let right = self.parse_product()?;
+            expression = QualityExpression::Binary(operator, Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_product(&mut self) -> Result<QualityExpression, String> {
+        let mut expression = self.parse_unary()?;
+        while let Some(operator) = self.next_symbol_in("*/") {
+            let right = self.parse_unary()?;
+            expression = QualityExpression::Binary(operator, Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<QualityExpression, String> {
+        if self.next_symbol_in("-").is_some() {
+            return Ok(QualityExpression::Negate(Box::new(self.parse_unary()?)));
+        }
+        let base = self.parse_primary()?;
+        if self.next_symbol_in("^").is_some() {
+            let exponent = self.parse_unary()?;
+            return Ok(QualityExpression::Binary(
+                '^',
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> Result<QualityExpression, String> {
+        let token = self.peek().cloned();
+        self.position += 1;
+        match token {
+            Some(Token::Number(value)) => Ok(QualityExpression::Number(value)),
+            Some(Token::Identifier(name)) => self.parse_identifier(name),
+            Some(Token::Symbol('(')) => {
+                let inner = self.parse_sum()?;
+                self.expect_symbol(')')?;
+                Ok(inner)
+            }
+            other => Err(format!("Unexpected {other:?} in quality expression.")),
+        }
+    }
+
+    fn parse_identifier(&mut self, name: String) -> Result<QualityExpression, String> {
+        if self.next_symbol_in("(").is_none() {
+            if !KNOWN_VARIABLES.contains(&name.as_str()) {
+                return Err(format!(
+                    "Unknown variable '{name}' in quality expression. Available: {}.",
+                    KNOWN_VARIABLES.join(", ")
+                ));
+            }
+            return Ok(QualityExpression::Variable(name));
+        }
+        let arity = KNOWN_FUNCTIONS
+            .iter()
+            .find(|(function_name, _)| *function_name == name)
+            .map(|(_, arity)| *arity)
+            .ok_or_else(|| format!("Unknown function '{name}' in quality expression."))?;
+        let mut arguments = vec![self.parse_sum()?];
+        while self.next_symbol_in(",").is_some() {
+            arguments.push(self.parse_sum()?);
+        }
+        self.expect_symbol(')')?;
+        if arguments.len() != arity {
+            return Err(format!(
+                "Function '{name}' expects {arity} argument(s), got {}.",
+                arguments.len()
+            ));
+        }
+        Ok(QualityExpression::Call(name, arguments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_quality_expression, QualityVariables};
+
+    const VARIABLES: QualityVariables = QualityVariables {
+        quality_score: 0.5,
+        perplexity: 100.0,
+        token_length: 10.0,
+        char_length: 60.0,
+    };
+
+    #[test]
+    fn parse_quality_expression_respects_precedence() {
+        let expression = parse_quality_expression("1 + 2 * 3 ^ 2").expect("should parse");
+        assert_eq!(expression.evaluate(&VARIABLES), 19.0);
+    }
+
+    #[test]
+    fn parse_quality_expression_evaluates_functions_and_variables() {
+        let expression =
+            parse_quality_expression("clamp(quality_score * log10(token_length) * 4, 0, 1)")
+                .expect("should parse");
+        assert_eq!(expression.evaluate(&VARIABLES), 1.0);
+    }
+
+    #[test]
+    fn parse_quality_expression_rejects_unknown_variables() {
+        assert!(parse_quality_expression("quality + 1").is_err());
+    }
+
+    #[test]
+    fn parse_quality_expression_rejects_wrong_arity() {
+        assert!(parse_quality_expression("clamp(quality_score, 0)").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Derived quality-score recalibration command.
+//!
+//! Evaluates a user formula over each record's metadata and stores the result
+//! as an `extra_fields` column in a new child version, so ranking signals can
+//! be iterated on without re-running ingest.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::dataset_store_io::{read_records, resolve_version};
+use crate::commands::dataset_version_writer::{write_derived_version, DerivedVersionRequest};
+use crate::commands::quality_expression::{
+    parse_quality_expression, QualityExpression, QualityVariables,
+};
+use crate::commands::record_outliers::token_length;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{ForgeStudioError, QualityRecalibrationResult};
+use serde_json::{Map, Value};
+use tauri::State;
+
+const DEFAULT_RECALIBRATED_FIELD: &str = "recalibrated_quality";
+
+/// Rewrites every record of the version, so it runs on the blocking pool.
+#[tauri::command]
+pub async fn recalibrate_quality(
+    data_root: String,
+    dataset_name: String,
+    version_id: Option<String>,
+    expression: String,
+    output_field: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<QualityRecalibrationResult, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        let request = RecalibrationRequest {
+            dataset_name,
+            version_id,
+            expression,
+            output_field,
+        };
+        recalibrate_version(&data_root, request)
+    })
+    .await
+}
+
+/// Inputs for one recalibration, as the command received them.
+struct RecalibrationRequest {
+    dataset_name: String,
+    version_id: Option<String>,
+    expression: String,
+    output_field: Option<String>,
+}
+
+/// `data_root` is already resolved.
+fn recalibrate_version(
+    data_root: &str,
+    request: RecalibrationRequest,
+) -> Result<QualityRecalibrationResult, ForgeStudioError> {
+    let RecalibrationRequest {
+        dataset_name,
+        version_id,
+        expression,
+        output_field,
+    } = request;
+    let parsed_expression = parse_quality_expression(&expression)?;
+    let output_field = output_field
+        .map(|field| field.trim().to_string())
+        .filter(|field| !field.is_empty())
+        .unwrap_or_else(|| DEFAULT_RECALIBRATED_FIELD.to_string());
+    let parent_version = resolve_version(data_root, &dataset_name, version_id)?;
+    let mut records = read_records(data_root, &dataset_name, &parent_version)?;
+    let mut min_value = f64::INFINITY;
+    let mut max_value = f64::NEG_INFINITY;
+    for record in &mut records {
+        let value = apply_expression(record, &parsed_expression, &output_field)?;
+        min_value = min_value.min(value);
+        max_value = max_value.max(value);
+    }
+    let record_count = records.len() as u64;
+    let new_version_id = write_derived_version(DerivedVersionRequest {
+        data_root,
+        dataset_name: &dataset_name,
+        parent_version: &parent_version,
+        recipe_step: format!("recalibrate_quality:{output_field}={}", expression.trim()),
+        records,
+    })?;
+    Ok(QualityRecalibrationResult {
+        dataset_name,
+        parent_version,
+        version_id: new_version_id,
+        output_field,
+        record_count,
+        min_value: if record_count == 0 { 0.0 } else { min_value },
+        max_value: if record_count == 0 { 0.0 } else { max_value },
+    })
+}
+
+fn apply_expression(
+    record: &mut Value,
+    expression: &QualityExpression,
+    output_field: &str,
+) -> Result<f64, ForgeStudioError> {
+    let text_length = record
+        .get("text")
+        .and_then(Value::as_str)
+        .map(|text| (token_length(text), text.chars().count() as f64));
+    let (token_count, char_count) = text_length.unwrap_or((0.0, 0.0));
+    let record_id = record
+        .get("record_id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let metadata = record
+        .get_mut("metadata")
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| {
+            ForgeStudioError::parse(format!("Record '{record_id}' metadata is missing"))
+        })?;
+    let variables = QualityVariables {
+        quality_score: metadata
+            .get("quality_score")
+            .and_then(Value::as_f64)
+            .unwrap_or(0.0),
+        perplexity: metadata
+            .get("perplexity")
+            .and_then(Value::as_f64)
+            .unwrap_or(0.0),
+        token_length: token_count,
+        char_length: char_count,
+    };
+    let value = expression.evaluate(&variables);
+    if !value.is_finite() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Quality expression produced a non-finite value for record '{record_id}'. Guard log/sqrt/division inputs with clamp or max."
+        )));
+    }
+    let extra_fields = metadata
+        .entry("extra_fields")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or_else(|| {
+            ForgeStudioError::parse(format!(
+                "Record '{record_id}' extra_fields is not an object"
+            ))
+        })?;
+    // The CLI reads extra_fields as string-to-string, so the value is stored as text.
+    extra_fields.insert(output_field.to_string(), Value::String(value.to_string()));
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{recalibrate_version, RecalibrationRequest};
+    use crate::commands::dataset_store_io::{
+        dataset_root, read_catalog, read_records, records_path, version_dir,
+    };
+    use serde_json::{json, Value};
+    use std::fs;
+
+    #[test]
+    fn recalibration_writes_a_child_version_with_the_derived_field() {
+        let data_root = std::env::temp_dir().join(format!(
+            "forge-quality-recalibration-{}",
+            std::process::id()
+        ));
+        let data_root = data_root.display().to_string();
+        fs::create_dir_all(version_dir(&data_root, "demo", "v1")).expect("version dir created");
+        let records = [
+            json!({"record_id": "a", "text": "one two", "metadata": {"quality_score": 0.5}}),
+            json!({"record_id": "b", "text": "one two three four", "metadata": {}}),
+        ];
+        let lines: Vec<String> = records.iter().map(Value::to_string).collect();
+        fs::write(records_path(&data_root, "demo", "v1"), lines.join("\n"))
+            .expect("records written");
+        let catalog = json!({
+            "latest_version": "v1",
+            "versions": [{"version_id": "v1", "record_count": 2, "recipe_steps": ["ingest"]}],
+        });
+        let catalog_root = dataset_root(&data_root, "demo");
+        fs::write(catalog_root.join("catalog.json"), catalog.to_string()).expect("catalog written");
+
+        let request = RecalibrationRequest {
+            dataset_name: "demo".to_string(),
+            version_id: None,
+            expression: "quality_score * 2 + token_length".to_string(),
+            output_field: Some("  ".to_string()),
+        };
+        let result = recalibrate_version(&data_root, request).expect("recalibration succeeds");
+        assert_eq!(result.parent_version, "v1");
+        assert_eq!(result.output_field, "recalibrated_quality");
+        assert_eq!((result.min_value, result.max_value), (3.0, 4.0));
+
+        let derived = read_records(&data_root, "demo", &result.version_id).expect("child records");
+        let values: Vec<&str> = derived
+            .iter()
+            .map(|record| {
+                record["metadata"]["extra_fields"]["recalibrated_quality"]
+                    .as_str()
+                    .expect("derived field is text")
+            })
+            .collect();
+        assert_eq!(values, ["3", "4"]);
+        let catalog = read_catalog(&catalog_root).expect("catalog reads");
+        assert_eq!(catalog["latest_version"], json!(result.version_id));
+        let entry = &catalog["versions"][1];
+        assert_eq!(entry["parent_version"], "v1");
+        assert_eq!(entry["record_count"], 2);
+        assert_eq!(
+            entry["recipe_steps"],
+            json!([
+                "ingest",
+                "recalibrate_quality:recalibrated_quality=quality_score * 2 + token_length"
+            ])
+        );
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,219 @@
+//! Read-only mode, for browsing a shared or production data root safely.
+//!
+//! While the `read_only` setting is on, the invoke handler and the RPC
+//! server only run commands in `READ_ONLY_COMMANDS`; every other command is
+//! rejected before it runs, whatever the frontend shows, so a command added
+//! without a classification fails closed. Reads, checks, and Studio's own
+//! settings stay available, so the mode can always be turned off again.
+//! Schedules already ticking skip their runs until it is turned off, and
+//! reads that normally refresh a cache in the data root (the metadata index,
+//! duplicate-rate stats) skip the write. Every registered command is listed
+//! in exactly one of the two lists below.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::models::ForgeStudioError;
+use tauri::ipc::Invoke;
+
+/// Commands that never write to a data root. Plugin commands check their
+/// manifest's `read_only` flag themselves.
+const READ_ONLY_COMMANDS: [&str; 81] = [
+    "analyze_training_curves",
+    "audit_lineage",
+    "check_data_root",
+    "check_forge_updates",
+    "check_training_feasibility",
+    "clear_crash_reports",
+    "close_chat_session",
+    "compare_batch_inferences",
+    "compare_model_exports",
+    "compare_runs",
+    "detect_dataset_drift",
+    "diagnose_run_failure",
+    "diff_canvases",
+    "diff_run_configs",
+    "explain_filter_for_record",
+    "find_outlier_records",
+    "get_audit_log",
+    "get_batch_inference",
+    "get_canvas_edit_journal",
+    "get_chat_transcript",
+    "get_cost_rates",
+    "get_crash_reports",
+    "get_dataset_dashboard",
+    "get_forge_command_status",
+    "get_hardware_profile",
+    "get_hardware_telemetry_history",
+    "get_leaderboard",
+    "get_lineage_graph",
+    "get_model_by_stage",
+    "get_pipeline_node_types",
+    "get_pipeline_run",
+    "get_pipeline_run_status",
+    "get_recent_app_logs",
+    "get_run_attachment",
+    "get_run_cost",
+    "get_run_details",
+    "get_settings",
+    "get_sweep_status",
+    "get_usage_telemetry",
+    "get_version_duplicate_rate",
+    "import_pipeline_canvas",
+    "import_pipeline_config",
+    "inspect_model_artifact",
+    "lint_pipeline_canvas",
+    "list_backend_events",
+    "list_batch_inferences",
+    "list_canvas_exports",
+    "list_canvas_snapshots",
+    "list_datasets",
+    "list_eval_results",
+    "list_model_exports",
+    "list_pipeline_runs",
+    "list_pipeline_schedules",
+    "list_plugins",
+    "list_registered_models",
+    "list_run_attachments",
+    "list_training_runs",
+    "list_versions",
+    "load_training_history",
+    "merge_canvases",
+    "plan_pipeline_canvas",
+    "refresh_hardware_profile",
+    "render_pipeline_canvas",
+    "restore_canvas_snapshot",
+    "run_dataset_analyzer",
+    "run_plugin_command",
+    "sample_records",
+    "sample_records_arrow",
+    "search_metadata_index",
+    "send_chat_prompt",
+    "set_locale",
+    "start_chat_session",
+    "start_history_watch",
+    "start_run_state_watch",
+    "stop_history_watch",
+    "stop_run_state_watch",
+    "update_settings",
+    "validate_pipeline_canvas",
+    "verify_artifact_contract",
+    "verify_version_integrity",
+    "version_diff",
+];
+
+/// Commands that change a data root, start work that does, or write exports.
+#[cfg(test)]
+const WRITING_COMMANDS: [&str; 37] = [
+    "archive_run",
+    "attach_run_artifact",
+    "backup_workspace",
+    "clone_run",
+    "create_pipeline_schedule",
+    "delete_pipeline_schedule",
+    "delete_run",
+    "export_canvas_bundle",
+    "export_history_to_tensorboard",
+    "export_lineage_graph",
+    "export_model",
+    "export_pipeline_canvas",
+    "gc_runs",
+    "generate_dataset_health_report",
+    "import_canvas_bundle",
+    "init_data_root",
+    "mark_run_failed",
+    "push_canvas_edit",
+    "rebuild_metadata_index",
+    "rebuild_runs_index",
+    "recalibrate_quality",
+    "record_eval_result",
+    "redo_canvas_edit",
+    "register_model",
+    "restore_workspace",
+    "resume_training_run",
+    "run_batch_inference",
+    "run_pipeline_canvas",
+    "save_canvas_snapshot",
+    "set_cost_rates",
+    "set_model_stage",
+    "set_run_notes",
+    "set_run_tags",
+    "start_evaluation",
+    "start_forge_command",
+    "start_sweep",
+    "undo_canvas_edit",
+];
+
+/// Wraps the command handler so mutating commands are rejected while
+/// read-only mode is on.
+pub fn with_read_only_mode(
+    settings: SettingsStore,
+    handler: impl Fn(Invoke) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        let rejection = settings
+            .current()
+            .read_only
+            .then(|| ensure_writable(invoke.message.command()).err())
+            .flatten();
+        match rejection {
+            Some(error) => {
+                invoke.resolver.reject(error);
+                true
+            }
+            None => handler(invoke),
+        }
+    }
+}
+
+/// Fails for `command` unless it is known not to change a data root.
+pub(crate) fn ensure_writable(command: &str) -> Result<(), ForgeStudioError> {
+    if !READ_ONLY_COMMANDS.contains(&command) {
+        return Err(ForgeStudioError::read_only(format!(
+            "'{command}' is unavailable in read-only mode because it changes the data root. Turn off read_only in settings to make changes."
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ensure_writable, READ_ONLY_COMMANDS, WRITING_COMMANDS};
+
+    #[test]
+    fn writing_and_unknown_commands_are_rejected_with_a_read_only_error() {
+        let error = ensure_writable("delete_run").expect_err("delete_run should be rejected");
+        assert_eq!(error.code(), "read_only");
+        assert_eq!(error.detail().context.as_deref(), Some("delete_run"));
+        assert!(ensure_writable("recalibrate_quality").is_err());
+        assert!(ensure_writable("not_a_command").is_err());
+        assert!(ensure_writable("list_training_runs").is_ok());
+        assert!(ensure_writable("update_settings").is_ok());
+    }
+
+    #[test]
+    fn every_registered_command_is_classified_once() {
+        for list in [&READ_ONLY_COMMANDS[..], &WRITING_COMMANDS[..]] {
+            assert!(list.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+        let registered: Vec<&str> = include_str!("../lib.rs")
+            .split("generate_handler![")
+            .nth(1)
+            .and_then(|handlers| handlers.split(']').next())
+            .expect("lib.rs should register commands")
+            .split(',')
+            .filter_map(|path| path.trim().rsplit("::").next())
+            .filter(|name| !name.is_empty())
+            .collect();
+        assert!(registered.len() > 100);
+        for command in &registered {
+            let listed = READ_ONLY_COMMANDS.contains(command) as u8
+                + WRITING_COMMANDS.contains(command) as u8;
+            assert_eq!(listed, 1, "'{command}' must be in exactly one list");
+        }
+        for command in READ_ONLY_COMMANDS.iter().chain(&WRITING_COMMANDS) {
+            assert!(
+                registered.contains(command),
+                "'{command}' is not registered"
+            );
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Outlier detection over record length and quality score distributions.
+//!
+//! Flags records whose whitespace token count or quality score sits far from
+//! the version mean, which usually means binary blobs or scraping artifacts.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::{parse_record_sample, read_records, resolve_version};
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{ForgeStudioError, RecordSample};
+use tauri::State;
+
+const OUTLIER_Z_SCORE_THRESHOLD: f64 = 3.0;
+const MAX_OUTLIER_RECORDS: usize = 200;
+
+#[tauri::command]
+pub fn find_outlier_records(
+    data_root: String,
+    dataset_name: String,
+    version_id: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<RecordSample>, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    let selected_version = resolve_version(&data_root, &dataset_name, version_id)?;
+    let records = read_records(&data_root, &dataset_name, &selected_version)?;
+    let mut samples = Vec::with_capacity(records.len());
+    for record in &records {
+        samples.push(parse_record_sample(record)?);
+    }
+    let token_lengths: Vec<f64> = samples
+        .iter()
+        .map(|sample| token_length(&sample.text))
+        .collect();
+    let quality_scores: Vec<f64> = samples.iter().map(|sample| sample.quality_score).collect();
+    let length_stats = DistributionStats::from_values(&token_lengths);
+    let quality_stats = DistributionStats::from_values(&quality_scores);
+    let outliers = samples
+        .into_iter()
+        .zip(token_lengths.iter().zip(quality_scores.iter()))
+        .filter(|(_, (length, quality))| {
+            length_stats.is_outlier(**length) || quality_stats.is_outlier(**quality)
+        })
+        .map(|(sample, _)| sample)
+        .take(MAX_OUTLIER_RECORDS)
+        .collect();
+    Ok(outliers)
+}
+
+/// Approximates model token length with whitespace-delimited words.
+pub(crate) fn token_length(text: &str) -> f64 {
+    text.split_whitespace().count() as f64
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DistributionStats {
+    pub mean: f64,
+    pub standard_deviation: f64,
+}
+
+impl DistributionStats {
+    pub(crate) fn from_values(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Self {
+                mean: 0.0,
+                standard_deviation: 0.0,
+            };
+        }
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        Self {
+            mean,
+            standard_deviation: variance.sqrt(),
+        }
+    }
+
+    pub(crate) fn z_score(&self, value: f64) -> f64 {
+        if self.standard_deviation == 0.0 {
+            return 0.0;
+        }
+        (value - self.mean) / self.standard_deviation
+    }
+
+    pub(crate) fn is_outlier(&self, value: f64) -> bool {
+        self.z_score(value).abs() > OUTLIER_Z_SCORE_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{token_length, DistributionStats};
+
+    #[test]
+    fn distribution_stats_flags_values_beyond_threshold() {
+        let mut values = vec![10.0; 50];
+        values.push(10_000.0);
+        let stats = DistributionStats::from_values(&values);
+        assert!(stats.is_outlier(10_000.0));
+        assert!(!stats.is_outlier(10.0));
+    }
+
+    #[test]
+    fn distribution_stats_never_flags_constant_values() {
+        let stats = DistributionStats::from_values(&[0.5, 0.5, 0.5]);
+        assert!(!stats.is_outlier(0.5));
+    }
+
+    #[test]
+    fn token_length_counts_whitespace_words() {
+        assert_eq!(token_length("  one two\nthree  "), 3.0);
+    }
+}
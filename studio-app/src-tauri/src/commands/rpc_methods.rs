@@ -0,0 +1,186 @@
+//! Commands the JSON-RPC server exposes, by the same names as the Tauri
+//! commands they run.
+//!
+//! Params are a JSON object keyed like the frontend's `invoke` args
+//! (`dataRoot`, `datasetName`, ...); omitted optional params are null.
+//! Commands that take Tauri state call the store-based helper behind the
+//! command, so both entry points share one implementation; data roots are
+//! confined to the settings store's workspace roots as the commands do.
+//! Read-only mode applies here as it does to the webview.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_dashboard::build_dataset_dashboard;
+use crate::commands::dataset_queries::{
+    dataset_listings, diff_versions, record_samples, version_summaries, MAX_SAMPLE_LIMIT,
+};
+use crate::commands::forge_commands::start_forge_task;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::path_safety::WorkspaceRoots;
+use crate::commands::pipeline_history::{pipeline_run_history, pipeline_run_summaries};
+use crate::commands::pipeline_runs::{pipeline_run_status, start_pipeline_run};
+use crate::commands::read_only_mode::ensure_writable;
+use crate::commands::run_store_io::{resolve_data_root_path, resolve_data_root_text};
+use crate::models::{ForgeStudioError, PipelineCanvasState};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Every method the server answers; anything else is "method not found".
+pub(crate) const RPC_METHODS: [&str; 11] = [
+    "get_dataset_dashboard",
+    "get_forge_command_status",
+    "get_pipeline_run",
+    "get_pipeline_run_status",
+    "list_datasets",
+    "list_pipeline_runs",
+    "list_versions",
+    "run_pipeline_canvas",
+    "sample_records",
+    "start_forge_command",
+    "version_diff",
+];
+
+/// Stores the methods run against, shared with the app when it has a window.
+#[derive(Clone, Default)]
+pub(crate) struct RpcContext {
+    pub task_store: CommandTaskStore,
+    pub settings: SettingsStore,
+}
+
+/// Runs `method` with `params` and returns its JSON result.
+pub(crate) fn call_method(
+    context: &RpcContext,
+    method: &str,
+    params: &Value,
+) -> Result<Value, ForgeStudioError> {
+    if context.settings.current().read_only {
+        ensure_writable(method)?;
+    }
+    let roots = context.settings.workspace_roots();
+    let data_root = || resolve_data_root_path(&param::<String>(params, "dataRoot")?, &roots);
+    let pipeline_run_id = || param::<String>(params, "pipelineRunId");
+    let task_store = &context.task_store;
+    match method {
+        "get_forge_command_status" => {
+            reply(task_store.get_task_status(&param::<String>(params, "taskId")?))
+        }
+        "get_pipeline_run" => reply(pipeline_run_history(&data_root()?, &pipeline_run_id()?)),
+        "get_pipeline_run_status" => reply(pipeline_run_status(
+            &data_root()?,
+            &pipeline_run_id()?,
+            task_store,
+        )),
+        "list_pipeline_runs" => reply(pipeline_run_summaries(&data_root()?)),
+        "run_pipeline_canvas" => run_pipeline_canvas(params, task_store, &roots),
+        "start_forge_command" => reply(start_forge_task(
+            &param::<String>(params, "dataRoot")?,
+            param(params, "args")?,
+            task_store,
+            &roots,
+        )),
+        _ => call_dataset_method(method, params, &context.settings),
+    }
+}
+
+/// Dataset queries, which need only the settings.
+fn call_dataset_method(
+    method: &str,
+    params: &Value,
+    settings: &SettingsStore,
+) -> Result<Value, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let data_root = || resolve_data_root_text(&param::<String>(params, "dataRoot")?, &roots);
+    let dataset_name = || param::<String>(params, "datasetName");
+    match method {
+        "get_dataset_dashboard" => reply(build_dataset_dashboard(
+            &data_root()?,
+            dataset_name()?,
+            param(params, "versionId")?,
+        )),
+        "list_datasets" => reply(dataset_listings(
+            param(params, "dataRoot")?,
+            &settings.current().data_roots,
+            &roots,
+        )),
+        "list_versions" => reply(version_summaries(&data_root()?, &dataset_name()?)),
+        "sample_records" => {
+            let offset: usize = param(params, "offset")?;
+            let limit: usize = param(params, "limit")?;
+            reply(record_samples(
+                &data_root()?,
+                &dataset_name()?,
+                param(params, "versionId")?,
+                offset..offset.saturating_add(limit.min(MAX_SAMPLE_LIMIT)),
+            ))
+        }
+        "version_diff" => reply(diff_versions(
+            &data_root()?,
+            dataset_name()?,
+            param(params, "baseVersion")?,
+            param(params, "targetVersion")?,
+        )),
+        _ => Err(ForgeStudioError::not_found(format!(
+            "Unknown RPC method '{method}'. Use one of: {}.",
+            RPC_METHODS.join(", ")
+        ))),
+    }
+}
+
+fn run_pipeline_canvas(
+    params: &Value,
+    task_store: &CommandTaskStore,
+    roots: &WorkspaceRoots,
+) -> Result<Value, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&param::<String>(params, "dataRoot")?, roots)?;
+    let canvas: PipelineCanvasState = param(params, "canvas")?;
+    let options = param::<Option<_>>(params, "options")?.unwrap_or_default();
+    reply(start_pipeline_run(
+        data_root,
+        canvas,
+        options,
+        task_store.clone(),
+    ))
+}
+
+/// The param `name`, with a missing one read as null.
+fn param<T: DeserializeOwned>(params: &Value, name: &str) -> Result<T, ForgeStudioError> {
+    let value = params.get(name).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value).map_err(|error| {
+        ForgeStudioError::invalid_input(format!(
+            "RPC param '{name}' is invalid: {error}. Pass it as the frontend's invoke args do."
+        ))
+    })
+}
+
+fn reply<T: Serialize, E: Into<ForgeStudioError>>(
+    result: Result<T, E>,
+) -> Result<Value, ForgeStudioError> {
+    let value = result.map_err(Into::into)?;
+    serde_json::to_value(value).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize RPC result: {error}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{call_method, RpcContext, RPC_METHODS};
+    use serde_json::json;
+
+    #[test]
+    fn unknown_methods_and_bad_params_are_rejected() {
+        let context = RpcContext::default();
+        assert!(RPC_METHODS.windows(2).all(|pair| pair[0] < pair[1]));
+        let unknown = call_method(&context, "delete_run", &json!({})).expect_err("not exposed");
+        assert_eq!(unknown.code(), "not_found");
+        let missing = call_method(&context, "list_versions", &json!({"dataRoot": "."}))
+            .expect_err("datasetName is required");
+        assert_eq!(missing.detail().context.as_deref(), Some("datasetName"));
+        let status = call_method(
+            &context,
+            "get_forge_command_status",
+            &json!({"taskId": "t"}),
+        )
+        .expect_err("task does not exist");
+        assert_eq!(status.code(), "not_found");
+    }
+}
@@ -0,0 +1,219 @@
+//! Local JSON-RPC 2.0 server over HTTP, so scripts and CI can drive Studio
+//! without the webview.
+//!
+//! `studio-app --serve [--port N]` runs it headless: no window, logs on
+//! stderr, and the app's saved settings, so read-only mode and workspace
+//! roots apply as they do in the window. The `rpc_server` setting instead
+//! starts it next to the app at launch, sharing the app's task store. Each
+//! request is a `POST` of one JSON-RPC request, answered by a fixed pool of
+//! worker threads; `rpc_methods` lists the methods, and a command's error
+//! comes back as error code -32000 with the Studio error as its `data`.
+//! Only local scripts are answered: the server listens on loopback, refuses requests with an `Origin` header or a non-local `Host`,
+//! and requires `Content-Type: application/json`, which a web page cannot
+//! send cross-origin without a preflight this server never approves.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::crash_reports::CrashReporter;
+use crate::commands::event_bus::EventBus;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::rpc_methods::{call_method, RpcContext, RPC_METHODS};
+use crate::models::ForgeStudioError;
+use serde_json::{json, Value};
+use std::io::Read;
+use std::sync::Arc;
+use std::thread;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+pub(crate) const SERVE_FLAG: &str = "--serve";
+const PORT_FLAG: &str = "--port";
+const LOCAL_HOSTS: [&str; 3] = ["127.0.0.1", "localhost", "[::1]"];
+const MAX_BODY_BYTES: u64 = 16 * 1024 * 1024;
+/// Requests beyond this many wait in the listener's queue.
+const RPC_WORKER_THREADS: usize = 4;
+/// Mirrors `identifier` in `tauri.conf.json`, which names the config dir.
+const APP_IDENTIFIER: &str = "com.tomalmog.studio-app";
+
+/// Runs the server in the foreground for `--serve`; returns only on error.
+pub fn serve_headless(args: &[String]) -> Result<(), ForgeStudioError> {
+    let _ = tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .try_init();
+    let settings = SettingsStore::default();
+    match dirs::config_dir() {
+        Some(config_dir) => {
+            if let Err(error) = settings.load(&config_dir.join(APP_IDENTIFIER)) {
+                tracing::warn!("{error}");
+            }
+        }
+        None => tracing::warn!("No config directory found; serving with default settings."),
+    }
+    let port = match args.iter().position(|arg| arg == PORT_FLAG) {
+        Some(position) => args
+            .get(position + 1)
+            .and_then(|port| port.parse::<u16>().ok())
+            .ok_or_else(|| {
+                ForgeStudioError::invalid_input(format!(
+                    "{PORT_FLAG} needs a port number, e.g. {PORT_FLAG} 7733."
+                ))
+            })?,
+        None => settings.current().rpc_server.port,
+    };
+    let task_store = CommandTaskStore::new(
+        EventBus::default(),
+        CrashReporter::default(),
+        settings.clone(),
+    );
+    let context = RpcContext {
+        task_store,
+        settings,
+    };
+    serve(context, port)
+}
+
+/// Starts the server on a background thread of the running app.
+pub(crate) fn spawn_rpc_server(task_store: CommandTaskStore, settings: SettingsStore, port: u16) {
+    let context = RpcContext {
+        task_store,
+        settings,
+    };
+    thread::spawn(move || {
+        if let Err(error) = serve(context, port) {
+            tracing::warn!("{error}");
+        }
+    });
+}
+
+fn serve(context: RpcContext, port: u16) -> Result<(), ForgeStudioError> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "RPC server could not listen on 127.0.0.1:{port}: {error}. Pick another port."
+        ))
+    })?;
+    tracing::info!("RPC server listening on http://127.0.0.1:{port}");
+    let server = Arc::new(server);
+    let workers: Vec<_> = (0..RPC_WORKER_THREADS)
+        .map(|_| {
+            let (server, context) = (Arc::clone(&server), context.clone());
+            thread::spawn(move || {
+                while let Ok(request) = server.recv() {
+                    handle_request(&context, request);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        if worker.join().is_err() {
+            tracing::warn!("An RPC worker thread panicked.");
+        }
+    }
+    Ok(())
+}
+
+fn handle_request(context: &RpcContext, mut request: Request) {
+    let (status_code, body) = match check_request(&request) {
+        Ok(()) => {
+            let mut body = String::new();
+            match request
+                .as_reader()
+                .take(MAX_BODY_BYTES)
+                .read_to_string(&mut body)
+            {
+                Ok(_) => (200, rpc_response(context, &body)),
+                Err(error) => (400, rpc_error(Value::Null, -32700, &error.to_string())),
+            }
+        }
+        Err((status_code, reason)) => (status_code, rpc_error(Value::Null, -32600, reason)),
+    };
+    let mut response = Response::from_string(body.to_string()).with_status_code(status_code);
+    if let Ok(content_type) = Header::from_bytes("Content-Type", "application/json") {
+        response.add_header(content_type);
+    }
+    if let Err(error) = request.respond(response) {
+        tracing::debug!("RPC response was not sent: {error}");
+    }
+}
+
+/// Refuses anything but a local script's JSON `POST`.
+fn check_request(request: &Request) -> Result<(), (u16, &'static str)> {
+    let header = |name: &'static str| {
+        request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv(name))
+            .map(|header| header.value.as_str().to_string())
+    };
+    if request.method() != &Method::Post {
+        return Err((405, "Send JSON-RPC requests with POST."));
+    }
+    if header("Origin").is_some() {
+        return Err((
+            403,
+            "Browser requests are refused; call the server from a script.",
+        ));
+    }
+    let host = header("Host").unwrap_or_default();
+    let host_name = host
+        .rsplit_once(':')
+        .map_or(host.as_str(), |(name, _)| name);
+    if !LOCAL_HOSTS.contains(&host_name) {
+        return Err((403, "Connect through 127.0.0.1 or localhost."));
+    }
+    let content_type = header("Content-Type").unwrap_or_default();
+    if !content_type.starts_with("application/json") {
+        return Err((415, "Set Content-Type: application/json."));
+    }
+    Ok(())
+}
+
+/// Answers one JSON-RPC request body.
+fn rpc_response(context: &RpcContext, body: &str) -> Value {
+    let Ok(request) = serde_json::from_str::<Value>(body) else {
+        return rpc_error(Value::Null, -32700, "Request body is not JSON.");
+    };
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return rpc_error(id, -32600, "Request has no method name.");
+    };
+    if !RPC_METHODS.contains(&method) {
+        return rpc_error(id, -32601, &format!("Unknown RPC method '{method}'."));
+    }
+    let params = request.get("params").cloned().unwrap_or(json!({}));
+    match call_method(context, method, &params) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32000, "message": error.to_string(), "data": error },
+        }),
+    }
+}
+
+fn rpc_error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rpc_response;
+    use crate::commands::rpc_methods::RpcContext;
+
+    #[test]
+    fn responses_follow_json_rpc_error_codes() {
+        let context = RpcContext::default();
+        let code = |body: &str| rpc_response(&context, body)["error"]["code"].clone();
+        assert_eq!(code("{not json"), -32700);
+        assert_eq!(code(r#"{"jsonrpc": "2.0", "id": 1}"#), -32600);
+        assert_eq!(
+            code(r#"{"jsonrpc": "2.0", "id": 1, "method": "delete_run"}"#),
+            -32601
+        );
+
+        let failed = rpc_response(
+            &context,
+            r#"{"jsonrpc": "2.0", "id": 7, "method": "get_forge_command_status", "params": {"taskId": "t"}}"#,
+        );
+        assert_eq!(failed["id"], 7);
+        assert_eq!(failed["error"]["code"], -32000);
+        assert_eq!(failed["error"]["data"]["code"], "not_found");
+    }
+}
@@ -0,0 +1,138 @@
+//! User-authored tags and notes attached to training runs.
+//!
+//! Annotations live in `runs/<run_id>/meta.json`, next to the CLI-owned
+//! `lifecycle.json`, so the CLI never overwrites them on state transitions.
+//! Studio-derived links such as resume provenance are stored there too.
+//! Tag and note changes are stamped with the settings identity and recorded
+//! in the audit log.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::audit_log::record_audit_entry;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, run_dir, write_json_file,
+};
+use crate::models::{ForgeStudioError, RunUserMetadata};
+use chrono::{SecondsFormat, Utc};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const RUN_META_FILE_NAME: &str = "meta.json";
+const MAX_TAG_LENGTH: usize = 64;
+
+#[tauri::command]
+pub fn set_run_tags(
+    data_root: String,
+    run_id: String,
+    tags: Vec<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunUserMetadata, ForgeStudioError> {
+    let normalized_tags = normalize_tags(tags)?;
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let author = settings.author();
+    let meta = update_run_meta(&resolved_data_root, &run_id, |meta| {
+        meta.tags = normalized_tags;
+        meta.updated_by = author.clone();
+    })?;
+    record_audit_entry(&resolved_data_root, author, "set_run_tags", &run_id);
+    Ok(meta)
+}
+
+#[tauri::command]
+pub fn set_run_notes(
+    data_root: String,
+    run_id: String,
+    markdown: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunUserMetadata, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let author = settings.author();
+    let meta = update_run_meta(&resolved_data_root, &run_id, |meta| {
+        meta.notes = markdown;
+        meta.updated_by = author.clone();
+    })?;
+    record_audit_entry(&resolved_data_root, author, "set_run_notes", &run_id);
+    Ok(meta)
+}
+
+/// Reads run annotations, treating a missing file as "no annotations yet".
+pub(crate) fn load_run_meta(
+    data_root: &Path,
+    run_id: &str,
+) -> Result<RunUserMetadata, ForgeStudioError> {
+    let meta_path = run_meta_path(data_root, run_id);
+    if !meta_path.exists() {
+        return Ok(RunUserMetadata::default());
+    }
+    serde_json::from_value(read_json_file(&meta_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse run meta {}: {error}",
+            meta_path.display()
+        ))
+    })
+}
+
+pub(crate) fn update_run_meta(
+    data_root: &Path,
+    run_id: &str,
+    apply: impl FnOnce(&mut RunUserMetadata),
+) -> Result<RunUserMetadata, ForgeStudioError> {
+    // Validates the run exists so annotations never create orphan run dirs.
+    load_run_lifecycle(data_root, run_id)?;
+    let mut meta = load_run_meta(data_root, run_id)?;
+    apply(&mut meta);
+    meta.updated_at = Some(Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true));
+    let payload = serde_json::to_value(&meta).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize run meta for '{run_id}': {error}"
+        ))
+    })?;
+    write_json_file(&run_meta_path(data_root, run_id), &payload)?;
+    Ok(meta)
+}
+
+pub(crate) fn run_meta_path(data_root: &Path, run_id: &str) -> PathBuf {
+    run_dir(data_root, run_id).join(RUN_META_FILE_NAME)
+}
+
+/// Trims tags and drops blanks and duplicates while keeping the user's order.
+fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>, ForgeStudioError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let trimmed = tag.trim();
+        if trimmed.is_empty() || normalized.iter().any(|existing| existing == trimmed) {
+            continue;
+        }
+        if trimmed.chars().count() > MAX_TAG_LENGTH {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Tag '{trimmed}' is longer than {MAX_TAG_LENGTH} characters. Use a shorter tag and put details in the run notes."
+            )));
+        }
+        normalized.push(trimmed.to_string());
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_tags;
+
+    #[test]
+    fn normalize_tags_trims_and_deduplicates_in_order() {
+        let tags = vec![
+            " baseline ".to_string(),
+            "".to_string(),
+            "lr sweep #3".to_string(),
+            "baseline".to_string(),
+        ];
+        let normalized = normalize_tags(tags).expect("tags should normalize");
+        assert_eq!(
+            normalized,
+            vec!["baseline".to_string(), "lr sweep #3".to_string()]
+        );
+    }
+
+    #[test]
+    fn normalize_tags_rejects_overlong_tags() {
+        assert!(normalize_tags(vec!["x".repeat(65)]).is_err());
+    }
+}
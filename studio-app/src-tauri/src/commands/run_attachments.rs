@@ -0,0 +1,204 @@
+//! User files attached to training runs: notebooks, reports, and plots.
+//!
+//! Attaching copies the file into `runs/<run_id>/attachments/` so it stays
+//! with the run when the original moves or the run is archived, and records
+//! it in the run's `meta.json` next to tags and notes. Files are copied
+//! rather than linked because analysis notebooks are usually edited in place
+//! after the fact, and the attachment should show what was reviewed.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::content_hash::sha256_file;
+use crate::commands::path_safety::confine_path;
+use crate::commands::run_annotations::{load_run_meta, update_run_meta};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path, run_dir};
+use crate::models::{ForgeStudioError, RunAttachment, RunAttachmentContent};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use chrono::{SecondsFormat, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const ATTACHMENTS_DIR_NAME: &str = "attachments";
+const ATTACHMENT_ID_PREFIX: &str = "attachment-";
+const ATTACHMENT_KINDS: [&str; 4] = ["notebook", "report", "plot", "other"];
+const MAX_ATTACHMENT_BYTES: u64 = 25 * 1024 * 1024;
+const TEXT_ENCODING: &str = "utf-8";
+const BASE64_ENCODING: &str = "base64";
+
+#[tauri::command]
+pub fn attach_run_artifact(
+    data_root: String,
+    run_id: String,
+    file_path: String,
+    kind: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunAttachment, ForgeStudioError> {
+    if !ATTACHMENT_KINDS.contains(&kind.as_str()) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Unknown attachment kind '{kind}'. Use one of: {}.",
+            ATTACHMENT_KINDS.join(", ")
+        )));
+    }
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let source_path = confine_path(
+        Path::new(&file_path),
+        "Attachment file",
+        &settings.workspace_roots(),
+    )?;
+    let size_bytes = attachment_size(&source_path)?;
+    let file_name = source_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            ForgeStudioError::invalid_input(format!(
+                "{file_path} has no usable file name. Rename the file."
+            ))
+        })?
+        .to_string();
+    let meta = load_run_meta(&resolved_data_root, &run_id)?;
+    let attachment_number = next_attachment_number(&meta.attachments);
+    let attachment_id = format!("{ATTACHMENT_ID_PREFIX}{attachment_number:04}");
+    let attachments_dir = run_dir(&resolved_data_root, &run_id).join(ATTACHMENTS_DIR_NAME);
+    let stored_name = format!("{attachment_id}-{file_name}");
+    let stored_path = copy_into_run(&source_path, &attachments_dir, &stored_name)?;
+    let attachment = RunAttachment {
+        attachment_id,
+        kind,
+        file_name,
+        stored_path: stored_path.display().to_string(),
+        source_path: source_path.display().to_string(),
+        size_bytes,
+        sha256: sha256_file(&stored_path)?,
+        attached_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+    };
+    let recorded = attachment.clone();
+    update_run_meta(&resolved_data_root, &run_id, |meta| {
+        meta.attachments.push(recorded)
+    })
+    .inspect_err(|_| {
+        let _ = fs::remove_file(&stored_path);
+    })?;
+    Ok(attachment)
+}
+
+#[tauri::command]
+pub fn list_run_attachments(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<RunAttachment>, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    load_run_lifecycle(&resolved_data_root, &run_id)?;
+    Ok(load_run_meta(&resolved_data_root, &run_id)?.attachments)
+}
+
+#[tauri::command]
+pub fn get_run_attachment(
+    data_root: String,
+    run_id: String,
+    attachment_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunAttachmentContent, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let attachment = load_run_meta(&resolved_data_root, &run_id)?
+        .attachments
+        .into_iter()
+        .find(|attachment| attachment.attachment_id == attachment_id)
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{run_id}' has no attachment '{attachment_id}'. \
+                 List the run's attachments to pick one."
+            ))
+        })?;
+    let payload = fs::read(&attachment.stored_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read attachment {}: {error}. Re-attach the file if it was removed.",
+            attachment.stored_path
+        ))
+    })?;
+    let (encoding, content) = encode_content(payload);
+    Ok(RunAttachmentContent {
+        attachment,
+        encoding: encoding.to_string(),
+        content,
+    })
+}
+
+fn copy_into_run(
+    source_path: &Path,
+    attachments_dir: &Path,
+    stored_name: &str,
+) -> Result<PathBuf, ForgeStudioError> {
+    fs::create_dir_all(attachments_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to create {}: {error}",
+            attachments_dir.display()
+        ))
+    })?;
+    let stored_path = attachments_dir.join(stored_name);
+    fs::copy(source_path, &stored_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to copy {} into the run: {error}. Check the file is readable.",
+            source_path.display()
+        ))
+    })?;
+    Ok(stored_path)
+}
+
+fn attachment_size(source_path: &Path) -> Result<u64, ForgeStudioError> {
+    let metadata = fs::metadata(source_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Cannot attach {}: {error}. Check the file path.",
+            source_path.display()
+        ))
+    })?;
+    if !metadata.is_file() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "{} is not a file. Attach a single file, or zip a directory first.",
+            source_path.display()
+        )));
+    }
+    if metadata.len() > MAX_ATTACHMENT_BYTES {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "{} is {} bytes, over the {MAX_ATTACHMENT_BYTES}-byte attachment limit. \
+             Attach a smaller export of the file.",
+            source_path.display(),
+            metadata.len()
+        )));
+    }
+    Ok(metadata.len())
+}
+
+/// One past the highest existing number, so ids stay unique if entries are ever removed.
+fn next_attachment_number(attachments: &[RunAttachment]) -> u32 {
+    attachments
+        .iter()
+        .filter_map(|attachment| attachment.attachment_id.strip_prefix(ATTACHMENT_ID_PREFIX))
+        .filter_map(|number| number.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1
+}
+
+/// Text comes back as-is so markdown and notebooks need no decoding in the UI.
+fn encode_content(payload: Vec<u8>) -> (&'static str, String) {
+    match String::from_utf8(payload) {
+        Ok(text) => (TEXT_ENCODING, text),
+        Err(error) => (BASE64_ENCODING, BASE64_STANDARD.encode(error.into_bytes())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_content;
+
+    #[test]
+    fn encode_content_keeps_text_and_base64_encodes_binary() {
+        let (encoding, content) = encode_content(b"# Report\n".to_vec());
+        assert_eq!((encoding, content.as_str()), ("utf-8", "# Report\n"));
+        let (encoding, content) = encode_content(vec![0x89, b'P', b'N', b'G']);
+        assert_eq!((encoding, content.as_str()), ("base64", "iVBORw=="));
+    }
+}
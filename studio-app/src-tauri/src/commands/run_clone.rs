@@ -0,0 +1,167 @@
+//! Re-launch a run's training config with user overrides.
+//!
+//! The clone replays the source run's saved `training_config.json` with the
+//! overrides merged in, into a fresh output dir so the source artifacts stay
+//! intact. Once the CLI registers the new run, a `cloned` edge from the
+//! source run is added to the lineage graph.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::forge_commands::validate_args;
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::lineage_store_io::{run_node_id, update_lineage_graph};
+use crate::commands::new_run_linker::{spawn_new_run_linker, NewRunLinkRequest};
+use crate::commands::run_details::{read_optional_json, resolve_run_artifacts};
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_resume::{build_train_args, RESERVED_CONFIG_KEYS};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::commands::train_task_log::spawn_train_log_recorder;
+use crate::models::{ForgeStudioError, RunCloneOverrides, RunCloneStart, RunLifecycleRecord};
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use std::path::Path;
+use tauri::State;
+
+const CLONED_EDGE_TYPE: &str = "cloned";
+
+#[tauri::command]
+pub fn clone_run(
+    data_root: String,
+    run_id: String,
+    overrides: Option<RunCloneOverrides>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunCloneStart, ForgeStudioError> {
+    let overrides = overrides.unwrap_or_default();
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let config = merge_overrides(load_source_config(&lifecycle)?, overrides.config)?;
+    let output_dir = clone_output_dir(overrides.output_dir, &lifecycle.output_dir)?;
+    let dataset_version_id = overrides
+        .dataset_version_id
+        .unwrap_or_else(|| lifecycle.dataset_version_id.clone());
+    let args = build_train_args(
+        &lifecycle.dataset_name,
+        &dataset_version_id,
+        &output_dir,
+        Some(&Value::Object(config)),
+    );
+    validate_args(&args)?;
+    let known_run_ids = read_run_index(&resolved_data_root)?.into_iter().collect();
+    let task = task_store.start_task(data_root, args.clone());
+    spawn_train_log_recorder(task_store.inner().clone(), task.task_id.clone(), &args);
+    let link_request = NewRunLinkRequest {
+        data_root: resolved_data_root,
+        task_store: task_store.inner().clone(),
+        task_id: task.task_id.clone(),
+        output_dir: output_dir.clone(),
+        known_run_ids,
+    };
+    let source_run_id = run_id.clone();
+    spawn_new_run_linker(link_request, move |data_root, new_run_id| {
+        if let Err(error) = record_clone_edge(data_root, &source_run_id, new_run_id) {
+            tracing::warn!("{error}");
+        }
+    });
+    Ok(RunCloneStart {
+        task_id: task.task_id,
+        estimated_total_seconds: task.estimated_total_seconds,
+        source_run_id: run_id,
+        dataset_version_id,
+        output_dir,
+    })
+}
+
+fn load_source_config(
+    lifecycle: &RunLifecycleRecord,
+) -> Result<Map<String, Value>, ForgeStudioError> {
+    let artifacts = resolve_run_artifacts(lifecycle)?;
+    read_optional_json(artifacts.training_config_path.as_deref())?
+        .and_then(|config| config.as_object().cloned())
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{}' has no saved training config to clone. \
+                 Only runs that finished writing artifacts can be cloned.",
+                lifecycle.run_id
+            ))
+        })
+}
+
+/// Overrides may only name keys the source config has, so typos fail loudly.
+fn merge_overrides(
+    mut config: Map<String, Value>,
+    overrides: Map<String, Value>,
+) -> Result<Map<String, Value>, ForgeStudioError> {
+    for (key, value) in overrides {
+        if RESERVED_CONFIG_KEYS.contains(&key.as_str()) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "'{key}' cannot be overridden through config. Use the dedicated clone \
+                 override field instead."
+            )));
+        }
+        if !config.contains_key(&key) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "'{key}' is not a training config key of the source run. Check the spelling \
+                 against the run's training config."
+            )));
+        }
+        config.insert(key, value);
+    }
+    Ok(config)
+}
+
+/// The requested output dir, or a timestamped sibling of the source's.
+fn clone_output_dir(
+    requested: Option<String>,
+    source_output_dir: &str,
+) -> Result<String, ForgeStudioError> {
+    let output_dir = requested.unwrap_or_else(|| {
+        let suffix = Utc::now().format("%Y%m%d-%H%M%S");
+        format!("{}-clone-{suffix}", source_output_dir.trim_end_matches('/'))
+    });
+    if output_dir == source_output_dir {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Clone output dir {output_dir} is the source run's output dir. \
+             Pick a different dir so the source artifacts are not overwritten."
+        )));
+    }
+    Ok(output_dir)
+}
+
+fn record_clone_edge(
+    data_root: &Path,
+    source_run_id: &str,
+    new_run_id: &str,
+) -> Result<(), ForgeStudioError> {
+    update_lineage_graph(data_root, |graph| {
+        graph.edges.push(json!({
+            "from": run_node_id(source_run_id),
+            "to": run_node_id(new_run_id),
+            "type": CLONED_EDGE_TYPE,
+        }));
+        Ok(((), true))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_overrides;
+    use serde_json::json;
+
+    #[test]
+    fn merge_overrides_replaces_known_keys_and_rejects_unknown_ones() {
+        let config = json!({"epochs": 3, "learning_rate": 0.001, "output_dir": "out"});
+        let config = config.as_object().cloned().expect("config object");
+        let overrides = json!({"epochs": 10, "learning_rate": null});
+        let merged = merge_overrides(config.clone(), overrides.as_object().cloned().expect("map"))
+            .expect("known keys merge");
+        assert_eq!(merged.get("epochs"), Some(&json!(10)));
+        assert_eq!(merged.get("learning_rate"), Some(&json!(null)));
+        let typo = json!({"epoch": 10}).as_object().cloned().expect("map");
+        assert!(merge_overrides(config.clone(), typo).is_err());
+        let reserved = json!({"output_dir": "elsewhere"})
+            .as_object()
+            .cloned()
+            .expect("map");
+        assert!(merge_overrides(config, reserved).is_err());
+    }
+}
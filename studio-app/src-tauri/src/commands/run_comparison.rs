@@ -0,0 +1,179 @@
+//! Side-by-side comparison of training runs.
+//!
+//! Loss curves are aligned on epoch and global step so runs of different
+//! lengths share one x-axis; missing points are `None` rather than dropped,
+//! keeping every curve array index-aligned with `RunComparison::runs`.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_details::{
+    compute_final_metrics, load_history, read_optional_json, resolve_run_artifacts,
+};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::models::{
+    ComparedRun, ConfigFieldDiff, EpochLossAlignment, ForgeStudioError, RunComparison,
+    StepLossAlignment, TrainingHistory,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tauri::State;
+
+const MIN_COMPARED_RUNS: usize = 2;
+
+struct LoadedRun {
+    summary: ComparedRun,
+    history: Option<TrainingHistory>,
+    config: Option<Value>,
+}
+
+#[tauri::command]
+pub fn compare_runs(
+    data_root: String,
+    run_ids: Vec<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunComparison, ForgeStudioError> {
+    if run_ids.len() < MIN_COMPARED_RUNS {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Run comparison needs at least {MIN_COMPARED_RUNS} run ids, got {}. Select more runs.",
+            run_ids.len()
+        )));
+    }
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let mut loaded_runs = Vec::with_capacity(run_ids.len());
+    for run_id in &run_ids {
+        loaded_runs.push(load_compared_run(&resolved_data_root, run_id)?);
+    }
+    let histories: Vec<Option<&TrainingHistory>> =
+        loaded_runs.iter().map(|run| run.history.as_ref()).collect();
+    let configs: Vec<Option<&Value>> = loaded_runs.iter().map(|run| run.config.as_ref()).collect();
+    let epoch_losses = align_epoch_losses(&histories);
+    let step_losses = align_step_losses(&histories);
+    let config_differences = diff_configs(&configs);
+    Ok(RunComparison {
+        runs: loaded_runs.into_iter().map(|run| run.summary).collect(),
+        epoch_losses,
+        step_losses,
+        config_differences,
+    })
+}
+
+fn load_compared_run(data_root: &Path, run_id: &str) -> Result<LoadedRun, ForgeStudioError> {
+    let lifecycle = load_run_lifecycle(data_root, run_id)?;
+    let artifacts = resolve_run_artifacts(&lifecycle)?;
+    let history = match artifacts.history_path.as_deref() {
+        Some(history_path) => load_history(history_path)?,
+        None => None,
+    };
+    let config = read_optional_json(artifacts.training_config_path.as_deref())?;
+    let summary = ComparedRun {
+        run_id: lifecycle.run_id,
+        state: lifecycle.state,
+        dataset_version_id: lifecycle.dataset_version_id,
+        config_hash: lifecycle.config_hash,
+        final_metrics: history.as_ref().and_then(compute_final_metrics),
+    };
+    Ok(LoadedRun {
+        summary,
+        history,
+        config,
+    })
+}
+
+fn align_epoch_losses(histories: &[Option<&TrainingHistory>]) -> Vec<EpochLossAlignment> {
+    let mut rows: BTreeMap<u64, EpochLossAlignment> = BTreeMap::new();
+    for (run_index, history) in histories.iter().enumerate() {
+        for epoch in history
+            .map(|value| value.epochs.as_slice())
+            .unwrap_or_default()
+        {
+            let row = rows
+                .entry(epoch.epoch)
+                .or_insert_with(|| EpochLossAlignment {
+                    epoch: epoch.epoch,
+                    train_losses: vec![None; histories.len()],
+                    validation_losses: vec![None; histories.len()],
+                });
+            row.train_losses[run_index] = Some(epoch.train_loss);
+            row.validation_losses[run_index] = Some(epoch.validation_loss);
+        }
+    }
+    rows.into_values().collect()
+}
+
+fn align_step_losses(histories: &[Option<&TrainingHistory>]) -> Vec<StepLossAlignment> {
+    let mut rows: BTreeMap<u64, StepLossAlignment> = BTreeMap::new();
+    for (run_index, history) in histories.iter().enumerate() {
+        for batch in history
+            .map(|value| value.batch_losses.as_slice())
+            .unwrap_or_default()
+        {
+            let row = rows
+                .entry(batch.global_step)
+                .or_insert_with(|| StepLossAlignment {
+                    global_step: batch.global_step,
+                    train_losses: vec![None; histories.len()],
+                });
+            row.train_losses[run_index] = Some(batch.train_loss);
+        }
+    }
+    rows.into_values().collect()
+}
+
+/// Lists top-level config fields whose values are not identical across all runs.
+fn diff_configs(configs: &[Option<&Value>]) -> Vec<ConfigFieldDiff> {
+    let objects: Vec<Option<&serde_json::Map<String, Value>>> = configs
+        .iter()
+        .map(|config| config.and_then(Value::as_object))
+        .collect();
+    let fields: BTreeSet<&String> = objects
+        .iter()
+        .flatten()
+        .flat_map(|object| object.keys())
+        .collect();
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let values: Vec<Option<Value>> = objects
+                .iter()
+                .map(|object| object.and_then(|value| value.get(field)).cloned())
+                .collect();
+            let all_equal = values.windows(2).all(|pair| pair[0] == pair[1]);
+            (!all_equal).then(|| ConfigFieldDiff {
+                field: field.clone(),
+                values,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{align_epoch_losses, diff_configs};
+    use crate::models::TrainingHistory;
+    use serde_json::json;
+
+    #[test]
+    fn align_epoch_losses_pads_shorter_runs_with_none() {
+        let short: TrainingHistory = serde_json::from_value(json!({"epochs": [
+            {"epoch": 1, "train_loss": 2.0, "validation_loss": 2.2}
+        ]}))
+        .expect("history should parse");
+        let long: TrainingHistory = serde_json::from_value(json!({"epochs": [
+            {"epoch": 1, "train_loss": 1.8, "validation_loss": 2.0},
+            {"epoch": 2, "train_loss": 1.5, "validation_loss": 1.9}
+        ]}))
+        .expect("history should parse");
+        let rows = align_epoch_losses(&[Some(&short), Some(&long)]);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].train_losses, vec![None, Some(1.5)]);
+    }
+
+    #[test]
+    fn diff_configs_reports_only_changed_fields() {
+        let baseline = json!({"learning_rate": 0.001, "epochs": 3});
+        let candidate = json!({"learning_rate": 0.01, "epochs": 3});
+        let differences = diff_configs(&[Some(&baseline), Some(&candidate)]);
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].field, "learning_rate");
+    }
+}
@@ -0,0 +1,139 @@
+//! Pairwise training-config diff for the "what changed" experiment panel.
+//!
+//! Unlike `compare_runs`, which lists top-level fields differing across any
+//! number of runs, this walks nested objects so a changed layer inside an
+//! architecture block is reported by its dotted path rather than as the
+//! whole block.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_details::{read_optional_json, resolve_run_artifacts};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::models::{ConfigKeyChange, ForgeStudioError, RunConfigDiff};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::State;
+
+#[tauri::command]
+pub fn diff_run_configs(
+    data_root: String,
+    run_a: String,
+    run_b: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunConfigDiff, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let (config_hash_a, config_a) = load_run_config(&resolved_data_root, &run_a)?;
+    let (config_hash_b, config_b) = load_run_config(&resolved_data_root, &run_b)?;
+    let mut diff = RunConfigDiff {
+        run_a,
+        run_b,
+        config_hash_a,
+        config_hash_b,
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+        unchanged_count: 0,
+    };
+    classify_changes(&flatten(&config_a), &flatten(&config_b), &mut diff);
+    Ok(diff)
+}
+
+fn load_run_config(data_root: &Path, run_id: &str) -> Result<(String, Value), ForgeStudioError> {
+    let lifecycle = load_run_lifecycle(data_root, run_id)?;
+    let artifacts = resolve_run_artifacts(&lifecycle)?;
+    let config_path = artifacts.training_config_path.as_deref();
+    let config = read_optional_json(config_path)?.ok_or_else(|| {
+        ForgeStudioError::not_found(format!(
+            "Run '{run_id}' has no saved training config. Configs are written when training \
+             finishes; pick a completed run."
+        ))
+    })?;
+    Ok((lifecycle.config_hash, config))
+}
+
+fn classify_changes(
+    before: &BTreeMap<String, Value>,
+    after: &BTreeMap<String, Value>,
+    diff: &mut RunConfigDiff,
+) {
+    for (key, old_value) in before {
+        match after.get(key) {
+            None => diff.removed.push(change(key, Some(old_value), None)),
+            Some(new_value) if new_value != old_value => {
+                diff.changed
+                    .push(change(key, Some(old_value), Some(new_value)));
+            }
+            Some(_) => diff.unchanged_count += 1,
+        }
+    }
+    for (key, new_value) in after {
+        if !before.contains_key(key) {
+            diff.added.push(change(key, None, Some(new_value)));
+        }
+    }
+}
+
+fn change(key: &str, before: Option<&Value>, after: Option<&Value>) -> ConfigKeyChange {
+    ConfigKeyChange {
+        key: key.to_string(),
+        before: before.cloned(),
+        after: after.cloned(),
+    }
+}
+
+/// Leaf values keyed by dotted path; arrays are leaves since their items have no stable key.
+fn flatten(config: &Value) -> BTreeMap<String, Value> {
+    let mut leaves = BTreeMap::new();
+    if let Some(object) = config.as_object() {
+        flatten_into(object, "", &mut leaves);
+    }
+    leaves
+}
+
+fn flatten_into(object: &Map<String, Value>, prefix: &str, leaves: &mut BTreeMap<String, Value>) {
+    for (key, value) in object {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            Value::Object(nested) if !nested.is_empty() => flatten_into(nested, &path, leaves),
+            _ => {
+                leaves.insert(path, value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify_changes, flatten};
+    use crate::models::RunConfigDiff;
+    use serde_json::json;
+
+    #[test]
+    fn classify_changes_reports_nested_paths() {
+        let before = json!({"epochs": 3, "lr": 0.001, "model": {"layers": 2, "dim": 64}});
+        let after = json!({"epochs": 3, "lr": 0.01, "model": {"layers": 4}, "dropout": 0.1});
+        let mut diff = RunConfigDiff {
+            run_a: "a".to_string(),
+            run_b: "b".to_string(),
+            config_hash_a: "h1".to_string(),
+            config_hash_b: "h2".to_string(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+            unchanged_count: 0,
+        };
+        classify_changes(&flatten(&before), &flatten(&after), &mut diff);
+        let keys = |rows: &[crate::models::ConfigKeyChange]| -> Vec<String> {
+            rows.iter().map(|row| row.key.clone()).collect()
+        };
+        assert_eq!(keys(&diff.added), vec!["dropout"]);
+        assert_eq!(keys(&diff.removed), vec!["model.dim"]);
+        assert_eq!(keys(&diff.changed), vec!["lr", "model.layers"]);
+        assert_eq!(diff.changed[0].after, Some(json!(0.01)));
+        assert_eq!(diff.unchanged_count, 1);
+    }
+}
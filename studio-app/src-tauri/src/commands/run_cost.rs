@@ -0,0 +1,141 @@
+//! Per-run energy and cost accounting.
+//!
+//! Rates live in `runs/cost_rates.json` so every Studio window pricing the
+//! same data root agrees. Energy cost and GPU-hour cost add up when both
+//! rates are set, covering metered power on top of rented hardware.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::energy_ledger::{load_run_energy_usage, EnergyUsage};
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, write_json_file,
+};
+use crate::models::{CostRates, ForgeStudioError, RunCost};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const COST_RATES_FILE_NAME: &str = "cost_rates.json";
+
+#[tauri::command]
+pub fn get_run_cost(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunCost, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let rates = load_cost_rates(&resolved_data_root)?;
+    let usage = load_run_energy_usage(&lifecycle);
+    Ok(RunCost {
+        run_id,
+        sample_count: usage.sample_count,
+        measured_hours: usage.measured_hours,
+        gpu_hours: usage.gpu_hours,
+        energy_kwh: usage.energy_kwh,
+        cost: price_usage(&usage, &rates),
+        currency: rates.currency,
+    })
+}
+
+#[tauri::command]
+pub fn get_cost_rates(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<CostRates, ForgeStudioError> {
+    load_cost_rates(&resolve_data_root_path(
+        &data_root,
+        &settings.workspace_roots(),
+    )?)
+}
+
+#[tauri::command]
+pub fn set_cost_rates(
+    data_root: String,
+    rates: CostRates,
+    settings: State<'_, SettingsStore>,
+) -> Result<CostRates, ForgeStudioError> {
+    let negative = [rates.price_per_kwh, rates.price_per_gpu_hour]
+        .into_iter()
+        .flatten()
+        .any(|rate| rate < 0.0 || !rate.is_finite());
+    if negative {
+        return Err(ForgeStudioError::invalid_input(
+            "Cost rates must be finite and non-negative. Clear a rate to disable it.",
+        ));
+    }
+    if rates.currency.trim().is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Cost currency is empty. Use a currency code such as USD.",
+        ));
+    }
+    let rates_path = cost_rates_path(&resolve_data_root_path(
+        &data_root,
+        &settings.workspace_roots(),
+    )?);
+    if let Some(parent) = rates_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(&rates).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize cost rates: {error}"))
+    })?;
+    write_json_file(&rates_path, &payload)?;
+    Ok(rates)
+}
+
+/// Missing rates file means no rates are configured yet.
+pub(crate) fn load_cost_rates(data_root: &Path) -> Result<CostRates, ForgeStudioError> {
+    let rates_path = cost_rates_path(data_root);
+    if !rates_path.exists() {
+        return Ok(CostRates::default());
+    }
+    serde_json::from_value(read_json_file(&rates_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse cost rates {}: {error}. Fix or delete the file to reset rates.",
+            rates_path.display()
+        ))
+    })
+}
+
+/// `None` when no configured rate applies to what was measured.
+pub(crate) fn price_usage(usage: &EnergyUsage, rates: &CostRates) -> Option<f64> {
+    let energy_cost = rates
+        .price_per_kwh
+        .zip(usage.energy_kwh)
+        .map(|(rate, kwh)| rate * kwh);
+    let gpu_hour_cost = rates.price_per_gpu_hour.map(|rate| rate * usage.gpu_hours);
+    match (energy_cost, gpu_hour_cost) {
+        (None, None) => None,
+        (left, right) => Some(left.unwrap_or(0.0) + right.unwrap_or(0.0)),
+    }
+}
+
+fn cost_rates_path(data_root: &Path) -> PathBuf {
+    data_root.join("runs").join(COST_RATES_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::price_usage;
+    use crate::commands::energy_ledger::EnergyUsage;
+    use crate::models::CostRates;
+
+    #[test]
+    fn price_usage_adds_energy_and_gpu_hour_costs() {
+        let usage = EnergyUsage {
+            sample_count: 10,
+            measured_hours: 2.0,
+            gpu_hours: 4.0,
+            energy_kwh: Some(3.0),
+        };
+        let mut rates = CostRates {
+            price_per_kwh: Some(0.25),
+            ..CostRates::default()
+        };
+        assert_eq!(price_usage(&usage, &rates), Some(0.75));
+        rates.price_per_gpu_hour = Some(1.5);
+        assert_eq!(price_usage(&usage, &rates), Some(6.75));
+        assert_eq!(price_usage(&usage, &CostRates::default()), None);
+    }
+}
@@ -0,0 +1,187 @@
+//! Full detail view of one training run.
+//!
+//! Joins the lifecycle record, artifact contract, resolved training config,
+//! history-derived final metrics, and on-disk checkpoints into one model.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, optional_string, read_json_file, resolve_data_root_path,
+    resolve_workspace_path,
+};
+use crate::models::{
+    ForgeStudioError, RunArtifactPaths, RunCheckpoint, RunFinalMetrics, RunLifecycleRecord,
+    TrainingHistory, TrainingRunDetails,
+};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+pub(crate) const ARTIFACT_CONTRACT_FILE_NAME: &str = "training_artifacts_manifest.json";
+
+#[tauri::command]
+pub fn get_run_details(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<TrainingRunDetails, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let artifacts = resolve_run_artifacts(&lifecycle)?;
+    let training_config = read_optional_json(artifacts.training_config_path.as_deref())?;
+    let final_metrics = match artifacts.history_path.as_deref() {
+        Some(history_path) => load_history(history_path)?
+            .as_ref()
+            .and_then(compute_final_metrics),
+        None => None,
+    };
+    let checkpoints = list_checkpoints(artifacts.checkpoint_dir.as_deref())?;
+    Ok(TrainingRunDetails {
+        lifecycle,
+        training_config,
+        final_metrics,
+        artifacts,
+        checkpoints,
+    })
+}
+
+/// Resolves artifact paths from the run's contract, falling back to CLI default names.
+pub(crate) fn resolve_run_artifacts(
+    lifecycle: &RunLifecycleRecord,
+) -> Result<RunArtifactPaths, ForgeStudioError> {
+    let output_dir = resolve_workspace_path(&lifecycle.output_dir);
+    let contract_path = lifecycle
+        .artifact_contract_path
+        .as_deref()
+        .map(resolve_workspace_path)
+        .unwrap_or_else(|| output_dir.join(ARTIFACT_CONTRACT_FILE_NAME));
+    if !contract_path.exists() {
+        return Ok(default_artifact_paths(&output_dir));
+    }
+    let payload = read_json_file(&contract_path)?;
+    let contract = payload.as_object().ok_or_else(|| {
+        ForgeStudioError::parse(format!(
+            "Artifact contract {} must be a JSON object",
+            contract_path.display()
+        ))
+    })?;
+    Ok(RunArtifactPaths {
+        artifact_contract_path: Some(contract_path.display().to_string()),
+        model_path: contract_path_field(contract, "model_path"),
+        history_path: contract_path_field(contract, "history_path"),
+        plot_path: contract_path_field(contract, "plot_path"),
+        tokenizer_path: contract_path_field(contract, "tokenizer_path"),
+        training_config_path: contract_path_field(contract, "training_config_path"),
+        checkpoint_dir: contract_path_field(contract, "checkpoint_dir"),
+        best_checkpoint_path: contract_path_field(contract, "best_checkpoint_path"),
+        reproducibility_bundle_path: contract_path_field(contract, "reproducibility_bundle_path"),
+    })
+}
+
+fn contract_path_field(contract: &Map<String, Value>, key: &str) -> Option<String> {
+    optional_string(contract, key).map(|value| resolve_workspace_path(&value).display().to_string())
+}
+
+fn default_artifact_paths(output_dir: &Path) -> RunArtifactPaths {
+    let existing = |file_name: &str| -> Option<String> {
+        let candidate = output_dir.join(file_name);
+        candidate.exists().then(|| candidate.display().to_string())
+    };
+    RunArtifactPaths {
+        model_path: existing("model.pt"),
+        history_path: existing("history.json"),
+        plot_path: existing("training_curves.png"),
+        tokenizer_path: existing("tokenizer_vocab.json"),
+        training_config_path: existing("training_config.json"),
+        checkpoint_dir: existing("checkpoints"),
+        ..RunArtifactPaths::default()
+    }
+}
+
+pub(crate) fn read_optional_json(path: Option<&str>) -> Result<Option<Value>, ForgeStudioError> {
+    match path.map(PathBuf::from) {
+        Some(payload_path) if payload_path.exists() => read_json_file(&payload_path).map(Some),
+        _ => Ok(None),
+    }
+}
+
+pub(crate) fn load_history(
+    history_path: &str,
+) -> Result<Option<TrainingHistory>, ForgeStudioError> {
+    let Some(payload) = read_optional_json(Some(history_path))? else {
+        return Ok(None);
+    };
+    serde_json::from_value(payload).map(Some).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse history file {history_path}: {error}"
+        ))
+    })
+}
+
+pub(crate) fn compute_final_metrics(history: &TrainingHistory) -> Option<RunFinalMetrics> {
+    let last_epoch = history.epochs.last()?;
+    let best_epoch = history
+        .epochs
+        .iter()
+        .min_by(|left, right| left.validation_loss.total_cmp(&right.validation_loss))?;
+    Some(RunFinalMetrics {
+        epochs_completed: history.epochs.len() as u64,
+        final_train_loss: last_epoch.train_loss,
+        final_validation_loss: last_epoch.validation_loss,
+        best_validation_loss: best_epoch.validation_loss,
+        best_epoch: best_epoch.epoch,
+    })
+}
+
+pub(crate) fn list_checkpoints(
+    checkpoint_dir: Option<&str>,
+) -> Result<Vec<RunCheckpoint>, ForgeStudioError> {
+    let Some(checkpoint_dir) = checkpoint_dir.map(Path::new).filter(|dir| dir.is_dir()) else {
+        return Ok(vec![]);
+    };
+    let entries = fs::read_dir(checkpoint_dir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read {}: {error}",
+            checkpoint_dir.display()
+        ))
+    })?;
+    let mut checkpoints = Vec::new();
+    for entry in entries {
+        let entry = entry
+            .map_err(|error| ForgeStudioError::io(format!("Failed to read dir entry: {error}")))?;
+        let path = entry.path();
+        if path.extension().and_then(|value| value.to_str()) != Some("pt") {
+            continue;
+        }
+        let metadata = entry.metadata().map_err(|error| {
+            ForgeStudioError::io(format!("Failed to stat {}: {error}", path.display()))
+        })?;
+        checkpoints.push(RunCheckpoint {
+            path: path.display().to_string(),
+            file_name: entry.file_name().to_string_lossy().to_string(),
+            size_bytes: metadata.len(),
+        });
+    }
+    checkpoints.sort_by(|left, right| left.file_name.cmp(&right.file_name));
+    Ok(checkpoints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_final_metrics;
+    use crate::models::TrainingHistory;
+
+    #[test]
+    fn compute_final_metrics_reports_last_and_best_epochs() {
+        let history: TrainingHistory = serde_json::from_str(
+            r#"{"epochs": [
+                {"epoch": 1, "train_loss": 3.0, "validation_loss": 2.5},
+                {"epoch": 2, "train_loss": 2.0, "validation_loss": 2.1},
+                {"epoch": 3, "train_loss": 1.5, "validation_loss": 2.3}
+            ]}"#,
+        )
+        .expect("history should parse");
+        let metrics = compute_final_metrics(&history).expect("metrics should exist");
+        assert_eq!((metrics.best_epoch, metrics.final_train_loss), (2, 1.5));
+    }
+}
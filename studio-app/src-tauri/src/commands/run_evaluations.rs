@@ -0,0 +1,142 @@
+//! Benchmark evaluation results stored alongside each training run.
+//!
+//! Results live in `runs/<run_id>/eval.json` as `{"results": [...]}` in
+//! recording order, so perplexity or task-suite scores become queryable data
+//! rather than lines buried in CLI logs.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, run_dir, write_json_file,
+};
+use crate::models::{EvalResult, EvalResultInput, ForgeStudioError};
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const EVAL_FILE_NAME: &str = "eval.json";
+
+#[tauri::command]
+pub fn list_eval_results(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<EvalResult>, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    load_run_lifecycle(&resolved_data_root, &run_id)?;
+    load_eval_results(&resolved_data_root, &run_id)
+}
+
+#[tauri::command]
+pub fn record_eval_result(
+    data_root: String,
+    run_id: String,
+    payload: EvalResultInput,
+    settings: State<'_, SettingsStore>,
+) -> Result<EvalResult, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    load_run_lifecycle(&resolved_data_root, &run_id)?;
+    append_eval_result(&resolved_data_root, &run_id, payload)
+}
+
+/// Validates and appends one result, assigning the next sequential eval id.
+pub(crate) fn append_eval_result(
+    data_root: &Path,
+    run_id: &str,
+    payload: EvalResultInput,
+) -> Result<EvalResult, ForgeStudioError> {
+    validate_eval_input(&payload)?;
+    let mut results = load_eval_results(data_root, run_id)?;
+    let result = EvalResult {
+        eval_id: format!("eval-{:04}", results.len() + 1),
+        run_id: run_id.to_string(),
+        suite: payload.suite.trim().to_string(),
+        metrics: payload.metrics,
+        model_path: payload.model_path,
+        dataset_version_id: payload.dataset_version_id,
+        details: payload.details,
+        recorded_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+    };
+    results.push(result.clone());
+    let serialized = serde_json::to_value(&results).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize eval results for '{run_id}': {error}"
+        ))
+    })?;
+    write_json_file(
+        &eval_path(data_root, run_id),
+        &json!({"results": serialized}),
+    )?;
+    Ok(result)
+}
+
+/// Reads recorded results; a run without `eval.json` simply has none yet.
+pub(crate) fn load_eval_results(
+    data_root: &Path,
+    run_id: &str,
+) -> Result<Vec<EvalResult>, ForgeStudioError> {
+    let payload_path = eval_path(data_root, run_id);
+    if !payload_path.exists() {
+        return Ok(vec![]);
+    }
+    let payload = read_json_file(&payload_path)?;
+    let rows = payload
+        .get("results")
+        .cloned()
+        .unwrap_or(Value::Array(vec![]));
+    serde_json::from_value(rows).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse eval results {}: {error}",
+            payload_path.display()
+        ))
+    })
+}
+
+fn eval_path(data_root: &Path, run_id: &str) -> PathBuf {
+    run_dir(data_root, run_id).join(EVAL_FILE_NAME)
+}
+
+fn validate_eval_input(payload: &EvalResultInput) -> Result<(), ForgeStudioError> {
+    if payload.suite.trim().is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Eval result suite is empty. Name the benchmark suite, e.g. 'wikitext'.",
+        ));
+    }
+    if payload.metrics.is_empty() {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Eval result for suite '{}' has no metrics. Provide at least one named score.",
+            payload.suite
+        )));
+    }
+    if let Some((name, value)) = payload.metrics.iter().find(|(_, value)| !value.is_finite()) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Eval metric '{name}' has non-finite value {value}. Record only finite scores."
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_eval_input;
+    use crate::models::EvalResultInput;
+    use serde_json::json;
+
+    fn input(payload: serde_json::Value) -> EvalResultInput {
+        serde_json::from_value(payload).expect("eval input should parse")
+    }
+
+    #[test]
+    fn validate_eval_input_accepts_named_finite_metrics() {
+        let payload = input(json!({"suite": "wikitext", "metrics": {"perplexity": 41.2}}));
+        assert!(validate_eval_input(&payload).is_ok());
+    }
+
+    #[test]
+    fn validate_eval_input_rejects_blank_suite_and_empty_metrics() {
+        let blank_suite = input(json!({"suite": " ", "metrics": {"acc": 0.5}}));
+        let no_metrics = input(json!({"suite": "hellaswag", "metrics": {}}));
+        assert!(validate_eval_input(&blank_suite).is_err());
+        assert!(validate_eval_input(&no_metrics).is_err());
+    }
+}
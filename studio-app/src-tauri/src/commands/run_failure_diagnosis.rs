@@ -0,0 +1,238 @@
+//! Structured diagnosis of failed training runs.
+//!
+//! Evidence comes from the lifecycle error message and, when the run was
+//! launched from Studio, the persisted train task log. Known signatures are
+//! checked in order of specificity; the first match wins, since one root
+//! cause (e.g. OOM) often triggers later secondary errors in the same log.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::commands::train_task_log::load_train_task_log;
+use crate::models::{ForgeStudioError, RunFailureDiagnosis, RunLifecycleRecord, TrainTaskLog};
+use chrono::DateTime;
+use tauri::State;
+
+/// 137 is SIGKILL reported through a shell; the task store records -1 for any signal death.
+const SIGNAL_EXIT_CODES: [i32; 2] = [137, -1];
+const KILLED_BY_OS_CAUSE: &str = "killed_by_os";
+
+struct FailureSignature {
+    cause: &'static str,
+    summary: &'static str,
+    /// Lowercase fragments; any one matching a log line identifies the cause.
+    markers: &'static [&'static str],
+    remediations: &'static [&'static str],
+}
+
+const FAILURE_SIGNATURES: [FailureSignature; 4] = [
+    FailureSignature {
+        cause: "accelerator_out_of_memory",
+        summary: "The accelerator ran out of memory.",
+        markers: &[
+            "cuda out of memory",
+            "outofmemoryerror",
+            "cublas_status_alloc_failed",
+            "mps backend out of memory",
+        ],
+        remediations: &[
+            "Lower batch_size; halving it roughly halves activation memory.",
+            "Lower max_token_length, since attention memory grows with its square.",
+            "Train in bf16 or fp16 instead of fp32.",
+            "Shrink hidden_dim or num_layers, or add gradient checkpointing in a custom loop.",
+        ],
+    },
+    FailureSignature {
+        cause: "disk_full",
+        summary: "The output volume ran out of space while writing artifacts.",
+        markers: &["no space left on device", "errno 28", "disk quota exceeded"],
+        remediations: &[
+            "Free space on the output volume or pick an output dir on a larger disk.",
+            "Lower max_checkpoint_files or raise checkpoint_every_epochs.",
+        ],
+    },
+    FailureSignature {
+        cause: "nan_loss",
+        summary: "The loss became NaN or infinite and training diverged.",
+        markers: &[
+            "\"loss\": nan",
+            "loss=nan",
+            "nan loss",
+            "loss is nan",
+            "\"loss\": infinity",
+        ],
+        remediations: &[
+            "Lower learning_rate by 10x.",
+            "Prefer bf16 over fp16; fp16 overflows without loss scaling.",
+            "Add gradient clipping in a custom loop or hooks file.",
+            "Filter empty or degenerate records out of the dataset version.",
+        ],
+    },
+    FailureSignature {
+        cause: KILLED_BY_OS_CAUSE,
+        summary: "The operating system killed the process, usually the out-of-memory killer.",
+        markers: &["killed", "sigkill"],
+        remediations: &[
+            "Reduce host memory use: train on a smaller dataset version or lower batch_size.",
+            "Close other memory-heavy processes or raise the container memory limit.",
+        ],
+    },
+];
+
+#[tauri::command]
+pub fn diagnose_run_failure(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunFailureDiagnosis, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    if lifecycle.state == "completed" {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Run '{run_id}' completed successfully, so there is no failure to diagnose."
+        )));
+    }
+    // A log older than the run belongs to an earlier run in the same output dir.
+    let created_at = DateTime::parse_from_rfc3339(&lifecycle.created_at).ok();
+    let task_log = load_train_task_log(&lifecycle.output_dir)?.filter(|log| {
+        let finished_at = DateTime::parse_from_rfc3339(&log.finished_at).ok();
+        finished_at
+            .zip(created_at)
+            .is_none_or(|(finished, created)| finished >= created)
+    });
+    Ok(diagnose(run_id, &lifecycle, task_log.as_ref()))
+}
+
+fn diagnose(
+    run_id: String,
+    lifecycle: &RunLifecycleRecord,
+    task_log: Option<&TrainTaskLog>,
+) -> RunFailureDiagnosis {
+    let exit_code = task_log.and_then(|log| log.exit_code);
+    let evidence_text = evidence_text(lifecycle, task_log);
+    let matched = FAILURE_SIGNATURES.iter().find_map(|signature| {
+        let line = evidence_text.lines().rev().find(|line| {
+            let lowered = line.to_lowercase();
+            signature
+                .markers
+                .iter()
+                .any(|marker| lowered.contains(marker))
+        });
+        line.map(|line| (signature, Some(line.trim().to_string())))
+    });
+    let killed = exit_code.is_some_and(|code| SIGNAL_EXIT_CODES.contains(&code));
+    let matched = matched.or_else(|| {
+        let signature = FAILURE_SIGNATURES
+            .iter()
+            .find(|row| row.cause == KILLED_BY_OS_CAUSE)?;
+        killed.then_some((signature, None))
+    });
+    let (cause, summary, evidence, remediations) = match matched {
+        Some((signature, evidence)) => (
+            signature.cause.to_string(),
+            signature.summary.to_string(),
+            evidence,
+            signature
+                .remediations
+                .iter()
+                .map(|text| text.to_string())
+                .collect(),
+        ),
+        None => (
+            "unknown".to_string(),
+            lifecycle
+                .error_message
+                .clone()
+                .unwrap_or_else(|| "No known failure signature was found.".to_string()),
+            None,
+            vec!["Inspect the full task output in the command console.".to_string()],
+        ),
+    };
+    RunFailureDiagnosis {
+        run_id,
+        state: lifecycle.state.clone(),
+        cause,
+        summary,
+        evidence,
+        remediations,
+        exit_code,
+        task_log_available: task_log.is_some(),
+    }
+}
+
+fn evidence_text(lifecycle: &RunLifecycleRecord, task_log: Option<&TrainTaskLog>) -> String {
+    let mut sections: Vec<&str> = Vec::new();
+    sections.extend(
+        lifecycle
+            .events
+            .iter()
+            .filter_map(|event| event.message.as_deref()),
+    );
+    sections.extend(lifecycle.error_message.as_deref());
+    if let Some(log) = task_log {
+        sections.push(&log.stdout_tail);
+        sections.push(&log.stderr_tail);
+    }
+    sections.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diagnose;
+    use crate::models::{RunLifecycleRecord, TrainTaskLog};
+
+    fn failed_lifecycle(error_message: Option<&str>) -> RunLifecycleRecord {
+        serde_json::from_value(serde_json::json!({
+            "run_id": "run-1",
+            "dataset_name": "demo",
+            "dataset_version_id": "v1",
+            "output_dir": "outputs/demo",
+            "parent_model_path": null,
+            "config_hash": "abc",
+            "state": "failed",
+            "created_at": "2026-01-01T00:00:00+00:00",
+            "updated_at": "2026-01-01T01:00:00+00:00",
+            "artifact_contract_path": null,
+            "error_message": error_message,
+        }))
+        .expect("lifecycle should parse")
+    }
+
+    fn task_log(exit_code: i32, stderr_tail: &str) -> TrainTaskLog {
+        TrainTaskLog {
+            task_id: "forge-task-1".to_string(),
+            status: "failed".to_string(),
+            exit_code: Some(exit_code),
+            finished_at: "2026-01-01T01:00:00Z".to_string(),
+            stdout_tail: String::new(),
+            stderr_tail: stderr_tail.to_string(),
+        }
+    }
+
+    #[test]
+    fn diagnose_prefers_cuda_oom_over_later_errors() {
+        let lifecycle = failed_lifecycle(Some("CUDA out of memory. Tried to allocate 2.00 GiB"));
+        let log = task_log(1, "RuntimeError: CUDA error: an illegal memory access");
+        let diagnosis = diagnose("run-1".to_string(), &lifecycle, Some(&log));
+        assert_eq!(diagnosis.cause, "accelerator_out_of_memory");
+        assert!(diagnosis
+            .evidence
+            .expect("evidence")
+            .contains("Tried to allocate"));
+    }
+
+    #[test]
+    fn diagnose_reads_sigkill_exit_code_without_log_text() {
+        let lifecycle = failed_lifecycle(None);
+        let diagnosis = diagnose("run-1".to_string(), &lifecycle, Some(&task_log(137, "")));
+        assert_eq!(diagnosis.cause, "killed_by_os");
+        assert_eq!(diagnosis.evidence, None);
+    }
+
+    #[test]
+    fn diagnose_falls_back_to_error_message() {
+        let lifecycle = failed_lifecycle(Some("Hook 'on_epoch_end' failed: boom"));
+        let diagnosis = diagnose("run-1".to_string(), &lifecycle, None);
+        assert_eq!(diagnosis.cause, "unknown");
+        assert!(diagnosis.summary.contains("boom"));
+    }
+}
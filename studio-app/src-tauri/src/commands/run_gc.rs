@@ -0,0 +1,294 @@
+//! Policy-driven reclamation of disk used by old runs' heavyweight artifacts.
+//!
+//! Unlike `archive_run`, collection keeps the run in the index and lineage:
+//! lifecycle, history, training config, and annotations stay in place, so
+//! the runs list and loss curves remain browsable. `prune` deletes the model
+//! weights and checkpoints; `archive` moves them to `<destination>/<run_id>`,
+//! leaving the rest of the output dir, such as history and plots, in place.
+//! Tagged runs and runs with a registered model version are skipped, since a
+//! user already marked them as worth keeping. Each collected run gets an
+//! `artifact_gc.json` record so later passes skip it and the UI can explain
+//! where its model went.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::model_registry::load_registry;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::commands::run_annotations::load_run_meta;
+use crate::commands::run_details::resolve_run_artifacts;
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_output_dirs::{move_dir, remove_dir};
+use crate::commands::run_store_io::{
+    load_run_lifecycle, resolve_data_root_path, run_dir, write_json_file,
+};
+use crate::models::{
+    ForgeStudioError, RunArtifactGc, RunGcPolicy, RunGcReport, RunGcSkip, RunLifecycleRecord,
+};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const GC_MODES: [&str; 2] = ["archive", "prune"];
+const ARCHIVE_MODE: &str = "archive";
+const COMPLETED_STATE: &str = "completed";
+const GC_RECORD_FILE_NAME: &str = "artifact_gc.json";
+
+/// Policy inputs resolved once per pass.
+struct GcPass {
+    data_root: PathBuf,
+    mode: String,
+    archive_root: Option<PathBuf>,
+    cutoff: DateTime<Utc>,
+    registered_run_ids: HashSet<String>,
+    dry_run: bool,
+}
+
+enum GcOutcome {
+    Collected(RunArtifactGc),
+    Skipped(RunGcSkip),
+}
+
+#[tauri::command]
+pub fn gc_runs(
+    data_root: String,
+    policy: RunGcPolicy,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunGcReport, ForgeStudioError> {
+    let pass = resolve_pass(&data_root, policy, &settings.workspace_roots())?;
+    let mut report = RunGcReport {
+        mode: pass.mode.clone(),
+        dry_run: pass.dry_run,
+        reclaimed_bytes: 0,
+        collected: vec![],
+        skipped: vec![],
+    };
+    for run_id in read_run_index(&pass.data_root)? {
+        match collect_run(&pass, &run_id)? {
+            Some(GcOutcome::Collected(record)) => {
+                report.reclaimed_bytes += record.reclaimed_bytes;
+                report.collected.push(record);
+            }
+            Some(GcOutcome::Skipped(skip)) => report.skipped.push(skip),
+            None => {}
+        }
+    }
+    Ok(report)
+}
+
+fn resolve_pass(
+    data_root: &str,
+    policy: RunGcPolicy,
+    roots: &WorkspaceRoots,
+) -> Result<GcPass, ForgeStudioError> {
+    let mode = policy.mode.trim().to_lowercase();
+    if !GC_MODES.contains(&mode.as_str()) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Unknown gc mode '{mode}'. Use one of: {}.",
+            GC_MODES.join(", ")
+        )));
+    }
+    let archive_root = match policy.archive_destination.as_deref() {
+        Some(destination) => Some(confine_path(
+            Path::new(destination),
+            "Archive destination",
+            roots,
+        )?),
+        None if mode == ARCHIVE_MODE => {
+            return Err(ForgeStudioError::invalid_input(
+                "Archive mode needs an archive_destination. Set one or use prune mode.",
+            ))
+        }
+        None => None,
+    };
+    let data_root = resolve_data_root_path(data_root, roots)?;
+    let registered_run_ids = load_registry(&data_root)?
+        .models
+        .values()
+        .flat_map(|model| model.versions.iter().map(|version| version.run_id.clone()))
+        .collect();
+    Ok(GcPass {
+        data_root,
+        mode,
+        archive_root,
+        cutoff: Utc::now() - Duration::days(i64::from(policy.older_than_days)),
+        registered_run_ids,
+        dry_run: policy.dry_run,
+    })
+}
+
+/// Returns `None` for runs the policy does not cover at all (too new, not completed, done).
+fn collect_run(pass: &GcPass, run_id: &str) -> Result<Option<GcOutcome>, ForgeStudioError> {
+    let record_dir = run_dir(&pass.data_root, run_id);
+    if !record_dir.join("lifecycle.json").exists() || record_dir.join(GC_RECORD_FILE_NAME).exists()
+    {
+        return Ok(None);
+    }
+    let lifecycle = load_run_lifecycle(&pass.data_root, run_id)?;
+    if lifecycle.state != COMPLETED_STATE || !finished_before(&lifecycle.updated_at, pass.cutoff) {
+        return Ok(None);
+    }
+    let skip = |reason: &str| {
+        Ok(Some(GcOutcome::Skipped(RunGcSkip {
+            run_id: run_id.to_string(),
+            reason: reason.to_string(),
+        })))
+    };
+    if !load_run_meta(&pass.data_root, run_id)?.tags.is_empty() {
+        return skip("tagged");
+    }
+    if pass.registered_run_ids.contains(run_id) {
+        return skip("registered");
+    }
+    let targets = collection_targets(&lifecycle)?;
+    if targets.is_empty() {
+        return skip("no heavyweight artifacts left");
+    }
+    let archive_path = pass
+        .archive_root
+        .as_ref()
+        .filter(|_| pass.mode == ARCHIVE_MODE)
+        .map(|root| root.join(run_id));
+    let record = RunArtifactGc {
+        run_id: run_id.to_string(),
+        mode: pass.mode.clone(),
+        collected_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        reclaimed_bytes: targets.iter().map(|path| path_size(path)).sum(),
+        paths: targets
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect(),
+        archive_path: archive_path.as_ref().map(|path| path.display().to_string()),
+    };
+    if !pass.dry_run {
+        apply_collection(&targets, archive_path.as_deref())?;
+        let payload = serde_json::to_value(&record).map_err(|error| {
+            ForgeStudioError::internal(format!(
+                "Failed to serialize gc record for '{run_id}': {error}"
+            ))
+        })?;
+        write_json_file(&record_dir.join(GC_RECORD_FILE_NAME), &payload)?;
+    }
+    Ok(Some(GcOutcome::Collected(record)))
+}
+
+/// Model weights and checkpoints, the artifacts both modes collect.
+fn collection_targets(lifecycle: &RunLifecycleRecord) -> Result<Vec<PathBuf>, ForgeStudioError> {
+    let artifacts = resolve_run_artifacts(lifecycle)?;
+    let candidates = [
+        artifacts.model_path,
+        artifacts.checkpoint_dir,
+        artifacts.best_checkpoint_path,
+    ];
+    let existing: Vec<PathBuf> = candidates
+        .into_iter()
+        .flatten()
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+        .collect();
+    Ok(outermost_paths(existing))
+}
+
+fn apply_collection(
+    targets: &[PathBuf],
+    archive_path: Option<&Path>,
+) -> Result<(), ForgeStudioError> {
+    if let Some(archive_path) = archive_path {
+        if archive_path.exists() {
+            return Err(ForgeStudioError::conflict(format!(
+                "Archive target {} already exists. Choose another destination or remove it first.",
+                archive_path.display()
+            )));
+        }
+        fs::create_dir_all(archive_path).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to create {}: {error}",
+                archive_path.display()
+            ))
+        })?;
+        return targets.iter().try_for_each(|target| {
+            let file_name = target.file_name().unwrap_or(target.as_os_str());
+            move_dir(target, &archive_path.join(file_name))
+        });
+    }
+    for target in targets {
+        if target.is_dir() {
+            remove_dir(target)?;
+        } else {
+            fs::remove_file(target).map_err(|error| {
+                ForgeStudioError::io(format!("Failed to remove {}: {error}", target.display()))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Drops paths nested under another target, e.g. a best checkpoint inside `checkpoints/`.
+fn outermost_paths(mut paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    paths.sort();
+    paths.dedup();
+    let mut kept: Vec<PathBuf> = Vec::with_capacity(paths.len());
+    for path in paths {
+        if !kept.iter().any(|parent| path.starts_with(parent)) {
+            kept.push(path);
+        }
+    }
+    kept
+}
+
+/// Unparseable timestamps never qualify, so a malformed lifecycle is never collected.
+fn finished_before(updated_at: &str, cutoff: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(updated_at).is_ok_and(|finished| finished < cutoff)
+}
+
+/// Total bytes under `path`, without following symlinks out of it.
+fn path_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| path_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{finished_before, outermost_paths};
+    use chrono::{TimeZone, Utc};
+    use std::path::PathBuf;
+
+    #[test]
+    fn outermost_paths_drops_nested_checkpoints() {
+        let paths = vec![
+            PathBuf::from("/out/checkpoints/best.pt"),
+            PathBuf::from("/out/model.pt"),
+            PathBuf::from("/out/checkpoints"),
+        ];
+        assert_eq!(
+            outermost_paths(paths),
+            vec![
+                PathBuf::from("/out/checkpoints"),
+                PathBuf::from("/out/model.pt")
+            ]
+        );
+    }
+
+    #[test]
+    fn finished_before_accepts_python_isoformat_and_rejects_garbage() {
+        let cutoff = Utc
+            .with_ymd_and_hms(2024, 6, 1, 0, 0, 0)
+            .single()
+            .expect("cutoff should be a valid date");
+        assert!(finished_before("2024-05-01T10:00:00.123456+00:00", cutoff));
+        assert!(!finished_before("2024-07-01T10:00:00+00:00", cutoff));
+        assert!(!finished_before("yesterday", cutoff));
+    }
+}
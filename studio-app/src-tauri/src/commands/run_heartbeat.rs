@@ -0,0 +1,181 @@
+//! Stalled-run detection and manual reconciliation of stuck lifecycles.
+//!
+//! A training process killed by OOM, a reboot, or `kill -9` never writes its
+//! `failed` transition, so its lifecycle says `running` forever. The CLI has
+//! no separate heartbeat file; the run's heartbeat is the newest mtime among
+//! its lifecycle file and the output dir, where history and checkpoints land
+//! as training progresses. A running run whose heartbeat is older than the
+//! threshold is reported as stalled, and `mark_run_failed` lets the user
+//! close it out once they have confirmed the process is gone. Only running
+//! runs have their output dir walked; a finished run writes its lifecycle
+//! last, so that file's mtime is already its last activity.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::forge_commands::{flag_value, OUTPUT_DIR_FLAG};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, resolve_workspace_path, run_dir,
+    write_json_file,
+};
+use crate::models::{ForgeStudioError, RunLifecycleRecord};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tauri::State;
+
+/// Longer than one epoch of most runs, since epoch checkpoints are the slowest heartbeat.
+const DEFAULT_STALLED_AFTER_SECONDS: u64 = 30 * 60;
+const RUNNING_STATE: &str = "running";
+const FAILED_STATE: &str = "failed";
+const RECONCILABLE_STATES: [&str; 2] = ["queued", "running"];
+const DEFAULT_FAILURE_REASON: &str = "Marked failed from Studio after the run stopped reporting.";
+
+/// Stall verdict for one run, as shown in the runs list.
+pub(crate) struct RunActivity {
+    pub(crate) stalled: bool,
+    pub(crate) last_activity_at: Option<String>,
+}
+
+pub(crate) fn stall_threshold(stalled_after_seconds: Option<u64>) -> Duration {
+    Duration::from_secs(stalled_after_seconds.unwrap_or(DEFAULT_STALLED_AFTER_SECONDS))
+}
+
+pub(crate) fn run_activity(
+    data_root: &Path,
+    lifecycle: &RunLifecycleRecord,
+    threshold: Duration,
+) -> RunActivity {
+    let activity = last_activity(data_root, lifecycle);
+    RunActivity {
+        stalled: is_stalled(&lifecycle.state, activity, threshold),
+        last_activity_at: activity.map(format_system_time),
+    }
+}
+
+/// Newest modification time among the run's lifecycle file and, while it
+/// runs, its output dir.
+fn last_activity(data_root: &Path, lifecycle: &RunLifecycleRecord) -> Option<SystemTime> {
+    let lifecycle_path = run_dir(data_root, &lifecycle.run_id).join("lifecycle.json");
+    let lifecycle_modified = modified(&lifecycle_path);
+    if lifecycle.state != RUNNING_STATE {
+        return lifecycle_modified;
+    }
+    let output_dir = resolve_workspace_path(&lifecycle.output_dir);
+    [lifecycle_modified, newest_modified(&output_dir, 2)]
+        .into_iter()
+        .flatten()
+        .max()
+}
+
+/// Whether a `running` run has been silent for longer than `threshold`.
+fn is_stalled(state: &str, last_activity: Option<SystemTime>, threshold: Duration) -> bool {
+    state == RUNNING_STATE
+        && last_activity
+            .and_then(|activity| SystemTime::now().duration_since(activity).ok())
+            .is_some_and(|silence| silence > threshold)
+}
+
+fn format_system_time(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Records a `failed` transition for a run whose process is gone.
+#[tauri::command]
+pub fn mark_run_failed(
+    data_root: String,
+    run_id: String,
+    reason: Option<String>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunLifecycleRecord, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    if !RECONCILABLE_STATES.contains(&lifecycle.state.as_str()) {
+        return Err(ForgeStudioError::conflict(format!(
+            "Run '{run_id}' is already {}. Only queued or running runs can be marked failed.",
+            lifecycle.state
+        )));
+    }
+    let still_training = task_store
+        .running_command_args("train")
+        .iter()
+        .any(|args| flag_value(args, OUTPUT_DIR_FLAG) == Some(lifecycle.output_dir.as_str()));
+    if still_training {
+        return Err(ForgeStudioError::conflict(format!(
+            "Run '{run_id}' is still training in a Studio task. Cancel the task instead."
+        )));
+    }
+    let lifecycle_path = run_dir(&resolved_data_root, &run_id).join("lifecycle.json");
+    let mut payload = read_json_file(&lifecycle_path)?;
+    let message = reason.unwrap_or_else(|| DEFAULT_FAILURE_REASON.to_string());
+    apply_failed_transition(&mut payload, &message, &Utc::now().to_rfc3339());
+    write_json_file(&lifecycle_path, &payload)?;
+    load_run_lifecycle(&resolved_data_root, &run_id)
+}
+
+/// Edits the raw payload so fields this app does not model survive the rewrite.
+fn apply_failed_transition(payload: &mut Value, message: &str, timestamp: &str) {
+    let Some(object) = payload.as_object_mut() else {
+        return;
+    };
+    object.insert("state".to_string(), json!(FAILED_STATE));
+    object.insert("updated_at".to_string(), json!(timestamp));
+    object.insert("error_message".to_string(), json!(message));
+    let event = json!({"state": FAILED_STATE, "timestamp": timestamp, "message": message});
+    match object.get_mut("events").and_then(Value::as_array_mut) {
+        Some(events) => events.push(event),
+        None => {
+            object.insert("events".to_string(), json!([event]));
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Newest mtime under `path`, descending `depth` levels (checkpoints sit one level down).
+fn newest_modified(path: &Path, depth: u32) -> Option<SystemTime> {
+    let own = modified(path);
+    if depth == 0 || !path.is_dir() {
+        return own;
+    }
+    let children = fs::read_dir(path).ok()?.flatten();
+    children
+        .filter_map(|entry| newest_modified(&entry.path(), depth - 1))
+        .chain(own)
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_failed_transition, is_stalled};
+    use serde_json::json;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn is_stalled_only_flags_silent_running_runs() {
+        let hour_ago = SystemTime::now() - Duration::from_secs(3600);
+        let threshold = Duration::from_secs(600);
+        assert!(is_stalled("running", Some(hour_ago), threshold));
+        assert!(!is_stalled("completed", Some(hour_ago), threshold));
+        assert!(!is_stalled("running", Some(SystemTime::now()), threshold));
+        assert!(!is_stalled("running", None, threshold));
+    }
+
+    #[test]
+    fn apply_failed_transition_appends_event_and_keeps_unknown_fields() {
+        let mut payload = json!({
+            "state": "running",
+            "events": [{"state": "running", "timestamp": "t0", "message": null}],
+            "future_field": 1
+        });
+        apply_failed_transition(&mut payload, "process gone", "t1");
+        assert_eq!(payload["state"], "failed");
+        assert_eq!(payload["error_message"], "process gone");
+        assert_eq!(payload["events"][1]["timestamp"], "t1");
+        assert_eq!(payload["future_field"], 1);
+    }
+}
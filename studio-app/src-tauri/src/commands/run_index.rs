@@ -0,0 +1,162 @@
+//! Run index access with a directory-scan fallback, plus index rebuild.
+//!
+//! A CLI crash mid-write can leave `runs/index.json` missing or truncated;
+//! reading falls back to scanning `runs/*/lifecycle.json` so Studio keeps
+//! run visibility, and `rebuild_runs_index` persists the repaired index.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::read_child_dirs;
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, write_json_file,
+};
+use crate::models::{ForgeStudioError, RunIndexRebuildResult};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Run ids recovered from disk, plus directories whose lifecycle could not be read.
+struct RunDirScan {
+    run_ids: Vec<String>,
+    unreadable_run_dirs: Vec<String>,
+}
+
+/// Reads run ids in insertion order, scanning run dirs if the index is missing or corrupt.
+pub(crate) fn read_run_index(data_root: &Path) -> Result<Vec<String>, ForgeStudioError> {
+    match read_index_file(data_root) {
+        Ok(Some(run_ids)) => Ok(run_ids),
+        Ok(None) | Err(_) => Ok(scan_run_dirs(data_root)?.run_ids),
+    }
+}
+
+#[tauri::command]
+pub fn rebuild_runs_index(
+    data_root: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunIndexRebuildResult, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    // Held across the scan so a run the CLI registers meanwhile is not dropped.
+    with_file_lock(&index_path(&resolved_data_root), || {
+        rebuild_index_file(&resolved_data_root)
+    })
+}
+
+fn rebuild_index_file(data_root: &Path) -> Result<RunIndexRebuildResult, ForgeStudioError> {
+    let previous_ids: HashSet<String> = read_index_file(data_root)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    let scan = scan_run_dirs(data_root)?;
+    let scanned_ids: HashSet<&String> = scan.run_ids.iter().collect();
+    let recovered_run_ids = scan
+        .run_ids
+        .iter()
+        .filter(|run_id| !previous_ids.contains(*run_id))
+        .cloned()
+        .collect();
+    let mut dropped_run_ids: Vec<String> = previous_ids
+        .iter()
+        .filter(|run_id| !scanned_ids.contains(run_id))
+        .cloned()
+        .collect();
+    dropped_run_ids.sort();
+    let index_path = index_path(data_root);
+    write_json_file(&index_path, &json!({"runs": scan.run_ids}))?;
+    Ok(RunIndexRebuildResult {
+        index_path: index_path.display().to_string(),
+        run_count: scan.run_ids.len() as u64,
+        recovered_run_ids,
+        dropped_run_ids,
+        unreadable_run_dirs: scan.unreadable_run_dirs,
+    })
+}
+
+fn index_path(data_root: &Path) -> PathBuf {
+    data_root.join("runs").join("index.json")
+}
+
+/// Returns `Ok(None)` when the index file does not exist.
+fn read_index_file(data_root: &Path) -> Result<Option<Vec<String>>, ForgeStudioError> {
+    let index_path = index_path(data_root);
+    if !index_path.exists() {
+        return Ok(None);
+    }
+    let index_payload = read_json_file(&index_path)?;
+    let run_ids = index_payload
+        .get("runs")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ForgeStudioError::parse("Run index is missing runs array"))?;
+    run_ids
+        .iter()
+        .map(|value| {
+            value
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| ForgeStudioError::parse("Run id entry must be a string"))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+/// Orders recovered runs by creation time to match the CLI's append order.
+fn scan_run_dirs(data_root: &Path) -> Result<RunDirScan, ForgeStudioError> {
+    let runs_root = data_root.join("runs");
+    if !runs_root.is_dir() {
+        return Ok(RunDirScan {
+            run_ids: vec![],
+            unreadable_run_dirs: vec![],
+        });
+    }
+    let mut dated_runs = Vec::new();
+    let mut unreadable_run_dirs = Vec::new();
+    for dir_name in read_child_dirs(&runs_root)? {
+        if !runs_root.join(&dir_name).join("lifecycle.json").exists() {
+            continue;
+        }
+        match load_run_lifecycle(data_root, &dir_name) {
+            Ok(lifecycle) => dated_runs.push((lifecycle.created_at, lifecycle.run_id)),
+            Err(_) => unreadable_run_dirs.push(dir_name),
+        }
+    }
+    dated_runs.sort();
+    unreadable_run_dirs.sort();
+    Ok(RunDirScan {
+        run_ids: dated_runs.into_iter().map(|(_, run_id)| run_id).collect(),
+        unreadable_run_dirs,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_run_index;
+    use std::fs;
+    use std::path::Path;
+
+    fn write_lifecycle(data_root: &Path, run_id: &str, created_at: &str) {
+        let run_dir = data_root.join("runs").join(run_id);
+        fs::create_dir_all(&run_dir).expect("run dir should be created");
+        let payload = serde_json::json!({
+            "run_id": run_id, "dataset_name": "demo", "dataset_version_id": "demo-v1",
+            "output_dir": "/tmp/out", "parent_model_path": null, "config_hash": "abc",
+            "state": "completed", "created_at": created_at, "updated_at": created_at,
+            "artifact_contract_path": null, "error_message": null
+        });
+        fs::write(run_dir.join("lifecycle.json"), payload.to_string()).expect("lifecycle write");
+    }
+
+    #[test]
+    fn read_run_index_scans_run_dirs_when_index_is_corrupt() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-run-index-{}", std::process::id()));
+        write_lifecycle(&data_root, "run-b", "2026-03-02T00:00:00+00:00");
+        write_lifecycle(&data_root, "run-a", "2026-03-01T00:00:00+00:00");
+        let truncated_index = "{\"runs\": [";
+        fs::write(data_root.join("runs").join("index.json"), truncated_index).expect("index write");
+        let run_ids = read_run_index(&data_root).expect("fallback scan should succeed");
+        fs::remove_dir_all(&data_root).expect("cleanup should succeed");
+        assert_eq!(run_ids, vec!["run-a".to_string(), "run-b".to_string()]);
+    }
+}
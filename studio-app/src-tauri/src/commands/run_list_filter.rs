@@ -0,0 +1,110 @@
+//! Filter matching for the training runs list.
+//!
+//! Kept separate from the listing loop so the match rules are testable
+//! without fixture run directories.
+
+use crate::models::{RunLifecycleRecord, RunUserMetadata, TrainingRunQuery};
+
+pub(crate) fn matches_run_query(
+    query: &TrainingRunQuery,
+    lifecycle: &RunLifecycleRecord,
+    meta: &RunUserMetadata,
+) -> bool {
+    exact_match(query.dataset_name.as_deref(), &lifecycle.dataset_name)
+        && exact_match(query.state.as_deref(), &lifecycle.state)
+        && created_within(query, &lifecycle.created_at)
+        && query
+            .tag
+            .as_deref()
+            .is_none_or(|tag| meta.tags.iter().any(|candidate| candidate == tag))
+        && query
+            .search
+            .as_deref()
+            .is_none_or(|needle| text_matches(needle, lifecycle, meta))
+}
+
+fn exact_match(expected: Option<&str>, actual: &str) -> bool {
+    expected.is_none_or(|value| value == actual)
+}
+
+/// Bounds are inclusive date or datetime prefixes, e.g. `2026-01-01`.
+fn created_within(query: &TrainingRunQuery, created_at: &str) -> bool {
+    let after_start = query
+        .created_from
+        .as_deref()
+        .is_none_or(|start| created_at >= start);
+    let before_end = query.created_to.as_deref().is_none_or(|end| {
+        let comparable_length = end.len().min(created_at.len());
+        &created_at[..comparable_length] <= end
+    });
+    after_start && before_end
+}
+
+fn text_matches(needle: &str, lifecycle: &RunLifecycleRecord, meta: &RunUserMetadata) -> bool {
+    let needle = needle.trim().to_lowercase();
+    if needle.is_empty() {
+        return true;
+    }
+    lifecycle.run_id.to_lowercase().contains(&needle)
+        || meta.notes.to_lowercase().contains(&needle)
+        || meta
+            .tags
+            .iter()
+            .any(|tag| tag.to_lowercase().contains(&needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_run_query;
+    use crate::models::{RunLifecycleRecord, RunUserMetadata, TrainingRunQuery};
+    use serde_json::json;
+
+    fn lifecycle() -> RunLifecycleRecord {
+        serde_json::from_value(json!({
+            "run_id": "run-20260301T101500000000Z-ab12cd34", "dataset_name": "demo",
+            "dataset_version_id": "demo-v1", "output_dir": "/tmp/out",
+            "parent_model_path": null, "config_hash": "abc", "state": "completed",
+            "created_at": "2026-03-01T10:15:00+00:00", "updated_at": "2026-03-01T11:00:00+00:00",
+            "artifact_contract_path": null, "error_message": null
+        }))
+        .expect("lifecycle should parse")
+    }
+
+    fn meta() -> RunUserMetadata {
+        RunUserMetadata {
+            tags: vec!["baseline".to_string()],
+            notes: "Raised LR to 3e-4".to_string(),
+            ..RunUserMetadata::default()
+        }
+    }
+
+    #[test]
+    fn matches_run_query_applies_date_prefix_bounds() {
+        let inside = TrainingRunQuery {
+            created_from: Some("2026-03-01".to_string()),
+            created_to: Some("2026-03-01".to_string()),
+            ..TrainingRunQuery::default()
+        };
+        let after = TrainingRunQuery {
+            created_from: Some("2026-03-02".to_string()),
+            ..TrainingRunQuery::default()
+        };
+        assert!(matches_run_query(&inside, &lifecycle(), &meta()));
+        assert!(!matches_run_query(&after, &lifecycle(), &meta()));
+    }
+
+    #[test]
+    fn matches_run_query_searches_notes_and_filters_tags() {
+        let by_notes = TrainingRunQuery {
+            search: Some("raised lr".to_string()),
+            tag: Some("baseline".to_string()),
+            ..TrainingRunQuery::default()
+        };
+        let wrong_tag = TrainingRunQuery {
+            tag: Some("lr sweep".to_string()),
+            ..TrainingRunQuery::default()
+        };
+        assert!(matches_run_query(&by_notes, &lifecycle(), &meta()));
+        assert!(!matches_run_query(&wrong_tag, &lifecycle(), &meta()));
+    }
+}
@@ -0,0 +1,111 @@
+//! Removing and moving run output directories.
+//!
+//! A run's `output_dir` comes from its lifecycle file, which any process can
+//! write, so it is confined to the registered workspaces and then accepted
+//! for removal only when it lies strictly inside an `outputs` directory,
+//! either the workspace's or the data root's. Anything else, including the
+//! `outputs` directory itself, is refused rather than deleted.
+
+use crate::commands::data_root_init::OUTPUTS_DIR_NAME;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::commands::run_store_io::workspace_root_dir;
+use crate::models::ForgeStudioError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The run output dir when it exists and may be removed; `None` when it is gone.
+pub(crate) fn removable_output_dir(
+    data_root: &Path,
+    raw_output_dir: &str,
+    roots: &WorkspaceRoots,
+) -> Result<Option<PathBuf>, ForgeStudioError> {
+    let output_dir = confine_path(Path::new(raw_output_dir), "Run output dir", roots)?;
+    if !output_dir.is_dir() {
+        return Ok(None);
+    }
+    let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let outputs_roots = [
+        canonical(&workspace_root_dir().join(OUTPUTS_DIR_NAME)),
+        canonical(&data_root.join(OUTPUTS_DIR_NAME)),
+    ];
+    let inside_outputs = outputs_roots
+        .iter()
+        .any(|root| output_dir.starts_with(root) && output_dir != *root);
+    if !inside_outputs {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Run output dir {} is not inside an {OUTPUTS_DIR_NAME} directory; refusing to remove it. Remove it by hand, or move run outputs under {}.",
+            output_dir.display(),
+            outputs_roots[0].display()
+        )));
+    }
+    Ok(Some(output_dir))
+}
+
+pub(crate) fn remove_dir(path: &Path) -> Result<(), ForgeStudioError> {
+    if !path.exists() {
+        return Ok(());
+    }
+    fs::remove_dir_all(path).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to remove {}: {error}", path.display()))
+    })
+}
+
+pub(crate) fn move_dir(source: &Path, target: &Path) -> Result<(), ForgeStudioError> {
+    if !source.exists() {
+        return Ok(());
+    }
+    fs::rename(source, target).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to move {} to {}: {error}. Archive destinations must be on the same filesystem as the source.",
+            source.display(),
+            target.display()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::removable_output_dir;
+    use crate::commands::path_safety::WorkspaceRoots;
+    use crate::models::ForgeStudioError;
+    use std::fs;
+
+    #[test]
+    fn removable_output_dir_accepts_only_dirs_inside_outputs() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-run-output-dirs-{}", std::process::id()));
+        fs::create_dir_all(&data_root).expect("temp dir should be created");
+        let data_root = data_root
+            .canonicalize()
+            .expect("temp dir should canonicalize");
+        let data_root_text = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&data_root_text));
+        let run_output = data_root.join("outputs").join("run-a");
+        let stray_output = data_root.join("datasets");
+        fs::create_dir_all(&run_output).expect("run output should be created");
+        fs::create_dir_all(&stray_output).expect("stray dir should be created");
+
+        let accepted = removable_output_dir(&data_root, &run_output.display().to_string(), &roots)
+            .expect("output under outputs/ should be accepted");
+        assert_eq!(accepted, Some(run_output.clone()));
+        let outside_workspaces = "/forge-outside-workspaces/outputs/run-a";
+        let error = removable_output_dir(&data_root, outside_workspaces, &roots)
+            .expect_err("dir outside the workspaces should be refused");
+        assert!(matches!(error, ForgeStudioError::InvalidInput(_)));
+        for refused in [&stray_output, &data_root.join("outputs"), &data_root] {
+            let error = removable_output_dir(&data_root, &refused.display().to_string(), &roots)
+                .expect_err("dir outside outputs/ should be refused");
+            assert!(matches!(error, ForgeStudioError::InvalidInput(_)));
+        }
+        let missing = data_root
+            .join("outputs")
+            .join("run-gone")
+            .display()
+            .to_string();
+        assert_eq!(
+            removable_output_dir(&data_root, &missing, &roots).expect("missing dir is fine"),
+            None
+        );
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
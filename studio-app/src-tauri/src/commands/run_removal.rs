@@ -0,0 +1,280 @@
+//! Delete and archive commands for training runs.
+//!
+//! Both commands remove the run from `runs/index.json` and the lineage graph
+//! together so the runs list and lineage view never disagree. Runs whose
+//! model initialized another run are refused: removing them would leave the
+//! child's `initialized_from` edge pointing at nothing, and so are queued or
+//! running runs, whose process would keep writing into the removed dirs.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::file_locks::with_file_lock;
+use crate::commands::lineage_store_io::{
+    edge_field, model_node_id, read_lineage_graph, run_node_id, update_lineage_graph,
+    LineageGraphFile,
+};
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::commands::run_output_dirs::{move_dir, removable_output_dir, remove_dir};
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, run_dir, write_json_file,
+};
+use crate::models::{ForgeStudioError, RunRemovalResult};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Lifecycle states of a run whose process may still be writing its outputs.
+const ACTIVE_STATES: [&str; 2] = ["queued", "running"];
+
+/// Lineage entries removed from the graph, kept so archives can restore provenance.
+struct DetachedLineage {
+    run_entry: Option<Value>,
+    edges: Vec<Value>,
+}
+
+#[tauri::command]
+pub fn delete_run(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunRemovalResult, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let resolved_data_root = resolve_data_root_path(&data_root, &roots)?;
+    delete_run_files(&resolved_data_root, run_id, &roots)
+}
+
+fn delete_run_files(
+    data_root: &Path,
+    run_id: String,
+    roots: &WorkspaceRoots,
+) -> Result<RunRemovalResult, ForgeStudioError> {
+    let output_dir = checked_output_dir(data_root, &run_id, roots)?;
+    if let Some(output_dir) = &output_dir {
+        remove_dir(output_dir)?;
+    }
+    remove_dir(&run_dir(data_root, &run_id))?;
+    let detached = detach_from_lineage(data_root, &run_id)?;
+    remove_from_run_index(data_root, &run_id)?;
+    Ok(RunRemovalResult {
+        run_id,
+        action: "deleted".to_string(),
+        output_dir_removed: output_dir.is_some(),
+        removed_lineage_edges: detached.edges.len() as u64,
+        archive_path: None,
+    })
+}
+
+/// Moves the run metadata and outputs to `<destination>/<run_id>/{run,output}`.
+#[tauri::command]
+pub fn archive_run(
+    data_root: String,
+    run_id: String,
+    destination: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunRemovalResult, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let resolved_data_root = resolve_data_root_path(&data_root, &roots)?;
+    let output_dir = checked_output_dir(&resolved_data_root, &run_id, &roots)?;
+    let archive_root =
+        confine_path(Path::new(&destination), "Archive destination", &roots)?.join(&run_id);
+    if archive_root.exists() {
+        return Err(ForgeStudioError::conflict(format!(
+            "Archive target {} already exists. Choose another destination or remove it first.",
+            archive_root.display()
+        )));
+    }
+    fs::create_dir_all(&archive_root).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to create {}: {error}",
+            archive_root.display()
+        ))
+    })?;
+    if let Some(output_dir) = &output_dir {
+        move_dir(output_dir, &archive_root.join("output"))?;
+    }
+    move_dir(
+        &run_dir(&resolved_data_root, &run_id),
+        &archive_root.join("run"),
+    )?;
+    let detached = detach_from_lineage(&resolved_data_root, &run_id)?;
+    let lineage_payload = json!({"run": detached.run_entry, "edges": detached.edges});
+    write_json_file(&archive_root.join("lineage.json"), &lineage_payload)?;
+    remove_from_run_index(&resolved_data_root, &run_id)?;
+    Ok(RunRemovalResult {
+        run_id,
+        action: "archived".to_string(),
+        output_dir_removed: output_dir.is_some(),
+        removed_lineage_edges: detached.edges.len() as u64,
+        archive_path: Some(archive_root.display().to_string()),
+    })
+}
+
+/// Refuses active runs and parents of other runs, then resolves the output dir.
+fn checked_output_dir(
+    data_root: &Path,
+    run_id: &str,
+    roots: &WorkspaceRoots,
+) -> Result<Option<PathBuf>, ForgeStudioError> {
+    let lifecycle = load_run_lifecycle(data_root, run_id)?;
+    if ACTIVE_STATES.contains(&lifecycle.state.as_str()) {
+        return Err(ForgeStudioError::conflict(format!(
+            "Run '{run_id}' is {}. Cancel it or wait for it to finish, then remove it.",
+            lifecycle.state
+        )));
+    }
+    ensure_no_child_runs(&read_lineage_graph(data_root)?, run_id)?;
+    removable_output_dir(data_root, &lifecycle.output_dir, roots)
+}
+
+fn ensure_no_child_runs(graph: &LineageGraphFile, run_id: &str) -> Result<(), ForgeStudioError> {
+    let child_run_ids = child_run_ids(graph, run_id);
+    if child_run_ids.is_empty() {
+        return Ok(());
+    }
+    Err(ForgeStudioError::conflict(format!(
+        "Run '{run_id}' produced the parent model of run(s) {}. Remove those runs first.",
+        child_run_ids.join(", ")
+    )))
+}
+
+/// Returns ids of runs initialized from the model this run produced.
+fn child_run_ids(graph: &LineageGraphFile, run_id: &str) -> Vec<String> {
+    let Some(model_path) = graph
+        .runs
+        .get(run_id)
+        .and_then(|entry| entry.get("model_path"))
+        .and_then(Value::as_str)
+    else {
+        return vec![];
+    };
+    let model_node = model_node_id(model_path);
+    graph
+        .edges
+        .iter()
+        .filter(|edge| edge_field(edge, "type") == Some("initialized_from"))
+        .filter(|edge| edge_field(edge, "from") == Some(model_node.as_str()))
+        .filter_map(|edge| edge_field(edge, "to")?.strip_prefix("run:"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn detach_from_lineage(
+    data_root: &Path,
+    run_id: &str,
+) -> Result<DetachedLineage, ForgeStudioError> {
+    update_lineage_graph(data_root, |graph| Ok((detach_run(graph, run_id), true)))
+}
+
+fn detach_run(graph: &mut LineageGraphFile, run_id: &str) -> DetachedLineage {
+    let run_node = run_node_id(run_id);
+    let touches_run = |edge: &Value| {
+        edge_field(edge, "from") == Some(run_node.as_str())
+            || edge_field(edge, "to") == Some(run_node.as_str())
+    };
+    let (removed, kept): (Vec<Value>, Vec<Value>) =
+        graph.edges.drain(..).partition(|edge| touches_run(edge));
+    graph.edges = kept;
+    DetachedLineage {
+        run_entry: graph.runs.remove(run_id),
+        edges: removed,
+    }
+}
+
+fn remove_from_run_index(data_root: &Path, run_id: &str) -> Result<(), ForgeStudioError> {
+    let index_path = data_root.join("runs").join("index.json");
+    if !index_path.exists() {
+        return Ok(());
+    }
+    with_file_lock(&index_path, || {
+        let mut payload = read_json_file(&index_path)?;
+        let run_ids = payload
+            .get_mut("runs")
+            .and_then(Value::as_array_mut)
+            .ok_or_else(|| {
+                ForgeStudioError::parse(format!(
+                    "Run index {} is missing runs array",
+                    index_path.display()
+                ))
+            })?;
+        run_ids.retain(|value| value.as_str() != Some(run_id));
+        write_json_file(&index_path, &payload)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{child_run_ids, delete_run_files, detach_run};
+    use crate::commands::lineage_store_io::LineageGraphFile;
+    use crate::commands::path_safety::WorkspaceRoots;
+    use crate::commands::run_store_io::{run_dir, write_json_file};
+    use crate::models::ForgeStudioError;
+    use serde_json::json;
+    use std::fs;
+
+    fn sample_graph() -> LineageGraphFile {
+        let runs = json!({
+            "run-a": {"model_path": "out/a/model.pt"},
+            "run-b": {"model_path": "out/b/model.pt"}
+        });
+        LineageGraphFile {
+            runs: runs.as_object().cloned().unwrap_or_default(),
+            edges: vec![
+                json!({"from": "dataset:demo:v1", "to": "run:run-a", "type": "trained_on"}),
+                json!({"from": "run:run-a", "to": "model:out/a/model.pt", "type": "produced"}),
+                json!({"from": "model:out/a/model.pt", "to": "run:run-b", "type": "initialized_from"}),
+            ],
+        }
+    }
+
+    #[test]
+    fn child_run_ids_follows_initialized_from_edges() {
+        let graph = sample_graph();
+        assert_eq!(child_run_ids(&graph, "run-a"), vec!["run-b".to_string()]);
+        assert!(child_run_ids(&graph, "run-b").is_empty());
+    }
+
+    #[test]
+    fn detach_run_removes_entry_and_touching_edges() {
+        let mut graph = sample_graph();
+        let detached = detach_run(&mut graph, "run-b");
+        assert!(detached.run_entry.is_some());
+        assert_eq!(detached.edges.len(), 1);
+        assert_eq!(graph.edges.len(), 2);
+        assert!(!graph.runs.contains_key("run-b"));
+    }
+
+    #[test]
+    fn delete_refuses_running_runs_and_keeps_their_files() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-run-removal-{}", std::process::id()));
+        let output_dir = data_root.join("outputs").join("run-a");
+        fs::create_dir_all(&output_dir).expect("output dir should be created");
+        let data_root = data_root
+            .canonicalize()
+            .expect("temp dir should canonicalize");
+        let data_root_text = data_root.display().to_string();
+        let roots = WorkspaceRoots::new(std::slice::from_ref(&data_root_text));
+        let lifecycle = |state: &str| {
+            json!({
+                "run_id": "run-a", "dataset_name": "demo", "dataset_version_id": "v1",
+                "output_dir": output_dir.display().to_string(), "config_hash": "abc",
+                "state": state, "created_at": "t0", "updated_at": "t1",
+            })
+        };
+        let lifecycle_path = run_dir(&data_root, "run-a").join("lifecycle.json");
+        fs::create_dir_all(run_dir(&data_root, "run-a")).expect("run dir should be created");
+        write_json_file(&lifecycle_path, &lifecycle("running")).expect("lifecycle written");
+
+        let error = delete_run_files(&data_root, "run-a".to_string(), &roots)
+            .expect_err("running run should be refused");
+        assert!(matches!(error, ForgeStudioError::Conflict(_)));
+        assert!(output_dir.is_dir() && lifecycle_path.is_file());
+
+        write_json_file(&lifecycle_path, &lifecycle("completed")).expect("lifecycle written");
+        let removed = delete_run_files(&data_root, "run-a".to_string(), &roots)
+            .expect("completed run should be deleted");
+        assert!(removed.output_dir_removed);
+        assert!(!output_dir.exists() && !run_dir(&data_root, "run-a").exists());
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
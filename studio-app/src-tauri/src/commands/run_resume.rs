@@ -0,0 +1,191 @@
+//! Resume an interrupted training run from one of its checkpoints.
+//!
+//! The resumed run replays the source run's saved training config so the
+//! checkpoint loads into an identical architecture, and writes into the same
+//! output dir so new checkpoints continue the existing sequence. The CLI
+//! records the checkpoint, and the run that wrote it, in the new run's
+//! lifecycle record.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::forge_commands::{validate_args, OUTPUT_DIR_FLAG, RESUME_CHECKPOINT_FLAG};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_details::{list_checkpoints, read_optional_json, resolve_run_artifacts};
+use crate::commands::run_store_io::{
+    load_run_lifecycle, resolve_data_root_path, resolve_workspace_path,
+};
+use crate::commands::train_task_log::spawn_train_log_recorder;
+use crate::models::{ForgeStudioError, RunArtifactPaths, RunLifecycleRecord, RunResumeStart};
+use serde_json::Value;
+use tauri::State;
+
+/// Config keys whose CLI flag differs from the dashed key name.
+const RENAMED_CONFIG_FLAGS: [(&str, &str); 3] = [
+    ("architecture_path", "--architecture-file"),
+    ("custom_loop_path", "--custom-loop-file"),
+    ("hooks_path", "--hooks-file"),
+];
+
+/// Config keys set by dedicated `train` args or by resume. Replays skip them,
+/// and clone overrides and sweeps may not set them through a config.
+pub(crate) const RESERVED_CONFIG_KEYS: [&str; 4] = [
+    "dataset_name",
+    "version_id",
+    "output_dir",
+    "resume_checkpoint_path",
+];
+
+#[tauri::command]
+pub fn resume_training_run(
+    data_root: String,
+    run_id: String,
+    checkpoint_path: Option<String>,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunResumeStart, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let artifacts = resolve_run_artifacts(&lifecycle)?;
+    let checkpoint = resolve_resume_checkpoint(checkpoint_path, &artifacts, &run_id)?;
+    let training_config = read_optional_json(artifacts.training_config_path.as_deref())?;
+    let args = build_resume_args(&lifecycle, training_config.as_ref(), &checkpoint);
+    validate_args(&args)?;
+    let task = task_store.start_task(data_root, args.clone());
+    spawn_train_log_recorder(task_store.inner().clone(), task.task_id.clone(), &args);
+    Ok(RunResumeStart {
+        task_id: task.task_id,
+        estimated_total_seconds: task.estimated_total_seconds,
+        source_run_id: run_id,
+        checkpoint_path: checkpoint,
+    })
+}
+
+/// Uses the requested checkpoint, else the newest epoch checkpoint of the run.
+fn resolve_resume_checkpoint(
+    requested: Option<String>,
+    artifacts: &RunArtifactPaths,
+    run_id: &str,
+) -> Result<String, ForgeStudioError> {
+    if let Some(requested) = requested {
+        let requested_path = resolve_workspace_path(&requested);
+        if !requested_path.is_file() {
+            return Err(ForgeStudioError::not_found(format!(
+                "Checkpoint {} does not exist. Pick one listed in the run details.",
+                requested_path.display()
+            )));
+        }
+        return Ok(requested_path.display().to_string());
+    }
+    list_checkpoints(artifacts.checkpoint_dir.as_deref())?
+        .into_iter()
+        .filter(|checkpoint| checkpoint.file_name.starts_with("epoch-"))
+        .max_by(|left, right| left.file_name.cmp(&right.file_name))
+        .map(|checkpoint| checkpoint.path)
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Run '{run_id}' has no epoch checkpoints to resume from. Pass a checkpoint path explicitly."
+            ))
+        })
+}
+
+fn build_resume_args(
+    lifecycle: &RunLifecycleRecord,
+    training_config: Option<&Value>,
+    checkpoint_path: &str,
+) -> Vec<String> {
+    let mut args = build_train_args(
+        &lifecycle.dataset_name,
+        &lifecycle.dataset_version_id,
+        &lifecycle.output_dir,
+        training_config,
+    );
+    args.push(RESUME_CHECKPOINT_FLAG.to_string());
+    args.push(checkpoint_path.to_string());
+    args
+}
+
+/// `train` args replaying a saved training config against the given dataset version.
+pub(crate) fn build_train_args(
+    dataset_name: &str,
+    version_id: &str,
+    output_dir: &str,
+    training_config: Option<&Value>,
+) -> Vec<String> {
+    let mut args: Vec<String> = vec![
+        "train".to_string(),
+        "--dataset".to_string(),
+        dataset_name.to_string(),
+        "--version-id".to_string(),
+        version_id.to_string(),
+        OUTPUT_DIR_FLAG.to_string(),
+        output_dir.to_string(),
+    ];
+    let config = training_config.and_then(Value::as_object);
+    for (key, value) in config.into_iter().flatten() {
+        if RESERVED_CONFIG_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        let rendered = match value {
+            Value::String(text) => text.clone(),
+            Value::Number(number) => number.to_string(),
+            // Boolean train options take `--key` / `--no-key` flags.
+            Value::Bool(true) => {
+                args.push(config_flag(key));
+                continue;
+            }
+            Value::Bool(false) => {
+                args.push(config_flag(key).replacen("--", "--no-", 1));
+                continue;
+            }
+            _ => continue,
+        };
+        args.push(config_flag(key));
+        args.push(rendered);
+    }
+    args
+}
+
+fn config_flag(key: &str) -> String {
+    RENAMED_CONFIG_FLAGS
+        .iter()
+        .find(|(config_key, _)| *config_key == key)
+        .map(|(_, flag)| flag.to_string())
+        .unwrap_or_else(|| format!("--{}", key.replace('_', "-")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_resume_args, build_train_args};
+    use crate::models::RunLifecycleRecord;
+    use serde_json::json;
+
+    #[test]
+    fn build_resume_args_replays_config_and_appends_checkpoint() {
+        let lifecycle: RunLifecycleRecord = serde_json::from_value(json!({
+            "run_id": "run-1", "dataset_name": "demo", "dataset_version_id": "demo-v1",
+            "output_dir": "/tmp/out", "parent_model_path": null, "config_hash": "abc",
+            "state": "failed", "created_at": "t0", "updated_at": "t1",
+            "artifact_contract_path": null, "error_message": "interrupted"
+        }))
+        .expect("lifecycle should parse");
+        let config = json!({
+            "dataset_name": "demo", "epochs": 5, "architecture_path": "arch.py",
+            "hooks_path": null, "save_best_checkpoint": false, "learning_rate": 0.001
+        });
+        let checkpoint = "/tmp/out/checkpoints/epoch-0002.pt";
+        let joined = build_resume_args(&lifecycle, Some(&config), checkpoint).join(" ");
+        assert!(joined.starts_with("train --dataset demo --version-id demo-v1"));
+        assert!(joined.contains("--architecture-file arch.py"));
+        assert!(joined.contains("--epochs 5"));
+        assert!(!joined.contains("--hooks-file"));
+        assert!(joined.contains("--no-save-best-checkpoint"));
+        assert!(joined.ends_with("--resume-checkpoint-path /tmp/out/checkpoints/epoch-0002.pt"));
+    }
+
+    #[test]
+    fn build_train_args_renders_booleans_as_paired_flags() {
+        let config = json!({"save_best_checkpoint": true, "epochs": 2});
+        let args = build_train_args("demo", "demo-v1", "/tmp/out", Some(&config));
+        assert!(args.contains(&"--save-best-checkpoint".to_string()));
+        assert!(!args.contains(&"true".to_string()));
+    }
+}
@@ -0,0 +1,134 @@
+//! Run-spec structure checks, mirroring forge's `core/run_spec.py`.
+//!
+//! Takes the YAML read by `run_spec_yaml` and applies the same root, defaults,
+//! and step rules `forge run-spec` does, so a file Studio imports is one the
+//! CLI would also accept. Step args are flattened to config strings because
+//! canvas node configs hold nothing else.
+
+use crate::commands::canvas_run_spec::RUN_SPEC_COMMANDS;
+use crate::commands::run_spec_yaml::parse_yaml;
+use crate::models::ForgeStudioError;
+use serde_json::{Map, Value};
+
+const RUN_SPEC_VERSION: i64 = 1;
+const RUN_SPEC_ROOT_KEYS: [&str; 3] = ["version", "defaults", "steps"];
+const RUN_SPEC_DEFAULT_KEYS: [&str; 2] = ["data_root", "dataset"];
+
+pub(crate) struct RunSpecFile {
+    pub(crate) default_data_root: Option<String>,
+    pub(crate) default_dataset: Option<String>,
+    pub(crate) steps: Vec<RunSpecStep>,
+}
+
+pub(crate) struct RunSpecStep {
+    pub(crate) command: String,
+    /// Config strings sorted by key; `null` values are left out.
+    pub(crate) args: Vec<(String, String)>,
+}
+
+pub(crate) fn read_run_spec(text: &str) -> Result<RunSpecFile, ForgeStudioError> {
+    let payload = parse_yaml(text)?;
+    let root = payload.as_object().ok_or_else(|| {
+        ForgeStudioError::parse("the file is not a run spec. Define 'version' and 'steps'.")
+    })?;
+    reject_unknown_keys(root, &RUN_SPEC_ROOT_KEYS, "top-level")?;
+    if root.get("version").and_then(Value::as_i64) != Some(RUN_SPEC_VERSION) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "unsupported run spec version. Set version: {RUN_SPEC_VERSION}."
+        )));
+    }
+    let empty = Map::new();
+    let defaults = match root.get("defaults") {
+        None | Some(Value::Null) => &empty,
+        Some(Value::Object(defaults)) => defaults,
+        Some(_) => {
+            return Err(ForgeStudioError::invalid_input(
+                "'defaults' must be a mapping of data_root and dataset.",
+            ))
+        }
+    };
+    reject_unknown_keys(defaults, &RUN_SPEC_DEFAULT_KEYS, "defaults")?;
+    let default_text = |key: &str| {
+        defaults
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    let steps = match root.get("steps") {
+        Some(Value::Array(steps)) if !steps.is_empty() => steps,
+        _ => {
+            return Err(ForgeStudioError::invalid_input(
+                "'steps' must be a non-empty list. Add the commands to run.",
+            ))
+        }
+    };
+    let steps: Result<Vec<RunSpecStep>, ForgeStudioError> = steps
+        .iter()
+        .enumerate()
+        .map(|(index, step)| read_step(step, &format!("step #{}", index + 1)))
+        .collect();
+    Ok(RunSpecFile {
+        default_data_root: default_text("data_root"),
+        default_dataset: default_text("dataset"),
+        steps: steps?,
+    })
+}
+
+fn read_step(step: &Value, context: &str) -> Result<RunSpecStep, ForgeStudioError> {
+    let step = step.as_object().ok_or_else(|| {
+        ForgeStudioError::invalid_input(format!(
+            "{context} is not a mapping. Give it a 'command' key."
+        ))
+    })?;
+    let command = step
+        .get("command")
+        .and_then(Value::as_str)
+        .filter(|command| RUN_SPEC_COMMANDS.contains(command))
+        .ok_or_else(|| {
+            ForgeStudioError::invalid_input(format!(
+                "{context} needs a 'command' of {}.",
+                RUN_SPEC_COMMANDS.join(", ")
+            ))
+        })?;
+    let args = match step.get("args") {
+        Some(Value::Object(args)) if step.len() == 2 => args,
+        Some(_) => {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "{context} 'args' must be a mapping and not mixed with inline keys."
+            )))
+        }
+        None => step,
+    };
+    let mut converted = Vec::new();
+    for (key, value) in args.iter().filter(|(key, _)| *key != "command") {
+        let text = match value {
+            Value::Null => continue,
+            Value::String(text) => text.clone(),
+            Value::Bool(_) | Value::Number(_) => value.to_string(),
+            Value::Array(_) | Value::Object(_) => {
+                return Err(ForgeStudioError::invalid_input(format!(
+                    "{context} sets '{key}' to a list or mapping, which canvas nodes cannot hold. Use a single value."
+                )))
+            }
+        };
+        converted.push((key.clone(), text));
+    }
+    Ok(RunSpecStep {
+        command: command.to_string(),
+        args: converted,
+    })
+}
+
+fn reject_unknown_keys(
+    mapping: &Map<String, Value>,
+    allowed: &[&str],
+    context: &str,
+) -> Result<(), ForgeStudioError> {
+    match mapping.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) => Err(ForgeStudioError::invalid_input(format!(
+            "unknown {context} key '{key}'. Use only {}.",
+            allowed.join(", ")
+        ))),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,178 @@
+//! Reader for forge run-spec YAML.
+//!
+//! `yaml-rust2` does the parsing; this module turns its events into JSON
+//! values. Forge loads run specs with PyYAML's `safe_load`, which follows
+//! YAML 1.1, so plain scalars are typed the way PyYAML types them:
+//! `true`/`yes`/`on` are booleans, whole numbers are integers, and only
+//! numbers with a decimal point are floats. Quoted scalars stay strings.
+//! Aliases resolve to the value of their anchor. Tags and repeated keys are
+//! rejected with the line that uses them.
+
+use crate::models::ForgeStudioError;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+const TRUE_WORDS: [&str; 3] = ["true", "yes", "on"];
+const FALSE_WORDS: [&str; 3] = ["false", "no", "off"];
+const NULL_WORDS: [&str; 3] = ["", "null", "~"];
+
+/// A sequence or mapping still being read, with its anchor id (0 for none).
+enum OpenNode {
+    Sequence(Vec<Value>, usize),
+    /// Entries so far and the key, with its line, waiting for a value.
+    Mapping(Map<String, Value>, Option<(String, usize)>, usize),
+}
+
+#[derive(Default)]
+struct JsonBuilder {
+    open_nodes: Vec<OpenNode>,
+    anchors: HashMap<usize, Value>,
+    document: Option<Value>,
+    error: Option<ForgeStudioError>,
+}
+
+/// Parses `text` into JSON values; an empty document is `null`.
+pub(crate) fn parse_yaml(text: &str) -> Result<Value, ForgeStudioError> {
+    let mut builder = JsonBuilder::default();
+    Parser::new_from_str(text)
+        .load(&mut builder, false)
+        .map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Failed to parse run-spec YAML: {error}. Fix the YAML at that line."
+            ))
+        })?;
+    match builder.error {
+        Some(error) => Err(error),
+        None => Ok(builder.document.unwrap_or(Value::Null)),
+    }
+}
+
+impl MarkedEventReceiver for JsonBuilder {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        if self.error.is_some() {
+            return;
+        }
+        match event {
+            Event::Scalar(_, _, _, Some(_))
+            | Event::SequenceStart(_, Some(_))
+            | Event::MappingStart(_, Some(_)) => {
+                self.error = Some(unsupported(mark.line(), "tags"));
+            }
+            Event::Scalar(raw, TScalarStyle::Plain, anchor, None) => {
+                self.complete(plain_scalar(&raw), anchor, mark)
+            }
+            Event::Scalar(raw, _, anchor, None) => self.complete(Value::String(raw), anchor, mark),
+            Event::Alias(anchor) => match self.anchors.get(&anchor).cloned() {
+                Some(value) => self.complete(value, 0, mark),
+                None => self.error = Some(unsupported(mark.line(), "an alias with no anchor")),
+            },
+            Event::SequenceStart(anchor, None) => {
+                self.open_nodes.push(OpenNode::Sequence(Vec::new(), anchor))
+            }
+            Event::MappingStart(anchor, None) => {
+                self.open_nodes
+                    .push(OpenNode::Mapping(Map::new(), None, anchor))
+            }
+            Event::SequenceEnd | Event::MappingEnd => match self.open_nodes.pop() {
+                Some(OpenNode::Sequence(items, anchor)) => {
+                    self.complete(Value::Array(items), anchor, mark)
+                }
+                Some(OpenNode::Mapping(entries, _, anchor)) => {
+                    self.complete(Value::Object(entries), anchor, mark)
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+impl JsonBuilder {
+    /// Places a finished value in the node that holds it.
+    fn complete(&mut self, value: Value, anchor: usize, mark: Marker) {
+        if anchor > 0 {
+            self.anchors.insert(anchor, value.clone());
+        }
+        match self.open_nodes.last_mut() {
+            None => self.document = Some(value),
+            Some(OpenNode::Sequence(items, _)) => items.push(value),
+            Some(OpenNode::Mapping(entries, pending_key, _)) => match pending_key.take() {
+                Some((key, number)) if entries.contains_key(&key) => {
+                    self.error = Some(ForgeStudioError::parse(format!(
+                        "YAML line {number} repeats key '{key}'. Keep one entry per key."
+                    )));
+                }
+                Some((key, _)) => {
+                    entries.insert(key, value);
+                }
+                None => match value {
+                    Value::Array(_) | Value::Object(_) => {
+                        self.error =
+                            Some(unsupported(mark.line(), "a sequence or mapping as a key"));
+                    }
+                    Value::String(key) => *pending_key = Some((key, mark.line())),
+                    other => *pending_key = Some((other.to_string(), mark.line())),
+                },
+            },
+        }
+    }
+}
+
+fn plain_scalar(raw: &str) -> Value {
+    let lowered = raw.to_ascii_lowercase();
+    if NULL_WORDS.contains(&lowered.as_str()) {
+        return Value::Null;
+    }
+    if TRUE_WORDS.contains(&lowered.as_str()) || FALSE_WORDS.contains(&lowered.as_str()) {
+        return Value::Bool(TRUE_WORDS.contains(&lowered.as_str()));
+    }
+    if let Ok(integer) = raw.replace('_', "").parse::<i64>() {
+        return Value::Number(integer.into());
+    }
+    if raw.contains('.') {
+        if let Some(float) = raw.parse::<f64>().ok().and_then(Number::from_f64) {
+            return Value::Number(float);
+        }
+    }
+    Value::String(raw.to_string())
+}
+
+fn unsupported(number: usize, feature: &str) -> ForgeStudioError {
+    ForgeStudioError::parse(format!(
+        "YAML line {number} uses {feature}, which Studio cannot import. Rewrite it as a plain or quoted value."
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_yaml;
+    use serde_json::json;
+
+    #[test]
+    fn run_spec_yaml_parses_into_json() {
+        let text = "# Nightly refresh\nversion: 1\ndefaults:\n  dataset: \"demo # one\"\nsteps:\n- command: filter\n  min_quality: 0.5   # keep the best\n  language: 'it''s'\n-   command: train\n    args:\n      epochs: 3\n      resume: yes\n      tags: [a, 2]\n      note: 'yes'\n";
+        let parsed = parse_yaml(text).expect("run spec should parse");
+        assert_eq!(
+            parsed,
+            json!({
+                "version": 1,
+                "defaults": {"dataset": "demo # one"},
+                "steps": [
+                    {"command": "filter", "min_quality": 0.5, "language": "it's"},
+                    {"command": "train", "args": {"epochs": 3, "resume": true, "tags": ["a", 2], "note": "yes"}},
+                ],
+            })
+        );
+        let aliased =
+            parse_yaml("base: &base {epochs: 2}\nnext: *base\n").expect("aliases resolve");
+        assert_eq!(
+            aliased,
+            json!({"base": {"epochs": 2}, "next": {"epochs": 2}})
+        );
+        assert!(parse_yaml("steps: !custom train\n").is_err());
+        assert!(parse_yaml("key: 1\nkey: 2\n").is_err());
+        assert!(parse_yaml("key: [1\n").is_err());
+    }
+}
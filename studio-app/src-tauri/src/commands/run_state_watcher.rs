@@ -0,0 +1,204 @@
+//! Watches every run's `lifecycle.json` for state transitions.
+//!
+//! Runs started from a terminal or another Studio window never pass through
+//! this app's task store, so the watcher polls `runs/*/lifecycle.json`
+//! modification times instead and publishes a `run_state_changed` event when
+//! a run's state differs from the previous poll. The first poll only records
+//! the current states, so opening Studio does not replay old transitions.
+//! Transitions into `completed` or `failed` can also raise a desktop
+//! notification, since failures matter most when nobody is watching the app.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::read_child_dirs;
+use crate::commands::event_bus::EventBus;
+use crate::commands::os_notification::send_os_notification;
+use crate::commands::run_store_io::{read_json_file, resolve_data_root_path};
+use crate::models::{
+    BackendEvent, ForgeStudioError, RunLifecycleRecord, RunStateChange, RunStateWatchStart,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tauri::State;
+
+const RUN_STATE_POLL_INTERVAL: Duration = Duration::from_millis(2000);
+const NOTIFIED_STATES: [&str; 2] = ["completed", "failed"];
+
+#[derive(Clone, Default)]
+pub struct RunStateWatchStore {
+    inner: Arc<RunStateWatchStoreInner>,
+}
+
+#[derive(Default)]
+struct RunStateWatchStoreInner {
+    stop_senders: Mutex<HashMap<String, Sender<()>>>,
+    next_watch_id: AtomicU64,
+}
+
+/// Last seen lifecycle mtime and state per run id.
+#[derive(Default)]
+struct RunStateCursor {
+    seeded: bool,
+    runs: HashMap<String, (SystemTime, String)>,
+}
+
+#[tauri::command]
+pub fn start_run_state_watch(
+    data_root: String,
+    os_notifications: Option<bool>,
+    watch_store: State<'_, RunStateWatchStore>,
+    event_bus: State<'_, EventBus>,
+    settings: State<'_, SettingsStore>,
+) -> Result<RunStateWatchStart, ForgeStudioError> {
+    let runs_dir = resolve_data_root_path(&data_root, &settings.workspace_roots())?.join("runs");
+    let watch_number = watch_store
+        .inner
+        .next_watch_id
+        .fetch_add(1, Ordering::Relaxed)
+        + 1;
+    let watch_id = format!("run-state-watch-{watch_number}");
+    let mut cursor = RunStateCursor::default();
+    poll_run_states(&watch_id, &runs_dir, &mut cursor);
+    let tracked_run_count = cursor.runs.len() as u64;
+    let (stop_sender, stop_receiver) = mpsc::channel::<()>();
+    watch_store
+        .inner
+        .stop_senders
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("Run state watch store lock poisoned"))?
+        .insert(watch_id.clone(), stop_sender);
+    let notify = os_notifications.unwrap_or(false);
+    let thread_watch_id = watch_id.clone();
+    let event_bus = event_bus.inner().clone();
+    std::thread::spawn(move || {
+        // As in the history watcher, the stop channel doubles as the poll timer.
+        while let Err(RecvTimeoutError::Timeout) =
+            stop_receiver.recv_timeout(RUN_STATE_POLL_INTERVAL)
+        {
+            for change in poll_run_states(&thread_watch_id, &runs_dir, &mut cursor) {
+                if notify && NOTIFIED_STATES.contains(&change.state.as_str()) {
+                    notify_transition(&change);
+                }
+                event_bus.publish(BackendEvent::RunStateChanged(change));
+            }
+        }
+    });
+    Ok(RunStateWatchStart {
+        watch_id,
+        tracked_run_count,
+    })
+}
+
+#[tauri::command]
+pub fn stop_run_state_watch(
+    watch_id: String,
+    watch_store: State<'_, RunStateWatchStore>,
+) -> Result<(), ForgeStudioError> {
+    let removed = watch_store
+        .inner
+        .stop_senders
+        .lock()
+        .map_err(|_| ForgeStudioError::internal("Run state watch store lock poisoned"))?
+        .remove(&watch_id);
+    match removed {
+        Some(stop_sender) => {
+            let _ = stop_sender.send(());
+            Ok(())
+        }
+        None => Err(ForgeStudioError::not_found(format!(
+            "Unknown run state watch id '{watch_id}'. It may already be stopped."
+        ))),
+    }
+}
+
+fn poll_run_states(
+    watch_id: &str,
+    runs_dir: &Path,
+    cursor: &mut RunStateCursor,
+) -> Vec<RunStateChange> {
+    let mut changes = Vec::new();
+    for run_id in read_child_dirs(runs_dir).unwrap_or_default() {
+        let lifecycle_path = runs_dir.join(&run_id).join("lifecycle.json");
+        let Ok(modified) = fs::metadata(&lifecycle_path).and_then(|meta| meta.modified()) else {
+            continue;
+        };
+        let previous = cursor.runs.get(&run_id);
+        if previous.is_some_and(|(seen, _)| *seen == modified) {
+            continue;
+        }
+        // A lifecycle caught mid-write fails to parse and is retried on the next poll.
+        let Some(lifecycle) = read_json_file(&lifecycle_path)
+            .ok()
+            .and_then(|payload| serde_json::from_value::<RunLifecycleRecord>(payload).ok())
+        else {
+            continue;
+        };
+        let previous_state = previous.map(|(_, state)| state.clone());
+        if cursor.seeded && previous_state.as_deref() != Some(lifecycle.state.as_str()) {
+            changes.push(RunStateChange {
+                watch_id: watch_id.to_string(),
+                run_id: run_id.clone(),
+                dataset_name: lifecycle.dataset_name,
+                previous_state,
+                state: lifecycle.state.clone(),
+                error_message: lifecycle.error_message,
+                updated_at: lifecycle.updated_at,
+            });
+        }
+        cursor.runs.insert(run_id, (modified, lifecycle.state));
+    }
+    cursor.seeded = true;
+    changes
+}
+
+fn notify_transition(change: &RunStateChange) {
+    let title = format!("Forge run {}", change.state);
+    let body = match change.error_message.as_deref() {
+        Some(error) => format!("{} ({}): {error}", change.run_id, change.dataset_name),
+        None => format!("{} ({})", change.run_id, change.dataset_name),
+    };
+    send_os_notification(&title, &body);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{poll_run_states, RunStateCursor};
+    use serde_json::json;
+    use std::fs;
+    use std::path::Path;
+
+    fn write_lifecycle(runs_dir: &Path, run_id: &str, state: &str) {
+        let run_dir = runs_dir.join(run_id);
+        fs::create_dir_all(&run_dir).expect("run dir should be created");
+        let payload = json!({
+            "run_id": run_id, "dataset_name": "demo", "dataset_version_id": "demo-v1",
+            "output_dir": "/tmp/out", "parent_model_path": null, "config_hash": "abc",
+            "state": state, "created_at": "t0", "updated_at": "t1",
+            "artifact_contract_path": null, "error_message": null
+        });
+        fs::write(run_dir.join("lifecycle.json"), payload.to_string()).expect("lifecycle write");
+    }
+
+    #[test]
+    fn poll_run_states_reports_transitions_after_seeding() {
+        let runs_dir = std::env::temp_dir().join(format!("forge-run-state-{}", std::process::id()));
+        write_lifecycle(&runs_dir, "run-a", "running");
+        let mut cursor = RunStateCursor::default();
+        assert!(poll_run_states("w", &runs_dir, &mut cursor).is_empty());
+        // Some filesystems keep second-resolution mtimes; force a distinct one.
+        cursor.runs.get_mut("run-a").expect("tracked run").0 = std::time::UNIX_EPOCH;
+        write_lifecycle(&runs_dir, "run-a", "failed");
+        write_lifecycle(&runs_dir, "run-b", "running");
+        let mut changes = poll_run_states("w", &runs_dir, &mut cursor);
+        fs::remove_dir_all(&runs_dir).expect("cleanup should succeed");
+        changes.sort_by(|left, right| left.run_id.cmp(&right.run_id));
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].previous_state.as_deref(), Some("running"));
+        assert_eq!(changes[0].state, "failed");
+        assert_eq!(changes[1].previous_state, None);
+    }
+}
@@ -0,0 +1,154 @@
+//! Shared path resolution and JSON IO for training run metadata commands.
+//!
+//! Run metadata lives under `<data_root>/runs` and `<data_root>/lineage`;
+//! relative data roots and output paths are anchored to the workspace root
+//! because the forge CLI is launched from there. JSON writes replace files
+//! atomically, so a crash mid-write leaves the previous contents.
+
+use crate::commands::atomic_write::write_file_atomic;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::models::{ForgeStudioError, RunLifecycleRecord};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) fn required_string(
+    payload: &Map<String, Value>,
+    key: &str,
+) -> Result<String, ForgeStudioError> {
+    payload
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| ForgeStudioError::parse(format!("Field '{key}' is missing or invalid")))
+}
+
+pub(crate) fn optional_string(payload: &Map<String, Value>, key: &str) -> Option<String> {
+    payload.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+pub(crate) fn read_json_file(payload_path: &Path) -> Result<Value, ForgeStudioError> {
+    let payload = fs::read_to_string(payload_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read JSON file {}: {error}",
+            payload_path.to_string_lossy()
+        ))
+    })?;
+    serde_json::from_str::<Value>(&payload).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse JSON file {}: {error}",
+            payload_path.to_string_lossy()
+        ))
+    })
+}
+
+pub(crate) fn write_json_file(
+    payload_path: &Path,
+    payload: &Value,
+) -> Result<(), ForgeStudioError> {
+    let serialized = serde_json::to_string_pretty(payload).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize {}: {error}",
+            payload_path.display()
+        ))
+    })?;
+    write_file_atomic(payload_path, (serialized + "\n").as_bytes())
+}
+
+/// Creates `dir_path` and any missing parents.
+pub(crate) fn ensure_dir(dir_path: &Path) -> Result<(), ForgeStudioError> {
+    fs::create_dir_all(dir_path).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to create {}: {error}", dir_path.display()))
+    })
+}
+
+pub(crate) fn workspace_root_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+/// Resolves a frontend-supplied data root, refusing one outside `roots`.
+pub(crate) fn resolve_data_root_path(
+    data_root: &str,
+    roots: &WorkspaceRoots,
+) -> Result<PathBuf, ForgeStudioError> {
+    confine_path(Path::new(data_root), "Data root", roots)
+}
+
+/// `resolve_data_root_path` for commands whose helpers take the data root as text.
+pub(crate) fn resolve_data_root_text(
+    data_root: &str,
+    roots: &WorkspaceRoots,
+) -> Result<String, ForgeStudioError> {
+    Ok(resolve_data_root_path(data_root, roots)?
+        .display()
+        .to_string())
+}
+
+/// Anchors a relative path (data root or CLI-recorded output dir) to the workspace root.
+pub(crate) fn resolve_workspace_path(raw_value: &str) -> PathBuf {
+    let raw_path = Path::new(raw_value);
+    if raw_path.is_absolute() {
+        return raw_path.to_path_buf();
+    }
+    workspace_root_dir().join(raw_path)
+}
+
+pub(crate) fn run_dir(data_root: &Path, run_id: &str) -> PathBuf {
+    data_root.join("runs").join(run_id)
+}
+
+pub(crate) fn load_run_lifecycle(
+    data_root: &Path,
+    run_id: &str,
+) -> Result<RunLifecycleRecord, ForgeStudioError> {
+    let lifecycle_path = run_dir(data_root, run_id).join("lifecycle.json");
+    if !lifecycle_path.exists() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Run '{run_id}' has no lifecycle file at {}. Check the run id or rebuild the runs index.",
+            lifecycle_path.display()
+        )));
+    }
+    let payload = read_json_file(&lifecycle_path)?;
+    serde_json::from_value(payload).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse lifecycle file {}: {error}",
+            lifecycle_path.display()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_run_lifecycle, resolve_data_root_path};
+    use crate::commands::path_safety::WorkspaceRoots;
+    use std::path::Path;
+
+    #[test]
+    fn resolve_data_root_path_keeps_absolute_paths() {
+        let temp_dir = std::env::temp_dir()
+            .canonicalize()
+            .expect("temp dir should canonicalize");
+        let data_root = temp_dir.join("forge-data-root");
+        let roots = WorkspaceRoots::new(&[temp_dir.display().to_string()]);
+        let absolute_path = resolve_data_root_path(&data_root.display().to_string(), &roots)
+            .expect("temp dir is a registered root");
+        assert_eq!(absolute_path, data_root);
+        assert!(resolve_data_root_path("/forge-data-root", &roots).is_err());
+    }
+
+    #[test]
+    fn resolve_data_root_path_anchors_relative_paths_to_workspace_root() {
+        let relative_path = resolve_data_root_path(".forge", &WorkspaceRoots::default())
+            .expect("workspace paths should be allowed");
+        assert!(relative_path.ends_with(Path::new(".forge")));
+        assert!(relative_path.is_absolute());
+    }
+
+    #[test]
+    fn load_run_lifecycle_reports_missing_runs_as_not_found() {
+        let data_root = std::env::temp_dir().join("forge-missing-lifecycle");
+        let error = load_run_lifecycle(&data_root, "run-404").expect_err("run does not exist");
+        assert_eq!(error.code(), "not_found");
+        assert_eq!(error.detail().context.as_deref(), Some("run-404"));
+    }
+}
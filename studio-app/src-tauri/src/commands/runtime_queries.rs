@@ -1,77 +1,148 @@
-//! Runtime metadata commands for lifecycle, lineage, and hardware profile views.
-
-use crate::models::{LineageEdge, LineageGraphSummary, LineageRunNode, TrainingRunSummary};
-use serde_json::{Map, Value};
-use std::collections::{BTreeMap, HashMap};
-use std::fs;
-use std::path::{Path, PathBuf};
-use std::process::Command;
-
+//! Runtime metadata commands for lifecycle and lineage views.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::energy_ledger::load_run_energy_usage;
+use crate::commands::lineage_datasets::attach_dataset_nodes;
+use crate::commands::lineage_store_io::read_lineage_graph;
+use crate::commands::lineage_subgraph::filter_lineage_summary;
+use crate::commands::metadata_index_queries::indexed_run_ids;
+use crate::commands::run_annotations::load_run_meta;
+use crate::commands::run_cost::{load_cost_rates, price_usage};
+use crate::commands::run_heartbeat::{run_activity, stall_threshold};
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_list_filter::matches_run_query;
+use crate::commands::run_store_io::{
+    load_run_lifecycle, optional_string, required_string, resolve_data_root_path, run_dir,
+};
+use crate::models::{
+    ForgeStudioError, LineageEdge, LineageGraphFilter, LineageGraphSummary, LineageRunNode,
+    RunLifecycleRecord, RunUserMetadata, TrainingRunQuery, TrainingRunSummary,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::State;
+
+/// Lists runs newest first; `query` filters and pages so callers need not load every run.
 #[tauri::command]
-pub fn list_training_runs(data_root: String) -> Result<Vec<TrainingRunSummary>, String> {
-    let resolved_data_root = resolve_data_root_path(&data_root);
-    let runs_root = resolved_data_root.join("runs");
-    let index_path = runs_root.join("index.json");
-    if !index_path.exists() {
-        return Ok(vec![]);
-    }
-    let index_payload = read_json_file(&index_path)?;
-    let run_ids = index_payload
-        .get("runs")
-        .and_then(Value::as_array)
-        .ok_or_else(|| "Run index is missing runs array".to_string())?;
-    let model_paths = load_lineage_model_paths(&resolved_data_root)?;
-    let mut rows = Vec::new();
-    for run_id_value in run_ids.iter().rev() {
-        let run_id = run_id_value
-            .as_str()
-            .ok_or_else(|| "Run id entry must be a string".to_string())?;
-        let lifecycle_path = runs_root.join(run_id).join("lifecycle.json");
-        if !lifecycle_path.exists() {
-            continue;
+pub fn list_training_runs(
+    data_root: String,
+    query: Option<TrainingRunQuery>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<TrainingRunSummary>, ForgeStudioError> {
+    let query = query.unwrap_or_default();
+    let stall_threshold = stall_threshold(query.stalled_after_seconds);
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let read_only = settings.current().read_only;
+    let page = match indexed_run_page(&resolved_data_root, &query, read_only) {
+        Ok(page) => page,
+        Err(error) => {
+            tracing::warn!("{error} Listing runs from their files instead.");
+            scanned_run_page(&resolved_data_root, &query)?
         }
-        let payload = read_json_file(&lifecycle_path)?;
-        let object = payload
-            .as_object()
-            .ok_or_else(|| "Lifecycle payload must be a JSON object".to_string())?;
+    };
+    let model_paths = load_lineage_model_paths(&resolved_data_root)?;
+    let cost_rates = load_cost_rates(&resolved_data_root)?;
+    let mut rows = Vec::with_capacity(page.len());
+    for (lifecycle, meta) in page {
+        let usage = load_run_energy_usage(&lifecycle);
+        let activity = run_activity(&resolved_data_root, &lifecycle, stall_threshold);
+        let run_id = lifecycle.run_id.clone();
         rows.push(TrainingRunSummary {
-            run_id: run_id.to_string(),
-            dataset_name: required_string(object, "dataset_name")?,
-            dataset_version_id: required_string(object, "dataset_version_id")?,
-            state: required_string(object, "state")?,
-            updated_at: required_string(object, "updated_at")?,
-            output_dir: required_string(object, "output_dir")?,
-            artifact_contract_path: optional_string(object, "artifact_contract_path"),
-            model_path: model_paths.get(run_id).cloned(),
+            model_path: model_paths.get(&run_id).cloned(),
+            run_id,
+            dataset_name: lifecycle.dataset_name,
+            dataset_version_id: lifecycle.dataset_version_id,
+            state: lifecycle.state,
+            updated_at: lifecycle.updated_at,
+            output_dir: lifecycle.output_dir,
+            artifact_contract_path: lifecycle.artifact_contract_path,
+            tags: meta.tags,
+            notes: meta.notes,
+            resumed_from_run_id: lifecycle.resumed_from_run_id,
+            cost: price_usage(&usage, &cost_rates),
+            energy_kwh: usage.energy_kwh,
+            stalled: activity.stalled,
+            last_activity_at: activity.last_activity_at,
         });
     }
     Ok(rows)
 }
 
+/// The requested page of runs, picked from the metadata index.
+fn indexed_run_page(
+    data_root: &Path,
+    query: &TrainingRunQuery,
+    read_only: bool,
+) -> Result<Vec<(RunLifecycleRecord, RunUserMetadata)>, ForgeStudioError> {
+    indexed_run_ids(data_root, query, read_only)?
+        .iter()
+        .map(|run_id| {
+            Ok((
+                load_run_lifecycle(data_root, run_id)?,
+                load_run_meta(data_root, run_id)?,
+            ))
+        })
+        .collect()
+}
+
+/// The requested page of runs, found by reading run files newest first.
+fn scanned_run_page(
+    data_root: &Path,
+    query: &TrainingRunQuery,
+) -> Result<Vec<(RunLifecycleRecord, RunUserMetadata)>, ForgeStudioError> {
+    let offset = query.offset.unwrap_or(0);
+    let limit = query.limit.unwrap_or(usize::MAX);
+    let mut page = Vec::new();
+    let mut skipped_matches = 0;
+    for run_id in read_run_index(data_root)?.iter().rev() {
+        // Stop reading lifecycle files once the requested page is full.
+        if page.len() >= limit {
+            break;
+        }
+        if !run_dir(data_root, run_id).join("lifecycle.json").exists() {
+            continue;
+        }
+        let lifecycle = load_run_lifecycle(data_root, run_id)?;
+        let meta = load_run_meta(data_root, run_id)?;
+        if !matches_run_query(query, &lifecycle, &meta) {
+            continue;
+        }
+        if skipped_matches < offset {
+            skipped_matches += 1;
+            continue;
+        }
+        page.push((lifecycle, meta));
+    }
+    Ok(page)
+}
+
+/// Returns the lineage graph, narrowed to a reachable subgraph when `filter` is given.
 #[tauri::command]
-pub fn get_lineage_graph(data_root: String) -> Result<LineageGraphSummary, String> {
-    let resolved_data_root = resolve_data_root_path(&data_root);
-    let graph_path = resolved_data_root.join("lineage").join("model_lineage.json");
-    if !graph_path.exists() {
-        return Ok(empty_lineage_graph());
+pub fn get_lineage_graph(
+    data_root: String,
+    filter: Option<LineageGraphFilter>,
+    settings: State<'_, SettingsStore>,
+) -> Result<LineageGraphSummary, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let summary = load_lineage_summary(&resolved_data_root)?;
+    match filter {
+        Some(filter) => Ok(filter_lineage_summary(summary, &filter)?),
+        None => Ok(summary),
     }
-    let payload = read_json_file(&graph_path)?;
-    let root = payload
-        .as_object()
-        .ok_or_else(|| "Lineage payload must be a JSON object".to_string())?;
-    let runs_map = root
-        .get("runs")
-        .and_then(Value::as_object)
-        .ok_or_else(|| "Lineage payload missing runs map".to_string())?;
-    let edges_rows = root
-        .get("edges")
-        .and_then(Value::as_array)
-        .ok_or_else(|| "Lineage payload missing edges array".to_string())?;
+}
+
+/// Parses the lineage graph into typed run and dataset nodes and edges, newest runs first.
+pub(crate) fn load_lineage_summary(
+    data_root: &Path,
+) -> Result<LineageGraphSummary, ForgeStudioError> {
+    let graph = read_lineage_graph(data_root)?;
+    let runs_map = &graph.runs;
+    let edges_rows = &graph.edges;
     let mut runs = Vec::with_capacity(runs_map.len());
     for (run_id, raw_run) in runs_map {
         let run_payload = raw_run
             .as_object()
-            .ok_or_else(|| "Lineage run payload must be object".to_string())?;
+            .ok_or_else(|| ForgeStudioError::parse("Lineage run payload must be object"))?;
         runs.push(LineageRunNode {
             run_id: run_id.to_string(),
             dataset_name: required_string(run_payload, "dataset_name")?,
@@ -89,162 +160,35 @@ pub fn get_lineage_graph(data_root: String) -> Result<LineageGraphSummary, Strin
     for edge_row in edges_rows {
         let edge = edge_row
             .as_object()
-            .ok_or_else(|| "Lineage edge row must be object".to_string())?;
+            .ok_or_else(|| ForgeStudioError::parse("Lineage edge row must be object"))?;
         edges.push(LineageEdge {
             from: required_string(edge, "from")?,
             to: required_string(edge, "to")?,
             edge_type: required_string(edge, "type")?,
         });
     }
-    Ok(LineageGraphSummary {
+    let mut summary = LineageGraphSummary {
         run_count: runs.len() as u64,
+        dataset_count: 0,
         edge_count: edges.len() as u64,
         runs,
+        datasets: Vec::new(),
         edges,
-    })
+    };
+    attach_dataset_nodes(data_root, &mut summary);
+    Ok(summary)
 }
 
-#[tauri::command]
-pub fn get_hardware_profile(data_root: String) -> Result<BTreeMap<String, String>, String> {
-    let resolved_data_root = resolve_data_root_path(&data_root);
-    let output = Command::new("forge")
-        .current_dir(workspace_root_dir())
-        .arg("--data-root")
-        .arg(resolved_data_root.as_os_str())
-        .arg("hardware-profile")
-        .output()
-        .map_err(|error| format!("Failed to run forge hardware-profile: {error}"))?;
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        return Err(format!(
-            "forge hardware-profile failed with status {}: {}",
-            output.status.code().unwrap_or(-1),
-            stderr
-        ));
-    }
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let profile = parse_hardware_profile_output(&stdout);
-    if profile.is_empty() {
-        return Err("Hardware profile output was empty".to_string());
-    }
-    Ok(profile)
-}
-
-fn load_lineage_model_paths(data_root: &Path) -> Result<HashMap<String, String>, String> {
-    let graph_path = data_root.join("lineage").join("model_lineage.json");
-    if !graph_path.exists() {
-        return Ok(HashMap::new());
-    }
-    let payload = read_json_file(&graph_path)?;
-    let root = payload
-        .as_object()
-        .ok_or_else(|| "Lineage payload must be a JSON object".to_string())?;
-    let runs_map = root
-        .get("runs")
-        .and_then(Value::as_object)
-        .ok_or_else(|| "Lineage payload missing runs map".to_string())?;
+fn load_lineage_model_paths(data_root: &Path) -> Result<HashMap<String, String>, ForgeStudioError> {
+    let runs_map = read_lineage_graph(data_root)?.runs;
     let mut model_paths = HashMap::new();
-    for (run_id, raw_run) in runs_map {
+    for (run_id, raw_run) in &runs_map {
         let run_payload = raw_run
             .as_object()
-            .ok_or_else(|| "Lineage run payload must be object".to_string())?;
+            .ok_or_else(|| ForgeStudioError::parse("Lineage run payload must be object"))?;
         if let Some(model_path) = optional_string(run_payload, "model_path") {
             model_paths.insert(run_id.to_string(), model_path);
         }
     }
     Ok(model_paths)
 }
-
-fn parse_hardware_profile_output(stdout: &str) -> BTreeMap<String, String> {
-    let mut profile = BTreeMap::new();
-    for line in stdout.lines() {
-        if let Some((key, value)) = line.split_once('=') {
-            let normalized_key = key.trim();
-            let normalized_value = value.trim();
-            if !normalized_key.is_empty() {
-                profile.insert(normalized_key.to_string(), normalized_value.to_string());
-            }
-        }
-    }
-    profile
-}
-
-fn required_string(payload: &Map<String, Value>, key: &str) -> Result<String, String> {
-    payload
-        .get(key)
-        .and_then(Value::as_str)
-        .map(str::to_string)
-        .ok_or_else(|| format!("Field '{key}' is missing or invalid"))
-}
-
-fn optional_string(payload: &Map<String, Value>, key: &str) -> Option<String> {
-    payload.get(key).and_then(Value::as_str).map(str::to_string)
-}
-
-fn empty_lineage_graph() -> LineageGraphSummary {
-    LineageGraphSummary {
-        run_count: 0,
-        edge_count: 0,
-        runs: vec![],
-        edges: vec![],
-    }
-}
-
-fn read_json_file(payload_path: &Path) -> Result<Value, String> {
-    let payload = fs::read_to_string(payload_path).map_err(|error| {
-        format!(
-            "Failed to read JSON file {}: {error}",
-            payload_path.to_string_lossy()
-        )
-    })?;
-    serde_json::from_str::<Value>(&payload).map_err(|error| {
-        format!(
-            "Failed to parse JSON file {}: {error}",
-            payload_path.to_string_lossy()
-        )
-    })
-}
-
-fn workspace_root_dir() -> PathBuf {
-    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
-}
-
-fn resolve_data_root_path(data_root: &str) -> PathBuf {
-    let raw_path = Path::new(data_root);
-    if raw_path.is_absolute() {
-        return raw_path.to_path_buf();
-    }
-    workspace_root_dir().join(raw_path)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::{parse_hardware_profile_output, resolve_data_root_path};
-    use std::path::Path;
-
-    #[test]
-    fn parse_hardware_profile_output_reads_key_value_rows() {
-        let profile = parse_hardware_profile_output(
-            "accelerator=cuda\ngpu_count=1\nrecommended_precision_mode=bf16\n",
-        );
-        assert_eq!(profile.get("accelerator"), Some(&"cuda".to_string()));
-        assert_eq!(profile.get("gpu_count"), Some(&"1".to_string()));
-        assert_eq!(
-            profile.get("recommended_precision_mode"),
-            Some(&"bf16".to_string())
-        );
-    }
-
-    #[test]
-    fn resolve_data_root_path_keeps_absolute_paths() {
-        let absolute_path = resolve_data_root_path("/tmp/forge-data-root");
-        assert_eq!(absolute_path, Path::new("/tmp/forge-data-root"));
-    }
-
-    #[test]
-    fn resolve_data_root_path_anchors_relative_paths_to_workspace_root() {
-        let relative_path = resolve_data_root_path(".forge");
-        assert!(relative_path.ends_with(Path::new(".forge")));
-        assert!(relative_path.is_absolute());
-    }
-}
@@ -0,0 +1,116 @@
+//! Checks a merged settings update before it is saved.
+//!
+//! Every message starts with "Settings were not saved" because a failed
+//! check leaves the stored settings untouched.
+
+use crate::commands::app_logging::parse_log_filter;
+use crate::commands::run_store_io::resolve_workspace_path;
+use crate::models::{AppSettings, ForgeStudioError, UserIdentity};
+
+const MAX_CONCURRENT_TASKS: usize = 16;
+/// Lower ports need elevated privileges on most systems.
+const MIN_SERVER_PORT: u16 = 1024;
+
+pub(crate) fn validate_settings(settings: &AppSettings) -> Result<(), ForgeStudioError> {
+    if settings.forge_binary.trim().is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Settings were not saved: forge_binary is empty. Use `forge` or a path to the forge CLI.",
+        ));
+    }
+    if !(1..=MAX_CONCURRENT_TASKS).contains(&settings.max_concurrent_tasks) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Settings were not saved: max_concurrent_tasks must be 1 to {MAX_CONCURRENT_TASKS}."
+        )));
+    }
+    if settings
+        .default_data_root
+        .as_deref()
+        .is_some_and(|data_root| data_root.trim().is_empty())
+    {
+        return Err(ForgeStudioError::invalid_input(
+            "Settings were not saved: default_data_root is empty. Set a path or null to reopen the last data root.",
+        ));
+    }
+    for workspace_root in &settings.workspace_roots {
+        if !resolve_workspace_path(workspace_root).is_dir() {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Settings were not saved: workspace root '{workspace_root}' is not a directory. Create it or remove it from workspace_roots."
+            )));
+        }
+    }
+    for data_root in &settings.data_roots {
+        if !resolve_workspace_path(data_root).is_dir() {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Settings were not saved: data root '{data_root}' is not a directory. Mount it or remove it from data_roots."
+            )));
+        }
+    }
+    parse_log_filter(&settings.log_filter).map_err(|error| {
+        ForgeStudioError::invalid_input(format!("Settings were not saved: {error}"))
+    })?;
+    validate_identity(&settings.identity)?;
+    let cache_sizes = &settings.cache_sizes;
+    if [
+        cache_sizes.finished_tasks,
+        cache_sizes.canvas_snapshots,
+        cache_sizes.canvas_edits,
+    ]
+    .contains(&0)
+    {
+        return Err(ForgeStudioError::invalid_input(
+            "Settings were not saved: cache sizes must be at least 1.",
+        ));
+    }
+    if let Some(upload_url) = &settings.telemetry.upload_url {
+        if !upload_url.starts_with("https://") {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Settings were not saved: telemetry upload_url '{upload_url}' is not an https:// URL. Use HTTPS or null to keep telemetry local."
+            )));
+        }
+    }
+    validate_server_ports(settings)
+}
+
+fn validate_identity(identity: &UserIdentity) -> Result<(), ForgeStudioError> {
+    if identity
+        .name
+        .as_deref()
+        .is_some_and(|name| name.trim().is_empty())
+    {
+        return Err(ForgeStudioError::invalid_input(
+            "Settings were not saved: identity name is empty. Set a name or null to leave changes unattributed.",
+        ));
+    }
+    if let Some(email) = identity
+        .email
+        .as_deref()
+        .filter(|email| !email.contains('@'))
+    {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Settings were not saved: identity email '{email}' is not an email address. Use name@host or null."
+        )));
+    }
+    Ok(())
+}
+
+/// Local servers stay off privileged ports and off each other's.
+fn validate_server_ports(settings: &AppSettings) -> Result<(), ForgeStudioError> {
+    let servers = [
+        ("rpc_server", settings.rpc_server.port),
+        ("event_bridge", settings.event_bridge.port),
+    ];
+    if let Some((server, _)) = servers.iter().find(|(_, port)| *port < MIN_SERVER_PORT) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Settings were not saved: {server} port must be {MIN_SERVER_PORT} to 65535."
+        )));
+    }
+    if settings.rpc_server.enabled
+        && settings.event_bridge.enabled
+        && settings.rpc_server.port == settings.event_bridge.port
+    {
+        return Err(ForgeStudioError::invalid_input(
+            "Settings were not saved: rpc_server and event_bridge use the same port. Give each its own.",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,209 @@
+//! Trial parameter generation for hyperparameter sweeps.
+//!
+//! Random search uses a seeded SplitMix64 stream rather than OS entropy, so
+//! a sweep record's seed is enough to regenerate exactly the same trials.
+//! Swept values must be strings, numbers, or booleans because those are the
+//! only config values the `train` CLI args can carry.
+
+use crate::commands::run_resume::RESERVED_CONFIG_KEYS;
+use crate::models::{ForgeStudioError, SweepParameter, SweepSpec};
+use serde_json::{Map, Value};
+
+const GRID_STRATEGY: &str = "grid";
+const RANDOM_STRATEGY: &str = "random";
+const MAX_SWEEP_TRIALS: usize = 256;
+const DEFAULT_SWEEP_SEED: u64 = 0;
+
+/// Per-trial parameter maps, in trial order.
+pub(crate) fn generate_trial_params(
+    spec: &SweepSpec,
+) -> Result<Vec<Map<String, Value>>, ForgeStudioError> {
+    if spec.parameters.is_empty() {
+        return Err(ForgeStudioError::invalid_input(
+            "Sweep spec has no parameters. Add at least one key to sweep.",
+        ));
+    }
+    for (key, parameter) in &spec.parameters {
+        validate_parameter(key, parameter)?;
+    }
+    match spec.strategy.as_str() {
+        GRID_STRATEGY => grid_trials(spec),
+        RANDOM_STRATEGY => random_trials(spec),
+        other => Err(ForgeStudioError::invalid_input(format!(
+            "Unknown sweep strategy '{other}'. Use '{GRID_STRATEGY}' or '{RANDOM_STRATEGY}'."
+        ))),
+    }
+}
+
+/// Rejects config keys the sweep sets per trial, in the base config or the swept parameters.
+pub(crate) fn reject_reserved_key(key: &str) -> Result<(), ForgeStudioError> {
+    if RESERVED_CONFIG_KEYS.contains(&key) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "'{key}' cannot be set in a sweep config. Use the sweep request field instead."
+        )));
+    }
+    Ok(())
+}
+
+fn validate_parameter(key: &str, parameter: &SweepParameter) -> Result<(), ForgeStudioError> {
+    reject_reserved_key(key)?;
+    let values = parameter.values.iter().flatten();
+    if values
+        .clone()
+        .any(|value| !value.is_string() && !value.is_number() && !value.is_boolean())
+    {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Sweep values for '{key}' must be strings, numbers, or booleans. Other values \
+             cannot be passed to the train command."
+        )));
+    }
+    if let (Some(min), Some(max)) = (parameter.min, parameter.max) {
+        if min > max || (parameter.log_scale && min <= 0.0) {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Sweep range for '{key}' is invalid ({min} to {max}). Use min <= max, and \
+                 min > 0 for log scale."
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn grid_trials(spec: &SweepSpec) -> Result<Vec<Map<String, Value>>, ForgeStudioError> {
+    let mut trials = vec![Map::new()];
+    for (key, parameter) in &spec.parameters {
+        let values = match parameter.values.as_deref() {
+            Some(values) if !values.is_empty() => values,
+            _ => {
+                return Err(ForgeStudioError::invalid_input(format!(
+                    "Grid sweep parameter '{key}' has no values. List explicit values, or \
+                     use a random sweep for ranges."
+                )))
+            }
+        };
+        if trials.len() * values.len() > MAX_SWEEP_TRIALS {
+            return Err(ForgeStudioError::invalid_input(format!(
+                "Grid sweep expands to more than {MAX_SWEEP_TRIALS} trials. Drop values or \
+                 switch to a random sweep."
+            )));
+        }
+        trials = trials
+            .into_iter()
+            .flat_map(|trial| {
+                values.iter().map(move |value| {
+                    let mut next = trial.clone();
+                    next.insert(key.clone(), value.clone());
+                    next
+                })
+            })
+            .collect();
+    }
+    Ok(trials)
+}
+
+fn random_trials(spec: &SweepSpec) -> Result<Vec<Map<String, Value>>, ForgeStudioError> {
+    let trial_count = spec.num_trials.unwrap_or(0);
+    if trial_count == 0 || trial_count > MAX_SWEEP_TRIALS {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Random sweep needs num_trials between 1 and {MAX_SWEEP_TRIALS}."
+        )));
+    }
+    let mut state = spec.seed.unwrap_or(DEFAULT_SWEEP_SEED);
+    let mut trials = Vec::with_capacity(trial_count);
+    for _ in 0..trial_count {
+        let mut trial = Map::new();
+        for (key, parameter) in &spec.parameters {
+            trial.insert(key.clone(), sample_value(key, parameter, &mut state)?);
+        }
+        trials.push(trial);
+    }
+    Ok(trials)
+}
+
+fn sample_value(
+    key: &str,
+    parameter: &SweepParameter,
+    state: &mut u64,
+) -> Result<Value, ForgeStudioError> {
+    if let Some(values) = parameter
+        .values
+        .as_deref()
+        .filter(|values| !values.is_empty())
+    {
+        let index = (next_unit(state) * values.len() as f64) as usize;
+        return Ok(values[index.min(values.len() - 1)].clone());
+    }
+    let (Some(min), Some(max)) = (parameter.min, parameter.max) else {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Random sweep parameter '{key}' needs values or a min/max range."
+        )));
+    };
+    let unit = next_unit(state);
+    let sampled = if parameter.log_scale {
+        (min.ln() + unit * (max.ln() - min.ln())).exp()
+    } else {
+        min + unit * (max - min)
+    };
+    if parameter.integer {
+        return Ok(Value::from(sampled.round() as i64));
+    }
+    Ok(Value::from(sampled))
+}
+
+/// Uniform in [0, 1) from the top 53 bits of a SplitMix64 step.
+fn next_unit(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut mixed = *state;
+    mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    mixed ^= mixed >> 31;
+    (mixed >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_trial_params;
+    use crate::models::SweepSpec;
+    use serde_json::json;
+
+    fn spec(payload: serde_json::Value) -> SweepSpec {
+        serde_json::from_value(payload).expect("spec should parse")
+    }
+
+    #[test]
+    fn grid_expands_cartesian_product_in_key_order() {
+        let trials = generate_trial_params(&spec(json!({
+            "strategy": "grid",
+            "parameters": {
+                "learning_rate": {"values": [0.001, 0.01]},
+                "batch_size": {"values": [16, 32, 64]}
+            }
+        })))
+        .expect("grid should expand");
+        assert_eq!(trials.len(), 6);
+        assert_eq!(trials[0].get("batch_size"), Some(&json!(16)));
+        assert_eq!(trials[1].get("learning_rate"), Some(&json!(0.01)));
+        let reserved = spec(json!({
+            "strategy": "grid", "parameters": {"output_dir": {"values": ["a"]}}
+        }));
+        assert!(generate_trial_params(&reserved).is_err());
+    }
+
+    #[test]
+    fn random_sampling_is_seeded_and_within_range() {
+        let random = spec(json!({
+            "strategy": "random", "num_trials": 8, "seed": 7,
+            "parameters": {
+                "learning_rate": {"min": 0.0001, "max": 0.1, "log_scale": true},
+                "epochs": {"min": 1, "max": 5, "integer": true}
+            }
+        }));
+        let first = generate_trial_params(&random).expect("random should sample");
+        assert_eq!(first, generate_trial_params(&random).expect("same seed"));
+        for trial in &first {
+            let rate = trial["learning_rate"].as_f64().expect("float rate");
+            assert!((0.0001..=0.1).contains(&rate));
+            let epochs = trial["epochs"].as_i64().expect("integer epochs");
+            assert!((1..=5).contains(&epochs));
+        }
+    }
+}
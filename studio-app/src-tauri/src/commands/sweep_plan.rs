@@ -0,0 +1,161 @@
+//! Planning a sweep's trials before any of them start.
+//!
+//! Every trial's `train` args are built and validated up front, so a sweep
+//! that cannot run is refused as a whole instead of failing trial by trial.
+
+use crate::commands::forge_commands::validate_args;
+use crate::commands::run_resume::build_train_args;
+use crate::commands::sweep_configs::{generate_trial_params, reject_reserved_key};
+use crate::commands::sweeps::PENDING_STATUS;
+use crate::models::{ForgeStudioError, SweepRequest, SweepTrial};
+use serde_json::{Map, Value};
+
+const MAX_PARALLEL_TRIALS: usize = 8;
+
+/// Base config with the trial's swept values layered on top.
+pub(crate) fn trial_config(base_config: &Map<String, Value>, params: &Map<String, Value>) -> Value {
+    let mut config = base_config.clone();
+    config.extend(params.clone());
+    Value::Object(config)
+}
+
+pub(crate) fn validate_sweep_request(request: &SweepRequest) -> Result<(), ForgeStudioError> {
+    if request.max_parallel == 0 || request.max_parallel > MAX_PARALLEL_TRIALS {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "max_parallel {} is out of range. Use 1 to {MAX_PARALLEL_TRIALS} trials.",
+            request.max_parallel
+        )));
+    }
+    for key in request.base_config.keys() {
+        reject_reserved_key(key)?;
+    }
+    Ok(())
+}
+
+/// Pending trials for the request, each checked as a runnable `train` command.
+pub(crate) fn plan_trials(
+    request: &SweepRequest,
+    version_id: &str,
+    output_root: &str,
+) -> Result<Vec<SweepTrial>, ForgeStudioError> {
+    let trials = generate_trial_params(&request.spec)?
+        .into_iter()
+        .enumerate()
+        .map(|(trial_index, params)| new_trial(trial_index, params, output_root))
+        .collect::<Vec<_>>();
+    for trial in &trials {
+        let config = trial_config(&request.base_config, &trial.params);
+        validate_args(&build_train_args(
+            &request.dataset_name,
+            version_id,
+            &trial.output_dir,
+            Some(&config),
+        ))?;
+    }
+    Ok(trials)
+}
+
+fn new_trial(trial_index: usize, params: Map<String, Value>, output_root: &str) -> SweepTrial {
+    SweepTrial {
+        trial_index,
+        params,
+        output_dir: format!(
+            "{}/trial-{trial_index:03}",
+            output_root.trim_end_matches('/')
+        ),
+        status: PENDING_STATUS.to_string(),
+        task_id: None,
+        run_id: None,
+        started_at: None,
+        finished_at: None,
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_trials, trial_config, validate_sweep_request};
+    use crate::commands::run_resume::build_train_args;
+    use crate::models::SweepRequest;
+    use serde_json::{json, Value};
+
+    fn request(spec: Value, base_config: Value) -> SweepRequest {
+        serde_json::from_value(json!({
+            "dataset_name": "demo",
+            "base_config": base_config,
+            "spec": spec,
+            "max_parallel": 2,
+        }))
+        .expect("request should parse")
+    }
+
+    #[test]
+    fn plan_trials_expands_the_grid_with_per_trial_args() {
+        let sweep = request(
+            json!({
+                "strategy": "grid",
+                "parameters": {
+                    "learning_rate": {"values": [0.001, 0.01]},
+                    "use_amp": {"values": [true, false]}
+                }
+            }),
+            json!({"epochs": 3, "learning_rate": 0.5}),
+        );
+        let trials = plan_trials(&sweep, "v1", "out/sweep/").expect("grid should plan");
+        assert_eq!(trials.len(), 4);
+        assert_eq!(trials[3].output_dir, "out/sweep/trial-003");
+        let config = trial_config(&sweep.base_config, &trials[3].params);
+        assert_eq!(config["learning_rate"], json!(0.01));
+        let args = build_train_args("demo", "v1", &trials[3].output_dir, Some(&config));
+        assert_eq!(
+            args[5..],
+            [
+                "--output-dir",
+                "out/sweep/trial-003",
+                "--epochs",
+                "3",
+                "--learning-rate",
+                "0.01",
+                "--no-use-amp"
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_trials_rejects_unpassable_values_and_oversized_grids() {
+        let nested = request(
+            json!({"strategy": "grid", "parameters": {"layers": {"values": [[1, 2]]}}}),
+            json!({}),
+        );
+        assert!(plan_trials(&nested, "v1", "out").is_err());
+        let values: Vec<u32> = (0..17).collect();
+        let oversized = request(
+            json!({
+                "strategy": "grid",
+                "parameters": {"a": {"values": values}, "b": {"values": values}}
+            }),
+            json!({}),
+        );
+        assert!(plan_trials(&oversized, "v1", "out").is_err());
+        let too_many_random = request(
+            json!({
+                "strategy": "random",
+                "num_trials": 257,
+                "parameters": {"lr": {"min": 0.1, "max": 1.0}}
+            }),
+            json!({}),
+        );
+        assert!(plan_trials(&too_many_random, "v1", "out").is_err());
+    }
+
+    #[test]
+    fn validate_sweep_request_checks_parallelism_and_reserved_keys() {
+        let spec = json!({"strategy": "grid", "parameters": {"lr": {"values": [0.1]}}});
+        assert!(validate_sweep_request(&request(spec.clone(), json!({}))).is_ok());
+        let reserved = request(spec.clone(), json!({"output_dir": "elsewhere"}));
+        assert!(validate_sweep_request(&reserved).is_err());
+        let mut serial = request(spec, json!({}));
+        serial.max_parallel = 0;
+        assert!(validate_sweep_request(&serial).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! Background scheduler that feeds sweep trials into the task store.
+//!
+//! The task store starts every task immediately, so queueing lives here: a
+//! thread per sweep polls its running trials and starts pending ones while
+//! fewer than `max_parallel` are running. The scheduler owns the sweep
+//! record for the sweep's lifetime and is its only writer. Sweeps do not
+//! outlive the app; trials still marked running after a restart were cut off.
+
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_index::read_run_index;
+use crate::commands::run_resume::build_train_args;
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_workspace_path};
+use crate::commands::sweep_plan::trial_config;
+use crate::commands::sweeps::{save_sweep, timestamp_now, PENDING_STATUS};
+use crate::commands::train_task_log::spawn_train_log_recorder;
+use crate::models::{SweepRecord, SweepTrial};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+const SWEEP_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+struct SweepScheduler {
+    data_root: String,
+    resolved_data_root: PathBuf,
+    task_store: CommandTaskStore,
+    /// Runs that existed before the sweep, skipped when matching trials to runs.
+    known_run_ids: HashSet<String>,
+    sweep: SweepRecord,
+}
+
+pub(crate) fn spawn_sweep_scheduler(
+    data_root: String,
+    task_store: CommandTaskStore,
+    sweep: SweepRecord,
+) {
+    // `start_sweep` already confined `data_root`.
+    let resolved_data_root = resolve_workspace_path(&data_root);
+    let known_run_ids = read_run_index(&resolved_data_root)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    let mut scheduler = SweepScheduler {
+        data_root,
+        resolved_data_root,
+        task_store,
+        known_run_ids,
+        sweep,
+    };
+    thread::spawn(move || loop {
+        let changed = scheduler.refresh_running_trials() | scheduler.start_pending_trials();
+        let finished = scheduler.sweep.trials.iter().all(|trial| {
+            trial.status == task_status_name(TaskLifecycleStatus::Completed)
+                || trial.status == task_status_name(TaskLifecycleStatus::Failed)
+        });
+        if finished {
+            let any_completed =
+                scheduler.count(task_status_name(TaskLifecycleStatus::Completed)) > 0;
+            scheduler.sweep.status = if any_completed {
+                task_status_name(TaskLifecycleStatus::Completed)
+            } else {
+                task_status_name(TaskLifecycleStatus::Failed)
+            }
+            .to_string();
+            scheduler.sweep.finished_at = Some(timestamp_now());
+        }
+        if changed || finished {
+            if let Err(error) = save_sweep(&scheduler.resolved_data_root, &scheduler.sweep) {
+                tracing::warn!("{error}");
+            }
+        }
+        if finished {
+            return;
+        }
+        thread::sleep(SWEEP_POLL_INTERVAL);
+    });
+}
+
+impl SweepScheduler {
+    fn count(&self, status: &str) -> usize {
+        self.sweep
+            .trials
+            .iter()
+            .filter(|trial| trial.status == status)
+            .count()
+    }
+
+    /// Moves finished tasks to a terminal status and links trials to their runs.
+    fn refresh_running_trials(&mut self) -> bool {
+        let mut changed = false;
+        let running = self
+            .sweep
+            .trials
+            .iter_mut()
+            .filter(|trial| trial.status == task_status_name(TaskLifecycleStatus::Running));
+        for trial in running {
+            if trial.run_id.is_none() {
+                trial.run_id = find_trial_run(&self.resolved_data_root, &self.known_run_ids, trial);
+                changed |= trial.run_id.is_some();
+            }
+            let Some(task_id) = trial.task_id.as_deref() else {
+                continue;
+            };
+            let task_status = match self.task_store.get_task_status(task_id) {
+                Ok(status) if status.status == task_status_name(TaskLifecycleStatus::Running) => {
+                    continue
+                }
+                Ok(status) => status,
+                Err(error) => {
+                    finish_trial(
+                        trial,
+                        task_status_name(TaskLifecycleStatus::Failed),
+                        Some(error.to_string()),
+                    );
+                    changed = true;
+                    continue;
+                }
+            };
+            if trial.run_id.is_none() {
+                trial.run_id = find_trial_run(&self.resolved_data_root, &self.known_run_ids, trial);
+            }
+            let error = (task_status.status != task_status_name(TaskLifecycleStatus::Completed))
+                .then(|| {
+                    format!(
+                        "Trial task exited with code {:?}. Diagnose the trial's run for details.",
+                        task_status.exit_code
+                    )
+                });
+            finish_trial(trial, &task_status.status, error);
+            changed = true;
+        }
+        changed
+    }
+
+    fn start_pending_trials(&mut self) -> bool {
+        let free_slots = self
+            .sweep
+            .max_parallel
+            .saturating_sub(self.count(task_status_name(TaskLifecycleStatus::Running)));
+        let pending = self
+            .sweep
+            .trials
+            .iter_mut()
+            .filter(|trial| trial.status == PENDING_STATUS);
+        let mut changed = false;
+        for trial in pending.take(free_slots) {
+            let config = trial_config(&self.sweep.base_config, &trial.params);
+            let args = build_train_args(
+                &self.sweep.dataset_name,
+                &self.sweep.version_id,
+                &trial.output_dir,
+                Some(&config),
+            );
+            let task = self
+                .task_store
+                .start_task(self.data_root.clone(), args.clone());
+            spawn_train_log_recorder(self.task_store.clone(), task.task_id.clone(), &args);
+            trial.status = task_status_name(TaskLifecycleStatus::Running).to_string();
+            trial.task_id = Some(task.task_id);
+            trial.started_at = Some(timestamp_now());
+            changed = true;
+        }
+        changed
+    }
+}
+
+fn finish_trial(trial: &mut SweepTrial, status: &str, error: Option<String>) {
+    trial.status = status.to_string();
+    trial.finished_at = Some(timestamp_now());
+    trial.error = error;
+}
+
+/// Trial output dirs are unique per sweep, so the new run writing there is the trial's.
+fn find_trial_run(
+    data_root: &Path,
+    known_run_ids: &HashSet<String>,
+    trial: &SweepTrial,
+) -> Option<String> {
+    read_run_index(data_root)
+        .ok()?
+        .into_iter()
+        .filter(|run_id| !known_run_ids.contains(run_id))
+        .find(|run_id| {
+            load_run_lifecycle(data_root, run_id)
+                .map(|lifecycle| lifecycle.output_dir == trial.output_dir)
+                .unwrap_or(false)
+        })
+}
@@ -0,0 +1,187 @@
+//! Hyperparameter sweeps: many `train` runs over generated configs.
+//!
+//! A sweep pins its dataset version up front so every trial trains on the
+//! same data, then hands the trials to a background scheduler that keeps at
+//! most `max_parallel` of them in the task store at once. The sweep record
+//! is the source of truth for trial state; per-trial metrics are read from
+//! each trial's run when status is requested, so they fill in live.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::resolve_version;
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_details::{compute_final_metrics, load_history, resolve_run_artifacts};
+use crate::commands::run_store_io::{
+    load_run_lifecycle, read_json_file, resolve_data_root_path, write_json_file,
+};
+use crate::commands::sweep_plan::{plan_trials, validate_sweep_request};
+use crate::commands::sweep_scheduler::spawn_sweep_scheduler;
+use crate::models::{
+    ForgeStudioError, RunFinalMetrics, SweepRecord, SweepRequest, SweepStart, SweepStatus,
+    SweepTrialStatus,
+};
+use chrono::{SecondsFormat, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const SWEEPS_DIR_NAME: &str = "sweeps";
+const SWEEP_FILE_NAME: &str = "sweep.json";
+pub(crate) const PENDING_STATUS: &str = "pending";
+
+#[tauri::command]
+pub fn start_sweep(
+    data_root: String,
+    request: SweepRequest,
+    task_store: State<'_, CommandTaskStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<SweepStart, ForgeStudioError> {
+    validate_sweep_request(&request)?;
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let version_id = resolve_version(
+        &data_root,
+        &request.dataset_name,
+        request.version_id.clone(),
+    )?;
+    let sweep_id = next_sweep_id(&resolved_data_root)?;
+    let output_root = request.output_dir.clone().unwrap_or_else(|| {
+        sweep_dir(&resolved_data_root, &sweep_id)
+            .display()
+            .to_string()
+    });
+    let trials = plan_trials(&request, &version_id, &output_root)?;
+    let sweep = SweepRecord {
+        sweep_id,
+        dataset_name: request.dataset_name,
+        version_id,
+        strategy: request.spec.strategy,
+        seed: request.spec.seed,
+        max_parallel: request.max_parallel,
+        base_config: request.base_config,
+        status: task_status_name(TaskLifecycleStatus::Running).to_string(),
+        created_at: timestamp_now(),
+        finished_at: None,
+        trials,
+    };
+    save_sweep(&resolved_data_root, &sweep)?;
+    let start = SweepStart {
+        sweep_id: sweep.sweep_id.clone(),
+        version_id: sweep.version_id.clone(),
+        trial_count: sweep.trials.len(),
+        max_parallel: sweep.max_parallel,
+    };
+    spawn_sweep_scheduler(data_root, task_store.inner().clone(), sweep);
+    Ok(start)
+}
+
+#[tauri::command]
+pub fn get_sweep_status(
+    data_root: String,
+    sweep_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<SweepStatus, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let sweep = load_sweep(&resolved_data_root, &sweep_id)?;
+    let count = |status: &str| {
+        sweep
+            .trials
+            .iter()
+            .filter(|trial| trial.status == status)
+            .count()
+    };
+    let trials: Vec<SweepTrialStatus> = sweep
+        .trials
+        .iter()
+        .map(|trial| SweepTrialStatus {
+            trial_index: trial.trial_index,
+            params: trial.params.clone(),
+            status: trial.status.clone(),
+            task_id: trial.task_id.clone(),
+            run_id: trial.run_id.clone(),
+            error: trial.error.clone(),
+            final_metrics: trial
+                .run_id
+                .as_deref()
+                .and_then(|run_id| trial_metrics(&resolved_data_root, run_id)),
+        })
+        .collect();
+    let best_trial_index = trials
+        .iter()
+        .filter_map(|trial| Some((trial.trial_index, trial.final_metrics.as_ref()?)))
+        .min_by(|left, right| {
+            left.1
+                .best_validation_loss
+                .total_cmp(&right.1.best_validation_loss)
+        })
+        .map(|(trial_index, _)| trial_index);
+    Ok(SweepStatus {
+        pending_count: count(PENDING_STATUS),
+        running_count: count(task_status_name(TaskLifecycleStatus::Running)),
+        completed_count: count(task_status_name(TaskLifecycleStatus::Completed)),
+        failed_count: count(task_status_name(TaskLifecycleStatus::Failed)),
+        sweep_id: sweep.sweep_id,
+        status: sweep.status,
+        dataset_name: sweep.dataset_name,
+        version_id: sweep.version_id,
+        best_trial_index,
+        trials,
+    })
+}
+
+pub(crate) fn save_sweep(data_root: &Path, sweep: &SweepRecord) -> Result<(), ForgeStudioError> {
+    let payload_path = sweep_dir(data_root, &sweep.sweep_id).join(SWEEP_FILE_NAME);
+    if let Some(parent) = payload_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(sweep).map_err(|error| {
+        ForgeStudioError::internal(format!(
+            "Failed to serialize sweep '{}': {error}",
+            sweep.sweep_id
+        ))
+    })?;
+    write_json_file(&payload_path, &payload)
+}
+
+pub(crate) fn timestamp_now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Metrics are best-effort: a trial still starting up has no history yet.
+fn trial_metrics(data_root: &Path, run_id: &str) -> Option<RunFinalMetrics> {
+    let lifecycle = load_run_lifecycle(data_root, run_id).ok()?;
+    let history_path = resolve_run_artifacts(&lifecycle).ok()?.history_path?;
+    compute_final_metrics(&load_history(&history_path).ok()??)
+}
+
+fn sweep_dir(data_root: &Path, sweep_id: &str) -> PathBuf {
+    data_root.join(SWEEPS_DIR_NAME).join(sweep_id)
+}
+
+fn next_sweep_id(data_root: &Path) -> Result<String, ForgeStudioError> {
+    let sweeps_dir = data_root.join(SWEEPS_DIR_NAME);
+    if !sweeps_dir.is_dir() {
+        return Ok("sweep-0001".to_string());
+    }
+    let entries = fs::read_dir(&sweeps_dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", sweeps_dir.display()))
+    })?;
+    Ok(format!("sweep-{:04}", entries.flatten().count() + 1))
+}
+
+fn load_sweep(data_root: &Path, sweep_id: &str) -> Result<SweepRecord, ForgeStudioError> {
+    let payload_path = sweep_dir(data_root, sweep_id).join(SWEEP_FILE_NAME);
+    if !payload_path.is_file() {
+        return Err(ForgeStudioError::not_found(format!(
+            "Sweep '{sweep_id}' does not exist under {}. Check the sweep id.",
+            data_root.display()
+        )));
+    }
+    serde_json::from_value(read_json_file(&payload_path)?).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse sweep {}: {error}",
+            payload_path.display()
+        ))
+    })
+}
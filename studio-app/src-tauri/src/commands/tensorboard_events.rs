@@ -0,0 +1,115 @@
+//! Minimal TensorBoard event-file encoding.
+//!
+//! Event files are TFRecord streams of serialized `tensorflow.Event`
+//! protobufs. Only scalar summaries are needed for loss curves, so the few
+//! protobuf fields involved are hand-encoded here rather than pulling in a
+//! protobuf toolchain for a single message type.
+
+const FILE_VERSION: &str = "brain.Event:2";
+const CRC_MASK_DELTA: u32 = 0xa282_ead8;
+/// Reflected CRC-32C (Castagnoli) polynomial, as TFRecord framing requires.
+const CRC32C_POLYNOMIAL: u32 = 0x82f6_3b78;
+
+// Protobuf keys are `(field_number << 3) | wire_type`.
+const EVENT_WALL_TIME_KEY: u8 = (1 << 3) | 1;
+const EVENT_STEP_KEY: u8 = 2 << 3;
+const EVENT_FILE_VERSION_KEY: u8 = (3 << 3) | 2;
+const EVENT_SUMMARY_KEY: u8 = (5 << 3) | 2;
+const SUMMARY_VALUE_KEY: u8 = (1 << 3) | 2;
+const VALUE_TAG_KEY: u8 = (1 << 3) | 2;
+const VALUE_SIMPLE_VALUE_KEY: u8 = (2 << 3) | 5;
+
+/// The header event TensorBoard expects first in every event file.
+pub(crate) fn file_version_record(wall_time: f64) -> Vec<u8> {
+    let mut event = Vec::new();
+    event.push(EVENT_WALL_TIME_KEY);
+    event.extend_from_slice(&wall_time.to_le_bytes());
+    push_length_delimited(&mut event, EVENT_FILE_VERSION_KEY, FILE_VERSION.as_bytes());
+    frame_record(&event)
+}
+
+/// One scalar point; TensorBoard stores scalars as f32.
+pub(crate) fn scalar_record(wall_time: f64, step: u64, tag: &str, value: f64) -> Vec<u8> {
+    let mut summary_value = Vec::new();
+    push_length_delimited(&mut summary_value, VALUE_TAG_KEY, tag.as_bytes());
+    summary_value.push(VALUE_SIMPLE_VALUE_KEY);
+    summary_value.extend_from_slice(&(value as f32).to_le_bytes());
+    let mut summary = Vec::new();
+    push_length_delimited(&mut summary, SUMMARY_VALUE_KEY, &summary_value);
+    let mut event = Vec::new();
+    event.push(EVENT_WALL_TIME_KEY);
+    event.extend_from_slice(&wall_time.to_le_bytes());
+    event.push(EVENT_STEP_KEY);
+    push_varint(&mut event, step);
+    push_length_delimited(&mut event, EVENT_SUMMARY_KEY, &summary);
+    frame_record(&event)
+}
+
+/// TFRecord framing: length, masked CRC of the length, payload, masked CRC of the payload.
+fn frame_record(payload: &[u8]) -> Vec<u8> {
+    let length = (payload.len() as u64).to_le_bytes();
+    let mut record = Vec::with_capacity(payload.len() + 16);
+    record.extend_from_slice(&length);
+    record.extend_from_slice(&masked_crc32c(&length).to_le_bytes());
+    record.extend_from_slice(payload);
+    record.extend_from_slice(&masked_crc32c(payload).to_le_bytes());
+    record
+}
+
+fn push_length_delimited(buffer: &mut Vec<u8>, key: u8, payload: &[u8]) {
+    buffer.push(key);
+    push_varint(buffer, payload.len() as u64);
+    buffer.extend_from_slice(payload);
+}
+
+fn push_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn masked_crc32c(payload: &[u8]) -> u32 {
+    let crc = crc32c(payload);
+    crc.rotate_right(15).wrapping_add(CRC_MASK_DELTA)
+}
+
+fn crc32c(payload: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for byte in payload {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (CRC32C_POLYNOMIAL & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc32c, push_varint, scalar_record};
+
+    #[test]
+    fn crc32c_and_varint_match_reference_encodings() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+        let mut buffer = Vec::new();
+        push_varint(&mut buffer, 300);
+        assert_eq!(buffer, vec![0xac, 0x02]);
+    }
+
+    #[test]
+    fn scalar_record_frames_event_with_declared_length() {
+        let record = scalar_record(1.5, 3, "epoch/train_loss", 0.25);
+        let mut length_bytes = [0_u8; 8];
+        length_bytes.copy_from_slice(&record[..8]);
+        let payload_length = u64::from_le_bytes(length_bytes) as usize;
+        assert_eq!(record.len(), payload_length + 16);
+        let payload = &record[12..12 + payload_length];
+        assert_eq!(&payload[9..11], &[0x10, 3]);
+        assert!(payload
+            .windows(16)
+            .any(|window| window == b"epoch/train_loss"));
+    }
+}
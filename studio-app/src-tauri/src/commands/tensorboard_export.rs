@@ -0,0 +1,168 @@
+//! Export a run's training history as TensorBoard scalars.
+//!
+//! Each run gets its own subdirectory of `logdir`, so pointing TensorBoard
+//! at one logdir shows several exported runs side by side. Epoch metrics are
+//! stepped by epoch number and batch metrics by global step, under separate
+//! `epoch/` and `batch/` tag prefixes so the two scales never share a chart.
+//! The history has no per-row timestamps, so every event carries the export
+//! time as its wall time.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::path_safety::confine_path;
+use crate::commands::run_details::{load_history, resolve_run_artifacts};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::commands::tensorboard_events::{file_version_record, scalar_record};
+use crate::models::{ForgeStudioError, TensorboardExportResult, TrainingHistory};
+use chrono::Utc;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use tauri::State;
+
+const EVENT_FILE_PREFIX: &str = "events.out.tfevents";
+const EVENT_FILE_HOST: &str = "forge-studio";
+
+/// `(tag, step, value)` for one TensorBoard scalar point.
+type Scalar = (String, u64, f64);
+
+#[tauri::command]
+pub fn export_history_to_tensorboard(
+    data_root: String,
+    run_id: String,
+    logdir: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<TensorboardExportResult, ForgeStudioError> {
+    let lifecycle = load_run_lifecycle(
+        &resolve_data_root_path(&data_root, &settings.workspace_roots())?,
+        &run_id,
+    )?;
+    let history_path = resolve_run_artifacts(&lifecycle)?.history_path;
+    let history = match history_path.as_deref() {
+        Some(path) => load_history(path)?,
+        None => None,
+    }
+    .ok_or_else(|| {
+        ForgeStudioError::not_found(format!(
+            "Run '{run_id}' has no training history to export. \
+             Wait for the run to log its first epoch."
+        ))
+    })?;
+    let run_logdir = confine_path(
+        Path::new(&logdir),
+        "TensorBoard log dir",
+        &settings.workspace_roots(),
+    )?
+    .join(&run_id);
+    fs::create_dir_all(&run_logdir).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to create {}: {error}",
+            run_logdir.display()
+        ))
+    })?;
+    let now = Utc::now();
+    let event_file_path = run_logdir.join(format!(
+        "{EVENT_FILE_PREFIX}.{}.{EVENT_FILE_HOST}",
+        now.timestamp()
+    ));
+    let scalars = collect_scalars(&history);
+    let wall_time = now.timestamp_micros() as f64 / 1e6;
+    write_event_file(&event_file_path, wall_time, &scalars)?;
+    Ok(TensorboardExportResult {
+        run_id,
+        event_file_path: event_file_path.display().to_string(),
+        epoch_count: history.epochs.len() as u64,
+        batch_count: history.batch_losses.len() as u64,
+        scalar_count: scalars.len() as u64,
+    })
+}
+
+fn collect_scalars(history: &TrainingHistory) -> Vec<Scalar> {
+    let mut scalars = Vec::new();
+    for row in &history.epochs {
+        let metrics = [
+            ("train_loss", Some(row.train_loss)),
+            ("validation_loss", Some(row.validation_loss)),
+            ("learning_rate", row.learning_rate),
+            ("gradient_norm", row.gradient_norm),
+            ("tokens_per_second", row.tokens_per_second),
+            ("gpu_memory_mb", row.gpu_memory_mb),
+        ];
+        push_metrics(&mut scalars, "epoch", row.epoch, &metrics);
+    }
+    for row in &history.batch_losses {
+        let metrics = [
+            ("train_loss", Some(row.train_loss)),
+            ("learning_rate", row.learning_rate),
+            ("gradient_norm", row.gradient_norm),
+            ("tokens_per_second", row.tokens_per_second),
+            ("gpu_memory_mb", row.gpu_memory_mb),
+        ];
+        push_metrics(&mut scalars, "batch", row.global_step, &metrics);
+    }
+    scalars
+}
+
+fn push_metrics(
+    scalars: &mut Vec<Scalar>,
+    prefix: &str,
+    step: u64,
+    metrics: &[(&str, Option<f64>)],
+) {
+    for (name, value) in metrics {
+        if let Some(value) = value.filter(|value| value.is_finite()) {
+            scalars.push((format!("{prefix}/{name}"), step, value));
+        }
+    }
+}
+
+fn write_event_file(
+    event_file_path: &Path,
+    wall_time: f64,
+    scalars: &[Scalar],
+) -> Result<(), ForgeStudioError> {
+    let write_error = |error: std::io::Error| {
+        ForgeStudioError::io(format!(
+            "Failed to write {}: {error}",
+            event_file_path.display()
+        ))
+    };
+    let mut writer = BufWriter::new(File::create(event_file_path).map_err(write_error)?);
+    writer
+        .write_all(&file_version_record(wall_time))
+        .map_err(write_error)?;
+    for (tag, step, value) in scalars {
+        writer
+            .write_all(&scalar_record(wall_time, *step, tag, *value))
+            .map_err(write_error)?;
+    }
+    writer.flush().map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collect_scalars;
+    use crate::models::TrainingHistory;
+    use serde_json::json;
+
+    #[test]
+    fn collect_scalars_prefixes_tags_and_skips_missing_metrics() {
+        let history: TrainingHistory = serde_json::from_value(json!({
+            "epochs": [{"epoch": 1, "train_loss": 2.0, "validation_loss": 2.5, "lr": 0.001}],
+            "batch_losses": [{"epoch": 1, "batch_index": 1, "global_step": 41, "train_loss": 1.9}]
+        }))
+        .expect("history should parse");
+        let tags: Vec<(String, u64)> = collect_scalars(&history)
+            .into_iter()
+            .map(|(tag, step, _)| (tag, step))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                ("epoch/train_loss".to_string(), 1),
+                ("epoch/validation_loss".to_string(), 1),
+                ("epoch/learning_rate".to_string(), 1),
+                ("batch/train_loss".to_string(), 41),
+            ]
+        );
+    }
+}
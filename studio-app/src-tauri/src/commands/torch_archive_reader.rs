@@ -0,0 +1,148 @@
+//! Minimal zip central-directory reader for PyTorch `.pt` archives.
+//!
+//! `torch.save` writes an uncompressed zip whose `<archive>/data/<key>`
+//! entries hold raw tensor storages. Listing entry sizes is enough to
+//! estimate parameter bytes without unpickling, so this reads only the
+//! central directory rather than pulling in a full zip dependency.
+
+use crate::models::ForgeStudioError;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+const END_OF_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const DIRECTORY_ENTRY_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_DIRECTORY_MIN_SIZE: u64 = 22;
+const MAX_ARCHIVE_COMMENT_SIZE: u64 = 65_535;
+const DIRECTORY_ENTRY_FIXED_SIZE: usize = 46;
+const ZIP64_SIZE_MARKER: u32 = u32::MAX;
+
+pub(crate) struct ArchiveEntry {
+    pub name: String,
+    pub uncompressed_size: u64,
+}
+
+/// Lists zip entries; fails for zip64 archives, which torch only writes above 4 GiB.
+pub(crate) fn list_archive_entries(
+    archive_path: &Path,
+) -> Result<Vec<ArchiveEntry>, ForgeStudioError> {
+    let read_error = |error: std::io::Error| {
+        ForgeStudioError::io(format!(
+            "Failed to read {}: {error}",
+            archive_path.display()
+        ))
+    };
+    let mut file = File::open(archive_path).map_err(read_error)?;
+    let file_size = file.metadata().map_err(read_error)?.len();
+    let tail_size = file_size.min(END_OF_DIRECTORY_MIN_SIZE + MAX_ARCHIVE_COMMENT_SIZE);
+    file.seek(SeekFrom::Start(file_size - tail_size))
+        .map_err(read_error)?;
+    let mut tail = vec![0_u8; tail_size as usize];
+    file.read_exact(&mut tail).map_err(read_error)?;
+    let directory_start = find_end_of_directory(&tail).ok_or_else(|| {
+        ForgeStudioError::parse(format!(
+            "{} is not a zip archive (no end-of-directory record).",
+            archive_path.display()
+        ))
+    })?;
+    let record = &tail[directory_start..];
+    let entry_count = u16_at(record, 10) as usize;
+    let directory_size = u32_at(record, 12);
+    let directory_offset = u32_at(record, 16);
+    if directory_offset == ZIP64_SIZE_MARKER || directory_size == ZIP64_SIZE_MARKER {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "{} is a zip64 archive, which inspection does not support. \
+             Export the model to safetensors to inspect it.",
+            archive_path.display()
+        )));
+    }
+    file.seek(SeekFrom::Start(u64::from(directory_offset)))
+        .map_err(read_error)?;
+    let mut directory = vec![0_u8; directory_size as usize];
+    file.read_exact(&mut directory).map_err(read_error)?;
+    parse_directory_entries(&directory, entry_count)
+}
+
+fn find_end_of_directory(tail: &[u8]) -> Option<usize> {
+    let last_start = tail.len().checked_sub(END_OF_DIRECTORY_MIN_SIZE as usize)?;
+    (0..=last_start)
+        .rev()
+        .find(|&index| u32_at(tail, index) == END_OF_DIRECTORY_SIGNATURE)
+}
+
+fn parse_directory_entries(
+    directory: &[u8],
+    entry_count: usize,
+) -> Result<Vec<ArchiveEntry>, ForgeStudioError> {
+    let mut entries = Vec::with_capacity(entry_count);
+    let mut cursor = 0;
+    for _ in 0..entry_count {
+        if cursor + DIRECTORY_ENTRY_FIXED_SIZE > directory.len()
+            || u32_at(directory, cursor) != DIRECTORY_ENTRY_SIGNATURE
+        {
+            return Err(ForgeStudioError::parse(
+                "Zip central directory is truncated or corrupt.",
+            ));
+        }
+        let uncompressed_size = u32_at(directory, cursor + 24);
+        let name_length = u16_at(directory, cursor + 28) as usize;
+        let extra_length = u16_at(directory, cursor + 30) as usize;
+        let comment_length = u16_at(directory, cursor + 32) as usize;
+        let name_start = cursor + DIRECTORY_ENTRY_FIXED_SIZE;
+        let name_bytes = directory
+            .get(name_start..name_start + name_length)
+            .ok_or_else(|| {
+                ForgeStudioError::parse("Zip entry name runs past the central directory.")
+            })?;
+        entries.push(ArchiveEntry {
+            name: String::from_utf8_lossy(name_bytes).to_string(),
+            uncompressed_size: u64::from(uncompressed_size),
+        });
+        cursor = name_start + name_length + extra_length + comment_length;
+    }
+    Ok(entries)
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_end_of_directory, parse_directory_entries};
+
+    fn directory_entry(name: &str, size: u32) -> Vec<u8> {
+        let mut entry = vec![0_u8; 46];
+        entry[0..4].copy_from_slice(&0x0201_4b50_u32.to_le_bytes());
+        entry[24..28].copy_from_slice(&size.to_le_bytes());
+        entry[28..30].copy_from_slice(&(name.len() as u16).to_le_bytes());
+        entry.extend_from_slice(name.as_bytes());
+        entry
+    }
+
+    #[test]
+    fn parse_directory_entries_reads_names_and_sizes() {
+        let mut directory = directory_entry("model/data.pkl", 120);
+        directory.extend(directory_entry("model/data/0", 4096));
+        let entries = parse_directory_entries(&directory, 2).expect("directory should parse");
+        assert_eq!(entries[1].name, "model/data/0");
+        assert_eq!(entries[1].uncompressed_size, 4096);
+    }
+
+    #[test]
+    fn find_end_of_directory_locates_trailing_record() {
+        let mut tail = vec![7_u8; 10];
+        tail.extend_from_slice(&0x0605_4b50_u32.to_le_bytes());
+        tail.extend(vec![0_u8; 18]);
+        assert_eq!(find_end_of_directory(&tail), Some(10));
+    }
+}
@@ -0,0 +1,115 @@
+//! Persisted output of finished `train` tasks.
+//!
+//! Task output only lives in the in-memory task store, and a process killed
+//! by the OOM-killer never gets to record its failure in `lifecycle.json`.
+//! A watcher therefore writes each train task's exit code and output tails
+//! into its `--output-dir`, where failure diagnosis can read them after the
+//! task has been pruned or Studio restarted.
+
+use crate::commands::forge_commands::{flag_value, OUTPUT_DIR_FLAG};
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::commands::forge_task_store::CommandTaskStore;
+use crate::commands::run_store_io::{read_json_file, resolve_workspace_path, write_json_file};
+use crate::models::{ForgeStudioError, TrainTaskLog};
+use chrono::{SecondsFormat, Utc};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+const TRAIN_TASK_LOG_FILE_NAME: &str = "train_task_log.json";
+const TRAIN_LOG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Failure signatures sit at the end of the output; older lines only bloat the file.
+const MAX_TAIL_BYTES: usize = 64 * 1024;
+
+/// Starts a watcher for train tasks with an output dir; other commands are ignored.
+pub(crate) fn spawn_train_log_recorder(
+    task_store: CommandTaskStore,
+    task_id: String,
+    args: &[String],
+) {
+    if args.first().map(String::as_str) != Some("train") {
+        return;
+    }
+    let Some(output_dir) = flag_value(args, OUTPUT_DIR_FLAG) else {
+        return;
+    };
+    let log_path = train_task_log_path(output_dir);
+    thread::spawn(move || loop {
+        let Ok(status) = task_store.get_task_status(&task_id) else {
+            return;
+        };
+        if status.status == task_status_name(TaskLifecycleStatus::Running) {
+            thread::sleep(TRAIN_LOG_POLL_INTERVAL);
+            continue;
+        }
+        let log = TrainTaskLog {
+            task_id: status.task_id,
+            status: status.status,
+            exit_code: status.exit_code,
+            finished_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            stdout_tail: tail(&status.stdout).to_string(),
+            stderr_tail: tail(&status.stderr).to_string(),
+        };
+        if let Err(error) = save_train_task_log(&log_path, &log) {
+            tracing::warn!("{error}");
+        }
+        return;
+    });
+}
+
+/// `None` when no train task launched from Studio has finished in this output dir.
+pub(crate) fn load_train_task_log(
+    output_dir: &str,
+) -> Result<Option<TrainTaskLog>, ForgeStudioError> {
+    let log_path = train_task_log_path(output_dir);
+    if !log_path.is_file() {
+        return Ok(None);
+    }
+    serde_json::from_value(read_json_file(&log_path)?)
+        .map(Some)
+        .map_err(|error| {
+            ForgeStudioError::parse(format!(
+                "Failed to parse train task log {}: {error}",
+                log_path.display()
+            ))
+        })
+}
+
+fn save_train_task_log(log_path: &Path, log: &TrainTaskLog) -> Result<(), ForgeStudioError> {
+    if let Some(parent) = log_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", parent.display()))
+        })?;
+    }
+    let payload = serde_json::to_value(log).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize train task log: {error}"))
+    })?;
+    write_json_file(log_path, &payload)
+}
+
+fn train_task_log_path(output_dir: &str) -> PathBuf {
+    resolve_workspace_path(output_dir).join(TRAIN_TASK_LOG_FILE_NAME)
+}
+
+/// Last `MAX_TAIL_BYTES` of `text`, cut at a character boundary.
+fn tail(text: &str) -> &str {
+    let mut start = text.len().saturating_sub(MAX_TAIL_BYTES);
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    &text[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tail, MAX_TAIL_BYTES};
+
+    #[test]
+    fn tail_keeps_the_end_on_a_char_boundary() {
+        let text = format!("é{}", "x".repeat(MAX_TAIL_BYTES - 1));
+        let kept = tail(&text);
+        assert_eq!(kept.len(), MAX_TAIL_BYTES - 1);
+        assert!(kept.starts_with('x'));
+        assert_eq!(tail("short"), "short");
+    }
+}
@@ -0,0 +1,195 @@
+//! Overfitting, plateau, and loss-spike detection over a run's history.
+//!
+//! Findings carry epoch (and, for spikes, step) ranges so the UI can shade
+//! them on the loss chart. Thresholds are relative to the run's own losses
+//! because absolute loss scales differ between tokenizers and datasets.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::loss_spike_detection::{detect_batch_spikes, detect_epoch_spikes};
+use crate::commands::run_details::{load_history, resolve_run_artifacts};
+use crate::commands::run_store_io::{load_run_lifecycle, resolve_data_root_path};
+use crate::models::{CurveFinding, ForgeStudioError, TrainingCurveAnalysis, TrainingEpoch};
+use tauri::State;
+
+/// Epochs past the best validation loss before a rise counts as divergence.
+const DIVERGENCE_PATIENCE_EPOCHS: usize = 2;
+/// Rises smaller than this fraction of the best loss are noise.
+const MIN_DIVERGENCE_RISE: f64 = 0.01;
+const CRITICAL_DIVERGENCE_RISE: f64 = 0.10;
+/// Per-epoch validation change below this fraction counts as flat.
+const PLATEAU_RELATIVE_CHANGE: f64 = 0.005;
+const PLATEAU_MIN_EPOCHS: usize = 3;
+
+#[tauri::command]
+pub fn analyze_training_curves(
+    data_root: String,
+    run_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<TrainingCurveAnalysis, ForgeStudioError> {
+    let resolved_data_root = resolve_data_root_path(&data_root, &settings.workspace_roots())?;
+    let lifecycle = load_run_lifecycle(&resolved_data_root, &run_id)?;
+    let artifacts = resolve_run_artifacts(&lifecycle)?;
+    let history_path = artifacts.history_path.ok_or_else(|| {
+        ForgeStudioError::not_found(format!(
+            "Run '{run_id}' has no training history yet. Analyze it after an epoch completes."
+        ))
+    })?;
+    let history = load_history(&history_path)?.ok_or_else(|| {
+        ForgeStudioError::not_found(format!("Training history {history_path} is missing."))
+    })?;
+    let best = history
+        .epochs
+        .iter()
+        .min_by(|left, right| left.validation_loss.total_cmp(&right.validation_loss));
+    let mut findings = Vec::new();
+    let divergence = detect_divergence(&history.epochs);
+    let recommended_stop_epoch = divergence.as_ref().and(best.map(|epoch| epoch.epoch));
+    findings.extend(divergence);
+    findings.extend(detect_plateaus(&history.epochs));
+    if history.batch_losses.is_empty() {
+        findings.extend(detect_epoch_spikes(&history.epochs));
+    } else {
+        findings.extend(detect_batch_spikes(&history.batch_losses));
+    }
+    findings.sort_by_key(|finding| (finding.start_epoch, finding.start_step));
+    Ok(TrainingCurveAnalysis {
+        run_id,
+        best_epoch: best.map(|epoch| epoch.epoch),
+        best_validation_loss: best.map(|epoch| epoch.validation_loss),
+        recommended_stop_epoch,
+        findings,
+    })
+}
+
+/// Validation loss rising after its best epoch while train loss keeps falling.
+fn detect_divergence(epochs: &[TrainingEpoch]) -> Option<CurveFinding> {
+    let best_index = epochs
+        .iter()
+        .enumerate()
+        .min_by(|left, right| left.1.validation_loss.total_cmp(&right.1.validation_loss))?
+        .0;
+    let best = &epochs[best_index];
+    let after_best = &epochs[best_index + 1..];
+    let last = after_best.last()?;
+    let rise =
+        (last.validation_loss - best.validation_loss) / best.validation_loss.abs().max(1e-12);
+    let train_still_falling = last.train_loss < best.train_loss;
+    if after_best.len() < DIVERGENCE_PATIENCE_EPOCHS
+        || rise < MIN_DIVERGENCE_RISE
+        || !train_still_falling
+    {
+        return None;
+    }
+    let severity = if rise >= CRITICAL_DIVERGENCE_RISE {
+        "critical"
+    } else {
+        "warning"
+    };
+    Some(epoch_finding(
+        "overfitting",
+        severity,
+        (after_best[0].epoch, last.epoch),
+        format!(
+            "Validation loss rose {:.1}% after its best at epoch {} while train loss kept \
+             falling. Stop at epoch {} (its checkpoint is the best one) or add dropout or \
+             weight decay.",
+            rise * 100.0,
+            best.epoch,
+            best.epoch
+        ),
+    ))
+}
+
+/// Runs of epochs whose validation loss barely moves.
+fn detect_plateaus(epochs: &[TrainingEpoch]) -> Vec<CurveFinding> {
+    let mut findings = Vec::new();
+    let mut run_start = 0;
+    for index in 1..=epochs.len() {
+        let flat = index < epochs.len() && {
+            let previous = epochs[index - 1].validation_loss;
+            let change = (epochs[index].validation_loss - previous).abs();
+            change <= previous.abs() * PLATEAU_RELATIVE_CHANGE
+        };
+        if flat {
+            continue;
+        }
+        if index - run_start >= PLATEAU_MIN_EPOCHS {
+            let range = (epochs[run_start].epoch, epochs[index - 1].epoch);
+            findings.push(epoch_finding(
+                "plateau",
+                "info",
+                range,
+                format!(
+                    "Validation loss changed less than {:.1}% per epoch across epochs {}-{}. \
+                     Lower the learning rate or stop early to save compute.",
+                    PLATEAU_RELATIVE_CHANGE * 100.0,
+                    range.0,
+                    range.1
+                ),
+            ));
+        }
+        run_start = index;
+    }
+    findings
+}
+
+pub(crate) fn epoch_finding(
+    kind: &str,
+    severity: &str,
+    (start_epoch, end_epoch): (u64, u64),
+    message: String,
+) -> CurveFinding {
+    CurveFinding {
+        kind: kind.to_string(),
+        severity: severity.to_string(),
+        start_epoch,
+        end_epoch,
+        start_step: None,
+        end_step: None,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_divergence, detect_plateaus};
+    use crate::models::TrainingEpoch;
+
+    fn epochs(rows: &[(f64, f64)]) -> Vec<TrainingEpoch> {
+        let payload: Vec<serde_json::Value> = rows
+            .iter()
+            .enumerate()
+            .map(|(index, (train, validation))| {
+                serde_json::json!({
+                    "epoch": index + 1,
+                    "train_loss": train,
+                    "validation_loss": validation,
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::Value::Array(payload)).expect("epochs should parse")
+    }
+
+    #[test]
+    fn detect_divergence_flags_rising_validation_after_best_epoch() {
+        let rows = epochs(&[(3.0, 3.1), (2.5, 2.8), (2.0, 2.9), (1.5, 3.2)]);
+        let finding = detect_divergence(&rows).expect("validation diverged");
+        assert_eq!((finding.start_epoch, finding.end_epoch), (3, 4));
+        assert_eq!(finding.severity, "critical");
+        assert!(detect_divergence(&epochs(&[(3.0, 3.0), (2.0, 2.5), (1.5, 2.2)])).is_none());
+    }
+
+    #[test]
+    fn detect_plateaus_reports_flat_epoch_runs() {
+        let rows = epochs(&[
+            (3.0, 3.0),
+            (2.5, 2.0),
+            (2.4, 2.001),
+            (2.3, 2.002),
+            (2.2, 1.5),
+        ]);
+        let findings = detect_plateaus(&rows);
+        assert_eq!(findings.len(), 1);
+        assert_eq!((findings[0].start_epoch, findings[0].end_epoch), (2, 4));
+    }
+}
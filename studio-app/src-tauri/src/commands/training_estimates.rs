@@ -0,0 +1,204 @@
+//! Closed-form size, memory, and time estimates for the default forge model.
+//!
+//! The parameter count mirrors `serve/default_model.py` exactly. Memory and
+//! time are order-of-magnitude estimates: activations follow the usual
+//! transformer rule of thumb and throughput assumes a fixed fraction of the
+//! device's published peak, which is enough to flag runs that are off by 10x.
+
+use crate::models::{FeasibilityTrainConfig, HardwareProfile};
+
+const DEFAULT_EPOCHS: u32 = 3;
+const DEFAULT_BATCH_SIZE: u32 = 16;
+const DEFAULT_MAX_TOKEN_LENGTH: u32 = 512;
+const DEFAULT_VALIDATION_SPLIT: f64 = 0.1;
+const DEFAULT_HIDDEN_DIM: u32 = 256;
+const DEFAULT_NUM_LAYERS: u32 = 2;
+const DEFAULT_ATTENTION_HEADS: u32 = 8;
+const DEFAULT_MLP_HIDDEN_DIM: u32 = 1024;
+const DEFAULT_MLP_LAYERS: u32 = 2;
+const DEFAULT_OPTIMIZER_TYPE: &str = "adam";
+const DEFAULT_POSITION_EMBEDDING_TYPE: &str = "learned";
+const FALLBACK_PRECISION_MODE: &str = "fp32";
+
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+/// fp32 master weights plus fp32 gradients.
+const WEIGHT_AND_GRAD_BYTES_PER_PARAM: f64 = 8.0;
+/// CUDA context, allocator slack, and kernels that no formula captures.
+const RUNTIME_OVERHEAD_BYTES: f64 = 0.5 * BYTES_PER_GB;
+/// Logits and their gradient are both kept in fp32 for the loss.
+const LOGIT_COPIES: f64 = 2.0;
+/// Fraction of peak throughput a small single-device training loop sustains.
+const MODEL_FLOPS_UTILIZATION: f64 = 0.3;
+/// Forward plus backward cost per parameter per token.
+const TRAINING_FLOPS_PER_PARAM_TOKEN: f64 = 6.0;
+/// fp32 matmuls run at roughly this fraction of half-precision peak.
+const FP32_THROUGHPUT_FACTOR: f64 = 0.25;
+
+/// Dense half-precision peak TFLOPS by GPU name fragment; more specific names first.
+const GPU_PEAK_TFLOPS: [(&str, f64); 8] = [
+    ("H100", 989.0),
+    ("A100", 312.0),
+    ("L40", 181.0),
+    ("4090", 165.0),
+    ("V100", 125.0),
+    ("A10", 125.0),
+    ("3090", 71.0),
+    ("T4", 65.0),
+];
+const UNKNOWN_CUDA_PEAK_TFLOPS: f64 = 30.0;
+const MPS_PEAK_TFLOPS: f64 = 10.0;
+const CPU_PEAK_TFLOPS: f64 = 1.0;
+
+/// Train options with CLI defaults applied and `auto` precision resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedTrainConfig {
+    pub epochs: u32,
+    pub batch_size: u32,
+    pub max_token_length: u32,
+    pub validation_split: f64,
+    pub hidden_dim: u32,
+    pub num_layers: u32,
+    pub attention_heads: u32,
+    pub mlp_hidden_dim: u32,
+    pub mlp_layers: u32,
+    pub vocabulary_cap: Option<u32>,
+    pub learned_positions: bool,
+    pub precision_mode: String,
+    pub optimizer_type: String,
+}
+
+pub(crate) fn resolve_train_config(
+    config: &FeasibilityTrainConfig,
+    profile: Option<&HardwareProfile>,
+) -> ResolvedTrainConfig {
+    let requested_precision = config.precision_mode.as_deref().unwrap_or("auto");
+    let precision_mode = match (requested_precision, profile) {
+        ("auto", Some(profile)) => profile.recommended_precision_mode.clone(),
+        ("auto", None) => FALLBACK_PRECISION_MODE.to_string(),
+        (mode, _) => mode.to_string(),
+    };
+    let position_type = config
+        .position_embedding_type
+        .as_deref()
+        .unwrap_or(DEFAULT_POSITION_EMBEDDING_TYPE);
+    ResolvedTrainConfig {
+        epochs: config.epochs.unwrap_or(DEFAULT_EPOCHS),
+        batch_size: config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
+        max_token_length: config.max_token_length.unwrap_or(DEFAULT_MAX_TOKEN_LENGTH),
+        validation_split: config.validation_split.unwrap_or(DEFAULT_VALIDATION_SPLIT),
+        hidden_dim: config.hidden_dim.unwrap_or(DEFAULT_HIDDEN_DIM),
+        num_layers: config.num_layers.unwrap_or(DEFAULT_NUM_LAYERS),
+        attention_heads: config.attention_heads.unwrap_or(DEFAULT_ATTENTION_HEADS),
+        mlp_hidden_dim: config.mlp_hidden_dim.unwrap_or(DEFAULT_MLP_HIDDEN_DIM),
+        mlp_layers: config.mlp_layers.unwrap_or(DEFAULT_MLP_LAYERS),
+        vocabulary_cap: config.vocabulary_size,
+        learned_positions: position_type == DEFAULT_POSITION_EMBEDDING_TYPE,
+        precision_mode,
+        optimizer_type: config
+            .optimizer_type
+            .clone()
+            .unwrap_or_else(|| DEFAULT_OPTIMIZER_TYPE.to_string()),
+    }
+}
+
+/// Embedding, positions, `TransformerEncoderLayer` stack, and MLP projection head.
+pub(crate) fn parameter_count(config: &ResolvedTrainConfig, vocabulary_size: u64) -> u64 {
+    let hidden = u64::from(config.hidden_dim);
+    let mlp = u64::from(config.mlp_hidden_dim);
+    let embedding = vocabulary_size * hidden;
+    let positions = if config.learned_positions {
+        u64::from(config.max_token_length) * hidden
+    } else {
+        0
+    };
+    // Attention in/out projections, two feed-forward linears, two layer norms.
+    let encoder_layer =
+        4 * hidden * hidden + 4 * hidden + 2 * hidden * mlp + mlp + hidden + 4 * hidden;
+    let head = if config.mlp_layers <= 1 {
+        hidden * vocabulary_size + vocabulary_size
+    } else {
+        let hidden_blocks = u64::from(config.mlp_layers - 2) * (mlp * mlp + mlp);
+        hidden * mlp + mlp + hidden_blocks + mlp * vocabulary_size + vocabulary_size
+    };
+    embedding + positions + u64::from(config.num_layers) * encoder_layer + head
+}
+
+/// Peak training memory for one full-length batch.
+pub(crate) fn estimate_memory_gb(
+    config: &ResolvedTrainConfig,
+    vocabulary_size: u64,
+    parameter_count: u64,
+) -> f64 {
+    let optimizer_bytes = match config.optimizer_type.as_str() {
+        "sgd" => 4.0,
+        _ => 8.0,
+    };
+    let state = parameter_count as f64 * (WEIGHT_AND_GRAD_BYTES_PER_PARAM + optimizer_bytes);
+    let tokens = f64::from(config.batch_size) * f64::from(config.max_token_length);
+    let half_precision = matches!(config.precision_mode.as_str(), "fp16" | "bf16");
+    let activation_scale = if half_precision { 1.0 } else { 2.0 };
+    // Per layer: 34*h bytes per token plus 5*heads*seq bytes of attention scores (16-bit).
+    let per_layer = tokens
+        * (34.0 * f64::from(config.hidden_dim)
+            + 5.0 * f64::from(config.attention_heads) * f64::from(config.max_token_length));
+    let activations = per_layer * f64::from(config.num_layers) * activation_scale;
+    let logits = tokens * vocabulary_size as f64 * 4.0 * LOGIT_COPIES;
+    (state + activations + logits + RUNTIME_OVERHEAD_BYTES) / BYTES_PER_GB
+}
+
+pub(crate) fn estimate_training_hours(
+    config: &ResolvedTrainConfig,
+    parameter_count: u64,
+    train_tokens_per_epoch: u64,
+    profile: Option<&HardwareProfile>,
+) -> f64 {
+    let total_flops = TRAINING_FLOPS_PER_PARAM_TOKEN
+        * parameter_count as f64
+        * train_tokens_per_epoch as f64
+        * f64::from(config.epochs);
+    let mut peak_tflops = profile.map_or(CPU_PEAK_TFLOPS, device_peak_tflops);
+    if !matches!(config.precision_mode.as_str(), "fp16" | "bf16") {
+        peak_tflops *= FP32_THROUGHPUT_FACTOR;
+    }
+    let sustained_flops_per_second = peak_tflops * 1e12 * MODEL_FLOPS_UTILIZATION;
+    total_flops / sustained_flops_per_second / 3600.0
+}
+
+/// Training runs on one device, so only the first GPU counts.
+fn device_peak_tflops(profile: &HardwareProfile) -> f64 {
+    match profile.accelerator.as_str() {
+        "cuda" => profile
+            .gpus
+            .first()
+            .and_then(|gpu| {
+                GPU_PEAK_TFLOPS
+                    .iter()
+                    .find(|(marker, _)| gpu.name.contains(marker))
+                    .map(|(_, tflops)| *tflops)
+            })
+            .unwrap_or(UNKNOWN_CUDA_PEAK_TFLOPS),
+        "mps" => MPS_PEAK_TFLOPS,
+        _ => CPU_PEAK_TFLOPS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parameter_count, resolve_train_config};
+    use crate::models::FeasibilityTrainConfig;
+
+    #[test]
+    fn parameter_count_matches_default_model_layout() {
+        let config = resolve_train_config(&FeasibilityTrainConfig::default(), None);
+        // Per encoder layer: 4h^2+4h attention, 2hm+m+h feed-forward, 4h layer norms.
+        // 256_000 embedding + 131_072 positions + 2 * 789_760 layers + 1_288_168 head.
+        assert_eq!(parameter_count(&config, 1000), 3_254_760);
+    }
+
+    #[test]
+    fn auto_precision_falls_back_to_fp32_without_a_profile() {
+        let config = resolve_train_config(&FeasibilityTrainConfig::default(), None);
+        assert_eq!(config.precision_mode, "fp32");
+        assert!(config.learned_positions);
+    }
+}
@@ -0,0 +1,180 @@
+//! Step-timing ETA for running `train` tasks.
+//!
+//! The CLI's structured logger prints `training_started` and
+//! `training_batch_progress` JSON events on stdout, which the task store
+//! already tails. Step rate comes from a rolling window of recent train
+//! batch events, so validation passes and warm-up are amortized rather than
+//! extrapolated. Without structlog the events go to stderr, which is only
+//! read at exit, and callers fall back to the command-level estimate.
+
+use crate::commands::forge_task_status::{task_status_name, TaskLifecycleStatus};
+use crate::models::CommandTaskStatus;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Recent progress events used for the step rate; older ones reflect stale throughput.
+const ROLLING_WINDOW_EVENTS: usize = 8;
+const TRAIN_PHASE: &str = "train";
+const TRAIN_COMMAND: &str = "train";
+pub(crate) const COMMAND_AVERAGE_ETA_SOURCE: &str = "command_average";
+const STEP_TIMING_ETA_SOURCE: &str = "step_timing";
+
+#[derive(Debug, PartialEq)]
+struct TrainingEta {
+    pub completed_steps: u64,
+    pub total_steps: u64,
+    pub seconds_per_step: f64,
+    pub remaining_seconds: u64,
+}
+
+#[derive(Default)]
+struct ProgressPlan {
+    start_epoch: u64,
+    total_epochs: u64,
+    train_batches: u64,
+}
+
+/// Replaces the command-average estimate of a running train task once step timing is known.
+pub(crate) fn apply_step_timing_eta(task_status: &mut CommandTaskStatus, max_progress: f64) {
+    if task_status.command != TRAIN_COMMAND
+        || task_status.status != task_status_name(TaskLifecycleStatus::Running)
+    {
+        return;
+    }
+    let Some(eta) = estimate_training_eta(&task_status.stdout, Utc::now()) else {
+        return;
+    };
+    let step_fraction = eta.completed_steps as f64 / eta.total_steps.max(1) as f64;
+    task_status.remaining_seconds = eta.remaining_seconds;
+    task_status.estimated_total_seconds = task_status.elapsed_seconds + eta.remaining_seconds;
+    task_status.progress_percent = (step_fraction * 100.0).clamp(1.0, max_progress);
+    task_status.eta_source = STEP_TIMING_ETA_SOURCE.to_string();
+    task_status.completed_steps = Some(eta.completed_steps);
+    task_status.total_steps = Some(eta.total_steps);
+    task_status.seconds_per_step = Some(eta.seconds_per_step);
+}
+
+/// `None` until at least two timed train batch events have been logged.
+fn estimate_training_eta(stdout: &str, now: DateTime<Utc>) -> Option<TrainingEta> {
+    let mut plan = ProgressPlan::default();
+    let mut points: Vec<(DateTime<Utc>, u64)> = Vec::new();
+    for event in stdout.lines().filter_map(parse_event_line) {
+        match event.get("event").and_then(Value::as_str) {
+            Some("training_started") => {
+                plan = ProgressPlan {
+                    start_epoch: u64_field(&event, "start_epoch").unwrap_or(1),
+                    total_epochs: u64_field(&event, "total_epochs").unwrap_or(0),
+                    train_batches: u64_field(&event, "train_batches").unwrap_or(0),
+                };
+                points.clear();
+            }
+            Some("training_batch_progress") => {
+                if let Some(point) = progress_point(&event, &mut plan) {
+                    points.push(point);
+                }
+            }
+            _ => {}
+        }
+    }
+    let window = &points[points.len().saturating_sub(ROLLING_WINDOW_EVENTS)..];
+    let (first_at, first_step) = *window.first()?;
+    let (last_at, last_step) = *window.last()?;
+    if last_step <= first_step {
+        return None;
+    }
+    let window_seconds = (last_at - first_at).num_milliseconds() as f64 / 1000.0;
+    let seconds_per_step = window_seconds / (last_step - first_step) as f64;
+    let epochs_in_run = (plan.total_epochs + 1).saturating_sub(plan.start_epoch.max(1));
+    let total_steps = (epochs_in_run * plan.train_batches).max(last_step);
+    let since_last_event = (now - last_at).num_seconds().max(0) as f64;
+    let remaining = (total_steps - last_step) as f64 * seconds_per_step - since_last_event;
+    Some(TrainingEta {
+        completed_steps: last_step,
+        total_steps,
+        seconds_per_step,
+        remaining_seconds: remaining.max(0.0).round() as u64,
+    })
+}
+
+/// Maps a train batch event to (timestamp, steps completed in this process).
+fn progress_point(event: &Value, plan: &mut ProgressPlan) -> Option<(DateTime<Utc>, u64)> {
+    if event.get("phase").and_then(Value::as_str) != Some(TRAIN_PHASE) {
+        return None;
+    }
+    let timestamp = event.get("timestamp").and_then(Value::as_str)?;
+    let at = DateTime::parse_from_rfc3339(timestamp)
+        .ok()?
+        .with_timezone(&Utc);
+    let epoch = u64_field(event, "epoch")?;
+    let batch = u64_field(event, "batch")?;
+    let total_batches = u64_field(event, "total_batches")?;
+    // Output from before `training_started` was tailed still carries the plan.
+    if plan.train_batches == 0 {
+        plan.train_batches = total_batches;
+        plan.total_epochs = u64_field(event, "total_epochs").unwrap_or(epoch);
+        plan.start_epoch = 1;
+    }
+    let epochs_done = epoch.saturating_sub(plan.start_epoch.max(1));
+    Some((at, epochs_done * total_batches + batch))
+}
+
+fn parse_event_line(line: &str) -> Option<Value> {
+    let start = line.find('{')?;
+    serde_json::from_str(&line[start..]).ok()
+}
+
+fn u64_field(event: &Value, key: &str) -> Option<u64> {
+    event.get(key).and_then(Value::as_u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::estimate_training_eta;
+    use chrono::{DateTime, Utc};
+
+    fn batch_line(epoch: u64, batch: u64, phase: &str, timestamp: &str) -> String {
+        format!(
+            concat!(
+                r#"{{"event": "training_batch_progress", "phase": "{}", "epoch": {}, "#,
+                r#""total_epochs": 2, "batch": {}, "total_batches": 100, "timestamp": "{}"}}"#
+            ),
+            phase, epoch, batch, timestamp
+        )
+    }
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp)
+            .expect("timestamp should parse")
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn eta_uses_recent_train_step_rate() {
+        let stdout = [
+            concat!(
+                r#"{"event": "training_started", "start_epoch": 1, "total_epochs": 2, "#,
+                r#""train_batches": 100}"#
+            )
+            .to_string(),
+            batch_line(1, 10, "train", "2026-01-01T00:00:00Z"),
+            batch_line(1, 5, "validation", "2026-01-01T00:00:05Z"),
+            batch_line(1, 30, "train", "2026-01-01T00:00:40Z"),
+        ]
+        .join("\n");
+        let eta = estimate_training_eta(&stdout, at("2026-01-01T00:00:50Z"))
+            .expect("two train events give an eta");
+        assert_eq!(eta.completed_steps, 30);
+        assert_eq!(eta.total_steps, 200);
+        assert_eq!(eta.seconds_per_step, 2.0);
+        assert_eq!(eta.remaining_seconds, 170 * 2 - 10);
+    }
+
+    #[test]
+    fn eta_is_unknown_with_a_single_progress_event() {
+        let stdout = batch_line(1, 10, "train", "2026-01-01T00:00:00Z");
+        assert_eq!(
+            estimate_training_eta(&stdout, at("2026-01-01T00:00:10Z")),
+            None
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Pre-flight check that flags training runs that will obviously fail or drag.
+//!
+//! The dataset is tokenized the way `serve/tokenization.py` does it
+//! (lowercase whitespace tokens) so vocabulary and token counts are exact;
+//! model size, memory, and duration then come from `training_estimates`.
+//! Only gross problems are reported, since the estimates are rough.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::dataset_store_io::{read_records, resolve_version};
+use crate::commands::hardware_profile::{load_cached_hardware_profile, HardwareProfileCache};
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::commands::training_estimates::{
+    estimate_memory_gb, estimate_training_hours, parameter_count, resolve_train_config,
+    ResolvedTrainConfig,
+};
+use crate::models::{
+    FeasibilityWarning, ForgeStudioError, HardwareProfile, TrainingFeasibilityReport,
+    TrainingFeasibilityRequest,
+};
+use serde_json::Value;
+use std::collections::HashSet;
+use tauri::State;
+
+/// Pad and unknown tokens the forge tokenizer always reserves.
+const SPECIAL_TOKEN_COUNT: u64 = 2;
+const NEAR_MEMORY_LIMIT_FRACTION: f64 = 0.9;
+const LONG_RUNTIME_HOURS: f64 = 7.0 * 24.0;
+const BLOCKING_SEVERITY: &str = "blocking";
+const WARNING_SEVERITY: &str = "warning";
+
+struct CorpusStats {
+    record_count: u64,
+    train_tokens_per_epoch: u64,
+    vocabulary_size: u64,
+}
+
+struct Estimates<'a> {
+    config: &'a ResolvedTrainConfig,
+    profile: Option<&'a HardwareProfile>,
+    hardware_error: Option<&'a ForgeStudioError>,
+    memory_gb: f64,
+    available_memory_gb: Option<f64>,
+    training_hours: f64,
+}
+
+#[tauri::command]
+pub fn check_training_feasibility(
+    data_root: String,
+    request: TrainingFeasibilityRequest,
+    hardware_cache: State<'_, HardwareProfileCache>,
+    settings: State<'_, SettingsStore>,
+) -> Result<TrainingFeasibilityReport, ForgeStudioError> {
+    let version_id = resolve_version(&data_root, &request.dataset_name, request.version_id)?;
+    let records = read_records(&data_root, &request.dataset_name, &version_id)?;
+    let hardware = load_cached_hardware_profile(
+        resolve_data_root_path(&data_root, &settings.workspace_roots())?,
+        &hardware_cache,
+        &settings.current().forge_binary,
+    );
+    let profile = hardware.as_ref().ok();
+    let hardware_error = hardware.as_ref().err();
+    let config = resolve_train_config(&request.train_config, profile);
+    let corpus = scan_corpus(&records, &config);
+    let parameters = parameter_count(&config, corpus.vocabulary_size);
+    let estimates = Estimates {
+        config: &config,
+        profile,
+        hardware_error,
+        memory_gb: estimate_memory_gb(&config, corpus.vocabulary_size, parameters),
+        available_memory_gb: profile
+            .filter(|profile| profile.accelerator == "cuda")
+            .and_then(|profile| profile.gpus.first())
+            .map(|gpu| gpu.total_memory_gb),
+        training_hours: estimate_training_hours(
+            &config,
+            parameters,
+            corpus.train_tokens_per_epoch,
+            profile,
+        ),
+    };
+    let warnings = collect_warnings(&estimates);
+    Ok(TrainingFeasibilityReport {
+        dataset_name: request.dataset_name,
+        version_id,
+        record_count: corpus.record_count,
+        train_tokens_per_epoch: corpus.train_tokens_per_epoch,
+        vocabulary_size: corpus.vocabulary_size,
+        parameter_count: parameters,
+        precision_mode: config.precision_mode.clone(),
+        accelerator: profile.map_or_else(|| "cpu".to_string(), |p| p.accelerator.clone()),
+        estimated_memory_gb: estimates.memory_gb,
+        available_memory_gb: estimates.available_memory_gb,
+        estimated_training_hours: estimates.training_hours,
+        feasible: warnings.iter().all(|row| row.severity != BLOCKING_SEVERITY),
+        warnings,
+    })
+}
+
+fn scan_corpus(records: &[Value], config: &ResolvedTrainConfig) -> CorpusStats {
+    let vocabulary_cap = config.vocabulary_cap.map(u64::from);
+    let mut vocabulary: HashSet<String> = HashSet::new();
+    let mut total_tokens = 0_u64;
+    for text in records
+        .iter()
+        .filter_map(|record| record.get("text")?.as_str())
+    {
+        let lowered = text.to_lowercase();
+        let tokens: Vec<&str> = lowered.split_whitespace().collect();
+        total_tokens += tokens.len().min(config.max_token_length as usize) as u64;
+        let vocabulary_full = |size: usize| {
+            vocabulary_cap.is_some_and(|cap| size as u64 + SPECIAL_TOKEN_COUNT >= cap)
+        };
+        for token in tokens {
+            if !vocabulary_full(vocabulary.len()) {
+                vocabulary.insert(token.to_string());
+            }
+        }
+    }
+    let train_fraction = (1.0 - config.validation_split).clamp(0.0, 1.0);
+    CorpusStats {
+        record_count: records.len() as u64,
+        train_tokens_per_epoch: (total_tokens as f64 * train_fraction).round() as u64,
+        vocabulary_size: vocabulary.len() as u64 + SPECIAL_TOKEN_COUNT,
+    }
+}
+
+fn collect_warnings(estimates: &Estimates<'_>) -> Vec<FeasibilityWarning> {
+    let mut warnings = Vec::new();
+    if let Some(error) = estimates.hardware_error {
+        warnings.push(warning(
+            "hardware_unknown",
+            WARNING_SEVERITY,
+            format!("Hardware profile unavailable ({error}); estimates assume CPU-only training."),
+        ));
+    }
+    warnings.extend(memory_warning(estimates));
+    warnings.extend(device_warnings(estimates));
+    if estimates.training_hours > LONG_RUNTIME_HOURS {
+        warnings.push(warning(
+            "long_runtime",
+            WARNING_SEVERITY,
+            format!(
+                "Estimated training time is {:.1} days. \
+                 Reduce epochs or model size, or train on a filtered dataset version.",
+                estimates.training_hours / 24.0
+            ),
+        ));
+    }
+    warnings
+}
+
+/// Peak memory over, or close to, the device's memory.
+fn memory_warning(estimates: &Estimates<'_>) -> Option<FeasibilityWarning> {
+    let available = estimates.available_memory_gb?;
+    if estimates.memory_gb > available {
+        return Some(warning(
+            "out_of_memory",
+            BLOCKING_SEVERITY,
+            format!(
+                "Estimated peak memory {:.1} GB exceeds the {available:.1} GB GPU. \
+                 Lower batch_size or max_token_length, or train in bf16/fp16.",
+                estimates.memory_gb
+            ),
+        ));
+    }
+    (estimates.memory_gb > available * NEAR_MEMORY_LIMIT_FRACTION).then(|| {
+        warning(
+            "near_memory_limit",
+            WARNING_SEVERITY,
+            format!(
+                "Estimated peak memory {:.1} GB is within 10% of the {available:.1} GB GPU. \
+                 Lower batch_size if the run hits out-of-memory errors.",
+                estimates.memory_gb
+            ),
+        )
+    })
+}
+
+/// Precision and accelerator problems with the detected device.
+fn device_warnings(estimates: &Estimates<'_>) -> Vec<FeasibilityWarning> {
+    let mut warnings = Vec::new();
+    let bf16_unsupported = estimates
+        .profile
+        .is_some_and(|profile| !profile.bf16_supported);
+    if estimates.config.precision_mode == "bf16" && bf16_unsupported {
+        warnings.push(warning(
+            "bf16_unsupported",
+            BLOCKING_SEVERITY,
+            "This device does not support bf16. Use fp16 or auto precision.".to_string(),
+        ));
+    }
+    if estimates
+        .profile
+        .is_some_and(|profile| profile.accelerator == "cpu")
+    {
+        warnings.push(warning(
+            "cpu_only",
+            WARNING_SEVERITY,
+            "No accelerator detected; training will run on the CPU.".to_string(),
+        ));
+    }
+    warnings
+}
+
+fn warning(code: &str, severity: &str, message: String) -> FeasibilityWarning {
+    FeasibilityWarning {
+        code: code.to_string(),
+        severity: severity.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scan_corpus;
+    use crate::commands::training_estimates::resolve_train_config;
+    use crate::models::FeasibilityTrainConfig;
+    use serde_json::json;
+
+    #[test]
+    fn scan_corpus_counts_truncated_tokens_and_capped_vocabulary() {
+        let train_config = FeasibilityTrainConfig {
+            max_token_length: Some(3),
+            validation_split: Some(0.0),
+            vocabulary_size: Some(4),
+            ..FeasibilityTrainConfig::default()
+        };
+        let config = resolve_train_config(&train_config, None);
+        let records = vec![
+            json!({"text": "The cat sat down"}),
+            json!({"text": "the dog"}),
+        ];
+        let stats = scan_corpus(&records, &config);
+        assert_eq!(stats.record_count, 2);
+        assert_eq!(stats.train_tokens_per_epoch, 5);
+        assert_eq!(stats.vocabulary_size, 4);
+    }
+}
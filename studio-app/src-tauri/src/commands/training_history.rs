@@ -0,0 +1,72 @@
+//! Training history loading for the loss charts.
+//!
+//! Histories are read whole, then batch losses are optionally smoothed and
+//! downsampled so long runs stay cheap to ship over IPC and draw.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::history_downsampling::{downsample_indices, MIN_DOWNSAMPLE_POINTS};
+use crate::commands::history_smoothing::ema_train_loss;
+use crate::commands::path_safety::confine_path;
+use crate::models::{ForgeStudioError, SmoothedLossPoint, TrainingHistory};
+use std::fs;
+use std::path::Path;
+use tauri::State;
+
+/// `max_points` caps `batch_losses` for charting; epochs are never downsampled.
+/// `smoothing_window` adds an EMA series computed before downsampling.
+#[tauri::command]
+pub fn load_training_history(
+    history_path: String,
+    max_points: Option<usize>,
+    smoothing_window: Option<usize>,
+    settings: State<'_, SettingsStore>,
+) -> Result<TrainingHistory, ForgeStudioError> {
+    if max_points.is_some_and(|limit| limit < MIN_DOWNSAMPLE_POINTS) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "max_points must be at least {MIN_DOWNSAMPLE_POINTS}. Omit it to load every batch loss."
+        )));
+    }
+    if smoothing_window == Some(0) {
+        return Err(ForgeStudioError::invalid_input(
+            "smoothing_window must be at least 1. Omit it to skip smoothing.",
+        ));
+    }
+    let history_path = confine_path(
+        Path::new(&history_path),
+        "History file",
+        &settings.workspace_roots(),
+    )?;
+    let payload = fs::read_to_string(&history_path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read history file {}: {error}",
+            history_path.display()
+        ))
+    })?;
+    let mut history: TrainingHistory = serde_json::from_str(&payload).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse history file {}: {error}",
+            history_path.display()
+        ))
+    })?;
+    let kept = match max_points {
+        Some(limit) => downsample_indices(&history.batch_losses, limit),
+        None => (0..history.batch_losses.len()).collect(),
+    };
+    if let Some(window) = smoothing_window {
+        let smoothed = ema_train_loss(&history.batch_losses, window);
+        history.smoothed_batch_losses = kept
+            .iter()
+            .map(|&index| SmoothedLossPoint {
+                global_step: history.batch_losses[index].global_step,
+                train_loss: smoothed[index],
+            })
+            .collect();
+    }
+    if kept.len() < history.batch_losses.len() {
+        history.batch_losses = kept
+            .iter()
+            .map(|&index| history.batch_losses[index].clone())
+            .collect();
+    }
+    Ok(history)
+}
@@ -0,0 +1,296 @@
+//! Opt-in, anonymous usage telemetry about which Studio features are used.
+//!
+//! Nothing is recorded until `telemetry.enabled` is set. While it is, each
+//! command call adds to a per-command count and to the time the call held the
+//! IPC thread, which for async commands is only their dispatch. Nothing else
+//! is kept: no arguments, paths, results, or user or machine identifiers.
+//! Aggregates are flushed to the app data directory every few minutes so they
+//! survive restarts, and posted to `telemetry.upload_url` once a day, along
+//! with the Studio version and OS; a successful upload starts a new period.
+//! Turning telemetry off discards whatever was not uploaded yet.
+//! `get_usage_telemetry` shows exactly what the next upload would send.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::run_store_io::{ensure_dir, read_json_file, write_json_file};
+use crate::models::{CommandUsage, ForgeStudioError, UsageTelemetry};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::ipc::Invoke;
+use tauri::State;
+
+const USAGE_FILE_NAME: &str = "usage_telemetry.json";
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const UPLOAD_INTERVAL_HOURS: i64 = 24;
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Usage aggregated since the last upload, shared by the invoke handler.
+#[derive(Clone, Default)]
+pub struct UsageTelemetryStore {
+    inner: Arc<Mutex<UsageState>>,
+}
+
+#[derive(Default)]
+struct UsageState {
+    /// Unset until `load` runs during app setup.
+    path: Option<PathBuf>,
+    period: UsagePeriod,
+    /// Whether `period` changed since it was last written.
+    dirty: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct UsagePeriod {
+    period_start: String,
+    last_uploaded_at: Option<String>,
+    commands: BTreeMap<String, CommandUsage>,
+}
+
+impl Default for UsagePeriod {
+    fn default() -> Self {
+        Self {
+            period_start: timestamp(Utc::now()),
+            last_uploaded_at: None,
+            commands: BTreeMap::new(),
+        }
+    }
+}
+
+impl UsageTelemetryStore {
+    /// Reads usage saved in `data_dir` by an earlier launch. The error
+    /// describes an unreadable file; an empty period starts anyway.
+    pub fn load(&self, data_dir: &Path) -> Result<(), ForgeStudioError> {
+        let path = data_dir.join(USAGE_FILE_NAME);
+        let (period, outcome) = if path.is_file() {
+            match read_json_file(&path).and_then(|payload| {
+                serde_json::from_value(payload)
+                    .map_err(|error| ForgeStudioError::parse(error.to_string()))
+            }) {
+                Ok(period) => (period, Ok(())),
+                Err(error) => (
+                    UsagePeriod::default(),
+                    Err(ForgeStudioError::parse(format!(
+                        "Ignoring unreadable usage telemetry file {}: {error}. It is replaced at the next flush.",
+                        path.display()
+                    ))),
+                ),
+            }
+        } else {
+            (UsagePeriod::default(), Ok(()))
+        };
+        if let Ok(mut state) = self.inner.lock() {
+            *state = UsageState {
+                path: Some(path),
+                period,
+                dirty: false,
+            };
+        }
+        outcome
+    }
+
+    fn record(&self, command: &str, elapsed: Duration) {
+        let millis = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        if let Ok(mut state) = self.inner.lock() {
+            let usage = state
+                .period
+                .commands
+                .entry(command.to_string())
+                .or_default();
+            usage.call_count += 1;
+            usage.total_millis = usage.total_millis.saturating_add(millis);
+            usage.max_millis = usage.max_millis.max(millis);
+            state.dirty = true;
+        }
+    }
+
+    fn period(&self) -> UsagePeriod {
+        self.inner
+            .lock()
+            .map(|state| state.period.clone())
+            .unwrap_or_default()
+    }
+
+    /// Writes the period if it changed since the last flush.
+    fn flush(&self) -> Result<(), ForgeStudioError> {
+        let mut state = self.inner.lock().map_err(|_| {
+            ForgeStudioError::internal("Usage telemetry lock poisoned. Restart Studio.")
+        })?;
+        let Some(path) = state.path.clone().filter(|_| state.dirty) else {
+            return Ok(());
+        };
+        if let Some(parent_dir) = path.parent() {
+            ensure_dir(parent_dir)?;
+        }
+        let payload = serde_json::to_value(&state.period).map_err(|error| {
+            ForgeStudioError::internal(format!("Failed to serialize usage telemetry: {error}"))
+        })?;
+        write_json_file(&path, &payload)?;
+        state.dirty = false;
+        Ok(())
+    }
+
+    /// Starts a new period, dropping the recorded usage.
+    fn reset(&self, last_uploaded_at: Option<String>) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.period = UsagePeriod {
+                last_uploaded_at,
+                ..UsagePeriod::default()
+            };
+            state.dirty = true;
+        }
+    }
+}
+
+/// Wraps the generated command handler so each call is recorded while
+/// telemetry is enabled.
+pub fn with_usage_telemetry(
+    usage_store: UsageTelemetryStore,
+    settings: SettingsStore,
+    handler: impl Fn(Invoke) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        if !settings.current().telemetry.enabled {
+            return handler(invoke);
+        }
+        let command = invoke.message.command().to_string();
+        let started = Instant::now();
+        let handled = handler(invoke);
+        usage_store.record(&command, started.elapsed());
+        handled
+    }
+}
+
+#[tauri::command]
+pub fn get_usage_telemetry(
+    usage_store: State<'_, UsageTelemetryStore>,
+    settings: State<'_, SettingsStore>,
+) -> Result<UsageTelemetry, ForgeStudioError> {
+    let telemetry = settings.current().telemetry;
+    let period = usage_store.period();
+    Ok(UsageTelemetry {
+        enabled: telemetry.enabled,
+        upload_url: telemetry.upload_url,
+        period_start: period.period_start,
+        last_uploaded_at: period.last_uploaded_at,
+        commands: period.commands,
+    })
+}
+
+/// Flushes usage every few minutes and uploads it once a day while enabled.
+pub fn spawn_usage_uploader(usage_store: UsageTelemetryStore, settings: SettingsStore) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(FLUSH_INTERVAL);
+        let telemetry = settings.current().telemetry;
+        if !telemetry.enabled {
+            if !usage_store.period().commands.is_empty() {
+                usage_store.reset(None);
+            }
+        } else if let Some(upload_url) = telemetry.upload_url {
+            if let Err(error) = upload_if_due(&usage_store, &upload_url) {
+                tracing::warn!("{error}");
+            }
+        }
+        if let Err(error) = usage_store.flush() {
+            tracing::warn!("{error}");
+        }
+    });
+}
+
+fn upload_if_due(
+    usage_store: &UsageTelemetryStore,
+    upload_url: &str,
+) -> Result<(), ForgeStudioError> {
+    let period = usage_store.period();
+    let now = Utc::now();
+    let since = period
+        .last_uploaded_at
+        .as_deref()
+        .unwrap_or(&period.period_start);
+    let due = DateTime::parse_from_rfc3339(since).map_or(true, |since| {
+        now.signed_duration_since(since).num_hours() >= UPLOAD_INTERVAL_HOURS
+    });
+    if !due || period.commands.is_empty() {
+        return Ok(());
+    }
+    ureq::post(upload_url)
+        .timeout(UPLOAD_TIMEOUT)
+        .send_json(upload_payload(&period, now))
+        .map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to upload usage telemetry to {upload_url}: {error}. Retrying later."
+            ))
+        })?;
+    usage_store.reset(Some(timestamp(now)));
+    Ok(())
+}
+
+/// The anonymized body of an upload: versions and per-command aggregates.
+fn upload_payload(period: &UsagePeriod, now: DateTime<Utc>) -> Value {
+    json!({
+        "studio_version": env!("CARGO_PKG_VERSION"),
+        "os": std::env::consts::OS,
+        "period_start": period.period_start,
+        "period_end": timestamp(now),
+        "commands": period.commands,
+    })
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{upload_payload, UsageTelemetryStore};
+    use chrono::Utc;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn usage_aggregates_persist_and_upload_without_identifiers() {
+        let data_dir =
+            std::env::temp_dir().join(format!("forge-usage-telemetry-{}", std::process::id()));
+        let store = UsageTelemetryStore::default();
+        store
+            .load(&data_dir)
+            .expect("missing file should start empty");
+        store.record("list_datasets", Duration::from_millis(30));
+        store.record("list_datasets", Duration::from_millis(10));
+        store.record("get_settings", Duration::from_millis(1));
+        store.flush().expect("flush should write");
+
+        let reloaded = UsageTelemetryStore::default();
+        reloaded.load(&data_dir).expect("saved file should load");
+        let period = reloaded.period();
+        let usage = &period.commands["list_datasets"];
+        assert_eq!(
+            (usage.call_count, usage.total_millis, usage.max_millis),
+            (2, 40, 30)
+        );
+        let payload = upload_payload(&period, Utc::now());
+        let mut keys: Vec<&str> = payload
+            .as_object()
+            .expect("payload should be an object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "commands",
+                "os",
+                "period_end",
+                "period_start",
+                "studio_version"
+            ]
+        );
+        reloaded.reset(Some("2026-01-01T00:00:00Z".to_string()));
+        assert!(reloaded.period().commands.is_empty());
+        fs::remove_dir_all(&data_dir).expect("temp dir should be removed");
+    }
+}
@@ -0,0 +1,179 @@
+//! Lazily computed duplicate-rate statistics for dataset versions.
+//!
+//! Computing a rate hashes every record of the version, so version listings
+//! only report rates already cached and `get_version_duplicate_rate` computes
+//! one on the blocking pool when the UI asks for it. Snapshot versions are
+//! immutable, so a rate is computed once and cached under the data root's
+//! `index` cache folder rather than inside the snapshot, except in read-only
+//! mode, which never writes to the data root.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::atomic_write::write_file_atomic;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::dataset_store_io::read_records;
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::ForgeStudioError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Per-version stats files, `<dataset>/<version_id>.json`, in the cache folder
+/// the metadata index also lives in; backups skip it.
+const DUPLICATE_STATS_DIR: &str = "index/duplicate_stats";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DuplicateStats {
+    record_count: u64,
+    unique_record_count: u64,
+    duplicate_rate: f64,
+}
+
+/// Reads every record of the version on a cache miss, so it runs on the blocking pool.
+#[tauri::command]
+pub async fn get_version_duplicate_rate(
+    data_root: String,
+    dataset_name: String,
+    version_id: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<f64, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    let read_only = settings.current().read_only;
+    run_blocking(move || {
+        let data_root = resolve_data_root_text(&data_root, &roots)?;
+        load_or_compute_duplicate_rate(&data_root, &dataset_name, &version_id, read_only)
+    })
+    .await
+}
+
+/// The version's duplicate rate if an earlier computation cached it.
+pub(crate) fn cached_duplicate_rate(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: &str,
+) -> Option<f64> {
+    read_cached_stats(&stats_path(data_root, dataset_name, version_id))
+        .map(|stats| stats.duplicate_rate)
+}
+
+/// Returns the version's duplicate rate, computing and caching it on first access.
+pub(crate) fn load_or_compute_duplicate_rate(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: &str,
+    read_only: bool,
+) -> Result<f64, ForgeStudioError> {
+    let stats_path = stats_path(data_root, dataset_name, version_id);
+    if let Some(cached) = read_cached_stats(&stats_path) {
+        return Ok(cached.duplicate_rate);
+    }
+    let records = read_records(data_root, dataset_name, version_id)?;
+    let stats = compute_duplicate_stats(&records)?;
+    // Read-only mode recomputes the rate on each access instead of caching it.
+    if !read_only {
+        write_cached_stats(&stats_path, &stats)?;
+    }
+    Ok(stats.duplicate_rate)
+}
+
+fn compute_duplicate_stats(records: &[Value]) -> Result<DuplicateStats, ForgeStudioError> {
+    // `record_id` is the normalized-text content hash assigned at ingest, so
+    // repeated ids are exact duplicates after normalization.
+    let mut content_hashes = HashSet::with_capacity(records.len());
+    for record in records {
+        let content_hash = record
+            .get("record_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ForgeStudioError::parse("Record is missing record_id"))?;
+        content_hashes.insert(content_hash);
+    }
+    let record_count = records.len() as u64;
+    let unique_record_count = content_hashes.len() as u64;
+    let duplicate_rate = if record_count == 0 {
+        0.0
+    } else {
+        (record_count - unique_record_count) as f64 / record_count as f64
+    };
+    Ok(DuplicateStats {
+        record_count,
+        unique_record_count,
+        duplicate_rate,
+    })
+}
+
+fn stats_path(data_root: &str, dataset_name: &str, version_id: &str) -> PathBuf {
+    Path::new(data_root)
+        .join(DUPLICATE_STATS_DIR)
+        .join(dataset_name)
+        .join(format!("{version_id}.json"))
+}
+
+fn read_cached_stats(stats_path: &Path) -> Option<DuplicateStats> {
+    // A missing or unreadable cache is not an error: the stats are recomputed.
+    let payload = fs::read_to_string(stats_path).ok()?;
+    serde_json::from_str(&payload).ok()
+}
+
+fn write_cached_stats(stats_path: &Path, stats: &DuplicateStats) -> Result<(), ForgeStudioError> {
+    let serialized = serde_json::to_string_pretty(stats).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize duplicate stats: {error}"))
+    })?;
+    if let Some(stats_dir) = stats_path.parent() {
+        fs::create_dir_all(stats_dir).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to create {}: {error}", stats_dir.display()))
+        })?;
+    }
+    write_file_atomic(stats_path, serialized.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cached_duplicate_rate, compute_duplicate_stats, load_or_compute_duplicate_rate};
+    use crate::commands::dataset_store_io::{records_path, version_dir};
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn compute_duplicate_stats_counts_repeated_content_hashes() {
+        let records = vec![
+            json!({"record_id": "a"}),
+            json!({"record_id": "b"}),
+            json!({"record_id": "a"}),
+            json!({"record_id": "a"}),
+        ];
+        let stats = compute_duplicate_stats(&records).expect("stats should compute");
+        assert_eq!(stats.duplicate_rate, 0.5);
+    }
+
+    #[test]
+    fn compute_duplicate_stats_handles_empty_versions() {
+        let stats = compute_duplicate_stats(&[]).expect("stats should compute");
+        assert_eq!(stats.duplicate_rate, 0.0);
+    }
+
+    #[test]
+    fn duplicate_rates_are_cached_outside_the_snapshot() {
+        let data_root =
+            std::env::temp_dir().join(format!("forge-version-duplicates-{}", std::process::id()));
+        let data_root = data_root.display().to_string();
+        let records = records_path(&data_root, "demo", "v1");
+        fs::create_dir_all(version_dir(&data_root, "demo", "v1")).expect("version dir created");
+        fs::write(&records, "{\"record_id\":\"a\"}\n{\"record_id\":\"a\"}\n")
+            .expect("records written");
+
+        assert_eq!(cached_duplicate_rate(&data_root, "demo", "v1"), None);
+        let rate = load_or_compute_duplicate_rate(&data_root, "demo", "v1", true)
+            .expect("rate should compute");
+        assert_eq!(rate, 0.5);
+        assert_eq!(cached_duplicate_rate(&data_root, "demo", "v1"), None);
+        load_or_compute_duplicate_rate(&data_root, "demo", "v1", false).expect("rate cached");
+        assert_eq!(cached_duplicate_rate(&data_root, "demo", "v1"), Some(0.5));
+        let snapshot_files = fs::read_dir(version_dir(&data_root, "demo", "v1"))
+            .expect("version dir readable")
+            .count();
+        assert_eq!(snapshot_files, 1);
+        fs::remove_dir_all(&data_root).expect("temp dir should be removed");
+    }
+}
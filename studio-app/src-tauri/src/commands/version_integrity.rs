@@ -0,0 +1,252 @@
+//! Integrity verification for immutable dataset versions.
+//!
+//! Detects partial writes (malformed lines, record count drift), tampered
+//! records (text no longer matching its content-hash id), duplicate ids, and
+//! checksum drift against the value recorded in `catalog.json` when the
+//! version was written. Versions written before checksums were recorded
+//! skip that check.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::content_hash::{build_record_id, sha256_hex};
+use crate::commands::dataset_store_io::{
+    dataset_root, read_catalog, records_path, resolve_version,
+};
+use crate::commands::run_store_io::resolve_data_root_text;
+use crate::models::{ForgeStudioError, VersionIntegrityReport};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use tauri::State;
+
+pub(crate) const CATALOG_CHECKSUM_FIELD: &str = "content_checksum";
+const MAX_REPORTED_IDS: usize = 50;
+
+#[tauri::command]
+pub fn verify_version_integrity(
+    data_root: String,
+    dataset_name: String,
+    version_id: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<VersionIntegrityReport, ForgeStudioError> {
+    let data_root = resolve_data_root_text(&data_root, &settings.workspace_roots())?;
+    version_integrity_report(&data_root, dataset_name, version_id)
+}
+
+/// `data_root` is already resolved.
+fn version_integrity_report(
+    data_root: &str,
+    dataset_name: String,
+    version_id: Option<String>,
+) -> Result<VersionIntegrityReport, ForgeStudioError> {
+    let selected_version = resolve_version(data_root, &dataset_name, version_id)?;
+    let catalog_entry = find_catalog_entry(data_root, &dataset_name, &selected_version)?;
+    let path = records_path(data_root, &dataset_name, &selected_version);
+    let payload = fs::read(&path).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read records {}: {error}",
+            path.display()
+        ))
+    })?;
+    let mut report = VersionIntegrityReport {
+        dataset_name,
+        version_id: selected_version,
+        is_valid: true,
+        expected_record_count: catalog_entry.get("record_count").and_then(Value::as_u64),
+        actual_record_count: 0,
+        malformed_lines: Vec::new(),
+        duplicate_record_ids: Vec::new(),
+        tampered_record_ids: Vec::new(),
+        recorded_checksum: catalog_entry
+            .get(CATALOG_CHECKSUM_FIELD)
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        computed_checksum: sha256_hex(&payload),
+        issues: Vec::new(),
+    };
+    inspect_record_lines(&String::from_utf8_lossy(&payload), &mut report);
+    collect_issues(&mut report);
+    Ok(report)
+}
+
+fn find_catalog_entry(
+    data_root: &str,
+    dataset_name: &str,
+    version_id: &str,
+) -> Result<Value, ForgeStudioError> {
+    let catalog = read_catalog(&dataset_root(data_root, dataset_name))?;
+    catalog
+        .get("versions")
+        .and_then(Value::as_array)
+        .and_then(|versions| {
+            versions
+                .iter()
+                .find(|entry| entry.get("version_id").and_then(Value::as_str) == Some(version_id))
+        })
+        .cloned()
+        .ok_or_else(|| {
+            ForgeStudioError::not_found(format!(
+                "Version '{version_id}' is not recorded in the {dataset_name} catalog."
+            ))
+        })
+}
+
+fn inspect_record_lines(payload: &str, report: &mut VersionIntegrityReport) {
+    let mut seen_ids = HashSet::new();
+    for (line_index, line) in payload.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let parsed = serde_json::from_str::<Value>(line).ok();
+        let fields = parsed.as_ref().and_then(|record| {
+            let record_id = record.get("record_id")?.as_str()?;
+            let text = record.get("text")?.as_str()?;
+            Some((record_id.to_string(), text.to_string()))
+        });
+        let Some((record_id, text)) = fields else {
+            report.malformed_lines.push(line_index as u64 + 1);
+            continue;
+        };
+        report.actual_record_count += 1;
+        if build_record_id(&text) != record_id {
+            push_capped(&mut report.tampered_record_ids, record_id.clone());
+        }
+        if !seen_ids.insert(record_id.clone()) {
+            push_capped(&mut report.duplicate_record_ids, record_id);
+        }
+    }
+}
+
+fn push_capped(target: &mut Vec<String>, record_id: String) {
+    if target.len() < MAX_REPORTED_IDS {
+        target.push(record_id);
+    }
+}
+
+fn collect_issues(report: &mut VersionIntegrityReport) {
+    let mut issues = Vec::new();
+    if !report.malformed_lines.is_empty() {
+        issues.push(format!(
+            "{} malformed line(s); the records file may be a partial write.",
+            report.malformed_lines.len()
+        ));
+    }
+    if let Some(expected) = report.expected_record_count {
+        if expected != report.actual_record_count {
+            issues.push(format!(
+                "Catalog records {expected} rows but the file holds {}.",
+                report.actual_record_count
+            ));
+        }
+    }
+    if !report.tampered_record_ids.is_empty() {
+        issues.push("Some record texts no longer match their content-hash ids.".to_string());
+    }
+    if !report.duplicate_record_ids.is_empty() {
+        issues.push("Record ids are not unique within the version.".to_string());
+    }
+    if let Some(recorded) = &report.recorded_checksum {
+        if recorded != &report.computed_checksum {
+            issues.push("Records checksum differs from the catalog checksum.".to_string());
+        }
+    }
+    report.is_valid = issues.is_empty();
+    report.issues = issues;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{version_integrity_report, CATALOG_CHECKSUM_FIELD};
+    use crate::commands::content_hash::{build_record_id, sha256_hex};
+    use crate::commands::dataset_store_io::{dataset_root, records_path, version_dir};
+    use crate::models::VersionIntegrityReport;
+    use serde_json::json;
+    use std::fs;
+
+    /// Writes a one-version `demo` dataset whose catalog records `record_count`
+    /// rows and, unless `legacy`, the checksum of `records`.
+    fn write_snapshot(name: &str, records: &str, record_count: u64, legacy: bool) -> String {
+        let data_root = std::env::temp_dir()
+            .join(format!(
+                "forge-version-integrity-{name}-{}",
+                std::process::id()
+            ))
+            .display()
+            .to_string();
+        fs::create_dir_all(version_dir(&data_root, "demo", "v1")).expect("version dir created");
+        fs::write(records_path(&data_root, "demo", "v1"), records).expect("records written");
+        let mut entry = json!({"version_id": "v1", "record_count": record_count});
+        if !legacy {
+            entry[CATALOG_CHECKSUM_FIELD] = json!(sha256_hex(records.as_bytes()));
+        }
+        let catalog = json!({"latest_version": "v1", "versions": [entry]});
+        fs::write(
+            dataset_root(&data_root, "demo").join("catalog.json"),
+            catalog.to_string(),
+        )
+        .expect("catalog written");
+        data_root
+    }
+
+    fn record_line(record_id: &str, text: &str) -> String {
+        format!("{}\n", json!({"record_id": record_id, "text": text}))
+    }
+
+    fn verify(data_root: &str) -> VersionIntegrityReport {
+        let report = version_integrity_report(data_root, "demo".to_string(), None)
+            .expect("version should verify");
+        fs::remove_dir_all(data_root).expect("temp dir should be removed");
+        report
+    }
+
+    #[test]
+    fn intact_versions_pass() {
+        let records = record_line(&build_record_id("alpha"), "alpha")
+            + &record_line(&build_record_id("beta"), "beta");
+        let report = verify(&write_snapshot("intact", &records, 2, false));
+        assert!(report.is_valid, "unexpected issues: {:?}", report.issues);
+        assert_eq!(report.actual_record_count, 2);
+    }
+
+    #[test]
+    fn malformed_lines_and_count_drift_are_reported() {
+        let records = record_line(&build_record_id("alpha"), "alpha") + "{\"record_id\": \n";
+        let report = verify(&write_snapshot("malformed", &records, 2, false));
+        assert!(!report.is_valid);
+        assert_eq!(report.malformed_lines, [2]);
+        assert_eq!(report.expected_record_count, Some(2));
+        assert_eq!(report.actual_record_count, 1);
+        assert_eq!(report.issues.len(), 2);
+    }
+
+    #[test]
+    fn tampered_and_duplicate_ids_are_reported() {
+        let alpha_id = build_record_id("alpha");
+        let records = record_line(&alpha_id, "alpha")
+            + &record_line(&alpha_id, "Alpha  ")
+            + &record_line(&alpha_id, "edited text");
+        let report = verify(&write_snapshot("tampered", &records, 3, false));
+        assert!(!report.is_valid);
+        assert_eq!(report.tampered_record_ids, std::slice::from_ref(&alpha_id));
+        assert_eq!(report.duplicate_record_ids, [alpha_id.clone(), alpha_id]);
+    }
+
+    #[test]
+    fn checksum_mismatch_is_reported_unless_the_catalog_is_legacy() {
+        let records = record_line(&build_record_id("alpha"), "alpha");
+        let data_root = write_snapshot("checksum", &records, 1, false);
+        // A trailing blank line changes the bytes but keeps every other check green.
+        fs::write(
+            records_path(&data_root, "demo", "v1"),
+            records.clone() + "\n",
+        )
+        .expect("records rewritten");
+        let report = verify(&data_root);
+        assert!(!report.is_valid);
+        assert_eq!(report.issues.len(), 1);
+        assert_ne!(report.recorded_checksum, Some(report.computed_checksum));
+
+        let legacy = verify(&write_snapshot("legacy", &records, 1, true));
+        assert!(legacy.is_valid);
+        assert_eq!(legacy.recorded_checksum, None);
+    }
+}
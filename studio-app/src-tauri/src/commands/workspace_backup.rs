@@ -0,0 +1,213 @@
+//! Compressed backups of a data root's metadata for disaster recovery.
+//!
+//! `backup_workspace` writes a `.tar.gz` of the data root: dataset catalogs
+//! and manifests, run lifecycles and annotations, the lineage graph, saved
+//! canvases, and Studio's own JSON files. Model weights and dataset records
+//! make up nearly all of a data root's size and can be regenerated or copied
+//! separately, so they are left out unless the options ask for them. The
+//! metadata index, lock files, and temp files are always skipped. The first
+//! entry is a manifest recording where and when the backup was taken, which
+//! `restore_workspace` reads before extracting anything.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::path_safety::confine_path;
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::models::{ForgeStudioError, WorkspaceBackup, WorkspaceBackupOptions};
+use chrono::{SecondsFormat, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde_json::json;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+pub(crate) const BACKUP_MANIFEST_NAME: &str = "forge-backup.json";
+pub(crate) const BACKUP_FORMAT_VERSION: u64 = 1;
+const MODEL_WEIGHT_EXTENSIONS: [&str; 7] =
+    ["pt", "pth", "bin", "ckpt", "safetensors", "onnx", "gguf"];
+const RECORDS_FILE_NAME: &str = "records.jsonl";
+const LANCE_DIR_NAME: &str = "data.lance";
+/// Top-level cache folder holding the metadata index, rebuilt on demand.
+const METADATA_INDEX_DIR_NAME: &str = "index";
+/// Suffixes and infixes of lock files and interrupted atomic writes.
+const SCRATCH_FILE_MARKERS: [&str; 3] = [".lock", ".tmp-", ".partial-"];
+
+/// Files selected for a backup, relative to the data root.
+struct BackupScan {
+    files: Vec<PathBuf>,
+    excluded_file_count: u64,
+}
+
+#[tauri::command]
+pub async fn backup_workspace(
+    data_root: String,
+    output_path: String,
+    options: Option<WorkspaceBackupOptions>,
+    settings: State<'_, SettingsStore>,
+) -> Result<WorkspaceBackup, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let data_root = resolve_data_root_path(&data_root, &roots)?;
+        let archive_path = confine_path(Path::new(&output_path), "Backup file", &roots)?;
+        write_backup(&data_root, &archive_path, &options.unwrap_or_default())
+    })
+    .await
+}
+
+/// Writes the archive beside `archive_path` and renames it into place once
+/// complete, so an interrupted backup never looks like a finished one.
+pub(crate) fn write_backup(
+    data_root: &Path,
+    archive_path: &Path,
+    options: &WorkspaceBackupOptions,
+) -> Result<WorkspaceBackup, ForgeStudioError> {
+    let mut scan = BackupScan {
+        files: Vec::new(),
+        excluded_file_count: 0,
+    };
+    scan_dir(data_root, data_root, archive_path, options, &mut scan)?;
+    if let Some(parent_dir) = archive_path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to create {}: {error}",
+                parent_dir.display()
+            ))
+        })?;
+    }
+    let file_name = archive_path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let partial_path =
+        archive_path.with_file_name(format!(".{file_name}.partial-{}", std::process::id()));
+    let written =
+        write_archive(data_root, &partial_path, &scan.files, options).and_then(|total_bytes| {
+            fs::rename(&partial_path, archive_path)
+                .map(|()| total_bytes)
+                .map_err(|error| {
+                    ForgeStudioError::io(format!(
+                        "Failed to rename backup into {}: {error}",
+                        archive_path.display()
+                    ))
+                })
+        });
+    let total_bytes = written.inspect_err(|_| {
+        let _ = fs::remove_file(&partial_path);
+    })?;
+    Ok(WorkspaceBackup {
+        archive_path: archive_path.display().to_string(),
+        file_count: scan.files.len() as u64,
+        total_bytes,
+        excluded_file_count: scan.excluded_file_count,
+    })
+}
+
+fn scan_dir(
+    data_root: &Path,
+    dir: &Path,
+    archive_path: &Path,
+    options: &WorkspaceBackupOptions,
+    scan: &mut BackupScan,
+) -> Result<(), ForgeStudioError> {
+    let read_dir = fs::read_dir(dir).map_err(|error| {
+        ForgeStudioError::io(format!("Failed to read {}: {error}", dir.display()))
+    })?;
+    for entry in read_dir {
+        let entry = entry.map_err(|error| {
+            ForgeStudioError::io(format!("Failed to read {}: {error}", dir.display()))
+        })?;
+        let path = entry.path();
+        let relative_path = path.strip_prefix(data_root).unwrap_or(&path).to_path_buf();
+        let name = entry.file_name().to_string_lossy().to_string();
+        // Symlinks are skipped: following them could archive files outside the data root.
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            if relative_path != Path::new(METADATA_INDEX_DIR_NAME) {
+                scan_dir(data_root, &path, archive_path, options, scan)?;
+            }
+        } else if file_type.is_file()
+            && path != archive_path
+            && !SCRATCH_FILE_MARKERS
+                .iter()
+                .any(|marker| name.contains(marker))
+        {
+            if is_excluded(&relative_path, options) {
+                scan.excluded_file_count += 1;
+            } else {
+                scan.files.push(relative_path);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_excluded(relative_path: &Path, options: &WorkspaceBackupOptions) -> bool {
+    let is_records = relative_path.ends_with(RECORDS_FILE_NAME)
+        || relative_path
+            .components()
+            .any(|component| component.as_os_str() == LANCE_DIR_NAME);
+    let is_weights = relative_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| MODEL_WEIGHT_EXTENSIONS.contains(&extension));
+    (is_records && !options.include_dataset_records)
+        || (is_weights && !options.include_model_weights)
+}
+
+/// Writes the manifest and `files`; returns their uncompressed size.
+fn write_archive(
+    data_root: &Path,
+    partial_path: &Path,
+    files: &[PathBuf],
+    options: &WorkspaceBackupOptions,
+) -> Result<u64, ForgeStudioError> {
+    let write_error = |error: std::io::Error| {
+        ForgeStudioError::io(format!(
+            "Failed to write backup {}: {error}",
+            partial_path.display()
+        ))
+    };
+    let archive_file = File::create(partial_path).map_err(write_error)?;
+    let mut builder = tar::Builder::new(GzEncoder::new(archive_file, Compression::default()));
+    let manifest = json!({
+        "format_version": BACKUP_FORMAT_VERSION,
+        "data_root": data_root.display().to_string(),
+        "created_at": Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        "file_count": files.len(),
+        "include_model_weights": options.include_model_weights,
+        "include_dataset_records": options.include_dataset_records,
+    });
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest).map_err(|error| {
+        ForgeStudioError::internal(format!("Failed to serialize backup manifest: {error}"))
+    })?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(Utc::now().timestamp().unsigned_abs());
+    builder
+        .append_data(&mut header, BACKUP_MANIFEST_NAME, manifest_bytes.as_slice())
+        .map_err(write_error)?;
+    let mut total_bytes = 0;
+    for relative_path in files {
+        let source_path = data_root.join(relative_path);
+        let mut source = File::open(&source_path).map_err(|error| {
+            ForgeStudioError::io(format!("Failed to read {}: {error}", source_path.display()))
+        })?;
+        total_bytes += source
+            .metadata()
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        builder
+            .append_file(relative_path, &mut source)
+            .map_err(write_error)?;
+    }
+    let archive_file = builder
+        .into_inner()
+        .and_then(GzEncoder::finish)
+        .map_err(write_error)?;
+    archive_file.sync_all().map_err(write_error)?;
+    Ok(total_bytes)
+}
@@ -0,0 +1,229 @@
+//! Restores a data root from an archive written by `backup_workspace`.
+//!
+//! The manifest entry is read first, so an archive from another tool is
+//! refused before anything is extracted. Files are restored into the data
+//! root the backup was taken from unless another one is given, overwriting
+//! files with the same path and leaving every other file alone. Each file is
+//! extracted beside its destination and renamed into place, so an interrupted
+//! restore never leaves a half-written catalog or lineage graph.
+
+use crate::commands::app_settings::SettingsStore;
+use crate::commands::blocking_pool::run_blocking;
+use crate::commands::path_safety::{confine_path, WorkspaceRoots};
+use crate::commands::run_store_io::resolve_data_root_path;
+use crate::commands::workspace_backup::{BACKUP_FORMAT_VERSION, BACKUP_MANIFEST_NAME};
+use crate::models::{ForgeStudioError, WorkspaceRestore};
+use flate2::read::GzDecoder;
+use serde_json::Value;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use tauri::State;
+
+#[tauri::command]
+pub async fn restore_workspace(
+    archive: String,
+    data_root: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<WorkspaceRestore, ForgeStudioError> {
+    let roots = settings.workspace_roots();
+    run_blocking(move || {
+        let archive_path = confine_path(Path::new(&archive), "Backup file", &roots)?;
+        restore_backup(&archive_path, data_root.as_deref(), &roots)
+    })
+    .await
+}
+
+pub(crate) fn restore_backup(
+    archive_path: &Path,
+    data_root: Option<&str>,
+    roots: &WorkspaceRoots,
+) -> Result<WorkspaceRestore, ForgeStudioError> {
+    let read_error = |error: std::io::Error| {
+        ForgeStudioError::io(format!(
+            "Failed to read backup {}: {error}",
+            archive_path.display()
+        ))
+    };
+    let archive_file = File::open(archive_path).map_err(read_error)?;
+    let mut archive = tar::Archive::new(GzDecoder::new(archive_file));
+    let mut entries = archive.entries().map_err(read_error)?;
+    let manifest = match entries.next() {
+        Some(entry) => read_manifest(archive_path, entry.map_err(read_error)?)?,
+        None => return Err(missing_manifest(archive_path)),
+    };
+    let backup_data_root = manifest_text(&manifest, "data_root");
+    let data_root = resolve_data_root_path(data_root.unwrap_or(&backup_data_root), roots)?;
+    let mut restored_file_count = 0;
+    for entry in entries {
+        let mut entry = entry.map_err(read_error)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let relative_path = entry.path().map_err(read_error)?.into_owned();
+        let target_path = safe_target_path(&data_root, &relative_path).ok_or_else(|| {
+            ForgeStudioError::parse(format!(
+                "Backup {} has an entry outside the data root: {}. The archive is corrupt or was not written by Studio.",
+                archive_path.display(),
+                relative_path.display()
+            ))
+        })?;
+        restore_file(&mut entry, &target_path)?;
+        restored_file_count += 1;
+    }
+    Ok(WorkspaceRestore {
+        data_root: data_root.display().to_string(),
+        restored_file_count,
+        backup_created_at: manifest_text(&manifest, "created_at"),
+    })
+}
+
+fn read_manifest(
+    archive_path: &Path,
+    mut entry: tar::Entry<'_, impl Read>,
+) -> Result<Value, ForgeStudioError> {
+    let is_manifest = entry
+        .path()
+        .is_ok_and(|path| path == Path::new(BACKUP_MANIFEST_NAME));
+    if !is_manifest {
+        return Err(missing_manifest(archive_path));
+    }
+    let mut text = String::new();
+    entry.read_to_string(&mut text).map_err(|error| {
+        ForgeStudioError::io(format!(
+            "Failed to read backup {}: {error}",
+            archive_path.display()
+        ))
+    })?;
+    let manifest: Value = serde_json::from_str(&text).map_err(|error| {
+        ForgeStudioError::parse(format!(
+            "Failed to parse the manifest of backup {}: {error}",
+            archive_path.display()
+        ))
+    })?;
+    let format_version = manifest.get("format_version").and_then(Value::as_u64);
+    if format_version.is_none_or(|version| version > BACKUP_FORMAT_VERSION) {
+        return Err(ForgeStudioError::invalid_input(format!(
+            "Backup {} uses format {format_version:?}, newer than this Studio supports. Update Studio and retry.",
+            archive_path.display()
+        )));
+    }
+    Ok(manifest)
+}
+
+fn missing_manifest(archive_path: &Path) -> ForgeStudioError {
+    ForgeStudioError::parse(format!(
+        "Backup {} is missing its {BACKUP_MANIFEST_NAME} manifest. Choose an archive written by backup_workspace.",
+        archive_path.display()
+    ))
+}
+
+fn manifest_text(manifest: &Value, key: &str) -> String {
+    manifest
+        .get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// `relative_path` under `data_root`, or `None` when it could escape it.
+fn safe_target_path(data_root: &Path, relative_path: &Path) -> Option<PathBuf> {
+    let is_plain = relative_path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    (is_plain && relative_path.components().next().is_some()).then(|| data_root.join(relative_path))
+}
+
+fn restore_file(
+    entry: &mut tar::Entry<'_, impl Read>,
+    target_path: &Path,
+) -> Result<(), ForgeStudioError> {
+    if let Some(parent_dir) = target_path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to create {}: {error}",
+                parent_dir.display()
+            ))
+        })?;
+    }
+    let file_name = target_path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let temp_path =
+        target_path.with_file_name(format!(".{file_name}.tmp-restore-{}", std::process::id()));
+    let unpacked = entry
+        .unpack(&temp_path)
+        .map_err(|error| {
+            ForgeStudioError::io(format!(
+                "Failed to write {}: {error}",
+                target_path.display()
+            ))
+        })
+        .and_then(|_| {
+            fs::rename(&temp_path, target_path).map_err(|error| {
+                ForgeStudioError::io(format!(
+                    "Failed to rename into {}: {error}",
+                    target_path.display()
+                ))
+            })
+        });
+    if unpacked.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    unpacked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::restore_backup;
+    use crate::commands::path_safety::WorkspaceRoots;
+    use crate::commands::workspace_backup::write_backup;
+    use crate::models::WorkspaceBackupOptions;
+    use std::fs;
+
+    #[test]
+    fn backup_round_trips_metadata_and_leaves_out_weights() {
+        let temp_root =
+            std::env::temp_dir().join(format!("forge-workspace-backup-{}", std::process::id()));
+        let data_root = temp_root.join("data");
+        let version_dir = data_root.join("datasets/news/versions/v1");
+        fs::create_dir_all(&version_dir).expect("version dir should be created");
+        fs::create_dir_all(data_root.join("runs/run-a")).expect("run dir should be created");
+        fs::create_dir_all(data_root.join("index")).expect("index dir should be created");
+        let files = [
+            ("datasets/news/catalog.json", "{\"versions\": []}"),
+            ("datasets/news/versions/v1/manifest.json", "{}"),
+            ("datasets/news/versions/v1/records.jsonl", "{}\n"),
+            ("runs/run-a/meta.json", "{\"tags\": [\"baseline\"]}"),
+            ("runs/run-a/model.pt", "weights"),
+            ("runs/index.json.lock", ""),
+            ("index/metadata.sqlite", "cache"),
+        ];
+        for (relative_path, contents) in files {
+            fs::write(data_root.join(relative_path), contents).expect("file should be written");
+        }
+
+        let archive_path = temp_root.join("backups/workspace.tar.gz");
+        let options = WorkspaceBackupOptions::default();
+        let backup = write_backup(&data_root, &archive_path, &options).expect("backup should run");
+        assert_eq!((backup.file_count, backup.excluded_file_count), (3, 2));
+
+        let restored_root = temp_root.join("restored");
+        let restored_text = restored_root.display().to_string();
+        let roots = WorkspaceRoots::new(&[temp_root.display().to_string()]);
+        let restore = restore_backup(&archive_path, Some(&restored_text), &roots)
+            .expect("restore should run");
+        assert_eq!(restore.restored_file_count, 3);
+        assert_eq!(
+            fs::read_to_string(restored_root.join("runs/run-a/meta.json"))
+                .expect("annotations should be restored"),
+            "{\"tags\": [\"baseline\"]}"
+        );
+        assert!(!restored_root.join("runs/run-a/model.pt").exists());
+        assert!(!restored_root.join("index").exists());
+        fs::write(temp_root.join("not-a-backup.tar.gz"), "plain text").expect("file write");
+        assert!(restore_backup(&temp_root.join("not-a-backup.tar.gz"), None, &roots).is_err());
+        fs::remove_dir_all(&temp_root).expect("temp dir should be removed");
+    }
+}
@@ -3,26 +3,222 @@
 mod commands;
 mod models;
 
+use tauri::Manager;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let args: Vec<String> = std::env::args().collect();
+    if args
+        .iter()
+        .any(|arg| arg == commands::rpc_server::SERVE_FLAG)
+    {
+        if let Err(error) = commands::rpc_server::serve_headless(&args) {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    let event_bus = commands::event_bus::EventBus::default();
+    let crash_reporter = commands::crash_reports::CrashReporter::default();
+    let settings_store = commands::app_settings::SettingsStore::default();
+    let task_store = commands::forge_task_store::CommandTaskStore::new(
+        event_bus.clone(),
+        crash_reporter.clone(),
+        settings_store.clone(),
+    );
+    let telemetry_store = commands::hardware_telemetry::TelemetryStore::default();
+    let usage_store = commands::usage_telemetry::UsageTelemetryStore::default();
+    // Setup takes the stores above; the invoke handler's wrappers use these.
+    let handler_usage_store = usage_store.clone();
+    let handler_settings_store = settings_store.clone();
     tauri::Builder::default()
-        .manage(commands::forge_task_store::CommandTaskStore::default())
+        .manage(commands::chat_sessions::ChatSessionStore::default())
+        .manage(task_store.clone())
+        .manage(commands::hardware_profile::HardwareProfileCache::default())
+        .manage(commands::pipeline_schedules::PipelineScheduleStore::default())
+        .manage(commands::history_watcher::HistoryWatchStore::default())
+        .manage(commands::run_state_watcher::RunStateWatchStore::default())
+        .manage(telemetry_store.clone())
+        .manage(settings_store.clone())
+        .manage(usage_store.clone())
+        .manage(crash_reporter.clone())
+        .manage(event_bus.clone())
+        .setup(move |app| {
+            // Logging starts after settings load so it can honour `log_filter`,
+            // and before everything else so their startup is logged.
+            let settings_loaded = settings_store.load(&app.path().app_config_dir()?);
+            commands::app_logging::init_logging(
+                &app.path().app_log_dir()?,
+                &settings_store.current().log_filter,
+            );
+            crash_reporter.install(&app.path().app_data_dir()?, &app.path().app_log_dir()?);
+            if let Err(error) = settings_loaded {
+                tracing::warn!("{error}");
+            }
+            let locale_activated = commands::message_catalog::activate_locale(
+                &app.path().app_config_dir()?,
+                &settings_store.current().locale,
+            );
+            if let Err(error) = locale_activated {
+                tracing::warn!("{error} Using English messages.");
+            }
+            match commands::plugin_registry::load_plugins(&app.path().app_config_dir()?) {
+                Ok(loaded) => tracing::info!("Loaded {loaded} plugin(s)"),
+                Err(error) => tracing::warn!("{error} Plugins are unavailable."),
+            }
+            if let Err(error) = usage_store.load(&app.path().app_data_dir()?) {
+                tracing::warn!("{error}");
+            }
+            commands::usage_telemetry::spawn_usage_uploader(
+                usage_store.clone(),
+                settings_store.clone(),
+            );
+            event_bus.attach(app.handle().clone());
+            let rpc_server = settings_store.current().rpc_server;
+            if rpc_server.enabled {
+                commands::rpc_server::spawn_rpc_server(
+                    task_store.clone(),
+                    settings_store.clone(),
+                    rpc_server.port,
+                );
+            }
+            let event_bridge = settings_store.current().event_bridge;
+            if event_bridge.enabled {
+                commands::event_bridge::spawn_event_bridge(event_bus.clone(), event_bridge);
+            }
+            // The sampler shares the managed stores so it sees tasks started by any command.
+            commands::hardware_telemetry::spawn_telemetry_sampler(task_store, telemetry_store);
+            Ok(())
+        })
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![
-            commands::canvas_export::export_pipeline_canvas,
-            commands::dataset_queries::get_dataset_dashboard,
-            commands::dataset_queries::list_datasets,
-            commands::dataset_queries::list_versions,
-            commands::dataset_queries::load_training_history,
-            commands::dataset_queries::sample_records,
-            commands::dataset_queries::version_diff,
-            commands::forge_commands::start_forge_command,
-            commands::forge_commands::get_forge_command_status,
-            commands::runtime_queries::list_training_runs,
-            commands::runtime_queries::get_lineage_graph,
-            commands::runtime_queries::get_hardware_profile
-        ])
+        .invoke_handler(commands::usage_telemetry::with_usage_telemetry(
+            handler_usage_store,
+            handler_settings_store.clone(),
+            commands::read_only_mode::with_read_only_mode(
+                handler_settings_store,
+                tauri::generate_handler![
+                    commands::app_logging::get_recent_app_logs,
+                    commands::app_settings::get_settings,
+                    commands::app_settings::update_settings,
+                    commands::artifact_contract_verification::verify_artifact_contract,
+                    commands::audit_log::get_audit_log,
+                    commands::batch_inference::run_batch_inference,
+                    commands::batch_inference::list_batch_inferences,
+                    commands::batch_inference::get_batch_inference,
+                    commands::batch_inference::compare_batch_inferences,
+                    commands::canvas_export::export_pipeline_canvas,
+                    commands::canvas_export::list_canvas_exports,
+                    commands::canvas_import::import_pipeline_canvas,
+                    commands::canvas_bundle::export_canvas_bundle,
+                    commands::canvas_bundle_import::import_canvas_bundle,
+                    commands::pipeline_config_import::import_pipeline_config,
+                    commands::canvas_snapshots::save_canvas_snapshot,
+                    commands::canvas_snapshots::list_canvas_snapshots,
+                    commands::canvas_snapshots::restore_canvas_snapshot,
+                    commands::canvas_edit_journal::push_canvas_edit,
+                    commands::canvas_edit_journal::undo_canvas_edit,
+                    commands::canvas_edit_journal::redo_canvas_edit,
+                    commands::canvas_edit_journal::get_canvas_edit_journal,
+                    commands::canvas_validation::validate_pipeline_canvas,
+                    commands::canvas_lint::lint_pipeline_canvas,
+                    commands::canvas_diff::diff_canvases,
+                    commands::canvas_merge::merge_canvases,
+                    commands::canvas_render::render_pipeline_canvas,
+                    commands::chat_sessions::start_chat_session,
+                    commands::chat_sessions::send_chat_prompt,
+                    commands::chat_sessions::get_chat_transcript,
+                    commands::chat_sessions::close_chat_session,
+                    commands::crash_reports::get_crash_reports,
+                    commands::crash_reports::clear_crash_reports,
+                    commands::data_root_check::check_data_root,
+                    commands::data_root_init::init_data_root,
+                    commands::workspace_backup::backup_workspace,
+                    commands::workspace_restore::restore_workspace,
+                    commands::dataset_drift::detect_dataset_drift,
+                    commands::dataset_health_report::generate_dataset_health_report,
+                    commands::dataset_queries::get_dataset_dashboard,
+                    commands::dataset_queries::list_datasets,
+                    commands::dataset_queries::list_versions,
+                    commands::dataset_queries::sample_records,
+                    commands::dataset_queries::version_diff,
+                    commands::version_duplicates::get_version_duplicate_rate,
+                    commands::evaluation_harness::start_evaluation,
+                    commands::filter_explanation::explain_filter_for_record,
+                    commands::leaderboard::get_leaderboard,
+                    commands::lineage_audit::audit_lineage,
+                    commands::lineage_export::export_lineage_graph,
+                    commands::message_catalog::set_locale,
+                    commands::metadata_index::rebuild_metadata_index,
+                    commands::metadata_index_queries::search_metadata_index,
+                    commands::model_export::export_model,
+                    commands::model_export::list_model_exports,
+                    commands::model_export_comparison::compare_model_exports,
+                    commands::model_inspection::inspect_model_artifact,
+                    commands::model_registry::register_model,
+                    commands::model_registry::set_model_stage,
+                    commands::model_registry::list_registered_models,
+                    commands::model_registry::get_model_by_stage,
+                    commands::pipeline_runs::run_pipeline_canvas,
+                    commands::pipeline_dry_run::plan_pipeline_canvas,
+                    commands::pipeline_runs::get_pipeline_run_status,
+                    commands::pipeline_history::list_pipeline_runs,
+                    commands::pipeline_history::get_pipeline_run,
+                    commands::pipeline_schedules::create_pipeline_schedule,
+                    commands::pipeline_schedules::list_pipeline_schedules,
+                    commands::pipeline_schedules::delete_pipeline_schedule,
+                    commands::pipeline_node_types::get_pipeline_node_types,
+                    commands::plugin_registry::list_plugins,
+                    commands::plugin_protocol::run_plugin_command,
+                    commands::plugin_protocol::run_dataset_analyzer,
+                    commands::forge_commands::start_forge_command,
+                    commands::forge_commands::get_forge_command_status,
+                    commands::forge_updates::check_forge_updates,
+                    commands::quality_recalibration::recalibrate_quality,
+                    commands::hardware_profile::get_hardware_profile,
+                    commands::hardware_profile::refresh_hardware_profile,
+                    commands::event_bus::list_backend_events,
+                    commands::hardware_telemetry::get_hardware_telemetry_history,
+                    commands::history_watcher::start_history_watch,
+                    commands::history_watcher::stop_history_watch,
+                    commands::record_arrow_ipc::sample_records_arrow,
+                    commands::record_outliers::find_outlier_records,
+                    commands::run_clone::clone_run,
+                    commands::run_comparison::compare_runs,
+                    commands::run_annotations::set_run_tags,
+                    commands::run_annotations::set_run_notes,
+                    commands::run_attachments::attach_run_artifact,
+                    commands::run_attachments::list_run_attachments,
+                    commands::run_attachments::get_run_attachment,
+                    commands::run_config_diff::diff_run_configs,
+                    commands::run_cost::get_run_cost,
+                    commands::run_cost::get_cost_rates,
+                    commands::run_cost::set_cost_rates,
+                    commands::run_details::get_run_details,
+                    commands::run_evaluations::list_eval_results,
+                    commands::run_evaluations::record_eval_result,
+                    commands::run_failure_diagnosis::diagnose_run_failure,
+                    commands::run_gc::gc_runs,
+                    commands::run_heartbeat::mark_run_failed,
+                    commands::run_index::rebuild_runs_index,
+                    commands::run_removal::delete_run,
+                    commands::run_removal::archive_run,
+                    commands::run_resume::resume_training_run,
+                    commands::run_state_watcher::start_run_state_watch,
+                    commands::run_state_watcher::stop_run_state_watch,
+                    commands::runtime_queries::list_training_runs,
+                    commands::runtime_queries::get_lineage_graph,
+                    commands::sweeps::start_sweep,
+                    commands::sweeps::get_sweep_status,
+                    commands::tensorboard_export::export_history_to_tensorboard,
+                    commands::training_curve_analysis::analyze_training_curves,
+                    commands::training_feasibility::check_training_feasibility,
+                    commands::training_history::load_training_history,
+                    commands::usage_telemetry::get_usage_telemetry,
+                    commands::version_integrity::verify_version_integrity
+                ],
+            ),
+        ))
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,37 @@
+//! Entries read back from Studio's own log files, and crash reports.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// One logged event, as written to the rolling log file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AppLogEntry {
+    pub timestamp: String,
+    /// `error`, `warn`, `info`, `debug`, or `trace`.
+    pub level: String,
+    /// Module that logged the event, e.g. `studio_app_lib::commands::forge_task_store`.
+    pub target: String,
+    pub message: String,
+    /// Structured fields logged alongside the message.
+    pub fields: Map<String, Value>,
+}
+
+/// A panic in Studio or a crashed forge task, saved for bug reports.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub report_id: String,
+    /// `panic` or `task_failure`.
+    pub kind: String,
+    pub created_at: String,
+    pub studio_version: String,
+    pub os: String,
+    pub message: String,
+    /// Source location of a panic.
+    pub location: Option<String>,
+    /// Rust backtrace of a panic.
+    pub backtrace: Option<String>,
+    /// End of a crashed forge task's stderr, usually its traceback.
+    pub stderr_tail: Option<String>,
+    /// Studio's last log lines before the crash, oldest first.
+    pub recent_logs: Vec<String>,
+}
@@ -0,0 +1,47 @@
+//! Typed events published on the backend event bus.
+
+use crate::models::{RunStateChange, TelemetrySnapshot, TrainingHistoryUpdate};
+use serde::Serialize;
+
+/// Something a backend subsystem reports, tagged by `kind` on the wire.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BackendEvent {
+    TaskStarted {
+        task_id: String,
+        command: String,
+        args: Vec<String>,
+    },
+    TaskFinished {
+        task_id: String,
+        command: String,
+        /// `completed` or `failed`.
+        status: String,
+        exit_code: Option<i32>,
+    },
+    /// A completed `ingest` or `filter` task wrote a new version of a dataset.
+    DatasetChanged {
+        dataset_name: String,
+        command: String,
+        task_id: String,
+    },
+    /// A pipeline run's node statuses or overall progress changed.
+    PipelineRunUpdated {
+        pipeline_run_id: String,
+        status: String,
+        overall_percent: f64,
+    },
+    RunStateChanged(RunStateChange),
+    TrainingHistoryUpdated(TrainingHistoryUpdate),
+    HardwareTelemetry(TelemetrySnapshot),
+}
+
+/// A published event with its position in the bus's sequence.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendEventEnvelope {
+    /// Increases by one per event, so a gap means events were missed.
+    pub sequence: u64,
+    pub published_at: String,
+    #[serde(flatten)]
+    pub event: BackendEvent,
+}
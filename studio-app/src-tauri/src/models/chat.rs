@@ -0,0 +1,50 @@
+//! Inference playground session and streaming event models.
+
+use serde::{Deserialize, Serialize};
+
+/// Optional `forge chat` overrides; unset fields fall back to CLI defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ChatSessionOptions {
+    pub dataset_name: Option<String>,
+    pub tokenizer_path: Option<String>,
+    pub architecture_path: Option<String>,
+    pub max_new_tokens: Option<u32>,
+    pub temperature: Option<f64>,
+    pub top_k: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChatSessionStart {
+    pub session_id: String,
+    pub model_path: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChatPromptStart {
+    pub session_id: String,
+    pub message_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatTurn {
+    pub message_id: String,
+    pub prompt: String,
+    pub response: String,
+}
+
+/// Payload of `chat-token` events, one per decoded stdout chunk.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatTokenEvent {
+    pub session_id: String,
+    pub message_id: String,
+    pub text: String,
+}
+
+/// Payload of the `chat-complete` event emitted once per prompt.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatCompleteEvent {
+    pub session_id: String,
+    pub message_id: String,
+    pub response: String,
+    pub error: Option<String>,
+}
@@ -0,0 +1,88 @@
+//! Data root checks, setup, backups, and the audit log.
+
+use serde::{Deserialize, Serialize};
+
+/// One change to a data root, appended to `<data_root>/audit_log.jsonl`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub timestamp: String,
+    /// The settings identity of whoever made the change, if one is set.
+    pub author: Option<String>,
+    /// The command that made it, e.g. `set_run_notes`.
+    pub action: String,
+    /// Run id or `model_name@version` it changed.
+    pub target: String,
+}
+
+/// One line of the data root checklist.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DataRootCheckItem {
+    /// Stable id the UI keys on: `directory`, `layout`, `permissions`,
+    /// `disk_space`, `catalogs`, or `forge_binary`.
+    pub check_id: String,
+    pub label: String,
+    /// `pass`, `warn`, `fail`, or `skipped` when an earlier failure makes
+    /// the check meaningless.
+    pub status: String,
+    pub detail: String,
+    /// What to do about a warning or failure.
+    pub remediation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataRootCheck {
+    pub data_root: String,
+    /// No check failed; warnings do not block work.
+    pub ready: bool,
+    pub checks: Vec<DataRootCheckItem>,
+}
+
+/// Choices made in the data root setup wizard.
+#[derive(Debug, Default, Deserialize)]
+pub struct DataRootInitOptions {
+    /// Add the folder to `workspace_roots` first, so one outside the
+    /// workspace Studio runs from is accepted.
+    #[serde(default)]
+    pub add_workspace_root: bool,
+    /// Save the folder as `default_data_root`, opened at the next launch.
+    #[serde(default)]
+    pub set_default: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataRootInit {
+    pub data_root: String,
+    /// Folders and index files created, relative to the data root.
+    pub created: Vec<String>,
+    /// Ones that already existed and were left untouched.
+    pub existing: Vec<String>,
+}
+
+/// What a workspace backup includes besides metadata, catalogs, and canvases.
+#[derive(Debug, Default, Deserialize)]
+pub struct WorkspaceBackupOptions {
+    /// Keep model weights and checkpoints such as `.pt` or `.safetensors`.
+    #[serde(default)]
+    pub include_model_weights: bool,
+    /// Keep version `records.jsonl` files and Lance datasets.
+    #[serde(default)]
+    pub include_dataset_records: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkspaceBackup {
+    pub archive_path: String,
+    pub file_count: u64,
+    /// Size of the archived files before compression.
+    pub total_bytes: u64,
+    /// Files left out because the options excluded them.
+    pub excluded_file_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkspaceRestore {
+    pub data_root: String,
+    pub restored_file_count: u64,
+    /// When the restored backup was taken.
+    pub backup_created_at: String,
+}
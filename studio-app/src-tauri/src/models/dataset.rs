@@ -0,0 +1,72 @@
+//! Dataset browsing, dashboard, and record sample models.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize)]
+pub struct DatasetListing {
+    pub dataset_name: String,
+    pub data_root: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VersionSummary {
+    pub version_id: String,
+    pub record_count: u64,
+    pub created_at: String,
+    pub parent_version: Option<String>,
+    pub duplicate_rate: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatasetDashboard {
+    pub dataset_name: String,
+    pub version_id: String,
+    pub record_count: u64,
+    pub average_quality: f64,
+    pub min_quality: f64,
+    pub max_quality: f64,
+    pub language_counts: BTreeMap<String, u64>,
+    pub language_quality: BTreeMap<String, LanguageQualityStats>,
+    pub source_counts: Vec<SourceCount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LanguageQualityStats {
+    pub record_count: u64,
+    pub average_quality: f64,
+    pub min_quality: f64,
+    pub max_quality: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceCount {
+    pub source: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecordSample {
+    pub record_id: String,
+    pub source_uri: String,
+    pub language: String,
+    pub quality_score: f64,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArrowIpcPayload {
+    pub encoding: String,
+    pub row_count: u64,
+    pub data: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VersionDiff {
+    pub dataset_name: String,
+    pub base_version: String,
+    pub target_version: String,
+    pub added_records: u64,
+    pub removed_records: u64,
+    pub shared_records: u64,
+}
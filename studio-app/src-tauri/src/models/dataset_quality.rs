@@ -0,0 +1,101 @@
+//! Dataset quality, filtering, and integrity report models.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct MetadataFilterConfig {
+    pub language: Option<String>,
+    pub min_quality: Option<f64>,
+    pub source_prefix: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilterRuleOutcome {
+    pub rule: String,
+    pub configured_value: String,
+    pub record_value: String,
+    pub passed: bool,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilterExplanation {
+    pub record_id: String,
+    pub version_id: String,
+    pub kept: bool,
+    pub rule_outcomes: Vec<FilterRuleOutcome>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QualityRecalibrationResult {
+    pub dataset_name: String,
+    pub parent_version: String,
+    pub version_id: String,
+    pub output_field: String,
+    pub record_count: u64,
+    pub min_value: f64,
+    pub max_value: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthFinding {
+    pub check: String,
+    /// What the check found, in the active locale.
+    pub summary: String,
+    pub affected_records: u64,
+    pub rate: f64,
+    pub penalty: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatasetHealthReport {
+    pub dataset_name: String,
+    pub version_id: String,
+    pub record_count: u64,
+    pub score: f64,
+    pub verdict: String,
+    /// `verdict` in the active locale, for display.
+    pub verdict_label: String,
+    pub findings: Vec<HealthFinding>,
+    pub report_path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VersionIntegrityReport {
+    pub dataset_name: String,
+    pub version_id: String,
+    pub is_valid: bool,
+    pub expected_record_count: Option<u64>,
+    pub actual_record_count: u64,
+    pub malformed_lines: Vec<u64>,
+    pub duplicate_record_ids: Vec<String>,
+    pub tampered_record_ids: Vec<String>,
+    pub recorded_checksum: Option<String>,
+    pub computed_checksum: String,
+    pub issues: Vec<String>,
+}
+
+/// Jensen-Shannon divergence (0 = identical, 1 = disjoint) of one record attribute.
+#[derive(Debug, Serialize)]
+pub struct DriftDimension {
+    pub dimension: String,
+    pub score: f64,
+    /// `stable`, `moderate`, or `high`.
+    pub level: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatasetDriftReport {
+    pub run_id: String,
+    pub dataset_name: String,
+    pub training_version: String,
+    pub current_version: String,
+    pub training_record_count: u64,
+    pub current_record_count: u64,
+    /// Share of current records whose id was not in the training version.
+    pub new_record_fraction: f64,
+    pub overall_score: f64,
+    pub retrain_recommended: bool,
+    pub dimensions: Vec<DriftDimension>,
+}
@@ -0,0 +1,69 @@
+//! Benchmark evaluation result models stored per training run.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Caller-supplied evaluation payload before Studio assigns id and timestamp.
+#[derive(Debug, Deserialize)]
+pub struct EvalResultInput {
+    pub suite: String,
+    pub metrics: BTreeMap<String, f64>,
+    pub model_path: Option<String>,
+    pub dataset_version_id: Option<String>,
+    pub details: Option<Value>,
+}
+
+/// One row of `runs/<run_id>/eval.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalResult {
+    pub eval_id: String,
+    pub run_id: String,
+    pub suite: String,
+    pub metrics: BTreeMap<String, f64>,
+    pub model_path: Option<String>,
+    pub dataset_version_id: Option<String>,
+    pub details: Option<Value>,
+    pub recorded_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EvaluationRequest {
+    pub run_id: String,
+    pub dataset_name: String,
+    pub version_id: Option<String>,
+    pub metrics: Vec<String>,
+    pub model_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationStart {
+    pub task_id: String,
+    pub estimated_total_seconds: u64,
+    pub run_id: String,
+    pub dataset_name: String,
+    pub version_id: String,
+    pub metrics: Vec<String>,
+}
+
+/// One run's best score for the leaderboard metric.
+#[derive(Debug, Serialize)]
+pub struct LeaderboardEntry {
+    pub rank: u32,
+    pub run_id: String,
+    pub score: f64,
+    pub eval_id: String,
+    pub suite: String,
+    pub dataset_version_id: Option<String>,
+    pub model_path: Option<String>,
+    pub recorded_at: String,
+    pub config_summary: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Leaderboard {
+    pub metric: String,
+    pub suite: Option<String>,
+    pub lower_is_better: bool,
+    pub entries: Vec<LeaderboardEntry>,
+}
@@ -0,0 +1,55 @@
+//! Pre-flight training feasibility models.
+
+use serde::{Deserialize, Serialize};
+
+/// Subset of `forge train` options that drive cost; unset fields use CLI defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FeasibilityTrainConfig {
+    pub epochs: Option<u32>,
+    pub batch_size: Option<u32>,
+    pub max_token_length: Option<u32>,
+    pub validation_split: Option<f64>,
+    pub hidden_dim: Option<u32>,
+    pub num_layers: Option<u32>,
+    pub attention_heads: Option<u32>,
+    pub mlp_hidden_dim: Option<u32>,
+    pub mlp_layers: Option<u32>,
+    pub vocabulary_size: Option<u32>,
+    pub position_embedding_type: Option<String>,
+    pub precision_mode: Option<String>,
+    pub optimizer_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrainingFeasibilityRequest {
+    pub dataset_name: String,
+    pub version_id: Option<String>,
+    #[serde(default)]
+    pub train_config: FeasibilityTrainConfig,
+}
+
+/// `severity` is `blocking` when the run cannot succeed as configured, else `warning`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FeasibilityWarning {
+    pub code: String,
+    pub severity: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingFeasibilityReport {
+    pub dataset_name: String,
+    pub version_id: String,
+    pub record_count: u64,
+    pub train_tokens_per_epoch: u64,
+    pub vocabulary_size: u64,
+    pub parameter_count: u64,
+    pub precision_mode: String,
+    pub accelerator: String,
+    pub estimated_memory_gb: f64,
+    /// Memory of the first GPU; `None` when the device shares system memory.
+    pub available_memory_gb: Option<f64>,
+    pub estimated_training_hours: f64,
+    pub feasible: bool,
+    pub warnings: Vec<FeasibilityWarning>,
+}
@@ -0,0 +1,18 @@
+//! Forge CLI update and compatibility checks.
+
+use serde::Serialize;
+
+/// Installed and latest forge versions, and how well they suit this Studio.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ForgeUpdateCheck {
+    pub studio_version: String,
+    /// `None` when `forge --version` could not be run or read.
+    pub installed_version: Option<String>,
+    /// `None` when the release feed could not be reached.
+    pub latest_version: Option<String>,
+    pub release_url: Option<String>,
+    pub update_available: bool,
+    /// `compatible`, `untested`, `incompatible`, or `unknown`.
+    pub compatibility: String,
+    pub warnings: Vec<String>,
+}
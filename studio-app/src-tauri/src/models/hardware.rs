@@ -0,0 +1,28 @@
+//! Hardware profile models mirroring `forge hardware-profile --json`.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HardwareGpu {
+    pub index: u32,
+    pub name: String,
+    pub total_memory_gb: f64,
+    /// CUDA compute capability such as `8.0`; absent on other backends.
+    pub capability: Option<String>,
+}
+
+/// Detected accelerators plus the training defaults the CLI recommends for them.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HardwareProfile {
+    pub accelerator: String,
+    pub gpu_count: u32,
+    #[serde(default)]
+    pub gpus: Vec<HardwareGpu>,
+    pub bf16_supported: bool,
+    pub recommended_precision_mode: String,
+    pub recommended_batch_size: u32,
+    pub suggested_profile: String,
+    /// Set by Studio when the profile is read, so the UI can show cache age.
+    #[serde(default)]
+    pub detected_at: String,
+}
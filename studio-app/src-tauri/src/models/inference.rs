@@ -0,0 +1,65 @@
+//! Batch inference models for qualitative checkpoint review.
+
+use crate::models::ChatSessionOptions;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct BatchInferenceRequest {
+    pub run_id: String,
+    pub model_path: Option<String>,
+    pub dataset_name: String,
+    pub version_id: Option<String>,
+    pub sample_size: usize,
+    pub options: Option<ChatSessionOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchInferencePair {
+    pub record_id: String,
+    pub prompt: String,
+    pub response: String,
+    pub error: Option<String>,
+}
+
+/// Contents of `runs/<run_id>/inference/<batch_id>.json`, rewritten after each record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchInferenceRun {
+    pub batch_id: String,
+    pub run_id: String,
+    pub model_path: String,
+    pub dataset_name: String,
+    pub version_id: String,
+    pub sample_size: usize,
+    pub status: String,
+    pub created_at: String,
+    pub finished_at: Option<String>,
+    pub pairs: Vec<BatchInferencePair>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchInferenceStart {
+    pub batch_id: String,
+    pub run_id: String,
+    pub record_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchInferenceRef {
+    pub run_id: String,
+    pub batch_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchInferenceComparisonRow {
+    pub record_id: String,
+    pub prompt: String,
+    pub left_response: Option<String>,
+    pub right_response: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchInferenceComparison {
+    pub left_model_path: String,
+    pub right_model_path: String,
+    pub rows: Vec<BatchInferenceComparisonRow>,
+}
@@ -0,0 +1,78 @@
+//! Model lineage graph models.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct LineageRunNode {
+    pub run_id: String,
+    pub dataset_name: String,
+    pub dataset_version_id: String,
+    pub output_dir: String,
+    pub parent_model_path: Option<String>,
+    pub model_path: Option<String>,
+    pub config_hash: String,
+    pub created_at: String,
+    pub artifact_contract_path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LineageEdge {
+    pub from: String,
+    pub to: String,
+    #[serde(rename = "type")]
+    pub edge_type: String,
+}
+
+/// Dataset version node; `record_count` and `created_at` come from its catalog when present.
+#[derive(Debug, Serialize)]
+pub struct LineageDatasetNode {
+    pub node_id: String,
+    pub dataset_name: String,
+    pub version_id: String,
+    pub created_at: Option<String>,
+    pub record_count: Option<u64>,
+    pub consumed_by_run_ids: Vec<String>,
+    pub produced_by_run_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LineageGraphSummary {
+    pub run_count: u64,
+    pub dataset_count: u64,
+    pub edge_count: u64,
+    pub runs: Vec<LineageRunNode>,
+    pub datasets: Vec<LineageDatasetNode>,
+    pub edges: Vec<LineageEdge>,
+}
+
+/// Optional subgraph filters; `max_depth` counts edge hops from the root run.
+#[derive(Debug, Default, Deserialize)]
+pub struct LineageGraphFilter {
+    pub root_run_id: Option<String>,
+    pub dataset_name: Option<String>,
+    pub max_depth: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LineageExportResult {
+    pub format: String,
+    pub output_path: String,
+    pub node_count: u64,
+    pub edge_count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LineageAuditIssue {
+    pub kind: String,
+    pub subject: String,
+    pub detail: String,
+    pub suggested_repair: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LineageAuditReport {
+    pub checked_runs: u64,
+    pub checked_edges: u64,
+    pub healthy: bool,
+    pub issues: Vec<LineageAuditIssue>,
+}
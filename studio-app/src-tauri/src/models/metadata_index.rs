@@ -0,0 +1,31 @@
+//! SQLite metadata index models.
+
+use serde::Serialize;
+
+/// Outcome of bringing the metadata index up to date with the data root.
+#[derive(Debug, Serialize)]
+pub struct MetadataIndexSync {
+    pub index_path: String,
+    pub dataset_count: u64,
+    pub version_count: u64,
+    pub run_count: u64,
+    pub lineage_edge_count: u64,
+    /// Catalogs, runs, or lineage files re-read because they changed.
+    pub refreshed_source_count: u64,
+    /// Sources deleted from disk since the previous sync.
+    pub removed_source_count: u64,
+    /// Sources that could not be parsed; retried on the next sync.
+    pub unreadable_sources: Vec<String>,
+}
+
+/// One dataset, version, run, or model matching a metadata search.
+#[derive(Debug, Serialize)]
+pub struct MetadataSearchHit {
+    /// `dataset`, `version`, `run`, or `model`.
+    pub kind: String,
+    /// Dataset name, version id, run id, or model path.
+    pub id: String,
+    pub dataset_name: Option<String>,
+    pub run_id: Option<String>,
+    pub created_at: Option<String>,
+}
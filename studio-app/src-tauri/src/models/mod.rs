@@ -0,0 +1,117 @@
+//! Shared serialization models for Studio commands.
+//!
+//! Models are grouped by domain in submodules and re-exported here so
+//! commands import them from `crate::models` regardless of grouping.
+
+mod app_log;
+mod backend_event;
+mod chat;
+mod data_root;
+mod dataset;
+mod dataset_quality;
+mod evaluation;
+mod feasibility;
+mod forge_update;
+mod hardware;
+mod inference;
+mod lineage;
+mod metadata_index;
+mod model_artifact;
+mod pipeline;
+mod pipeline_edit;
+mod pipeline_merge;
+mod pipeline_run;
+mod plugin;
+mod registry;
+mod run;
+mod run_gc;
+mod settings;
+mod studio_error;
+mod sweep;
+mod task;
+mod telemetry;
+mod training;
+mod usage;
+
+pub use app_log::{AppLogEntry, CrashReport};
+pub use backend_event::{BackendEvent, BackendEventEnvelope};
+pub use chat::{
+    ChatCompleteEvent, ChatPromptStart, ChatSessionOptions, ChatSessionStart, ChatTokenEvent,
+    ChatTurn,
+};
+pub use data_root::{
+    AuditLogEntry, DataRootCheck, DataRootCheckItem, DataRootInit, DataRootInitOptions,
+    WorkspaceBackup, WorkspaceBackupOptions, WorkspaceRestore,
+};
+pub use dataset::{
+    ArrowIpcPayload, DatasetDashboard, DatasetListing, LanguageQualityStats, RecordSample,
+    SourceCount, VersionDiff, VersionSummary,
+};
+pub use dataset_quality::{
+    DatasetDriftReport, DatasetHealthReport, DriftDimension, FilterExplanation, FilterRuleOutcome,
+    HealthFinding, MetadataFilterConfig, QualityRecalibrationResult, VersionIntegrityReport,
+};
+pub use evaluation::{
+    EvalResult, EvalResultInput, EvaluationRequest, EvaluationStart, Leaderboard, LeaderboardEntry,
+};
+pub use feasibility::{
+    FeasibilityTrainConfig, FeasibilityWarning, TrainingFeasibilityReport,
+    TrainingFeasibilityRequest,
+};
+pub use forge_update::ForgeUpdateCheck;
+pub use hardware::HardwareProfile;
+pub use inference::{
+    BatchInferenceComparison, BatchInferenceComparisonRow, BatchInferencePair, BatchInferenceRef,
+    BatchInferenceRequest, BatchInferenceRun, BatchInferenceStart,
+};
+pub use lineage::{
+    LineageAuditIssue, LineageAuditReport, LineageDatasetNode, LineageEdge, LineageExportResult,
+    LineageGraphFilter, LineageGraphSummary, LineageRunNode,
+};
+pub use metadata_index::{MetadataIndexSync, MetadataSearchHit};
+pub use model_artifact::{
+    ModelArtifactInspection, ModelExportComparison, ModelExportRecord, ModelExportStart,
+    ModelExportVariant, ModelTensorInfo,
+};
+pub use pipeline::{
+    PipelineBundleFile, PipelineCanvasBundleExport, PipelineCanvasBundleImport, PipelineCanvasDiff,
+    PipelineCanvasExportEntry, PipelineCanvasExportResult, PipelineCanvasImport,
+    PipelineCanvasIssue, PipelineCanvasSnapshot, PipelineCanvasState, PipelineCanvasValidation,
+    PipelineCanvasVariable, PipelineConfigImport, PipelineEdgeCondition, PipelineEdgeDiff,
+    PipelineEdgeSnapshot, PipelineNodeDiff, PipelineNodeGroup, PipelineNodeParameter,
+    PipelineNodeSnapshot, PipelineNodeType, PipelineNodeTypeCatalog, PipelineParameterRange,
+    PipelineVariableUsage,
+};
+pub use pipeline_edit::{
+    PipelineCanvasEdit, PipelineCanvasEditCursor, PipelineCanvasEditJournal, PipelineCanvasEditStep,
+};
+pub use pipeline_merge::{PipelineCanvasMerge, PipelineMergeConflict, PipelineMergeRename};
+pub use pipeline_run::{
+    PipelineCacheEntry, PipelineNodeOutputs, PipelinePlanStep, PipelineRunHistory,
+    PipelineRunHistorySummary, PipelineRunNode, PipelineRunNodeHistory, PipelineRunOptions,
+    PipelineRunPlan, PipelineRunRecord, PipelineRunStart, PipelineSchedule,
+};
+pub use plugin::{PluginDatasetAnalysis, PluginInfo, PluginManifest};
+pub use registry::{ModelRegistryFile, RegisteredModel, RegisteredModelVersion};
+pub use run::{
+    ArtifactContractVerification, ArtifactHashCheck, ComparedRun, ConfigFieldDiff, ConfigKeyChange,
+    EpochLossAlignment, RunArtifactPaths, RunAttachment, RunAttachmentContent, RunCheckpoint,
+    RunCloneOverrides, RunCloneStart, RunComparison, RunConfigDiff, RunFailureDiagnosis,
+    RunFinalMetrics, RunIndexRebuildResult, RunLifecycleRecord, RunRemovalResult, RunResumeStart,
+    RunUserMetadata, StepLossAlignment, TrainingRunDetails,
+};
+pub use run_gc::{RunArtifactGc, RunGcPolicy, RunGcReport, RunGcSkip};
+pub use settings::{AppSettings, EventBridgeSettings, UserIdentity};
+pub use studio_error::ForgeStudioError;
+pub use sweep::{
+    SweepParameter, SweepRecord, SweepRequest, SweepSpec, SweepStart, SweepStatus, SweepTrial,
+    SweepTrialStatus,
+};
+pub use task::{CommandTaskStart, CommandTaskStatus, TrainTaskLog};
+pub use telemetry::{AcceleratorSample, CostRates, PowerSample, RunCost, TelemetrySnapshot};
+pub use training::{
+    CurveFinding, HistoryWatchStart, RunStateChange, RunStateWatchStart, SmoothedLossPoint,
+    TensorboardExportResult, TrainingBatchLoss, TrainingCurveAnalysis, TrainingEpoch,
+    TrainingHistory, TrainingHistoryUpdate, TrainingRunQuery, TrainingRunSummary,
+};
+pub use usage::{CommandUsage, UsageTelemetry};
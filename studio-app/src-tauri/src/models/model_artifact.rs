@@ -0,0 +1,75 @@
+//! Model artifact inspection models.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize)]
+pub struct ModelTensorInfo {
+    pub name: String,
+    pub dtype: String,
+    pub shape: Vec<u64>,
+    pub parameter_count: u64,
+}
+
+/// Sanity-check view of a weights file; tensor detail is only available for safetensors.
+#[derive(Debug, Serialize)]
+pub struct ModelArtifactInspection {
+    pub model_path: String,
+    pub format: String,
+    pub file_size_bytes: u64,
+    pub parameter_count: Option<u64>,
+    pub parameter_count_is_estimate: bool,
+    pub dtype_tensor_counts: BTreeMap<String, u64>,
+    pub tensors: Vec<ModelTensorInfo>,
+    pub run_id: Option<String>,
+    pub config_hash: Option<String>,
+    pub notes: Vec<String>,
+}
+
+/// One row of `runs/<run_id>/exports.json`; `status` follows the export task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelExportRecord {
+    pub export_id: String,
+    pub run_id: String,
+    pub format: String,
+    pub quantization: Option<String>,
+    pub source_model_path: String,
+    pub output_path: String,
+    pub task_id: String,
+    pub status: String,
+    pub requested_at: String,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModelExportStart {
+    pub estimated_total_seconds: u64,
+    pub export: ModelExportRecord,
+}
+
+/// One deployable artifact of a run, measured against the run's source model.
+#[derive(Debug, Serialize)]
+pub struct ModelExportVariant {
+    /// `source` for the trained model, otherwise `<format>-<quantization>`.
+    pub label: String,
+    pub format: String,
+    pub quantization: Option<String>,
+    pub path: String,
+    pub size_bytes: u64,
+    /// Size relative to the source model; `0.25` means a quarter of the size.
+    pub size_ratio: Option<f64>,
+    /// Wall time to read the file from disk; says nothing about runtime load time.
+    pub disk_read_seconds: Option<f64>,
+    /// Latest recorded eval score per `<suite>/<metric>` for this artifact.
+    pub metrics: BTreeMap<String, f64>,
+    /// Variant minus source for metrics recorded on both.
+    pub metric_deltas: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModelExportComparison {
+    pub run_id: String,
+    /// Source model first, then completed exports in request order.
+    pub variants: Vec<ModelExportVariant>,
+    pub notes: Vec<String>,
+}
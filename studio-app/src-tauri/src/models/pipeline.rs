@@ -0,0 +1,279 @@
+//! Pipeline canvas snapshot and export models.
+
+use crate::models::ConfigKeyChange;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineNodeSnapshot {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub node_type: String,
+    pub title: String,
+    pub canvas_x: f64,
+    pub canvas_y: f64,
+    pub config: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineEdgeSnapshot {
+    pub id: String,
+    pub source_node_id: String,
+    pub target_node_id: String,
+    /// Gate added in canvas format v2; `None` means the target always runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<PipelineEdgeCondition>,
+}
+
+/// Runs an edge's target only if the source node's output passes, e.g.
+/// `record_count > 1000` on the version an upstream filter wrote.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineEdgeCondition {
+    /// `record_count` of the dataset version an ingest or filter source wrote.
+    pub metric: String,
+    /// `>`, `>=`, `<`, `<=`, `==`, or `!=`.
+    pub operator: String,
+    pub value: f64,
+}
+
+/// A collapsible container that runs its member nodes as one composite step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineNodeGroup {
+    pub id: String,
+    pub title: String,
+    pub node_ids: Vec<String>,
+    /// Drawn as a single box instead of its members.
+    #[serde(default)]
+    pub collapsed: bool,
+    pub canvas_x: f64,
+    pub canvas_y: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasExportResult {
+    pub output_path: String,
+    /// Stale dataset or version references; the file is written regardless.
+    pub warnings: Vec<PipelineCanvasIssue>,
+}
+
+/// A previously exported canvas file under `outputs/canvas/`.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasExportEntry {
+    pub path: String,
+    pub file_name: String,
+    /// `json` or `yaml-pipeline`, matching the export format that wrote it.
+    pub format: String,
+    pub size_bytes: u64,
+    pub modified_at: Option<String>,
+}
+
+/// A canvas file read back and migrated to the current format.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasImport {
+    pub source_path: String,
+    /// Version found in the file, before migration.
+    pub source_format_version: u32,
+    pub format_version: u32,
+    pub start_node_id: Option<String>,
+    pub nodes: Vec<PipelineNodeSnapshot>,
+    pub edges: Vec<PipelineEdgeSnapshot>,
+    pub groups: Vec<PipelineNodeGroup>,
+}
+
+/// A file a node config referenced, packed into a canvas bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineBundleFile {
+    pub node_id: String,
+    /// Config key that held the path, such as `architecture_file`.
+    pub field: String,
+    /// Where the file sits inside the bundle, e.g. `files/train-1/model.json`.
+    pub bundle_path: String,
+    /// Path on the machine that exported the bundle.
+    pub original_path: String,
+    pub sha256: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasBundleExport {
+    pub output_path: String,
+    /// Forge version the bundle asks for, from the exporting workspace.
+    pub forge_version: Option<String>,
+    pub files: Vec<PipelineBundleFile>,
+    /// Referenced files that were left out, such as missing or oversized ones.
+    pub warnings: Vec<String>,
+}
+
+/// A canvas bundle unpacked with node paths pointing at the extracted files.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasBundleImport {
+    pub source_path: String,
+    /// Directory the bundled files were extracted into.
+    pub files_dir: String,
+    pub bundle_forge_version: Option<String>,
+    pub installed_forge_version: Option<String>,
+    pub start_node_id: Option<String>,
+    pub nodes: Vec<PipelineNodeSnapshot>,
+    pub edges: Vec<PipelineEdgeSnapshot>,
+    pub groups: Vec<PipelineNodeGroup>,
+    /// Version mismatches and other differences worth checking before a run.
+    pub warnings: Vec<String>,
+}
+
+/// A forge run-spec file converted into canvas nodes chained in step order.
+#[derive(Debug, Serialize)]
+pub struct PipelineConfigImport {
+    pub source_path: String,
+    pub start_node_id: Option<String>,
+    pub nodes: Vec<PipelineNodeSnapshot>,
+    pub edges: Vec<PipelineEdgeSnapshot>,
+    /// Settings the canvas could not carry over as written.
+    pub warnings: Vec<String>,
+}
+
+/// One problem found in a canvas graph, pointing at the nodes or edge involved.
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineCanvasIssue {
+    /// `empty_id`, `duplicate_node_id`, `unknown_node_type`, `invalid_config`,
+    /// `dangling_edge`, `cycle`, `missing_start_node`, `unreachable_node`,
+    /// `stale_reference`, `invalid_condition`, `invalid_group`, or
+    /// `group_reentry`; lint adds `missing_quality_threshold` and
+    /// `unfed_train_node`.
+    pub kind: String,
+    /// `error` blocks a run; `warning` only means some nodes will be skipped.
+    pub severity: String,
+    pub node_ids: Vec<String>,
+    pub edge_id: Option<String>,
+    /// Config key at fault, for `invalid_config` issues.
+    pub field: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasValidation {
+    /// True when no issue has `error` severity.
+    pub valid: bool,
+    pub issues: Vec<PipelineCanvasIssue>,
+}
+
+/// One configurable field on a pipeline node, keyed like the node's `config`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineNodeParameter {
+    pub name: String,
+    /// CLI flag the value is passed with; `None` for positional arguments.
+    pub flag: Option<String>,
+    /// `string`, `integer`, `float`, `boolean`, or `path`.
+    pub value_type: String,
+    pub required: bool,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub range: Option<PipelineParameterRange>,
+    /// Accepted values for enum-like parameters; empty means any value.
+    #[serde(default)]
+    pub allowed_values: Vec<String>,
+}
+
+/// Numeric bounds named as in JSON Schema; unset bounds are open.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PipelineParameterRange {
+    #[serde(default)]
+    pub minimum: Option<f64>,
+    #[serde(default)]
+    pub exclusive_minimum: Option<f64>,
+    #[serde(default)]
+    pub maximum: Option<f64>,
+    #[serde(default)]
+    pub exclusive_maximum: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineNodeType {
+    pub node_type: String,
+    /// Forge subcommand the node runs; `None` for custom nodes, which hold raw args.
+    pub command: Option<String>,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parameters: Vec<PipelineNodeParameter>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PipelineNodeTypeCatalog {
+    /// `forge` when read from the installed CLI, `bundled` for the built-in schema.
+    pub source: String,
+    /// Why the installed CLI could not be used, when the bundled schema was.
+    pub fallback_reason: Option<String>,
+    pub node_types: Vec<PipelineNodeType>,
+}
+
+/// Live canvas state sent by the editor for autosave.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineCanvasState {
+    pub start_node_id: Option<String>,
+    pub nodes: Vec<PipelineNodeSnapshot>,
+    pub edges: Vec<PipelineEdgeSnapshot>,
+    /// Added in canvas format v3; canvases without groups omit the field.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<PipelineNodeGroup>,
+}
+
+/// One autosaved canvas under `outputs/canvas/.history/`.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasSnapshot {
+    /// UTC save time as `YYYYMMDDTHHMMSSmmmZ`, which also names the file.
+    pub snapshot_id: String,
+    pub saved_at: String,
+    pub path: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+}
+
+/// A node present in both canvases whose type, title, or config differs.
+#[derive(Debug, Serialize)]
+pub struct PipelineNodeDiff {
+    pub node_id: String,
+    pub node_type: String,
+    pub title: String,
+    /// Changes to `type` and `title`.
+    pub attribute_changes: Vec<ConfigKeyChange>,
+    pub config_changes: Vec<ConfigKeyChange>,
+}
+
+/// An edge id present in both canvases that now connects different nodes.
+#[derive(Debug, Serialize)]
+pub struct PipelineEdgeDiff {
+    pub edge_id: String,
+    pub before: PipelineEdgeSnapshot,
+    pub after: PipelineEdgeSnapshot,
+}
+
+/// Changes from canvas `path_a` to canvas `path_b`; nodes and edges match by id.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasDiff {
+    pub path_a: String,
+    pub path_b: String,
+    pub start_node_change: Option<ConfigKeyChange>,
+    pub added_nodes: Vec<PipelineNodeSnapshot>,
+    pub removed_nodes: Vec<PipelineNodeSnapshot>,
+    pub modified_nodes: Vec<PipelineNodeDiff>,
+    /// Nodes only repositioned on the canvas, kept apart from real modifications.
+    pub moved_node_ids: Vec<String>,
+    pub added_edges: Vec<PipelineEdgeSnapshot>,
+    pub removed_edges: Vec<PipelineEdgeSnapshot>,
+    pub modified_edges: Vec<PipelineEdgeDiff>,
+}
+
+/// Where a `${name}` placeholder appears in node configs.
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineVariableUsage {
+    pub node_id: String,
+    pub field: String,
+}
+
+/// One `${name}` placeholder; a run must supply its value.
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineCanvasVariable {
+    pub name: String,
+    pub usages: Vec<PipelineVariableUsage>,
+}
@@ -0,0 +1,45 @@
+//! Canvas undo and redo journal models.
+
+use crate::models::PipelineCanvasState;
+use serde::{Deserialize, Serialize};
+
+/// One canvas edit, holding the canvas before and after it so it can be
+/// undone, redone, or replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineCanvasEdit {
+    /// Increases by one per edit along the current history.
+    pub edit_id: u64,
+    /// Short description shown in undo menus, e.g. `Move node`.
+    pub label: String,
+    pub applied_at: String,
+    pub before: PipelineCanvasState,
+    pub after: PipelineCanvasState,
+}
+
+/// Edit history of one canvas file; edits at and after `position` are redoable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineCanvasEditJournal {
+    pub canvas_path: String,
+    /// Number of edits currently applied.
+    pub position: usize,
+    pub edits: Vec<PipelineCanvasEdit>,
+}
+
+/// Where a journal stands after a push, undo, or redo.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasEditCursor {
+    pub canvas_path: String,
+    pub position: usize,
+    pub edit_count: usize,
+    /// Label of the edit `undo_canvas_edit` would revert.
+    pub undo_label: Option<String>,
+    /// Label of the edit `redo_canvas_edit` would reapply.
+    pub redo_label: Option<String>,
+}
+
+/// Canvas to show after an undo or redo.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasEditStep {
+    pub canvas: PipelineCanvasState,
+    pub cursor: PipelineCanvasEditCursor,
+}
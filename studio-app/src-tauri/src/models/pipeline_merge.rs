@@ -0,0 +1,40 @@
+//! Three-way canvas merge models.
+
+use crate::models::{PipelineEdgeSnapshot, PipelineNodeGroup, PipelineNodeSnapshot};
+use serde::Serialize;
+use serde_json::Value;
+
+/// A change both sides made differently; the merged canvas keeps ours.
+#[derive(Debug, Serialize)]
+pub struct PipelineMergeConflict {
+    /// `config`, `attribute`, `edit_delete`, `edge`, or `start_node`.
+    pub kind: String,
+    /// Node or edge id the conflict is on; empty for the start node.
+    pub item_id: String,
+    /// Config key, or `type`/`title` for attribute conflicts.
+    pub key: Option<String>,
+    pub base: Option<Value>,
+    pub theirs: Option<Value>,
+    pub ours: Option<Value>,
+}
+
+/// A node or edge both sides added under the same id; theirs was renamed.
+#[derive(Debug, Serialize)]
+pub struct PipelineMergeRename {
+    pub from_id: String,
+    pub to_id: String,
+}
+
+/// Result of merging `theirs` and `ours` from their common `base`.
+#[derive(Debug, Serialize)]
+pub struct PipelineCanvasMerge {
+    pub start_node_id: Option<String>,
+    pub nodes: Vec<PipelineNodeSnapshot>,
+    pub edges: Vec<PipelineEdgeSnapshot>,
+    pub groups: Vec<PipelineNodeGroup>,
+    pub conflicts: Vec<PipelineMergeConflict>,
+    pub renamed_node_ids: Vec<PipelineMergeRename>,
+    pub renamed_edge_ids: Vec<PipelineMergeRename>,
+    /// Edges left out because one side deleted a node the edge connects.
+    pub dropped_edge_ids: Vec<String>,
+}
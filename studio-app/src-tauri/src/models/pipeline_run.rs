@@ -0,0 +1,219 @@
+//! Pipeline run, step cache, and run history models.
+
+use crate::models::{PipelineCanvasIssue, PipelineCanvasState, PipelineEdgeCondition};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One canvas node scheduled as a forge task within a pipeline run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRunNode {
+    pub node_id: String,
+    pub node_type: String,
+    pub title: String,
+    pub args: Vec<String>,
+    /// Upstream node ids that must complete before this node starts.
+    pub depends_on: Vec<String>,
+    /// Conditions on incoming edges, keyed by upstream node id; an unmet one skips the node.
+    #[serde(default)]
+    pub conditions: BTreeMap<String, PipelineEdgeCondition>,
+    /// Canvas group the node belongs to; a group fails as a unit.
+    #[serde(default)]
+    pub group_id: Option<String>,
+    /// `pending`, `running`, `completed`, `cached`, `failed`, or `skipped`.
+    pub status: String,
+    pub task_id: Option<String>,
+    pub progress_percent: f64,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub error: Option<String>,
+    /// Hash of the command, config, and input dataset version; `None` if not cacheable.
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    /// Pipeline run whose earlier execution a `cached` node reused.
+    #[serde(default)]
+    pub cached_from_run_id: Option<String>,
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    /// Dataset versions, model files, and export manifests the node produced.
+    #[serde(default)]
+    pub artifacts: Vec<String>,
+    #[serde(default)]
+    pub outputs: PipelineNodeOutputs,
+}
+
+/// Ids a node produced, so the canvas can link to the dataset dashboard or
+/// training run behind it; fields a node type does not produce stay `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PipelineNodeOutputs {
+    /// Dataset an ingest or filter node wrote a version of.
+    pub dataset_name: Option<String>,
+    pub dataset_version_id: Option<String>,
+    /// Training run a train node recorded, as listed by `list_training_runs`.
+    pub training_run_id: Option<String>,
+    pub model_path: Option<String>,
+}
+
+/// Persisted in `<data_root>/pipeline_runs/<pipeline_run_id>.json`; nodes in run order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRunRecord {
+    pub pipeline_run_id: String,
+    pub start_node_id: String,
+    pub status: String,
+    pub created_at: String,
+    pub finished_at: Option<String>,
+    #[serde(default)]
+    pub overall_percent: f64,
+    /// Rerun every node even when the pipeline cache holds a matching execution.
+    #[serde(default)]
+    pub force: bool,
+    /// Most node tasks the run keeps in the task store at once.
+    #[serde(default)]
+    pub max_parallel: usize,
+    /// Pipeline schedule that triggered the run; `None` for manual runs.
+    #[serde(default)]
+    pub schedule_id: Option<String>,
+    pub nodes: Vec<PipelineRunNode>,
+}
+
+/// Narrows a pipeline run to part of its plan, e.g. to retrain without
+/// re-ingesting, and controls reuse of cached node executions.
+#[derive(Debug, Default, Deserialize)]
+pub struct PipelineRunOptions {
+    /// Run this node and everything downstream of it.
+    #[serde(default)]
+    pub start_from_node_id: Option<String>,
+    /// Run only these nodes; with `start_from_node_id`, only those downstream of it.
+    #[serde(default)]
+    pub only_nodes: Vec<String>,
+    /// Rerun nodes whose fingerprint matches an earlier successful execution.
+    #[serde(default)]
+    pub force: bool,
+    /// Most nodes on independent branches to run at once; defaults to 2.
+    #[serde(default)]
+    pub max_parallel: Option<usize>,
+    /// Values for the canvas's `${name}` placeholders.
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    /// Set by the schedule runner; callers cannot supply it.
+    #[serde(skip)]
+    pub schedule_id: Option<String>,
+}
+
+/// One forge command a run would execute, in `plan_pipeline_canvas` order.
+#[derive(Debug, Serialize)]
+pub struct PipelinePlanStep {
+    pub node_id: String,
+    pub node_type: String,
+    pub title: String,
+    /// Full forge arguments with variables substituted, command name first.
+    pub args: Vec<String>,
+    pub depends_on: Vec<String>,
+    pub group_id: Option<String>,
+    pub estimated_seconds: u64,
+}
+
+/// What `run_pipeline_canvas` would execute, without starting anything.
+#[derive(Debug, Serialize)]
+pub struct PipelineRunPlan {
+    pub steps: Vec<PipelinePlanStep>,
+    /// Sum of step estimates, i.e. the duration if nothing runs in parallel
+    /// and no step is reused from the cache.
+    pub total_estimated_seconds: u64,
+    /// Canvas nodes not reachable from the start node, which a run leaves out.
+    pub unreachable_node_ids: Vec<String>,
+    /// Reachable nodes left out by the run selection.
+    pub excluded_node_ids: Vec<String>,
+}
+
+/// A successful node execution in `<data_root>/pipeline_runs/cache.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineCacheEntry {
+    pub fingerprint: String,
+    pub node_id: String,
+    pub args: Vec<String>,
+    pub pipeline_run_id: String,
+    pub completed_at: String,
+    /// What the execution produced, reported again by nodes that reuse it.
+    #[serde(default)]
+    pub artifacts: Vec<String>,
+    #[serde(default)]
+    pub outputs: PipelineNodeOutputs,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PipelineRunStart {
+    pub pipeline_run_id: String,
+    pub node_count: usize,
+    /// Canvas nodes not reachable from the start node, which the run leaves out.
+    pub unreachable_node_ids: Vec<String>,
+    /// Reachable nodes left out by the run selection.
+    pub excluded_node_ids: Vec<String>,
+    /// Stale dataset or version references in the planned nodes.
+    pub warnings: Vec<PipelineCanvasIssue>,
+}
+
+/// One node's outcome in pipeline run history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRunNodeHistory {
+    pub node_id: String,
+    pub node_type: String,
+    pub title: String,
+    pub status: String,
+    pub exit_code: Option<i32>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub duration_seconds: Option<i64>,
+    pub artifacts: Vec<String>,
+    #[serde(default)]
+    pub outputs: PipelineNodeOutputs,
+    pub cached_from_run_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// One canvas execution in `<data_root>/outputs/pipeline-runs/<pipeline_run_id>.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRunHistory {
+    pub pipeline_run_id: String,
+    pub status: String,
+    pub created_at: String,
+    pub finished_at: Option<String>,
+    pub duration_seconds: Option<i64>,
+    /// The graph as it ran, variables substituted, so later edits do not rewrite history.
+    pub canvas: PipelineCanvasState,
+    /// Values the run substituted for the canvas's `${name}` placeholders.
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    #[serde(default)]
+    pub schedule_id: Option<String>,
+    pub nodes: Vec<PipelineRunNodeHistory>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PipelineRunHistorySummary {
+    pub pipeline_run_id: String,
+    pub status: String,
+    pub created_at: String,
+    pub finished_at: Option<String>,
+    pub duration_seconds: Option<i64>,
+    pub schedule_id: Option<String>,
+    pub node_count: usize,
+    pub failed_node_ids: Vec<String>,
+}
+
+/// A saved canvas run on a cron schedule, kept in `<data_root>/pipeline_runs/schedules.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineSchedule {
+    pub schedule_id: String,
+    /// Canvas file saved by `export_pipeline_canvas`, relative to the data root or absolute.
+    pub canvas_path: String,
+    /// Five-field cron expression evaluated in UTC, or a shorthand such as `@daily`.
+    pub cron: String,
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    pub created_at: String,
+    pub next_run_at: String,
+    pub last_run_at: Option<String>,
+    pub last_pipeline_run_id: Option<String>,
+    /// Why the last trigger did not start a run.
+    pub last_error: Option<String>,
+}
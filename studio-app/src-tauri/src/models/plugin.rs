@@ -0,0 +1,66 @@
+//! Studio plugins: their manifests and what running them returns.
+
+use crate::models::PipelineNodeType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// `plugin.json` in a plugin's directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginManifest {
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    /// Version of the subprocess protocol the executable speaks.
+    pub protocol_version: u32,
+    /// Program run for commands and analyzers, relative to the plugin directory.
+    #[serde(default)]
+    pub executable: Option<String>,
+    #[serde(default)]
+    pub commands: Vec<PluginCommand>,
+    #[serde(default)]
+    pub dataset_analyzers: Vec<PluginDatasetAnalyzer>,
+    /// Canvas node types; each runs its forge `command` with its parameters as flags.
+    #[serde(default)]
+    pub node_types: Vec<PipelineNodeType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginCommand {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// True when the command never writes, so it stays available in read-only mode.
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginDatasetAnalyzer {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A plugin found at startup, loaded or not.
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginInfo {
+    pub plugin_dir: String,
+    /// Holds only the directory name as `name` when the manifest is unreadable.
+    #[serde(flatten)]
+    pub manifest: PluginManifest,
+    /// Why the plugin was not loaded; its commands and node types are unavailable.
+    pub load_error: Option<String>,
+}
+
+/// What a plugin's dataset analyzer reported for one dataset version.
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginDatasetAnalysis {
+    pub plugin_name: String,
+    pub analyzer: String,
+    pub dataset_name: String,
+    pub version_id: String,
+    /// The analyzer's reply, as it sent it.
+    pub result: Value,
+}
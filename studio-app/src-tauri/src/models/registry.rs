@@ -0,0 +1,36 @@
+//! Model registry models persisted in `<data_root>/models/registry.json`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredModelVersion {
+    pub name: String,
+    pub version: u32,
+    pub run_id: String,
+    pub model_path: String,
+    pub stage: String,
+    pub description: Option<String>,
+    pub registered_at: String,
+    pub stage_updated_at: String,
+    /// Identities from settings of who registered the version and who last
+    /// changed its stage, including by promoting a version that retired it.
+    #[serde(default)]
+    pub registered_by: Option<String>,
+    #[serde(default)]
+    pub stage_updated_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredModel {
+    pub name: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub versions: Vec<RegisteredModelVersion>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ModelRegistryFile {
+    #[serde(default)]
+    pub models: BTreeMap<String, RegisteredModel>,
+}
@@ -0,0 +1,257 @@
+//! Training run lifecycle and detail models.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunLifecycleEvent {
+    pub state: String,
+    pub timestamp: String,
+    pub message: Option<String>,
+}
+
+/// Typed view of `runs/<run_id>/lifecycle.json` written by the forge CLI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunLifecycleRecord {
+    pub run_id: String,
+    pub dataset_name: String,
+    pub dataset_version_id: String,
+    pub output_dir: String,
+    pub parent_model_path: Option<String>,
+    pub config_hash: String,
+    pub state: String,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub events: Vec<RunLifecycleEvent>,
+    pub artifact_contract_path: Option<String>,
+    pub error_message: Option<String>,
+    /// Set by the CLI when the run was resumed from a checkpoint.
+    #[serde(default)]
+    pub resumed_from_checkpoint: Option<String>,
+    /// The run whose output dir holds `resumed_from_checkpoint`, if registered.
+    #[serde(default)]
+    pub resumed_from_run_id: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RunArtifactPaths {
+    pub artifact_contract_path: Option<String>,
+    pub model_path: Option<String>,
+    pub history_path: Option<String>,
+    pub plot_path: Option<String>,
+    pub tokenizer_path: Option<String>,
+    pub training_config_path: Option<String>,
+    pub checkpoint_dir: Option<String>,
+    pub best_checkpoint_path: Option<String>,
+    pub reproducibility_bundle_path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunFinalMetrics {
+    pub epochs_completed: u64,
+    pub final_train_loss: f64,
+    pub final_validation_loss: f64,
+    pub best_validation_loss: f64,
+    pub best_epoch: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunCheckpoint {
+    pub path: String,
+    pub file_name: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrainingRunDetails {
+    pub lifecycle: RunLifecycleRecord,
+    pub training_config: Option<Value>,
+    pub final_metrics: Option<RunFinalMetrics>,
+    pub artifacts: RunArtifactPaths,
+    pub checkpoints: Vec<RunCheckpoint>,
+}
+
+/// Per-run header row of a comparison; curve and config arrays index runs in this order.
+#[derive(Debug, Serialize)]
+pub struct ComparedRun {
+    pub run_id: String,
+    pub state: String,
+    pub dataset_version_id: String,
+    pub config_hash: String,
+    pub final_metrics: Option<RunFinalMetrics>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EpochLossAlignment {
+    pub epoch: u64,
+    pub train_losses: Vec<Option<f64>>,
+    pub validation_losses: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StepLossAlignment {
+    pub global_step: u64,
+    pub train_losses: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConfigFieldDiff {
+    pub field: String,
+    pub values: Vec<Option<Value>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunComparison {
+    pub runs: Vec<ComparedRun>,
+    pub epoch_losses: Vec<EpochLossAlignment>,
+    pub step_losses: Vec<StepLossAlignment>,
+    pub config_differences: Vec<ConfigFieldDiff>,
+}
+
+/// One config key that differs between two runs; dotted paths name nested keys.
+#[derive(Debug, Serialize)]
+pub struct ConfigKeyChange {
+    pub key: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// Typed diff of `run_b`'s training config against `run_a`'s.
+#[derive(Debug, Serialize)]
+pub struct RunConfigDiff {
+    pub run_a: String,
+    pub run_b: String,
+    pub config_hash_a: String,
+    pub config_hash_b: String,
+    pub added: Vec<ConfigKeyChange>,
+    pub removed: Vec<ConfigKeyChange>,
+    pub changed: Vec<ConfigKeyChange>,
+    pub unchanged_count: u64,
+}
+
+/// Outcome of deleting or archiving a run; `archive_path` is set only for archives.
+#[derive(Debug, Serialize)]
+pub struct RunRemovalResult {
+    pub run_id: String,
+    pub action: String,
+    pub output_dir_removed: bool,
+    pub removed_lineage_edges: u64,
+    pub archive_path: Option<String>,
+}
+
+/// User-authored run annotations persisted in `runs/<run_id>/meta.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunUserMetadata {
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: String,
+    pub updated_at: Option<String>,
+    /// Identity from settings of whoever last changed the tags or notes.
+    #[serde(default)]
+    pub updated_by: Option<String>,
+    #[serde(default)]
+    pub attachments: Vec<RunAttachment>,
+}
+
+/// A user file copied into `runs/<run_id>/attachments/`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunAttachment {
+    pub attachment_id: String,
+    /// `notebook`, `report`, `plot`, or `other`.
+    pub kind: String,
+    pub file_name: String,
+    pub stored_path: String,
+    pub source_path: String,
+    pub size_bytes: u64,
+    pub sha256: String,
+    pub attached_at: String,
+}
+
+/// Attachment bytes as UTF-8 text, or base64 when the file is binary.
+#[derive(Debug, Serialize)]
+pub struct RunAttachmentContent {
+    pub attachment: RunAttachment,
+    pub encoding: String,
+    pub content: String,
+}
+
+/// Background task handle for a run resumed from one of its checkpoints.
+#[derive(Debug, Serialize)]
+pub struct RunResumeStart {
+    pub task_id: String,
+    pub estimated_total_seconds: u64,
+    pub source_run_id: String,
+    pub checkpoint_path: String,
+}
+
+/// Changes applied on top of the source run's saved training config when cloning.
+#[derive(Debug, Default, Deserialize)]
+pub struct RunCloneOverrides {
+    #[serde(default)]
+    pub dataset_version_id: Option<String>,
+    /// Defaults to a timestamped sibling of the source output dir.
+    #[serde(default)]
+    pub output_dir: Option<String>,
+    /// Training config keys to replace; `null` drops the key so the CLI default applies.
+    #[serde(default)]
+    pub config: Map<String, Value>,
+}
+
+/// Background task handle for a run cloned from another run's config.
+#[derive(Debug, Serialize)]
+pub struct RunCloneStart {
+    pub task_id: String,
+    pub estimated_total_seconds: u64,
+    pub source_run_id: String,
+    pub dataset_version_id: String,
+    pub output_dir: String,
+}
+
+/// Result of regenerating `runs/index.json` from the run directories on disk.
+#[derive(Debug, Serialize)]
+pub struct RunIndexRebuildResult {
+    pub index_path: String,
+    pub run_count: u64,
+    pub recovered_run_ids: Vec<String>,
+    pub dropped_run_ids: Vec<String>,
+    pub unreadable_run_dirs: Vec<String>,
+}
+
+/// Most likely cause of a failed run; `cause` is `unknown` when no signature matched.
+#[derive(Debug, Serialize)]
+pub struct RunFailureDiagnosis {
+    pub run_id: String,
+    pub state: String,
+    pub cause: String,
+    pub summary: String,
+    /// Log line that matched the cause's signature.
+    pub evidence: Option<String>,
+    pub remediations: Vec<String>,
+    pub exit_code: Option<i32>,
+    pub task_log_available: bool,
+}
+
+/// One artifact's recorded versus recomputed content hash.
+#[derive(Debug, Serialize)]
+pub struct ArtifactHashCheck {
+    /// Contract field naming the artifact, e.g. `model_path`.
+    pub artifact: String,
+    pub path: Option<String>,
+    pub recorded_hash: Option<String>,
+    pub computed_hash: Option<String>,
+    /// `match`, `mismatch`, `missing` (file gone), or `unrecorded` (no stored hash).
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArtifactContractVerification {
+    pub run_id: String,
+    pub contract_path: String,
+    pub is_valid: bool,
+    pub lifecycle_config_hash: String,
+    pub contract_config_hash: Option<String>,
+    pub checks: Vec<ArtifactHashCheck>,
+    pub issues: Vec<String>,
+}
@@ -0,0 +1,45 @@
+//! Run garbage-collection policy and report models.
+
+use serde::{Deserialize, Serialize};
+
+/// Which completed runs to collect and what to do with their heavyweight artifacts.
+#[derive(Debug, Deserialize)]
+pub struct RunGcPolicy {
+    /// Only runs whose last lifecycle update is older than this are collected.
+    pub older_than_days: u32,
+    /// `archive` moves the output dir under `archive_destination`; `prune` deletes
+    /// model weights and checkpoints in place.
+    pub mode: String,
+    pub archive_destination: Option<String>,
+    /// Reports what would be reclaimed without touching any files.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// One collected run; also persisted as `runs/<run_id>/artifact_gc.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunArtifactGc {
+    pub run_id: String,
+    pub mode: String,
+    pub collected_at: String,
+    pub reclaimed_bytes: u64,
+    /// Artifact paths that were removed or moved.
+    pub paths: Vec<String>,
+    pub archive_path: Option<String>,
+}
+
+/// An old completed run the policy left alone, with the reason.
+#[derive(Debug, Serialize)]
+pub struct RunGcSkip {
+    pub run_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunGcReport {
+    pub mode: String,
+    pub dry_run: bool,
+    pub reclaimed_bytes: u64,
+    pub collected: Vec<RunArtifactGc>,
+    pub skipped: Vec<RunGcSkip>,
+}
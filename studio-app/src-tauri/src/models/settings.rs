@@ -0,0 +1,167 @@
+//! Persistent Studio application settings.
+
+use serde::{Deserialize, Serialize};
+
+/// Everything Studio lets a user configure. Missing fields take their
+/// defaults, so settings files written by older versions keep loading.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Command name or path used to run the forge CLI.
+    pub forge_binary: String,
+    /// Most forge commands Studio runs at once.
+    pub max_concurrent_tasks: usize,
+    /// Data root opened at startup; `None` reopens the last one used.
+    pub default_data_root: Option<String>,
+    /// Folders besides the workspace Studio runs from that commands may read
+    /// and write; paths outside every workspace are refused.
+    pub workspace_roots: Vec<String>,
+    /// Data roots, such as a team's NAS mount, whose datasets the browser
+    /// lists beside the open one. Each is also accepted as a workspace.
+    pub data_roots: Vec<String>,
+    /// `tracing` filter directives for Studio's log, e.g.
+    /// `info,studio_app_lib::commands::forge_task_store=debug`. Read at launch.
+    pub log_filter: String,
+    /// Rejects every command that changes a data root, so a shared or
+    /// production one can be browsed safely.
+    pub read_only: bool,
+    /// Message catalog for backend-formatted text; set with `set_locale`.
+    pub locale: String,
+    pub identity: UserIdentity,
+    pub notifications: NotificationSettings,
+    pub cache_sizes: CacheSizeSettings,
+    pub telemetry: TelemetrySettings,
+    pub rpc_server: RpcServerSettings,
+    pub event_bridge: EventBridgeSettings,
+}
+
+/// Who is making changes, stamped onto annotations, model versions, and
+/// audit log entries so teams sharing a data root can attribute them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserIdentity {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Which finished work raises a desktop notification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    pub task_completed: bool,
+    pub task_failed: bool,
+    pub pipeline_finished: bool,
+}
+
+/// How many entries Studio keeps in its in-memory and on-disk histories.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheSizeSettings {
+    /// Finished forge tasks kept for status queries.
+    pub finished_tasks: usize,
+    /// Autosaved canvas snapshots kept per data root.
+    pub canvas_snapshots: usize,
+    /// Undoable edits kept per canvas file.
+    pub canvas_edits: usize,
+}
+
+/// Opt-in anonymous usage telemetry; see `usage_telemetry` for what is sent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetrySettings {
+    pub enabled: bool,
+    /// HTTPS endpoint daily aggregates are posted to; `None` keeps them
+    /// local. Release builds default it from `FORGE_STUDIO_TELEMETRY_URL`.
+    pub upload_url: Option<String>,
+}
+
+/// Local JSON-RPC server for scripts and CI; see `rpc_server`. Read at launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RpcServerSettings {
+    pub enabled: bool,
+    /// Port on 127.0.0.1 the server listens on.
+    pub port: u16,
+}
+
+/// Local WebSocket endpoint broadcasting backend events; see
+/// `event_bridge`. Read at launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventBridgeSettings {
+    pub enabled: bool,
+    /// Port on 127.0.0.1 the endpoint listens on.
+    pub port: u16,
+    /// Web origins allowed to connect, e.g. `http://localhost:8888` for a
+    /// notebook. Clients that send no `Origin` are always allowed.
+    pub allowed_origins: Vec<String>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            forge_binary: "forge".to_string(),
+            max_concurrent_tasks: 4,
+            default_data_root: None,
+            workspace_roots: Vec::new(),
+            data_roots: Vec::new(),
+            log_filter: "info".to_string(),
+            read_only: false,
+            locale: "en".to_string(),
+            identity: UserIdentity::default(),
+            notifications: NotificationSettings::default(),
+            cache_sizes: CacheSizeSettings::default(),
+            telemetry: TelemetrySettings::default(),
+            rpc_server: RpcServerSettings::default(),
+            event_bridge: EventBridgeSettings::default(),
+        }
+    }
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            task_completed: false,
+            task_failed: true,
+            pipeline_finished: true,
+        }
+    }
+}
+
+impl Default for CacheSizeSettings {
+    fn default() -> Self {
+        Self {
+            finished_tasks: 200,
+            canvas_snapshots: 50,
+            canvas_edits: 200,
+        }
+    }
+}
+
+impl Default for TelemetrySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            upload_url: option_env!("FORGE_STUDIO_TELEMETRY_URL").map(str::to_string),
+        }
+    }
+}
+
+impl Default for RpcServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 7733,
+        }
+    }
+}
+
+impl Default for EventBridgeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 7734,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Typed error returned by every Studio command.
+//!
+//! Commands serialize errors as `{code, title, message, context,
+//! remediation}`, so the frontend picks its error UI by `code` instead of
+//! matching message text; `title` is the code's label in the active message
+//! catalog. Errors are built with the variant's constructor where they
+//! happen, from text worded as "What happened. How to fix it."; the
+//! constructor splits off the remediation sentence and takes any
+//! single-quoted id in the message as its context.
+
+use crate::commands::message_catalog::message;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForgeStudioError {
+    /// A dataset, run, task, file, or id the command was given does not exist.
+    NotFound(ErrorDetail),
+    /// The request itself is wrong: a bad value, option, or combination.
+    InvalidInput(ErrorDetail),
+    /// Reading or writing the data root or app files failed.
+    Io(ErrorDetail),
+    /// A file on disk exists but its contents are malformed.
+    Parse(ErrorDetail),
+    /// The target already exists or is in use.
+    Conflict(ErrorDetail),
+    /// The command changes a data root while Studio is in read-only mode.
+    ReadOnly(ErrorDetail),
+    /// Studio itself misbehaved; restarting usually clears it.
+    Internal(ErrorDetail),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorDetail {
+    pub message: String,
+    /// Id or name the error is about, when there is one.
+    pub context: Option<String>,
+    pub remediation: Option<String>,
+}
+
+impl ForgeStudioError {
+    pub fn not_found(text: impl Into<String>) -> Self {
+        Self::NotFound(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn invalid_input(text: impl Into<String>) -> Self {
+        Self::InvalidInput(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn io(text: impl Into<String>) -> Self {
+        Self::Io(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn parse(text: impl Into<String>) -> Self {
+        Self::Parse(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn conflict(text: impl Into<String>) -> Self {
+        Self::Conflict(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn read_only(text: impl Into<String>) -> Self {
+        Self::ReadOnly(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn internal(text: impl Into<String>) -> Self {
+        Self::Internal(ErrorDetail::from_text(&text.into()))
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => "not_found",
+            Self::InvalidInput(_) => "invalid_input",
+            Self::Io(_) => "io",
+            Self::Parse(_) => "parse",
+            Self::Conflict(_) => "conflict",
+            Self::ReadOnly(_) => "read_only",
+            Self::Internal(_) => "internal",
+        }
+    }
+
+    pub fn detail(&self) -> &ErrorDetail {
+        match self {
+            Self::NotFound(detail)
+            | Self::InvalidInput(detail)
+            | Self::Io(detail)
+            | Self::Parse(detail)
+            | Self::Conflict(detail)
+            | Self::ReadOnly(detail)
+            | Self::Internal(detail) => detail,
+        }
+    }
+}
+
+impl ErrorDetail {
+    /// Splits off the remediation sentence and takes any single-quoted id
+    /// in the message as its context.
+    fn from_text(text: &str) -> Self {
+        let (message, remediation) = split_remediation(text);
+        let context = message
+            .split('\'')
+            .nth(1)
+            .filter(|quoted| !quoted.is_empty())
+            .map(str::to_string);
+        Self {
+            message: message.to_string(),
+            context,
+            remediation: remediation.map(str::to_string),
+        }
+    }
+}
+
+impl fmt::Display for ForgeStudioError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let detail = self.detail();
+        match &detail.remediation {
+            Some(remediation) => write!(formatter, "{}. {remediation}", detail.message),
+            None => write!(formatter, "{}", detail.message),
+        }
+    }
+}
+
+impl std::error::Error for ForgeStudioError {}
+
+impl Serialize for ForgeStudioError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let detail = self.detail();
+        let mut state = serializer.serialize_struct("ForgeStudioError", 5)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("title", &message(&format!("error.{}", self.code()), &[]))?;
+        state.serialize_field("message", &detail.message)?;
+        state.serialize_field("context", &detail.context)?;
+        state.serialize_field("remediation", &detail.remediation)?;
+        state.end()
+    }
+}
+
+/// Splits "What happened. How to fix it." at the first sentence break.
+fn split_remediation(text: &str) -> (&str, Option<&str>) {
+    let sentence_break = text.match_indices(". ").find(|(index, _)| {
+        text[index + 2..]
+            .chars()
+            .next()
+            .is_some_and(char::is_uppercase)
+    });
+    match sentence_break {
+        Some((index, _)) => (&text[..index], Some(text[index + 2..].trim_end())),
+        None => (text.trim_end_matches('.'), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForgeStudioError;
+    use serde_json::json;
+
+    #[test]
+    fn constructors_split_remediation_and_context() {
+        let error = ForgeStudioError::not_found(
+            "Unknown run state watch id 'watch-3'. It may already be stopped.",
+        );
+        assert_eq!(
+            serde_json::to_value(&error).expect("error should serialize"),
+            json!({
+                "code": "not_found",
+                "title": "Not found",
+                "message": "Unknown run state watch id 'watch-3'",
+                "context": "watch-3",
+                "remediation": "It may already be stopped.",
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "Unknown run state watch id 'watch-3'. It may already be stopped."
+        );
+        let io = ForgeStudioError::io("Failed to write /tmp/a.json: disk full");
+        assert_eq!((io.code(), io.detail().remediation.clone()), ("io", None));
+    }
+}
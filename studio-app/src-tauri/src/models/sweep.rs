@@ -0,0 +1,101 @@
+//! Hyperparameter sweep models for scheduled multi-run training.
+
+use crate::models::RunFinalMetrics;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// One swept key: explicit `values`, or a `min`/`max` range for random search.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SweepParameter {
+    pub values: Option<Vec<Value>>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    #[serde(default)]
+    pub log_scale: bool,
+    #[serde(default)]
+    pub integer: bool,
+}
+
+/// `grid` expands every value combination; `random` samples `num_trials` configs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SweepSpec {
+    pub strategy: String,
+    pub parameters: BTreeMap<String, SweepParameter>,
+    pub num_trials: Option<usize>,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SweepRequest {
+    pub dataset_name: String,
+    pub version_id: Option<String>,
+    pub output_dir: Option<String>,
+    #[serde(default)]
+    pub base_config: Map<String, Value>,
+    pub spec: SweepSpec,
+    pub max_parallel: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepTrial {
+    pub trial_index: usize,
+    pub params: Map<String, Value>,
+    pub output_dir: String,
+    pub status: String,
+    pub task_id: Option<String>,
+    pub run_id: Option<String>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Contents of `sweeps/<sweep_id>/sweep.json`, rewritten whenever a trial changes state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepRecord {
+    pub sweep_id: String,
+    pub dataset_name: String,
+    pub version_id: String,
+    pub strategy: String,
+    pub seed: Option<u64>,
+    pub max_parallel: usize,
+    pub base_config: Map<String, Value>,
+    pub status: String,
+    pub created_at: String,
+    pub finished_at: Option<String>,
+    pub trials: Vec<SweepTrial>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SweepStart {
+    pub sweep_id: String,
+    pub version_id: String,
+    pub trial_count: usize,
+    pub max_parallel: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SweepTrialStatus {
+    pub trial_index: usize,
+    pub params: Map<String, Value>,
+    pub status: String,
+    pub task_id: Option<String>,
+    pub run_id: Option<String>,
+    pub error: Option<String>,
+    pub final_metrics: Option<RunFinalMetrics>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SweepStatus {
+    pub sweep_id: String,
+    pub status: String,
+    pub dataset_name: String,
+    pub version_id: String,
+    pub pending_count: usize,
+    pub running_count: usize,
+    pub completed_count: usize,
+    pub failed_count: usize,
+    /// Trial with the lowest best validation loss among trials that reported metrics.
+    pub best_trial_index: Option<usize>,
+    pub trials: Vec<SweepTrialStatus>,
+}
@@ -0,0 +1,42 @@
+//! Background forge command task models.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct CommandTaskStart {
+    pub task_id: String,
+    pub estimated_total_seconds: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommandTaskStatus {
+    pub task_id: String,
+    pub status: String,
+    /// `status` in the active locale, for display.
+    pub status_label: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed_seconds: u64,
+    pub estimated_total_seconds: u64,
+    pub remaining_seconds: u64,
+    pub progress_percent: f64,
+    /// `step_timing` when derived from logged train steps, else `command_average`.
+    pub eta_source: String,
+    pub completed_steps: Option<u64>,
+    pub total_steps: Option<u64>,
+    pub seconds_per_step: Option<f64>,
+}
+
+/// Output of a finished `train` task, kept in its output dir for failure diagnosis.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TrainTaskLog {
+    pub task_id: String,
+    pub status: String,
+    pub exit_code: Option<i32>,
+    pub finished_at: String,
+    pub stdout_tail: String,
+    pub stderr_tail: String,
+}
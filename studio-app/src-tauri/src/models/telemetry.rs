@@ -0,0 +1,66 @@
+//! Accelerator telemetry and energy cost models.
+
+use serde::{Deserialize, Serialize};
+
+/// One device reading; fields a backend cannot report stay `None`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AcceleratorSample {
+    pub device_index: u32,
+    pub name: String,
+    pub backend: String,
+    pub utilization_percent: Option<f64>,
+    pub memory_used_mb: Option<f64>,
+    pub memory_total_mb: Option<f64>,
+    pub temperature_celsius: Option<f64>,
+    pub power_watts: Option<f64>,
+}
+
+/// Payload of `hardware_telemetry` events and rows of the telemetry history.
+#[derive(Debug, Clone, Serialize)]
+pub struct TelemetrySnapshot {
+    pub sampled_at: String,
+    pub devices: Vec<AcceleratorSample>,
+}
+
+/// One persisted power reading for a training output dir; summed over devices.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PowerSample {
+    pub sampled_at: String,
+    pub device_count: u32,
+    /// `None` when no device reported power draw (e.g. Apple GPUs).
+    pub power_watts: Option<f64>,
+}
+
+/// Either or both rates may be set; costs are `None` when neither applies.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CostRates {
+    pub price_per_kwh: Option<f64>,
+    pub price_per_gpu_hour: Option<f64>,
+    #[serde(default = "default_currency")]
+    pub currency: String,
+}
+
+impl Default for CostRates {
+    fn default() -> Self {
+        Self {
+            price_per_kwh: None,
+            price_per_gpu_hour: None,
+            currency: default_currency(),
+        }
+    }
+}
+
+fn default_currency() -> String {
+    "USD".to_string()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunCost {
+    pub run_id: String,
+    pub sample_count: usize,
+    pub measured_hours: f64,
+    pub gpu_hours: f64,
+    pub energy_kwh: Option<f64>,
+    pub cost: Option<f64>,
+    pub currency: String,
+}
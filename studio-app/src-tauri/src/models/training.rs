@@ -0,0 +1,176 @@
+//! Training history and run summary models.
+
+use serde::{Deserialize, Serialize};
+
+/// One epoch row; metrics beyond loss are optional because older histories lack them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingEpoch {
+    pub epoch: u64,
+    pub train_loss: f64,
+    pub validation_loss: f64,
+    #[serde(default, alias = "lr")]
+    pub learning_rate: Option<f64>,
+    #[serde(default, alias = "grad_norm")]
+    pub gradient_norm: Option<f64>,
+    #[serde(default)]
+    pub tokens_per_second: Option<f64>,
+    #[serde(default)]
+    pub gpu_memory_mb: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingBatchLoss {
+    pub epoch: u64,
+    pub batch_index: u64,
+    pub global_step: u64,
+    pub train_loss: f64,
+    #[serde(default, alias = "lr")]
+    pub learning_rate: Option<f64>,
+    #[serde(default, alias = "grad_norm")]
+    pub gradient_norm: Option<f64>,
+    #[serde(default)]
+    pub tokens_per_second: Option<f64>,
+    #[serde(default)]
+    pub gpu_memory_mb: Option<f64>,
+}
+
+/// A point of the server-side smoothed batch loss curve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmoothedLossPoint {
+    pub global_step: u64,
+    pub train_loss: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrainingHistory {
+    pub epochs: Vec<TrainingEpoch>,
+    #[serde(default)]
+    pub batch_losses: Vec<TrainingBatchLoss>,
+    /// EMA of `batch_losses`, aligned row for row; empty unless smoothing was requested.
+    #[serde(default)]
+    pub smoothed_batch_losses: Vec<SmoothedLossPoint>,
+}
+
+/// Incremental history rows emitted while a training run writes `history.json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingHistoryUpdate {
+    pub watch_id: String,
+    pub history_path: String,
+    pub new_epochs: Vec<TrainingEpoch>,
+    pub new_batch_losses: Vec<TrainingBatchLoss>,
+    pub total_epochs: u64,
+    pub total_batch_losses: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistoryWatchStart {
+    pub watch_id: String,
+}
+
+/// A run's lifecycle state as seen by the run state watcher.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunStateChange {
+    pub watch_id: String,
+    pub run_id: String,
+    pub dataset_name: String,
+    /// `None` when the run appeared after the watch started.
+    pub previous_state: Option<String>,
+    pub state: String,
+    pub error_message: Option<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunStateWatchStart {
+    pub watch_id: String,
+    pub tracked_run_count: u64,
+}
+
+/// Optional runs-list filters; date bounds compare against the ISO `created_at` prefix.
+#[derive(Debug, Default, Deserialize)]
+pub struct TrainingRunQuery {
+    pub dataset_name: Option<String>,
+    pub state: Option<String>,
+    pub created_from: Option<String>,
+    pub created_to: Option<String>,
+    pub tag: Option<String>,
+    pub search: Option<String>,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+    /// Silence after which a running run counts as stalled; defaults to 30 minutes.
+    pub stalled_after_seconds: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrainingRunSummary {
+    pub run_id: String,
+    pub dataset_name: String,
+    pub dataset_version_id: String,
+    pub state: String,
+    pub updated_at: String,
+    pub output_dir: String,
+    pub artifact_contract_path: Option<String>,
+    pub model_path: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: String,
+    pub resumed_from_run_id: Option<String>,
+    pub energy_kwh: Option<f64>,
+    pub cost: Option<f64>,
+    /// Running, but no lifecycle or output-dir write within the stall threshold.
+    pub stalled: bool,
+    pub last_activity_at: Option<String>,
+}
+
+/// One annotated region of a training curve for chart overlays.
+#[derive(Debug, Clone, Serialize)]
+pub struct CurveFinding {
+    /// `overfitting`, `plateau`, or `loss_spike`.
+    pub kind: String,
+    /// `info`, `warning`, or `critical`.
+    pub severity: String,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    /// Step range for batch-level findings; `None` for epoch-level ones.
+    pub start_step: Option<u64>,
+    pub end_step: Option<u64>,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrainingCurveAnalysis {
+    pub run_id: String,
+    pub best_epoch: Option<u64>,
+    pub best_validation_loss: Option<f64>,
+    /// Set when validation loss diverged; training past this epoch did not help.
+    pub recommended_stop_epoch: Option<u64>,
+    pub findings: Vec<CurveFinding>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TensorboardExportResult {
+    pub run_id: String,
+    pub event_file_path: String,
+    pub epoch_count: u64,
+    pub batch_count: u64,
+    pub scalar_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrainingHistory;
+
+    #[test]
+    fn history_parses_optional_extended_metrics() {
+        let history: TrainingHistory = serde_json::from_str(
+            r#"{"epochs": [
+                {"epoch": 1, "train_loss": 3.0, "validation_loss": 2.5, "lr": 0.001,
+                 "grad_norm": 1.2, "tokens_per_second": 5400.0, "gpu_memory_mb": 2048.0},
+                {"epoch": 2, "train_loss": 2.0, "validation_loss": 2.1}
+            ]}"#,
+        )
+        .expect("history should parse");
+        assert_eq!(history.epochs[0].learning_rate, Some(0.001));
+        assert_eq!(history.epochs[0].gradient_norm, Some(1.2));
+        assert_eq!(history.epochs[1].tokens_per_second, None);
+    }
+}
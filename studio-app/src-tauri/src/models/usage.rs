@@ -0,0 +1,24 @@
+//! Usage telemetry models.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Calls of one command within a telemetry period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommandUsage {
+    pub call_count: u64,
+    /// Time calls held the IPC thread; only dispatch for async commands.
+    pub total_millis: u64,
+    pub max_millis: u64,
+}
+
+/// Aggregates collected since the last upload, exactly as they would be sent.
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageTelemetry {
+    pub enabled: bool,
+    pub upload_url: Option<String>,
+    pub period_start: String,
+    pub last_uploaded_at: Option<String>,
+    /// Keyed by command name.
+    pub commands: BTreeMap<String, CommandUsage>,
+}
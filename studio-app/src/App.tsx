@@ -13,6 +13,8 @@ import { usePipelineGraphState } from "./hooks/use_pipeline_graph_state";
 import { loadSessionState, saveSessionState } from "./session_state";
 import {
   DatasetDashboard,
+  DatasetListing,
+  HardwareProfile,
   LineageGraphSummary,
   RecordSample,
   TrainingRunSummary,
@@ -26,10 +28,9 @@ import "./App.css";
 const INITIAL_SESSION = loadSessionState();
 function App() {
   const [dataRoot, setDataRoot] = useState(INITIAL_SESSION.data_root);
-  const [datasets, setDatasets] = useState<string[]>([]);
-  const [selectedDataset, setSelectedDataset] = useState<string | null>(
-    INITIAL_SESSION.selected_dataset,
-  );
+  const [datasets, setDatasets] = useState<DatasetListing[]>([]);
+  const [selectedDataset, setSelectedDataset] =
+    useState<DatasetListing | null>(INITIAL_SESSION.selected_dataset);
   const [versions, setVersions] = useState<VersionSummary[]>([]);
   const [selectedVersion, setSelectedVersion] = useState<string | null>(
     INITIAL_SESSION.selected_version,
@@ -47,10 +48,8 @@ function App() {
   const [lineageGraph, setLineageGraph] = useState<LineageGraphSummary | null>(
     null,
   );
-  const [hardwareProfile, setHardwareProfile] = useState<Record<
-    string,
-    string
-  > | null>(null);
+  const [hardwareProfile, setHardwareProfile] =
+    useState<HardwareProfile | null>(null);
   const [isViewControlsOpen, setIsViewControlsOpen] = useState(
     INITIAL_SESSION.is_view_controls_open,
   );
@@ -140,17 +139,24 @@ function App() {
       setTargetVersion(null);
       return;
     }
-    if (!selectedDataset || !rows.includes(selectedDataset)) {
+    // The same dataset name can exist in several roots, so match on both.
+    const stillListed = rows.find(
+      (row) =>
+        row.data_root === selectedDataset?.data_root &&
+        row.dataset_name === selectedDataset?.dataset_name,
+    );
+    if (!stillListed) {
       setSelectedDataset(rows[0]);
       setSelectedVersion(null);
     }
   }
 
   async function refreshDatasetDetails(
-    datasetName: string,
+    dataset: DatasetListing,
     versionId: string | null,
   ) {
-    const versionRows = await listVersions(dataRoot, datasetName);
+    const { data_root: datasetRoot, dataset_name: datasetName } = dataset;
+    const versionRows = await listVersions(datasetRoot, datasetName);
     setVersions(versionRows);
     if (versionRows.length === 0) {
       setDashboard(null);
@@ -160,12 +166,12 @@ function App() {
       return;
     }
     const dashboardRow = await getDatasetDashboard(
-      dataRoot,
+      datasetRoot,
       datasetName,
       versionId,
     );
     const sampleRows = await sampleRecords(
-      dataRoot,
+      datasetRoot,
       datasetName,
       versionId,
       0,
@@ -189,23 +195,23 @@ function App() {
       return;
     }
     const result = await versionDiff(
-      dataRoot,
-      selectedDataset,
+      selectedDataset.data_root,
+      selectedDataset.dataset_name,
       baseVersion,
       targetVersion,
     );
     setDiff(result);
   }
 
-  async function refreshRuntimeInsights() {
-    const snapshot = await loadRuntimeInsights(dataRoot);
+  async function refreshRuntimeInsights(redetectHardware = false) {
+    const snapshot = await loadRuntimeInsights(dataRoot, redetectHardware);
     setTrainingRuns(snapshot.runs);
     setLineageGraph(snapshot.lineage);
     setHardwareProfile(snapshot.hardwareProfile);
   }
 
-  function onDatasetSelect(datasetName: string) {
-    setSelectedDataset(datasetName);
+  function onDatasetSelect(dataset: DatasetListing) {
+    setSelectedDataset(dataset);
     setSelectedVersion(null);
     setBaseVersion(null);
     setTargetVersion(null);
@@ -281,7 +287,7 @@ function App() {
         trainingRuns={trainingRuns}
         lineage={lineageGraph}
         onRefreshRuntimeInsights={() =>
-          refreshRuntimeInsights().catch(logUiError)
+          refreshRuntimeInsights(true).catch(logUiError)
         }
       />
       <ViewControlDrawer
@@ -1,21 +1,73 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import {
+  AppLogEntry,
+  AppLogLevel,
+  AppSettings,
+  AppSettingsUpdate,
+  AuditLogEntry,
+  BackendEventEnvelope,
   CommandTaskStart,
   CommandTaskStatus,
+  CrashReport,
+  DataRootCheck,
+  DataRootInit,
+  DataRootInitOptions,
   DatasetDashboard,
+  DatasetListing,
+  ForgeStudioError,
+  ForgeUpdateCheck,
+  HardwareProfile,
+  MetadataIndexSync,
+  MetadataSearchHit,
+  PipelineCanvasBundleExport,
+  PipelineCanvasBundleImport,
+  PipelineCanvasEditCursor,
+  PipelineCanvasEditJournal,
+  PipelineCanvasEditStep,
+  PipelineCanvasExportEntry,
   PipelineCanvasExportResult,
+  PipelineCanvasMerge,
+  PipelineCanvasState,
+  PipelineConfigImport,
   LineageGraphSummary,
   PipelineEdge,
   PipelineNode,
+  PipelineNodeGroup,
+  PluginDatasetAnalysis,
+  PluginInfo,
   RecordSample,
   TrainingRunSummary,
   TrainingHistory,
+  UsageTelemetry,
   VersionDiff,
   VersionSummary,
+  WorkspaceBackup,
+  WorkspaceBackupOptions,
+  WorkspaceRestore,
 } from "../types";
 
-export async function listDatasets(dataRoot: string): Promise<string[]> {
-  return invoke<string[]>("list_datasets", { dataRoot });
+function isForgeStudioError(error: unknown): error is ForgeStudioError {
+  return (
+    typeof error === "object" &&
+    error !== null &&
+    "code" in error &&
+    "message" in error
+  );
+}
+
+/** Readable text for a failed command or any other thrown value. */
+export function errorMessage(error: unknown): string {
+  if (isForgeStudioError(error)) {
+    return error.remediation
+      ? `${error.message}. ${error.remediation}`
+      : error.message;
+  }
+  return error instanceof Error ? error.message : String(error);
+}
+
+export async function listDatasets(dataRoot: string): Promise<DatasetListing[]> {
+  return invoke<DatasetListing[]>("list_datasets", { dataRoot });
 }
 
 export async function listVersions(
@@ -67,6 +119,18 @@ export async function versionDiff(
   });
 }
 
+export async function getVersionDuplicateRate(
+  dataRoot: string,
+  datasetName: string,
+  versionId: string,
+): Promise<number> {
+  return invoke<number>("get_version_duplicate_rate", {
+    dataRoot,
+    datasetName,
+    versionId,
+  });
+}
+
 export async function startForgeCommand(
   dataRoot: string,
   args: string[],
@@ -82,8 +146,14 @@ export async function getForgeCommandStatus(
 
 export async function loadTrainingHistory(
   historyPath: string,
+  maxPoints: number | null = null,
+  smoothingWindow: number | null = null,
 ): Promise<TrainingHistory> {
-  return invoke<TrainingHistory>("load_training_history", { historyPath });
+  return invoke<TrainingHistory>("load_training_history", {
+    historyPath,
+    maxPoints,
+    smoothingWindow,
+  });
 }
 
 export async function exportPipelineCanvas(
@@ -92,16 +162,105 @@ export async function exportPipelineCanvas(
   edges: PipelineEdge[],
   startNodeId: string | null,
   outputPath: string | null,
+  format: "json" | "yaml-pipeline" = "json",
+  overwrite = false,
+  groups: PipelineNodeGroup[] = [],
 ): Promise<PipelineCanvasExportResult> {
-  return invoke<PipelineCanvasExportResult>("export_pipeline_canvas", {
-    dataRoot,
+  const canvas: PipelineCanvasState = {
+    start_node_id: startNodeId,
     nodes,
     edges,
-    startNodeId,
+    groups,
+  };
+  return invoke<PipelineCanvasExportResult>("export_pipeline_canvas", {
+    dataRoot,
+    canvas,
     outputPath,
+    format,
+    overwrite,
   });
 }
 
+export async function listCanvasExports(
+  dataRoot: string,
+): Promise<PipelineCanvasExportEntry[]> {
+  return invoke<PipelineCanvasExportEntry[]>("list_canvas_exports", { dataRoot });
+}
+
+export async function exportCanvasBundle(
+  dataRoot: string,
+  canvas: PipelineCanvasState,
+  outputPath: string | null,
+  overwrite = false,
+): Promise<PipelineCanvasBundleExport> {
+  return invoke<PipelineCanvasBundleExport>("export_canvas_bundle", {
+    dataRoot,
+    canvas,
+    outputPath,
+    overwrite,
+  });
+}
+
+export async function importCanvasBundle(
+  dataRoot: string,
+  path: string,
+): Promise<PipelineCanvasBundleImport> {
+  return invoke<PipelineCanvasBundleImport>("import_canvas_bundle", { dataRoot, path });
+}
+
+export async function mergeCanvases(
+  dataRoot: string,
+  base: string,
+  theirs: string,
+  ours: string,
+): Promise<PipelineCanvasMerge> {
+  return invoke<PipelineCanvasMerge>("merge_canvases", { dataRoot, base, theirs, ours });
+}
+
+export async function pushCanvasEdit(
+  dataRoot: string,
+  canvasPath: string,
+  label: string,
+  before: PipelineCanvasState,
+  after: PipelineCanvasState,
+): Promise<PipelineCanvasEditCursor> {
+  return invoke<PipelineCanvasEditCursor>("push_canvas_edit", {
+    dataRoot,
+    canvasPath,
+    label,
+    before,
+    after,
+  });
+}
+
+export async function undoCanvasEdit(
+  dataRoot: string,
+  canvasPath: string,
+): Promise<PipelineCanvasEditStep> {
+  return invoke<PipelineCanvasEditStep>("undo_canvas_edit", { dataRoot, canvasPath });
+}
+
+export async function redoCanvasEdit(
+  dataRoot: string,
+  canvasPath: string,
+): Promise<PipelineCanvasEditStep> {
+  return invoke<PipelineCanvasEditStep>("redo_canvas_edit", { dataRoot, canvasPath });
+}
+
+export async function getCanvasEditJournal(
+  dataRoot: string,
+  canvasPath: string,
+): Promise<PipelineCanvasEditJournal> {
+  return invoke<PipelineCanvasEditJournal>("get_canvas_edit_journal", { dataRoot, canvasPath });
+}
+
+export async function importPipelineConfig(
+  dataRoot: string,
+  path: string,
+): Promise<PipelineConfigImport> {
+  return invoke<PipelineConfigImport>("import_pipeline_config", { dataRoot, path });
+}
+
 export async function listTrainingRuns(
   dataRoot: string,
 ): Promise<TrainingRunSummary[]> {
@@ -114,8 +273,132 @@ export async function getLineageGraph(
   return invoke<LineageGraphSummary>("get_lineage_graph", { dataRoot });
 }
 
+export async function getSettings(): Promise<AppSettings> {
+  return invoke<AppSettings>("get_settings");
+}
+
+export async function updateSettings(changes: AppSettingsUpdate): Promise<AppSettings> {
+  return invoke<AppSettings>("update_settings", { changes });
+}
+
+/** Selects the message catalog for backend text; resolves to the active locale. */
+export async function setLocale(locale: string): Promise<string> {
+  return invoke<string>("set_locale", { locale });
+}
+
+export async function getCrashReports(limit?: number): Promise<CrashReport[]> {
+  return invoke<CrashReport[]>("get_crash_reports", { limit });
+}
+
+export async function clearCrashReports(): Promise<number> {
+  return invoke<number>("clear_crash_reports");
+}
+
+export async function getUsageTelemetry(): Promise<UsageTelemetry> {
+  return invoke<UsageTelemetry>("get_usage_telemetry");
+}
+
+export async function getRecentAppLogs(
+  level?: AppLogLevel,
+  limit?: number,
+): Promise<AppLogEntry[]> {
+  return invoke<AppLogEntry[]>("get_recent_app_logs", { level, limit });
+}
+
+export async function getAuditLog(dataRoot: string, limit?: number): Promise<AuditLogEntry[]> {
+  return invoke<AuditLogEntry[]>("get_audit_log", { dataRoot, limit });
+}
+
+export async function listPlugins(): Promise<PluginInfo[]> {
+  return invoke<PluginInfo[]>("list_plugins");
+}
+
+export async function runPluginCommand(
+  pluginName: string,
+  command: string,
+  params?: Record<string, unknown>,
+): Promise<unknown> {
+  return invoke<unknown>("run_plugin_command", { pluginName, command, params });
+}
+
+export async function runDatasetAnalyzer(
+  dataRoot: string,
+  datasetName: string,
+  pluginName: string,
+  analyzer: string,
+  versionId?: string,
+): Promise<PluginDatasetAnalysis> {
+  return invoke<PluginDatasetAnalysis>("run_dataset_analyzer", {
+    dataRoot,
+    datasetName,
+    versionId,
+    pluginName,
+    analyzer,
+  });
+}
+
+export async function checkDataRoot(path: string): Promise<DataRootCheck> {
+  return invoke<DataRootCheck>("check_data_root", { path });
+}
+
+export async function initDataRoot(
+  path: string,
+  options?: DataRootInitOptions,
+): Promise<DataRootInit> {
+  return invoke<DataRootInit>("init_data_root", { path, options });
+}
+
+export async function backupWorkspace(
+  dataRoot: string,
+  outputPath: string,
+  options?: WorkspaceBackupOptions,
+): Promise<WorkspaceBackup> {
+  return invoke<WorkspaceBackup>("backup_workspace", { dataRoot, outputPath, options });
+}
+
+export async function restoreWorkspace(
+  archive: string,
+  dataRoot?: string,
+): Promise<WorkspaceRestore> {
+  return invoke<WorkspaceRestore>("restore_workspace", { archive, dataRoot });
+}
+
+export async function checkForgeUpdates(): Promise<ForgeUpdateCheck> {
+  return invoke<ForgeUpdateCheck>("check_forge_updates");
+}
+
+export async function listBackendEvents(
+  afterSequence?: number,
+): Promise<BackendEventEnvelope[]> {
+  return invoke<BackendEventEnvelope[]>("list_backend_events", { afterSequence });
+}
+
+export async function onBackendEvent(
+  handler: (event: BackendEventEnvelope) => void,
+): Promise<UnlistenFn> {
+  return listen<BackendEventEnvelope>("backend-event", (event) => handler(event.payload));
+}
+
 export async function getHardwareProfile(
   dataRoot: string,
-): Promise<Record<string, string>> {
-  return invoke<Record<string, string>>("get_hardware_profile", { dataRoot });
+): Promise<HardwareProfile> {
+  return invoke<HardwareProfile>("get_hardware_profile", { dataRoot });
+}
+
+export async function refreshHardwareProfile(
+  dataRoot: string,
+): Promise<HardwareProfile> {
+  return invoke<HardwareProfile>("refresh_hardware_profile", { dataRoot });
+}
+
+export async function rebuildMetadataIndex(dataRoot: string): Promise<MetadataIndexSync> {
+  return invoke<MetadataIndexSync>("rebuild_metadata_index", { dataRoot });
+}
+
+export async function searchMetadataIndex(
+  dataRoot: string,
+  text: string,
+  limit?: number,
+): Promise<MetadataSearchHit[]> {
+  return invoke<MetadataSearchHit[]>("search_metadata_index", { dataRoot, text, limit });
 }
@@ -1,5 +1,9 @@
 import { Dispatch, FormEvent, SetStateAction, useEffect, useMemo, useState } from "react";
-import { getForgeCommandStatus, startForgeCommand } from "../api/studioApi";
+import {
+  errorMessage,
+  getForgeCommandStatus,
+  startForgeCommand,
+} from "../api/studioApi";
 
 interface ChatRoomViewProps {
   dataRoot: string;
@@ -96,7 +100,7 @@ export function ChatRoomView(props: ChatRoomViewProps) {
         return updated;
       });
     } catch (error) {
-      const message = error instanceof Error ? error.message : String(error);
+      const message = errorMessage(error);
       setChatError(message);
     } finally {
       setIsSending(false);
@@ -1,18 +1,20 @@
-import { VersionSummary } from "../types";
+import { DatasetListing, VersionSummary } from "../types";
 
 interface DatasetSidebarProps {
   dataRoot: string;
-  datasets: string[];
-  selectedDataset: string | null;
+  datasets: DatasetListing[];
+  selectedDataset: DatasetListing | null;
   versions: VersionSummary[];
   selectedVersion: string | null;
   onDataRootChange: (value: string) => void;
   onRefresh: () => void;
-  onDatasetSelect: (dataset: string) => void;
+  onDatasetSelect: (dataset: DatasetListing) => void;
   onVersionSelect: (versionId: string | null) => void;
 }
 
 export function DatasetSidebar(props: DatasetSidebarProps) {
+  const isFederated =
+    new Set(props.datasets.map((row) => row.data_root)).size > 1;
   return (
     <aside className="sidebar">
       <h2>Forge Store</h2>
@@ -33,12 +35,13 @@ export function DatasetSidebar(props: DatasetSidebarProps) {
         <h3>Datasets</h3>
         <ul className="list">
           {props.datasets.map((dataset) => (
-            <li key={dataset}>
+            <li key={`${dataset.data_root}/${dataset.dataset_name}`}>
               <button
-                className={`list-item ${dataset === props.selectedDataset ? "active" : ""}`}
+                className={`list-item ${isSelected(dataset, props.selectedDataset) ? "active" : ""}`}
                 onClick={() => props.onDatasetSelect(dataset)}
               >
-                {dataset}
+                <span>{dataset.dataset_name}</span>
+                {isFederated && <small>{dataset.data_root}</small>}
               </button>
             </li>
           ))}
@@ -70,3 +73,13 @@ export function DatasetSidebar(props: DatasetSidebarProps) {
     </aside>
   );
 }
+
+function isSelected(
+  dataset: DatasetListing,
+  selected: DatasetListing | null,
+): boolean {
+  return (
+    dataset.data_root === selected?.data_root &&
+    dataset.dataset_name === selected?.dataset_name
+  );
+}
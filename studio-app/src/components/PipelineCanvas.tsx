@@ -1,6 +1,6 @@
 import { useState } from "react";
 import { save } from "@tauri-apps/plugin-dialog";
-import { exportPipelineCanvas } from "../api/studioApi";
+import { errorMessage, exportPipelineCanvas } from "../api/studioApi";
 import { PipelineEdge, PipelineNode, PipelineNodeType } from "../types";
 import { PipelineCanvasToolbar } from "./PipelineCanvasToolbar";
 import { PipelineEditorList } from "./PipelineEditorList";
@@ -227,7 +227,7 @@ export function PipelineCanvas(props: PipelineCanvasProps) {
       }
       setCanvasActionMessage(`Canvas exported to ${result.output_path}`);
     } catch (error) {
-      const message = error instanceof Error ? error.message : String(error);
+      const message = errorMessage(error);
       setCanvasActionMessage(`Canvas export failed: ${message}`);
     }
   }
@@ -1,12 +1,34 @@
-import { LineageGraphSummary, TrainingRunSummary } from "../types";
+import {
+  HardwareProfile,
+  LineageGraphSummary,
+  TrainingRunSummary,
+} from "../types";
 
 interface RuntimeInsightsViewProps {
-  hardwareProfile: Record<string, string> | null;
+  hardwareProfile: HardwareProfile | null;
   trainingRuns: TrainingRunSummary[];
   lineage: LineageGraphSummary | null;
   onRefresh: () => void;
 }
 
+function hardwareProfileRows(profile: HardwareProfile): [string, string][] {
+  const gpuRows = profile.gpus.map((gpu): [string, string] => [
+    `gpu ${gpu.index}`,
+    `${gpu.name} (${gpu.total_memory_gb.toFixed(1)} GB` +
+      (gpu.capability ? `, sm ${gpu.capability})` : ")"),
+  ]);
+  return [
+    ["accelerator", profile.accelerator],
+    ["gpu_count", String(profile.gpu_count)],
+    ...gpuRows,
+    ["bf16_supported", profile.bf16_supported ? "yes" : "no"],
+    ["recommended_precision_mode", profile.recommended_precision_mode],
+    ["recommended_batch_size", String(profile.recommended_batch_size)],
+    ["suggested_profile", profile.suggested_profile],
+    ["detected_at", profile.detected_at],
+  ];
+}
+
 export function RuntimeInsightsView(props: RuntimeInsightsViewProps) {
   return (
     <section className="panel">
@@ -21,7 +43,7 @@ export function RuntimeInsightsView(props: RuntimeInsightsViewProps) {
           <h4>Hardware Profile</h4>
           {props.hardwareProfile ? (
             <dl className="runtime-key-value-list">
-              {Object.entries(props.hardwareProfile).map(([key, value]) => (
+              {hardwareProfileRows(props.hardwareProfile).map(([key, value]) => (
                 <div key={key} className="runtime-key-value-row">
                   <dt>{key}</dt>
                   <dd>{value}</dd>
@@ -58,7 +80,8 @@ export function RuntimeInsightsView(props: RuntimeInsightsViewProps) {
         {props.lineage ? (
           <>
             <p>
-              runs={props.lineage.run_count} edges={props.lineage.edge_count}
+              runs={props.lineage.run_count} datasets={props.lineage.dataset_count}{" "}
+              edges={props.lineage.edge_count}
             </p>
             {props.lineage.edges.length > 0 ? (
               <ul className="runtime-lineage-list">
@@ -8,6 +8,8 @@ import { VersionDiffView } from "./VersionDiffView";
 import { RuntimeInsightsView } from "./RuntimeInsightsView";
 import {
   DatasetDashboard,
+  DatasetListing,
+  HardwareProfile,
   LineageGraphSummary,
   PipelineEdge,
   PipelineNode,
@@ -23,7 +25,7 @@ import { anyDashboardSectionVisible, PanelVisibility } from "../view_controls";
 interface WorkspacePanelsProps {
   panelVisibility: PanelVisibility;
   dataRoot: string;
-  selectedDataset: string | null;
+  selectedDataset: DatasetListing | null;
   dashboard: DatasetDashboard | null;
   versions: VersionSummary[];
   baseVersion: string | null;
@@ -62,7 +64,7 @@ interface WorkspacePanelsProps {
   onHistoryPathChange: (value: string) => void;
   onLoadHistory: () => void;
   consoleOutput: string;
-  hardwareProfile: Record<string, string> | null;
+  hardwareProfile: HardwareProfile | null;
   trainingRuns: TrainingRunSummary[];
   lineage: LineageGraphSummary | null;
   onRefreshRuntimeInsights: () => void;
@@ -134,8 +136,8 @@ export function WorkspacePanels(props: WorkspacePanelsProps) {
       )}
       {props.panelVisibility.chat_room && (
         <ChatRoomView
-          dataRoot={props.dataRoot}
-          selectedDataset={props.selectedDataset}
+          dataRoot={props.selectedDataset?.data_root ?? props.dataRoot}
+          selectedDataset={props.selectedDataset?.dataset_name ?? null}
         />
       )}
       {props.panelVisibility.training_curves && (
@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { loadTrainingHistory } from "../api/studioApi";
+import { errorMessage, loadTrainingHistory } from "../api/studioApi";
 import { buildDefaultNode } from "../pipeline";
 import { resolveNonOverlappingNodePosition } from "../pipeline_layout";
 import {
@@ -18,6 +18,9 @@ import {
   TrainingHistory,
 } from "../types";
 
+/** Two batch points per pixel of the training curve chart width. */
+const HISTORY_CHART_MAX_POINTS = 2000;
+
 interface PipelineGraphInitialState {
   nodes: PipelineNode[];
   edges: PipelineEdge[];
@@ -216,10 +219,9 @@ export function usePipelineGraphState(
       await options.on_pipeline_complete();
     } catch (error) {
       setProgress((current) => ({ ...current, is_running: false }));
-      const errorMessage =
-        error instanceof Error ? error.message : String(error);
+      const message = errorMessage(error);
       setConsoleOutput((current) =>
-        `${current}\nPipeline failed: ${errorMessage}`.trim(),
+        `${current}\nPipeline failed: ${message}`.trim(),
       );
       throw error;
     }
@@ -229,7 +231,10 @@ export function usePipelineGraphState(
     if (!historyPath.trim()) {
       return;
     }
-    const row = await loadTrainingHistory(historyPath.trim());
+    const row = await loadTrainingHistory(
+      historyPath.trim(),
+      HISTORY_CHART_MAX_POINTS,
+    );
     setHistory(row);
   }
 
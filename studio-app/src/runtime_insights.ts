@@ -2,22 +2,31 @@ import {
   getHardwareProfile,
   getLineageGraph,
   listTrainingRuns,
+  refreshHardwareProfile,
 } from "./api/studioApi";
-import { LineageGraphSummary, TrainingRunSummary } from "./types";
+import {
+  HardwareProfile,
+  LineageGraphSummary,
+  TrainingRunSummary,
+} from "./types";
 
 export interface RuntimeInsightsPayload {
   runs: TrainingRunSummary[];
   lineage: LineageGraphSummary;
-  hardwareProfile: Record<string, string>;
+  hardwareProfile: HardwareProfile;
 }
 
+/** The hardware profile is cached backend-side; `redetectHardware` bypasses it. */
 export async function loadRuntimeInsights(
   dataRoot: string,
+  redetectHardware = false,
 ): Promise<RuntimeInsightsPayload> {
   const [runs, lineage, hardwareProfile] = await Promise.all([
     listTrainingRuns(dataRoot),
     getLineageGraph(dataRoot),
-    getHardwareProfile(dataRoot),
+    redetectHardware
+      ? refreshHardwareProfile(dataRoot)
+      : getHardwareProfile(dataRoot),
   ]);
   return { runs, lineage, hardwareProfile };
 }
@@ -1,11 +1,16 @@
-import { PipelineEdge, PipelineNode, PipelineNodeType } from "./types";
+import {
+  DatasetListing,
+  PipelineEdge,
+  PipelineNode,
+  PipelineNodeType,
+} from "./types";
 import { DEFAULT_PANEL_VISIBILITY, PanelVisibility } from "./view_controls";
 
 const SESSION_STORAGE_KEY = "forge_studio_session_v1";
 
 export interface StudioSessionState {
   data_root: string;
-  selected_dataset: string | null;
+  selected_dataset: DatasetListing | null;
   selected_version: string | null;
   base_version: string | null;
   target_version: string | null;
@@ -49,7 +54,7 @@ export function loadSessionState(): StudioSessionState {
     const parsed = JSON.parse(rawValue) as Partial<StudioSessionState>;
     return {
       data_root: asString(parsed.data_root, DEFAULT_SESSION_STATE.data_root),
-      selected_dataset: asNullableString(parsed.selected_dataset),
+      selected_dataset: parseDatasetListing(parsed.selected_dataset),
       selected_version: asNullableString(parsed.selected_version),
       base_version: asNullableString(parsed.base_version),
       target_version: asNullableString(parsed.target_version),
@@ -68,6 +73,21 @@ export function loadSessionState(): StudioSessionState {
   }
 }
 
+/** Sessions saved before federated roots stored a bare name; those reselect. */
+function parseDatasetListing(value: unknown): DatasetListing | null {
+  if (!value || typeof value !== "object") {
+    return null;
+  }
+  const payload = value as Partial<DatasetListing>;
+  if (
+    typeof payload.dataset_name !== "string" ||
+    typeof payload.data_root !== "string"
+  ) {
+    return null;
+  }
+  return { dataset_name: payload.dataset_name, data_root: payload.data_root };
+}
+
 function parseNodes(value: unknown): PipelineNode[] {
   if (!Array.isArray(value)) {
     return [];
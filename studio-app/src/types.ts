@@ -1,8 +1,14 @@
+export interface DatasetListing {
+  dataset_name: string;
+  data_root: string;
+}
+
 export interface VersionSummary {
   version_id: string;
   record_count: number;
   created_at: string;
   parent_version: string | null;
+  duplicate_rate: number | null;
 }
 
 export interface SourceCount {
@@ -46,6 +52,8 @@ export interface CommandTaskStart {
 export interface CommandTaskStatus {
   task_id: string;
   status: "running" | "completed" | "failed";
+  /** `status` in the active locale. */
+  status_label: string;
   command: string;
   args: string[];
   exit_code: number | null;
@@ -55,6 +63,10 @@ export interface CommandTaskStatus {
   estimated_total_seconds: number;
   remaining_seconds: number;
   progress_percent: number;
+  eta_source: "step_timing" | "command_average";
+  completed_steps: number | null;
+  total_steps: number | null;
+  seconds_per_step: number | null;
 }
 
 export interface TrainingEpoch {
@@ -70,11 +82,82 @@ export interface TrainingBatchLoss {
   train_loss: number;
 }
 
+export interface SmoothedLossPoint {
+  global_step: number;
+  train_loss: number;
+}
+
 export interface TrainingHistory {
   epochs: TrainingEpoch[];
   batch_losses: TrainingBatchLoss[];
+  smoothed_batch_losses: SmoothedLossPoint[];
+}
+
+export interface TrainingHistoryUpdate {
+  watch_id: string;
+  history_path: string;
+  new_epochs: TrainingEpoch[];
+  new_batch_losses: TrainingBatchLoss[];
+  total_epochs: number;
+  total_batch_losses: number;
+}
+
+export interface RunStateChange {
+  watch_id: string;
+  run_id: string;
+  dataset_name: string;
+  previous_state: string | null;
+  state: string;
+  error_message: string | null;
+  updated_at: string;
+}
+
+export interface AcceleratorSample {
+  device_index: number;
+  name: string;
+  backend: string;
+  utilization_percent: number | null;
+  memory_used_mb: number | null;
+  memory_total_mb: number | null;
+  temperature_celsius: number | null;
+  power_watts: number | null;
 }
 
+export interface TelemetrySnapshot {
+  sampled_at: string;
+  devices: AcceleratorSample[];
+}
+
+export type BackendEvent =
+  | { kind: "task_started"; task_id: string; command: string; args: string[] }
+  | {
+      kind: "task_finished";
+      task_id: string;
+      command: string;
+      status: string;
+      exit_code: number | null;
+    }
+  | {
+      kind: "dataset_changed";
+      dataset_name: string;
+      command: string;
+      task_id: string;
+    }
+  | {
+      kind: "pipeline_run_updated";
+      pipeline_run_id: string;
+      status: string;
+      overall_percent: number;
+    }
+  | ({ kind: "run_state_changed" } & RunStateChange)
+  | ({ kind: "training_history_updated" } & TrainingHistoryUpdate)
+  | ({ kind: "hardware_telemetry" } & TelemetrySnapshot);
+
+export type BackendEventEnvelope = BackendEvent & {
+  sequence: number;
+  published_at: string;
+};
+
 export type PipelineNodeType =
   | "ingest"
   | "filter"
@@ -92,16 +175,135 @@ export interface PipelineNode {
   config: Record<string, string>;
 }
 
+export interface PipelineEdgeCondition {
+  metric: "record_count";
+  operator: ">" | ">=" | "<" | "<=" | "==" | "!=";
+  value: number;
+}
+
 export interface PipelineEdge {
   id: string;
   source_node_id: string;
   target_node_id: string;
+  condition?: PipelineEdgeCondition | null;
+}
+
+export interface PipelineNodeGroup {
+  id: string;
+  title: string;
+  node_ids: string[];
+  collapsed: boolean;
+  canvas_x: number;
+  canvas_y: number;
 }
 
 export interface PipelineCanvasExportResult {
   output_path: string;
 }
 
+export interface PipelineCanvasExportEntry {
+  path: string;
+  file_name: string;
+  format: "json" | "yaml-pipeline";
+  size_bytes: number;
+  modified_at: string | null;
+}
+
+export interface PipelineBundleFile {
+  node_id: string;
+  field: string;
+  bundle_path: string;
+  original_path: string;
+  sha256: string;
+  size_bytes: number;
+}
+
+export interface PipelineCanvasBundleExport {
+  output_path: string;
+  forge_version: string | null;
+  files: PipelineBundleFile[];
+  warnings: string[];
+}
+
+export interface PipelineMergeConflict {
+  kind: "config" | "attribute" | "edit_delete" | "edge" | "start_node";
+  item_id: string;
+  key: string | null;
+  base: unknown;
+  theirs: unknown;
+  ours: unknown;
+}
+
+export interface PipelineMergeRename {
+  from_id: string;
+  to_id: string;
+}
+
+export interface PipelineCanvasMerge {
+  start_node_id: string | null;
+  nodes: PipelineNode[];
+  edges: PipelineEdge[];
+  groups: PipelineNodeGroup[];
+  conflicts: PipelineMergeConflict[];
+  renamed_node_ids: PipelineMergeRename[];
+  renamed_edge_ids: PipelineMergeRename[];
+  dropped_edge_ids: string[];
+}
+
+export interface PipelineCanvasBundleImport {
+  source_path: string;
+  files_dir: string;
+  bundle_forge_version: string | null;
+  installed_forge_version: string | null;
+  start_node_id: string | null;
+  nodes: PipelineNode[];
+  edges: PipelineEdge[];
+  groups: PipelineNodeGroup[];
+  warnings: string[];
+}
+
+export interface PipelineCanvasState {
+  start_node_id: string | null;
+  nodes: PipelineNode[];
+  edges: PipelineEdge[];
+  groups?: PipelineNodeGroup[];
+}
+
+export interface PipelineCanvasEdit {
+  edit_id: number;
+  label: string;
+  applied_at: string;
+  before: PipelineCanvasState;
+  after: PipelineCanvasState;
+}
+
+export interface PipelineCanvasEditJournal {
+  canvas_path: string;
+  position: number;
+  edits: PipelineCanvasEdit[];
+}
+
+export interface PipelineCanvasEditCursor {
+  canvas_path: string;
+  position: number;
+  edit_count: number;
+  undo_label: string | null;
+  redo_label: string | null;
+}
+
+export interface PipelineCanvasEditStep {
+  canvas: PipelineCanvasState;
+  cursor: PipelineCanvasEditCursor;
+}
+
+export interface PipelineConfigImport {
+  source_path: string;
+  start_node_id: string | null;
+  nodes: PipelineNode[];
+  edges: PipelineEdge[];
+  warnings: string[];
+}
+
 export interface TrainingRunSummary {
   run_id: string;
   dataset_name: string;
@@ -113,6 +315,238 @@ export interface TrainingRunSummary {
   model_path: string | null;
 }
 
+export interface HardwareGpu {
+  index: number;
+  name: string;
+  total_memory_gb: number;
+  capability: string | null;
+}
+
+export interface NotificationSettings {
+  task_completed: boolean;
+  task_failed: boolean;
+  pipeline_finished: boolean;
+}
+
+export interface CacheSizeSettings {
+  finished_tasks: number;
+  canvas_snapshots: number;
+  canvas_edits: number;
+}
+
+export interface TelemetrySettings {
+  enabled: boolean;
+  upload_url: string | null;
+}
+
+/** Stamped onto annotations, model versions, and audit log entries. */
+export interface UserIdentity {
+  name: string | null;
+  email: string | null;
+}
+
+/** Local JSON-RPC server for scripts; read at launch. */
+export interface RpcServerSettings {
+  enabled: boolean;
+  port: number;
+}
+
+export interface EventBridgeSettings {
+  enabled: boolean;
+  port: number;
+  allowed_origins: string[];
+}
+
+export interface AppSettings {
+  forge_binary: string;
+  max_concurrent_tasks: number;
+  default_data_root: string | null;
+  workspace_roots: string[];
+  data_roots: string[];
+  log_filter: string;
+  read_only: boolean;
+  locale: string;
+  identity: UserIdentity;
+  notifications: NotificationSettings;
+  cache_sizes: CacheSizeSettings;
+  telemetry: TelemetrySettings;
+  rpc_server: RpcServerSettings;
+  event_bridge: EventBridgeSettings;
+}
+
+/** Fields to change; `null` resets a field to its default. */
+export interface AppSettingsUpdate {
+  forge_binary?: string | null;
+  max_concurrent_tasks?: number | null;
+  default_data_root?: string | null;
+  workspace_roots?: string[] | null;
+  data_roots?: string[] | null;
+  log_filter?: string | null;
+  read_only?: boolean | null;
+  locale?: string | null;
+  identity?: Partial<UserIdentity> | null;
+  notifications?: Partial<NotificationSettings> | null;
+  cache_sizes?: Partial<CacheSizeSettings> | null;
+  telemetry?: Partial<TelemetrySettings> | null;
+  rpc_server?: Partial<RpcServerSettings> | null;
+  event_bridge?: Partial<EventBridgeSettings> | null;
+}
+
+export interface CommandUsage {
+  call_count: number;
+  total_millis: number;
+  max_millis: number;
+}
+
+export interface UsageTelemetry {
+  enabled: boolean;
+  upload_url: string | null;
+  period_start: string;
+  last_uploaded_at: string | null;
+  commands: Record<string, CommandUsage>;
+}
+
+export type AppLogLevel = "error" | "warn" | "info" | "debug" | "trace";
+
+export interface AppLogEntry {
+  timestamp: string;
+  level: AppLogLevel;
+  target: string;
+  message: string;
+  fields: Record<string, unknown>;
+}
+
+export interface CrashReport {
+  report_id: string;
+  kind: "panic" | "task_failure";
+  created_at: string;
+  studio_version: string;
+  os: string;
+  message: string;
+  location: string | null;
+  backtrace: string | null;
+  stderr_tail: string | null;
+  recent_logs: string[];
+}
+
+export interface AuditLogEntry {
+  timestamp: string;
+  author: string | null;
+  action: string;
+  target: string;
+}
+
+export interface PipelineNodeParameter {
+  name: string;
+  flag: string | null;
+  value_type: "string" | "integer" | "float" | "boolean" | "path";
+  required: boolean;
+  default_value: string | null;
+  range: {
+    minimum: number | null;
+    exclusive_minimum: number | null;
+    maximum: number | null;
+    exclusive_maximum: number | null;
+  } | null;
+  allowed_values: string[];
+}
+
+export interface PluginNodeType {
+  node_type: string;
+  command: string | null;
+  description: string;
+  parameters: PipelineNodeParameter[];
+}
+
+export interface PluginInfo {
+  plugin_dir: string;
+  name: string;
+  version: string;
+  description: string;
+  protocol_version: number;
+  executable: string | null;
+  commands: Array<{ name: string; description: string; read_only: boolean }>;
+  dataset_analyzers: Array<{ name: string; description: string }>;
+  node_types: PluginNodeType[];
+  load_error: string | null;
+}
+
+export interface PluginDatasetAnalysis {
+  plugin_name: string;
+  analyzer: string;
+  dataset_name: string;
+  version_id: string;
+  result: unknown;
+}
+
+export type DataRootCheckStatus = "pass" | "warn" | "fail" | "skipped";
+
+export interface DataRootCheckItem {
+  check_id: string;
+  label: string;
+  status: DataRootCheckStatus;
+  detail: string;
+  remediation: string | null;
+}
+
+export interface DataRootCheck {
+  data_root: string;
+  ready: boolean;
+  checks: DataRootCheckItem[];
+}
+
+export interface DataRootInitOptions {
+  add_workspace_root?: boolean;
+  set_default?: boolean;
+}
+
+export interface DataRootInit {
+  data_root: string;
+  created: string[];
+  existing: string[];
+}
+
+export interface WorkspaceBackupOptions {
+  include_model_weights?: boolean;
+  include_dataset_records?: boolean;
+}
+
+export interface WorkspaceBackup {
+  archive_path: string;
+  file_count: number;
+  total_bytes: number;
+  excluded_file_count: number;
+}
+
+export interface WorkspaceRestore {
+  data_root: string;
+  restored_file_count: number;
+  backup_created_at: string;
+}
+
+export type ForgeCompatibility = "compatible" | "untested" | "incompatible" | "unknown";
+
+export interface ForgeUpdateCheck {
+  studio_version: string;
+  installed_version: string | null;
+  latest_version: string | null;
+  release_url: string | null;
+  update_available: boolean;
+  compatibility: ForgeCompatibility;
+  warnings: string[];
+}
+
+export interface HardwareProfile {
+  accelerator: string;
+  gpu_count: number;
+  gpus: HardwareGpu[];
+  bf16_supported: boolean;
+  recommended_precision_mode: string;
+  recommended_batch_size: number;
+  suggested_profile: string;
+  detected_at: string;
+}
+
 export interface LineageRunNode {
   run_id: string;
   dataset_name: string;
@@ -131,9 +565,57 @@ export interface LineageEdge {
   type: string;
 }
 
+export interface LineageDatasetNode {
+  node_id: string;
+  dataset_name: string;
+  version_id: string;
+  created_at: string | null;
+  record_count: number | null;
+  consumed_by_run_ids: string[];
+  produced_by_run_ids: string[];
+}
+
 export interface LineageGraphSummary {
   run_count: number;
+  dataset_count: number;
   edge_count: number;
   runs: LineageRunNode[];
+  datasets: LineageDatasetNode[];
   edges: LineageEdge[];
 }
+
+export interface MetadataIndexSync {
+  index_path: string;
+  dataset_count: number;
+  version_count: number;
+  run_count: number;
+  lineage_edge_count: number;
+  refreshed_source_count: number;
+  removed_source_count: number;
+  unreadable_sources: string[];
+}
+
+export interface MetadataSearchHit {
+  kind: "dataset" | "version" | "run" | "model";
+  id: string;
+  dataset_name: string | null;
+  run_id: string | null;
+  created_at: string | null;
+}
+
+/** Rejection value of every Studio command. */
+export interface ForgeStudioError {
+  code:
+    | "not_found"
+    | "invalid_input"
+    | "io"
+    | "parse"
+    | "conflict"
+    | "read_only"
+    | "internal";
+  /** The code's label in the active locale. */
+  title: string;
+  message: string;
+  context: string | null;
+  remediation: string | null;
+}
@@ -0,0 +1,47 @@
+"""Unit tests for eval CLI command."""
+
+from __future__ import annotations
+
+import pytest
+
+from cli.main import main
+from core.errors import ForgeServeError
+from serve.model_evaluation import EvaluationOptions, evaluate_model
+
+
+def test_cli_eval_prints_requested_metric_rows(monkeypatch, capsys) -> None:
+    """Eval command should print one metric=value row per requested metric."""
+
+    class _FakeDataset:
+        def load_records(self, version_id: str | None) -> tuple[object, list[object]]:
+            return object(), []
+
+    captured: list[EvaluationOptions] = []
+
+    def _fake_evaluate(records: list[object], options: EvaluationOptions) -> dict[str, float]:
+        captured.append(options)
+        return {"loss": 3.5, "perplexity": 33.1}
+
+    monkeypatch.setattr("cli.main.ForgeClient.dataset", lambda self, name: _FakeDataset())
+    monkeypatch.setattr("cli.eval_command.evaluate_model", _fake_evaluate)
+    exit_code = main(
+        [
+            "eval",
+            "--model-path",
+            "out/model.pt",
+            "--dataset",
+            "demo",
+            "--metrics",
+            "loss,perplexity",
+        ]
+    )
+    output = capsys.readouterr().out.strip().splitlines()
+
+    assert exit_code == 0 and output == ["loss=3.5", "perplexity=33.1"]
+    assert captured[0].metrics == ("loss", "perplexity")
+
+
+def test_evaluate_model_rejects_unknown_metrics() -> None:
+    """Evaluation should name supported metrics when given an unknown one."""
+    with pytest.raises(ForgeServeError):
+        evaluate_model([], EvaluationOptions(model_path="out/model.pt", metrics=("bleu",)))
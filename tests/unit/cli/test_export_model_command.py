@@ -0,0 +1,44 @@
+"""Unit tests for export-model CLI command."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from cli.main import main
+from core.errors import ForgeServeError
+from serve.model_export import ModelExportOptions, export_model
+
+
+def test_cli_export_model_passes_options_and_prints_path(monkeypatch, capsys) -> None:
+    """Export-model command should forward its flags and print the output path."""
+    captured: list[ModelExportOptions] = []
+
+    def _fake_export(options: ModelExportOptions) -> Path:
+        captured.append(options)
+        return Path(options.output_path)
+
+    monkeypatch.setattr("cli.export_model_command.export_model", _fake_export)
+    exit_code = main(
+        [
+            "export-model",
+            "--model-path",
+            "out/model.pt",
+            "--output-path",
+            "out/exports/model-int8.onnx",
+            "--quantization",
+            "int8",
+        ]
+    )
+
+    assert exit_code == 0
+    assert captured[0].export_format == "onnx" and captured[0].quantization == "int8"
+    assert capsys.readouterr().out.strip() == "out/exports/model-int8.onnx"
+
+
+def test_export_model_rejects_non_pytorch_weights() -> None:
+    """Export should only accept .pt weights from a training run."""
+    options = ModelExportOptions(model_path="out/model.onnx", output_path="out/model.onnx")
+    with pytest.raises(ForgeServeError):
+        export_model(options)
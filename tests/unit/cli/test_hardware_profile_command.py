@@ -2,6 +2,8 @@
 
 from __future__ import annotations
 
+import json
+
 from cli.main import main
 
 
@@ -22,3 +24,22 @@ def test_cli_hardware_profile_prints_sorted_key_value_rows(
     output = capsys.readouterr().out.strip().splitlines()
 
     assert exit_code == 0 and output == ["accelerator=cpu", "gpu_count=0"]
+
+
+def test_cli_hardware_profile_json_prints_one_object(
+    monkeypatch,
+    capsys,
+) -> None:
+    """Hardware-profile --json should print the full profile as one JSON object."""
+
+    class _FakeProfile:
+        def to_dict(self) -> dict[str, object]:
+            return {"accelerator": "cuda", "gpus": [{"index": 0, "name": "A100"}]}
+
+    monkeypatch.setattr(
+        "cli.hardware_profile_command.detect_hardware_profile", lambda: _FakeProfile()
+    )
+    exit_code = main(["hardware-profile", "--json"])
+    payload = json.loads(capsys.readouterr().out)
+
+    assert exit_code == 0 and payload["gpus"][0]["name"] == "A100"
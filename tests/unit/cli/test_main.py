@@ -2,8 +2,11 @@
 
 from __future__ import annotations
 
+import json
 from dataclasses import replace
 
+import pytest
+
 from cli.main import main
 from core.config import ForgeConfig
 
@@ -25,3 +28,28 @@ def test_cli_ingest_creates_version(tmp_path, capsys) -> None:
     output = capsys.readouterr().out.strip()
 
     assert exit_code == 0 and bool(output)
+
+
+def test_cli_version_prints_forge_version(capsys) -> None:
+    """CLI --version should print the forge version and exit cleanly."""
+    with pytest.raises(SystemExit) as exit_info:
+        main(["--version"])
+    output = capsys.readouterr().out.strip()
+
+    assert exit_info.value.code == 0 and output.startswith("forge ")
+
+
+def test_cli_describe_commands_lists_subcommand_parameters(capsys) -> None:
+    """CLI --describe-commands should print each subcommand's parameters as JSON."""
+    with pytest.raises(SystemExit) as exit_info:
+        main(["--describe-commands"])
+    described = json.loads(capsys.readouterr().out)
+    commands = {command["name"]: command for command in described["commands"]}
+    shard_size = next(
+        parameter
+        for parameter in commands["export-training"]["parameters"]
+        if parameter["name"] == "shard_size"
+    )
+
+    assert exit_info.value.code == 0 and "train" in commands
+    assert shard_size["flag"] == "--shard-size" and shard_size["value_type"] == "integer"
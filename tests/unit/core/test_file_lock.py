@@ -0,0 +1,20 @@
+"""Unit tests for cross-process data-root file locks."""
+
+from __future__ import annotations
+
+import pytest
+
+from core.errors import ForgeLockError
+from core.file_lock import locked_file
+
+
+def test_held_lock_times_out_other_writers_until_released(tmp_path) -> None:
+    """A second writer should fail with retry guidance while the lock is held."""
+    index_path = tmp_path / "runs" / "index.json"
+    with locked_file(index_path):
+        with pytest.raises(ForgeLockError, match="in use by another process"):
+            with locked_file(index_path, wait_seconds=0.1):
+                pass
+
+    with locked_file(index_path, wait_seconds=0.0):
+        assert (tmp_path / "runs" / "index.json.lock").is_file()
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import hashlib
 from pathlib import Path
 
 from core.types import TrainingRunResult
@@ -40,3 +41,33 @@ def test_save_and_load_training_artifact_contract_round_trip(tmp_path: Path) ->
     payload = load_training_artifact_contract(result.model_path)
 
     assert payload is not None and payload["run_id"] == "run-123"
+
+
+def test_save_training_artifact_contract_hashes_existing_artifacts(tmp_path: Path) -> None:
+    """Artifact contract should record content hashes for artifacts present on disk."""
+    result = TrainingRunResult(
+        model_path=str(tmp_path / "model.pt"),
+        history_path=str(tmp_path / "history.json"),
+        plot_path=None,
+        epochs_completed=1,
+        checkpoint_dir=None,
+        best_checkpoint_path=None,
+        run_id="run-123",
+    )
+    Path(result.model_path).write_bytes(b"weights")
+    save_training_artifact_contract(
+        output_dir=tmp_path,
+        run_id="run-123",
+        dataset_name="demo",
+        dataset_version_id="demo-v1",
+        parent_model_path=None,
+        config_hash="abc123",
+        result=result,
+    )
+
+    payload = load_training_artifact_contract(result.model_path)
+
+    assert payload is not None
+    assert payload["artifact_hashes"] == {
+        "model_path": hashlib.sha256(b"weights").hexdigest(),
+    }
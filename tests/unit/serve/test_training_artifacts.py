@@ -46,6 +46,20 @@ def test_save_training_history_writes_batch_loss_rows(tmp_path: Path) -> None:
     assert len(payload["batch_losses"]) == 1
 
 
+def test_save_training_history_replaces_earlier_epochs(tmp_path: Path) -> None:
+    """Per-epoch rewrites should leave only the latest history and no temp file."""
+    first_epoch = EpochMetric(epoch=1, train_loss=1.2, validation_loss=1.3)
+    save_training_history(tmp_path, [first_epoch], [])
+    history_path = save_training_history(
+        tmp_path,
+        [first_epoch, EpochMetric(epoch=2, train_loss=1.0, validation_loss=1.1)],
+        [],
+    )
+
+    payload = json.loads(history_path.read_text(encoding="utf-8"))
+    assert len(payload["epochs"]) == 2 and sorted(tmp_path.iterdir()) == [history_path]
+
+
 def test_save_training_plot_raises_without_matplotlib(monkeypatch, tmp_path: Path) -> None:
     """Plot writer should raise dependency error when matplotlib is unavailable."""
     import builtins
@@ -76,3 +76,29 @@ def test_transition_completed_updates_artifact_and_model_lineage(tmp_path) -> No
     }
 
     assert completed.artifact_contract_path and produced_edge in lineage["edges"]
+
+
+def test_start_run_records_resume_provenance(tmp_path) -> None:
+    """A resumed run should name its checkpoint and the run that wrote it."""
+    registry = TrainingRunRegistry(tmp_path)
+    output_dir = tmp_path / "out"
+    source = registry.start_run(
+        dataset_name="demo",
+        dataset_version_id="demo-v1",
+        output_dir=str(output_dir),
+        parent_model_path=None,
+        config_hash="abc123",
+    )
+    checkpoint_path = str(output_dir / "checkpoints" / "epoch-0002.pt")
+    resumed = registry.start_run(
+        dataset_name="demo",
+        dataset_version_id="demo-v1",
+        output_dir=str(output_dir),
+        parent_model_path=None,
+        config_hash="abc123",
+        resumed_from_checkpoint=checkpoint_path,
+    )
+    running = registry.transition(resumed.run_id, "running")
+
+    assert running.resumed_from_run_id == source.run_id
+    assert running.resumed_from_checkpoint == checkpoint_path
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import hashlib
 from dataclasses import replace
 
 import pytest
@@ -37,6 +38,23 @@ def test_create_snapshot_persists_manifest(tmp_path) -> None:
     assert manifest.dataset_name == "demo"
 
 
+def test_create_snapshot_records_content_checksum(tmp_path) -> None:
+    """Catalog entries should carry the SHA-256 of the version's records file."""
+    config = replace(ForgeConfig.from_env(), data_root=tmp_path)
+    store = SnapshotStore(config)
+    request = SnapshotWriteRequest(
+        dataset_name="demo",
+        records=(_sample_record(),),
+        recipe_steps=("step",),
+    )
+
+    manifest = store.create_snapshot(request)
+    records_path = tmp_path / "datasets" / "demo" / "versions" / manifest.version_id
+    expected = hashlib.sha256((records_path / "records.jsonl").read_bytes()).hexdigest()
+
+    assert store.list_versions("demo")[0].content_checksum == expected
+
+
 def test_load_records_returns_written_payload(tmp_path) -> None:
     """Store should return records written into a snapshot."""
     config = replace(ForgeConfig.from_env(), data_root=tmp_path)